@@ -0,0 +1,87 @@
+//! Decoding throughput over `tests/fixtures/cap.pcap`: walking the schema for every message
+//! against the plans precompiled by the `ProtocolManager`, and against a partial decoding.
+
+use std::time::{Duration, Instant};
+
+use dtools::sniffer::{
+    offline::frame_file,
+    parser::{
+        metadata::PacketMetadata,
+        packet::{Packet, PacketError, PacketParser},
+        selection::FieldSelection,
+    },
+    protocol::protocol::ProtocolManager,
+};
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/");
+const FIXTURE_CAPTURE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/cap.pcap");
+const ITERATIONS: u32 = 50;
+
+fn messages() -> Vec<PacketMetadata> {
+    frame_file(FIXTURE_CAPTURE)
+        .expect("Failed to open capture")
+        .map(|message| message.expect("Failed to read capture").metadata)
+        .collect()
+}
+
+fn bench(
+    name: &str,
+    messages: &[PacketMetadata],
+    protocol: &ProtocolManager,
+    decode: impl Fn(&mut PacketParser, &ProtocolManager) -> Result<Packet, PacketError>,
+) -> Duration {
+    let run = || {
+        let mut decoded = 0;
+        for metadata in messages {
+            let mut parser = PacketParser::from_metadata(metadata);
+            if decode(&mut parser, protocol).is_ok() {
+                decoded += 1;
+            }
+        }
+        decoded
+    };
+
+    // Warm up the caches and the allocator
+    let decoded = run();
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        std::hint::black_box(run());
+    }
+    let elapsed = start.elapsed() / ITERATIONS;
+
+    let per_message = elapsed / messages.len().max(1) as u32;
+    println!(
+        "{:<8} {:>10.2?} per capture, {:>8.2?} per message ({} decoded)",
+        name, elapsed, per_message, decoded
+    );
+    elapsed
+}
+
+fn main() {
+    let mut protocol = ProtocolManager::new();
+    protocol
+        .init(FIXTURES)
+        .expect("Failed to load the protocol");
+
+    let messages = messages();
+    println!("{} messages, {} iterations", messages.len(), ITERATIONS);
+
+    let schema = bench("schema", &messages, &protocol, |parser, protocol| {
+        parser.parse_with_schema(protocol)
+    });
+    let plan = bench("plan", &messages, &protocol, |parser, protocol| {
+        parser.parse(protocol)
+    });
+    // A few fields of the chat messages
+    let selection = FieldSelection::from_paths(["channel", "senderName", "content"]);
+    let fields = bench("fields", &messages, &protocol, |parser, protocol| {
+        parser.parse_fields(protocol, &selection)
+    });
+
+    println!(
+        "plan is {:.2}x faster, fields {:.2}x",
+        schema.as_secs_f64() / plan.as_secs_f64(),
+        schema.as_secs_f64() / fields.as_secs_f64()
+    );
+}
@@ -0,0 +1,241 @@
+//! Headless version of the sniffer, decoded packets are printed on stdout as JSON lines.
+//!
+//! Logs are written to stderr and `<data-dir>/logs`, use `RUST_LOG` to change the level.
+
+use std::{
+    io::{self, Write},
+    path::PathBuf,
+    process::ExitCode,
+    thread,
+    time::Duration,
+};
+
+use dtools::{
+    node::Node,
+    sniffer::{
+        filter::EventFilter,
+        network::{CaptureState, Overflow, ReplaySpeed},
+        parser::{packet::Packet, selection::FieldSelection},
+        protocol::protocol::EventId,
+    },
+};
+
+const USAGE: &str = "Usage: dtools-cli --data-dir <dir> [options]
+
+Options:
+    --data-dir <dir>        Directory with the protocol files (same layout as the app data directory)
+    --update                Download the latest protocol files before decoding
+    --interface <name>      Capture on this interface instead of the configured/detected one
+    --file <path>           Decode a capture file (pcap/pcapng) instead of a live interface
+    --realtime              Replay the capture file with its original timing
+    --name <message>        Only print messages with this name (`*` wildcards allowed), can be repeated
+    --extends <class>       Only print messages inheriting from this class, can be repeated
+    --id <id>               Only print messages with this id, can be repeated
+    --field <path>          Only decode this field (e.g. `actors.name`), can be repeated
+    -h, --help              Print this message";
+
+const LISTENER_ID: &str = "cli";
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+/// Room for the bursts of a live capture, files wait for the printer instead
+const QUEUE_CAPACITY: usize = 1 << 16;
+
+#[derive(Debug, Default)]
+struct Args {
+    data_dir: PathBuf,
+    update: bool,
+    interface: Option<String>,
+    file: Option<PathBuf>,
+    realtime: bool,
+    names: Vec<String>,
+    superclasses: Vec<String>,
+    ids: Vec<EventId>,
+    fields: Vec<String>,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut parsed = Args::default();
+    let mut data_dir = None;
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {}", arg))
+        };
+        match arg.as_str() {
+            "--data-dir" => data_dir = Some(PathBuf::from(value()?)),
+            "--update" => parsed.update = true,
+            "--interface" => parsed.interface = Some(value()?),
+            "--file" => parsed.file = Some(PathBuf::from(value()?)),
+            "--realtime" => parsed.realtime = true,
+            "--name" => parsed.names.push(value()?),
+            "--extends" => parsed.superclasses.push(value()?),
+            "--field" => parsed.fields.push(value()?),
+            "--id" => {
+                let id = value()?;
+                let id = id
+                    .parse()
+                    .map_err(|_| format!("Invalid message id: {}", id))?;
+                parsed.ids.push(id);
+            }
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+
+    if parsed.interface.is_some() && parsed.file.is_some() {
+        return Err("--interface and --file can't be used together".to_string());
+    }
+    parsed.data_dir = data_dir.ok_or("--data-dir is required")?;
+    Ok(parsed)
+}
+
+fn print_packet(packet: &Packet) {
+    let line = match serde_json::to_string(packet) {
+        Ok(line) => line,
+        Err(err) => {
+            eprintln!("Failed to serialize {}: {}", packet.name, err);
+            return;
+        }
+    };
+
+    let mut stdout = io::stdout().lock();
+    if writeln!(stdout, "{}", line).is_err() {
+        // The reader is gone (e.g. `| head`), nothing left to do
+        std::process::exit(0);
+    }
+}
+
+/// Messages to subscribe to, every message when there is no filter
+fn resolve_filters(node: &Node, args: &Args) -> Result<Vec<EventFilter>, String> {
+    let mut filters = args
+        .ids
+        .iter()
+        .map(|id| EventFilter::Id(*id))
+        .chain(
+            args.names
+                .iter()
+                .map(String::as_str)
+                .map(EventFilter::from_pattern),
+        )
+        .chain(args.superclasses.iter().cloned().map(EventFilter::Subclass))
+        .collect::<Vec<_>>();
+    if filters.is_empty() {
+        filters.push(EventFilter::All);
+    }
+
+    // Most likely a typo
+    let protocol = node.protocol.read().unwrap();
+    let unmatched = filters
+        .iter()
+        .find(|filter| !protocol.ids().any(|id| filter.matches(*id, &protocol)));
+    match unmatched {
+        Some(EventFilter::Id(id)) => Err(format!("Unknown message id: {}", id)),
+        Some(EventFilter::Name(name)) => Err(format!("Unknown message name: {}", name)),
+        Some(filter) => Err(format!("No message matches {:?}", filter)),
+        None => Ok(filters),
+    }
+}
+
+fn run(args: Args) -> Result<(), String> {
+    let node = tauri::async_runtime::block_on(Node::new(&args.data_dir, None, args.update))
+        .map_err(|err| format!("Failed to initialize node: {}", err))?;
+
+    let filters = resolve_filters(&node, &args)?;
+    let selection = match args.fields.is_empty() {
+        true => FieldSelection::All,
+        false => FieldSelection::from_paths(&args.fields),
+    };
+    // Files are decoded faster than they can be printed, every message must still be printed
+    let overflow = match args.file {
+        Some(_) => Overflow::Wait,
+        None => Overflow::Drop,
+    };
+    let mut stream = node.packet_listener.lock().unwrap().stream(
+        LISTENER_ID,
+        filters.iter().cloned(),
+        selection,
+        QUEUE_CAPACITY,
+        overflow,
+    );
+    // Ends once the subscriptions are removed and the queue is drained
+    let printer = thread::spawn(move || {
+        while let Some(packet) = stream.blocking_recv() {
+            print_packet(&packet);
+        }
+    });
+
+    if let Some(interface) = &args.interface {
+        // Only for this run, the saved config is left untouched
+        node.config.config.write().unwrap().network.interface = Some(interface.clone());
+    }
+
+    let res = match &args.file {
+        Some(file) => {
+            let speed = if args.realtime {
+                ReplaySpeed::Original
+            } else {
+                ReplaySpeed::Fast
+            };
+            node.packet_listener.lock().unwrap().replay(file, speed)
+        }
+        None => node.packet_listener.lock().unwrap().run(),
+    };
+    res.map_err(|err| err.to_string())?;
+
+    // Live captures only end on error or when the process is killed
+    while node.packet_listener.lock().unwrap().is_running() {
+        thread::sleep(POLL_INTERVAL);
+    }
+
+    let mut listener = node.packet_listener.lock().unwrap();
+    for filter in filters {
+        listener.unsubscribe(filter, LISTENER_ID);
+    }
+    let _ = printer.join();
+
+    let stats = listener.get_stats();
+    eprintln!(
+        "{} frames, {} messages, {} decoded, {} decode failures, {} dropped",
+        stats.frames,
+        stats.messages_framed,
+        stats.messages_decoded,
+        stats.total_decode_failures(),
+        stats.total_dropped_messages()
+    );
+
+    if let CaptureState::Errored { message } = listener.get_state() {
+        return Err(message);
+    }
+    match stats.total_dropped_messages() {
+        0 => Ok(()),
+        dropped => Err(format!("{} messages were not printed", dropped)),
+    }
+}
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    let args = match parse_args(args.into_iter()) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{}\n\n{}", err, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // Keep stderr readable, debug logs are still available with RUST_LOG
+    if std::env::var("RUST_LOG").is_err() {
+        std::env::set_var("RUST_LOG", "warn");
+    }
+
+    match run(args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
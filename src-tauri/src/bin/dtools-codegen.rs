@@ -0,0 +1,37 @@
+//! Generate the typed messages of `dtools::sniffer::messages` from a protocol.
+//!
+//! Usage: dtools-codegen [protocol-dir] [output]
+//! Defaults to the protocol of the test fixtures and `src/sniffer/messages/generated.rs`.
+
+use std::{fs, process::ExitCode};
+
+use dtools::sniffer::protocol::{codegen, protocol::ProtocolManager};
+
+const DEFAULT_PROTOCOL_DIR: &str = "tests/fixtures/";
+const DEFAULT_OUTPUT: &str = "src/sniffer/messages/generated.rs";
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let protocol_dir = args.next().unwrap_or(DEFAULT_PROTOCOL_DIR.to_string());
+    let output = args.next().unwrap_or(DEFAULT_OUTPUT.to_string());
+
+    let mut protocol = ProtocolManager::new();
+    if let Err(err) = protocol.init(&protocol_dir) {
+        eprintln!("Failed to load the protocol from {}: {}", protocol_dir, err);
+        return ExitCode::FAILURE;
+    }
+
+    let code = match codegen::generate(&protocol) {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("Failed to generate the messages: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(err) = fs::write(&output, code) {
+        eprintln!("Failed to write {}: {}", output, err);
+        return ExitCode::FAILURE;
+    }
+    println!("Wrote {}", output);
+    ExitCode::SUCCESS
+}
@@ -6,13 +6,15 @@ use std::{
 use thiserror::Error;
 use tracing::info;
 
-use crate::sniffer::config::NetworkConfig;
+use crate::sniffer::{config::NetworkConfig, protocol::protocol::ProtocolConfig};
 
 #[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
 #[serde(rename_all = "camelCase")]
 pub struct NodeConfig {
     pub network: NetworkConfig,
     pub game_version: Version,
+    #[serde(default)]
+    pub protocol: ProtocolConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
@@ -56,6 +58,7 @@ impl Default for NodeConfig {
                 version: "".to_string(),
                 check_for_updates: false,
             },
+            protocol: ProtocolConfig::default(),
         }
     }
 }
@@ -101,11 +104,8 @@ where
         &self,
         update_fn: impl FnOnce(&mut ConfigType),
     ) -> Result<(), ConfigError> {
-        let mut config = self.config.write().unwrap();
-        update_fn(&mut config);
-        ConfigLoader::<ConfigType>::save(&config, &self.config_file_path)
-            .await
-            .map_err(Into::into)
+        // The lock can't be held across an await, the file is small enough to be written here
+        self.update_config_sync(update_fn)
     }
 
     pub fn update_config_sync(
@@ -114,7 +114,7 @@ where
     ) -> Result<(), ConfigError> {
         let mut config = self.config.write().unwrap();
         update_fn(&mut config);
-        ConfigLoader::<ConfigType>::save_sync(&config, &self.config_file_path).map_err(Into::into)
+        ConfigLoader::<ConfigType>::save_sync(&config, &self.config_file_path)
     }
 }
 
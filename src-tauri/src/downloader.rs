@@ -22,14 +22,14 @@ impl Downloader {
     pub async fn init(&mut self, node: &Node) -> Result<(), DownloaderError> {
         self.latest_version = self.get_latest_version(&node.http).await;
 
-        let res = match self.latest_version {
+        match self.latest_version {
             Some(ref version) => {
                 let need_update = {
                     let current_version = &node.config.config.read().unwrap().game_version;
                     let check_for_updates = &current_version.check_for_updates;
                     let current_version = &current_version.version;
                     info!("Current version: {}", &current_version);
-                    let check_for_updates = *check_for_updates || current_version.len() == 0;
+                    let check_for_updates = *check_for_updates || current_version.is_empty();
                     info!("Check for updates: {}", check_for_updates);
                     check_for_updates && current_version != version
                 };
@@ -55,9 +55,7 @@ impl Downloader {
                 }
             }
             None => Err(DownloaderError::FailedToDownloadLatestVersion),
-        };
-
-        return res;
+        }
     }
 
     pub async fn get_latest_version(&mut self, client: &reqwest::Client) -> Option<String> {
@@ -71,7 +69,7 @@ impl Downloader {
             "Invalid version format"
         );
 
-        return Some(final_segment.to_string());
+        Some(final_segment.to_string())
     }
 
     pub async fn download(
@@ -125,7 +123,7 @@ impl Downloader {
 
         temp_dir.close()?;
 
-        return Ok(());
+        Ok(())
     }
 }
 
@@ -1,9 +1,10 @@
 use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use specta::Type;
 
-use crate::sniffer::parser::packet::Packet;
+use crate::sniffer::messages::{GameMessage, ObjectItem};
 
 #[derive(Clone, Serialize, Deserialize, Debug, specta::Type)]
 pub struct ChatViewsConfig {
@@ -38,40 +39,36 @@ pub struct ChatEvent {
 }
 
 impl ChatEvent {
-    pub fn from_packet(packet: &Packet) -> Self {
-        ChatEvent {
-            channel: packet.data.get("channel").unwrap().as_u64().unwrap() as u8,
-            sender_name: packet
-                .data
-                .get("senderName")
-                .unwrap()
-                .as_str()
-                .unwrap()
-                .to_string(),
-            content: packet
-                .data
-                .get("content")
-                .unwrap()
-                .as_str()
-                .unwrap()
-                .to_string(),
-            timestamp: packet.data.get("timestamp").unwrap().as_u64().unwrap() as u32,
-            objects: packet.data.get("objects").map(|objects| {
-                objects
-                    .as_array()
-                    .unwrap()
-                    .iter()
-                    .map(|object| {
-                        object
-                            .as_object()
-                            .unwrap()
-                            .iter()
-                            .map(|(key, value)| (key.to_string(), value.to_string()))
-                            .collect()
-                    })
-                    .collect()
-            }),
-        }
+    /// `None` for the other messages
+    pub fn from_message(message: &GameMessage) -> Option<Self> {
+        let event = match message {
+            GameMessage::ChatServerMessage(message) => ChatEvent {
+                channel: message.channel as u8,
+                sender_name: message.sender_name.clone(),
+                content: message.content.clone(),
+                timestamp: message.timestamp as u32,
+                objects: None,
+            },
+            GameMessage::ChatServerWithObjectMessage(message) => ChatEvent {
+                channel: message.channel as u8,
+                sender_name: message.sender_name.clone(),
+                content: message.content.clone(),
+                timestamp: message.timestamp as u32,
+                objects: Some(message.objects.iter().map(object_fields).collect()),
+            },
+            _ => return None,
+        };
+        Some(event)
+    }
+}
+
+fn object_fields(object: &ObjectItem) -> HashMap<String, String> {
+    match serde_json::to_value(object) {
+        Ok(Value::Object(fields)) => fields
+            .into_iter()
+            .map(|(key, value)| (key, value.to_string()))
+            .collect(),
+        _ => HashMap::new(),
     }
 }
 
@@ -3,29 +3,48 @@ use std::{
     collections::HashMap,
     fs::{self, OpenOptions},
     path::PathBuf,
-    sync::Arc,
+    sync::{Arc, RwLock},
 };
 
 use crate::{
     features::chat::config::ChatEvent,
     node::Node,
-    sniffer::{parser::packet::Packet, protocol::protocol::KnownEvent},
+    sniffer::{
+        filter::EventFilter,
+        messages::GameMessage,
+        network::{PacketHandler, SubscriptionToken},
+        parser::{packet::Packet, selection::FieldSelection},
+        protocol::protocol::KnownEvent,
+    },
 };
 use tauri_plugin_notification::NotificationExt;
 use tauri_specta::Event;
+use tracing::warn;
 use uuid::Uuid;
 
 use super::config::ChatViewsConfig;
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct ChatFeature {
     node: Option<Arc<Node>>,
     dir_path: Option<PathBuf>,
     config: Option<Arc<crate::config::Manager<ChatViewsConfig>>>,
-    pub active_tab: Option<String>,
+    active_tab: Arc<RwLock<Option<String>>>,
+    subscriptions: Vec<SubscriptionToken>,
+}
+
+/// Handles the chat messages with its own handles on the state of the feature,
+/// so the feature is not locked for every message
+#[derive(Debug)]
+struct ChatListener {
+    dir_path: PathBuf,
+    config: Arc<crate::config::Manager<ChatViewsConfig>>,
+    active_tab: Arc<RwLock<Option<String>>>,
 }
 
 const LISTENER_ID: &str = "chat";
+/// Fields read by `ChatEvent::from_message`
+const CHAT_FIELDS: &[&str] = &["channel", "senderName", "content", "timestamp", "objects"];
 
 impl ChatFeature {
     pub fn new() -> Self {
@@ -33,7 +52,8 @@ impl ChatFeature {
             node: None,
             config: None,
             dir_path: None,
-            active_tab: None,
+            active_tab: Arc::new(RwLock::new(None)),
+            subscriptions: Vec::new(),
         }
     }
 
@@ -52,89 +72,32 @@ impl ChatFeature {
         self.init_subscription();
     }
 
-    fn append_history(&self, id: &str, event: &ChatEvent) {
-        let to_str = serde_json::to_string(event).unwrap();
-        let dir_path = self.dir_path.as_ref().unwrap().join("history");
-        let history_path = dir_path.join(format!("{}.jsonl", id));
-        fs::create_dir_all(&dir_path).unwrap();
-
-        let mut file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(history_path)
-            .unwrap();
-
-        writeln!(file, "{}", to_str).unwrap();
-    }
+    /// Replaces the previous subscriptions, they are removed when their tokens are dropped
+    fn init_subscription(&mut self) {
+        let node = self.node.clone().unwrap();
+        // Headless nodes (e.g. the cli) have no chat to show, the messages aren't decoded for it
+        if node.handle.is_none() {
+            self.subscriptions.clear();
+            return;
+        }
+        let listener = ChatListener {
+            dir_path: self.dir_path.clone().unwrap(),
+            config: self.config.clone().unwrap(),
+            active_tab: self.active_tab.clone(),
+        };
 
-    fn init_subscription(&self) {
-        let node = self.node.as_ref().unwrap();
         let mut packet_listner = node.packet_listener.lock().unwrap();
         let events = [
-            KnownEvent::ChatServerMessage.to_string(),
-            KnownEvent::ChatServerWithObjectMessage.to_string(),
+            KnownEvent::ChatServerMessage,
+            KnownEvent::ChatServerWithObjectMessage,
         ]
-        .iter()
-        .map(|name| {
-            let protocol = node.protocol.read().unwrap();
-            let id = protocol.get_protocol_id_by_class(name).unwrap();
-            *id
-        })
-        .collect::<Vec<_>>();
-
-        events.iter().for_each(|id| {
-            let is_subscribed = packet_listner.has_subscriptions_for(id, LISTENER_ID);
-            if is_subscribed {
-                return;
-            }
-
-            packet_listner.subscribe(*id, LISTENER_ID, move |packet, node| {
-                ChatFeature::listener(packet, node);
-            });
-        });
-    }
-
-    fn listener(packet: &Packet, node: &Node) {
-        let chat_feature = node.features.chat.read().unwrap();
-        let config = chat_feature.config.as_ref().unwrap().config.read().unwrap();
-
-        let handle = node.handle.as_ref().unwrap();
-
-        let chat_event = ChatEvent::from_packet(&packet);
-        let views = config
-            .views
-            .iter()
-            .filter(|(_, tab)| {
-                tab.filters
-                    .as_ref()
-                    .map_or(true, |filters| filters.evaluate(&chat_event))
-            })
-            .collect::<Vec<_>>();
-        let active_window = chat_feature.active_tab.clone();
-
-        for (id, tab) in views.iter() {
-            let is_active = active_window.as_ref().map_or(false, |active| active == *id);
-            let has_notification = tab.options.notify;
-            let is_persistent = tab.options.keep_history;
-
-            if is_persistent {
-                chat_feature.append_history(&id, &chat_event);
-            }
-
-            if has_notification {
-                handle
-                    .notification()
-                    .builder()
-                    .title(format!("{} in {}", "New chat message", tab.name))
-                    .show()
-                    .unwrap();
-            }
-
-            if is_active {
-                chat_event.clone().emit(handle).unwrap();
-                continue;
-            }
-        }
+        .map(|event| EventFilter::Name(event.to_string()));
+        self.subscriptions = packet_listner.subscribe_many(
+            events,
+            LISTENER_ID,
+            FieldSelection::from_paths(CHAT_FIELDS),
+            listener,
+        );
     }
 
     pub fn create_tab(&mut self, config: super::config::ChatTabConfig) -> String {
@@ -145,7 +108,7 @@ impl ChatFeature {
 
     pub fn set_active_tab(&mut self, tab_id: Option<String>) {
         if let Some(tab_id) = tab_id {
-            self.active_tab = Some(tab_id.clone());
+            *self.active_tab.write().unwrap() = Some(tab_id.clone());
             let config = self.config.as_ref().unwrap();
             config
                 .update_config_sync(|config| {
@@ -153,7 +116,7 @@ impl ChatFeature {
                 })
                 .unwrap();
         } else {
-            self.active_tab = None;
+            *self.active_tab.write().unwrap() = None;
         }
     }
 
@@ -193,3 +156,76 @@ impl ChatFeature {
             .unwrap();
     }
 }
+
+impl ChatListener {
+    fn append_history(&self, id: &str, event: &ChatEvent) {
+        let to_str = serde_json::to_string(event).unwrap();
+        let dir_path = self.dir_path.join("history");
+        let history_path = dir_path.join(format!("{}.jsonl", id));
+        fs::create_dir_all(&dir_path).unwrap();
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(history_path)
+            .unwrap();
+
+        writeln!(file, "{}", to_str).unwrap();
+    }
+}
+
+impl PacketHandler for ChatListener {
+    fn handle(&mut self, packet: &Packet, node: &Node) {
+        // Nothing to show without a UI
+        let Some(handle) = node.handle.as_ref() else {
+            return;
+        };
+
+        let chat_event = match GameMessage::from_packet(packet) {
+            Ok(message) => ChatEvent::from_message(&message),
+            Err(err) => {
+                warn!("Invalid chat message: {}", err);
+                None
+            }
+        };
+        let Some(chat_event) = chat_event else {
+            return;
+        };
+
+        let config = self.config.config.read().unwrap();
+        let views = config
+            .views
+            .iter()
+            .filter(|(_, tab)| {
+                tab.filters
+                    .as_ref()
+                    .map_or(true, |filters| filters.evaluate(&chat_event))
+            })
+            .collect::<Vec<_>>();
+        let active_window = self.active_tab.read().unwrap().clone();
+
+        for (id, tab) in views.iter() {
+            let is_active = active_window.as_ref().map_or(false, |active| active == *id);
+            let has_notification = tab.options.notify;
+            let is_persistent = tab.options.keep_history;
+
+            if is_persistent {
+                self.append_history(&id, &chat_event);
+            }
+
+            if has_notification {
+                handle
+                    .notification()
+                    .builder()
+                    .title(format!("{} in {}", "New chat message", tab.name))
+                    .show()
+                    .unwrap();
+            }
+
+            if is_active {
+                chat_event.clone().emit(handle).unwrap();
+                continue;
+            }
+        }
+    }
+}
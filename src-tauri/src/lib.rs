@@ -0,0 +1,6 @@
+pub mod config;
+pub mod constants;
+pub mod downloader;
+pub mod features;
+pub mod node;
+pub mod sniffer;
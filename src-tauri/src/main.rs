@@ -3,19 +3,15 @@
 
 use std::sync::Arc;
 
-use node::Node;
 use tauri::{Manager, WindowEvent};
 use tauri_specta::ts;
 use tracing::{debug, error, info};
 
-use crate::features::chat::config::ChatEvent;
-
-pub mod config;
-pub mod constants;
-pub mod downloader;
-pub mod features;
-pub mod node;
-pub mod sniffer;
+use dtools::features::chat::config::ChatEvent;
+use dtools::node::Node;
+use dtools::sniffer::network::{CaptureState, ReplaySpeed};
+use dtools::sniffer::stats::CaptureStats;
+use dtools::{config, features, node, sniffer};
 
 fn fix_specta(path: &str) {
     // replace all occurence of "plugin:tauri-specta" in the file
@@ -116,6 +112,105 @@ fn get_last_packet_timestamp(state: tauri::State<'_, Arc<Node>>) -> u128 {
     last_packet_time.clone()
 }
 
+#[tauri::command]
+#[specta::specta]
+fn get_capture_state(state: tauri::State<'_, Arc<Node>>) -> CaptureState {
+    let packet_listener = state.packet_listener.lock().unwrap();
+    packet_listener.get_state()
+}
+
+#[tauri::command]
+#[specta::specta]
+fn get_capture_stats(state: tauri::State<'_, Arc<Node>>) -> CaptureStats {
+    let packet_listener = state.packet_listener.lock().unwrap();
+    packet_listener.get_stats()
+}
+
+#[tauri::command(async)]
+#[specta::specta]
+fn start_capture(state: tauri::State<'_, Arc<Node>>) -> CaptureState {
+    let mut packet_listener = state.packet_listener.lock().unwrap();
+    // Errors are reported through the capture state
+    let _ = packet_listener.run();
+    packet_listener.get_state()
+}
+
+#[tauri::command]
+#[specta::specta]
+fn stop_capture(state: tauri::State<'_, Arc<Node>>) -> CaptureState {
+    let mut packet_listener = state.packet_listener.lock().unwrap();
+    packet_listener.stop();
+    packet_listener.get_state()
+}
+
+#[tauri::command(async)]
+#[specta::specta]
+fn restart_capture(state: tauri::State<'_, Arc<Node>>) -> CaptureState {
+    let mut packet_listener = state.packet_listener.lock().unwrap();
+    let _ = packet_listener.restart();
+    packet_listener.get_state()
+}
+
+#[tauri::command(async)]
+#[specta::specta]
+fn update_network_config(
+    state: tauri::State<'_, Arc<Node>>,
+    network: sniffer::config::NetworkConfig,
+) -> CaptureState {
+    if let Err(err) = state
+        .config
+        .update_config_sync(|config| config.network = network)
+    {
+        error!("Failed to save network config: {:?}", err);
+    }
+    let mut packet_listener = state.packet_listener.lock().unwrap();
+    let _ = packet_listener.restart();
+    packet_listener.get_state()
+}
+
+#[tauri::command]
+#[specta::specta]
+fn start_recording(state: tauri::State<'_, Arc<Node>>) -> bool {
+    let packet_listener = state.packet_listener.lock().unwrap();
+    if let Err(err) = packet_listener.start_recording() {
+        error!("Failed to start recording: {}", err);
+    }
+    packet_listener.is_recording()
+}
+
+#[tauri::command]
+#[specta::specta]
+fn stop_recording(state: tauri::State<'_, Arc<Node>>) -> bool {
+    let packet_listener = state.packet_listener.lock().unwrap();
+    packet_listener.stop_recording();
+    packet_listener.is_recording()
+}
+
+#[tauri::command]
+#[specta::specta]
+fn list_recordings(state: tauri::State<'_, Arc<Node>>) -> Vec<String> {
+    let dir = state.data_dir.join(sniffer::recorder::RECORDINGS_DIR);
+    sniffer::recorder::list_recordings(dir)
+        .unwrap_or_default()
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect()
+}
+
+#[tauri::command]
+#[specta::specta]
+fn replay_recording(
+    state: tauri::State<'_, Arc<Node>>,
+    path: String,
+    speed: ReplaySpeed,
+) -> CaptureState {
+    let mut packet_listener = state.packet_listener.lock().unwrap();
+    if let Err(err) = packet_listener.replay(&path, speed) {
+        error!("Failed to replay {}: {}", path, err);
+    }
+    packet_listener.get_state()
+}
+
 fn main() {
     let app = tauri::Builder::default();
 
@@ -124,7 +219,11 @@ fn main() {
     // TODO: use plugin when v2 is released
     let specta_plugin = {
         let specta_builder = ts::builder()
-            .events(tauri_specta::collect_events![ChatEvent])
+            .events(tauri_specta::collect_events![
+                ChatEvent,
+                CaptureState,
+                CaptureStats
+            ])
             .commands(tauri_specta::collect_commands![
                 app_ready,
                 create_chat_tab,
@@ -135,6 +234,16 @@ fn main() {
                 get_last_packet_timestamp,
                 set_active_chat_tab,
                 get_last_open_chat_tab,
+                get_capture_state,
+                get_capture_stats,
+                start_capture,
+                stop_capture,
+                restart_capture,
+                update_network_config,
+                start_recording,
+                stop_recording,
+                list_recordings,
+                replay_recording,
             ])
             .config(
                 specta::ts::ExportConfig::default()
@@ -198,6 +307,16 @@ fn main() {
             get_last_packet_timestamp,
             set_active_chat_tab,
             get_last_open_chat_tab,
+            get_capture_state,
+            get_capture_stats,
+            start_capture,
+            stop_capture,
+            restart_capture,
+            update_network_config,
+            start_recording,
+            stop_recording,
+            list_recordings,
+            replay_recording,
         ]);
 
     app.run(tauri::generate_context!())
@@ -1,5 +1,4 @@
 use std::{
-    collections::HashMap,
     fs,
     path::{Path, PathBuf},
     sync::{Arc, Mutex, RwLock},
@@ -33,9 +32,6 @@ pub struct Node {
     pub handle: Option<tauri::AppHandle>,
 
     pub features: Features,
-
-    /// Temporary store for data, often use in the packet listener
-    pub store: Arc<Mutex<HashMap<String, String>>>,
 }
 
 #[derive(Debug)]
@@ -62,7 +58,9 @@ impl Node {
 
         let http_client = reqwest::Client::new();
 
-        let protocol = protocol::protocol::ProtocolManager::new();
+        let protocol = protocol::protocol::ProtocolManager::with_config(
+            config.config.read().unwrap().protocol.clone(),
+        );
 
         let packet_listener = network::PacketListener::new();
         let downloader = downloader::Downloader::new();
@@ -80,14 +78,20 @@ impl Node {
             packet_listener: Arc::new(Mutex::new(packet_listener)),
             handle,
             features,
-            store: Arc::new(Mutex::new(HashMap::new())),
         });
 
         node.packet_listener.lock().unwrap().set_node(node.clone());
 
         if init {
             node.downloader.lock().unwrap().init(&node).await?;
-            node.packet_listener.lock().unwrap().run()?;
+        }
+
+        // Headless nodes (e.g. the cli) choose what to capture themselves
+        if init && node.handle.is_some() {
+            // The capture can be restarted from the UI, don't prevent the app from starting
+            if let Err(err) = node.packet_listener.lock().unwrap().run() {
+                error!("Failed to start packet listener: {}", err);
+            }
         }
 
         node.protocol.write().unwrap().init(data_dir_path)?;
@@ -101,7 +105,7 @@ impl Node {
 
         info!("Node initialized successfully");
 
-        return Ok(node);
+        Ok(node)
     }
 
     pub fn init_logger(data_dir: &Path) -> Result<WorkerGuard, FromEnvError> {
@@ -133,7 +137,8 @@ impl Node {
                 tracing_subscriber::fmt::layer()
                     .with_file(true)
                     .with_line_number(true)
-                    .with_writer(std::io::stdout)
+                    // stdout is left to the cli output
+                    .with_writer(std::io::stderr)
                     .with_filter(EnvFilter::from_default_env()),
             )
             .init();
@@ -150,7 +155,7 @@ impl Node {
             }
         }));
 
-        return Ok(guard);
+        Ok(guard)
     }
 }
 
@@ -1,16 +1,130 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+#[derive(Debug, Clone, Serialize, specta::Type)]
 pub struct NetworkConfig {
-    pub port: u16,
-    pub interface: String,
+    /// Game server ports
+    pub ports: Vec<PortRange>,
+    /// Capture interface, `None` to find the one carrying the game traffic
+    pub interface: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct PortRange {
+    pub start: u16,
+    pub end: u16,
+}
+
+impl PortRange {
+    pub fn single(port: u16) -> Self {
+        PortRange {
+            start: port,
+            end: port,
+        }
+    }
+
+    pub fn contains(&self, port: u16) -> bool {
+        self.start <= port && port <= self.end
+    }
+}
+
+impl NetworkConfig {
+    pub fn is_server_port(&self, port: u16) -> bool {
+        self.ports.iter().any(|range| range.contains(port))
+    }
+
+    /// BPF filter matching the game traffic
+    pub fn filter(&self) -> String {
+        let ports = self
+            .ports
+            .iter()
+            .map(|range| {
+                if range.start == range.end {
+                    format!("port {}", range.start)
+                } else {
+                    format!("portrange {}-{}", range.start, range.end)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if ports.is_empty() {
+            return "tcp".to_string();
+        }
+        format!("tcp and ({})", ports.join(" or "))
+    }
 }
 
 impl Default for NetworkConfig {
     fn default() -> Self {
         NetworkConfig {
-            port: 5555,
-            interface: "en0".to_string(),
+            ports: vec![PortRange::single(5555)],
+            interface: None,
         }
     }
 }
+
+/// Missing fields get their default, the single `port` of the older configs becomes a range
+impl<'de> Deserialize<'de> for NetworkConfig {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct RawNetworkConfig {
+            ports: Option<Vec<PortRange>>,
+            port: Option<u16>,
+            interface: Option<String>,
+        }
+
+        let raw = RawNetworkConfig::deserialize(deserializer)?;
+        let ports = match (raw.ports, raw.port) {
+            (Some(ports), _) => ports,
+            (None, Some(port)) => vec![PortRange::single(port)],
+            (None, None) => NetworkConfig::default().ports,
+        };
+        Ok(NetworkConfig {
+            ports,
+            interface: raw.interface,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter() {
+        let mut config = NetworkConfig::default();
+        assert_eq!(config.filter(), "tcp and (port 5555)");
+
+        config.ports.push(PortRange {
+            start: 6000,
+            end: 6010,
+        });
+        assert_eq!(
+            config.filter(),
+            "tcp and (port 5555 or portrange 6000-6010)"
+        );
+        assert!(config.is_server_port(6005));
+        assert!(!config.is_server_port(6011));
+    }
+
+    #[test]
+    fn test_deserialize_legacy() {
+        let config = r#"{"port":443,"interface":"en0"}"#;
+        let config: NetworkConfig = serde_json::from_str(config).unwrap();
+        assert_eq!(config.interface, Some("en0".to_string()));
+        assert_eq!(config.ports, vec![PortRange::single(443)]);
+
+        let config: NetworkConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(config.ports, vec![PortRange::single(5555)]);
+        assert_eq!(config.interface, None);
+
+        let config = r#"{"ports":[{"start":6000,"end":6010}],"port":443}"#;
+        let config: NetworkConfig = serde_json::from_str(config).unwrap();
+        assert_eq!(
+            config.ports,
+            vec![PortRange {
+                start: 6000,
+                end: 6010
+            }]
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use pcap::{Capture, Device};
+use tracing::{debug, info};
+
+use super::{network::PacketListenerError, parser::metadata::PacketHeader};
+
+/// How long each device is listened to before picking one
+const PROBE_DURATION: Duration = Duration::from_secs(3);
+/// Read timeout of the probe captures, so they can stop once the probe is over
+const PROBE_READ_TIMEOUT_MS: i32 = 100;
+
+/// Listen on every device that is up and return the one with the most game traffic,
+/// `None` when the game is not running
+pub fn find_game_device(filter: &str) -> Result<Option<String>, PacketListenerError> {
+    let devices = Device::list().map_err(PacketListenerError::FailedToListDevices)?;
+    debug!(
+        "Probing devices: {:?}",
+        devices
+            .iter()
+            .map(|device| &device.name)
+            .collect::<Vec<_>>()
+    );
+
+    let probes = devices
+        .into_iter()
+        .filter(|device| device.flags.is_up() && device.flags.is_running())
+        .map(|device| {
+            let filter = filter.to_string();
+            thread::spawn(move || {
+                let name = device.name.clone();
+                let count = probe_device(device, &filter);
+                (name, count)
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let best = probes
+        .into_iter()
+        .filter_map(|probe| probe.join().ok())
+        .inspect(|(name, count)| debug!("Device {} saw {} game packets", name, count))
+        .filter(|(_, count)| *count > 0)
+        // "any" sees the same packets as the real device, prefer the real one
+        .max_by_key(|(name, count)| (*count, name != "any"));
+
+    if let Some((name, _)) = best {
+        info!("Found game traffic on device: {}", name);
+        return Ok(Some(name));
+    }
+    Ok(None)
+}
+
+pub fn default_device() -> Result<String, PacketListenerError> {
+    match Device::lookup().map_err(PacketListenerError::FailedToListDevices)? {
+        Some(device) => Ok(device.name),
+        None => Err(PacketListenerError::NoDeviceFound),
+    }
+}
+
+/// Whether pcap knows a device with this name
+pub fn device_exists(name: &str) -> bool {
+    Device::list().is_ok_and(|devices| devices.iter().any(|device| device.name == name))
+}
+
+fn probe_device(device: Device, filter: &str) -> usize {
+    let name = device.name.clone();
+    let cap = Capture::from_device(device).and_then(|cap| {
+        cap.immediate_mode(true)
+            .timeout(PROBE_READ_TIMEOUT_MS)
+            .open()
+    });
+    let mut cap = match cap {
+        Ok(cap) => cap,
+        Err(err) => {
+            debug!("Can't probe device {}: {}", name, err);
+            return 0;
+        }
+    };
+    if let Err(err) = cap.filter(filter, true) {
+        debug!("Can't set filter on device {}: {}", name, err);
+        return 0;
+    }
+
+    let link_type = cap.get_datalink();
+    let deadline = Instant::now() + PROBE_DURATION;
+    let mut count = 0;
+    while Instant::now() < deadline {
+        match cap.next_packet() {
+            Ok(packet) => {
+                if PacketHeader::from_link(packet.data, link_type).is_ok() {
+                    count += 1;
+                }
+            }
+            Err(pcap::Error::TimeoutExpired) => continue,
+            Err(_) => break,
+        }
+    }
+    count
+}
@@ -0,0 +1,119 @@
+use super::protocol::protocol::{EventId, EventName, ProtocolManager};
+
+/// Messages a listener subscribes to. Names are matched against the protocol loaded when the
+/// message is received, so they keep working when a new protocol changes the ids
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EventFilter {
+    Id(EventId),
+    /// Class name, e.g. `ChatServerMessage`
+    Name(EventName),
+    /// Class names with `*` wildcards, e.g. `Exchange*`
+    Glob(String),
+    /// The class and every class inheriting from it
+    Subclass(EventName),
+    All,
+}
+
+impl EventFilter {
+    /// `*` alone matches every message, other patterns with a `*` are globs
+    pub fn from_pattern(pattern: &str) -> Self {
+        match pattern {
+            "*" => EventFilter::All,
+            _ if pattern.contains('*') => EventFilter::Glob(pattern.to_string()),
+            _ => EventFilter::Name(pattern.to_string()),
+        }
+    }
+
+    pub fn matches(&self, id: EventId, protocol: &ProtocolManager) -> bool {
+        let Some(schema) = protocol.get_protocol(&id) else {
+            // Only ids can match messages missing from the protocol
+            return *self == EventFilter::Id(id);
+        };
+        match self {
+            EventFilter::Id(expected) => *expected == id,
+            EventFilter::Name(name) => schema.name == *name,
+            EventFilter::Glob(pattern) => glob_match(pattern, &schema.name),
+            EventFilter::Subclass(class) => protocol.is_subclass_of(schema, class),
+            EventFilter::All => true,
+        }
+    }
+}
+
+impl From<EventId> for EventFilter {
+    fn from(id: EventId) -> Self {
+        EventFilter::Id(id)
+    }
+}
+
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    // There is always a first part, empty when the pattern starts with `*`
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = name.strip_prefix(first) else {
+        return false;
+    };
+
+    let mut parts = parts.collect::<Vec<_>>();
+    // Without `*` the name must be the pattern
+    let Some(last) = parts.pop() else {
+        return rest.is_empty();
+    };
+    for part in parts {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("Exchange*", "ExchangeAcceptMessage"));
+        assert!(glob_match("*Message", "ExchangeAcceptMessage"));
+        assert!(glob_match("Exchange*Accept*", "ExchangeAcceptMessage"));
+        assert!(glob_match("*", "ExchangeAcceptMessage"));
+        assert!(glob_match("ChatServerMessage", "ChatServerMessage"));
+        assert!(!glob_match("ChatServer", "ChatServerMessage"));
+        assert!(!glob_match("Exchange*", "GameExchangeMessage"));
+        assert!(!glob_match("*Messages", "ExchangeAcceptMessage"));
+        // The parts can't overlap
+        assert!(!glob_match("Ab*ba", "Aba"));
+    }
+
+    #[test]
+    fn test_filters() {
+        let mut protocol = ProtocolManager::new();
+        protocol.init("tests/fixtures/").unwrap();
+        let id = |name: &str| {
+            *protocol
+                .get_protocol_id_by_class(&name.to_string())
+                .unwrap()
+        };
+        let chat = id("ChatServerMessage");
+        let action = id("GameActionFightDeathMessage");
+
+        assert!(EventFilter::Id(chat).matches(chat, &protocol));
+        assert!(EventFilter::from_pattern("ChatServerMessage").matches(chat, &protocol));
+        assert!(!EventFilter::from_pattern("ChatServerMessage").matches(action, &protocol));
+        assert!(EventFilter::from_pattern("Chat*").matches(chat, &protocol));
+        assert!(EventFilter::from_pattern("*").matches(action, &protocol));
+
+        let abstract_action = EventFilter::Subclass("AbstractGameActionMessage".to_string());
+        assert!(abstract_action.matches(action, &protocol));
+        assert!(abstract_action.matches(id("AbstractGameActionMessage"), &protocol));
+        assert!(!abstract_action.matches(chat, &protocol));
+        let actions = protocol
+            .ids()
+            .filter(|id| abstract_action.matches(**id, &protocol))
+            .count();
+        assert!(actions > 30);
+
+        assert!(EventFilter::Id(u16::MAX).matches(u16::MAX, &protocol));
+        assert!(!EventFilter::All.matches(u16::MAX, &protocol));
+    }
+}
@@ -0,0 +1,20156 @@
+// @generated by `cargo run --bin dtools-codegen`, do not edit
+#![allow(clippy::large_enum_variant)]
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::{decode, float, floats, MessageError};
+
+/// `AbstractCharacterInformation`, id 4664
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AbstractCharacterInformation {
+    pub id: u64,
+}
+
+/// `AbstractContactInformations`, id 4780
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AbstractContactInformations {
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+    #[serde(rename = "accountTag")]
+    pub account_tag: AccountTagInformation,
+}
+
+/// `AbstractFightDispellableEffect`, id 5248
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AbstractFightDispellableEffect {
+    pub uid: u32,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "turnDuration")]
+    pub turn_duration: i16,
+    pub dispelable: i8,
+    #[serde(rename = "spellId")]
+    pub spell_id: u16,
+    #[serde(rename = "effectId")]
+    pub effect_id: u32,
+    #[serde(rename = "parentBoostUid")]
+    pub parent_boost_uid: u32,
+}
+
+/// `AbstractFightTeamInformations`, id 4798
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AbstractFightTeamInformations {
+    #[serde(rename = "teamId")]
+    pub team_id: i8,
+    #[serde(rename = "leaderId", deserialize_with = "float")]
+    pub leader_id: f64,
+    #[serde(rename = "teamSide")]
+    pub team_side: i8,
+    #[serde(rename = "teamTypeId")]
+    pub team_type_id: i8,
+    #[serde(rename = "nbWaves")]
+    pub nb_waves: i8,
+}
+
+/// `AbstractGameActionFightTargetedAbilityMessage`, id 9632
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AbstractGameActionFightTargetedAbilityMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "silentCast")]
+    pub silent_cast: bool,
+    #[serde(rename = "verboseCast")]
+    pub verbose_cast: bool,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "destinationCellId")]
+    pub destination_cell_id: i16,
+    pub critical: i8,
+}
+
+/// `AbstractGameActionMessage`, id 14
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AbstractGameActionMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+}
+
+/// `AbstractGameActionWithAckMessage`, id 2943
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AbstractGameActionWithAckMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "waitAckId")]
+    pub wait_ack_id: i16,
+}
+
+/// `AbstractPartyEventMessage`, id 9511
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AbstractPartyEventMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+}
+
+/// `AbstractPartyMemberInFightMessage`, id 392
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AbstractPartyMemberInFightMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    pub reason: i8,
+    #[serde(rename = "memberId")]
+    pub member_id: u64,
+    #[serde(rename = "memberAccountId")]
+    pub member_account_id: i32,
+    #[serde(rename = "memberName")]
+    pub member_name: String,
+    #[serde(rename = "fightId")]
+    pub fight_id: u16,
+    #[serde(rename = "timeBeforeFightStart")]
+    pub time_before_fight_start: u16,
+}
+
+/// `AbstractPartyMessage`, id 1319
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AbstractPartyMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+}
+
+/// `AbstractPlayerSearchInformation`, id 7835
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AbstractPlayerSearchInformation {}
+
+/// `AbstractSocialGroupInfos`, id 7935
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AbstractSocialGroupInfos {}
+
+/// `AccessoryPreviewErrorMessage`, id 9070
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccessoryPreviewErrorMessage {
+    pub error: i8,
+}
+
+/// `AccessoryPreviewMessage`, id 9769
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccessoryPreviewMessage {
+    pub look: EntityLook,
+}
+
+/// `AccessoryPreviewRequestMessage`, id 1230
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccessoryPreviewRequestMessage {
+    #[serde(rename = "genericId")]
+    pub generic_id: Vec<u32>,
+}
+
+/// `AccountCapabilitiesMessage`, id 7022
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccountCapabilitiesMessage {
+    #[serde(rename = "tutorialAvailable")]
+    pub tutorial_available: bool,
+    #[serde(rename = "canCreateNewCharacter")]
+    pub can_create_new_character: bool,
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+    pub status: i8,
+}
+
+/// `AccountHouseInformations`, id 8107
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccountHouseInformations {
+    #[serde(rename = "houseId")]
+    pub house_id: u32,
+    #[serde(rename = "modelId")]
+    pub model_id: u16,
+    #[serde(rename = "houseInfos")]
+    pub house_infos: Box<HouseInstanceInformationsKind>,
+    #[serde(rename = "worldX")]
+    pub world_x: i16,
+    #[serde(rename = "worldY")]
+    pub world_y: i16,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+}
+
+/// `AccountHouseMessage`, id 6567
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccountHouseMessage {
+    pub houses: Vec<AccountHouseInformations>,
+}
+
+/// `AccountInformationsUpdateMessage`, id 2997
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccountInformationsUpdateMessage {
+    #[serde(rename = "subscriptionEndDate", deserialize_with = "float")]
+    pub subscription_end_date: f64,
+}
+
+/// `AccountLinkRequiredMessage`, id 9543
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AccountLinkRequiredMessage {}
+
+/// `AccountLoggingKickedMessage`, id 4552
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccountLoggingKickedMessage {
+    pub days: u16,
+    pub hours: i8,
+    pub minutes: i8,
+}
+
+/// `AccountSubscriptionElapsedDurationMessage`, id 2680
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccountSubscriptionElapsedDurationMessage {
+    #[serde(rename = "subscriptionElapsedDuration", deserialize_with = "float")]
+    pub subscription_elapsed_duration: f64,
+}
+
+/// `AccountTagInformation`, id 9531
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccountTagInformation {
+    pub nickname: String,
+    #[serde(rename = "tagNumber")]
+    pub tag_number: String,
+}
+
+/// `Achievement`, id 3667
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Achievement {
+    pub id: u16,
+    #[serde(rename = "finishedObjective")]
+    pub finished_objective: Vec<AchievementObjective>,
+    #[serde(rename = "startedObjectives")]
+    pub started_objectives: Vec<AchievementStartedObjective>,
+}
+
+/// `AchievementAchieved`, id 6128
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AchievementAchieved {
+    pub id: u16,
+    #[serde(rename = "achievedBy")]
+    pub achieved_by: u64,
+    #[serde(rename = "achievedPioneerRank")]
+    pub achieved_pioneer_rank: u32,
+}
+
+/// `AchievementAchievedRewardable`, id 6359
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AchievementAchievedRewardable {
+    pub id: u16,
+    #[serde(rename = "achievedBy")]
+    pub achieved_by: u64,
+    #[serde(rename = "achievedPioneerRank")]
+    pub achieved_pioneer_rank: u32,
+    #[serde(rename = "finishedLevel")]
+    pub finished_level: u16,
+}
+
+/// `AchievementAlmostFinishedDetailedListMessage`, id 6796
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AchievementAlmostFinishedDetailedListMessage {
+    #[serde(rename = "almostFinishedAchievements")]
+    pub almost_finished_achievements: Vec<Achievement>,
+}
+
+/// `AchievementAlmostFinishedDetailedListRequestMessage`, id 605
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AchievementAlmostFinishedDetailedListRequestMessage {}
+
+/// `AchievementDetailedListMessage`, id 9668
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AchievementDetailedListMessage {
+    #[serde(rename = "startedAchievements")]
+    pub started_achievements: Vec<Achievement>,
+    #[serde(rename = "finishedAchievements")]
+    pub finished_achievements: Vec<Achievement>,
+}
+
+/// `AchievementDetailedListRequestMessage`, id 4300
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AchievementDetailedListRequestMessage {
+    #[serde(rename = "categoryId")]
+    pub category_id: u16,
+}
+
+/// `AchievementDetailsMessage`, id 8282
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AchievementDetailsMessage {
+    pub achievement: Achievement,
+}
+
+/// `AchievementDetailsRequestMessage`, id 5489
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AchievementDetailsRequestMessage {
+    #[serde(rename = "achievementId")]
+    pub achievement_id: u16,
+}
+
+/// `AchievementFinishedInformationMessage`, id 6691
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AchievementFinishedInformationMessage {
+    pub achievement: AchievementAchievedRewardable,
+    pub name: String,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+}
+
+/// `AchievementFinishedMessage`, id 4765
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AchievementFinishedMessage {
+    pub achievement: AchievementAchievedRewardable,
+}
+
+/// `AchievementListMessage`, id 3789
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AchievementListMessage {
+    #[serde(rename = "finishedAchievements")]
+    pub finished_achievements: Vec<AchievementAchievedKind>,
+}
+
+/// `AchievementObjective`, id 2521
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AchievementObjective {
+    pub id: u32,
+    #[serde(rename = "maxValue")]
+    pub max_value: u64,
+}
+
+/// `AchievementPioneerRank`, id 8013
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AchievementPioneerRank {
+    #[serde(rename = "achievementId")]
+    pub achievement_id: u32,
+    #[serde(rename = "pioneerRank")]
+    pub pioneer_rank: u32,
+}
+
+/// `AchievementRewardErrorMessage`, id 3288
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AchievementRewardErrorMessage {
+    #[serde(rename = "achievementId")]
+    pub achievement_id: i16,
+}
+
+/// `AchievementRewardRequestMessage`, id 1409
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AchievementRewardRequestMessage {
+    #[serde(rename = "achievementId")]
+    pub achievement_id: i16,
+}
+
+/// `AchievementRewardSuccessMessage`, id 2996
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AchievementRewardSuccessMessage {
+    #[serde(rename = "achievementId")]
+    pub achievement_id: i16,
+}
+
+/// `AchievementStartedObjective`, id 6683
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AchievementStartedObjective {
+    pub id: u32,
+    #[serde(rename = "maxValue")]
+    pub max_value: u64,
+    pub value: u64,
+}
+
+/// `AchievementsPioneerRanksMessage`, id 7311
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AchievementsPioneerRanksMessage {
+    #[serde(rename = "achievementsPioneerRanks")]
+    pub achievements_pioneer_ranks: Vec<AchievementPioneerRank>,
+}
+
+/// `AchievementsPioneerRanksRequestMessage`, id 4661
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AchievementsPioneerRanksRequestMessage {}
+
+/// `AcquaintanceAddedMessage`, id 1512
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AcquaintanceAddedMessage {
+    #[serde(rename = "acquaintanceAdded")]
+    pub acquaintance_added: Box<AcquaintanceInformationKind>,
+}
+
+/// `AcquaintanceInformation`, id 5239
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AcquaintanceInformation {
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+    #[serde(rename = "accountTag")]
+    pub account_tag: AccountTagInformation,
+    #[serde(rename = "playerState")]
+    pub player_state: i8,
+}
+
+/// `AcquaintanceOnlineInformation`, id 6565
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AcquaintanceOnlineInformation {
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+    #[serde(rename = "accountTag")]
+    pub account_tag: AccountTagInformation,
+    #[serde(rename = "playerState")]
+    pub player_state: i8,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+    #[serde(rename = "playerName")]
+    pub player_name: String,
+    #[serde(rename = "moodSmileyId")]
+    pub mood_smiley_id: u16,
+    pub status: Box<PlayerStatusKind>,
+}
+
+/// `AcquaintanceSearchErrorMessage`, id 4272
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AcquaintanceSearchErrorMessage {
+    pub reason: i8,
+}
+
+/// `AcquaintanceSearchMessage`, id 3695
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AcquaintanceSearchMessage {
+    pub tag: AccountTagInformation,
+}
+
+/// `AcquaintanceServerListMessage`, id 9101
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AcquaintanceServerListMessage {
+    pub servers: Vec<u16>,
+}
+
+/// `AcquaintancesGetListMessage`, id 3337
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AcquaintancesGetListMessage {}
+
+/// `AcquaintancesListMessage`, id 1541
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AcquaintancesListMessage {
+    #[serde(rename = "acquaintanceList")]
+    pub acquaintance_list: Vec<AcquaintanceInformationKind>,
+}
+
+/// `ActivityHideRequestMessage`, id 8594
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ActivityHideRequestMessage {
+    #[serde(rename = "activityId")]
+    pub activity_id: u16,
+}
+
+/// `ActivityLockRequestMessage`, id 2188
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ActivityLockRequestMessage {
+    #[serde(rename = "activityId")]
+    pub activity_id: u16,
+    pub lock: bool,
+}
+
+/// `ActivitySuggestionsMessage`, id 6835
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ActivitySuggestionsMessage {
+    #[serde(rename = "lockedActivitiesIds")]
+    pub locked_activities_ids: Vec<u16>,
+    #[serde(rename = "unlockedActivitiesIds")]
+    pub unlocked_activities_ids: Vec<u16>,
+}
+
+/// `ActivitySuggestionsRequestMessage`, id 3114
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ActivitySuggestionsRequestMessage {
+    #[serde(rename = "minLevel")]
+    pub min_level: u16,
+    #[serde(rename = "maxLevel")]
+    pub max_level: u16,
+    #[serde(rename = "areaId")]
+    pub area_id: u16,
+    #[serde(rename = "activityCategoryId")]
+    pub activity_category_id: u16,
+    #[serde(rename = "nbCards")]
+    pub nb_cards: i16,
+}
+
+/// `ActorAlignmentInformations`, id 7770
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ActorAlignmentInformations {
+    #[serde(rename = "alignmentSide")]
+    pub alignment_side: i8,
+    #[serde(rename = "alignmentValue")]
+    pub alignment_value: i8,
+    #[serde(rename = "alignmentGrade")]
+    pub alignment_grade: i8,
+    #[serde(rename = "characterPower", deserialize_with = "float")]
+    pub character_power: f64,
+}
+
+/// `ActorExtendedAlignmentInformations`, id 83
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ActorExtendedAlignmentInformations {
+    #[serde(rename = "alignmentSide")]
+    pub alignment_side: i8,
+    #[serde(rename = "alignmentValue")]
+    pub alignment_value: i8,
+    #[serde(rename = "alignmentGrade")]
+    pub alignment_grade: i8,
+    #[serde(rename = "characterPower", deserialize_with = "float")]
+    pub character_power: f64,
+    pub honor: u16,
+    #[serde(rename = "honorGradeFloor")]
+    pub honor_grade_floor: u16,
+    #[serde(rename = "honorNextGradeFloor")]
+    pub honor_next_grade_floor: u16,
+    pub aggressable: i8,
+}
+
+/// `ActorOrientation`, id 8326
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ActorOrientation {
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+    pub direction: i8,
+}
+
+/// `ActorRestrictionsInformations`, id 2944
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ActorRestrictionsInformations {
+    #[serde(rename = "cantBeAggressed")]
+    pub cant_be_aggressed: bool,
+    #[serde(rename = "cantBeChallenged")]
+    pub cant_be_challenged: bool,
+    #[serde(rename = "cantTrade")]
+    pub cant_trade: bool,
+    #[serde(rename = "cantBeAttackedByMutant")]
+    pub cant_be_attacked_by_mutant: bool,
+    #[serde(rename = "cantRun")]
+    pub cant_run: bool,
+    #[serde(rename = "forceSlowWalk")]
+    pub force_slow_walk: bool,
+    #[serde(rename = "cantMinimize")]
+    pub cant_minimize: bool,
+    #[serde(rename = "cantMove")]
+    pub cant_move: bool,
+    #[serde(rename = "cantAggress")]
+    pub cant_aggress: bool,
+    #[serde(rename = "cantChallenge")]
+    pub cant_challenge: bool,
+    #[serde(rename = "cantExchange")]
+    pub cant_exchange: bool,
+    #[serde(rename = "cantAttack")]
+    pub cant_attack: bool,
+    #[serde(rename = "cantChat")]
+    pub cant_chat: bool,
+    #[serde(rename = "cantUseObject")]
+    pub cant_use_object: bool,
+    #[serde(rename = "cantUseTaxCollector")]
+    pub cant_use_tax_collector: bool,
+    #[serde(rename = "cantUseInteractive")]
+    pub cant_use_interactive: bool,
+    #[serde(rename = "cantSpeakToNPC")]
+    pub cant_speak_to_npc: bool,
+    #[serde(rename = "cantChangeZone")]
+    pub cant_change_zone: bool,
+    #[serde(rename = "cantAttackMonster")]
+    pub cant_attack_monster: bool,
+}
+
+/// `AddListenerOnSynchronizedStorageMessage`, id 9782
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AddListenerOnSynchronizedStorageMessage {
+    pub player: String,
+}
+
+/// `AddTaxCollectorOrderedSpellMessage`, id 3303
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AddTaxCollectorOrderedSpellMessage {
+    #[serde(rename = "taxCollectorId", deserialize_with = "float")]
+    pub tax_collector_id: f64,
+    pub spell: TaxCollectorOrderedSpell,
+}
+
+/// `AddTaxCollectorPresetSpellMessage`, id 3362
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AddTaxCollectorPresetSpellMessage {
+    #[serde(rename = "presetId")]
+    pub preset_id: Uuid,
+    pub spell: TaxCollectorOrderedSpell,
+}
+
+/// `AdditionalTaxCollectorInformation`, id 21
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AdditionalTaxCollectorInformation {
+    #[serde(rename = "collectorCallerId")]
+    pub collector_caller_id: u64,
+    #[serde(rename = "collectorCallerName")]
+    pub collector_caller_name: String,
+    pub date: i32,
+}
+
+/// `AdminCommandMessage`, id 8164
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AdminCommandMessage {
+    #[serde(rename = "messageUuid")]
+    pub message_uuid: Uuid,
+    pub content: String,
+}
+
+/// `AdminQuietCommandMessage`, id 1038
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AdminQuietCommandMessage {
+    #[serde(rename = "messageUuid")]
+    pub message_uuid: Uuid,
+    pub content: String,
+}
+
+/// `AgressableStatusMessage`, id 5616
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AgressableStatusMessage {
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+    pub enable: i8,
+    #[serde(rename = "roleAvAId")]
+    pub role_av_a_id: i32,
+    #[serde(rename = "pictoScore")]
+    pub picto_score: i32,
+}
+
+/// `AlignmentRankUpdateMessage`, id 1223
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlignmentRankUpdateMessage {
+    #[serde(rename = "alignmentRank")]
+    pub alignment_rank: i8,
+    pub verbose: bool,
+}
+
+/// `AlignmentWarEffortDonatePreviewMessage`, id 8775
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlignmentWarEffortDonatePreviewMessage {
+    #[serde(deserialize_with = "float")]
+    pub xp: f64,
+}
+
+/// `AlignmentWarEffortDonateRequestMessage`, id 7495
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlignmentWarEffortDonateRequestMessage {
+    pub donation: u64,
+}
+
+/// `AlignmentWarEffortDonationResultMessage`, id 108
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlignmentWarEffortDonationResultMessage {
+    pub result: i8,
+}
+
+/// `AlignmentWarEffortInformation`, id 1570
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlignmentWarEffortInformation {
+    #[serde(rename = "alignmentSide")]
+    pub alignment_side: i8,
+    #[serde(rename = "alignmentWarEffort")]
+    pub alignment_war_effort: u64,
+}
+
+/// `AlignmentWarEffortProgressionMessage`, id 7078
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlignmentWarEffortProgressionMessage {
+    #[serde(rename = "effortProgressions")]
+    pub effort_progressions: Vec<AlignmentWarEffortInformation>,
+}
+
+/// `AlignmentWarEffortProgressionRequestMessage`, id 2857
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AlignmentWarEffortProgressionRequestMessage {}
+
+/// `AllianceAllRanksUpdateRequestMessage`, id 8075
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceAllRanksUpdateRequestMessage {
+    pub ranks: Vec<RankInformation>,
+}
+
+/// `AllianceApplicationAnswerMessage`, id 5108
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceApplicationAnswerMessage {
+    pub accepted: bool,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+}
+
+/// `AllianceApplicationDeletedMessage`, id 9346
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceApplicationDeletedMessage {
+    pub deleted: bool,
+}
+
+/// `AllianceApplicationIsAnsweredMessage`, id 7034
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceApplicationIsAnsweredMessage {
+    pub accepted: bool,
+    #[serde(rename = "allianceInformation")]
+    pub alliance_information: AllianceInformation,
+}
+
+/// `AllianceApplicationListenMessage`, id 7179
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceApplicationListenMessage {
+    pub listen: bool,
+}
+
+/// `AllianceApplicationPresenceMessage`, id 8696
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceApplicationPresenceMessage {
+    #[serde(rename = "isApplication")]
+    pub is_application: bool,
+}
+
+/// `AllianceApplicationReceivedMessage`, id 9953
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceApplicationReceivedMessage {
+    #[serde(rename = "playerName")]
+    pub player_name: String,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+}
+
+/// `AllianceBulletinMessage`, id 3779
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceBulletinMessage {
+    pub content: String,
+    pub timestamp: i32,
+    #[serde(rename = "memberId")]
+    pub member_id: u64,
+    #[serde(rename = "memberName")]
+    pub member_name: String,
+}
+
+/// `AllianceBulletinSetErrorMessage`, id 3948
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceBulletinSetErrorMessage {
+    pub reason: i8,
+}
+
+/// `AllianceBulletinSetRequestMessage`, id 3198
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceBulletinSetRequestMessage {
+    pub content: String,
+}
+
+/// `AllianceChangeMemberRankMessage`, id 2565
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceChangeMemberRankMessage {
+    #[serde(rename = "memberId")]
+    pub member_id: u64,
+    #[serde(rename = "rankId")]
+    pub rank_id: u32,
+}
+
+/// `AllianceCreationResultMessage`, id 6160
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceCreationResultMessage {
+    pub result: i8,
+}
+
+/// `AllianceCreationStartedMessage`, id 1215
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AllianceCreationStartedMessage {}
+
+/// `AllianceCreationValidMessage`, id 909
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceCreationValidMessage {
+    #[serde(rename = "allianceName")]
+    pub alliance_name: String,
+    #[serde(rename = "allianceTag")]
+    pub alliance_tag: String,
+    #[serde(rename = "allianceEmblem")]
+    pub alliance_emblem: SocialEmblem,
+}
+
+/// `AllianceDeleteApplicationRequestMessage`, id 612
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AllianceDeleteApplicationRequestMessage {}
+
+/// `AllianceFactSheetInformation`, id 5455
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceFactSheetInformation {
+    #[serde(rename = "allianceId")]
+    pub alliance_id: u32,
+    #[serde(rename = "allianceTag")]
+    pub alliance_tag: String,
+    #[serde(rename = "allianceName")]
+    pub alliance_name: String,
+    #[serde(rename = "allianceEmblem")]
+    pub alliance_emblem: SocialEmblem,
+    #[serde(rename = "creationDate")]
+    pub creation_date: i32,
+    #[serde(rename = "nbMembers")]
+    pub nb_members: u16,
+    #[serde(rename = "nbSubarea")]
+    pub nb_subarea: u16,
+    #[serde(rename = "nbTaxCollectors")]
+    pub nb_tax_collectors: u16,
+    pub recruitment: AllianceRecruitmentInformation,
+}
+
+/// `AllianceFactsErrorMessage`, id 2325
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceFactsErrorMessage {
+    #[serde(rename = "allianceId")]
+    pub alliance_id: u32,
+}
+
+/// `AllianceFactsMessage`, id 2089
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceFactsMessage {
+    pub infos: Box<AllianceFactSheetInformationKind>,
+    pub members: Vec<CharacterMinimalSocialPublicInformations>,
+    #[serde(rename = "controlledSubareaIds")]
+    pub controlled_subarea_ids: Vec<u16>,
+    #[serde(rename = "leaderCharacterId")]
+    pub leader_character_id: u64,
+    #[serde(rename = "leaderCharacterName")]
+    pub leader_character_name: String,
+}
+
+/// `AllianceFactsRequestMessage`, id 1534
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceFactsRequestMessage {
+    #[serde(rename = "allianceId")]
+    pub alliance_id: u32,
+}
+
+/// `AllianceFightFighterAddedMessage`, id 8723
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceFightFighterAddedMessage {
+    #[serde(rename = "allianceFightInfo")]
+    pub alliance_fight_info: SocialFightInfo,
+    pub fighter: CharacterMinimalPlusLookInformations,
+    pub team: i8,
+}
+
+/// `AllianceFightFighterRemovedMessage`, id 4881
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceFightFighterRemovedMessage {
+    #[serde(rename = "allianceFightInfo")]
+    pub alliance_fight_info: SocialFightInfo,
+    #[serde(rename = "fighterId")]
+    pub fighter_id: u64,
+}
+
+/// `AllianceFightFinishedMessage`, id 2063
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceFightFinishedMessage {
+    #[serde(rename = "allianceFightInfo")]
+    pub alliance_fight_info: SocialFightInfo,
+}
+
+/// `AllianceFightInfoMessage`, id 465
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceFightInfoMessage {
+    #[serde(rename = "allianceFights")]
+    pub alliance_fights: Vec<SocialFight>,
+}
+
+/// `AllianceFightPhaseUpdateMessage`, id 3190
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceFightPhaseUpdateMessage {
+    #[serde(rename = "allianceFightInfo")]
+    pub alliance_fight_info: SocialFightInfo,
+    #[serde(rename = "newPhase")]
+    pub new_phase: FightPhase,
+}
+
+/// `AllianceFightStartedMessage`, id 6842
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceFightStartedMessage {
+    #[serde(rename = "allianceFightInfo")]
+    pub alliance_fight_info: SocialFightInfo,
+    pub phase: FightPhase,
+}
+
+/// `AllianceGetPlayerApplicationMessage`, id 1020
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AllianceGetPlayerApplicationMessage {}
+
+/// `AllianceGetRecruitmentInformationMessage`, id 3102
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AllianceGetRecruitmentInformationMessage {}
+
+/// `AllianceInformation`, id 9229
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceInformation {
+    #[serde(rename = "allianceId")]
+    pub alliance_id: u32,
+    #[serde(rename = "allianceTag")]
+    pub alliance_tag: String,
+    #[serde(rename = "allianceName")]
+    pub alliance_name: String,
+    #[serde(rename = "allianceEmblem")]
+    pub alliance_emblem: SocialEmblem,
+}
+
+/// `AllianceInsiderInfoMessage`, id 3419
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceInsiderInfoMessage {
+    #[serde(rename = "allianceInfos")]
+    pub alliance_infos: AllianceFactSheetInformation,
+    pub members: Vec<AllianceMemberInfo>,
+    pub prisms: Vec<PrismGeolocalizedInformationKind>,
+    #[serde(rename = "taxCollectors")]
+    pub tax_collectors: Vec<TaxCollectorInformations>,
+}
+
+/// `AllianceInsiderInfoRequestMessage`, id 4022
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AllianceInsiderInfoRequestMessage {}
+
+/// `AllianceInsiderPrismInformation`, id 6387
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceInsiderPrismInformation {
+    pub state: i8,
+    #[serde(rename = "placementDate")]
+    pub placement_date: i32,
+    #[serde(rename = "nuggetsCount")]
+    pub nuggets_count: u32,
+    pub durability: i32,
+    #[serde(rename = "nextEvolutionDate", deserialize_with = "float")]
+    pub next_evolution_date: f64,
+    #[serde(rename = "moduleObject")]
+    pub module_object: ObjectItem,
+    #[serde(rename = "moduleType")]
+    pub module_type: i32,
+    #[serde(rename = "cristalObject")]
+    pub cristal_object: ObjectItem,
+    #[serde(rename = "cristalType")]
+    pub cristal_type: i32,
+    #[serde(rename = "cristalNumberLeft")]
+    pub cristal_number_left: i32,
+}
+
+/// `AllianceInvitationAnswerMessage`, id 3368
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceInvitationAnswerMessage {
+    pub accept: bool,
+}
+
+/// `AllianceInvitationMessage`, id 7115
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceInvitationMessage {
+    #[serde(rename = "targetId")]
+    pub target_id: u64,
+}
+
+/// `AllianceInvitationStateRecrutedMessage`, id 3549
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceInvitationStateRecrutedMessage {
+    #[serde(rename = "invitationState")]
+    pub invitation_state: i8,
+}
+
+/// `AllianceInvitationStateRecruterMessage`, id 5260
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceInvitationStateRecruterMessage {
+    #[serde(rename = "recrutedName")]
+    pub recruted_name: String,
+    #[serde(rename = "invitationState")]
+    pub invitation_state: i8,
+}
+
+/// `AllianceInvitedMessage`, id 4208
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceInvitedMessage {
+    #[serde(rename = "recruterName")]
+    pub recruter_name: String,
+    #[serde(rename = "allianceInfo")]
+    pub alliance_info: AllianceInformation,
+}
+
+/// `AllianceIsThereAnyApplicationMessage`, id 7535
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AllianceIsThereAnyApplicationMessage {}
+
+/// `AllianceJoinAutomaticallyRequestMessage`, id 870
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceJoinAutomaticallyRequestMessage {
+    #[serde(rename = "allianceId")]
+    pub alliance_id: i32,
+}
+
+/// `AllianceJoinedMessage`, id 9790
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceJoinedMessage {
+    #[serde(rename = "allianceInfo")]
+    pub alliance_info: AllianceInformation,
+    #[serde(rename = "rankId")]
+    pub rank_id: u32,
+}
+
+/// `AllianceKickRequestMessage`, id 6188
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceKickRequestMessage {
+    #[serde(rename = "kickedId")]
+    pub kicked_id: u64,
+}
+
+/// `AllianceLeftMessage`, id 5936
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AllianceLeftMessage {}
+
+/// `AllianceListApplicationAnswerMessage`, id 122
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceListApplicationAnswerMessage {
+    #[serde(deserialize_with = "float")]
+    pub offset: f64,
+    pub count: u32,
+    pub total: u32,
+    pub applies: Vec<SocialApplicationInformation>,
+}
+
+/// `AllianceListApplicationModifiedMessage`, id 4438
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceListApplicationModifiedMessage {
+    pub apply: SocialApplicationInformation,
+    pub state: i8,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+}
+
+/// `AllianceListApplicationRequestMessage`, id 448
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceListApplicationRequestMessage {
+    #[serde(deserialize_with = "float")]
+    pub offset: f64,
+    pub count: u32,
+}
+
+/// `AllianceListMessage`, id 1660
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceListMessage {
+    pub alliances: Vec<AllianceFactSheetInformation>,
+}
+
+/// `AllianceMemberInfo`, id 9446
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceMemberInfo {
+    pub id: u64,
+    pub name: String,
+    pub level: u16,
+    pub breed: i8,
+    pub sex: bool,
+    pub connected: i8,
+    #[serde(rename = "hoursSinceLastConnection")]
+    pub hours_since_last_connection: i16,
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+    pub status: Box<PlayerStatusKind>,
+    #[serde(rename = "rankId")]
+    pub rank_id: i32,
+    #[serde(rename = "enrollmentDate", deserialize_with = "float")]
+    pub enrollment_date: f64,
+    #[serde(rename = "avaRoleId")]
+    pub ava_role_id: i32,
+}
+
+/// `AllianceMemberInformationUpdateMessage`, id 8221
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceMemberInformationUpdateMessage {
+    pub member: AllianceMemberInfo,
+}
+
+/// `AllianceMemberLeavingMessage`, id 1716
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceMemberLeavingMessage {
+    pub kicked: bool,
+    #[serde(rename = "memberId")]
+    pub member_id: u64,
+}
+
+/// `AllianceMemberOnlineStatusMessage`, id 1867
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceMemberOnlineStatusMessage {
+    #[serde(rename = "memberId")]
+    pub member_id: u64,
+    pub online: bool,
+}
+
+/// `AllianceMemberStartWarningOnConnectionMessage`, id 9640
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AllianceMemberStartWarningOnConnectionMessage {}
+
+/// `AllianceMemberStopWarningOnConnectionMessage`, id 348
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AllianceMemberStopWarningOnConnectionMessage {}
+
+/// `AllianceMembershipMessage`, id 1877
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceMembershipMessage {
+    #[serde(rename = "allianceInfo")]
+    pub alliance_info: AllianceInformation,
+    #[serde(rename = "rankId")]
+    pub rank_id: u32,
+}
+
+/// `AllianceModificationEmblemValidMessage`, id 3567
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceModificationEmblemValidMessage {
+    #[serde(rename = "allianceEmblem")]
+    pub alliance_emblem: SocialEmblem,
+}
+
+/// `AllianceModificationNameAndTagValidMessage`, id 3189
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceModificationNameAndTagValidMessage {
+    #[serde(rename = "allianceName")]
+    pub alliance_name: String,
+    #[serde(rename = "allianceTag")]
+    pub alliance_tag: String,
+}
+
+/// `AllianceModificationResultMessage`, id 1267
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceModificationResultMessage {
+    pub result: i8,
+}
+
+/// `AllianceModificationStartedMessage`, id 6600
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceModificationStartedMessage {
+    #[serde(rename = "canChangeName")]
+    pub can_change_name: bool,
+    #[serde(rename = "canChangeTag")]
+    pub can_change_tag: bool,
+    #[serde(rename = "canChangeEmblem")]
+    pub can_change_emblem: bool,
+}
+
+/// `AllianceModificationValidMessage`, id 5967
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceModificationValidMessage {
+    #[serde(rename = "allianceName")]
+    pub alliance_name: String,
+    #[serde(rename = "allianceTag")]
+    pub alliance_tag: String,
+    #[serde(rename = "allianceEmblem")]
+    pub alliance_emblem: SocialEmblem,
+}
+
+/// `AllianceMotdMessage`, id 9589
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceMotdMessage {
+    pub content: String,
+    pub timestamp: i32,
+    #[serde(rename = "memberId")]
+    pub member_id: u64,
+    #[serde(rename = "memberName")]
+    pub member_name: String,
+}
+
+/// `AllianceMotdSetErrorMessage`, id 5714
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceMotdSetErrorMessage {
+    pub reason: i8,
+}
+
+/// `AllianceMotdSetRequestMessage`, id 9891
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceMotdSetRequestMessage {
+    pub content: String,
+}
+
+/// `AlliancePartialListMessage`, id 9332
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlliancePartialListMessage {
+    pub alliances: Vec<AllianceFactSheetInformation>,
+}
+
+/// `AlliancePlayerApplicationAbstractMessage`, id 8912
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AlliancePlayerApplicationAbstractMessage {}
+
+/// `AlliancePlayerApplicationInformationMessage`, id 9416
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlliancePlayerApplicationInformationMessage {
+    #[serde(rename = "allianceInformation")]
+    pub alliance_information: AllianceInformation,
+    pub apply: SocialApplicationInformation,
+}
+
+/// `AlliancePlayerNoApplicationInformationMessage`, id 8598
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AlliancePlayerNoApplicationInformationMessage {}
+
+/// `AlliancePrismDialogQuestionMessage`, id 5567
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AlliancePrismDialogQuestionMessage {}
+
+/// `AlliancePrismInformation`, id 2052
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlliancePrismInformation {
+    pub state: i8,
+    #[serde(rename = "placementDate")]
+    pub placement_date: i32,
+    #[serde(rename = "nuggetsCount")]
+    pub nuggets_count: u32,
+    pub durability: i32,
+    #[serde(rename = "nextEvolutionDate", deserialize_with = "float")]
+    pub next_evolution_date: f64,
+    pub alliance: AllianceInformation,
+}
+
+/// `AllianceRankCreateRequestMessage`, id 4323
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceRankCreateRequestMessage {
+    #[serde(rename = "parentRankId")]
+    pub parent_rank_id: u32,
+    #[serde(rename = "gfxId")]
+    pub gfx_id: u32,
+    pub name: String,
+}
+
+/// `AllianceRankRemoveRequestMessage`, id 5081
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceRankRemoveRequestMessage {
+    #[serde(rename = "rankId")]
+    pub rank_id: u32,
+    #[serde(rename = "newRankId")]
+    pub new_rank_id: u32,
+}
+
+/// `AllianceRankUpdateRequestMessage`, id 1250
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceRankUpdateRequestMessage {
+    pub rank: RankInformation,
+}
+
+/// `AllianceRanksMessage`, id 3971
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceRanksMessage {
+    pub ranks: Vec<RankInformation>,
+}
+
+/// `AllianceRanksRequestMessage`, id 2665
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AllianceRanksRequestMessage {}
+
+/// `AllianceRecruitmentInformation`, id 4850
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceRecruitmentInformation {
+    #[serde(rename = "minLevelFacultative")]
+    pub min_level_facultative: bool,
+    #[serde(rename = "invalidatedByModeration")]
+    pub invalidated_by_moderation: bool,
+    #[serde(rename = "recruitmentAutoLocked")]
+    pub recruitment_auto_locked: bool,
+    #[serde(rename = "socialId")]
+    pub social_id: u32,
+    #[serde(rename = "recruitmentType")]
+    pub recruitment_type: i8,
+    #[serde(rename = "recruitmentTitle")]
+    pub recruitment_title: String,
+    #[serde(rename = "recruitmentText")]
+    pub recruitment_text: String,
+    #[serde(rename = "selectedLanguages")]
+    pub selected_languages: Vec<u32>,
+    #[serde(rename = "selectedCriterion")]
+    pub selected_criterion: Vec<u32>,
+    #[serde(rename = "minLevel")]
+    pub min_level: i16,
+    #[serde(rename = "lastEditPlayerName")]
+    pub last_edit_player_name: String,
+    #[serde(rename = "lastEditDate", deserialize_with = "float")]
+    pub last_edit_date: f64,
+}
+
+/// `AllianceRecruitmentInformationMessage`, id 7840
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceRecruitmentInformationMessage {
+    #[serde(rename = "recruitmentData")]
+    pub recruitment_data: AllianceRecruitmentInformation,
+}
+
+/// `AllianceRecruitmentInvalidateMessage`, id 3297
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AllianceRecruitmentInvalidateMessage {}
+
+/// `AllianceRightsUpdateMessage`, id 1711
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceRightsUpdateMessage {
+    #[serde(rename = "rankId")]
+    pub rank_id: u32,
+    pub rights: Vec<u32>,
+}
+
+/// `AllianceSubmitApplicationMessage`, id 5779
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceSubmitApplicationMessage {
+    #[serde(rename = "applyText")]
+    pub apply_text: String,
+    #[serde(rename = "allianceId")]
+    pub alliance_id: u32,
+}
+
+/// `AllianceSummaryMessage`, id 8718
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceSummaryMessage {
+    #[serde(deserialize_with = "float")]
+    pub offset: f64,
+    pub count: u32,
+    pub total: u32,
+    pub alliances: Vec<AllianceFactSheetInformation>,
+}
+
+/// `AllianceSummaryRequestMessage`, id 3299
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceSummaryRequestMessage {
+    #[serde(deserialize_with = "float")]
+    pub offset: f64,
+    pub count: u32,
+    #[serde(rename = "hideFullFilter")]
+    pub hide_full_filter: bool,
+    #[serde(rename = "followingAllianceCriteria")]
+    pub following_alliance_criteria: bool,
+    #[serde(rename = "sortDescending")]
+    pub sort_descending: bool,
+    #[serde(rename = "filterType")]
+    pub filter_type: i32,
+    #[serde(rename = "textFilter")]
+    pub text_filter: String,
+    #[serde(rename = "criterionFilter")]
+    pub criterion_filter: Vec<u32>,
+    #[serde(rename = "sortType")]
+    pub sort_type: i8,
+    #[serde(rename = "languagesFilter")]
+    pub languages_filter: Vec<u32>,
+    #[serde(rename = "recruitmentTypeFilter")]
+    pub recruitment_type_filter: Vec<i8>,
+    #[serde(rename = "minPlayerLevelFilter")]
+    pub min_player_level_filter: i16,
+    #[serde(rename = "maxPlayerLevelFilter")]
+    pub max_player_level_filter: i16,
+}
+
+/// `AllianceUpdateApplicationMessage`, id 6460
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceUpdateApplicationMessage {
+    #[serde(rename = "applyText")]
+    pub apply_text: String,
+    #[serde(rename = "allianceId")]
+    pub alliance_id: u32,
+}
+
+/// `AllianceUpdateRecruitmentInformationMessage`, id 9046
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AllianceUpdateRecruitmentInformationMessage {
+    #[serde(rename = "recruitmentData")]
+    pub recruitment_data: AllianceRecruitmentInformation,
+}
+
+/// `AlmanachCalendarDateMessage`, id 3125
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlmanachCalendarDateMessage {
+    pub date: i32,
+}
+
+/// `AlreadyConnectedMessage`, id 9038
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AlreadyConnectedMessage {}
+
+/// `AlterationAddedMessage`, id 3468
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlterationAddedMessage {
+    pub alteration: AlterationInfo,
+}
+
+/// `AlterationInfo`, id 7621
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlterationInfo {
+    #[serde(rename = "alterationId")]
+    pub alteration_id: u32,
+    #[serde(rename = "creationTime", deserialize_with = "float")]
+    pub creation_time: f64,
+    #[serde(rename = "expirationType")]
+    pub expiration_type: i8,
+    #[serde(rename = "expirationValue", deserialize_with = "float")]
+    pub expiration_value: f64,
+    pub effects: Vec<ObjectEffectKind>,
+}
+
+/// `AlterationRemovedMessage`, id 565
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlterationRemovedMessage {
+    pub alteration: AlterationInfo,
+}
+
+/// `AlterationsMessage`, id 2600
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlterationsMessage {
+    pub alterations: Vec<AlterationInfo>,
+}
+
+/// `AlterationsUpdatedMessage`, id 1872
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlterationsUpdatedMessage {
+    pub alterations: Vec<AlterationInfo>,
+}
+
+/// `AlternativeMonstersInGroupLightInformations`, id 494
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlternativeMonstersInGroupLightInformations {
+    #[serde(rename = "playerCount")]
+    pub player_count: i32,
+    pub monsters: Vec<MonsterInGroupLightInformations>,
+}
+
+/// `AnomalyOpenedMessage`, id 8157
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnomalyOpenedMessage {
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+}
+
+/// `AnomalyStateMessage`, id 7435
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnomalyStateMessage {
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+    pub open: bool,
+    #[serde(rename = "closingTime")]
+    pub closing_time: u64,
+}
+
+/// `AnomalySubareaInformation`, id 9751
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnomalySubareaInformation {
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+    #[serde(rename = "rewardRate")]
+    pub reward_rate: u16,
+    #[serde(rename = "hasAnomaly")]
+    pub has_anomaly: bool,
+    #[serde(rename = "anomalyClosingTime")]
+    pub anomaly_closing_time: u64,
+}
+
+/// `AnomalySubareaInformationRequestMessage`, id 2993
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AnomalySubareaInformationRequestMessage {}
+
+/// `AnomalySubareaInformationResponseMessage`, id 4744
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AnomalySubareaInformationResponseMessage {
+    pub subareas: Vec<AnomalySubareaInformation>,
+}
+
+/// `ApplicationPlayerInformation`, id 1848
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ApplicationPlayerInformation {
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+    #[serde(rename = "playerName")]
+    pub player_name: String,
+    pub breed: i8,
+    pub sex: bool,
+    pub level: u32,
+    #[serde(rename = "accountId")]
+    pub account_id: u32,
+    #[serde(rename = "accountTag")]
+    pub account_tag: String,
+    #[serde(rename = "accountNickname")]
+    pub account_nickname: String,
+    pub status: PlayerStatus,
+}
+
+/// `ApplySpellModifierMessage`, id 4327
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ApplySpellModifierMessage {
+    #[serde(rename = "actorId", deserialize_with = "float")]
+    pub actor_id: f64,
+    pub modifier: SpellModifierMessage,
+}
+
+/// `AreaFightModificatorUpdateMessage`, id 2162
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AreaFightModificatorUpdateMessage {
+    #[serde(rename = "spellPairId")]
+    pub spell_pair_id: i32,
+}
+
+/// `ArenaFightAnswerAcknowledgementMessage`, id 6909
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ArenaFightAnswerAcknowledgementMessage {
+    pub acknowledged: bool,
+}
+
+/// `ArenaFighterIdleMessage`, id 1369
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ArenaFighterIdleMessage {}
+
+/// `ArenaFighterLeaveMessage`, id 1144
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ArenaFighterLeaveMessage {
+    pub leaver: CharacterBasicMinimalInformations,
+}
+
+/// `ArenaLeagueRanking`, id 3295
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ArenaLeagueRanking {
+    pub rating: i32,
+    #[serde(rename = "leagueId")]
+    pub league_id: u16,
+    #[serde(rename = "ladderPosition")]
+    pub ladder_position: i32,
+}
+
+/// `ArenaRankInfos`, id 9831
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ArenaRankInfos {
+    #[serde(rename = "arenaType")]
+    pub arena_type: i32,
+    #[serde(rename = "leagueRanking")]
+    pub league_ranking: ArenaLeagueRanking,
+    #[serde(rename = "bestLeagueId")]
+    pub best_league_id: u16,
+    #[serde(rename = "bestRating")]
+    pub best_rating: i32,
+    #[serde(rename = "dailyVictoryCount")]
+    pub daily_victory_count: u16,
+    #[serde(rename = "seasonVictoryCount")]
+    pub season_victory_count: u16,
+    #[serde(rename = "dailyFightcount")]
+    pub daily_fightcount: u16,
+    #[serde(rename = "seasonFightcount")]
+    pub season_fightcount: u16,
+    #[serde(rename = "numFightNeededForLadder")]
+    pub num_fight_needed_for_ladder: i16,
+}
+
+/// `AtlasPointInformationsMessage`, id 4784
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AtlasPointInformationsMessage {
+    pub r#type: AtlasPointsInformations,
+}
+
+/// `AtlasPointsInformations`, id 9930
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AtlasPointsInformations {
+    pub r#type: i8,
+    pub coords: Vec<MapCoordinatesExtended>,
+}
+
+/// `AuthenticationTicketAcceptedMessage`, id 2376
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AuthenticationTicketAcceptedMessage {}
+
+/// `AuthenticationTicketMessage`, id 2950
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AuthenticationTicketMessage {
+    pub lang: String,
+    pub ticket: String,
+}
+
+/// `AuthenticationTicketRefusedMessage`, id 4659
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AuthenticationTicketRefusedMessage {}
+
+/// `BaseSpawnMonsterInformation`, id 5049
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BaseSpawnMonsterInformation {
+    #[serde(rename = "creatureGenericId")]
+    pub creature_generic_id: u16,
+}
+
+/// `BasicAckMessage`, id 4642
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BasicAckMessage {
+    pub seq: u32,
+    #[serde(rename = "lastPacketId")]
+    pub last_packet_id: u16,
+}
+
+/// `BasicAllianceInformations`, id 7430
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BasicAllianceInformations {
+    #[serde(rename = "allianceId")]
+    pub alliance_id: u32,
+    #[serde(rename = "allianceTag")]
+    pub alliance_tag: String,
+}
+
+/// `BasicDateMessage`, id 852
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BasicDateMessage {
+    pub day: i8,
+    pub month: i8,
+    pub year: i16,
+}
+
+/// `BasicGuildInformations`, id 9037
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BasicGuildInformations {
+    #[serde(rename = "guildId")]
+    pub guild_id: u32,
+    #[serde(rename = "guildName")]
+    pub guild_name: String,
+    #[serde(rename = "guildLevel")]
+    pub guild_level: i8,
+}
+
+/// `BasicLatencyStatsMessage`, id 2788
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BasicLatencyStatsMessage {
+    pub latency: i16,
+    #[serde(rename = "sampleCount")]
+    pub sample_count: u16,
+    pub max: u16,
+}
+
+/// `BasicLatencyStatsRequestMessage`, id 4623
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BasicLatencyStatsRequestMessage {}
+
+/// `BasicNamedAllianceInformations`, id 1117
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BasicNamedAllianceInformations {
+    #[serde(rename = "allianceId")]
+    pub alliance_id: u32,
+    #[serde(rename = "allianceTag")]
+    pub alliance_tag: String,
+    #[serde(rename = "allianceName")]
+    pub alliance_name: String,
+}
+
+/// `BasicNoOperationMessage`, id 3436
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BasicNoOperationMessage {}
+
+/// `BasicPingMessage`, id 8285
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BasicPingMessage {
+    pub quiet: bool,
+}
+
+/// `BasicPongMessage`, id 5906
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BasicPongMessage {
+    pub quiet: bool,
+}
+
+/// `BasicStatMessage`, id 5844
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BasicStatMessage {
+    #[serde(rename = "timeSpent", deserialize_with = "float")]
+    pub time_spent: f64,
+    #[serde(rename = "statId")]
+    pub stat_id: u16,
+}
+
+/// `BasicStatWithDataMessage`, id 9212
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BasicStatWithDataMessage {
+    #[serde(rename = "timeSpent", deserialize_with = "float")]
+    pub time_spent: f64,
+    #[serde(rename = "statId")]
+    pub stat_id: u16,
+    pub datas: Vec<StatisticDataKind>,
+}
+
+/// `BasicTimeMessage`, id 4794
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BasicTimeMessage {
+    #[serde(deserialize_with = "float")]
+    pub timestamp: f64,
+    #[serde(rename = "timezoneOffset")]
+    pub timezone_offset: i16,
+}
+
+/// `BasicWhoAmIRequestMessage`, id 7003
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BasicWhoAmIRequestMessage {
+    pub verbose: bool,
+}
+
+/// `BasicWhoIsMessage`, id 2139
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BasicWhoIsMessage {
+    #[serde(rename = "self")]
+    pub self_: bool,
+    pub verbose: bool,
+    pub position: i8,
+    #[serde(rename = "accountTag")]
+    pub account_tag: AccountTagInformation,
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+    #[serde(rename = "playerName")]
+    pub player_name: String,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+    #[serde(rename = "areaId")]
+    pub area_id: i16,
+    #[serde(rename = "serverId")]
+    pub server_id: i16,
+    #[serde(rename = "originServerId")]
+    pub origin_server_id: i16,
+    #[serde(rename = "socialGroups")]
+    pub social_groups: Vec<AbstractSocialGroupInfosKind>,
+    #[serde(rename = "playerState")]
+    pub player_state: i8,
+}
+
+/// `BasicWhoIsNoMatchMessage`, id 1116
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BasicWhoIsNoMatchMessage {
+    pub target: Box<AbstractPlayerSearchInformationKind>,
+}
+
+/// `BasicWhoIsRequestMessage`, id 5134
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BasicWhoIsRequestMessage {
+    pub verbose: bool,
+    pub target: Box<AbstractPlayerSearchInformationKind>,
+}
+
+/// `BidExchangerObjectInfo`, id 8930
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BidExchangerObjectInfo {
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+    #[serde(rename = "objectGID")]
+    pub object_gid: u32,
+    #[serde(rename = "objectType")]
+    pub object_type: i32,
+    pub effects: Vec<ObjectEffectKind>,
+    pub prices: Vec<u64>,
+}
+
+/// `BreachBonusMessage`, id 4269
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachBonusMessage {
+    pub bonus: ObjectEffectInteger,
+}
+
+/// `BreachBranch`, id 3663
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachBranch {
+    pub room: i8,
+    pub element: i32,
+    pub bosses: Vec<MonsterInGroupLightInformations>,
+    #[serde(deserialize_with = "float")]
+    pub map: f64,
+    pub score: i16,
+    #[serde(rename = "relativeScore")]
+    pub relative_score: i16,
+    pub monsters: Vec<MonsterInGroupLightInformations>,
+}
+
+/// `BreachBranchesMessage`, id 4087
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachBranchesMessage {
+    pub branches: Vec<ExtendedBreachBranchKind>,
+}
+
+/// `BreachBudgetMessage`, id 687
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachBudgetMessage {
+    pub bugdet: u32,
+}
+
+/// `BreachCharactersMessage`, id 6915
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachCharactersMessage {
+    pub characters: Vec<u64>,
+}
+
+/// `BreachEnterMessage`, id 5898
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachEnterMessage {
+    pub owner: u64,
+}
+
+/// `BreachExitRequestMessage`, id 2470
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BreachExitRequestMessage {}
+
+/// `BreachExitResponseMessage`, id 4083
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachExitResponseMessage {
+    pub exited: bool,
+}
+
+/// `BreachGameFightEndMessage`, id 7880
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachGameFightEndMessage {
+    pub duration: i32,
+    #[serde(rename = "rewardRate")]
+    pub reward_rate: u16,
+    #[serde(rename = "lootShareLimitMalus")]
+    pub loot_share_limit_malus: i16,
+    pub results: Vec<FightResultListEntryKind>,
+    #[serde(rename = "namedPartyTeamsOutcomes")]
+    pub named_party_teams_outcomes: Vec<NamedPartyTeamWithOutcome>,
+    pub budget: i32,
+}
+
+/// `BreachInvitationAnswerMessage`, id 2868
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachInvitationAnswerMessage {
+    pub accept: bool,
+}
+
+/// `BreachInvitationCloseMessage`, id 3291
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachInvitationCloseMessage {
+    pub host: CharacterMinimalInformations,
+}
+
+/// `BreachInvitationOfferMessage`, id 9316
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachInvitationOfferMessage {
+    pub host: CharacterMinimalInformations,
+    #[serde(rename = "timeLeftBeforeCancel")]
+    pub time_left_before_cancel: u32,
+}
+
+/// `BreachInvitationRequestMessage`, id 3577
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachInvitationRequestMessage {
+    pub guests: Vec<u64>,
+}
+
+/// `BreachInvitationResponseMessage`, id 979
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachInvitationResponseMessage {
+    pub guest: CharacterMinimalInformations,
+    pub accept: bool,
+}
+
+/// `BreachKickRequestMessage`, id 8162
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachKickRequestMessage {
+    pub target: u64,
+}
+
+/// `BreachKickResponseMessage`, id 6186
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachKickResponseMessage {
+    pub target: CharacterMinimalInformations,
+    pub kicked: bool,
+}
+
+/// `BreachReward`, id 32
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachReward {
+    pub id: u32,
+    #[serde(rename = "buyLocks")]
+    pub buy_locks: Vec<i8>,
+    #[serde(rename = "buyCriterion")]
+    pub buy_criterion: String,
+    #[serde(rename = "remainingQty")]
+    pub remaining_qty: u32,
+    pub price: u32,
+}
+
+/// `BreachRewardBoughtMessage`, id 9879
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachRewardBoughtMessage {
+    pub id: u32,
+    pub bought: bool,
+}
+
+/// `BreachRewardBuyMessage`, id 2104
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachRewardBuyMessage {
+    pub id: u32,
+}
+
+/// `BreachRewardsMessage`, id 5459
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachRewardsMessage {
+    pub rewards: Vec<BreachReward>,
+}
+
+/// `BreachRoomLockedMessage`, id 4580
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BreachRoomLockedMessage {}
+
+/// `BreachRoomUnlockRequestMessage`, id 9497
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachRoomUnlockRequestMessage {
+    #[serde(rename = "roomId")]
+    pub room_id: i8,
+}
+
+/// `BreachRoomUnlockResultMessage`, id 2728
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachRoomUnlockResultMessage {
+    #[serde(rename = "roomId")]
+    pub room_id: i8,
+    pub result: i8,
+}
+
+/// `BreachSavedMessage`, id 2414
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachSavedMessage {
+    pub saved: bool,
+}
+
+/// `BreachStateMessage`, id 4452
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachStateMessage {
+    pub owner: CharacterMinimalInformations,
+    pub bonuses: Vec<ObjectEffectInteger>,
+    pub bugdet: u32,
+    pub saved: bool,
+}
+
+/// `BreachTeleportRequestMessage`, id 3042
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BreachTeleportRequestMessage {}
+
+/// `BreachTeleportResponseMessage`, id 1192
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreachTeleportResponseMessage {
+    pub teleported: bool,
+}
+
+/// `BufferInformation`, id 4783
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BufferInformation {
+    pub id: u64,
+    pub amount: u64,
+}
+
+/// `BulletinMessage`, id 1385
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BulletinMessage {
+    pub content: String,
+    pub timestamp: i32,
+    #[serde(rename = "memberId")]
+    pub member_id: u64,
+    #[serde(rename = "memberName")]
+    pub member_name: String,
+}
+
+/// `ChallengeAddMessage`, id 415
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChallengeAddMessage {
+    #[serde(rename = "challengeInformation")]
+    pub challenge_information: ChallengeInformation,
+}
+
+/// `ChallengeBonusChoiceMessage`, id 6752
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChallengeBonusChoiceMessage {
+    #[serde(rename = "challengeBonus")]
+    pub challenge_bonus: i8,
+}
+
+/// `ChallengeBonusChoiceSelectedMessage`, id 4927
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChallengeBonusChoiceSelectedMessage {
+    #[serde(rename = "challengeBonus")]
+    pub challenge_bonus: i8,
+}
+
+/// `ChallengeFightJoinRefusedMessage`, id 1854
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChallengeFightJoinRefusedMessage {
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+    pub reason: i8,
+}
+
+/// `ChallengeInformation`, id 1254
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChallengeInformation {
+    #[serde(rename = "challengeId")]
+    pub challenge_id: u32,
+    #[serde(rename = "targetsList")]
+    pub targets_list: Vec<ChallengeTargetInformationKind>,
+    #[serde(rename = "dropBonus")]
+    pub drop_bonus: u32,
+    #[serde(rename = "xpBonus")]
+    pub xp_bonus: u32,
+    pub state: i8,
+}
+
+/// `ChallengeListMessage`, id 1098
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChallengeListMessage {
+    #[serde(rename = "challengesInformation")]
+    pub challenges_information: Vec<ChallengeInformation>,
+}
+
+/// `ChallengeModSelectMessage`, id 5193
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChallengeModSelectMessage {
+    #[serde(rename = "challengeMod")]
+    pub challenge_mod: i8,
+}
+
+/// `ChallengeModSelectedMessage`, id 676
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChallengeModSelectedMessage {
+    #[serde(rename = "challengeMod")]
+    pub challenge_mod: i8,
+}
+
+/// `ChallengeNumberMessage`, id 8579
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChallengeNumberMessage {
+    #[serde(rename = "challengeNumber")]
+    pub challenge_number: u32,
+}
+
+/// `ChallengeProposalMessage`, id 5972
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChallengeProposalMessage {
+    #[serde(rename = "challengeProposals")]
+    pub challenge_proposals: Vec<ChallengeInformation>,
+    #[serde(deserialize_with = "float")]
+    pub timer: f64,
+}
+
+/// `ChallengeReadyMessage`, id 6427
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChallengeReadyMessage {
+    #[serde(rename = "challengeMod")]
+    pub challenge_mod: i8,
+}
+
+/// `ChallengeResultMessage`, id 8892
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChallengeResultMessage {
+    #[serde(rename = "challengeId")]
+    pub challenge_id: u32,
+    pub success: bool,
+}
+
+/// `ChallengeSelectedMessage`, id 26
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChallengeSelectedMessage {
+    #[serde(rename = "challengeInformation")]
+    pub challenge_information: ChallengeInformation,
+}
+
+/// `ChallengeSelectionMessage`, id 7891
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChallengeSelectionMessage {
+    #[serde(rename = "challengeId")]
+    pub challenge_id: u32,
+}
+
+/// `ChallengeTargetInformation`, id 8948
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChallengeTargetInformation {
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "targetCell")]
+    pub target_cell: i16,
+}
+
+/// `ChallengeTargetWithAttackerInformation`, id 8417
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChallengeTargetWithAttackerInformation {
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "targetCell")]
+    pub target_cell: i16,
+    #[serde(rename = "attackersIds", deserialize_with = "floats")]
+    pub attackers_ids: Vec<f64>,
+}
+
+/// `ChallengeTargetsMessage`, id 1271
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChallengeTargetsMessage {
+    #[serde(rename = "challengeInformation")]
+    pub challenge_information: ChallengeInformation,
+}
+
+/// `ChallengeTargetsRequestMessage`, id 9484
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChallengeTargetsRequestMessage {
+    #[serde(rename = "challengeId")]
+    pub challenge_id: u32,
+}
+
+/// `ChallengeValidateMessage`, id 5501
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChallengeValidateMessage {
+    #[serde(rename = "challengeId")]
+    pub challenge_id: u32,
+}
+
+/// `ChangeHavenBagRoomRequestMessage`, id 5694
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChangeHavenBagRoomRequestMessage {
+    #[serde(rename = "roomId")]
+    pub room_id: i8,
+}
+
+/// `ChangeMapMessage`, id 70
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChangeMapMessage {
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    pub autopilot: bool,
+}
+
+/// `ChangeThemeRequestMessage`, id 6425
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChangeThemeRequestMessage {
+    pub theme: i8,
+}
+
+/// `ChannelEnablingChangeMessage`, id 5136
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChannelEnablingChangeMessage {
+    pub channel: i8,
+    pub enable: bool,
+}
+
+/// `ChannelEnablingMessage`, id 4437
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChannelEnablingMessage {
+    pub channel: i8,
+    pub enable: bool,
+}
+
+/// `CharacterAlignmentWarEffortProgressionMessage`, id 6750
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterAlignmentWarEffortProgressionMessage {
+    #[serde(rename = "alignmentWarEffortDailyLimit")]
+    pub alignment_war_effort_daily_limit: u64,
+    #[serde(rename = "alignmentWarEffortDailyDonation")]
+    pub alignment_war_effort_daily_donation: u64,
+    #[serde(rename = "alignmentWarEffortPersonalDonation")]
+    pub alignment_war_effort_personal_donation: u64,
+}
+
+/// `CharacterAlignmentWarEffortProgressionRequestMessage`, id 1456
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CharacterAlignmentWarEffortProgressionRequestMessage {}
+
+/// `CharacterBaseInformations`, id 4381
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterBaseInformations {
+    pub id: u64,
+    pub name: String,
+    pub level: u16,
+    #[serde(rename = "entityLook")]
+    pub entity_look: EntityLook,
+    pub breed: i8,
+    pub sex: bool,
+}
+
+/// `CharacterBasicMinimalInformations`, id 857
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterBasicMinimalInformations {
+    pub id: u64,
+    pub name: String,
+}
+
+/// `CharacterCanBeCreatedRequestMessage`, id 254
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CharacterCanBeCreatedRequestMessage {}
+
+/// `CharacterCanBeCreatedResultMessage`, id 5958
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterCanBeCreatedResultMessage {
+    #[serde(rename = "yesYouCan")]
+    pub yes_you_can: bool,
+}
+
+/// `CharacterCapabilitiesMessage`, id 665
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterCapabilitiesMessage {
+    #[serde(rename = "guildEmblemSymbolCategories")]
+    pub guild_emblem_symbol_categories: u32,
+}
+
+/// `CharacterCharacteristic`, id 3706
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterCharacteristic {
+    #[serde(rename = "characteristicId")]
+    pub characteristic_id: i16,
+}
+
+/// `CharacterCharacteristicDetailed`, id 2881
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterCharacteristicDetailed {
+    #[serde(rename = "characteristicId")]
+    pub characteristic_id: i16,
+    pub base: u32,
+    pub additional: u32,
+    #[serde(rename = "objectsAndMountBonus")]
+    pub objects_and_mount_bonus: u32,
+    #[serde(rename = "alignGiftBonus")]
+    pub align_gift_bonus: u32,
+    #[serde(rename = "contextModif")]
+    pub context_modif: u32,
+}
+
+/// `CharacterCharacteristicForPreset`, id 4957
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterCharacteristicForPreset {
+    pub keyword: String,
+    pub base: u32,
+    pub additionnal: u32,
+    pub stuff: u32,
+}
+
+/// `CharacterCharacteristicValue`, id 9684
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterCharacteristicValue {
+    #[serde(rename = "characteristicId")]
+    pub characteristic_id: i16,
+    pub total: i32,
+}
+
+/// `CharacterCharacteristics`, id 7623
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterCharacteristics {
+    pub characteristics: Vec<CharacterCharacteristicKind>,
+}
+
+/// `CharacterCharacteristicsInformations`, id 5200
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterCharacteristicsInformations {
+    pub experience: u64,
+    #[serde(rename = "experienceLevelFloor")]
+    pub experience_level_floor: u64,
+    #[serde(rename = "experienceNextLevelFloor")]
+    pub experience_next_level_floor: u64,
+    #[serde(rename = "experienceBonusLimit")]
+    pub experience_bonus_limit: u64,
+    pub kamas: u64,
+    #[serde(rename = "alignmentInfos")]
+    pub alignment_infos: ActorExtendedAlignmentInformations,
+    #[serde(rename = "criticalHitWeapon")]
+    pub critical_hit_weapon: u16,
+    pub characteristics: Vec<CharacterCharacteristicKind>,
+    #[serde(rename = "spellModifiers")]
+    pub spell_modifiers: Vec<SpellModifierMessage>,
+    #[serde(rename = "probationTime", deserialize_with = "float")]
+    pub probation_time: f64,
+}
+
+/// `CharacterCreationRequestMessage`, id 324
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterCreationRequestMessage {
+    pub name: String,
+    pub breed: i8,
+    pub sex: bool,
+    pub colors: Vec<i32>,
+    #[serde(rename = "cosmeticId")]
+    pub cosmetic_id: u16,
+}
+
+/// `CharacterCreationResultMessage`, id 2193
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterCreationResultMessage {
+    pub result: i8,
+    pub reason: i8,
+}
+
+/// `CharacterDeletionErrorMessage`, id 608
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterDeletionErrorMessage {
+    pub reason: i8,
+}
+
+/// `CharacterDeletionPrepareMessage`, id 4201
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterDeletionPrepareMessage {
+    #[serde(rename = "characterId")]
+    pub character_id: u64,
+    #[serde(rename = "characterName")]
+    pub character_name: String,
+    #[serde(rename = "secretQuestion")]
+    pub secret_question: String,
+    #[serde(rename = "needSecretAnswer")]
+    pub need_secret_answer: bool,
+}
+
+/// `CharacterDeletionPrepareRequestMessage`, id 6119
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterDeletionPrepareRequestMessage {
+    #[serde(rename = "characterId")]
+    pub character_id: u64,
+}
+
+/// `CharacterDeletionRequestMessage`, id 5964
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterDeletionRequestMessage {
+    #[serde(rename = "characterId")]
+    pub character_id: u64,
+    #[serde(rename = "secretAnswerHash")]
+    pub secret_answer_hash: String,
+}
+
+/// `CharacterExperienceGainMessage`, id 2271
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterExperienceGainMessage {
+    #[serde(rename = "experienceCharacter")]
+    pub experience_character: u64,
+    #[serde(rename = "experienceMount")]
+    pub experience_mount: u64,
+    #[serde(rename = "experienceGuild")]
+    pub experience_guild: u64,
+    #[serde(rename = "experienceIncarnation")]
+    pub experience_incarnation: u64,
+}
+
+/// `CharacterFirstSelectionMessage`, id 7333
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterFirstSelectionMessage {
+    pub id: u64,
+    #[serde(rename = "doTutorial")]
+    pub do_tutorial: bool,
+}
+
+/// `CharacterHardcoreOrEpicInformations`, id 969
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterHardcoreOrEpicInformations {
+    pub id: u64,
+    pub name: String,
+    pub level: u16,
+    #[serde(rename = "entityLook")]
+    pub entity_look: EntityLook,
+    pub breed: i8,
+    pub sex: bool,
+    #[serde(rename = "deathState")]
+    pub death_state: i8,
+    #[serde(rename = "deathCount")]
+    pub death_count: u16,
+    #[serde(rename = "deathMaxLevel")]
+    pub death_max_level: u16,
+}
+
+/// `CharacterLevelUpInformationMessage`, id 4030
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterLevelUpInformationMessage {
+    #[serde(rename = "newLevel")]
+    pub new_level: u16,
+    pub name: String,
+    pub id: u64,
+}
+
+/// `CharacterLevelUpMessage`, id 4096
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterLevelUpMessage {
+    #[serde(rename = "newLevel")]
+    pub new_level: u16,
+}
+
+/// `CharacterLoadingCompleteMessage`, id 7097
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CharacterLoadingCompleteMessage {}
+
+/// `CharacterMinimalAllianceInformations`, id 9325
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterMinimalAllianceInformations {
+    pub id: u64,
+    pub name: String,
+    pub level: u16,
+    #[serde(rename = "entityLook")]
+    pub entity_look: EntityLook,
+    pub breed: i8,
+    pub alliance: BasicNamedAllianceInformations,
+}
+
+/// `CharacterMinimalGuildInformations`, id 16
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterMinimalGuildInformations {
+    pub id: u64,
+    pub name: String,
+    pub level: u16,
+    #[serde(rename = "entityLook")]
+    pub entity_look: EntityLook,
+    pub breed: i8,
+    pub guild: BasicGuildInformations,
+}
+
+/// `CharacterMinimalInformations`, id 873
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterMinimalInformations {
+    pub id: u64,
+    pub name: String,
+    pub level: u16,
+}
+
+/// `CharacterMinimalPlusLookInformations`, id 8749
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterMinimalPlusLookInformations {
+    pub id: u64,
+    pub name: String,
+    pub level: u16,
+    #[serde(rename = "entityLook")]
+    pub entity_look: EntityLook,
+    pub breed: i8,
+}
+
+/// `CharacterMinimalSocialPublicInformations`, id 5230
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterMinimalSocialPublicInformations {
+    pub id: u64,
+    pub name: String,
+    pub level: u16,
+    pub rank: RankPublicInformation,
+}
+
+/// `CharacterNameSuggestionFailureMessage`, id 7428
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterNameSuggestionFailureMessage {
+    pub reason: i8,
+}
+
+/// `CharacterNameSuggestionRequestMessage`, id 1542
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CharacterNameSuggestionRequestMessage {}
+
+/// `CharacterNameSuggestionSuccessMessage`, id 6416
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterNameSuggestionSuccessMessage {
+    pub suggestion: String,
+}
+
+/// `CharacterRemodelingInformation`, id 3686
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterRemodelingInformation {
+    pub id: u64,
+    pub name: String,
+    pub breed: i8,
+    pub sex: bool,
+    #[serde(rename = "cosmeticId")]
+    pub cosmetic_id: u16,
+    pub colors: Vec<i32>,
+}
+
+/// `CharacterReplayRequestMessage`, id 1225
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterReplayRequestMessage {
+    #[serde(rename = "characterId")]
+    pub character_id: u64,
+}
+
+/// `CharacterReplayWithRemodelRequestMessage`, id 8665
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterReplayWithRemodelRequestMessage {
+    #[serde(rename = "characterId")]
+    pub character_id: u64,
+    pub remodel: RemodelingInformation,
+}
+
+/// `CharacterSelectedErrorMessage`, id 2398
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CharacterSelectedErrorMessage {}
+
+/// `CharacterSelectedForceMessage`, id 9409
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterSelectedForceMessage {
+    pub id: i32,
+}
+
+/// `CharacterSelectedForceReadyMessage`, id 2061
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CharacterSelectedForceReadyMessage {}
+
+/// `CharacterSelectedSuccessMessage`, id 2529
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterSelectedSuccessMessage {
+    pub infos: CharacterBaseInformations,
+    #[serde(rename = "isCollectingStats")]
+    pub is_collecting_stats: bool,
+}
+
+/// `CharacterSelectionMessage`, id 9292
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterSelectionMessage {
+    pub id: u64,
+}
+
+/// `CharacterSelectionWithRemodelMessage`, id 512
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterSelectionWithRemodelMessage {
+    pub id: u64,
+    pub remodel: RemodelingInformation,
+}
+
+/// `CharacterStatsListMessage`, id 9901
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterStatsListMessage {
+    pub stats: CharacterCharacteristicsInformations,
+}
+
+/// `CharacterToRemodelInformations`, id 2429
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterToRemodelInformations {
+    pub id: u64,
+    pub name: String,
+    pub breed: i8,
+    pub sex: bool,
+    #[serde(rename = "cosmeticId")]
+    pub cosmetic_id: u16,
+    pub colors: Vec<i32>,
+    #[serde(rename = "possibleChangeMask")]
+    pub possible_change_mask: i8,
+    #[serde(rename = "mandatoryChangeMask")]
+    pub mandatory_change_mask: i8,
+}
+
+/// `CharacterUsableCharacteristicDetailed`, id 6487
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharacterUsableCharacteristicDetailed {
+    #[serde(rename = "characteristicId")]
+    pub characteristic_id: i16,
+    pub base: u32,
+    pub additional: u32,
+    #[serde(rename = "objectsAndMountBonus")]
+    pub objects_and_mount_bonus: u32,
+    #[serde(rename = "alignGiftBonus")]
+    pub align_gift_bonus: u32,
+    #[serde(rename = "contextModif")]
+    pub context_modif: u32,
+    pub used: u32,
+}
+
+/// `CharactersListErrorMessage`, id 7977
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CharactersListErrorMessage {}
+
+/// `CharactersListMessage`, id 2168
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharactersListMessage {
+    pub characters: Vec<CharacterBaseInformationsKind>,
+}
+
+/// `CharactersListRequestMessage`, id 3698
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CharactersListRequestMessage {}
+
+/// `CharactersListWithRemodelingMessage`, id 8096
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CharactersListWithRemodelingMessage {
+    pub characters: Vec<CharacterBaseInformationsKind>,
+    #[serde(rename = "charactersToRemodel")]
+    pub characters_to_remodel: Vec<CharacterToRemodelInformations>,
+}
+
+/// `ChatAbstractClientMessage`, id 4180
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChatAbstractClientMessage {
+    pub content: String,
+}
+
+/// `ChatAbstractServerMessage`, id 2152
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChatAbstractServerMessage {
+    pub channel: i8,
+    pub content: String,
+    pub timestamp: i32,
+    pub fingerprint: String,
+}
+
+/// `ChatAdminServerMessage`, id 1046
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChatAdminServerMessage {
+    pub channel: i8,
+    pub content: String,
+    pub timestamp: i32,
+    pub fingerprint: String,
+    #[serde(rename = "senderId", deserialize_with = "float")]
+    pub sender_id: f64,
+    #[serde(rename = "senderName")]
+    pub sender_name: String,
+    pub prefix: String,
+    #[serde(rename = "senderAccountId")]
+    pub sender_account_id: i32,
+}
+
+/// `ChatClientMultiMessage`, id 2972
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChatClientMultiMessage {
+    pub content: String,
+    pub channel: i8,
+}
+
+/// `ChatClientMultiWithObjectMessage`, id 7821
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChatClientMultiWithObjectMessage {
+    pub content: String,
+    pub channel: i8,
+    pub objects: Vec<ObjectItem>,
+}
+
+/// `ChatClientPrivateMessage`, id 12
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChatClientPrivateMessage {
+    pub content: String,
+    pub receiver: Box<AbstractPlayerSearchInformationKind>,
+}
+
+/// `ChatClientPrivateWithObjectMessage`, id 3880
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChatClientPrivateWithObjectMessage {
+    pub content: String,
+    pub receiver: Box<AbstractPlayerSearchInformationKind>,
+    pub objects: Vec<ObjectItem>,
+}
+
+/// `ChatCommunityChannelCommunityMessage`, id 8760
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChatCommunityChannelCommunityMessage {
+    #[serde(rename = "communityId")]
+    pub community_id: i16,
+}
+
+/// `ChatCommunityChannelSetCommunityRequestMessage`, id 9774
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChatCommunityChannelSetCommunityRequestMessage {
+    #[serde(rename = "communityId")]
+    pub community_id: i16,
+}
+
+/// `ChatErrorMessage`, id 6523
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChatErrorMessage {
+    pub reason: i8,
+}
+
+/// `ChatKolizeumServerMessage`, id 2850
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChatKolizeumServerMessage {
+    pub channel: i8,
+    pub content: String,
+    pub timestamp: i32,
+    pub fingerprint: String,
+    #[serde(rename = "senderId", deserialize_with = "float")]
+    pub sender_id: f64,
+    #[serde(rename = "senderName")]
+    pub sender_name: String,
+    pub prefix: String,
+    #[serde(rename = "senderAccountId")]
+    pub sender_account_id: i32,
+    #[serde(rename = "originServerId")]
+    pub origin_server_id: i16,
+}
+
+/// `ChatServerCopyMessage`, id 7820
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChatServerCopyMessage {
+    pub channel: i8,
+    pub content: String,
+    pub timestamp: i32,
+    pub fingerprint: String,
+    #[serde(rename = "receiverId")]
+    pub receiver_id: u64,
+    #[serde(rename = "receiverName")]
+    pub receiver_name: String,
+}
+
+/// `ChatServerCopyWithObjectMessage`, id 889
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChatServerCopyWithObjectMessage {
+    pub channel: i8,
+    pub content: String,
+    pub timestamp: i32,
+    pub fingerprint: String,
+    #[serde(rename = "receiverId")]
+    pub receiver_id: u64,
+    #[serde(rename = "receiverName")]
+    pub receiver_name: String,
+    pub objects: Vec<ObjectItem>,
+}
+
+/// `ChatServerMessage`, id 1304
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChatServerMessage {
+    pub channel: i8,
+    pub content: String,
+    pub timestamp: i32,
+    pub fingerprint: String,
+    #[serde(rename = "senderId", deserialize_with = "float")]
+    pub sender_id: f64,
+    #[serde(rename = "senderName")]
+    pub sender_name: String,
+    pub prefix: String,
+    #[serde(rename = "senderAccountId")]
+    pub sender_account_id: i32,
+}
+
+/// `ChatServerWithObjectMessage`, id 2066
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChatServerWithObjectMessage {
+    pub channel: i8,
+    pub content: String,
+    pub timestamp: i32,
+    pub fingerprint: String,
+    #[serde(rename = "senderId", deserialize_with = "float")]
+    pub sender_id: f64,
+    #[serde(rename = "senderName")]
+    pub sender_name: String,
+    pub prefix: String,
+    #[serde(rename = "senderAccountId")]
+    pub sender_account_id: i32,
+    pub objects: Vec<ObjectItem>,
+}
+
+/// `ChatSmileyExtraPackListMessage`, id 8829
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChatSmileyExtraPackListMessage {
+    #[serde(rename = "packIds")]
+    pub pack_ids: Vec<i8>,
+}
+
+/// `ChatSmileyMessage`, id 2775
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChatSmileyMessage {
+    #[serde(rename = "entityId", deserialize_with = "float")]
+    pub entity_id: f64,
+    #[serde(rename = "smileyId")]
+    pub smiley_id: u16,
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+}
+
+/// `ChatSmileyRequestMessage`, id 2649
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChatSmileyRequestMessage {
+    #[serde(rename = "smileyId")]
+    pub smiley_id: u16,
+}
+
+/// `CheckFileMessage`, id 2644
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CheckFileMessage {
+    #[serde(rename = "filenameHash")]
+    pub filename_hash: String,
+    pub r#type: i8,
+    pub value: String,
+}
+
+/// `CheckFileRequestMessage`, id 1265
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CheckFileRequestMessage {
+    pub filename: String,
+    pub r#type: i8,
+}
+
+/// `CheckIntegrityMessage`, id 8109
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CheckIntegrityMessage {
+    pub data: Vec<i8>,
+}
+
+/// `CinematicMessage`, id 5524
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CinematicMessage {
+    #[serde(rename = "cinematicId")]
+    pub cinematic_id: u16,
+}
+
+/// `ClientKeyMessage`, id 8426
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ClientKeyMessage {
+    pub key: String,
+}
+
+/// `ClientUIOpenedByObjectMessage`, id 5533
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ClientUIOpenedByObjectMessage {
+    pub r#type: i8,
+    pub uid: u32,
+}
+
+/// `ClientUIOpenedMessage`, id 2590
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ClientUIOpenedMessage {
+    pub r#type: i8,
+}
+
+/// `ClientYouAreDrunkMessage`, id 3378
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ClientYouAreDrunkMessage {
+    pub level: i8,
+    pub message: String,
+}
+
+/// `CloseHavenBagFurnitureSequenceRequestMessage`, id 6578
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CloseHavenBagFurnitureSequenceRequestMessage {}
+
+/// `CompassResetMessage`, id 4281
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CompassResetMessage {
+    pub r#type: i8,
+}
+
+/// `CompassUpdateMessage`, id 1650
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CompassUpdateMessage {
+    pub r#type: i8,
+    pub coords: Box<MapCoordinatesKind>,
+}
+
+/// `CompassUpdatePartyMemberMessage`, id 242
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CompassUpdatePartyMemberMessage {
+    pub r#type: i8,
+    pub coords: Box<MapCoordinatesKind>,
+    #[serde(rename = "memberId")]
+    pub member_id: u64,
+    pub active: bool,
+}
+
+/// `CompassUpdatePvpSeekMessage`, id 9391
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CompassUpdatePvpSeekMessage {
+    pub r#type: i8,
+    pub coords: Box<MapCoordinatesKind>,
+    #[serde(rename = "memberId")]
+    pub member_id: u64,
+    #[serde(rename = "memberName")]
+    pub member_name: String,
+}
+
+/// `ConfirmationOfListeningTaxCollectorUpdatesMessage`, id 2015
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConfirmationOfListeningTaxCollectorUpdatesMessage {
+    pub information: TaxCollectorInformations,
+}
+
+/// `ConsoleCommandsListMessage`, id 2634
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConsoleCommandsListMessage {
+    pub aliases: Vec<String>,
+    pub args: Vec<String>,
+    pub descriptions: Vec<String>,
+}
+
+/// `ConsoleEndMessage`, id 111
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConsoleEndMessage {
+    #[serde(rename = "consoleUuid")]
+    pub console_uuid: Uuid,
+    #[serde(rename = "isSuccess")]
+    pub is_success: bool,
+}
+
+/// `ConsoleMessage`, id 4821
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConsoleMessage {
+    pub r#type: i8,
+    pub content: String,
+}
+
+/// `ConsumeAllGameActionItemMessage`, id 2381
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConsumeAllGameActionItemMessage {
+    #[serde(rename = "characterId")]
+    pub character_id: u64,
+}
+
+/// `ConsumeGameActionItemMessage`, id 9224
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConsumeGameActionItemMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: i32,
+    #[serde(rename = "characterId")]
+    pub character_id: u64,
+}
+
+/// `ContactAddFailureMessage`, id 749
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContactAddFailureMessage {
+    pub reason: i8,
+}
+
+/// `ContactLookErrorMessage`, id 2452
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContactLookErrorMessage {
+    #[serde(rename = "requestId")]
+    pub request_id: u32,
+}
+
+/// `ContactLookMessage`, id 1785
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContactLookMessage {
+    #[serde(rename = "requestId")]
+    pub request_id: u32,
+    #[serde(rename = "playerName")]
+    pub player_name: String,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+    pub look: EntityLook,
+}
+
+/// `ContactLookRequestByIdMessage`, id 2103
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContactLookRequestByIdMessage {
+    #[serde(rename = "requestId")]
+    pub request_id: i8,
+    #[serde(rename = "contactType")]
+    pub contact_type: i8,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+}
+
+/// `ContactLookRequestByNameMessage`, id 6338
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContactLookRequestByNameMessage {
+    #[serde(rename = "requestId")]
+    pub request_id: i8,
+    #[serde(rename = "contactType")]
+    pub contact_type: i8,
+    #[serde(rename = "playerName")]
+    pub player_name: String,
+}
+
+/// `ContactLookRequestMessage`, id 6862
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContactLookRequestMessage {
+    #[serde(rename = "requestId")]
+    pub request_id: i8,
+    #[serde(rename = "contactType")]
+    pub contact_type: i8,
+}
+
+/// `Contribution`, id 2526
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Contribution {
+    #[serde(rename = "contributorId")]
+    pub contributor_id: u64,
+    #[serde(rename = "contributorName")]
+    pub contributor_name: String,
+    pub amount: u64,
+}
+
+/// `CreateGuildRankRequestMessage`, id 4228
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CreateGuildRankRequestMessage {
+    #[serde(rename = "parentRankId")]
+    pub parent_rank_id: u32,
+    #[serde(rename = "gfxId")]
+    pub gfx_id: u32,
+    pub name: String,
+}
+
+/// `CredentialsAcknowledgementMessage`, id 2233
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CredentialsAcknowledgementMessage {}
+
+/// `CurrentMapInstanceMessage`, id 4338
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CurrentMapInstanceMessage {
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    #[serde(rename = "instantiatedMapId", deserialize_with = "float")]
+    pub instantiated_map_id: f64,
+}
+
+/// `CurrentMapMessage`, id 196
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CurrentMapMessage {
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+}
+
+/// `CurrentServerStatusUpdateMessage`, id 9841
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CurrentServerStatusUpdateMessage {
+    pub status: i8,
+}
+
+/// `DebtInformation`, id 2748
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DebtInformation {
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+    #[serde(deserialize_with = "float")]
+    pub timestamp: f64,
+}
+
+/// `DebtsDeleteMessage`, id 8800
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DebtsDeleteMessage {
+    pub reason: i8,
+    #[serde(deserialize_with = "floats")]
+    pub debts: Vec<f64>,
+}
+
+/// `DebtsUpdateMessage`, id 3509
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DebtsUpdateMessage {
+    pub action: i8,
+    pub debts: Vec<DebtInformationKind>,
+}
+
+/// `DebugClearHighlightCellsMessage`, id 4861
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DebugClearHighlightCellsMessage {}
+
+/// `DebugHighlightCellsMessage`, id 2076
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DebugHighlightCellsMessage {
+    #[serde(deserialize_with = "float")]
+    pub color: f64,
+    pub cells: Vec<u16>,
+}
+
+/// `DebugInClientMessage`, id 123
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DebugInClientMessage {
+    pub level: i8,
+    pub message: String,
+}
+
+/// `DecraftResultMessage`, id 1691
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DecraftResultMessage {
+    pub results: Vec<DecraftedItemStackInfo>,
+}
+
+/// `DecraftedItemStackInfo`, id 9479
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DecraftedItemStackInfo {
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+    #[serde(rename = "bonusMin", deserialize_with = "float")]
+    pub bonus_min: f32,
+    #[serde(rename = "bonusMax", deserialize_with = "float")]
+    pub bonus_max: f32,
+    #[serde(rename = "runesId")]
+    pub runes_id: Vec<u32>,
+    #[serde(rename = "runesQty")]
+    pub runes_qty: Vec<u32>,
+}
+
+/// `DiceRollRequestMessage`, id 1797
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DiceRollRequestMessage {
+    pub dice: u32,
+    pub faces: u32,
+    pub channel: i8,
+}
+
+/// `DisplayNumericalValuePaddockMessage`, id 9683
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DisplayNumericalValuePaddockMessage {
+    #[serde(rename = "rideId")]
+    pub ride_id: i32,
+    pub value: i32,
+    pub r#type: i8,
+}
+
+/// `DocumentReadingBeginMessage`, id 6514
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DocumentReadingBeginMessage {
+    #[serde(rename = "documentId")]
+    pub document_id: u16,
+}
+
+/// `DumpedEntityStatsMessage`, id 2463
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DumpedEntityStatsMessage {
+    #[serde(rename = "actorId", deserialize_with = "float")]
+    pub actor_id: f64,
+    pub stats: CharacterCharacteristics,
+}
+
+/// `DungeonKeyRingMessage`, id 2368
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DungeonKeyRingMessage {
+    pub availables: Vec<u16>,
+    pub unavailables: Vec<u16>,
+}
+
+/// `DungeonKeyRingUpdateMessage`, id 336
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DungeonKeyRingUpdateMessage {
+    #[serde(rename = "dungeonId")]
+    pub dungeon_id: u16,
+    pub available: bool,
+}
+
+/// `DungeonPartyFinderAvailableDungeonsMessage`, id 7398
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DungeonPartyFinderAvailableDungeonsMessage {
+    #[serde(rename = "dungeonIds")]
+    pub dungeon_ids: Vec<u16>,
+}
+
+/// `DungeonPartyFinderAvailableDungeonsRequestMessage`, id 4912
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DungeonPartyFinderAvailableDungeonsRequestMessage {}
+
+/// `DungeonPartyFinderListenErrorMessage`, id 3306
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DungeonPartyFinderListenErrorMessage {
+    #[serde(rename = "dungeonId")]
+    pub dungeon_id: u16,
+}
+
+/// `DungeonPartyFinderListenRequestMessage`, id 5987
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DungeonPartyFinderListenRequestMessage {
+    #[serde(rename = "dungeonId")]
+    pub dungeon_id: u16,
+}
+
+/// `DungeonPartyFinderPlayer`, id 1323
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DungeonPartyFinderPlayer {
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+    #[serde(rename = "playerName")]
+    pub player_name: String,
+    pub breed: i8,
+    pub sex: bool,
+    pub level: u16,
+}
+
+/// `DungeonPartyFinderRegisterErrorMessage`, id 8804
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DungeonPartyFinderRegisterErrorMessage {}
+
+/// `DungeonPartyFinderRegisterRequestMessage`, id 3137
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DungeonPartyFinderRegisterRequestMessage {
+    #[serde(rename = "dungeonIds")]
+    pub dungeon_ids: Vec<u16>,
+}
+
+/// `DungeonPartyFinderRegisterSuccessMessage`, id 8357
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DungeonPartyFinderRegisterSuccessMessage {
+    #[serde(rename = "dungeonIds")]
+    pub dungeon_ids: Vec<u16>,
+}
+
+/// `DungeonPartyFinderRoomContentMessage`, id 1307
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DungeonPartyFinderRoomContentMessage {
+    #[serde(rename = "dungeonId")]
+    pub dungeon_id: u16,
+    pub players: Vec<DungeonPartyFinderPlayer>,
+}
+
+/// `DungeonPartyFinderRoomContentUpdateMessage`, id 1495
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DungeonPartyFinderRoomContentUpdateMessage {
+    #[serde(rename = "dungeonId")]
+    pub dungeon_id: u16,
+    #[serde(rename = "addedPlayers")]
+    pub added_players: Vec<DungeonPartyFinderPlayer>,
+    #[serde(rename = "removedPlayersIds")]
+    pub removed_players_ids: Vec<u64>,
+}
+
+/// `EditHavenBagCancelRequestMessage`, id 7831
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EditHavenBagCancelRequestMessage {}
+
+/// `EditHavenBagFinishedMessage`, id 1610
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EditHavenBagFinishedMessage {}
+
+/// `EditHavenBagRequestMessage`, id 3856
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EditHavenBagRequestMessage {}
+
+/// `EditHavenBagStartMessage`, id 6376
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EditHavenBagStartMessage {}
+
+/// `EmoteAddMessage`, id 1679
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmoteAddMessage {
+    #[serde(rename = "emoteId")]
+    pub emote_id: i16,
+}
+
+/// `EmoteListMessage`, id 8395
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmoteListMessage {
+    #[serde(rename = "emoteIds")]
+    pub emote_ids: Vec<i16>,
+}
+
+/// `EmotePlayAbstractMessage`, id 8532
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmotePlayAbstractMessage {
+    #[serde(rename = "emoteId")]
+    pub emote_id: i16,
+    #[serde(rename = "emoteStartTime", deserialize_with = "float")]
+    pub emote_start_time: f64,
+}
+
+/// `EmotePlayErrorMessage`, id 8332
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmotePlayErrorMessage {
+    #[serde(rename = "emoteId")]
+    pub emote_id: i16,
+}
+
+/// `EmotePlayMassiveMessage`, id 6382
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmotePlayMassiveMessage {
+    #[serde(rename = "emoteId")]
+    pub emote_id: i16,
+    #[serde(rename = "emoteStartTime", deserialize_with = "float")]
+    pub emote_start_time: f64,
+    #[serde(rename = "actorIds", deserialize_with = "floats")]
+    pub actor_ids: Vec<f64>,
+}
+
+/// `EmotePlayMessage`, id 2220
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmotePlayMessage {
+    #[serde(rename = "emoteId")]
+    pub emote_id: i16,
+    #[serde(rename = "emoteStartTime", deserialize_with = "float")]
+    pub emote_start_time: f64,
+    #[serde(rename = "actorId", deserialize_with = "float")]
+    pub actor_id: f64,
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+}
+
+/// `EmotePlayRequestMessage`, id 9742
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmotePlayRequestMessage {
+    #[serde(rename = "emoteId")]
+    pub emote_id: i16,
+}
+
+/// `EmoteRemoveMessage`, id 3707
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmoteRemoveMessage {
+    #[serde(rename = "emoteId")]
+    pub emote_id: i16,
+}
+
+/// `EnabledChannelsMessage`, id 8311
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EnabledChannelsMessage {
+    pub channels: Vec<i8>,
+    pub disallowed: Vec<i8>,
+}
+
+/// `EnterHavenBagRequestMessage`, id 3128
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EnterHavenBagRequestMessage {
+    #[serde(rename = "havenBagOwner")]
+    pub haven_bag_owner: u64,
+}
+
+/// `EntitiesInformationMessage`, id 7214
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EntitiesInformationMessage {
+    pub entities: Vec<EntityInformation>,
+}
+
+/// `EntitiesPreset`, id 5403
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EntitiesPreset {
+    pub id: i16,
+    #[serde(rename = "iconId")]
+    pub icon_id: i16,
+    #[serde(rename = "entityIds")]
+    pub entity_ids: Vec<u16>,
+}
+
+/// `EntityDispositionInformations`, id 1844
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EntityDispositionInformations {
+    #[serde(rename = "cellId")]
+    pub cell_id: i16,
+    pub direction: i8,
+}
+
+/// `EntityInformation`, id 4405
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EntityInformation {
+    pub id: u16,
+    pub experience: u32,
+    pub status: bool,
+}
+
+/// `EntityInformationMessage`, id 9079
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EntityInformationMessage {
+    pub entity: EntityInformation,
+}
+
+/// `EntityLook`, id 7362
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EntityLook {
+    #[serde(rename = "bonesId")]
+    pub bones_id: u16,
+    pub skins: Vec<u16>,
+    #[serde(rename = "indexedColors")]
+    pub indexed_colors: Vec<i32>,
+    pub scales: Vec<u16>,
+    pub subentities: Vec<SubEntity>,
+}
+
+/// `EntityMovementInformations`, id 7529
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EntityMovementInformations {
+    pub id: i32,
+    pub steps: Vec<i8>,
+}
+
+/// `EntityTalkMessage`, id 2173
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EntityTalkMessage {
+    #[serde(rename = "entityId", deserialize_with = "float")]
+    pub entity_id: f64,
+    #[serde(rename = "textId")]
+    pub text_id: u16,
+    pub parameters: Vec<String>,
+}
+
+/// `ErrorMapNotFoundMessage`, id 7483
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ErrorMapNotFoundMessage {
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+}
+
+/// `EvolutiveObjectRecycleResultMessage`, id 9075
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EvolutiveObjectRecycleResultMessage {
+    #[serde(rename = "recycledItems")]
+    pub recycled_items: Vec<RecycledItem>,
+}
+
+/// `ExchangeAcceptMessage`, id 6140
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExchangeAcceptMessage {}
+
+/// `ExchangeBidHouseBuyMessage`, id 3286
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeBidHouseBuyMessage {
+    pub uid: u32,
+    pub qty: u32,
+    pub price: u64,
+}
+
+/// `ExchangeBidHouseBuyResultMessage`, id 2273
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeBidHouseBuyResultMessage {
+    pub uid: u32,
+    pub bought: bool,
+}
+
+/// `ExchangeBidHouseGenericItemAddedMessage`, id 5922
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeBidHouseGenericItemAddedMessage {
+    #[serde(rename = "objGenericId")]
+    pub obj_generic_id: u32,
+}
+
+/// `ExchangeBidHouseGenericItemRemovedMessage`, id 9552
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeBidHouseGenericItemRemovedMessage {
+    #[serde(rename = "objGenericId")]
+    pub obj_generic_id: u32,
+}
+
+/// `ExchangeBidHouseInListAddedMessage`, id 1976
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeBidHouseInListAddedMessage {
+    #[serde(rename = "itemUID")]
+    pub item_uid: i32,
+    #[serde(rename = "objectGID")]
+    pub object_gid: u32,
+    #[serde(rename = "objectType")]
+    pub object_type: i32,
+    pub effects: Vec<ObjectEffectKind>,
+    pub prices: Vec<u64>,
+}
+
+/// `ExchangeBidHouseInListRemovedMessage`, id 5494
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeBidHouseInListRemovedMessage {
+    #[serde(rename = "itemUID")]
+    pub item_uid: i32,
+    #[serde(rename = "objectGID")]
+    pub object_gid: u32,
+    #[serde(rename = "objectType")]
+    pub object_type: i32,
+}
+
+/// `ExchangeBidHouseInListUpdatedMessage`, id 4284
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeBidHouseInListUpdatedMessage {
+    #[serde(rename = "itemUID")]
+    pub item_uid: i32,
+    #[serde(rename = "objectGID")]
+    pub object_gid: u32,
+    #[serde(rename = "objectType")]
+    pub object_type: i32,
+    pub effects: Vec<ObjectEffectKind>,
+    pub prices: Vec<u64>,
+}
+
+/// `ExchangeBidHouseItemAddOkMessage`, id 1827
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeBidHouseItemAddOkMessage {
+    #[serde(rename = "itemInfo")]
+    pub item_info: ObjectItemToSellInBid,
+}
+
+/// `ExchangeBidHouseItemRemoveOkMessage`, id 8561
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeBidHouseItemRemoveOkMessage {
+    #[serde(rename = "sellerId")]
+    pub seller_id: i32,
+}
+
+/// `ExchangeBidHouseListMessage`, id 752
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeBidHouseListMessage {
+    #[serde(rename = "objectGID")]
+    pub object_gid: u32,
+    pub follow: bool,
+}
+
+/// `ExchangeBidHousePriceMessage`, id 9348
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeBidHousePriceMessage {
+    #[serde(rename = "objectGID")]
+    pub object_gid: u32,
+}
+
+/// `ExchangeBidHouseSearchMessage`, id 8231
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeBidHouseSearchMessage {
+    #[serde(rename = "objectGID")]
+    pub object_gid: u32,
+    pub follow: bool,
+}
+
+/// `ExchangeBidHouseTypeMessage`, id 6181
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeBidHouseTypeMessage {
+    pub r#type: u32,
+    pub follow: bool,
+}
+
+/// `ExchangeBidHouseUnsoldItemsMessage`, id 4179
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeBidHouseUnsoldItemsMessage {
+    pub items: Vec<ObjectItemGenericQuantity>,
+}
+
+/// `ExchangeBidPriceForSellerMessage`, id 9288
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeBidPriceForSellerMessage {
+    #[serde(rename = "genericId")]
+    pub generic_id: u32,
+    #[serde(rename = "averagePrice")]
+    pub average_price: u64,
+    #[serde(rename = "allIdentical")]
+    pub all_identical: bool,
+    #[serde(rename = "minimalPrices")]
+    pub minimal_prices: Vec<u64>,
+}
+
+/// `ExchangeBidPriceMessage`, id 3774
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeBidPriceMessage {
+    #[serde(rename = "genericId")]
+    pub generic_id: u32,
+    #[serde(rename = "averagePrice")]
+    pub average_price: u64,
+}
+
+/// `ExchangeBidSearchOkMessage`, id 5514
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExchangeBidSearchOkMessage {}
+
+/// `ExchangeBuyMessage`, id 3093
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeBuyMessage {
+    #[serde(rename = "objectToBuyId")]
+    pub object_to_buy_id: u32,
+    pub quantity: u32,
+}
+
+/// `ExchangeBuyOkMessage`, id 8484
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExchangeBuyOkMessage {}
+
+/// `ExchangeCraftCountModifiedMessage`, id 559
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeCraftCountModifiedMessage {
+    pub count: u32,
+}
+
+/// `ExchangeCraftCountRequestMessage`, id 9863
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeCraftCountRequestMessage {
+    pub count: u32,
+}
+
+/// `ExchangeCraftPaymentModificationRequestMessage`, id 6924
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeCraftPaymentModificationRequestMessage {
+    pub quantity: u64,
+}
+
+/// `ExchangeCraftPaymentModifiedMessage`, id 7399
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeCraftPaymentModifiedMessage {
+    #[serde(rename = "goldSum")]
+    pub gold_sum: u64,
+}
+
+/// `ExchangeCraftResultMagicWithObjectDescMessage`, id 4029
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeCraftResultMagicWithObjectDescMessage {
+    #[serde(rename = "craftResult")]
+    pub craft_result: i8,
+    #[serde(rename = "objectInfo")]
+    pub object_info: ObjectItemNotInContainer,
+    #[serde(rename = "magicPoolStatus")]
+    pub magic_pool_status: i8,
+}
+
+/// `ExchangeCraftResultMessage`, id 2633
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeCraftResultMessage {
+    #[serde(rename = "craftResult")]
+    pub craft_result: i8,
+}
+
+/// `ExchangeCraftResultWithObjectDescMessage`, id 2158
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeCraftResultWithObjectDescMessage {
+    #[serde(rename = "craftResult")]
+    pub craft_result: i8,
+    #[serde(rename = "objectInfo")]
+    pub object_info: ObjectItemNotInContainer,
+}
+
+/// `ExchangeCraftResultWithObjectIdMessage`, id 4226
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeCraftResultWithObjectIdMessage {
+    #[serde(rename = "craftResult")]
+    pub craft_result: i8,
+    #[serde(rename = "objectGenericId")]
+    pub object_generic_id: u32,
+}
+
+/// `ExchangeCrafterJobLevelupMessage`, id 2469
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeCrafterJobLevelupMessage {
+    #[serde(rename = "crafterJobLevel")]
+    pub crafter_job_level: i8,
+}
+
+/// `ExchangeErrorMessage`, id 3514
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeErrorMessage {
+    #[serde(rename = "errorType")]
+    pub error_type: i8,
+}
+
+/// `ExchangeHandleMountsMessage`, id 4382
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeHandleMountsMessage {
+    #[serde(rename = "actionType")]
+    pub action_type: i8,
+    #[serde(rename = "ridesId")]
+    pub rides_id: Vec<u32>,
+}
+
+/// `ExchangeIsReadyMessage`, id 9119
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeIsReadyMessage {
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+    pub ready: bool,
+}
+
+/// `ExchangeItemAutoCraftStopedMessage`, id 6145
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeItemAutoCraftStopedMessage {
+    pub reason: i8,
+}
+
+/// `ExchangeKamaModifiedMessage`, id 9828
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeKamaModifiedMessage {
+    pub remote: bool,
+    pub quantity: u64,
+}
+
+/// `ExchangeLeaveMessage`, id 8715
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeLeaveMessage {
+    #[serde(rename = "dialogType")]
+    pub dialog_type: i8,
+    pub success: bool,
+}
+
+/// `ExchangeMoneyMovementInformationMessage`, id 8170
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeMoneyMovementInformationMessage {
+    pub limit: u64,
+}
+
+/// `ExchangeMountFreeFromPaddockMessage`, id 1058
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeMountFreeFromPaddockMessage {
+    pub name: String,
+    #[serde(rename = "worldX")]
+    pub world_x: i16,
+    #[serde(rename = "worldY")]
+    pub world_y: i16,
+    pub liberator: String,
+}
+
+/// `ExchangeMountStableErrorMessage`, id 9689
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExchangeMountStableErrorMessage {}
+
+/// `ExchangeMountSterilizeFromPaddockMessage`, id 4460
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeMountSterilizeFromPaddockMessage {
+    pub name: String,
+    #[serde(rename = "worldX")]
+    pub world_x: i16,
+    #[serde(rename = "worldY")]
+    pub world_y: i16,
+    pub sterilizator: String,
+}
+
+/// `ExchangeMountsPaddockAddMessage`, id 630
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeMountsPaddockAddMessage {
+    #[serde(rename = "mountDescription")]
+    pub mount_description: Vec<MountClientData>,
+}
+
+/// `ExchangeMountsPaddockRemoveMessage`, id 5043
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeMountsPaddockRemoveMessage {
+    #[serde(rename = "mountsId")]
+    pub mounts_id: Vec<u32>,
+}
+
+/// `ExchangeMountsStableAddMessage`, id 3850
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeMountsStableAddMessage {
+    #[serde(rename = "mountDescription")]
+    pub mount_description: Vec<MountClientData>,
+}
+
+/// `ExchangeMountsStableBornAddMessage`, id 9976
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeMountsStableBornAddMessage {
+    #[serde(rename = "mountDescription")]
+    pub mount_description: Vec<MountClientData>,
+}
+
+/// `ExchangeMountsStableRemoveMessage`, id 2507
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeMountsStableRemoveMessage {
+    #[serde(rename = "mountsId")]
+    pub mounts_id: Vec<u32>,
+}
+
+/// `ExchangeMountsTakenFromPaddockMessage`, id 4238
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeMountsTakenFromPaddockMessage {
+    pub name: String,
+    #[serde(rename = "worldX")]
+    pub world_x: i16,
+    #[serde(rename = "worldY")]
+    pub world_y: i16,
+    pub ownername: String,
+}
+
+/// `ExchangeMultiCraftCrafterCanUseHisRessourcesMessage`, id 1269
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeMultiCraftCrafterCanUseHisRessourcesMessage {
+    pub allowed: bool,
+}
+
+/// `ExchangeMultiCraftSetCrafterCanUseHisRessourcesMessage`, id 6299
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeMultiCraftSetCrafterCanUseHisRessourcesMessage {
+    pub allow: bool,
+}
+
+/// `ExchangeObjectAddedMessage`, id 8785
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeObjectAddedMessage {
+    pub remote: bool,
+    pub object: ObjectItem,
+}
+
+/// `ExchangeObjectMessage`, id 321
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeObjectMessage {
+    pub remote: bool,
+}
+
+/// `ExchangeObjectModifiedInBagMessage`, id 7655
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeObjectModifiedInBagMessage {
+    pub remote: bool,
+    pub object: ObjectItem,
+}
+
+/// `ExchangeObjectModifiedMessage`, id 7502
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeObjectModifiedMessage {
+    pub remote: bool,
+    pub object: ObjectItem,
+}
+
+/// `ExchangeObjectModifyPricedMessage`, id 8519
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeObjectModifyPricedMessage {
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+    pub quantity: u32,
+    pub price: u64,
+}
+
+/// `ExchangeObjectMoveKamaMessage`, id 2684
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeObjectMoveKamaMessage {
+    pub quantity: u64,
+}
+
+/// `ExchangeObjectMoveMessage`, id 6631
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeObjectMoveMessage {
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+    pub quantity: u32,
+}
+
+/// `ExchangeObjectMovePricedMessage`, id 4540
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeObjectMovePricedMessage {
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+    pub quantity: u32,
+    pub price: u64,
+}
+
+/// `ExchangeObjectMoveToTabMessage`, id 3518
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeObjectMoveToTabMessage {
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+    pub quantity: u32,
+    #[serde(rename = "tabNumber")]
+    pub tab_number: u32,
+}
+
+/// `ExchangeObjectPutInBagMessage`, id 1085
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeObjectPutInBagMessage {
+    pub remote: bool,
+    pub object: ObjectItem,
+}
+
+/// `ExchangeObjectRemovedFromBagMessage`, id 1969
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeObjectRemovedFromBagMessage {
+    pub remote: bool,
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+}
+
+/// `ExchangeObjectRemovedMessage`, id 8079
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeObjectRemovedMessage {
+    pub remote: bool,
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+}
+
+/// `ExchangeObjectTransfertAllFromInvMessage`, id 1264
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExchangeObjectTransfertAllFromInvMessage {}
+
+/// `ExchangeObjectTransfertAllToInvMessage`, id 1184
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExchangeObjectTransfertAllToInvMessage {}
+
+/// `ExchangeObjectTransfertExistingFromInvMessage`, id 5965
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExchangeObjectTransfertExistingFromInvMessage {}
+
+/// `ExchangeObjectTransfertExistingToInvMessage`, id 9982
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExchangeObjectTransfertExistingToInvMessage {}
+
+/// `ExchangeObjectTransfertListFromInvMessage`, id 6238
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeObjectTransfertListFromInvMessage {
+    pub ids: Vec<u32>,
+}
+
+/// `ExchangeObjectTransfertListToInvMessage`, id 7242
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeObjectTransfertListToInvMessage {
+    pub ids: Vec<u32>,
+}
+
+/// `ExchangeObjectTransfertListWithQuantityToInvMessage`, id 5461
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeObjectTransfertListWithQuantityToInvMessage {
+    pub ids: Vec<u32>,
+    pub qtys: Vec<u32>,
+}
+
+/// `ExchangeObjectUseInWorkshopMessage`, id 6792
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeObjectUseInWorkshopMessage {
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+    pub quantity: u32,
+}
+
+/// `ExchangeObjectsAddedMessage`, id 2916
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeObjectsAddedMessage {
+    pub remote: bool,
+    pub object: Vec<ObjectItem>,
+}
+
+/// `ExchangeObjectsModifiedMessage`, id 4641
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeObjectsModifiedMessage {
+    pub remote: bool,
+    pub object: Vec<ObjectItem>,
+}
+
+/// `ExchangeObjectsRemovedMessage`, id 4341
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeObjectsRemovedMessage {
+    pub remote: bool,
+    #[serde(rename = "objectUID")]
+    pub object_uid: Vec<u32>,
+}
+
+/// `ExchangeOfflineSoldItemsMessage`, id 1127
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeOfflineSoldItemsMessage {
+    #[serde(rename = "bidHouseItems")]
+    pub bid_house_items: Vec<ObjectItemQuantityPriceDateEffects>,
+}
+
+/// `ExchangeOkMultiCraftMessage`, id 8117
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeOkMultiCraftMessage {
+    #[serde(rename = "initiatorId")]
+    pub initiator_id: u64,
+    #[serde(rename = "otherId")]
+    pub other_id: u64,
+    pub role: i8,
+}
+
+/// `ExchangePlayerMultiCraftRequestMessage`, id 9588
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangePlayerMultiCraftRequestMessage {
+    #[serde(rename = "exchangeType")]
+    pub exchange_type: i8,
+    pub target: u64,
+    #[serde(rename = "skillId")]
+    pub skill_id: u32,
+}
+
+/// `ExchangePlayerRequestMessage`, id 2587
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangePlayerRequestMessage {
+    #[serde(rename = "exchangeType")]
+    pub exchange_type: i8,
+    pub target: u64,
+}
+
+/// `ExchangePodsModifiedMessage`, id 8862
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangePodsModifiedMessage {
+    pub remote: bool,
+    #[serde(rename = "currentWeight")]
+    pub current_weight: u32,
+    #[serde(rename = "maxWeight")]
+    pub max_weight: u32,
+}
+
+/// `ExchangeReadyMessage`, id 2256
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeReadyMessage {
+    pub ready: bool,
+    pub step: u16,
+}
+
+/// `ExchangeReplayStopMessage`, id 780
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExchangeReplayStopMessage {}
+
+/// `ExchangeRequestMessage`, id 7721
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeRequestMessage {
+    #[serde(rename = "exchangeType")]
+    pub exchange_type: i8,
+}
+
+/// `ExchangeRequestOnMountStockMessage`, id 1500
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExchangeRequestOnMountStockMessage {}
+
+/// `ExchangeRequestOnTaxCollectorMessage`, id 1349
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExchangeRequestOnTaxCollectorMessage {}
+
+/// `ExchangeRequestedMessage`, id 4365
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeRequestedMessage {
+    #[serde(rename = "exchangeType")]
+    pub exchange_type: i8,
+}
+
+/// `ExchangeRequestedTradeMessage`, id 9062
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeRequestedTradeMessage {
+    #[serde(rename = "exchangeType")]
+    pub exchange_type: i8,
+    pub source: u64,
+    pub target: u64,
+}
+
+/// `ExchangeSellMessage`, id 3049
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeSellMessage {
+    #[serde(rename = "objectToSellId")]
+    pub object_to_sell_id: u32,
+    pub quantity: u32,
+}
+
+/// `ExchangeSellOkMessage`, id 1656
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExchangeSellOkMessage {}
+
+/// `ExchangeSetCraftRecipeMessage`, id 6304
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeSetCraftRecipeMessage {
+    #[serde(rename = "objectGID")]
+    pub object_gid: u32,
+}
+
+/// `ExchangeStartOkCraftMessage`, id 3016
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExchangeStartOkCraftMessage {}
+
+/// `ExchangeStartOkCraftWithInformationMessage`, id 6872
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeStartOkCraftWithInformationMessage {
+    #[serde(rename = "skillId")]
+    pub skill_id: u32,
+}
+
+/// `ExchangeStartOkEvolutiveObjectRecycleTradeMessage`, id 2389
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExchangeStartOkEvolutiveObjectRecycleTradeMessage {}
+
+/// `ExchangeStartOkJobIndexMessage`, id 9770
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeStartOkJobIndexMessage {
+    pub jobs: Vec<u32>,
+}
+
+/// `ExchangeStartOkMountMessage`, id 5527
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeStartOkMountMessage {
+    #[serde(rename = "stabledMountsDescription")]
+    pub stabled_mounts_description: Vec<MountClientData>,
+    #[serde(rename = "paddockedMountsDescription")]
+    pub paddocked_mounts_description: Vec<MountClientData>,
+}
+
+/// `ExchangeStartOkMountWithOutPaddockMessage`, id 715
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeStartOkMountWithOutPaddockMessage {
+    #[serde(rename = "stabledMountsDescription")]
+    pub stabled_mounts_description: Vec<MountClientData>,
+}
+
+/// `ExchangeStartOkMulticraftCrafterMessage`, id 6019
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeStartOkMulticraftCrafterMessage {
+    #[serde(rename = "skillId")]
+    pub skill_id: u32,
+}
+
+/// `ExchangeStartOkMulticraftCustomerMessage`, id 6847
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeStartOkMulticraftCustomerMessage {
+    #[serde(rename = "skillId")]
+    pub skill_id: u32,
+    #[serde(rename = "crafterJobLevel")]
+    pub crafter_job_level: i8,
+}
+
+/// `ExchangeStartOkNpcShopMessage`, id 5405
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeStartOkNpcShopMessage {
+    #[serde(rename = "npcSellerId", deserialize_with = "float")]
+    pub npc_seller_id: f64,
+    #[serde(rename = "tokenId")]
+    pub token_id: u32,
+    #[serde(rename = "objectsInfos")]
+    pub objects_infos: Vec<ObjectItemToSellInNpcShop>,
+}
+
+/// `ExchangeStartOkNpcTradeMessage`, id 7225
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeStartOkNpcTradeMessage {
+    #[serde(rename = "npcId", deserialize_with = "float")]
+    pub npc_id: f64,
+}
+
+/// `ExchangeStartOkRecycleTradeMessage`, id 5825
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeStartOkRecycleTradeMessage {
+    #[serde(rename = "percentToPrism")]
+    pub percent_to_prism: i16,
+    #[serde(rename = "percentToPlayer")]
+    pub percent_to_player: i16,
+    #[serde(rename = "adjacentSubareaPossessed")]
+    pub adjacent_subarea_possessed: Vec<i32>,
+    #[serde(rename = "adjacentSubareaUnpossessed")]
+    pub adjacent_subarea_unpossessed: Vec<i32>,
+}
+
+/// `ExchangeStartOkRunesTradeMessage`, id 1502
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExchangeStartOkRunesTradeMessage {}
+
+/// `ExchangeStartedBidBuyerMessage`, id 6287
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeStartedBidBuyerMessage {
+    #[serde(rename = "buyerDescriptor")]
+    pub buyer_descriptor: SellerBuyerDescriptor,
+}
+
+/// `ExchangeStartedBidSellerMessage`, id 8658
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeStartedBidSellerMessage {
+    #[serde(rename = "sellerDescriptor")]
+    pub seller_descriptor: SellerBuyerDescriptor,
+    #[serde(rename = "objectsInfos")]
+    pub objects_infos: Vec<ObjectItemToSellInBid>,
+}
+
+/// `ExchangeStartedMessage`, id 4023
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeStartedMessage {
+    #[serde(rename = "exchangeType")]
+    pub exchange_type: i8,
+}
+
+/// `ExchangeStartedMountStockMessage`, id 9882
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeStartedMountStockMessage {
+    #[serde(rename = "objectsInfos")]
+    pub objects_infos: Vec<ObjectItem>,
+}
+
+/// `ExchangeStartedTaxCollectorEquipmentMessage`, id 9029
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeStartedTaxCollectorEquipmentMessage {
+    pub information: TaxCollectorInformations,
+}
+
+/// `ExchangeStartedTaxCollectorShopMessage`, id 7646
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeStartedTaxCollectorShopMessage {
+    pub objects: Vec<ObjectItem>,
+    pub kamas: u64,
+}
+
+/// `ExchangeStartedWithMultiTabStorageMessage`, id 4941
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeStartedWithMultiTabStorageMessage {
+    #[serde(rename = "exchangeType")]
+    pub exchange_type: i8,
+    #[serde(rename = "storageMaxSlot")]
+    pub storage_max_slot: u32,
+    #[serde(rename = "tabNumber")]
+    pub tab_number: u32,
+}
+
+/// `ExchangeStartedWithPodsMessage`, id 1672
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeStartedWithPodsMessage {
+    #[serde(rename = "exchangeType")]
+    pub exchange_type: i8,
+    #[serde(rename = "firstCharacterId", deserialize_with = "float")]
+    pub first_character_id: f64,
+    #[serde(rename = "firstCharacterCurrentWeight")]
+    pub first_character_current_weight: u32,
+    #[serde(rename = "firstCharacterMaxWeight")]
+    pub first_character_max_weight: u32,
+    #[serde(rename = "secondCharacterId", deserialize_with = "float")]
+    pub second_character_id: f64,
+    #[serde(rename = "secondCharacterCurrentWeight")]
+    pub second_character_current_weight: u32,
+    #[serde(rename = "secondCharacterMaxWeight")]
+    pub second_character_max_weight: u32,
+}
+
+/// `ExchangeStartedWithStorageMessage`, id 7720
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeStartedWithStorageMessage {
+    #[serde(rename = "exchangeType")]
+    pub exchange_type: i8,
+    #[serde(rename = "storageMaxSlot")]
+    pub storage_max_slot: u32,
+}
+
+/// `ExchangeStoppedMessage`, id 2319
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeStoppedMessage {
+    pub id: u64,
+}
+
+/// `ExchangeTaxCollectorGetMessage`, id 2438
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeTaxCollectorGetMessage {
+    #[serde(rename = "collectorName")]
+    pub collector_name: String,
+    #[serde(rename = "worldX")]
+    pub world_x: i16,
+    #[serde(rename = "worldY")]
+    pub world_y: i16,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+    #[serde(rename = "userName")]
+    pub user_name: String,
+    #[serde(rename = "callerId")]
+    pub caller_id: u64,
+    #[serde(rename = "callerName")]
+    pub caller_name: String,
+    pub pods: u16,
+    #[serde(rename = "objectsInfos")]
+    pub objects_infos: Vec<ObjectItemGenericQuantity>,
+    pub look: EntityLook,
+}
+
+/// `ExchangeTypesExchangerDescriptionForUserMessage`, id 8810
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeTypesExchangerDescriptionForUserMessage {
+    #[serde(rename = "objectType")]
+    pub object_type: i32,
+    #[serde(rename = "typeDescription")]
+    pub type_description: Vec<u32>,
+}
+
+/// `ExchangeTypesItemsExchangerDescriptionForUserMessage`, id 6116
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeTypesItemsExchangerDescriptionForUserMessage {
+    #[serde(rename = "objectGID")]
+    pub object_gid: u32,
+    #[serde(rename = "objectType")]
+    pub object_type: i32,
+    #[serde(rename = "itemTypeDescriptions")]
+    pub item_type_descriptions: Vec<BidExchangerObjectInfo>,
+}
+
+/// `ExchangeWaitingResultMessage`, id 6531
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeWaitingResultMessage {
+    pub bwait: bool,
+}
+
+/// `ExchangeWeightMessage`, id 1988
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExchangeWeightMessage {
+    #[serde(rename = "currentWeight")]
+    pub current_weight: u32,
+    #[serde(rename = "maxWeight")]
+    pub max_weight: u32,
+}
+
+/// `ExitHavenBagRequestMessage`, id 9019
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExitHavenBagRequestMessage {}
+
+/// `ExtendedBreachBranch`, id 6771
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExtendedBreachBranch {
+    pub room: i8,
+    pub element: i32,
+    pub bosses: Vec<MonsterInGroupLightInformations>,
+    #[serde(deserialize_with = "float")]
+    pub map: f64,
+    pub score: i16,
+    #[serde(rename = "relativeScore")]
+    pub relative_score: i16,
+    pub monsters: Vec<MonsterInGroupLightInformations>,
+    pub rewards: Vec<BreachReward>,
+    pub modifier: u32,
+    pub prize: u32,
+}
+
+/// `ExtendedLockedBreachBranch`, id 5890
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExtendedLockedBreachBranch {
+    pub room: i8,
+    pub element: i32,
+    pub bosses: Vec<MonsterInGroupLightInformations>,
+    #[serde(deserialize_with = "float")]
+    pub map: f64,
+    pub score: i16,
+    #[serde(rename = "relativeScore")]
+    pub relative_score: i16,
+    pub monsters: Vec<MonsterInGroupLightInformations>,
+    pub rewards: Vec<BreachReward>,
+    pub modifier: u32,
+    pub prize: u32,
+    #[serde(rename = "unlockPrice")]
+    pub unlock_price: u32,
+}
+
+/// `FightAllianceTeamInformations`, id 8140
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightAllianceTeamInformations {
+    #[serde(rename = "teamId")]
+    pub team_id: i8,
+    #[serde(rename = "leaderId", deserialize_with = "float")]
+    pub leader_id: f64,
+    #[serde(rename = "teamSide")]
+    pub team_side: i8,
+    #[serde(rename = "teamTypeId")]
+    pub team_type_id: i8,
+    #[serde(rename = "nbWaves")]
+    pub nb_waves: i8,
+    #[serde(rename = "teamMembers")]
+    pub team_members: Vec<FightTeamMemberInformationsKind>,
+    pub relation: i8,
+}
+
+/// `FightCommonInformations`, id 8687
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightCommonInformations {
+    #[serde(rename = "fightId")]
+    pub fight_id: u16,
+    #[serde(rename = "fightType")]
+    pub fight_type: i8,
+    #[serde(rename = "fightTeams")]
+    pub fight_teams: Vec<FightTeamInformationsKind>,
+    #[serde(rename = "fightTeamsPositions")]
+    pub fight_teams_positions: Vec<u16>,
+    #[serde(rename = "fightTeamsOptions")]
+    pub fight_teams_options: Vec<FightOptionsInformations>,
+}
+
+/// `FightDetailedTemporaryBoostEffect`, id 1155
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightDetailedTemporaryBoostEffect {
+    pub uid: u32,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "turnDuration")]
+    pub turn_duration: i16,
+    pub dispelable: i8,
+    #[serde(rename = "spellId")]
+    pub spell_id: u16,
+    #[serde(rename = "effectId")]
+    pub effect_id: u32,
+    #[serde(rename = "parentBoostUid")]
+    pub parent_boost_uid: u32,
+    pub delta: i32,
+    pub param1: i32,
+    pub param2: i32,
+    pub param3: i32,
+}
+
+/// `FightDispellableEffectExtendedInformations`, id 9482
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightDispellableEffectExtendedInformations {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    pub effect: Box<AbstractFightDispellableEffectKind>,
+}
+
+/// `FightEntityDispositionInformations`, id 6103
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightEntityDispositionInformations {
+    #[serde(rename = "cellId")]
+    pub cell_id: i16,
+    pub direction: i8,
+    #[serde(rename = "carryingCharacterId", deserialize_with = "float")]
+    pub carrying_character_id: f64,
+}
+
+/// `FightExternalInformations`, id 5040
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightExternalInformations {
+    #[serde(rename = "fightId")]
+    pub fight_id: u16,
+    #[serde(rename = "fightType")]
+    pub fight_type: i8,
+    #[serde(rename = "fightStart")]
+    pub fight_start: i32,
+    #[serde(rename = "fightSpectatorLocked")]
+    pub fight_spectator_locked: bool,
+    #[serde(rename = "fightTeams")]
+    pub fight_teams: Vec<FightTeamLightInformations>,
+    #[serde(rename = "fightTeamsOptions")]
+    pub fight_teams_options: Vec<FightOptionsInformations>,
+}
+
+/// `FightLoot`, id 5833
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightLoot {
+    pub objects: Vec<FightLootObject>,
+    pub kamas: u64,
+}
+
+/// `FightLootObject`, id 1967
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightLootObject {
+    #[serde(rename = "objectId")]
+    pub object_id: i32,
+    pub quantity: i32,
+    #[serde(rename = "priorityHint")]
+    pub priority_hint: i32,
+}
+
+/// `FightOptionsInformations`, id 2952
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightOptionsInformations {
+    #[serde(rename = "isSecret")]
+    pub is_secret: bool,
+    #[serde(rename = "isRestrictedToPartyOnly")]
+    pub is_restricted_to_party_only: bool,
+    #[serde(rename = "isClosed")]
+    pub is_closed: bool,
+    #[serde(rename = "isAskingForHelp")]
+    pub is_asking_for_help: bool,
+}
+
+/// `FightPhase`, id 9523
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightPhase {
+    pub phase: i8,
+    #[serde(rename = "phaseEndTimeStamp", deserialize_with = "float")]
+    pub phase_end_time_stamp: f64,
+}
+
+/// `FightResultAdditionalData`, id 658
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FightResultAdditionalData {}
+
+/// `FightResultExperienceData`, id 9334
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightResultExperienceData {
+    #[serde(rename = "showExperience")]
+    pub show_experience: bool,
+    #[serde(rename = "showExperienceLevelFloor")]
+    pub show_experience_level_floor: bool,
+    #[serde(rename = "showExperienceNextLevelFloor")]
+    pub show_experience_next_level_floor: bool,
+    #[serde(rename = "showExperienceFightDelta")]
+    pub show_experience_fight_delta: bool,
+    #[serde(rename = "showExperienceForGuild")]
+    pub show_experience_for_guild: bool,
+    #[serde(rename = "showExperienceForMount")]
+    pub show_experience_for_mount: bool,
+    #[serde(rename = "isIncarnationExperience")]
+    pub is_incarnation_experience: bool,
+    pub experience: u64,
+    #[serde(rename = "experienceLevelFloor")]
+    pub experience_level_floor: u64,
+    #[serde(rename = "experienceNextLevelFloor")]
+    pub experience_next_level_floor: u64,
+    #[serde(rename = "experienceFightDelta")]
+    pub experience_fight_delta: u64,
+    #[serde(rename = "experienceForGuild")]
+    pub experience_for_guild: u64,
+    #[serde(rename = "experienceForMount")]
+    pub experience_for_mount: u64,
+    #[serde(rename = "rerollExperienceMul")]
+    pub reroll_experience_mul: i8,
+}
+
+/// `FightResultFighterListEntry`, id 8223
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightResultFighterListEntry {
+    pub outcome: u16,
+    pub wave: i8,
+    pub rewards: FightLoot,
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+    pub alive: bool,
+}
+
+/// `FightResultListEntry`, id 3535
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightResultListEntry {
+    pub outcome: u16,
+    pub wave: i8,
+    pub rewards: FightLoot,
+}
+
+/// `FightResultMutantListEntry`, id 5503
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightResultMutantListEntry {
+    pub outcome: u16,
+    pub wave: i8,
+    pub rewards: FightLoot,
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+    pub alive: bool,
+    pub level: u16,
+}
+
+/// `FightResultPlayerListEntry`, id 1387
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightResultPlayerListEntry {
+    pub outcome: u16,
+    pub wave: i8,
+    pub rewards: FightLoot,
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+    pub alive: bool,
+    pub level: u16,
+    pub additional: Vec<FightResultAdditionalDataKind>,
+}
+
+/// `FightResultPvpData`, id 192
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightResultPvpData {
+    pub grade: i8,
+    #[serde(rename = "minHonorForGrade")]
+    pub min_honor_for_grade: u16,
+    #[serde(rename = "maxHonorForGrade")]
+    pub max_honor_for_grade: u16,
+    pub honor: u16,
+    #[serde(rename = "honorDelta")]
+    pub honor_delta: u16,
+}
+
+/// `FightResultTaxCollectorListEntry`, id 2302
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightResultTaxCollectorListEntry {
+    pub outcome: u16,
+    pub wave: i8,
+    pub rewards: FightLoot,
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+    pub alive: bool,
+    #[serde(rename = "allianceInfo")]
+    pub alliance_info: BasicAllianceInformations,
+}
+
+/// `FightStartingPositions`, id 9111
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightStartingPositions {
+    #[serde(rename = "positionsForChallengers")]
+    pub positions_for_challengers: Vec<u16>,
+    #[serde(rename = "positionsForDefenders")]
+    pub positions_for_defenders: Vec<u16>,
+}
+
+/// `FightTeamInformations`, id 3054
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightTeamInformations {
+    #[serde(rename = "teamId")]
+    pub team_id: i8,
+    #[serde(rename = "leaderId", deserialize_with = "float")]
+    pub leader_id: f64,
+    #[serde(rename = "teamSide")]
+    pub team_side: i8,
+    #[serde(rename = "teamTypeId")]
+    pub team_type_id: i8,
+    #[serde(rename = "nbWaves")]
+    pub nb_waves: i8,
+    #[serde(rename = "teamMembers")]
+    pub team_members: Vec<FightTeamMemberInformationsKind>,
+}
+
+/// `FightTeamLightInformations`, id 6934
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightTeamLightInformations {
+    #[serde(rename = "teamId")]
+    pub team_id: i8,
+    #[serde(rename = "leaderId", deserialize_with = "float")]
+    pub leader_id: f64,
+    #[serde(rename = "teamSide")]
+    pub team_side: i8,
+    #[serde(rename = "teamTypeId")]
+    pub team_type_id: i8,
+    #[serde(rename = "nbWaves")]
+    pub nb_waves: i8,
+    #[serde(rename = "hasFriend")]
+    pub has_friend: bool,
+    #[serde(rename = "hasGuildMember")]
+    pub has_guild_member: bool,
+    #[serde(rename = "hasAllianceMember")]
+    pub has_alliance_member: bool,
+    #[serde(rename = "hasGroupMember")]
+    pub has_group_member: bool,
+    #[serde(rename = "hasMyTaxCollector")]
+    pub has_my_tax_collector: bool,
+    #[serde(rename = "teamMembersCount")]
+    pub team_members_count: i8,
+    #[serde(rename = "meanLevel")]
+    pub mean_level: u32,
+}
+
+/// `FightTeamMemberCharacterInformations`, id 3781
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightTeamMemberCharacterInformations {
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+    pub name: String,
+    pub level: u16,
+}
+
+/// `FightTeamMemberEntityInformation`, id 3883
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightTeamMemberEntityInformation {
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+    #[serde(rename = "entityModelId")]
+    pub entity_model_id: i8,
+    pub level: u16,
+    #[serde(rename = "masterId", deserialize_with = "float")]
+    pub master_id: f64,
+}
+
+/// `FightTeamMemberInformations`, id 6092
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightTeamMemberInformations {
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+}
+
+/// `FightTeamMemberMonsterInformations`, id 9107
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightTeamMemberMonsterInformations {
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+    #[serde(rename = "monsterId")]
+    pub monster_id: i32,
+    pub grade: i8,
+}
+
+/// `FightTeamMemberTaxCollectorInformations`, id 874
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightTeamMemberTaxCollectorInformations {
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+    #[serde(rename = "firstNameId")]
+    pub first_name_id: u16,
+    #[serde(rename = "lastNameId")]
+    pub last_name_id: u16,
+    #[serde(rename = "groupId")]
+    pub group_id: u32,
+    #[serde(deserialize_with = "float")]
+    pub uid: f64,
+}
+
+/// `FightTeamMemberWithAllianceCharacterInformations`, id 7988
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightTeamMemberWithAllianceCharacterInformations {
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+    pub name: String,
+    pub level: u16,
+    #[serde(rename = "allianceInfos")]
+    pub alliance_infos: BasicAllianceInformations,
+}
+
+/// `FightTemporaryBoostEffect`, id 3061
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightTemporaryBoostEffect {
+    pub uid: u32,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "turnDuration")]
+    pub turn_duration: i16,
+    pub dispelable: i8,
+    #[serde(rename = "spellId")]
+    pub spell_id: u16,
+    #[serde(rename = "effectId")]
+    pub effect_id: u32,
+    #[serde(rename = "parentBoostUid")]
+    pub parent_boost_uid: u32,
+    pub delta: i32,
+}
+
+/// `FightTemporaryBoostStateEffect`, id 2054
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightTemporaryBoostStateEffect {
+    pub uid: u32,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "turnDuration")]
+    pub turn_duration: i16,
+    pub dispelable: i8,
+    #[serde(rename = "spellId")]
+    pub spell_id: u16,
+    #[serde(rename = "effectId")]
+    pub effect_id: u32,
+    #[serde(rename = "parentBoostUid")]
+    pub parent_boost_uid: u32,
+    pub delta: i32,
+    #[serde(rename = "stateId")]
+    pub state_id: i16,
+}
+
+/// `FightTemporaryBoostWeaponDamagesEffect`, id 8504
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightTemporaryBoostWeaponDamagesEffect {
+    pub uid: u32,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "turnDuration")]
+    pub turn_duration: i16,
+    pub dispelable: i8,
+    #[serde(rename = "spellId")]
+    pub spell_id: u16,
+    #[serde(rename = "effectId")]
+    pub effect_id: u32,
+    #[serde(rename = "parentBoostUid")]
+    pub parent_boost_uid: u32,
+    pub delta: i32,
+    #[serde(rename = "weaponTypeId")]
+    pub weapon_type_id: i16,
+}
+
+/// `FightTemporarySpellBoostEffect`, id 5892
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightTemporarySpellBoostEffect {
+    pub uid: u32,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "turnDuration")]
+    pub turn_duration: i16,
+    pub dispelable: i8,
+    #[serde(rename = "spellId")]
+    pub spell_id: u16,
+    #[serde(rename = "effectId")]
+    pub effect_id: u32,
+    #[serde(rename = "parentBoostUid")]
+    pub parent_boost_uid: u32,
+    pub delta: i32,
+    #[serde(rename = "boostedSpellId")]
+    pub boosted_spell_id: u16,
+}
+
+/// `FightTemporarySpellImmunityEffect`, id 273
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightTemporarySpellImmunityEffect {
+    pub uid: u32,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "turnDuration")]
+    pub turn_duration: i16,
+    pub dispelable: i8,
+    #[serde(rename = "spellId")]
+    pub spell_id: u16,
+    #[serde(rename = "effectId")]
+    pub effect_id: u32,
+    #[serde(rename = "parentBoostUid")]
+    pub parent_boost_uid: u32,
+    #[serde(rename = "immuneSpellId")]
+    pub immune_spell_id: i32,
+}
+
+/// `FightTriggeredEffect`, id 7243
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FightTriggeredEffect {
+    pub uid: u32,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "turnDuration")]
+    pub turn_duration: i16,
+    pub dispelable: i8,
+    #[serde(rename = "spellId")]
+    pub spell_id: u16,
+    #[serde(rename = "effectId")]
+    pub effect_id: u32,
+    #[serde(rename = "parentBoostUid")]
+    pub parent_boost_uid: u32,
+    pub param1: i32,
+    pub param2: i32,
+    pub param3: i32,
+    pub delay: i16,
+}
+
+/// `FighterStatsListMessage`, id 193
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FighterStatsListMessage {
+    pub stats: CharacterCharacteristicsInformations,
+}
+
+/// `FinishMoveInformations`, id 7706
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FinishMoveInformations {
+    #[serde(rename = "finishMoveId")]
+    pub finish_move_id: i32,
+    #[serde(rename = "finishMoveState")]
+    pub finish_move_state: bool,
+}
+
+/// `FinishMoveListMessage`, id 4314
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FinishMoveListMessage {
+    #[serde(rename = "finishMoves")]
+    pub finish_moves: Vec<FinishMoveInformations>,
+}
+
+/// `FinishMoveListRequestMessage`, id 8401
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FinishMoveListRequestMessage {}
+
+/// `FinishMoveSetRequestMessage`, id 8329
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FinishMoveSetRequestMessage {
+    #[serde(rename = "finishMoveId")]
+    pub finish_move_id: i32,
+    #[serde(rename = "finishMoveState")]
+    pub finish_move_state: bool,
+}
+
+/// `FocusedExchangeReadyMessage`, id 957
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FocusedExchangeReadyMessage {
+    pub ready: bool,
+    pub step: u16,
+    #[serde(rename = "focusActionId")]
+    pub focus_action_id: u32,
+}
+
+/// `FollowQuestObjectiveRequestMessage`, id 4169
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FollowQuestObjectiveRequestMessage {
+    #[serde(rename = "questId")]
+    pub quest_id: u16,
+    #[serde(rename = "objectiveId")]
+    pub objective_id: i16,
+}
+
+/// `FollowedQuestsMessage`, id 7010
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FollowedQuestsMessage {
+    pub quests: Vec<QuestActiveDetailedInformations>,
+}
+
+/// `ForceAccountErrorMessage`, id 9942
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ForceAccountErrorMessage {}
+
+/// `ForceAccountMessage`, id 1607
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ForceAccountMessage {
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+}
+
+/// `ForceAccountStatusMessage`, id 8363
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ForceAccountStatusMessage {
+    pub force: bool,
+    #[serde(rename = "forcedAccountId")]
+    pub forced_account_id: i32,
+    #[serde(rename = "forcedNickname")]
+    pub forced_nickname: String,
+    #[serde(rename = "forcedTag")]
+    pub forced_tag: String,
+}
+
+/// `ForgettableSpellClientActionMessage`, id 3647
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ForgettableSpellClientActionMessage {
+    #[serde(rename = "spellId")]
+    pub spell_id: i32,
+    pub action: i8,
+}
+
+/// `ForgettableSpellDeleteMessage`, id 4817
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ForgettableSpellDeleteMessage {
+    pub reason: i8,
+    pub spells: Vec<i32>,
+}
+
+/// `ForgettableSpellEquipmentSlotsMessage`, id 891
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ForgettableSpellEquipmentSlotsMessage {
+    pub quantity: u16,
+}
+
+/// `ForgettableSpellItem`, id 8758
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ForgettableSpellItem {
+    #[serde(rename = "spellId")]
+    pub spell_id: i32,
+    #[serde(rename = "spellLevel")]
+    pub spell_level: i16,
+    pub available: bool,
+}
+
+/// `ForgettableSpellListUpdateMessage`, id 1165
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ForgettableSpellListUpdateMessage {
+    pub action: i8,
+    pub spells: Vec<ForgettableSpellItem>,
+}
+
+/// `ForgettableSpellsPreset`, id 1546
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ForgettableSpellsPreset {
+    pub id: i16,
+    #[serde(rename = "baseSpellsPreset")]
+    pub base_spells_preset: SpellsPreset,
+    #[serde(rename = "forgettableSpells")]
+    pub forgettable_spells: Vec<SpellForPreset>,
+}
+
+/// `FriendAddFailureMessage`, id 1469
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FriendAddFailureMessage {
+    pub reason: i8,
+}
+
+/// `FriendAddRequestMessage`, id 8880
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FriendAddRequestMessage {
+    pub target: Box<AbstractPlayerSearchInformationKind>,
+}
+
+/// `FriendAddedMessage`, id 774
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FriendAddedMessage {
+    #[serde(rename = "friendAdded")]
+    pub friend_added: Box<FriendInformationsKind>,
+}
+
+/// `FriendDeleteRequestMessage`, id 6309
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FriendDeleteRequestMessage {
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+}
+
+/// `FriendDeleteResultMessage`, id 2320
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FriendDeleteResultMessage {
+    pub success: bool,
+    pub tag: AccountTagInformation,
+}
+
+/// `FriendGuildSetWarnOnAchievementCompleteMessage`, id 7085
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FriendGuildSetWarnOnAchievementCompleteMessage {
+    pub enable: bool,
+}
+
+/// `FriendGuildWarnOnAchievementCompleteStateMessage`, id 5656
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FriendGuildWarnOnAchievementCompleteStateMessage {
+    pub enable: bool,
+}
+
+/// `FriendInformations`, id 6157
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FriendInformations {
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+    #[serde(rename = "accountTag")]
+    pub account_tag: AccountTagInformation,
+    #[serde(rename = "playerState")]
+    pub player_state: i8,
+    #[serde(rename = "lastConnection")]
+    pub last_connection: u16,
+    #[serde(rename = "achievementPoints")]
+    pub achievement_points: i32,
+    #[serde(rename = "leagueId")]
+    pub league_id: u16,
+    #[serde(rename = "ladderPosition")]
+    pub ladder_position: i32,
+}
+
+/// `FriendJoinRequestMessage`, id 2517
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FriendJoinRequestMessage {
+    pub target: Box<AbstractPlayerSearchInformationKind>,
+}
+
+/// `FriendOnlineInformations`, id 476
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FriendOnlineInformations {
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+    #[serde(rename = "accountTag")]
+    pub account_tag: AccountTagInformation,
+    #[serde(rename = "playerState")]
+    pub player_state: i8,
+    #[serde(rename = "lastConnection")]
+    pub last_connection: u16,
+    #[serde(rename = "achievementPoints")]
+    pub achievement_points: i32,
+    #[serde(rename = "leagueId")]
+    pub league_id: u16,
+    #[serde(rename = "ladderPosition")]
+    pub ladder_position: i32,
+    pub sex: bool,
+    #[serde(rename = "havenBagShared")]
+    pub haven_bag_shared: bool,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+    #[serde(rename = "playerName")]
+    pub player_name: String,
+    pub level: u16,
+    #[serde(rename = "alignmentSide")]
+    pub alignment_side: i8,
+    pub breed: i8,
+    #[serde(rename = "guildInfo")]
+    pub guild_info: GuildInformations,
+    #[serde(rename = "moodSmileyId")]
+    pub mood_smiley_id: u16,
+    pub status: Box<PlayerStatusKind>,
+}
+
+/// `FriendSetStatusShareMessage`, id 3805
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FriendSetStatusShareMessage {
+    pub share: bool,
+}
+
+/// `FriendSetWarnOnConnectionMessage`, id 5631
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FriendSetWarnOnConnectionMessage {
+    pub enable: bool,
+}
+
+/// `FriendSetWarnOnLevelGainMessage`, id 5222
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FriendSetWarnOnLevelGainMessage {
+    pub enable: bool,
+}
+
+/// `FriendSpouseFollowWithCompassRequestMessage`, id 5274
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FriendSpouseFollowWithCompassRequestMessage {
+    pub enable: bool,
+}
+
+/// `FriendSpouseInformations`, id 2989
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FriendSpouseInformations {
+    #[serde(rename = "spouseAccountId")]
+    pub spouse_account_id: i32,
+    #[serde(rename = "spouseId")]
+    pub spouse_id: u64,
+    #[serde(rename = "spouseName")]
+    pub spouse_name: String,
+    #[serde(rename = "spouseLevel")]
+    pub spouse_level: u16,
+    pub breed: i8,
+    pub sex: i8,
+    #[serde(rename = "spouseEntityLook")]
+    pub spouse_entity_look: EntityLook,
+    #[serde(rename = "guildInfo")]
+    pub guild_info: GuildInformations,
+    #[serde(rename = "alignmentSide")]
+    pub alignment_side: i8,
+}
+
+/// `FriendSpouseJoinRequestMessage`, id 1302
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FriendSpouseJoinRequestMessage {}
+
+/// `FriendSpouseOnlineInformations`, id 1391
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FriendSpouseOnlineInformations {
+    #[serde(rename = "spouseAccountId")]
+    pub spouse_account_id: i32,
+    #[serde(rename = "spouseId")]
+    pub spouse_id: u64,
+    #[serde(rename = "spouseName")]
+    pub spouse_name: String,
+    #[serde(rename = "spouseLevel")]
+    pub spouse_level: u16,
+    pub breed: i8,
+    pub sex: i8,
+    #[serde(rename = "spouseEntityLook")]
+    pub spouse_entity_look: EntityLook,
+    #[serde(rename = "guildInfo")]
+    pub guild_info: GuildInformations,
+    #[serde(rename = "alignmentSide")]
+    pub alignment_side: i8,
+    #[serde(rename = "inFight")]
+    pub in_fight: bool,
+    #[serde(rename = "followSpouse")]
+    pub follow_spouse: bool,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+}
+
+/// `FriendStatusShareStateMessage`, id 9682
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FriendStatusShareStateMessage {
+    pub share: bool,
+}
+
+/// `FriendUpdateMessage`, id 8257
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FriendUpdateMessage {
+    #[serde(rename = "friendUpdated")]
+    pub friend_updated: Box<FriendInformationsKind>,
+}
+
+/// `FriendWarnOnConnectionStateMessage`, id 4810
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FriendWarnOnConnectionStateMessage {
+    pub enable: bool,
+}
+
+/// `FriendWarnOnLevelGainStateMessage`, id 6326
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FriendWarnOnLevelGainStateMessage {
+    pub enable: bool,
+}
+
+/// `FriendsGetListMessage`, id 7279
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FriendsGetListMessage {}
+
+/// `FriendsListMessage`, id 4516
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FriendsListMessage {
+    #[serde(rename = "friendsList")]
+    pub friends_list: Vec<FriendInformationsKind>,
+}
+
+/// `FullStatsPreset`, id 7800
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FullStatsPreset {
+    pub id: i16,
+    pub stats: Vec<CharacterCharacteristicForPreset>,
+}
+
+/// `GameActionAcknowledgementMessage`, id 6232
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionAcknowledgementMessage {
+    pub valid: bool,
+    #[serde(rename = "actionId")]
+    pub action_id: i8,
+}
+
+/// `GameActionFightActivateGlyphTrapMessage`, id 2523
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightActivateGlyphTrapMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "markId")]
+    pub mark_id: i16,
+    pub active: bool,
+}
+
+/// `GameActionFightCarryCharacterMessage`, id 2495
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightCarryCharacterMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "cellId")]
+    pub cell_id: i16,
+}
+
+/// `GameActionFightCastOnTargetRequestMessage`, id 2664
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightCastOnTargetRequestMessage {
+    #[serde(rename = "spellId")]
+    pub spell_id: u16,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+}
+
+/// `GameActionFightCastRequestMessage`, id 1786
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightCastRequestMessage {
+    #[serde(rename = "spellId")]
+    pub spell_id: u16,
+    #[serde(rename = "cellId")]
+    pub cell_id: i16,
+}
+
+/// `GameActionFightChangeLookMessage`, id 1752
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightChangeLookMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "entityLook")]
+    pub entity_look: EntityLook,
+}
+
+/// `GameActionFightCloseCombatMessage`, id 5508
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightCloseCombatMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "silentCast")]
+    pub silent_cast: bool,
+    #[serde(rename = "verboseCast")]
+    pub verbose_cast: bool,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "destinationCellId")]
+    pub destination_cell_id: i16,
+    pub critical: i8,
+    #[serde(rename = "weaponGenericId")]
+    pub weapon_generic_id: u32,
+}
+
+/// `GameActionFightDeathMessage`, id 7983
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightDeathMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+}
+
+/// `GameActionFightDispellEffectMessage`, id 9093
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightDispellEffectMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "verboseCast")]
+    pub verbose_cast: bool,
+    #[serde(rename = "boostUID")]
+    pub boost_uid: i32,
+}
+
+/// `GameActionFightDispellMessage`, id 4823
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightDispellMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "verboseCast")]
+    pub verbose_cast: bool,
+}
+
+/// `GameActionFightDispellSpellMessage`, id 1119
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightDispellSpellMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "verboseCast")]
+    pub verbose_cast: bool,
+    #[serde(rename = "spellId")]
+    pub spell_id: u16,
+}
+
+/// `GameActionFightDispellableEffectMessage`, id 3110
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightDispellableEffectMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    pub effect: Box<AbstractFightDispellableEffectKind>,
+}
+
+/// `GameActionFightDodgePointLossMessage`, id 2269
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightDodgePointLossMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    pub amount: u16,
+}
+
+/// `GameActionFightDropCharacterMessage`, id 370
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightDropCharacterMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "cellId")]
+    pub cell_id: i16,
+}
+
+/// `GameActionFightExchangePositionsMessage`, id 3330
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightExchangePositionsMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "casterCellId")]
+    pub caster_cell_id: i16,
+    #[serde(rename = "targetCellId")]
+    pub target_cell_id: i16,
+}
+
+/// `GameActionFightInvisibilityMessage`, id 6203
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightInvisibilityMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    pub state: i8,
+}
+
+/// `GameActionFightInvisibleDetectedMessage`, id 9766
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightInvisibleDetectedMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "cellId")]
+    pub cell_id: i16,
+}
+
+/// `GameActionFightKillMessage`, id 5400
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightKillMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+}
+
+/// `GameActionFightLifeAndShieldPointsLostMessage`, id 8896
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightLifeAndShieldPointsLostMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    pub loss: u32,
+    #[serde(rename = "permanentDamages")]
+    pub permanent_damages: u32,
+    #[serde(rename = "elementId")]
+    pub element_id: u32,
+    #[serde(rename = "shieldLoss")]
+    pub shield_loss: u16,
+}
+
+/// `GameActionFightLifePointsGainMessage`, id 5208
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightLifePointsGainMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    pub delta: u32,
+}
+
+/// `GameActionFightLifePointsLostMessage`, id 3266
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightLifePointsLostMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    pub loss: u32,
+    #[serde(rename = "permanentDamages")]
+    pub permanent_damages: u32,
+    #[serde(rename = "elementId")]
+    pub element_id: u32,
+}
+
+/// `GameActionFightMarkCellsMessage`, id 776
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightMarkCellsMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    pub mark: GameActionMark,
+}
+
+/// `GameActionFightModifyEffectsDurationMessage`, id 885
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightModifyEffectsDurationMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    pub delta: i16,
+}
+
+/// `GameActionFightMultipleSummonMessage`, id 2358
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightMultipleSummonMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    pub summons: Vec<GameContextSummonsInformationKind>,
+}
+
+/// `GameActionFightNoSpellCastMessage`, id 8870
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightNoSpellCastMessage {
+    #[serde(rename = "spellLevelId")]
+    pub spell_level_id: u32,
+}
+
+/// `GameActionFightPointsVariationMessage`, id 2005
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightPointsVariationMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    pub delta: i16,
+}
+
+/// `GameActionFightReduceDamagesMessage`, id 4133
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightReduceDamagesMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    pub amount: u32,
+}
+
+/// `GameActionFightReflectDamagesMessage`, id 3098
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightReflectDamagesMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+}
+
+/// `GameActionFightReflectSpellMessage`, id 994
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightReflectSpellMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+}
+
+/// `GameActionFightSlideMessage`, id 5406
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightSlideMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "startCellId")]
+    pub start_cell_id: i16,
+    #[serde(rename = "endCellId")]
+    pub end_cell_id: i16,
+}
+
+/// `GameActionFightSpellCastMessage`, id 9303
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightSpellCastMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "silentCast")]
+    pub silent_cast: bool,
+    #[serde(rename = "verboseCast")]
+    pub verbose_cast: bool,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "destinationCellId")]
+    pub destination_cell_id: i16,
+    pub critical: i8,
+    #[serde(rename = "spellId")]
+    pub spell_id: u16,
+    #[serde(rename = "spellLevel")]
+    pub spell_level: i16,
+    #[serde(rename = "portalsIds")]
+    pub portals_ids: Vec<i16>,
+}
+
+/// `GameActionFightSpellCooldownVariationMessage`, id 3158
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightSpellCooldownVariationMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "spellId")]
+    pub spell_id: u16,
+    pub value: u16,
+}
+
+/// `GameActionFightSpellImmunityMessage`, id 5485
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightSpellImmunityMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "spellId")]
+    pub spell_id: u16,
+}
+
+/// `GameActionFightStealKamaMessage`, id 920
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightStealKamaMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    pub amount: u64,
+}
+
+/// `GameActionFightSummonMessage`, id 997
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightSummonMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    pub summons: Vec<GameFightFighterInformationsKind>,
+}
+
+/// `GameActionFightTackledMessage`, id 9014
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightTackledMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "tacklersIds", deserialize_with = "floats")]
+    pub tacklers_ids: Vec<f64>,
+}
+
+/// `GameActionFightTeleportOnSameMapMessage`, id 2970
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightTeleportOnSameMapMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "cellId")]
+    pub cell_id: i16,
+}
+
+/// `GameActionFightThrowCharacterMessage`, id 7073
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightThrowCharacterMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "cellId")]
+    pub cell_id: i16,
+}
+
+/// `GameActionFightTriggerEffectMessage`, id 880
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightTriggerEffectMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "verboseCast")]
+    pub verbose_cast: bool,
+    #[serde(rename = "boostUID")]
+    pub boost_uid: i32,
+}
+
+/// `GameActionFightTriggerGlyphTrapMessage`, id 3525
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightTriggerGlyphTrapMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "markId")]
+    pub mark_id: i16,
+    #[serde(rename = "markImpactCell")]
+    pub mark_impact_cell: u16,
+    #[serde(rename = "triggeringCharacterId", deserialize_with = "float")]
+    pub triggering_character_id: f64,
+    #[serde(rename = "triggeredSpellId")]
+    pub triggered_spell_id: u16,
+}
+
+/// `GameActionFightUnmarkCellsMessage`, id 6447
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightUnmarkCellsMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "markId")]
+    pub mark_id: i16,
+}
+
+/// `GameActionFightVanishMessage`, id 3847
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionFightVanishMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+}
+
+/// `GameActionItem`, id 8473
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionItem {
+    pub uid: i32,
+    pub title: String,
+    pub text: String,
+    #[serde(rename = "descUrl")]
+    pub desc_url: String,
+    #[serde(rename = "pictureUrl")]
+    pub picture_url: String,
+    pub items: Vec<ObjectItemInformationWithQuantity>,
+}
+
+/// `GameActionItemAddMessage`, id 3631
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionItemAddMessage {
+    #[serde(rename = "newAction")]
+    pub new_action: GameActionItem,
+}
+
+/// `GameActionItemConsumedMessage`, id 9045
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionItemConsumedMessage {
+    pub success: bool,
+    #[serde(rename = "automaticAction")]
+    pub automatic_action: bool,
+    #[serde(rename = "actionId")]
+    pub action_id: i32,
+}
+
+/// `GameActionItemListMessage`, id 4354
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionItemListMessage {
+    pub actions: Vec<GameActionItem>,
+}
+
+/// `GameActionMark`, id 4305
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionMark {
+    #[serde(rename = "markAuthorId", deserialize_with = "float")]
+    pub mark_author_id: f64,
+    #[serde(rename = "markTeamId")]
+    pub mark_team_id: i8,
+    #[serde(rename = "markSpellId")]
+    pub mark_spell_id: i32,
+    #[serde(rename = "markSpellLevel")]
+    pub mark_spell_level: i16,
+    #[serde(rename = "markId")]
+    pub mark_id: i16,
+    #[serde(rename = "markType")]
+    pub mark_type: i8,
+    #[serde(rename = "markimpactCell")]
+    pub markimpact_cell: i16,
+    pub cells: Vec<GameActionMarkedCell>,
+    pub active: bool,
+}
+
+/// `GameActionMarkedCell`, id 4032
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionMarkedCell {
+    #[serde(rename = "cellId")]
+    pub cell_id: u16,
+    #[serde(rename = "zoneSize")]
+    pub zone_size: i8,
+    #[serde(rename = "cellColor")]
+    pub cell_color: i32,
+    #[serde(rename = "cellsType")]
+    pub cells_type: i8,
+}
+
+/// `GameActionNoopMessage`, id 5279
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GameActionNoopMessage {}
+
+/// `GameActionSpamMessage`, id 6901
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionSpamMessage {
+    pub cells: Vec<i16>,
+}
+
+/// `GameActionUpdateEffectTriggerCountMessage`, id 6965
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameActionUpdateEffectTriggerCountMessage {
+    #[serde(rename = "targetIds")]
+    pub target_ids: Vec<GameFightEffectTriggerCount>,
+}
+
+/// `GameCautiousMapMovementMessage`, id 6036
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameCautiousMapMovementMessage {
+    #[serde(rename = "keyMovements")]
+    pub key_movements: Vec<i16>,
+    #[serde(rename = "forcedDirection")]
+    pub forced_direction: i16,
+    #[serde(rename = "actorId", deserialize_with = "float")]
+    pub actor_id: f64,
+}
+
+/// `GameCautiousMapMovementRequestMessage`, id 5432
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameCautiousMapMovementRequestMessage {
+    #[serde(rename = "keyMovements")]
+    pub key_movements: Vec<i16>,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+}
+
+/// `GameContextActorInformations`, id 9847
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameContextActorInformations {
+    #[serde(rename = "contextualId", deserialize_with = "float")]
+    pub contextual_id: f64,
+    pub disposition: Box<EntityDispositionInformationsKind>,
+    pub look: EntityLook,
+}
+
+/// `GameContextActorPositionInformations`, id 3228
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameContextActorPositionInformations {
+    #[serde(rename = "contextualId", deserialize_with = "float")]
+    pub contextual_id: f64,
+    pub disposition: Box<EntityDispositionInformationsKind>,
+}
+
+/// `GameContextBasicSpawnInformation`, id 2004
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameContextBasicSpawnInformation {
+    #[serde(rename = "teamId")]
+    pub team_id: i8,
+    pub alive: bool,
+    pub informations: Box<GameContextActorPositionInformationsKind>,
+}
+
+/// `GameContextCreateErrorMessage`, id 3859
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GameContextCreateErrorMessage {}
+
+/// `GameContextCreateMessage`, id 5863
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameContextCreateMessage {
+    pub context: i8,
+}
+
+/// `GameContextCreateRequestMessage`, id 2864
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GameContextCreateRequestMessage {}
+
+/// `GameContextDestroyMessage`, id 9601
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GameContextDestroyMessage {}
+
+/// `GameContextKickMessage`, id 6406
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameContextKickMessage {
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+}
+
+/// `GameContextMoveElementMessage`, id 2284
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameContextMoveElementMessage {
+    pub movement: EntityMovementInformations,
+}
+
+/// `GameContextMoveMultipleElementsMessage`, id 7545
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameContextMoveMultipleElementsMessage {
+    pub movements: Vec<EntityMovementInformations>,
+}
+
+/// `GameContextQuitMessage`, id 7878
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GameContextQuitMessage {}
+
+/// `GameContextReadyMessage`, id 5961
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameContextReadyMessage {
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+}
+
+/// `GameContextRefreshEntityLookMessage`, id 2785
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameContextRefreshEntityLookMessage {
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+    pub look: EntityLook,
+}
+
+/// `GameContextRemoveElementMessage`, id 1463
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameContextRemoveElementMessage {
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+}
+
+/// `GameContextRemoveElementWithEventMessage`, id 1450
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameContextRemoveElementWithEventMessage {
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+    #[serde(rename = "elementEventId")]
+    pub element_event_id: i8,
+}
+
+/// `GameContextRemoveMultipleElementsMessage`, id 654
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameContextRemoveMultipleElementsMessage {
+    #[serde(rename = "elementsIds", deserialize_with = "floats")]
+    pub elements_ids: Vec<f64>,
+}
+
+/// `GameContextRemoveMultipleElementsWithEventsMessage`, id 9896
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameContextRemoveMultipleElementsWithEventsMessage {
+    #[serde(rename = "elementsIds", deserialize_with = "floats")]
+    pub elements_ids: Vec<f64>,
+    #[serde(rename = "elementEventIds")]
+    pub element_event_ids: Vec<i8>,
+}
+
+/// `GameContextSummonsInformation`, id 1255
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameContextSummonsInformation {
+    #[serde(rename = "spawnInformation")]
+    pub spawn_information: Box<SpawnInformationKind>,
+    pub wave: i8,
+    pub look: EntityLook,
+    pub stats: Box<GameFightCharacteristicsKind>,
+    pub summons: Vec<GameContextBasicSpawnInformationKind>,
+}
+
+/// `GameDataPaddockObjectAddMessage`, id 3035
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameDataPaddockObjectAddMessage {
+    #[serde(rename = "paddockItemDescription")]
+    pub paddock_item_description: PaddockItem,
+}
+
+/// `GameDataPaddockObjectListAddMessage`, id 4187
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameDataPaddockObjectListAddMessage {
+    #[serde(rename = "paddockItemDescription")]
+    pub paddock_item_description: Vec<PaddockItem>,
+}
+
+/// `GameDataPaddockObjectRemoveMessage`, id 4782
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameDataPaddockObjectRemoveMessage {
+    #[serde(rename = "cellId")]
+    pub cell_id: u16,
+}
+
+/// `GameDataPlayFarmObjectAnimationMessage`, id 5773
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameDataPlayFarmObjectAnimationMessage {
+    #[serde(rename = "cellId")]
+    pub cell_id: Vec<u16>,
+}
+
+/// `GameEntitiesDispositionMessage`, id 7401
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameEntitiesDispositionMessage {
+    pub dispositions: Vec<IdentifiedEntityDispositionInformations>,
+}
+
+/// `GameEntityDispositionErrorMessage`, id 9598
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GameEntityDispositionErrorMessage {}
+
+/// `GameEntityDispositionMessage`, id 3360
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameEntityDispositionMessage {
+    pub disposition: IdentifiedEntityDispositionInformations,
+}
+
+/// `GameFightAIInformations`, id 3603
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightAIInformations {
+    #[serde(rename = "contextualId", deserialize_with = "float")]
+    pub contextual_id: f64,
+    pub disposition: Box<EntityDispositionInformationsKind>,
+    pub look: EntityLook,
+    #[serde(rename = "spawnInfo")]
+    pub spawn_info: GameContextBasicSpawnInformation,
+    pub wave: i8,
+    pub stats: Box<GameFightCharacteristicsKind>,
+    #[serde(rename = "previousPositions")]
+    pub previous_positions: Vec<u16>,
+}
+
+/// `GameFightCharacterInformations`, id 9374
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightCharacterInformations {
+    #[serde(rename = "contextualId", deserialize_with = "float")]
+    pub contextual_id: f64,
+    pub disposition: Box<EntityDispositionInformationsKind>,
+    pub look: EntityLook,
+    #[serde(rename = "spawnInfo")]
+    pub spawn_info: GameContextBasicSpawnInformation,
+    pub wave: i8,
+    pub stats: Box<GameFightCharacteristicsKind>,
+    #[serde(rename = "previousPositions")]
+    pub previous_positions: Vec<u16>,
+    pub name: String,
+    pub status: PlayerStatus,
+    #[serde(rename = "leagueId")]
+    pub league_id: u16,
+    #[serde(rename = "ladderPosition")]
+    pub ladder_position: i32,
+    #[serde(rename = "hiddenInPrefight")]
+    pub hidden_in_prefight: bool,
+    pub level: u16,
+    #[serde(rename = "alignmentInfos")]
+    pub alignment_infos: ActorAlignmentInformations,
+    pub breed: i8,
+    pub sex: bool,
+}
+
+/// `GameFightCharacteristics`, id 3605
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightCharacteristics {
+    pub characteristics: CharacterCharacteristics,
+    #[serde(deserialize_with = "float")]
+    pub summoner: f64,
+    pub summoned: bool,
+    #[serde(rename = "invisibilityState")]
+    pub invisibility_state: i8,
+}
+
+/// `GameFightEffectTriggerCount`, id 7944
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightEffectTriggerCount {
+    #[serde(rename = "effectId")]
+    pub effect_id: u32,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    pub count: i16,
+}
+
+/// `GameFightEndMessage`, id 9470
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightEndMessage {
+    pub duration: i32,
+    #[serde(rename = "rewardRate")]
+    pub reward_rate: u16,
+    #[serde(rename = "lootShareLimitMalus")]
+    pub loot_share_limit_malus: i16,
+    pub results: Vec<FightResultListEntryKind>,
+    #[serde(rename = "namedPartyTeamsOutcomes")]
+    pub named_party_teams_outcomes: Vec<NamedPartyTeamWithOutcome>,
+}
+
+/// `GameFightEntityInformation`, id 8220
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightEntityInformation {
+    #[serde(rename = "contextualId", deserialize_with = "float")]
+    pub contextual_id: f64,
+    pub disposition: Box<EntityDispositionInformationsKind>,
+    pub look: EntityLook,
+    #[serde(rename = "spawnInfo")]
+    pub spawn_info: GameContextBasicSpawnInformation,
+    pub wave: i8,
+    pub stats: Box<GameFightCharacteristicsKind>,
+    #[serde(rename = "previousPositions")]
+    pub previous_positions: Vec<u16>,
+    #[serde(rename = "entityModelId")]
+    pub entity_model_id: i8,
+    pub level: u16,
+    #[serde(rename = "masterId", deserialize_with = "float")]
+    pub master_id: f64,
+}
+
+/// `GameFightFighterEntityLightInformation`, id 385
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightFighterEntityLightInformation {
+    pub sex: bool,
+    pub alive: bool,
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+    pub wave: i8,
+    pub level: u16,
+    pub breed: i8,
+    #[serde(rename = "entityModelId")]
+    pub entity_model_id: i8,
+    #[serde(rename = "masterId", deserialize_with = "float")]
+    pub master_id: f64,
+}
+
+/// `GameFightFighterInformations`, id 456
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightFighterInformations {
+    #[serde(rename = "contextualId", deserialize_with = "float")]
+    pub contextual_id: f64,
+    pub disposition: Box<EntityDispositionInformationsKind>,
+    pub look: EntityLook,
+    #[serde(rename = "spawnInfo")]
+    pub spawn_info: GameContextBasicSpawnInformation,
+    pub wave: i8,
+    pub stats: Box<GameFightCharacteristicsKind>,
+    #[serde(rename = "previousPositions")]
+    pub previous_positions: Vec<u16>,
+}
+
+/// `GameFightFighterLightInformations`, id 4812
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightFighterLightInformations {
+    pub sex: bool,
+    pub alive: bool,
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+    pub wave: i8,
+    pub level: u16,
+    pub breed: i8,
+}
+
+/// `GameFightFighterMonsterLightInformations`, id 8927
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightFighterMonsterLightInformations {
+    pub sex: bool,
+    pub alive: bool,
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+    pub wave: i8,
+    pub level: u16,
+    pub breed: i8,
+    #[serde(rename = "creatureGenericId")]
+    pub creature_generic_id: u16,
+}
+
+/// `GameFightFighterNamedInformations`, id 5462
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightFighterNamedInformations {
+    #[serde(rename = "contextualId", deserialize_with = "float")]
+    pub contextual_id: f64,
+    pub disposition: Box<EntityDispositionInformationsKind>,
+    pub look: EntityLook,
+    #[serde(rename = "spawnInfo")]
+    pub spawn_info: GameContextBasicSpawnInformation,
+    pub wave: i8,
+    pub stats: Box<GameFightCharacteristicsKind>,
+    #[serde(rename = "previousPositions")]
+    pub previous_positions: Vec<u16>,
+    pub name: String,
+    pub status: PlayerStatus,
+    #[serde(rename = "leagueId")]
+    pub league_id: u16,
+    #[serde(rename = "ladderPosition")]
+    pub ladder_position: i32,
+    #[serde(rename = "hiddenInPrefight")]
+    pub hidden_in_prefight: bool,
+}
+
+/// `GameFightFighterNamedLightInformations`, id 292
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightFighterNamedLightInformations {
+    pub sex: bool,
+    pub alive: bool,
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+    pub wave: i8,
+    pub level: u16,
+    pub breed: i8,
+    pub name: String,
+}
+
+/// `GameFightFighterTaxCollectorLightInformations`, id 528
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightFighterTaxCollectorLightInformations {
+    pub sex: bool,
+    pub alive: bool,
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+    pub wave: i8,
+    pub level: u16,
+    pub breed: i8,
+    #[serde(rename = "firstNameId")]
+    pub first_name_id: u16,
+    #[serde(rename = "lastNameId")]
+    pub last_name_id: u16,
+}
+
+/// `GameFightHumanReadyStateMessage`, id 9437
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightHumanReadyStateMessage {
+    #[serde(rename = "characterId")]
+    pub character_id: u64,
+    #[serde(rename = "isReady")]
+    pub is_ready: bool,
+}
+
+/// `GameFightJoinMessage`, id 8151
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightJoinMessage {
+    #[serde(rename = "isTeamPhase")]
+    pub is_team_phase: bool,
+    #[serde(rename = "canBeCancelled")]
+    pub can_be_cancelled: bool,
+    #[serde(rename = "canSayReady")]
+    pub can_say_ready: bool,
+    #[serde(rename = "isFightStarted")]
+    pub is_fight_started: bool,
+    #[serde(rename = "timeMaxBeforeFightStart")]
+    pub time_max_before_fight_start: i16,
+    #[serde(rename = "fightType")]
+    pub fight_type: i8,
+}
+
+/// `GameFightJoinRequestMessage`, id 7552
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightJoinRequestMessage {
+    #[serde(rename = "fighterId", deserialize_with = "float")]
+    pub fighter_id: f64,
+    #[serde(rename = "fightId")]
+    pub fight_id: u16,
+}
+
+/// `GameFightLeaveMessage`, id 5365
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightLeaveMessage {
+    #[serde(rename = "charId", deserialize_with = "float")]
+    pub char_id: f64,
+}
+
+/// `GameFightMonsterInformations`, id 9878
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightMonsterInformations {
+    #[serde(rename = "contextualId", deserialize_with = "float")]
+    pub contextual_id: f64,
+    pub disposition: Box<EntityDispositionInformationsKind>,
+    pub look: EntityLook,
+    #[serde(rename = "spawnInfo")]
+    pub spawn_info: GameContextBasicSpawnInformation,
+    pub wave: i8,
+    pub stats: Box<GameFightCharacteristicsKind>,
+    #[serde(rename = "previousPositions")]
+    pub previous_positions: Vec<u16>,
+    #[serde(rename = "creatureGenericId")]
+    pub creature_generic_id: u16,
+    #[serde(rename = "creatureGrade")]
+    pub creature_grade: i8,
+    #[serde(rename = "creatureLevel")]
+    pub creature_level: i16,
+}
+
+/// `GameFightMonsterWithAlignmentInformations`, id 4842
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightMonsterWithAlignmentInformations {
+    #[serde(rename = "contextualId", deserialize_with = "float")]
+    pub contextual_id: f64,
+    pub disposition: Box<EntityDispositionInformationsKind>,
+    pub look: EntityLook,
+    #[serde(rename = "spawnInfo")]
+    pub spawn_info: GameContextBasicSpawnInformation,
+    pub wave: i8,
+    pub stats: Box<GameFightCharacteristicsKind>,
+    #[serde(rename = "previousPositions")]
+    pub previous_positions: Vec<u16>,
+    #[serde(rename = "creatureGenericId")]
+    pub creature_generic_id: u16,
+    #[serde(rename = "creatureGrade")]
+    pub creature_grade: i8,
+    #[serde(rename = "creatureLevel")]
+    pub creature_level: i16,
+    #[serde(rename = "alignmentInfos")]
+    pub alignment_infos: ActorAlignmentInformations,
+}
+
+/// `GameFightMutantInformations`, id 6618
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightMutantInformations {
+    #[serde(rename = "contextualId", deserialize_with = "float")]
+    pub contextual_id: f64,
+    pub disposition: Box<EntityDispositionInformationsKind>,
+    pub look: EntityLook,
+    #[serde(rename = "spawnInfo")]
+    pub spawn_info: GameContextBasicSpawnInformation,
+    pub wave: i8,
+    pub stats: Box<GameFightCharacteristicsKind>,
+    #[serde(rename = "previousPositions")]
+    pub previous_positions: Vec<u16>,
+    pub name: String,
+    pub status: PlayerStatus,
+    #[serde(rename = "leagueId")]
+    pub league_id: u16,
+    #[serde(rename = "ladderPosition")]
+    pub ladder_position: i32,
+    #[serde(rename = "hiddenInPrefight")]
+    pub hidden_in_prefight: bool,
+    #[serde(rename = "powerLevel")]
+    pub power_level: i8,
+}
+
+/// `GameFightNewRoundMessage`, id 9614
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightNewRoundMessage {
+    #[serde(rename = "roundNumber")]
+    pub round_number: u32,
+}
+
+/// `GameFightNewWaveMessage`, id 2673
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightNewWaveMessage {
+    pub id: i8,
+    #[serde(rename = "teamId")]
+    pub team_id: i8,
+    #[serde(rename = "nbTurnBeforeNextWave")]
+    pub nb_turn_before_next_wave: i16,
+}
+
+/// `GameFightOptionStateUpdateMessage`, id 805
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightOptionStateUpdateMessage {
+    #[serde(rename = "fightId")]
+    pub fight_id: u16,
+    #[serde(rename = "teamId")]
+    pub team_id: i8,
+    pub option: i8,
+    pub state: bool,
+}
+
+/// `GameFightOptionToggleMessage`, id 4150
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightOptionToggleMessage {
+    pub option: i8,
+}
+
+/// `GameFightPauseMessage`, id 6935
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightPauseMessage {
+    #[serde(rename = "isPaused")]
+    pub is_paused: bool,
+}
+
+/// `GameFightPlacementPositionRequestMessage`, id 5156
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightPlacementPositionRequestMessage {
+    #[serde(rename = "cellId")]
+    pub cell_id: u16,
+}
+
+/// `GameFightPlacementPossiblePositionsMessage`, id 2290
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightPlacementPossiblePositionsMessage {
+    #[serde(rename = "positionsForChallengers")]
+    pub positions_for_challengers: Vec<u16>,
+    #[serde(rename = "positionsForDefenders")]
+    pub positions_for_defenders: Vec<u16>,
+    #[serde(rename = "teamNumber")]
+    pub team_number: i8,
+}
+
+/// `GameFightPlacementSwapPositionsAcceptMessage`, id 9902
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightPlacementSwapPositionsAcceptMessage {
+    #[serde(rename = "requestId")]
+    pub request_id: i32,
+}
+
+/// `GameFightPlacementSwapPositionsCancelMessage`, id 1618
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightPlacementSwapPositionsCancelMessage {
+    #[serde(rename = "requestId")]
+    pub request_id: i32,
+}
+
+/// `GameFightPlacementSwapPositionsCancelledMessage`, id 2326
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightPlacementSwapPositionsCancelledMessage {
+    #[serde(rename = "requestId")]
+    pub request_id: i32,
+    #[serde(rename = "cancellerId", deserialize_with = "float")]
+    pub canceller_id: f64,
+}
+
+/// `GameFightPlacementSwapPositionsErrorMessage`, id 1863
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GameFightPlacementSwapPositionsErrorMessage {}
+
+/// `GameFightPlacementSwapPositionsMessage`, id 3615
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightPlacementSwapPositionsMessage {
+    pub dispositions: Vec<IdentifiedEntityDispositionInformations>,
+}
+
+/// `GameFightPlacementSwapPositionsOfferMessage`, id 3056
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightPlacementSwapPositionsOfferMessage {
+    #[serde(rename = "requestId")]
+    pub request_id: i32,
+    #[serde(rename = "requesterId", deserialize_with = "float")]
+    pub requester_id: f64,
+    #[serde(rename = "requesterCellId")]
+    pub requester_cell_id: u16,
+    #[serde(rename = "requestedId", deserialize_with = "float")]
+    pub requested_id: f64,
+    #[serde(rename = "requestedCellId")]
+    pub requested_cell_id: u16,
+}
+
+/// `GameFightPlacementSwapPositionsRequestMessage`, id 3586
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightPlacementSwapPositionsRequestMessage {
+    #[serde(rename = "cellId")]
+    pub cell_id: u16,
+    #[serde(rename = "requestedId", deserialize_with = "float")]
+    pub requested_id: f64,
+}
+
+/// `GameFightReadyMessage`, id 86
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightReadyMessage {
+    #[serde(rename = "isReady")]
+    pub is_ready: bool,
+}
+
+/// `GameFightRefreshFighterMessage`, id 8738
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightRefreshFighterMessage {
+    pub informations: Box<GameContextActorInformationsKind>,
+}
+
+/// `GameFightRemoveTeamMemberMessage`, id 8088
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightRemoveTeamMemberMessage {
+    #[serde(rename = "fightId")]
+    pub fight_id: u16,
+    #[serde(rename = "teamId")]
+    pub team_id: i8,
+    #[serde(rename = "charId", deserialize_with = "float")]
+    pub char_id: f64,
+}
+
+/// `GameFightResumeMessage`, id 5578
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightResumeMessage {
+    pub effects: Vec<FightDispellableEffectExtendedInformations>,
+    pub marks: Vec<GameActionMark>,
+    #[serde(rename = "gameTurn")]
+    pub game_turn: u16,
+    #[serde(rename = "fightStart")]
+    pub fight_start: i32,
+    #[serde(rename = "fxTriggerCounts")]
+    pub fx_trigger_counts: Vec<GameFightEffectTriggerCount>,
+    #[serde(rename = "spellCooldowns")]
+    pub spell_cooldowns: Vec<GameFightSpellCooldown>,
+    #[serde(rename = "summonCount")]
+    pub summon_count: i8,
+    #[serde(rename = "bombCount")]
+    pub bomb_count: i8,
+}
+
+/// `GameFightResumeSlaveInfo`, id 9958
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightResumeSlaveInfo {
+    #[serde(rename = "slaveId", deserialize_with = "float")]
+    pub slave_id: f64,
+    #[serde(rename = "spellCooldowns")]
+    pub spell_cooldowns: Vec<GameFightSpellCooldown>,
+    #[serde(rename = "summonCount")]
+    pub summon_count: i8,
+    #[serde(rename = "bombCount")]
+    pub bomb_count: i8,
+}
+
+/// `GameFightResumeWithSlavesMessage`, id 4166
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightResumeWithSlavesMessage {
+    pub effects: Vec<FightDispellableEffectExtendedInformations>,
+    pub marks: Vec<GameActionMark>,
+    #[serde(rename = "gameTurn")]
+    pub game_turn: u16,
+    #[serde(rename = "fightStart")]
+    pub fight_start: i32,
+    #[serde(rename = "fxTriggerCounts")]
+    pub fx_trigger_counts: Vec<GameFightEffectTriggerCount>,
+    #[serde(rename = "spellCooldowns")]
+    pub spell_cooldowns: Vec<GameFightSpellCooldown>,
+    #[serde(rename = "summonCount")]
+    pub summon_count: i8,
+    #[serde(rename = "bombCount")]
+    pub bomb_count: i8,
+    #[serde(rename = "slavesInfo")]
+    pub slaves_info: Vec<GameFightResumeSlaveInfo>,
+}
+
+/// `GameFightShowFighterMessage`, id 6971
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightShowFighterMessage {
+    pub informations: Box<GameFightFighterInformationsKind>,
+}
+
+/// `GameFightShowFighterRandomStaticPoseMessage`, id 7042
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightShowFighterRandomStaticPoseMessage {
+    pub informations: Box<GameFightFighterInformationsKind>,
+}
+
+/// `GameFightSpectateMessage`, id 6788
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightSpectateMessage {
+    pub effects: Vec<FightDispellableEffectExtendedInformations>,
+    pub marks: Vec<GameActionMark>,
+    #[serde(rename = "gameTurn")]
+    pub game_turn: u16,
+    #[serde(rename = "fightStart")]
+    pub fight_start: i32,
+    #[serde(rename = "fxTriggerCounts")]
+    pub fx_trigger_counts: Vec<GameFightEffectTriggerCount>,
+}
+
+/// `GameFightSpectatePlayerRequestMessage`, id 4557
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightSpectatePlayerRequestMessage {
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+}
+
+/// `GameFightSpectatorJoinMessage`, id 6001
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightSpectatorJoinMessage {
+    #[serde(rename = "isTeamPhase")]
+    pub is_team_phase: bool,
+    #[serde(rename = "canBeCancelled")]
+    pub can_be_cancelled: bool,
+    #[serde(rename = "canSayReady")]
+    pub can_say_ready: bool,
+    #[serde(rename = "isFightStarted")]
+    pub is_fight_started: bool,
+    #[serde(rename = "timeMaxBeforeFightStart")]
+    pub time_max_before_fight_start: i16,
+    #[serde(rename = "fightType")]
+    pub fight_type: i8,
+    #[serde(rename = "namedPartyTeams")]
+    pub named_party_teams: Vec<NamedPartyTeam>,
+}
+
+/// `GameFightSpellCooldown`, id 5217
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightSpellCooldown {
+    #[serde(rename = "spellId")]
+    pub spell_id: i32,
+    pub cooldown: i8,
+}
+
+/// `GameFightStartMessage`, id 1715
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GameFightStartMessage {}
+
+/// `GameFightStartingMessage`, id 3380
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightStartingMessage {
+    #[serde(rename = "fightType")]
+    pub fight_type: i8,
+    #[serde(rename = "fightId")]
+    pub fight_id: u16,
+    #[serde(rename = "attackerId", deserialize_with = "float")]
+    pub attacker_id: f64,
+    #[serde(rename = "defenderId", deserialize_with = "float")]
+    pub defender_id: f64,
+    #[serde(rename = "containsBoss")]
+    pub contains_boss: bool,
+    pub monsters: Vec<i32>,
+}
+
+/// `GameFightSynchronizeMessage`, id 4424
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightSynchronizeMessage {
+    pub fighters: Vec<GameFightFighterInformationsKind>,
+}
+
+/// `GameFightTaxCollectorInformations`, id 3826
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightTaxCollectorInformations {
+    #[serde(rename = "contextualId", deserialize_with = "float")]
+    pub contextual_id: f64,
+    pub disposition: Box<EntityDispositionInformationsKind>,
+    pub look: EntityLook,
+    #[serde(rename = "spawnInfo")]
+    pub spawn_info: GameContextBasicSpawnInformation,
+    pub wave: i8,
+    pub stats: Box<GameFightCharacteristicsKind>,
+    #[serde(rename = "previousPositions")]
+    pub previous_positions: Vec<u16>,
+    #[serde(rename = "firstNameId")]
+    pub first_name_id: u16,
+    #[serde(rename = "lastNameId")]
+    pub last_name_id: u16,
+}
+
+/// `GameFightTurnEndMessage`, id 3804
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightTurnEndMessage {
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+}
+
+/// `GameFightTurnFinishMessage`, id 7713
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightTurnFinishMessage {
+    #[serde(rename = "isAfk")]
+    pub is_afk: bool,
+}
+
+/// `GameFightTurnListMessage`, id 8496
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightTurnListMessage {
+    #[serde(deserialize_with = "floats")]
+    pub ids: Vec<f64>,
+    #[serde(rename = "deadsIds", deserialize_with = "floats")]
+    pub deads_ids: Vec<f64>,
+}
+
+/// `GameFightTurnReadyMessage`, id 7742
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightTurnReadyMessage {
+    #[serde(rename = "isReady")]
+    pub is_ready: bool,
+}
+
+/// `GameFightTurnReadyRequestMessage`, id 3656
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightTurnReadyRequestMessage {
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+}
+
+/// `GameFightTurnResumeMessage`, id 447
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightTurnResumeMessage {
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+    #[serde(rename = "waitTime")]
+    pub wait_time: u32,
+    #[serde(rename = "remainingTime")]
+    pub remaining_time: u32,
+}
+
+/// `GameFightTurnStartMessage`, id 1384
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightTurnStartMessage {
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+    #[serde(rename = "waitTime")]
+    pub wait_time: u32,
+}
+
+/// `GameFightTurnStartPlayingMessage`, id 8127
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GameFightTurnStartPlayingMessage {}
+
+/// `GameFightUpdateTeamMessage`, id 5591
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameFightUpdateTeamMessage {
+    #[serde(rename = "fightId")]
+    pub fight_id: u16,
+    pub team: FightTeamInformations,
+}
+
+/// `GameMapChangeOrientationMessage`, id 9096
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameMapChangeOrientationMessage {
+    pub orientation: ActorOrientation,
+}
+
+/// `GameMapChangeOrientationRequestMessage`, id 1266
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameMapChangeOrientationRequestMessage {
+    pub direction: i8,
+}
+
+/// `GameMapChangeOrientationsMessage`, id 3010
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameMapChangeOrientationsMessage {
+    pub orientations: Vec<ActorOrientation>,
+}
+
+/// `GameMapMovementCancelMessage`, id 326
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameMapMovementCancelMessage {
+    #[serde(rename = "cellId")]
+    pub cell_id: u16,
+}
+
+/// `GameMapMovementConfirmMessage`, id 9475
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GameMapMovementConfirmMessage {}
+
+/// `GameMapMovementMessage`, id 4955
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameMapMovementMessage {
+    #[serde(rename = "keyMovements")]
+    pub key_movements: Vec<i16>,
+    #[serde(rename = "forcedDirection")]
+    pub forced_direction: i16,
+    #[serde(rename = "actorId", deserialize_with = "float")]
+    pub actor_id: f64,
+}
+
+/// `GameMapMovementRequestMessage`, id 2572
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameMapMovementRequestMessage {
+    #[serde(rename = "keyMovements")]
+    pub key_movements: Vec<i16>,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+}
+
+/// `GameMapNoMovementMessage`, id 6782
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameMapNoMovementMessage {
+    #[serde(rename = "cellX")]
+    pub cell_x: i16,
+    #[serde(rename = "cellY")]
+    pub cell_y: i16,
+}
+
+/// `GameRefreshMonsterBoostsMessage`, id 3014
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRefreshMonsterBoostsMessage {
+    #[serde(rename = "monsterBoosts")]
+    pub monster_boosts: Vec<MonsterBoosts>,
+    #[serde(rename = "familyBoosts")]
+    pub family_boosts: Vec<MonsterBoosts>,
+}
+
+/// `GameRolePlayActorInformations`, id 3676
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayActorInformations {
+    #[serde(rename = "contextualId", deserialize_with = "float")]
+    pub contextual_id: f64,
+    pub disposition: Box<EntityDispositionInformationsKind>,
+    pub look: EntityLook,
+}
+
+/// `GameRolePlayAggressionMessage`, id 6091
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayAggressionMessage {
+    #[serde(rename = "attackerId")]
+    pub attacker_id: u64,
+    #[serde(rename = "defenderId")]
+    pub defender_id: u64,
+}
+
+/// `GameRolePlayArenaFightAnswerMessage`, id 2112
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayArenaFightAnswerMessage {
+    #[serde(rename = "fightId")]
+    pub fight_id: u16,
+    pub accept: bool,
+}
+
+/// `GameRolePlayArenaFightPropositionMessage`, id 3773
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayArenaFightPropositionMessage {
+    #[serde(rename = "fightId")]
+    pub fight_id: u16,
+    #[serde(rename = "alliesId", deserialize_with = "floats")]
+    pub allies_id: Vec<f64>,
+    pub duration: u16,
+}
+
+/// `GameRolePlayArenaFighterStatusMessage`, id 7632
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayArenaFighterStatusMessage {
+    #[serde(rename = "fightId")]
+    pub fight_id: u16,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+    pub accepted: bool,
+}
+
+/// `GameRolePlayArenaInvitationCandidatesAnswerMessage`, id 8986
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayArenaInvitationCandidatesAnswerMessage {
+    pub candidates: Vec<LeagueFriendInformations>,
+}
+
+/// `GameRolePlayArenaLeagueRewardsMessage`, id 5646
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayArenaLeagueRewardsMessage {
+    #[serde(rename = "seasonId")]
+    pub season_id: u16,
+    #[serde(rename = "leagueId")]
+    pub league_id: u16,
+    #[serde(rename = "ladderPosition")]
+    pub ladder_position: i32,
+    #[serde(rename = "endSeasonReward")]
+    pub end_season_reward: bool,
+}
+
+/// `GameRolePlayArenaPlayerBehavioursMessage`, id 6735
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayArenaPlayerBehavioursMessage {
+    pub flags: Vec<String>,
+    pub sanctions: Vec<String>,
+    #[serde(rename = "banDuration")]
+    pub ban_duration: i32,
+}
+
+/// `GameRolePlayArenaRegisterMessage`, id 6088
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayArenaRegisterMessage {
+    #[serde(rename = "arenaType")]
+    pub arena_type: i32,
+}
+
+/// `GameRolePlayArenaRegistrationStatusMessage`, id 2374
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayArenaRegistrationStatusMessage {
+    pub registered: bool,
+    pub step: i8,
+    #[serde(rename = "battleMode")]
+    pub battle_mode: i32,
+}
+
+/// `GameRolePlayArenaRegistrationWarningMessage`, id 5553
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayArenaRegistrationWarningMessage {
+    #[serde(rename = "battleMode")]
+    pub battle_mode: i32,
+}
+
+/// `GameRolePlayArenaSwitchToFightServerMessage`, id 7992
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayArenaSwitchToFightServerMessage {
+    pub address: String,
+    pub ports: Vec<u16>,
+    pub token: String,
+}
+
+/// `GameRolePlayArenaSwitchToGameServerMessage`, id 8247
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayArenaSwitchToGameServerMessage {
+    #[serde(rename = "validToken")]
+    pub valid_token: bool,
+    pub token: String,
+    #[serde(rename = "homeServerId")]
+    pub home_server_id: i16,
+}
+
+/// `GameRolePlayArenaUnregisterMessage`, id 6928
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GameRolePlayArenaUnregisterMessage {}
+
+/// `GameRolePlayArenaUpdatePlayerInfosMessage`, id 5854
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayArenaUpdatePlayerInfosMessage {
+    #[serde(rename = "arenaRanks")]
+    pub arena_ranks: Vec<ArenaRankInfos>,
+    #[serde(rename = "banEndDate", deserialize_with = "float")]
+    pub ban_end_date: f64,
+}
+
+/// `GameRolePlayAttackMonsterRequestMessage`, id 6190
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayAttackMonsterRequestMessage {
+    #[serde(rename = "monsterGroupId", deserialize_with = "float")]
+    pub monster_group_id: f64,
+}
+
+/// `GameRolePlayCharacterInformations`, id 3924
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayCharacterInformations {
+    #[serde(rename = "contextualId", deserialize_with = "float")]
+    pub contextual_id: f64,
+    pub disposition: Box<EntityDispositionInformationsKind>,
+    pub look: EntityLook,
+    pub name: String,
+    #[serde(rename = "humanoidInfo")]
+    pub humanoid_info: Box<HumanInformationsKind>,
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+    #[serde(rename = "alignmentInfos")]
+    pub alignment_infos: ActorAlignmentInformations,
+}
+
+/// `GameRolePlayDelayedActionFinishedMessage`, id 1043
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayDelayedActionFinishedMessage {
+    #[serde(rename = "delayedCharacterId", deserialize_with = "float")]
+    pub delayed_character_id: f64,
+    #[serde(rename = "delayTypeId")]
+    pub delay_type_id: i8,
+}
+
+/// `GameRolePlayDelayedActionMessage`, id 1933
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayDelayedActionMessage {
+    #[serde(rename = "delayedCharacterId", deserialize_with = "float")]
+    pub delayed_character_id: f64,
+    #[serde(rename = "delayTypeId")]
+    pub delay_type_id: i8,
+    #[serde(rename = "delayEndTime", deserialize_with = "float")]
+    pub delay_end_time: f64,
+}
+
+/// `GameRolePlayDelayedObjectUseMessage`, id 4595
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayDelayedObjectUseMessage {
+    #[serde(rename = "delayedCharacterId", deserialize_with = "float")]
+    pub delayed_character_id: f64,
+    #[serde(rename = "delayTypeId")]
+    pub delay_type_id: i8,
+    #[serde(rename = "delayEndTime", deserialize_with = "float")]
+    pub delay_end_time: f64,
+    #[serde(rename = "objectGID")]
+    pub object_gid: u32,
+}
+
+/// `GameRolePlayFightRequestCanceledMessage`, id 9286
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayFightRequestCanceledMessage {
+    #[serde(rename = "fightId")]
+    pub fight_id: u16,
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+}
+
+/// `GameRolePlayFreeSoulRequestMessage`, id 7546
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GameRolePlayFreeSoulRequestMessage {}
+
+/// `GameRolePlayGameOverMessage`, id 2110
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GameRolePlayGameOverMessage {}
+
+/// `GameRolePlayGroupMonsterInformations`, id 9085
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayGroupMonsterInformations {
+    #[serde(rename = "contextualId", deserialize_with = "float")]
+    pub contextual_id: f64,
+    pub disposition: Box<EntityDispositionInformationsKind>,
+    pub look: EntityLook,
+    #[serde(rename = "staticInfos")]
+    pub static_infos: Box<GroupMonsterStaticInformationsKind>,
+    #[serde(rename = "lootShare")]
+    pub loot_share: i8,
+    #[serde(rename = "alignmentSide")]
+    pub alignment_side: i8,
+    #[serde(rename = "hasHardcoreDrop")]
+    pub has_hardcore_drop: bool,
+}
+
+/// `GameRolePlayGroupMonsterWaveInformations`, id 2546
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayGroupMonsterWaveInformations {
+    #[serde(rename = "contextualId", deserialize_with = "float")]
+    pub contextual_id: f64,
+    pub disposition: Box<EntityDispositionInformationsKind>,
+    pub look: EntityLook,
+    #[serde(rename = "staticInfos")]
+    pub static_infos: Box<GroupMonsterStaticInformationsKind>,
+    #[serde(rename = "lootShare")]
+    pub loot_share: i8,
+    #[serde(rename = "alignmentSide")]
+    pub alignment_side: i8,
+    #[serde(rename = "hasHardcoreDrop")]
+    pub has_hardcore_drop: bool,
+    #[serde(rename = "nbWaves")]
+    pub nb_waves: i8,
+    pub alternatives: Vec<GroupMonsterStaticInformationsKind>,
+}
+
+/// `GameRolePlayHumanoidInformations`, id 206
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayHumanoidInformations {
+    #[serde(rename = "contextualId", deserialize_with = "float")]
+    pub contextual_id: f64,
+    pub disposition: Box<EntityDispositionInformationsKind>,
+    pub look: EntityLook,
+    pub name: String,
+    #[serde(rename = "humanoidInfo")]
+    pub humanoid_info: Box<HumanInformationsKind>,
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+}
+
+/// `GameRolePlayMonsterAngryAtPlayerMessage`, id 6385
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayMonsterAngryAtPlayerMessage {
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+    #[serde(rename = "monsterGroupId", deserialize_with = "float")]
+    pub monster_group_id: f64,
+    #[serde(rename = "angryStartTime", deserialize_with = "float")]
+    pub angry_start_time: f64,
+    #[serde(rename = "attackTime", deserialize_with = "float")]
+    pub attack_time: f64,
+}
+
+/// `GameRolePlayMonsterNotAngryAtPlayerMessage`, id 8932
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayMonsterNotAngryAtPlayerMessage {
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+    #[serde(rename = "monsterGroupId", deserialize_with = "float")]
+    pub monster_group_id: f64,
+}
+
+/// `GameRolePlayMountInformations`, id 7627
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayMountInformations {
+    #[serde(rename = "contextualId", deserialize_with = "float")]
+    pub contextual_id: f64,
+    pub disposition: Box<EntityDispositionInformationsKind>,
+    pub look: EntityLook,
+    pub name: String,
+    #[serde(rename = "ownerName")]
+    pub owner_name: String,
+    pub level: i8,
+}
+
+/// `GameRolePlayMutantInformations`, id 3898
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayMutantInformations {
+    #[serde(rename = "contextualId", deserialize_with = "float")]
+    pub contextual_id: f64,
+    pub disposition: Box<EntityDispositionInformationsKind>,
+    pub look: EntityLook,
+    pub name: String,
+    #[serde(rename = "humanoidInfo")]
+    pub humanoid_info: Box<HumanInformationsKind>,
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+    #[serde(rename = "monsterId")]
+    pub monster_id: u16,
+    #[serde(rename = "powerLevel")]
+    pub power_level: i8,
+}
+
+/// `GameRolePlayNamedActorInformations`, id 2435
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayNamedActorInformations {
+    #[serde(rename = "contextualId", deserialize_with = "float")]
+    pub contextual_id: f64,
+    pub disposition: Box<EntityDispositionInformationsKind>,
+    pub look: EntityLook,
+    pub name: String,
+}
+
+/// `GameRolePlayNpcInformations`, id 7927
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayNpcInformations {
+    #[serde(rename = "contextualId", deserialize_with = "float")]
+    pub contextual_id: f64,
+    pub disposition: Box<EntityDispositionInformationsKind>,
+    pub look: EntityLook,
+    #[serde(rename = "npcId")]
+    pub npc_id: u16,
+    pub sex: bool,
+    #[serde(rename = "specialArtworkId")]
+    pub special_artwork_id: u16,
+}
+
+/// `GameRolePlayNpcQuestFlag`, id 6705
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayNpcQuestFlag {
+    #[serde(rename = "questsToValidId")]
+    pub quests_to_valid_id: Vec<u16>,
+    #[serde(rename = "questsToStartId")]
+    pub quests_to_start_id: Vec<u16>,
+}
+
+/// `GameRolePlayNpcWithQuestInformations`, id 1240
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayNpcWithQuestInformations {
+    #[serde(rename = "contextualId", deserialize_with = "float")]
+    pub contextual_id: f64,
+    pub disposition: Box<EntityDispositionInformationsKind>,
+    pub look: EntityLook,
+    #[serde(rename = "npcId")]
+    pub npc_id: u16,
+    pub sex: bool,
+    #[serde(rename = "specialArtworkId")]
+    pub special_artwork_id: u16,
+    #[serde(rename = "questFlag")]
+    pub quest_flag: GameRolePlayNpcQuestFlag,
+}
+
+/// `GameRolePlayPlayerFightFriendlyAnswerMessage`, id 9327
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayPlayerFightFriendlyAnswerMessage {
+    #[serde(rename = "fightId")]
+    pub fight_id: u16,
+    pub accept: bool,
+}
+
+/// `GameRolePlayPlayerFightFriendlyAnsweredMessage`, id 8608
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayPlayerFightFriendlyAnsweredMessage {
+    #[serde(rename = "fightId")]
+    pub fight_id: u16,
+    #[serde(rename = "sourceId")]
+    pub source_id: u64,
+    #[serde(rename = "targetId")]
+    pub target_id: u64,
+    pub accept: bool,
+}
+
+/// `GameRolePlayPlayerFightFriendlyRequestedMessage`, id 4876
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayPlayerFightFriendlyRequestedMessage {
+    #[serde(rename = "fightId")]
+    pub fight_id: u16,
+    #[serde(rename = "sourceId")]
+    pub source_id: u64,
+    #[serde(rename = "targetId")]
+    pub target_id: u64,
+}
+
+/// `GameRolePlayPlayerFightRequestMessage`, id 8488
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayPlayerFightRequestMessage {
+    #[serde(rename = "targetId")]
+    pub target_id: u64,
+    #[serde(rename = "targetCellId")]
+    pub target_cell_id: i16,
+    pub friendly: bool,
+}
+
+/// `GameRolePlayPlayerLifeStatusMessage`, id 3890
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayPlayerLifeStatusMessage {
+    pub state: i8,
+    #[serde(rename = "phenixMapId", deserialize_with = "float")]
+    pub phenix_map_id: f64,
+}
+
+/// `GameRolePlayPortalInformations`, id 5569
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayPortalInformations {
+    #[serde(rename = "contextualId", deserialize_with = "float")]
+    pub contextual_id: f64,
+    pub disposition: Box<EntityDispositionInformationsKind>,
+    pub look: EntityLook,
+    pub portal: Box<PortalInformationKind>,
+}
+
+/// `GameRolePlayPrismInformations`, id 4347
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayPrismInformations {
+    #[serde(rename = "contextualId", deserialize_with = "float")]
+    pub contextual_id: f64,
+    pub disposition: Box<EntityDispositionInformationsKind>,
+    pub look: EntityLook,
+    pub prism: Box<PrismInformationKind>,
+}
+
+/// `GameRolePlayRemoveChallengeMessage`, id 971
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayRemoveChallengeMessage {
+    #[serde(rename = "fightId")]
+    pub fight_id: u16,
+}
+
+/// `GameRolePlayShowActorMessage`, id 1338
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayShowActorMessage {
+    pub informations: Box<GameRolePlayActorInformationsKind>,
+}
+
+/// `GameRolePlayShowActorWithEventMessage`, id 5787
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayShowActorWithEventMessage {
+    pub informations: Box<GameRolePlayActorInformationsKind>,
+    #[serde(rename = "actorEventId")]
+    pub actor_event_id: i8,
+}
+
+/// `GameRolePlayShowChallengeMessage`, id 4727
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayShowChallengeMessage {
+    #[serde(rename = "commonsInfos")]
+    pub commons_infos: FightCommonInformations,
+}
+
+/// `GameRolePlayShowMultipleActorsMessage`, id 6824
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayShowMultipleActorsMessage {
+    #[serde(rename = "informationsList")]
+    pub informations_list: Vec<GameRolePlayActorInformationsKind>,
+}
+
+/// `GameRolePlaySpellAnimMessage`, id 9686
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlaySpellAnimMessage {
+    #[serde(rename = "casterId")]
+    pub caster_id: u64,
+    #[serde(rename = "targetCellId")]
+    pub target_cell_id: u16,
+    #[serde(rename = "spellId")]
+    pub spell_id: u16,
+    #[serde(rename = "spellLevel")]
+    pub spell_level: i16,
+    pub direction: i16,
+}
+
+/// `GameRolePlayTaxCollectorFightRequestMessage`, id 5023
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GameRolePlayTaxCollectorFightRequestMessage {}
+
+/// `GameRolePlayTaxCollectorInformations`, id 6587
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayTaxCollectorInformations {
+    #[serde(rename = "contextualId", deserialize_with = "float")]
+    pub contextual_id: f64,
+    pub disposition: Box<EntityDispositionInformationsKind>,
+    pub look: EntityLook,
+    pub identification: Box<TaxCollectorStaticInformationsKind>,
+    #[serde(rename = "taxCollectorAttack")]
+    pub tax_collector_attack: i32,
+}
+
+/// `GameRolePlayTreasureHintInformations`, id 5267
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameRolePlayTreasureHintInformations {
+    #[serde(rename = "contextualId", deserialize_with = "float")]
+    pub contextual_id: f64,
+    pub disposition: Box<EntityDispositionInformationsKind>,
+    pub look: EntityLook,
+    #[serde(rename = "npcId")]
+    pub npc_id: u16,
+}
+
+/// `GameServerInformations`, id 9294
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameServerInformations {
+    #[serde(rename = "isMonoAccount")]
+    pub is_mono_account: bool,
+    #[serde(rename = "isSelectable")]
+    pub is_selectable: bool,
+    pub id: u16,
+    pub r#type: i8,
+    pub status: i8,
+    pub completion: i8,
+    #[serde(rename = "charactersCount")]
+    pub characters_count: i8,
+    #[serde(rename = "charactersSlots")]
+    pub characters_slots: i8,
+    #[serde(deserialize_with = "float")]
+    pub date: f64,
+}
+
+/// `GoldAddedMessage`, id 4979
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GoldAddedMessage {
+    pub gold: GoldItem,
+}
+
+/// `GoldItem`, id 1772
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GoldItem {
+    pub sum: u64,
+}
+
+/// `GroupMonsterStaticInformations`, id 1952
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GroupMonsterStaticInformations {
+    #[serde(rename = "mainCreatureLightInfos")]
+    pub main_creature_light_infos: MonsterInGroupLightInformations,
+    pub underlings: Vec<MonsterInGroupInformations>,
+}
+
+/// `GroupMonsterStaticInformationsWithAlternatives`, id 7716
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GroupMonsterStaticInformationsWithAlternatives {
+    #[serde(rename = "mainCreatureLightInfos")]
+    pub main_creature_light_infos: MonsterInGroupLightInformations,
+    pub underlings: Vec<MonsterInGroupInformations>,
+    pub alternatives: Vec<AlternativeMonstersInGroupLightInformations>,
+}
+
+/// `GroupTeleportPlayerAnswerMessage`, id 4682
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GroupTeleportPlayerAnswerMessage {
+    pub accept: bool,
+    #[serde(rename = "requesterId")]
+    pub requester_id: u64,
+}
+
+/// `GroupTeleportPlayerCloseMessage`, id 5607
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GroupTeleportPlayerCloseMessage {
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    #[serde(rename = "requesterId")]
+    pub requester_id: u64,
+}
+
+/// `GroupTeleportPlayerOfferMessage`, id 7063
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GroupTeleportPlayerOfferMessage {
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    #[serde(rename = "worldX")]
+    pub world_x: i16,
+    #[serde(rename = "worldY")]
+    pub world_y: i16,
+    #[serde(rename = "timeLeft")]
+    pub time_left: u32,
+    #[serde(rename = "requesterId")]
+    pub requester_id: u64,
+    #[serde(rename = "requesterName")]
+    pub requester_name: String,
+}
+
+/// `GuestLimitationMessage`, id 4137
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuestLimitationMessage {
+    pub reason: i8,
+}
+
+/// `GuestModeMessage`, id 4353
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuestModeMessage {
+    pub active: bool,
+}
+
+/// `GuidedModeQuitRequestMessage`, id 6592
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GuidedModeQuitRequestMessage {}
+
+/// `GuidedModeReturnRequestMessage`, id 6979
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GuidedModeReturnRequestMessage {}
+
+/// `GuildApplicationAnswerMessage`, id 7158
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildApplicationAnswerMessage {
+    pub accepted: bool,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+}
+
+/// `GuildApplicationDeletedMessage`, id 5082
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildApplicationDeletedMessage {
+    pub deleted: bool,
+}
+
+/// `GuildApplicationIsAnsweredMessage`, id 4939
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildApplicationIsAnsweredMessage {
+    pub accepted: bool,
+    #[serde(rename = "guildInformation")]
+    pub guild_information: GuildInformations,
+}
+
+/// `GuildApplicationListenMessage`, id 5549
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildApplicationListenMessage {
+    pub listen: bool,
+}
+
+/// `GuildApplicationPresenceMessage`, id 5980
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildApplicationPresenceMessage {
+    #[serde(rename = "isApplication")]
+    pub is_application: bool,
+}
+
+/// `GuildApplicationReceivedMessage`, id 114
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildApplicationReceivedMessage {
+    #[serde(rename = "playerName")]
+    pub player_name: String,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+}
+
+/// `GuildBulletinMessage`, id 4247
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildBulletinMessage {
+    pub content: String,
+    pub timestamp: i32,
+    #[serde(rename = "memberId")]
+    pub member_id: u64,
+    #[serde(rename = "memberName")]
+    pub member_name: String,
+}
+
+/// `GuildBulletinSetErrorMessage`, id 8299
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildBulletinSetErrorMessage {
+    pub reason: i8,
+}
+
+/// `GuildBulletinSetRequestMessage`, id 8237
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildBulletinSetRequestMessage {
+    pub content: String,
+}
+
+/// `GuildChangeMemberParametersMessage`, id 1637
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildChangeMemberParametersMessage {
+    #[serde(rename = "memberId")]
+    pub member_id: u64,
+    #[serde(rename = "rankId")]
+    pub rank_id: u32,
+    #[serde(rename = "experienceGivenPercent")]
+    pub experience_given_percent: i8,
+}
+
+/// `GuildCharacsUpgradeRequestMessage`, id 4530
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildCharacsUpgradeRequestMessage {
+    #[serde(rename = "charaTypeTarget")]
+    pub chara_type_target: i8,
+}
+
+/// `GuildChestTabContributionMessage`, id 5650
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildChestTabContributionMessage {
+    #[serde(rename = "tabNumber")]
+    pub tab_number: u32,
+    #[serde(rename = "requiredAmount")]
+    pub required_amount: u64,
+    #[serde(rename = "currentAmount")]
+    pub current_amount: u64,
+    #[serde(rename = "chestContributionEnrollmentDelay", deserialize_with = "float")]
+    pub chest_contribution_enrollment_delay: f64,
+    #[serde(rename = "chestContributionDelay", deserialize_with = "float")]
+    pub chest_contribution_delay: f64,
+}
+
+/// `GuildChestTabContributionsMessage`, id 4062
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildChestTabContributionsMessage {
+    pub contributions: Vec<Contribution>,
+}
+
+/// `GuildChestTabLastContributionMessage`, id 6777
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildChestTabLastContributionMessage {
+    #[serde(rename = "lastContributionDate", deserialize_with = "float")]
+    pub last_contribution_date: f64,
+}
+
+/// `GuildCreationResultMessage`, id 8233
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildCreationResultMessage {
+    pub result: i8,
+}
+
+/// `GuildCreationStartedMessage`, id 7673
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GuildCreationStartedMessage {}
+
+/// `GuildCreationValidMessage`, id 2815
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildCreationValidMessage {
+    #[serde(rename = "guildName")]
+    pub guild_name: String,
+    #[serde(rename = "guildEmblem")]
+    pub guild_emblem: SocialEmblem,
+}
+
+/// `GuildDeleteApplicationRequestMessage`, id 1274
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GuildDeleteApplicationRequestMessage {}
+
+/// `GuildFactSheetInformations`, id 6548
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildFactSheetInformations {
+    #[serde(rename = "guildId")]
+    pub guild_id: u32,
+    #[serde(rename = "guildName")]
+    pub guild_name: String,
+    #[serde(rename = "guildLevel")]
+    pub guild_level: i8,
+    #[serde(rename = "guildEmblem")]
+    pub guild_emblem: SocialEmblem,
+    #[serde(rename = "leaderId")]
+    pub leader_id: u64,
+    #[serde(rename = "nbMembers")]
+    pub nb_members: u16,
+    #[serde(rename = "lastActivityDay")]
+    pub last_activity_day: i16,
+    pub recruitment: GuildRecruitmentInformation,
+    #[serde(rename = "nbPendingApply")]
+    pub nb_pending_apply: i32,
+}
+
+/// `GuildFactsErrorMessage`, id 1362
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildFactsErrorMessage {
+    #[serde(rename = "guildId")]
+    pub guild_id: u32,
+}
+
+/// `GuildFactsMessage`, id 1149
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildFactsMessage {
+    pub infos: Box<GuildFactSheetInformationsKind>,
+    #[serde(rename = "creationDate")]
+    pub creation_date: i32,
+    pub members: Vec<CharacterMinimalSocialPublicInformations>,
+}
+
+/// `GuildFactsRequestMessage`, id 2181
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildFactsRequestMessage {
+    #[serde(rename = "guildId")]
+    pub guild_id: u32,
+}
+
+/// `GuildGetChestTabContributionsRequestMessage`, id 8671
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GuildGetChestTabContributionsRequestMessage {}
+
+/// `GuildGetInformationsMessage`, id 6217
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildGetInformationsMessage {
+    #[serde(rename = "infoType")]
+    pub info_type: i8,
+}
+
+/// `GuildGetPlayerApplicationMessage`, id 3576
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GuildGetPlayerApplicationMessage {}
+
+/// `GuildHouseRemoveMessage`, id 250
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildHouseRemoveMessage {
+    #[serde(rename = "houseId")]
+    pub house_id: u32,
+    #[serde(rename = "instanceId")]
+    pub instance_id: i32,
+    #[serde(rename = "secondHand")]
+    pub second_hand: bool,
+}
+
+/// `GuildHouseUpdateInformationMessage`, id 9569
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildHouseUpdateInformationMessage {
+    #[serde(rename = "housesInformations")]
+    pub houses_informations: HouseInformationsForGuild,
+}
+
+/// `GuildHousesInformationMessage`, id 7768
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildHousesInformationMessage {
+    #[serde(rename = "housesInformations")]
+    pub houses_informations: Vec<HouseInformationsForGuild>,
+}
+
+/// `GuildInformations`, id 7125
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildInformations {
+    #[serde(rename = "guildId")]
+    pub guild_id: u32,
+    #[serde(rename = "guildName")]
+    pub guild_name: String,
+    #[serde(rename = "guildLevel")]
+    pub guild_level: i8,
+    #[serde(rename = "guildEmblem")]
+    pub guild_emblem: SocialEmblem,
+}
+
+/// `GuildInformationsGeneralMessage`, id 1991
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildInformationsGeneralMessage {
+    #[serde(rename = "abandonnedPaddock")]
+    pub abandonned_paddock: bool,
+    pub level: i8,
+    #[serde(rename = "expLevelFloor")]
+    pub exp_level_floor: u64,
+    pub experience: u64,
+    #[serde(rename = "expNextLevelFloor")]
+    pub exp_next_level_floor: u64,
+    #[serde(rename = "creationDate")]
+    pub creation_date: i32,
+    pub score: i32,
+}
+
+/// `GuildInformationsMemberUpdateMessage`, id 4123
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildInformationsMemberUpdateMessage {
+    pub member: GuildMemberInfo,
+}
+
+/// `GuildInformationsMembersMessage`, id 4402
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildInformationsMembersMessage {
+    pub members: Vec<GuildMemberInfo>,
+}
+
+/// `GuildInformationsPaddocksMessage`, id 6226
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildInformationsPaddocksMessage {
+    #[serde(rename = "nbPaddockMax")]
+    pub nb_paddock_max: i8,
+    #[serde(rename = "paddocksInformations")]
+    pub paddocks_informations: Vec<PaddockContentInformations>,
+}
+
+/// `GuildInsiderFactSheetInformations`, id 7914
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildInsiderFactSheetInformations {
+    #[serde(rename = "guildId")]
+    pub guild_id: u32,
+    #[serde(rename = "guildName")]
+    pub guild_name: String,
+    #[serde(rename = "guildLevel")]
+    pub guild_level: i8,
+    #[serde(rename = "guildEmblem")]
+    pub guild_emblem: SocialEmblem,
+    #[serde(rename = "leaderId")]
+    pub leader_id: u64,
+    #[serde(rename = "nbMembers")]
+    pub nb_members: u16,
+    #[serde(rename = "lastActivityDay")]
+    pub last_activity_day: i16,
+    pub recruitment: GuildRecruitmentInformation,
+    #[serde(rename = "nbPendingApply")]
+    pub nb_pending_apply: i32,
+    #[serde(rename = "leaderName")]
+    pub leader_name: String,
+}
+
+/// `GuildInvitationAnswerMessage`, id 1199
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildInvitationAnswerMessage {
+    pub accept: bool,
+}
+
+/// `GuildInvitationMessage`, id 46
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildInvitationMessage {
+    #[serde(rename = "targetId")]
+    pub target_id: u64,
+}
+
+/// `GuildInvitationStateRecrutedMessage`, id 762
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildInvitationStateRecrutedMessage {
+    #[serde(rename = "invitationState")]
+    pub invitation_state: i8,
+}
+
+/// `GuildInvitationStateRecruterMessage`, id 7952
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildInvitationStateRecruterMessage {
+    #[serde(rename = "recrutedName")]
+    pub recruted_name: String,
+    #[serde(rename = "invitationState")]
+    pub invitation_state: i8,
+}
+
+/// `GuildInvitedMessage`, id 1539
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildInvitedMessage {
+    #[serde(rename = "recruterName")]
+    pub recruter_name: String,
+    #[serde(rename = "guildInfo")]
+    pub guild_info: GuildInformations,
+}
+
+/// `GuildIsThereAnyApplicationMessage`, id 6927
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GuildIsThereAnyApplicationMessage {}
+
+/// `GuildJoinAutomaticallyRequestMessage`, id 8254
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildJoinAutomaticallyRequestMessage {
+    #[serde(rename = "guildId")]
+    pub guild_id: i32,
+}
+
+/// `GuildJoinedMessage`, id 7531
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildJoinedMessage {
+    #[serde(rename = "guildInfo")]
+    pub guild_info: GuildInformations,
+    #[serde(rename = "rankId")]
+    pub rank_id: u32,
+}
+
+/// `GuildKickRequestMessage`, id 291
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildKickRequestMessage {
+    #[serde(rename = "kickedId")]
+    pub kicked_id: u64,
+}
+
+/// `GuildLeftMessage`, id 5689
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GuildLeftMessage {}
+
+/// `GuildLevelUpActivity`, id 8975
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildLevelUpActivity {
+    pub id: u32,
+    #[serde(deserialize_with = "float")]
+    pub date: f64,
+    #[serde(rename = "newGuildLevel")]
+    pub new_guild_level: i8,
+}
+
+/// `GuildLevelUpMessage`, id 4852
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildLevelUpMessage {
+    #[serde(rename = "newLevel")]
+    pub new_level: i8,
+}
+
+/// `GuildListApplicationAnswerMessage`, id 1671
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildListApplicationAnswerMessage {
+    #[serde(deserialize_with = "float")]
+    pub offset: f64,
+    pub count: u32,
+    pub total: u32,
+    pub applies: Vec<SocialApplicationInformation>,
+}
+
+/// `GuildListApplicationModifiedMessage`, id 2025
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildListApplicationModifiedMessage {
+    pub apply: SocialApplicationInformation,
+    pub state: i8,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+}
+
+/// `GuildListApplicationRequestMessage`, id 5311
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildListApplicationRequestMessage {
+    #[serde(deserialize_with = "float")]
+    pub offset: f64,
+    pub count: u32,
+}
+
+/// `GuildListMessage`, id 4260
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildListMessage {
+    pub guilds: Vec<GuildInformations>,
+}
+
+/// `GuildLogbookChestActivity`, id 1784
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildLogbookChestActivity {
+    pub id: u32,
+    #[serde(deserialize_with = "float")]
+    pub date: f64,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+    #[serde(rename = "playerName")]
+    pub player_name: String,
+    #[serde(rename = "eventType")]
+    pub event_type: i8,
+    pub quantity: i32,
+    pub object: ObjectItemNotInContainer,
+    #[serde(rename = "sourceTabId")]
+    pub source_tab_id: i32,
+    #[serde(rename = "destinationTabId")]
+    pub destination_tab_id: i32,
+}
+
+/// `GuildLogbookEntryBasicInformation`, id 3539
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildLogbookEntryBasicInformation {
+    pub id: u32,
+    #[serde(deserialize_with = "float")]
+    pub date: f64,
+}
+
+/// `GuildLogbookInformationMessage`, id 8089
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildLogbookInformationMessage {
+    #[serde(rename = "globalActivities")]
+    pub global_activities: Vec<GuildLogbookEntryBasicInformationKind>,
+    #[serde(rename = "chestActivities")]
+    pub chest_activities: Vec<GuildLogbookEntryBasicInformationKind>,
+}
+
+/// `GuildLogbookInformationRequestMessage`, id 6225
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GuildLogbookInformationRequestMessage {}
+
+/// `GuildMemberInfo`, id 1244
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildMemberInfo {
+    pub id: u64,
+    pub name: String,
+    pub level: u16,
+    pub breed: i8,
+    pub sex: bool,
+    pub connected: i8,
+    #[serde(rename = "hoursSinceLastConnection")]
+    pub hours_since_last_connection: i16,
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+    pub status: Box<PlayerStatusKind>,
+    #[serde(rename = "rankId")]
+    pub rank_id: i32,
+    #[serde(rename = "enrollmentDate", deserialize_with = "float")]
+    pub enrollment_date: f64,
+    #[serde(rename = "givenExperience")]
+    pub given_experience: u64,
+    #[serde(rename = "experienceGivenPercent")]
+    pub experience_given_percent: i8,
+    #[serde(rename = "alignmentSide")]
+    pub alignment_side: i8,
+    #[serde(rename = "moodSmileyId")]
+    pub mood_smiley_id: u16,
+    #[serde(rename = "achievementPoints")]
+    pub achievement_points: i32,
+    #[serde(rename = "havenBagShared")]
+    pub haven_bag_shared: bool,
+    pub note: PlayerNote,
+}
+
+/// `GuildMemberLeavingMessage`, id 1205
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildMemberLeavingMessage {
+    pub kicked: bool,
+    #[serde(rename = "memberId")]
+    pub member_id: u64,
+}
+
+/// `GuildMemberOnlineStatusMessage`, id 4625
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildMemberOnlineStatusMessage {
+    #[serde(rename = "memberId")]
+    pub member_id: u64,
+    pub online: bool,
+}
+
+/// `GuildMemberStartWarnOnConnectionMessage`, id 8427
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GuildMemberStartWarnOnConnectionMessage {}
+
+/// `GuildMemberStopWarnOnConnectionMessage`, id 4002
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GuildMemberStopWarnOnConnectionMessage {}
+
+/// `GuildMembershipMessage`, id 2473
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildMembershipMessage {
+    #[serde(rename = "guildInfo")]
+    pub guild_info: GuildInformations,
+    #[serde(rename = "rankId")]
+    pub rank_id: u32,
+}
+
+/// `GuildModificationEmblemValidMessage`, id 1754
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildModificationEmblemValidMessage {
+    #[serde(rename = "guildEmblem")]
+    pub guild_emblem: SocialEmblem,
+}
+
+/// `GuildModificationNameValidMessage`, id 2309
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildModificationNameValidMessage {
+    #[serde(rename = "guildName")]
+    pub guild_name: String,
+}
+
+/// `GuildModificationResultMessage`, id 360
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildModificationResultMessage {
+    pub result: i8,
+}
+
+/// `GuildModificationStartedMessage`, id 7735
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildModificationStartedMessage {
+    #[serde(rename = "canChangeName")]
+    pub can_change_name: bool,
+    #[serde(rename = "canChangeEmblem")]
+    pub can_change_emblem: bool,
+}
+
+/// `GuildModificationValidMessage`, id 554
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildModificationValidMessage {
+    #[serde(rename = "guildName")]
+    pub guild_name: String,
+    #[serde(rename = "guildEmblem")]
+    pub guild_emblem: SocialEmblem,
+}
+
+/// `GuildMotdMessage`, id 425
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildMotdMessage {
+    pub content: String,
+    pub timestamp: i32,
+    #[serde(rename = "memberId")]
+    pub member_id: u64,
+    #[serde(rename = "memberName")]
+    pub member_name: String,
+}
+
+/// `GuildMotdSetErrorMessage`, id 5484
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildMotdSetErrorMessage {
+    pub reason: i8,
+}
+
+/// `GuildMotdSetRequestMessage`, id 2047
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildMotdSetRequestMessage {
+    pub content: String,
+}
+
+/// `GuildPaddockActivity`, id 8213
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildPaddockActivity {
+    pub id: u32,
+    #[serde(deserialize_with = "float")]
+    pub date: f64,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+    #[serde(rename = "playerName")]
+    pub player_name: String,
+    #[serde(rename = "paddockCoordinates")]
+    pub paddock_coordinates: MapCoordinatesExtended,
+    #[serde(rename = "farmId", deserialize_with = "float")]
+    pub farm_id: f64,
+    #[serde(rename = "paddockEventType")]
+    pub paddock_event_type: i8,
+}
+
+/// `GuildPaddockBoughtMessage`, id 2663
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildPaddockBoughtMessage {
+    #[serde(rename = "paddockInfo")]
+    pub paddock_info: PaddockContentInformations,
+}
+
+/// `GuildPaddockRemovedMessage`, id 3443
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildPaddockRemovedMessage {
+    #[serde(rename = "paddockId", deserialize_with = "float")]
+    pub paddock_id: f64,
+}
+
+/// `GuildPaddockTeleportRequestMessage`, id 5862
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildPaddockTeleportRequestMessage {
+    #[serde(rename = "paddockId", deserialize_with = "float")]
+    pub paddock_id: f64,
+}
+
+/// `GuildPlayerApplicationAbstractMessage`, id 6857
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GuildPlayerApplicationAbstractMessage {}
+
+/// `GuildPlayerApplicationInformationMessage`, id 7755
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildPlayerApplicationInformationMessage {
+    #[serde(rename = "guildInformation")]
+    pub guild_information: GuildInformations,
+    pub apply: SocialApplicationInformation,
+}
+
+/// `GuildPlayerFlowActivity`, id 148
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildPlayerFlowActivity {
+    pub id: u32,
+    #[serde(deserialize_with = "float")]
+    pub date: f64,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+    #[serde(rename = "playerName")]
+    pub player_name: String,
+    #[serde(rename = "playerFlowEventType")]
+    pub player_flow_event_type: i8,
+}
+
+/// `GuildPlayerNoApplicationInformationMessage`, id 6444
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GuildPlayerNoApplicationInformationMessage {}
+
+/// `GuildPlayerRankUpdateActivity`, id 7813
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildPlayerRankUpdateActivity {
+    pub id: u32,
+    #[serde(deserialize_with = "float")]
+    pub date: f64,
+    #[serde(rename = "guildRankMinimalInfos")]
+    pub guild_rank_minimal_infos: RankMinimalInformation,
+    #[serde(rename = "sourcePlayerId")]
+    pub source_player_id: u64,
+    #[serde(rename = "targetPlayerId")]
+    pub target_player_id: u64,
+    #[serde(rename = "sourcePlayerName")]
+    pub source_player_name: String,
+    #[serde(rename = "targetPlayerName")]
+    pub target_player_name: String,
+}
+
+/// `GuildRankActivity`, id 303
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildRankActivity {
+    pub id: u32,
+    #[serde(deserialize_with = "float")]
+    pub date: f64,
+    #[serde(rename = "rankActivityType")]
+    pub rank_activity_type: i8,
+    #[serde(rename = "guildRankMinimalInfos")]
+    pub guild_rank_minimal_infos: RankMinimalInformation,
+}
+
+/// `GuildRanksMessage`, id 433
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildRanksMessage {
+    pub ranks: Vec<RankInformation>,
+}
+
+/// `GuildRanksRequestMessage`, id 5540
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GuildRanksRequestMessage {}
+
+/// `GuildRecruitmentInformation`, id 3939
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildRecruitmentInformation {
+    #[serde(rename = "minLevelFacultative")]
+    pub min_level_facultative: bool,
+    #[serde(rename = "invalidatedByModeration")]
+    pub invalidated_by_moderation: bool,
+    #[serde(rename = "recruitmentAutoLocked")]
+    pub recruitment_auto_locked: bool,
+    #[serde(rename = "socialId")]
+    pub social_id: u32,
+    #[serde(rename = "recruitmentType")]
+    pub recruitment_type: i8,
+    #[serde(rename = "recruitmentTitle")]
+    pub recruitment_title: String,
+    #[serde(rename = "recruitmentText")]
+    pub recruitment_text: String,
+    #[serde(rename = "selectedLanguages")]
+    pub selected_languages: Vec<u32>,
+    #[serde(rename = "selectedCriterion")]
+    pub selected_criterion: Vec<u32>,
+    #[serde(rename = "minLevel")]
+    pub min_level: i16,
+    #[serde(rename = "lastEditPlayerName")]
+    pub last_edit_player_name: String,
+    #[serde(rename = "lastEditDate", deserialize_with = "float")]
+    pub last_edit_date: f64,
+    #[serde(rename = "minSuccess")]
+    pub min_success: u32,
+    #[serde(rename = "minSuccessFacultative")]
+    pub min_success_facultative: bool,
+}
+
+/// `GuildRecruitmentInvalidateMessage`, id 5093
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GuildRecruitmentInvalidateMessage {}
+
+/// `GuildSelectChestTabRequestMessage`, id 4254
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildSelectChestTabRequestMessage {
+    #[serde(rename = "tabNumber")]
+    pub tab_number: u32,
+}
+
+/// `GuildSpellUpgradeRequestMessage`, id 4828
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildSpellUpgradeRequestMessage {
+    #[serde(rename = "spellId")]
+    pub spell_id: i32,
+}
+
+/// `GuildSubmitApplicationMessage`, id 3903
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildSubmitApplicationMessage {
+    #[serde(rename = "applyText")]
+    pub apply_text: String,
+    #[serde(rename = "guildId")]
+    pub guild_id: u32,
+    #[serde(rename = "timeSpent")]
+    pub time_spent: u32,
+    #[serde(rename = "filterLanguage")]
+    pub filter_language: String,
+    #[serde(rename = "filterAmbiance")]
+    pub filter_ambiance: String,
+    #[serde(rename = "filterPlaytime")]
+    pub filter_playtime: String,
+    #[serde(rename = "filterInterest")]
+    pub filter_interest: String,
+    #[serde(rename = "filterMinMaxGuildLevel")]
+    pub filter_min_max_guild_level: String,
+    #[serde(rename = "filterRecruitmentType")]
+    pub filter_recruitment_type: String,
+    #[serde(rename = "filterMinMaxCharacterLevel")]
+    pub filter_min_max_character_level: String,
+    #[serde(rename = "filterMinMaxAchievement")]
+    pub filter_min_max_achievement: String,
+    #[serde(rename = "filterSearchName")]
+    pub filter_search_name: String,
+    #[serde(rename = "filterLastSort")]
+    pub filter_last_sort: String,
+}
+
+/// `GuildSummaryMessage`, id 6955
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildSummaryMessage {
+    #[serde(deserialize_with = "float")]
+    pub offset: f64,
+    pub count: u32,
+    pub total: u32,
+    pub guilds: Vec<GuildFactSheetInformations>,
+}
+
+/// `GuildSummaryRequestMessage`, id 4368
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildSummaryRequestMessage {
+    #[serde(deserialize_with = "float")]
+    pub offset: f64,
+    pub count: u32,
+    #[serde(rename = "hideFullFilter")]
+    pub hide_full_filter: bool,
+    #[serde(rename = "followingGuildCriteria")]
+    pub following_guild_criteria: bool,
+    #[serde(rename = "sortDescending")]
+    pub sort_descending: bool,
+    #[serde(rename = "nameFilter")]
+    pub name_filter: String,
+    #[serde(rename = "criterionFilter")]
+    pub criterion_filter: Vec<u32>,
+    #[serde(rename = "languagesFilter")]
+    pub languages_filter: Vec<u32>,
+    #[serde(rename = "recruitmentTypeFilter")]
+    pub recruitment_type_filter: Vec<i8>,
+    #[serde(rename = "minLevelFilter")]
+    pub min_level_filter: i16,
+    #[serde(rename = "maxLevelFilter")]
+    pub max_level_filter: i16,
+    #[serde(rename = "minPlayerLevelFilter")]
+    pub min_player_level_filter: i16,
+    #[serde(rename = "maxPlayerLevelFilter")]
+    pub max_player_level_filter: i16,
+    #[serde(rename = "minSuccessFilter")]
+    pub min_success_filter: u32,
+    #[serde(rename = "maxSuccessFilter")]
+    pub max_success_filter: u32,
+    #[serde(rename = "sortType")]
+    pub sort_type: i8,
+}
+
+/// `GuildUnlockNewTabActivity`, id 2028
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildUnlockNewTabActivity {
+    pub id: u32,
+    #[serde(deserialize_with = "float")]
+    pub date: f64,
+}
+
+/// `GuildUpdateApplicationMessage`, id 1808
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildUpdateApplicationMessage {
+    #[serde(rename = "applyText")]
+    pub apply_text: String,
+    #[serde(rename = "guildId")]
+    pub guild_id: u32,
+}
+
+/// `GuildUpdateChestTabRequestMessage`, id 7784
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildUpdateChestTabRequestMessage {
+    pub tab: UpdatedStorageTabInformation,
+}
+
+/// `GuildUpdateNoteMessage`, id 2599
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildUpdateNoteMessage {
+    #[serde(rename = "memberId")]
+    pub member_id: u64,
+    pub note: String,
+}
+
+/// `HaapiApiKeyMessage`, id 9558
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HaapiApiKeyMessage {
+    pub token: String,
+}
+
+/// `HaapiApiKeyRequestMessage`, id 1736
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HaapiApiKeyRequestMessage {}
+
+/// `HaapiAuthErrorMessage`, id 1892
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HaapiAuthErrorMessage {
+    pub r#type: i8,
+}
+
+/// `HaapiBufferListMessage`, id 8253
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HaapiBufferListMessage {
+    pub buffers: Vec<BufferInformation>,
+}
+
+/// `HaapiBufferListRequestMessage`, id 4312
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HaapiBufferListRequestMessage {}
+
+/// `HaapiBuyValidationMessage`, id 4326
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HaapiBuyValidationMessage {
+    pub action: i8,
+    pub code: i8,
+    pub amount: u64,
+    pub email: String,
+}
+
+/// `HaapiCancelBidRequestMessage`, id 7710
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HaapiCancelBidRequestMessage {
+    pub id: u64,
+    pub r#type: i8,
+}
+
+/// `HaapiConfirmationMessage`, id 1131
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HaapiConfirmationMessage {
+    pub kamas: u64,
+    pub amount: u64,
+    pub rate: u16,
+    pub action: i8,
+    pub transaction: String,
+}
+
+/// `HaapiConfirmationRequestMessage`, id 4700
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HaapiConfirmationRequestMessage {
+    pub kamas: u64,
+    pub ogrines: u64,
+    pub rate: u16,
+    pub action: i8,
+}
+
+/// `HaapiConsumeBufferRequestMessage`, id 2854
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HaapiConsumeBufferRequestMessage {}
+
+/// `HaapiSessionMessage`, id 407
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HaapiSessionMessage {
+    pub key: String,
+    pub r#type: i8,
+}
+
+/// `HaapiShopApiKeyMessage`, id 5016
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HaapiShopApiKeyMessage {
+    pub token: String,
+}
+
+/// `HaapiShopApiKeyRequestMessage`, id 2737
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HaapiShopApiKeyRequestMessage {}
+
+/// `HaapiTokenMessage`, id 118
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HaapiTokenMessage {
+    pub token: String,
+}
+
+/// `HaapiTokenRequestMessage`, id 2263
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HaapiTokenRequestMessage {}
+
+/// `HaapiValidationMessage`, id 1774
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HaapiValidationMessage {
+    pub action: i8,
+    pub code: i8,
+}
+
+/// `HaapiValidationRequestMessage`, id 9709
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HaapiValidationRequestMessage {
+    pub transaction: String,
+}
+
+/// `HavenBagDailyLoteryMessage`, id 4465
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HavenBagDailyLoteryMessage {
+    #[serde(rename = "returnType")]
+    pub return_type: i8,
+    #[serde(rename = "gameActionId")]
+    pub game_action_id: String,
+}
+
+/// `HavenBagFurnitureInformation`, id 9917
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HavenBagFurnitureInformation {
+    #[serde(rename = "cellId")]
+    pub cell_id: u16,
+    #[serde(rename = "funitureId")]
+    pub funiture_id: i32,
+    pub orientation: i8,
+}
+
+/// `HavenBagFurnituresMessage`, id 4859
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HavenBagFurnituresMessage {
+    #[serde(rename = "furnituresInfos")]
+    pub furnitures_infos: Vec<HavenBagFurnitureInformation>,
+}
+
+/// `HavenBagFurnituresRequestMessage`, id 101
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HavenBagFurnituresRequestMessage {
+    #[serde(rename = "cellIds")]
+    pub cell_ids: Vec<u16>,
+    #[serde(rename = "funitureIds")]
+    pub funiture_ids: Vec<i32>,
+    pub orientations: Vec<i8>,
+}
+
+/// `HavenBagPackListMessage`, id 4724
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HavenBagPackListMessage {
+    #[serde(rename = "packIds")]
+    pub pack_ids: Vec<i8>,
+}
+
+/// `HavenBagPermissionsUpdateMessage`, id 1402
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HavenBagPermissionsUpdateMessage {
+    pub permissions: i32,
+}
+
+/// `HavenBagPermissionsUpdateRequestMessage`, id 7413
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HavenBagPermissionsUpdateRequestMessage {
+    pub permissions: i32,
+}
+
+/// `HavenBagRoomPreviewInformation`, id 3005
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HavenBagRoomPreviewInformation {
+    #[serde(rename = "roomId")]
+    pub room_id: i8,
+    #[serde(rename = "themeId")]
+    pub theme_id: i8,
+}
+
+/// `HavenBagRoomUpdateMessage`, id 5144
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HavenBagRoomUpdateMessage {
+    pub action: i8,
+    #[serde(rename = "roomsPreview")]
+    pub rooms_preview: Vec<HavenBagRoomPreviewInformation>,
+}
+
+/// `HelloConnectMessage`, id 1657
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HelloConnectMessage {
+    pub salt: String,
+    pub key: Vec<i8>,
+}
+
+/// `HelloGameMessage`, id 551
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HelloGameMessage {}
+
+/// `HouseBuyRequestMessage`, id 2301
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HouseBuyRequestMessage {
+    #[serde(rename = "proposedPrice")]
+    pub proposed_price: u64,
+}
+
+/// `HouseBuyResultMessage`, id 3428
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HouseBuyResultMessage {
+    #[serde(rename = "secondHand")]
+    pub second_hand: bool,
+    pub bought: bool,
+    #[serde(rename = "houseId")]
+    pub house_id: u32,
+    #[serde(rename = "instanceId")]
+    pub instance_id: i32,
+    #[serde(rename = "realPrice")]
+    pub real_price: u64,
+}
+
+/// `HouseGuildNoneMessage`, id 1415
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HouseGuildNoneMessage {
+    #[serde(rename = "houseId")]
+    pub house_id: u32,
+    #[serde(rename = "instanceId")]
+    pub instance_id: i32,
+    #[serde(rename = "secondHand")]
+    pub second_hand: bool,
+}
+
+/// `HouseGuildRightsMessage`, id 1509
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HouseGuildRightsMessage {
+    #[serde(rename = "houseId")]
+    pub house_id: u32,
+    #[serde(rename = "instanceId")]
+    pub instance_id: i32,
+    #[serde(rename = "secondHand")]
+    pub second_hand: bool,
+    #[serde(rename = "guildInfo")]
+    pub guild_info: GuildInformations,
+    pub rights: u32,
+}
+
+/// `HouseGuildRightsViewMessage`, id 9539
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HouseGuildRightsViewMessage {
+    #[serde(rename = "houseId")]
+    pub house_id: u32,
+    #[serde(rename = "instanceId")]
+    pub instance_id: i32,
+}
+
+/// `HouseGuildShareRequestMessage`, id 8816
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HouseGuildShareRequestMessage {
+    #[serde(rename = "houseId")]
+    pub house_id: u32,
+    #[serde(rename = "instanceId")]
+    pub instance_id: i32,
+    pub enable: bool,
+    pub rights: u32,
+}
+
+/// `HouseGuildedInformations`, id 4484
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HouseGuildedInformations {
+    #[serde(rename = "secondHand")]
+    pub second_hand: bool,
+    #[serde(rename = "isLocked")]
+    pub is_locked: bool,
+    #[serde(rename = "hasOwner")]
+    pub has_owner: bool,
+    #[serde(rename = "isSaleLocked")]
+    pub is_sale_locked: bool,
+    #[serde(rename = "isAdminLocked")]
+    pub is_admin_locked: bool,
+    #[serde(rename = "instanceId")]
+    pub instance_id: i32,
+    #[serde(rename = "ownerTag")]
+    pub owner_tag: AccountTagInformation,
+    pub price: u64,
+    #[serde(rename = "guildInfo")]
+    pub guild_info: GuildInformations,
+}
+
+/// `HouseInformations`, id 1987
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HouseInformations {
+    #[serde(rename = "houseId")]
+    pub house_id: u32,
+    #[serde(rename = "modelId")]
+    pub model_id: u16,
+}
+
+/// `HouseInformationsForGuild`, id 7095
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HouseInformationsForGuild {
+    #[serde(rename = "houseId")]
+    pub house_id: u32,
+    #[serde(rename = "modelId")]
+    pub model_id: u16,
+    #[serde(rename = "instanceId")]
+    pub instance_id: i32,
+    #[serde(rename = "secondHand")]
+    pub second_hand: bool,
+    #[serde(rename = "ownerTag")]
+    pub owner_tag: AccountTagInformation,
+    #[serde(rename = "worldX")]
+    pub world_x: i16,
+    #[serde(rename = "worldY")]
+    pub world_y: i16,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+    #[serde(rename = "skillListIds")]
+    pub skill_list_ids: Vec<i32>,
+    #[serde(rename = "guildshareParams")]
+    pub guildshare_params: u32,
+}
+
+/// `HouseInformationsForSell`, id 2745
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HouseInformationsForSell {
+    #[serde(rename = "secondHand")]
+    pub second_hand: bool,
+    #[serde(rename = "hasOwner")]
+    pub has_owner: bool,
+    #[serde(rename = "isLocked")]
+    pub is_locked: bool,
+    #[serde(rename = "instanceId")]
+    pub instance_id: i32,
+    #[serde(rename = "modelId")]
+    pub model_id: u32,
+    #[serde(rename = "ownerTag")]
+    pub owner_tag: AccountTagInformation,
+    #[serde(rename = "ownerCharacterName")]
+    pub owner_character_name: String,
+    #[serde(rename = "worldX")]
+    pub world_x: i16,
+    #[serde(rename = "worldY")]
+    pub world_y: i16,
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+    #[serde(rename = "nbRoom")]
+    pub nb_room: i8,
+    #[serde(rename = "nbChest")]
+    pub nb_chest: i8,
+    #[serde(rename = "skillListIds")]
+    pub skill_list_ids: Vec<i32>,
+    pub price: u64,
+}
+
+/// `HouseInformationsInside`, id 4258
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HouseInformationsInside {
+    #[serde(rename = "houseId")]
+    pub house_id: u32,
+    #[serde(rename = "modelId")]
+    pub model_id: u16,
+    #[serde(rename = "houseInfos")]
+    pub house_infos: Box<HouseInstanceInformationsKind>,
+    #[serde(rename = "worldX")]
+    pub world_x: i16,
+    #[serde(rename = "worldY")]
+    pub world_y: i16,
+}
+
+/// `HouseInstanceInformations`, id 3942
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HouseInstanceInformations {
+    #[serde(rename = "secondHand")]
+    pub second_hand: bool,
+    #[serde(rename = "isLocked")]
+    pub is_locked: bool,
+    #[serde(rename = "hasOwner")]
+    pub has_owner: bool,
+    #[serde(rename = "isSaleLocked")]
+    pub is_sale_locked: bool,
+    #[serde(rename = "isAdminLocked")]
+    pub is_admin_locked: bool,
+    #[serde(rename = "instanceId")]
+    pub instance_id: i32,
+    #[serde(rename = "ownerTag")]
+    pub owner_tag: AccountTagInformation,
+    pub price: u64,
+}
+
+/// `HouseKickRequestMessage`, id 6252
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HouseKickRequestMessage {
+    pub id: u64,
+}
+
+/// `HouseLockFromInsideRequestMessage`, id 1838
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HouseLockFromInsideRequestMessage {
+    pub code: String,
+}
+
+/// `HouseOnMapInformations`, id 6443
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HouseOnMapInformations {
+    #[serde(rename = "houseId")]
+    pub house_id: u32,
+    #[serde(rename = "modelId")]
+    pub model_id: u16,
+    #[serde(rename = "doorsOnMap")]
+    pub doors_on_map: Vec<i32>,
+    #[serde(rename = "houseInstances")]
+    pub house_instances: Vec<HouseInstanceInformations>,
+}
+
+/// `HousePropertiesMessage`, id 8534
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HousePropertiesMessage {
+    #[serde(rename = "houseId")]
+    pub house_id: u32,
+    #[serde(rename = "doorsOnMap")]
+    pub doors_on_map: Vec<i32>,
+    pub properties: Box<HouseInstanceInformationsKind>,
+}
+
+/// `HouseSellFromInsideRequestMessage`, id 900
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HouseSellFromInsideRequestMessage {
+    #[serde(rename = "instanceId")]
+    pub instance_id: i32,
+    pub amount: u64,
+    #[serde(rename = "forSale")]
+    pub for_sale: bool,
+}
+
+/// `HouseSellRequestMessage`, id 4411
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HouseSellRequestMessage {
+    #[serde(rename = "instanceId")]
+    pub instance_id: i32,
+    pub amount: u64,
+    #[serde(rename = "forSale")]
+    pub for_sale: bool,
+}
+
+/// `HouseSellingUpdateMessage`, id 5600
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HouseSellingUpdateMessage {
+    #[serde(rename = "houseId")]
+    pub house_id: u32,
+    #[serde(rename = "instanceId")]
+    pub instance_id: i32,
+    #[serde(rename = "secondHand")]
+    pub second_hand: bool,
+    #[serde(rename = "realPrice")]
+    pub real_price: u64,
+    #[serde(rename = "buyerTag")]
+    pub buyer_tag: AccountTagInformation,
+}
+
+/// `HouseTeleportRequestMessage`, id 9533
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HouseTeleportRequestMessage {
+    #[serde(rename = "houseId")]
+    pub house_id: u32,
+    #[serde(rename = "houseInstanceId")]
+    pub house_instance_id: i32,
+}
+
+/// `HouseToSellFilterMessage`, id 7368
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HouseToSellFilterMessage {
+    #[serde(rename = "areaId")]
+    pub area_id: i32,
+    #[serde(rename = "atLeastNbRoom")]
+    pub at_least_nb_room: i8,
+    #[serde(rename = "atLeastNbChest")]
+    pub at_least_nb_chest: i8,
+    #[serde(rename = "skillRequested")]
+    pub skill_requested: u16,
+    #[serde(rename = "maxPrice")]
+    pub max_price: u64,
+    #[serde(rename = "orderBy")]
+    pub order_by: i8,
+}
+
+/// `HouseToSellListMessage`, id 4751
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HouseToSellListMessage {
+    #[serde(rename = "pageIndex")]
+    pub page_index: u16,
+    #[serde(rename = "totalPage")]
+    pub total_page: u16,
+    #[serde(rename = "houseList")]
+    pub house_list: Vec<HouseInformationsForSell>,
+}
+
+/// `HouseToSellListRequestMessage`, id 1421
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HouseToSellListRequestMessage {
+    #[serde(rename = "pageIndex")]
+    pub page_index: u16,
+}
+
+/// `HumanInformations`, id 9794
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HumanInformations {
+    pub restrictions: ActorRestrictionsInformations,
+    pub sex: bool,
+    pub options: Vec<HumanOptionKind>,
+}
+
+/// `HumanOption`, id 7267
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HumanOption {}
+
+/// `HumanOptionAlliance`, id 1832
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HumanOptionAlliance {
+    #[serde(rename = "allianceInformation")]
+    pub alliance_information: AllianceInformation,
+    pub aggressable: i8,
+}
+
+/// `HumanOptionEmote`, id 620
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HumanOptionEmote {
+    #[serde(rename = "emoteId")]
+    pub emote_id: i16,
+    #[serde(rename = "emoteStartTime", deserialize_with = "float")]
+    pub emote_start_time: f64,
+}
+
+/// `HumanOptionFollowers`, id 7033
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HumanOptionFollowers {
+    #[serde(rename = "followingCharactersLook")]
+    pub following_characters_look: Vec<IndexedEntityLook>,
+}
+
+/// `HumanOptionGuild`, id 6319
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HumanOptionGuild {
+    #[serde(rename = "guildInformations")]
+    pub guild_informations: GuildInformations,
+}
+
+/// `HumanOptionObjectUse`, id 6762
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HumanOptionObjectUse {
+    #[serde(rename = "delayTypeId")]
+    pub delay_type_id: i8,
+    #[serde(rename = "delayEndTime", deserialize_with = "float")]
+    pub delay_end_time: f64,
+    #[serde(rename = "objectGID")]
+    pub object_gid: u32,
+}
+
+/// `HumanOptionOrnament`, id 9745
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HumanOptionOrnament {
+    #[serde(rename = "ornamentId")]
+    pub ornament_id: u16,
+    pub level: u16,
+    #[serde(rename = "leagueId")]
+    pub league_id: u16,
+    #[serde(rename = "ladderPosition")]
+    pub ladder_position: i32,
+}
+
+/// `HumanOptionSkillUse`, id 2431
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HumanOptionSkillUse {
+    #[serde(rename = "elementId")]
+    pub element_id: u32,
+    #[serde(rename = "skillId")]
+    pub skill_id: u16,
+    #[serde(rename = "skillEndTime", deserialize_with = "float")]
+    pub skill_end_time: f64,
+}
+
+/// `HumanOptionSpeedMultiplier`, id 3163
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HumanOptionSpeedMultiplier {
+    #[serde(rename = "speedMultiplier")]
+    pub speed_multiplier: u32,
+}
+
+/// `HumanOptionTitle`, id 2153
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HumanOptionTitle {
+    #[serde(rename = "titleId")]
+    pub title_id: u16,
+    #[serde(rename = "titleParam")]
+    pub title_param: String,
+}
+
+/// `IconNamedPreset`, id 3270
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IconNamedPreset {
+    pub id: i16,
+    pub presets: Vec<PresetKind>,
+    #[serde(rename = "iconId")]
+    pub icon_id: i16,
+    pub name: String,
+}
+
+/// `IconNamedPresetSaveRequestMessage`, id 7981
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IconNamedPresetSaveRequestMessage {
+    #[serde(rename = "presetId")]
+    pub preset_id: i16,
+    #[serde(rename = "symbolId")]
+    pub symbol_id: i8,
+    #[serde(rename = "updateData")]
+    pub update_data: bool,
+    pub name: String,
+    pub r#type: i8,
+}
+
+/// `IconPresetSaveRequestMessage`, id 3821
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IconPresetSaveRequestMessage {
+    #[serde(rename = "presetId")]
+    pub preset_id: i16,
+    #[serde(rename = "symbolId")]
+    pub symbol_id: i8,
+    #[serde(rename = "updateData")]
+    pub update_data: bool,
+}
+
+/// `IdentificationFailedBannedMessage`, id 1821
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IdentificationFailedBannedMessage {
+    pub reason: i8,
+    #[serde(rename = "banEndDate", deserialize_with = "float")]
+    pub ban_end_date: f64,
+}
+
+/// `IdentificationFailedForBadVersionMessage`, id 8374
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IdentificationFailedForBadVersionMessage {
+    pub reason: i8,
+    #[serde(rename = "requiredVersion")]
+    pub required_version: Version,
+}
+
+/// `IdentificationFailedMessage`, id 10
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IdentificationFailedMessage {
+    pub reason: i8,
+}
+
+/// `IdentificationMessage`, id 17
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IdentificationMessage {
+    pub autoconnect: bool,
+    #[serde(rename = "useCertificate")]
+    pub use_certificate: bool,
+    #[serde(rename = "useLoginToken")]
+    pub use_login_token: bool,
+    pub version: Version,
+    pub lang: String,
+    pub credentials: Vec<i8>,
+    #[serde(rename = "serverId")]
+    pub server_id: i16,
+    #[serde(rename = "sessionOptionalSalt")]
+    pub session_optional_salt: u64,
+    #[serde(rename = "failedAttempts")]
+    pub failed_attempts: Vec<u16>,
+}
+
+/// `IdentificationSuccessMessage`, id 3227
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IdentificationSuccessMessage {
+    #[serde(rename = "hasRights")]
+    pub has_rights: bool,
+    #[serde(rename = "hasReportRight")]
+    pub has_report_right: bool,
+    #[serde(rename = "hasForceRight")]
+    pub has_force_right: bool,
+    #[serde(rename = "wasAlreadyConnected")]
+    pub was_already_connected: bool,
+    pub login: String,
+    #[serde(rename = "accountTag")]
+    pub account_tag: AccountTagInformation,
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+    #[serde(rename = "communityId")]
+    pub community_id: i8,
+    #[serde(rename = "accountCreation", deserialize_with = "float")]
+    pub account_creation: f64,
+    #[serde(rename = "subscriptionEndDate", deserialize_with = "float")]
+    pub subscription_end_date: f64,
+    #[serde(rename = "havenbagAvailableRoom")]
+    pub havenbag_available_room: i8,
+}
+
+/// `IdentificationSuccessWithLoginTokenMessage`, id 5227
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IdentificationSuccessWithLoginTokenMessage {
+    #[serde(rename = "hasRights")]
+    pub has_rights: bool,
+    #[serde(rename = "hasReportRight")]
+    pub has_report_right: bool,
+    #[serde(rename = "hasForceRight")]
+    pub has_force_right: bool,
+    #[serde(rename = "wasAlreadyConnected")]
+    pub was_already_connected: bool,
+    pub login: String,
+    #[serde(rename = "accountTag")]
+    pub account_tag: AccountTagInformation,
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+    #[serde(rename = "communityId")]
+    pub community_id: i8,
+    #[serde(rename = "accountCreation", deserialize_with = "float")]
+    pub account_creation: f64,
+    #[serde(rename = "subscriptionEndDate", deserialize_with = "float")]
+    pub subscription_end_date: f64,
+    #[serde(rename = "havenbagAvailableRoom")]
+    pub havenbag_available_room: i8,
+    #[serde(rename = "loginToken")]
+    pub login_token: String,
+}
+
+/// `IdentifiedEntityDispositionInformations`, id 6579
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IdentifiedEntityDispositionInformations {
+    #[serde(rename = "cellId")]
+    pub cell_id: i16,
+    pub direction: i8,
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+}
+
+/// `IgnoredAddFailureMessage`, id 2312
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IgnoredAddFailureMessage {
+    pub reason: i8,
+}
+
+/// `IgnoredAddRequestMessage`, id 7235
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IgnoredAddRequestMessage {
+    pub target: Box<AbstractPlayerSearchInformationKind>,
+    pub session: bool,
+}
+
+/// `IgnoredAddedMessage`, id 7216
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IgnoredAddedMessage {
+    #[serde(rename = "ignoreAdded")]
+    pub ignore_added: Box<IgnoredInformationsKind>,
+    pub session: bool,
+}
+
+/// `IgnoredDeleteRequestMessage`, id 5642
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IgnoredDeleteRequestMessage {
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+    pub session: bool,
+}
+
+/// `IgnoredDeleteResultMessage`, id 6419
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IgnoredDeleteResultMessage {
+    pub success: bool,
+    pub session: bool,
+    pub tag: AccountTagInformation,
+}
+
+/// `IgnoredGetListMessage`, id 2677
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IgnoredGetListMessage {}
+
+/// `IgnoredInformations`, id 6649
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IgnoredInformations {
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+    #[serde(rename = "accountTag")]
+    pub account_tag: AccountTagInformation,
+}
+
+/// `IgnoredListMessage`, id 8505
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IgnoredListMessage {
+    #[serde(rename = "ignoredList")]
+    pub ignored_list: Vec<IgnoredInformationsKind>,
+}
+
+/// `IgnoredOnlineInformations`, id 3193
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IgnoredOnlineInformations {
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+    #[serde(rename = "accountTag")]
+    pub account_tag: AccountTagInformation,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+    #[serde(rename = "playerName")]
+    pub player_name: String,
+    pub breed: i8,
+    pub sex: bool,
+}
+
+/// `IndexedEntityLook`, id 9998
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IndexedEntityLook {
+    pub look: EntityLook,
+    pub index: i8,
+}
+
+/// `InteractiveElement`, id 6006
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InteractiveElement {
+    #[serde(rename = "elementId")]
+    pub element_id: i32,
+    #[serde(rename = "elementTypeId")]
+    pub element_type_id: i32,
+    #[serde(rename = "enabledSkills")]
+    pub enabled_skills: Vec<InteractiveElementSkillKind>,
+    #[serde(rename = "disabledSkills")]
+    pub disabled_skills: Vec<InteractiveElementSkillKind>,
+    #[serde(rename = "onCurrentMap")]
+    pub on_current_map: bool,
+}
+
+/// `InteractiveElementNamedSkill`, id 345
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InteractiveElementNamedSkill {
+    #[serde(rename = "skillId")]
+    pub skill_id: u32,
+    #[serde(rename = "skillInstanceUid")]
+    pub skill_instance_uid: i32,
+    #[serde(rename = "nameId")]
+    pub name_id: u32,
+}
+
+/// `InteractiveElementSkill`, id 9415
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InteractiveElementSkill {
+    #[serde(rename = "skillId")]
+    pub skill_id: u32,
+    #[serde(rename = "skillInstanceUid")]
+    pub skill_instance_uid: i32,
+}
+
+/// `InteractiveElementUpdatedMessage`, id 6391
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InteractiveElementUpdatedMessage {
+    #[serde(rename = "interactiveElement")]
+    pub interactive_element: InteractiveElement,
+}
+
+/// `InteractiveElementWithAgeBonus`, id 2923
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InteractiveElementWithAgeBonus {
+    #[serde(rename = "elementId")]
+    pub element_id: i32,
+    #[serde(rename = "elementTypeId")]
+    pub element_type_id: i32,
+    #[serde(rename = "enabledSkills")]
+    pub enabled_skills: Vec<InteractiveElementSkillKind>,
+    #[serde(rename = "disabledSkills")]
+    pub disabled_skills: Vec<InteractiveElementSkillKind>,
+    #[serde(rename = "onCurrentMap")]
+    pub on_current_map: bool,
+    #[serde(rename = "ageBonus")]
+    pub age_bonus: i16,
+}
+
+/// `InteractiveMapUpdateMessage`, id 3960
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InteractiveMapUpdateMessage {
+    #[serde(rename = "interactiveElements")]
+    pub interactive_elements: Vec<InteractiveElementKind>,
+}
+
+/// `InteractiveUseEndedMessage`, id 467
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InteractiveUseEndedMessage {
+    #[serde(rename = "elemId")]
+    pub elem_id: u32,
+    #[serde(rename = "skillId")]
+    pub skill_id: u16,
+}
+
+/// `InteractiveUseErrorMessage`, id 7919
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InteractiveUseErrorMessage {
+    #[serde(rename = "elemId")]
+    pub elem_id: u32,
+    #[serde(rename = "skillInstanceUid")]
+    pub skill_instance_uid: u32,
+}
+
+/// `InteractiveUseRequestMessage`, id 7290
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InteractiveUseRequestMessage {
+    #[serde(rename = "elemId")]
+    pub elem_id: u32,
+    #[serde(rename = "skillInstanceUid")]
+    pub skill_instance_uid: u32,
+}
+
+/// `InteractiveUseWithParamRequestMessage`, id 8437
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InteractiveUseWithParamRequestMessage {
+    #[serde(rename = "elemId")]
+    pub elem_id: u32,
+    #[serde(rename = "skillInstanceUid")]
+    pub skill_instance_uid: u32,
+    pub id: i32,
+}
+
+/// `InteractiveUsedMessage`, id 5535
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InteractiveUsedMessage {
+    #[serde(rename = "entityId")]
+    pub entity_id: u64,
+    #[serde(rename = "elemId")]
+    pub elem_id: u32,
+    #[serde(rename = "skillId")]
+    pub skill_id: u16,
+    pub duration: u16,
+    #[serde(rename = "canMove")]
+    pub can_move: bool,
+}
+
+/// `InvalidPresetsMessage`, id 8794
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InvalidPresetsMessage {
+    #[serde(rename = "presetIds")]
+    pub preset_ids: Vec<i16>,
+}
+
+/// `InventoryContentMessage`, id 8307
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InventoryContentMessage {
+    pub objects: Vec<ObjectItem>,
+    pub kamas: u64,
+}
+
+/// `InventoryWeightMessage`, id 9175
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InventoryWeightMessage {
+    #[serde(rename = "inventoryWeight")]
+    pub inventory_weight: u32,
+    #[serde(rename = "weightMax")]
+    pub weight_max: u32,
+}
+
+/// `InviteInHavenBagClosedMessage`, id 8453
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InviteInHavenBagClosedMessage {
+    #[serde(rename = "hostInformations")]
+    pub host_informations: CharacterMinimalInformations,
+}
+
+/// `InviteInHavenBagMessage`, id 3956
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InviteInHavenBagMessage {
+    #[serde(rename = "guestInformations")]
+    pub guest_informations: CharacterMinimalInformations,
+    pub accept: bool,
+}
+
+/// `InviteInHavenBagOfferMessage`, id 4318
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InviteInHavenBagOfferMessage {
+    #[serde(rename = "hostInformations")]
+    pub host_informations: CharacterMinimalInformations,
+    #[serde(rename = "timeLeftBeforeCancel")]
+    pub time_left_before_cancel: u32,
+}
+
+/// `Item`, id 4569
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Item {}
+
+/// `ItemDurability`, id 1278
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ItemDurability {
+    pub durability: i16,
+    #[serde(rename = "durabilityMax")]
+    pub durability_max: i16,
+}
+
+/// `ItemForPreset`, id 7762
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ItemForPreset {
+    pub position: i16,
+    #[serde(rename = "objGid")]
+    pub obj_gid: u32,
+    #[serde(rename = "objUid")]
+    pub obj_uid: u32,
+}
+
+/// `ItemForPresetUpdateMessage`, id 1555
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ItemForPresetUpdateMessage {
+    #[serde(rename = "presetId")]
+    pub preset_id: i16,
+    #[serde(rename = "presetItem")]
+    pub preset_item: ItemForPreset,
+}
+
+/// `ItemNoMoreAvailableMessage`, id 6607
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ItemNoMoreAvailableMessage {}
+
+/// `ItemsPreset`, id 3328
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ItemsPreset {
+    pub id: i16,
+    pub items: Vec<ItemForPreset>,
+    #[serde(rename = "mountEquipped")]
+    pub mount_equipped: bool,
+    pub look: EntityLook,
+}
+
+/// `JobAllowMultiCraftRequestMessage`, id 1645
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobAllowMultiCraftRequestMessage {
+    pub enabled: bool,
+}
+
+/// `JobBookSubscribeRequestMessage`, id 7020
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobBookSubscribeRequestMessage {
+    #[serde(rename = "jobIds")]
+    pub job_ids: Vec<i8>,
+}
+
+/// `JobBookSubscription`, id 5877
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobBookSubscription {
+    #[serde(rename = "jobId")]
+    pub job_id: i8,
+    pub subscribed: bool,
+}
+
+/// `JobBookSubscriptionMessage`, id 9108
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobBookSubscriptionMessage {
+    pub subscriptions: Vec<JobBookSubscription>,
+}
+
+/// `JobCrafterDirectoryAddMessage`, id 6969
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobCrafterDirectoryAddMessage {
+    #[serde(rename = "listEntry")]
+    pub list_entry: JobCrafterDirectoryListEntry,
+}
+
+/// `JobCrafterDirectoryDefineSettingsMessage`, id 7558
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobCrafterDirectoryDefineSettingsMessage {
+    pub settings: JobCrafterDirectorySettings,
+}
+
+/// `JobCrafterDirectoryEntryJobInfo`, id 1676
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobCrafterDirectoryEntryJobInfo {
+    #[serde(rename = "jobId")]
+    pub job_id: i8,
+    #[serde(rename = "jobLevel")]
+    pub job_level: i8,
+    pub free: bool,
+    #[serde(rename = "minLevel")]
+    pub min_level: i8,
+}
+
+/// `JobCrafterDirectoryEntryMessage`, id 636
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobCrafterDirectoryEntryMessage {
+    #[serde(rename = "playerInfo")]
+    pub player_info: JobCrafterDirectoryEntryPlayerInfo,
+    #[serde(rename = "jobInfoList")]
+    pub job_info_list: Vec<JobCrafterDirectoryEntryJobInfo>,
+    #[serde(rename = "playerLook")]
+    pub player_look: EntityLook,
+}
+
+/// `JobCrafterDirectoryEntryPlayerInfo`, id 8355
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobCrafterDirectoryEntryPlayerInfo {
+    pub sex: bool,
+    #[serde(rename = "isInWorkshop")]
+    pub is_in_workshop: bool,
+    #[serde(rename = "canCraftLegendary")]
+    pub can_craft_legendary: bool,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+    #[serde(rename = "playerName")]
+    pub player_name: String,
+    #[serde(rename = "alignmentSide")]
+    pub alignment_side: i8,
+    pub breed: i8,
+    #[serde(rename = "worldX")]
+    pub world_x: i16,
+    #[serde(rename = "worldY")]
+    pub world_y: i16,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+    pub status: Box<PlayerStatusKind>,
+}
+
+/// `JobCrafterDirectoryEntryRequestMessage`, id 5343
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobCrafterDirectoryEntryRequestMessage {
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+}
+
+/// `JobCrafterDirectoryListEntry`, id 1115
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobCrafterDirectoryListEntry {
+    #[serde(rename = "playerInfo")]
+    pub player_info: JobCrafterDirectoryEntryPlayerInfo,
+    #[serde(rename = "jobInfo")]
+    pub job_info: JobCrafterDirectoryEntryJobInfo,
+}
+
+/// `JobCrafterDirectoryListMessage`, id 7103
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobCrafterDirectoryListMessage {
+    #[serde(rename = "listEntries")]
+    pub list_entries: Vec<JobCrafterDirectoryListEntry>,
+}
+
+/// `JobCrafterDirectoryListRequestMessage`, id 2511
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobCrafterDirectoryListRequestMessage {
+    #[serde(rename = "jobId")]
+    pub job_id: i8,
+}
+
+/// `JobCrafterDirectoryRemoveMessage`, id 8864
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobCrafterDirectoryRemoveMessage {
+    #[serde(rename = "jobId")]
+    pub job_id: i8,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+}
+
+/// `JobCrafterDirectorySettings`, id 7765
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobCrafterDirectorySettings {
+    #[serde(rename = "jobId")]
+    pub job_id: i8,
+    #[serde(rename = "minLevel")]
+    pub min_level: i8,
+    pub free: bool,
+}
+
+/// `JobCrafterDirectorySettingsMessage`, id 5924
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobCrafterDirectorySettingsMessage {
+    #[serde(rename = "craftersSettings")]
+    pub crafters_settings: Vec<JobCrafterDirectorySettings>,
+}
+
+/// `JobDescription`, id 9860
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobDescription {
+    #[serde(rename = "jobId")]
+    pub job_id: i8,
+    pub skills: Vec<SkillActionDescriptionKind>,
+}
+
+/// `JobDescriptionMessage`, id 430
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobDescriptionMessage {
+    #[serde(rename = "jobsDescription")]
+    pub jobs_description: Vec<JobDescription>,
+}
+
+/// `JobExperience`, id 3823
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobExperience {
+    #[serde(rename = "jobId")]
+    pub job_id: i8,
+    #[serde(rename = "jobLevel")]
+    pub job_level: i8,
+    #[serde(rename = "jobXP")]
+    pub job_xp: u64,
+    #[serde(rename = "jobXpLevelFloor")]
+    pub job_xp_level_floor: u64,
+    #[serde(rename = "jobXpNextLevelFloor")]
+    pub job_xp_next_level_floor: u64,
+}
+
+/// `JobExperienceMultiUpdateMessage`, id 1954
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobExperienceMultiUpdateMessage {
+    #[serde(rename = "experiencesUpdate")]
+    pub experiences_update: Vec<JobExperience>,
+}
+
+/// `JobExperienceOtherPlayerUpdateMessage`, id 4525
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobExperienceOtherPlayerUpdateMessage {
+    #[serde(rename = "experiencesUpdate")]
+    pub experiences_update: JobExperience,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+}
+
+/// `JobExperienceUpdateMessage`, id 9580
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobExperienceUpdateMessage {
+    #[serde(rename = "experiencesUpdate")]
+    pub experiences_update: JobExperience,
+}
+
+/// `JobLevelUpMessage`, id 8322
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobLevelUpMessage {
+    #[serde(rename = "newLevel")]
+    pub new_level: i8,
+    #[serde(rename = "jobsDescription")]
+    pub jobs_description: JobDescription,
+}
+
+/// `JobMultiCraftAvailableSkillsMessage`, id 6031
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobMultiCraftAvailableSkillsMessage {
+    pub enabled: bool,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+    pub skills: Vec<u16>,
+}
+
+/// `KamaDebtInformation`, id 3023
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KamaDebtInformation {
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+    #[serde(deserialize_with = "float")]
+    pub timestamp: f64,
+    pub kamas: u64,
+}
+
+/// `KamasUpdateMessage`, id 6059
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KamasUpdateMessage {
+    #[serde(rename = "kamasTotal")]
+    pub kamas_total: u64,
+}
+
+/// `KickHavenBagRequestMessage`, id 3366
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KickHavenBagRequestMessage {
+    #[serde(rename = "guestId")]
+    pub guest_id: u64,
+}
+
+/// `KnownZaapListMessage`, id 3175
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KnownZaapListMessage {
+    #[serde(deserialize_with = "floats")]
+    pub destinations: Vec<f64>,
+}
+
+/// `KohAllianceInfo`, id 5284
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KohAllianceInfo {
+    pub alliance: AllianceInformation,
+    #[serde(rename = "memberCount")]
+    pub member_count: u64,
+    #[serde(rename = "kohAllianceRoleMembers")]
+    pub koh_alliance_role_members: Vec<KohAllianceRoleMembers>,
+    pub scores: Vec<KohScore>,
+    #[serde(rename = "matchDominationScores")]
+    pub match_domination_scores: u32,
+}
+
+/// `KohAllianceRoleMembers`, id 4219
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KohAllianceRoleMembers {
+    #[serde(rename = "memberCount")]
+    pub member_count: u64,
+    #[serde(rename = "roleAvAId")]
+    pub role_av_a_id: i32,
+}
+
+/// `KohScore`, id 5062
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KohScore {
+    #[serde(rename = "avaScoreTypeEnum")]
+    pub ava_score_type_enum: i8,
+    #[serde(rename = "roundScores")]
+    pub round_scores: i32,
+    #[serde(rename = "cumulScores")]
+    pub cumul_scores: i32,
+}
+
+/// `KohUpdateMessage`, id 584
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KohUpdateMessage {
+    #[serde(rename = "kohAllianceInfo")]
+    pub koh_alliance_info: Vec<KohAllianceInfo>,
+    #[serde(rename = "startingAvaTimestamp")]
+    pub starting_ava_timestamp: i32,
+    #[serde(rename = "nextTickTime", deserialize_with = "float")]
+    pub next_tick_time: f64,
+}
+
+/// `KothEndMessage`, id 6893
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KothEndMessage {
+    pub winner: KothWinner,
+}
+
+/// `KothWinner`, id 7849
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct KothWinner {}
+
+/// `LeagueFriendInformations`, id 6198
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LeagueFriendInformations {
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+    #[serde(rename = "accountTag")]
+    pub account_tag: AccountTagInformation,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+    #[serde(rename = "playerName")]
+    pub player_name: String,
+    pub breed: i8,
+    pub sex: bool,
+    pub level: u16,
+    #[serde(rename = "leagueId")]
+    pub league_id: u16,
+    #[serde(rename = "totalLeaguePoints")]
+    pub total_league_points: u16,
+    #[serde(rename = "ladderPosition")]
+    pub ladder_position: i32,
+}
+
+/// `LeaveDialogMessage`, id 8958
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LeaveDialogMessage {
+    #[serde(rename = "dialogType")]
+    pub dialog_type: i8,
+}
+
+/// `LeaveDialogRequestMessage`, id 8122
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LeaveDialogRequestMessage {}
+
+/// `LifePointsRegenBeginMessage`, id 66
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LifePointsRegenBeginMessage {
+    #[serde(rename = "regenRate")]
+    pub regen_rate: i8,
+}
+
+/// `LifePointsRegenEndMessage`, id 2274
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LifePointsRegenEndMessage {
+    #[serde(rename = "lifePoints")]
+    pub life_points: u32,
+    #[serde(rename = "maxLifePoints")]
+    pub max_life_points: u32,
+    #[serde(rename = "lifePointsGained")]
+    pub life_points_gained: u32,
+}
+
+/// `ListMapNpcsQuestStatusUpdateMessage`, id 233
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ListMapNpcsQuestStatusUpdateMessage {
+    #[serde(rename = "mapInfo")]
+    pub map_info: Vec<MapNpcQuestInfo>,
+}
+
+/// `ListenersOfSynchronizedStorageMessage`, id 2330
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ListenersOfSynchronizedStorageMessage {
+    pub players: Vec<String>,
+}
+
+/// `LivingObjectChangeSkinRequestMessage`, id 9449
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LivingObjectChangeSkinRequestMessage {
+    #[serde(rename = "livingUID")]
+    pub living_uid: u32,
+    #[serde(rename = "livingPosition")]
+    pub living_position: i8,
+    #[serde(rename = "skinId")]
+    pub skin_id: u32,
+}
+
+/// `LivingObjectDissociateMessage`, id 6070
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LivingObjectDissociateMessage {
+    #[serde(rename = "livingUID")]
+    pub living_uid: u32,
+    #[serde(rename = "livingPosition")]
+    pub living_position: i8,
+}
+
+/// `LivingObjectMessageMessage`, id 1299
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LivingObjectMessageMessage {
+    #[serde(rename = "msgId")]
+    pub msg_id: u16,
+    #[serde(rename = "timeStamp")]
+    pub time_stamp: i32,
+    pub owner: String,
+    #[serde(rename = "objectGenericId")]
+    pub object_generic_id: u32,
+}
+
+/// `LivingObjectMessageRequestMessage`, id 8894
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LivingObjectMessageRequestMessage {
+    #[serde(rename = "msgId")]
+    pub msg_id: u16,
+    pub parameters: Vec<String>,
+    #[serde(rename = "livingObject")]
+    pub living_object: u32,
+}
+
+/// `LocalizedChatSmileyMessage`, id 9190
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LocalizedChatSmileyMessage {
+    #[serde(rename = "entityId", deserialize_with = "float")]
+    pub entity_id: f64,
+    #[serde(rename = "smileyId")]
+    pub smiley_id: u16,
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+    #[serde(rename = "cellId")]
+    pub cell_id: u16,
+}
+
+/// `LockableChangeCodeMessage`, id 420
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LockableChangeCodeMessage {
+    pub code: String,
+}
+
+/// `LockableCodeResultMessage`, id 9911
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LockableCodeResultMessage {
+    pub result: i8,
+}
+
+/// `LockableShowCodeDialogMessage`, id 8393
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LockableShowCodeDialogMessage {
+    #[serde(rename = "changeOrUse")]
+    pub change_or_use: bool,
+    #[serde(rename = "codeSize")]
+    pub code_size: i8,
+}
+
+/// `LockableStateUpdateAbstractMessage`, id 4059
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LockableStateUpdateAbstractMessage {
+    pub locked: bool,
+}
+
+/// `LockableStateUpdateHouseDoorMessage`, id 5814
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LockableStateUpdateHouseDoorMessage {
+    pub locked: bool,
+    #[serde(rename = "houseId")]
+    pub house_id: u32,
+    #[serde(rename = "instanceId")]
+    pub instance_id: i32,
+    #[serde(rename = "secondHand")]
+    pub second_hand: bool,
+}
+
+/// `LockableStateUpdateStorageMessage`, id 3377
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LockableStateUpdateStorageMessage {
+    pub locked: bool,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    #[serde(rename = "elementId")]
+    pub element_id: u32,
+}
+
+/// `LockableUseCodeMessage`, id 6910
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LockableUseCodeMessage {
+    pub code: String,
+}
+
+/// `LoginQueueStatusMessage`, id 3680
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LoginQueueStatusMessage {
+    pub position: i16,
+    pub total: i16,
+}
+
+/// `MapComplementaryInformationsAnomalyMessage`, id 527
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapComplementaryInformationsAnomalyMessage {
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    pub houses: Vec<HouseInformationsKind>,
+    pub actors: Vec<GameRolePlayActorInformationsKind>,
+    #[serde(rename = "interactiveElements")]
+    pub interactive_elements: Vec<InteractiveElementKind>,
+    #[serde(rename = "statedElements")]
+    pub stated_elements: Vec<StatedElement>,
+    pub obstacles: Vec<MapObstacle>,
+    pub fights: Vec<FightCommonInformations>,
+    #[serde(rename = "hasAggressiveMonsters")]
+    pub has_aggressive_monsters: bool,
+    #[serde(rename = "fightStartPositions")]
+    pub fight_start_positions: FightStartingPositions,
+    pub level: u16,
+    #[serde(rename = "closingTime")]
+    pub closing_time: u64,
+}
+
+/// `MapComplementaryInformationsBreachMessage`, id 748
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapComplementaryInformationsBreachMessage {
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    pub houses: Vec<HouseInformationsKind>,
+    pub actors: Vec<GameRolePlayActorInformationsKind>,
+    #[serde(rename = "interactiveElements")]
+    pub interactive_elements: Vec<InteractiveElementKind>,
+    #[serde(rename = "statedElements")]
+    pub stated_elements: Vec<StatedElement>,
+    pub obstacles: Vec<MapObstacle>,
+    pub fights: Vec<FightCommonInformations>,
+    #[serde(rename = "hasAggressiveMonsters")]
+    pub has_aggressive_monsters: bool,
+    #[serde(rename = "fightStartPositions")]
+    pub fight_start_positions: FightStartingPositions,
+    pub floor: u32,
+    pub room: i8,
+    #[serde(rename = "infinityMode")]
+    pub infinity_mode: i16,
+    pub branches: Vec<BreachBranchKind>,
+}
+
+/// `MapComplementaryInformationsDataInHavenBagMessage`, id 88
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapComplementaryInformationsDataInHavenBagMessage {
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    pub houses: Vec<HouseInformationsKind>,
+    pub actors: Vec<GameRolePlayActorInformationsKind>,
+    #[serde(rename = "interactiveElements")]
+    pub interactive_elements: Vec<InteractiveElementKind>,
+    #[serde(rename = "statedElements")]
+    pub stated_elements: Vec<StatedElement>,
+    pub obstacles: Vec<MapObstacle>,
+    pub fights: Vec<FightCommonInformations>,
+    #[serde(rename = "hasAggressiveMonsters")]
+    pub has_aggressive_monsters: bool,
+    #[serde(rename = "fightStartPositions")]
+    pub fight_start_positions: FightStartingPositions,
+    #[serde(rename = "ownerInformations")]
+    pub owner_informations: CharacterMinimalInformations,
+    pub theme: i8,
+    #[serde(rename = "roomId")]
+    pub room_id: i8,
+    #[serde(rename = "maxRoomId")]
+    pub max_room_id: i8,
+}
+
+/// `MapComplementaryInformationsDataInHouseMessage`, id 5057
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapComplementaryInformationsDataInHouseMessage {
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    pub houses: Vec<HouseInformationsKind>,
+    pub actors: Vec<GameRolePlayActorInformationsKind>,
+    #[serde(rename = "interactiveElements")]
+    pub interactive_elements: Vec<InteractiveElementKind>,
+    #[serde(rename = "statedElements")]
+    pub stated_elements: Vec<StatedElement>,
+    pub obstacles: Vec<MapObstacle>,
+    pub fights: Vec<FightCommonInformations>,
+    #[serde(rename = "hasAggressiveMonsters")]
+    pub has_aggressive_monsters: bool,
+    #[serde(rename = "fightStartPositions")]
+    pub fight_start_positions: FightStartingPositions,
+    #[serde(rename = "currentHouse")]
+    pub current_house: HouseInformationsInside,
+}
+
+/// `MapComplementaryInformationsDataMessage`, id 6795
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapComplementaryInformationsDataMessage {
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    pub houses: Vec<HouseInformationsKind>,
+    pub actors: Vec<GameRolePlayActorInformationsKind>,
+    #[serde(rename = "interactiveElements")]
+    pub interactive_elements: Vec<InteractiveElementKind>,
+    #[serde(rename = "statedElements")]
+    pub stated_elements: Vec<StatedElement>,
+    pub obstacles: Vec<MapObstacle>,
+    pub fights: Vec<FightCommonInformations>,
+    #[serde(rename = "hasAggressiveMonsters")]
+    pub has_aggressive_monsters: bool,
+    #[serde(rename = "fightStartPositions")]
+    pub fight_start_positions: FightStartingPositions,
+}
+
+/// `MapComplementaryInformationsWithCoordsMessage`, id 7785
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapComplementaryInformationsWithCoordsMessage {
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    pub houses: Vec<HouseInformationsKind>,
+    pub actors: Vec<GameRolePlayActorInformationsKind>,
+    #[serde(rename = "interactiveElements")]
+    pub interactive_elements: Vec<InteractiveElementKind>,
+    #[serde(rename = "statedElements")]
+    pub stated_elements: Vec<StatedElement>,
+    pub obstacles: Vec<MapObstacle>,
+    pub fights: Vec<FightCommonInformations>,
+    #[serde(rename = "hasAggressiveMonsters")]
+    pub has_aggressive_monsters: bool,
+    #[serde(rename = "fightStartPositions")]
+    pub fight_start_positions: FightStartingPositions,
+    #[serde(rename = "worldX")]
+    pub world_x: i16,
+    #[serde(rename = "worldY")]
+    pub world_y: i16,
+}
+
+/// `MapCoordinates`, id 1446
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapCoordinates {
+    #[serde(rename = "worldX")]
+    pub world_x: i16,
+    #[serde(rename = "worldY")]
+    pub world_y: i16,
+}
+
+/// `MapCoordinatesAndId`, id 9666
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapCoordinatesAndId {
+    #[serde(rename = "worldX")]
+    pub world_x: i16,
+    #[serde(rename = "worldY")]
+    pub world_y: i16,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+}
+
+/// `MapCoordinatesExtended`, id 9677
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapCoordinatesExtended {
+    #[serde(rename = "worldX")]
+    pub world_x: i16,
+    #[serde(rename = "worldY")]
+    pub world_y: i16,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+}
+
+/// `MapFightCountMessage`, id 213
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapFightCountMessage {
+    #[serde(rename = "fightCount")]
+    pub fight_count: u16,
+}
+
+/// `MapFightStartPositionsUpdateMessage`, id 7578
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapFightStartPositionsUpdateMessage {
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    #[serde(rename = "fightStartPositions")]
+    pub fight_start_positions: FightStartingPositions,
+}
+
+/// `MapInformationsRequestMessage`, id 6756
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapInformationsRequestMessage {
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+}
+
+/// `MapNpcQuestInfo`, id 7110
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapNpcQuestInfo {
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    #[serde(rename = "npcsIdsWithQuest")]
+    pub npcs_ids_with_quest: Vec<i32>,
+    #[serde(rename = "questFlags")]
+    pub quest_flags: Vec<GameRolePlayNpcQuestFlag>,
+}
+
+/// `MapObstacle`, id 3091
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapObstacle {
+    #[serde(rename = "obstacleCellId")]
+    pub obstacle_cell_id: u16,
+    pub state: i8,
+}
+
+/// `MapObstacleUpdateMessage`, id 978
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapObstacleUpdateMessage {
+    pub obstacles: Vec<MapObstacle>,
+}
+
+/// `MapRunningFightDetailsExtendedMessage`, id 1491
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapRunningFightDetailsExtendedMessage {
+    #[serde(rename = "fightId")]
+    pub fight_id: u16,
+    pub attackers: Vec<GameFightFighterLightInformationsKind>,
+    pub defenders: Vec<GameFightFighterLightInformationsKind>,
+    #[serde(rename = "namedPartyTeams")]
+    pub named_party_teams: Vec<NamedPartyTeam>,
+}
+
+/// `MapRunningFightDetailsMessage`, id 8907
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapRunningFightDetailsMessage {
+    #[serde(rename = "fightId")]
+    pub fight_id: u16,
+    pub attackers: Vec<GameFightFighterLightInformationsKind>,
+    pub defenders: Vec<GameFightFighterLightInformationsKind>,
+}
+
+/// `MapRunningFightDetailsRequestMessage`, id 5060
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapRunningFightDetailsRequestMessage {
+    #[serde(rename = "fightId")]
+    pub fight_id: u16,
+}
+
+/// `MapRunningFightListMessage`, id 8995
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapRunningFightListMessage {
+    pub fights: Vec<FightExternalInformations>,
+}
+
+/// `MapRunningFightListRequestMessage`, id 1702
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MapRunningFightListRequestMessage {}
+
+/// `MigratedServerListMessage`, id 1200
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MigratedServerListMessage {
+    #[serde(rename = "migratedServerIds")]
+    pub migrated_server_ids: Vec<u16>,
+}
+
+/// `MimicryObjectAssociatedMessage`, id 8767
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MimicryObjectAssociatedMessage {
+    #[serde(rename = "hostUID")]
+    pub host_uid: u32,
+}
+
+/// `MimicryObjectEraseRequestMessage`, id 4153
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MimicryObjectEraseRequestMessage {
+    #[serde(rename = "hostUID")]
+    pub host_uid: u32,
+    #[serde(rename = "hostPos")]
+    pub host_pos: i8,
+}
+
+/// `MimicryObjectErrorMessage`, id 3063
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MimicryObjectErrorMessage {
+    pub reason: i8,
+    #[serde(rename = "errorCode")]
+    pub error_code: i8,
+    pub preview: bool,
+}
+
+/// `MimicryObjectFeedAndAssociateRequestMessage`, id 8822
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MimicryObjectFeedAndAssociateRequestMessage {
+    #[serde(rename = "symbioteUID")]
+    pub symbiote_uid: u32,
+    #[serde(rename = "symbiotePos")]
+    pub symbiote_pos: i8,
+    #[serde(rename = "hostUID")]
+    pub host_uid: u32,
+    #[serde(rename = "hostPos")]
+    pub host_pos: i8,
+    #[serde(rename = "foodUID")]
+    pub food_uid: u32,
+    #[serde(rename = "foodPos")]
+    pub food_pos: i8,
+    pub preview: bool,
+}
+
+/// `MimicryObjectPreviewMessage`, id 2196
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MimicryObjectPreviewMessage {
+    pub result: ObjectItem,
+}
+
+/// `MonsterBoosts`, id 84
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MonsterBoosts {
+    pub id: u32,
+    #[serde(rename = "xpBoost")]
+    pub xp_boost: u16,
+    #[serde(rename = "dropBoost")]
+    pub drop_boost: u16,
+}
+
+/// `MonsterInGroupInformations`, id 5481
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MonsterInGroupInformations {
+    #[serde(rename = "genericId")]
+    pub generic_id: i32,
+    pub grade: i8,
+    pub level: i16,
+    pub look: EntityLook,
+}
+
+/// `MonsterInGroupLightInformations`, id 1902
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MonsterInGroupLightInformations {
+    #[serde(rename = "genericId")]
+    pub generic_id: i32,
+    pub grade: i8,
+    pub level: i16,
+}
+
+/// `MoodSmileyRequestMessage`, id 5278
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MoodSmileyRequestMessage {
+    #[serde(rename = "smileyId")]
+    pub smiley_id: u16,
+}
+
+/// `MoodSmileyResultMessage`, id 4081
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MoodSmileyResultMessage {
+    #[serde(rename = "resultCode")]
+    pub result_code: i8,
+    #[serde(rename = "smileyId")]
+    pub smiley_id: u16,
+}
+
+/// `MoodSmileyUpdateMessage`, id 2387
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MoodSmileyUpdateMessage {
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+    #[serde(rename = "smileyId")]
+    pub smiley_id: u16,
+}
+
+/// `MountClientData`, id 4752
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MountClientData {
+    pub sex: bool,
+    #[serde(rename = "isRideable")]
+    pub is_rideable: bool,
+    #[serde(rename = "isWild")]
+    pub is_wild: bool,
+    #[serde(rename = "isFecondationReady")]
+    pub is_fecondation_ready: bool,
+    #[serde(rename = "useHarnessColors")]
+    pub use_harness_colors: bool,
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+    pub model: u32,
+    pub ancestor: Vec<i32>,
+    pub behaviors: Vec<i32>,
+    pub name: String,
+    #[serde(rename = "ownerId")]
+    pub owner_id: i32,
+    pub experience: u64,
+    #[serde(rename = "experienceForLevel")]
+    pub experience_for_level: u64,
+    #[serde(rename = "experienceForNextLevel", deserialize_with = "float")]
+    pub experience_for_next_level: f64,
+    pub level: i8,
+    #[serde(rename = "maxPods")]
+    pub max_pods: u32,
+    pub stamina: u32,
+    #[serde(rename = "staminaMax")]
+    pub stamina_max: u32,
+    pub maturity: u32,
+    #[serde(rename = "maturityForAdult")]
+    pub maturity_for_adult: u32,
+    pub energy: u32,
+    #[serde(rename = "energyMax")]
+    pub energy_max: u32,
+    pub serenity: i32,
+    #[serde(rename = "aggressivityMax")]
+    pub aggressivity_max: i32,
+    #[serde(rename = "serenityMax")]
+    pub serenity_max: u32,
+    pub love: u32,
+    #[serde(rename = "loveMax")]
+    pub love_max: u32,
+    #[serde(rename = "fecondationTime")]
+    pub fecondation_time: i32,
+    #[serde(rename = "boostLimiter")]
+    pub boost_limiter: i32,
+    #[serde(rename = "boostMax", deserialize_with = "float")]
+    pub boost_max: f64,
+    #[serde(rename = "reproductionCount")]
+    pub reproduction_count: i32,
+    #[serde(rename = "reproductionCountMax")]
+    pub reproduction_count_max: u32,
+    #[serde(rename = "harnessGID")]
+    pub harness_gid: u32,
+    #[serde(rename = "effectList")]
+    pub effect_list: Vec<ObjectEffectInteger>,
+}
+
+/// `MountDataErrorMessage`, id 2178
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MountDataErrorMessage {
+    pub reason: i8,
+}
+
+/// `MountDataMessage`, id 1275
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MountDataMessage {
+    #[serde(rename = "mountData")]
+    pub mount_data: MountClientData,
+}
+
+/// `MountEmoteIconUsedOkMessage`, id 4913
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MountEmoteIconUsedOkMessage {
+    #[serde(rename = "mountId")]
+    pub mount_id: u32,
+    #[serde(rename = "reactionType")]
+    pub reaction_type: i8,
+}
+
+/// `MountEquipedErrorMessage`, id 2813
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MountEquipedErrorMessage {
+    #[serde(rename = "errorType")]
+    pub error_type: i8,
+}
+
+/// `MountFeedRequestMessage`, id 5031
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MountFeedRequestMessage {
+    #[serde(rename = "mountUid")]
+    pub mount_uid: u32,
+    #[serde(rename = "mountLocation")]
+    pub mount_location: i8,
+    #[serde(rename = "mountFoodUid")]
+    pub mount_food_uid: u32,
+    pub quantity: u32,
+}
+
+/// `MountHarnessColorsUpdateRequestMessage`, id 5226
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MountHarnessColorsUpdateRequestMessage {
+    #[serde(rename = "useHarnessColors")]
+    pub use_harness_colors: bool,
+}
+
+/// `MountHarnessDissociateRequestMessage`, id 8309
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MountHarnessDissociateRequestMessage {}
+
+/// `MountInformationInPaddockRequestMessage`, id 709
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MountInformationInPaddockRequestMessage {
+    #[serde(rename = "mapRideId")]
+    pub map_ride_id: u32,
+}
+
+/// `MountInformationRequestMessage`, id 1247
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MountInformationRequestMessage {
+    #[serde(deserialize_with = "float")]
+    pub id: f64,
+    #[serde(deserialize_with = "float")]
+    pub time: f64,
+}
+
+/// `MountInformationsForPaddock`, id 1475
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MountInformationsForPaddock {
+    #[serde(rename = "modelId")]
+    pub model_id: u16,
+    pub name: String,
+    #[serde(rename = "ownerName")]
+    pub owner_name: String,
+}
+
+/// `MountReleaseRequestMessage`, id 2466
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MountReleaseRequestMessage {}
+
+/// `MountReleasedMessage`, id 3534
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MountReleasedMessage {
+    #[serde(rename = "mountId")]
+    pub mount_id: u32,
+}
+
+/// `MountRenameRequestMessage`, id 5913
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MountRenameRequestMessage {
+    pub name: String,
+    #[serde(rename = "mountId")]
+    pub mount_id: u32,
+}
+
+/// `MountRenamedMessage`, id 3425
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MountRenamedMessage {
+    #[serde(rename = "mountId")]
+    pub mount_id: u32,
+    pub name: String,
+}
+
+/// `MountRidingMessage`, id 455
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MountRidingMessage {
+    #[serde(rename = "isRiding")]
+    pub is_riding: bool,
+    #[serde(rename = "isAutopilot")]
+    pub is_autopilot: bool,
+}
+
+/// `MountSetMessage`, id 792
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MountSetMessage {
+    #[serde(rename = "mountData")]
+    pub mount_data: MountClientData,
+}
+
+/// `MountSetXpRatioRequestMessage`, id 9855
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MountSetXpRatioRequestMessage {
+    #[serde(rename = "xpRatio")]
+    pub xp_ratio: i8,
+}
+
+/// `MountSterilizeRequestMessage`, id 8703
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MountSterilizeRequestMessage {}
+
+/// `MountSterilizedMessage`, id 3315
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MountSterilizedMessage {
+    #[serde(rename = "mountId")]
+    pub mount_id: u32,
+}
+
+/// `MountToggleRidingRequestMessage`, id 1190
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MountToggleRidingRequestMessage {}
+
+/// `MountUnSetMessage`, id 7032
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MountUnSetMessage {}
+
+/// `MountXpRatioMessage`, id 3453
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MountXpRatioMessage {
+    pub ratio: i8,
+}
+
+/// `MoveTaxCollectorOrderedSpellMessage`, id 6647
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MoveTaxCollectorOrderedSpellMessage {
+    #[serde(rename = "taxCollectorId", deserialize_with = "float")]
+    pub tax_collector_id: f64,
+    #[serde(rename = "movedFrom")]
+    pub moved_from: i8,
+    #[serde(rename = "movedTo")]
+    pub moved_to: i8,
+}
+
+/// `MoveTaxCollectorPresetSpellMessage`, id 2277
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MoveTaxCollectorPresetSpellMessage {
+    #[serde(rename = "presetId")]
+    pub preset_id: Uuid,
+    #[serde(rename = "movedFrom")]
+    pub moved_from: i8,
+    #[serde(rename = "movedTo")]
+    pub moved_to: i8,
+}
+
+/// `MultiTabStorageMessage`, id 3530
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MultiTabStorageMessage {
+    pub tabs: Vec<StorageTabInformation>,
+}
+
+/// `NamedPartyTeam`, id 3499
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NamedPartyTeam {
+    #[serde(rename = "teamId")]
+    pub team_id: i8,
+    #[serde(rename = "partyName")]
+    pub party_name: String,
+}
+
+/// `NamedPartyTeamWithOutcome`, id 252
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NamedPartyTeamWithOutcome {
+    pub team: NamedPartyTeam,
+    pub outcome: u16,
+}
+
+/// `NetworkDataContainerMessage`, id 2
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NetworkDataContainerMessage {}
+
+/// `NicknameAcceptedMessage`, id 6729
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NicknameAcceptedMessage {}
+
+/// `NicknameChoiceRequestMessage`, id 3485
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NicknameChoiceRequestMessage {
+    pub nickname: String,
+}
+
+/// `NicknameRefusedMessage`, id 8952
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NicknameRefusedMessage {
+    pub reason: i8,
+}
+
+/// `NicknameRegistrationMessage`, id 5262
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NicknameRegistrationMessage {}
+
+/// `NotificationByServerMessage`, id 485
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationByServerMessage {
+    pub id: u16,
+    pub parameters: Vec<String>,
+    #[serde(rename = "forceOpen")]
+    pub force_open: bool,
+}
+
+/// `NotificationListMessage`, id 1568
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationListMessage {
+    pub flags: Vec<u32>,
+}
+
+/// `NotificationResetMessage`, id 643
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NotificationResetMessage {}
+
+/// `NotificationUpdateFlagMessage`, id 1553
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationUpdateFlagMessage {
+    pub index: u16,
+}
+
+/// `NpcDialogCreationMessage`, id 2607
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NpcDialogCreationMessage {
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    #[serde(rename = "npcId")]
+    pub npc_id: i32,
+}
+
+/// `NpcDialogQuestionMessage`, id 7304
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NpcDialogQuestionMessage {
+    #[serde(rename = "messageId")]
+    pub message_id: u32,
+    #[serde(rename = "dialogParams")]
+    pub dialog_params: Vec<String>,
+    #[serde(rename = "visibleReplies")]
+    pub visible_replies: Vec<u32>,
+}
+
+/// `NpcDialogReplyMessage`, id 7329
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NpcDialogReplyMessage {
+    #[serde(rename = "replyId")]
+    pub reply_id: u32,
+}
+
+/// `NpcGenericActionFailureMessage`, id 9576
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NpcGenericActionFailureMessage {}
+
+/// `NpcGenericActionRequestMessage`, id 3401
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NpcGenericActionRequestMessage {
+    #[serde(rename = "npcId")]
+    pub npc_id: i32,
+    #[serde(rename = "npcActionId")]
+    pub npc_action_id: i8,
+    #[serde(rename = "npcMapId", deserialize_with = "float")]
+    pub npc_map_id: f64,
+}
+
+/// `NuggetsBeneficiary`, id 1089
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NuggetsBeneficiary {
+    #[serde(rename = "beneficiaryPlayerId")]
+    pub beneficiary_player_id: u64,
+    #[serde(rename = "nuggetsQuantity")]
+    pub nuggets_quantity: i32,
+}
+
+/// `NuggetsDistributionMessage`, id 7391
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NuggetsDistributionMessage {
+    pub beneficiaries: Vec<NuggetsBeneficiary>,
+}
+
+/// `NuggetsInformationMessage`, id 3652
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NuggetsInformationMessage {
+    #[serde(rename = "nuggetsQuantity")]
+    pub nuggets_quantity: i32,
+}
+
+/// `NumericWhoIsMessage`, id 3423
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NumericWhoIsMessage {
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+}
+
+/// `NumericWhoIsRequestMessage`, id 6212
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NumericWhoIsRequestMessage {
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+}
+
+/// `ObjectAddedMessage`, id 2795
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectAddedMessage {
+    pub object: ObjectItem,
+    pub origin: i8,
+}
+
+/// `ObjectAveragePricesErrorMessage`, id 3438
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ObjectAveragePricesErrorMessage {}
+
+/// `ObjectAveragePricesGetMessage`, id 6329
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ObjectAveragePricesGetMessage {}
+
+/// `ObjectAveragePricesMessage`, id 9395
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectAveragePricesMessage {
+    pub ids: Vec<u32>,
+    #[serde(rename = "avgPrices")]
+    pub avg_prices: Vec<u64>,
+}
+
+/// `ObjectDeleteMessage`, id 4046
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectDeleteMessage {
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+    pub quantity: u32,
+}
+
+/// `ObjectDeletedMessage`, id 4692
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectDeletedMessage {
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+}
+
+/// `ObjectDropMessage`, id 1929
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectDropMessage {
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+    pub quantity: u32,
+}
+
+/// `ObjectEffect`, id 8853
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectEffect {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+}
+
+/// `ObjectEffectCreature`, id 7906
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectEffectCreature {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "monsterFamilyId")]
+    pub monster_family_id: u16,
+}
+
+/// `ObjectEffectDate`, id 3111
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectEffectDate {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    pub year: u16,
+    pub month: i8,
+    pub day: i8,
+    pub hour: i8,
+    pub minute: i8,
+}
+
+/// `ObjectEffectDice`, id 5097
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectEffectDice {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "diceNum")]
+    pub dice_num: u32,
+    #[serde(rename = "diceSide")]
+    pub dice_side: u32,
+    #[serde(rename = "diceConst")]
+    pub dice_const: u32,
+}
+
+/// `ObjectEffectDuration`, id 5116
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectEffectDuration {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    pub days: u16,
+    pub hours: i8,
+    pub minutes: i8,
+}
+
+/// `ObjectEffectInteger`, id 5722
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectEffectInteger {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    pub value: u32,
+}
+
+/// `ObjectEffectLadder`, id 6905
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectEffectLadder {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "monsterFamilyId")]
+    pub monster_family_id: u16,
+    #[serde(rename = "monsterCount")]
+    pub monster_count: u32,
+}
+
+/// `ObjectEffectMinMax`, id 6457
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectEffectMinMax {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    pub min: u32,
+    pub max: u32,
+}
+
+/// `ObjectEffectMount`, id 7839
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectEffectMount {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    pub sex: bool,
+    #[serde(rename = "isRideable")]
+    pub is_rideable: bool,
+    #[serde(rename = "isFeconded")]
+    pub is_feconded: bool,
+    #[serde(rename = "isFecondationReady")]
+    pub is_fecondation_ready: bool,
+    pub id: u64,
+    #[serde(rename = "expirationDate")]
+    pub expiration_date: u64,
+    pub model: u32,
+    pub name: String,
+    pub owner: String,
+    pub level: i8,
+    #[serde(rename = "reproductionCount")]
+    pub reproduction_count: u32,
+    #[serde(rename = "reproductionCountMax")]
+    pub reproduction_count_max: u32,
+    pub effects: Vec<ObjectEffectInteger>,
+    pub capacities: Vec<u32>,
+}
+
+/// `ObjectEffectString`, id 8837
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectEffectString {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    pub value: String,
+}
+
+/// `ObjectEffects`, id 5223
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectEffects {
+    pub effects: Vec<ObjectEffectKind>,
+}
+
+/// `ObjectErrorMessage`, id 2753
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectErrorMessage {
+    pub reason: i8,
+}
+
+/// `ObjectFeedMessage`, id 6700
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectFeedMessage {
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+    pub meal: Vec<ObjectItemQuantity>,
+}
+
+/// `ObjectGroundAddedMessage`, id 8098
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectGroundAddedMessage {
+    #[serde(rename = "cellId")]
+    pub cell_id: u16,
+    #[serde(rename = "objectGID")]
+    pub object_gid: u32,
+}
+
+/// `ObjectGroundListAddedMessage`, id 4686
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectGroundListAddedMessage {
+    pub cells: Vec<u16>,
+    #[serde(rename = "referenceIds")]
+    pub reference_ids: Vec<u32>,
+}
+
+/// `ObjectGroundRemovedMessage`, id 3376
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectGroundRemovedMessage {
+    pub cell: u16,
+}
+
+/// `ObjectGroundRemovedMultipleMessage`, id 7894
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectGroundRemovedMultipleMessage {
+    pub cells: Vec<u16>,
+}
+
+/// `ObjectItem`, id 8371
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectItem {
+    pub position: i16,
+    #[serde(rename = "objectGID")]
+    pub object_gid: u32,
+    pub effects: Vec<ObjectEffectKind>,
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+    pub quantity: u32,
+    pub favorite: bool,
+}
+
+/// `ObjectItemGenericQuantity`, id 8155
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectItemGenericQuantity {
+    #[serde(rename = "objectGID")]
+    pub object_gid: u32,
+    pub quantity: u32,
+}
+
+/// `ObjectItemInRolePlay`, id 4028
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectItemInRolePlay {
+    #[serde(rename = "cellId")]
+    pub cell_id: u16,
+    #[serde(rename = "objectGID")]
+    pub object_gid: u32,
+}
+
+/// `ObjectItemInformationWithQuantity`, id 4037
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectItemInformationWithQuantity {
+    #[serde(rename = "objectGID")]
+    pub object_gid: u32,
+    pub effects: Vec<ObjectEffectKind>,
+    pub quantity: u32,
+}
+
+/// `ObjectItemMinimalInformation`, id 2911
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectItemMinimalInformation {
+    #[serde(rename = "objectGID")]
+    pub object_gid: u32,
+    pub effects: Vec<ObjectEffectKind>,
+}
+
+/// `ObjectItemNotInContainer`, id 7437
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectItemNotInContainer {
+    #[serde(rename = "objectGID")]
+    pub object_gid: u32,
+    pub effects: Vec<ObjectEffectKind>,
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+    pub quantity: u32,
+}
+
+/// `ObjectItemQuantity`, id 6363
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectItemQuantity {
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+    pub quantity: u32,
+}
+
+/// `ObjectItemQuantityPriceDateEffects`, id 8474
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectItemQuantityPriceDateEffects {
+    #[serde(rename = "objectGID")]
+    pub object_gid: u32,
+    pub quantity: u32,
+    pub price: u64,
+    pub effects: ObjectEffects,
+    pub date: i32,
+}
+
+/// `ObjectItemToSell`, id 2296
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectItemToSell {
+    #[serde(rename = "objectGID")]
+    pub object_gid: u32,
+    pub effects: Vec<ObjectEffectKind>,
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+    pub quantity: u32,
+    #[serde(rename = "objectPrice")]
+    pub object_price: u64,
+}
+
+/// `ObjectItemToSellInBid`, id 166
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectItemToSellInBid {
+    #[serde(rename = "objectGID")]
+    pub object_gid: u32,
+    pub effects: Vec<ObjectEffectKind>,
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+    pub quantity: u32,
+    #[serde(rename = "objectPrice")]
+    pub object_price: u64,
+    #[serde(rename = "unsoldDelay")]
+    pub unsold_delay: i32,
+}
+
+/// `ObjectItemToSellInNpcShop`, id 5422
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectItemToSellInNpcShop {
+    #[serde(rename = "objectGID")]
+    pub object_gid: u32,
+    pub effects: Vec<ObjectEffectKind>,
+    #[serde(rename = "objectPrice")]
+    pub object_price: u64,
+    #[serde(rename = "buyCriterion")]
+    pub buy_criterion: String,
+}
+
+/// `ObjectJobAddedMessage`, id 3028
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectJobAddedMessage {
+    #[serde(rename = "jobId")]
+    pub job_id: i8,
+}
+
+/// `ObjectModifiedMessage`, id 5182
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectModifiedMessage {
+    pub object: ObjectItem,
+}
+
+/// `ObjectMovementMessage`, id 8633
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectMovementMessage {
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+    pub position: i16,
+}
+
+/// `ObjectQuantityMessage`, id 9633
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectQuantityMessage {
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+    pub quantity: u32,
+    pub origin: i8,
+}
+
+/// `ObjectSetPositionMessage`, id 815
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectSetPositionMessage {
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+    pub position: i16,
+    pub quantity: u32,
+}
+
+/// `ObjectUseMessage`, id 8071
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectUseMessage {
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+}
+
+/// `ObjectUseMultipleMessage`, id 2700
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectUseMultipleMessage {
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+    pub quantity: u32,
+}
+
+/// `ObjectUseOnCellMessage`, id 320
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectUseOnCellMessage {
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+    pub cells: u16,
+}
+
+/// `ObjectUseOnCharacterMessage`, id 801
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectUseOnCharacterMessage {
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+    #[serde(rename = "characterId")]
+    pub character_id: u64,
+}
+
+/// `ObjectsAddedMessage`, id 3040
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectsAddedMessage {
+    pub object: Vec<ObjectItem>,
+}
+
+/// `ObjectsDeletedMessage`, id 2538
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectsDeletedMessage {
+    #[serde(rename = "objectUID")]
+    pub object_uid: Vec<u32>,
+}
+
+/// `ObjectsQuantityMessage`, id 3309
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectsQuantityMessage {
+    #[serde(rename = "objectsUIDAndQty")]
+    pub objects_uid_and_qty: Vec<ObjectItemQuantity>,
+}
+
+/// `ObtainedItemMessage`, id 426
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObtainedItemMessage {
+    #[serde(rename = "genericId")]
+    pub generic_id: u32,
+    #[serde(rename = "baseQuantity")]
+    pub base_quantity: u32,
+}
+
+/// `ObtainedItemWithBonusMessage`, id 1619
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObtainedItemWithBonusMessage {
+    #[serde(rename = "genericId")]
+    pub generic_id: u32,
+    #[serde(rename = "baseQuantity")]
+    pub base_quantity: u32,
+    #[serde(rename = "bonusQuantity")]
+    pub bonus_quantity: u32,
+}
+
+/// `OnConnectionEventMessage`, id 3041
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OnConnectionEventMessage {
+    #[serde(rename = "eventType")]
+    pub event_type: i8,
+}
+
+/// `OpenGuideBookMessage`, id 3314
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OpenGuideBookMessage {
+    #[serde(rename = "articleId")]
+    pub article_id: u16,
+}
+
+/// `OpenHavenBagFurnitureSequenceRequestMessage`, id 9599
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OpenHavenBagFurnitureSequenceRequestMessage {}
+
+/// `OpponentSurrenderMessage`, id 7133
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OpponentSurrenderMessage {}
+
+/// `OrnamentGainedMessage`, id 7842
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OrnamentGainedMessage {
+    #[serde(rename = "ornamentId")]
+    pub ornament_id: i16,
+}
+
+/// `OrnamentLostMessage`, id 1419
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OrnamentLostMessage {
+    #[serde(rename = "ornamentId")]
+    pub ornament_id: i16,
+}
+
+/// `OrnamentSelectErrorMessage`, id 7793
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OrnamentSelectErrorMessage {
+    pub reason: i8,
+}
+
+/// `OrnamentSelectRequestMessage`, id 9551
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OrnamentSelectRequestMessage {
+    #[serde(rename = "ornamentId")]
+    pub ornament_id: u16,
+}
+
+/// `OrnamentSelectedMessage`, id 1105
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OrnamentSelectedMessage {
+    #[serde(rename = "ornamentId")]
+    pub ornament_id: u16,
+}
+
+/// `PaddockBuyRequestMessage`, id 9946
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaddockBuyRequestMessage {
+    #[serde(rename = "proposedPrice")]
+    pub proposed_price: u64,
+}
+
+/// `PaddockBuyResultMessage`, id 335
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaddockBuyResultMessage {
+    #[serde(rename = "paddockId", deserialize_with = "float")]
+    pub paddock_id: f64,
+    pub bought: bool,
+    #[serde(rename = "realPrice")]
+    pub real_price: u64,
+}
+
+/// `PaddockBuyableInformations`, id 7593
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaddockBuyableInformations {
+    pub price: u64,
+    pub locked: bool,
+}
+
+/// `PaddockContentInformations`, id 8721
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaddockContentInformations {
+    #[serde(rename = "maxOutdoorMount")]
+    pub max_outdoor_mount: u16,
+    #[serde(rename = "maxItems")]
+    pub max_items: u16,
+    #[serde(rename = "paddockId", deserialize_with = "float")]
+    pub paddock_id: f64,
+    #[serde(rename = "worldX")]
+    pub world_x: i16,
+    #[serde(rename = "worldY")]
+    pub world_y: i16,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+    pub abandonned: bool,
+    #[serde(rename = "mountsInformations")]
+    pub mounts_informations: Vec<MountInformationsForPaddock>,
+}
+
+/// `PaddockGuildedInformations`, id 2305
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaddockGuildedInformations {
+    pub price: u64,
+    pub locked: bool,
+    pub deserted: bool,
+    #[serde(rename = "guildInfo")]
+    pub guild_info: GuildInformations,
+}
+
+/// `PaddockInformations`, id 3212
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaddockInformations {
+    #[serde(rename = "maxOutdoorMount")]
+    pub max_outdoor_mount: u16,
+    #[serde(rename = "maxItems")]
+    pub max_items: u16,
+}
+
+/// `PaddockInformationsForSell`, id 4240
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaddockInformationsForSell {
+    #[serde(rename = "guildOwner")]
+    pub guild_owner: String,
+    #[serde(rename = "worldX")]
+    pub world_x: i16,
+    #[serde(rename = "worldY")]
+    pub world_y: i16,
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+    #[serde(rename = "nbMount")]
+    pub nb_mount: i8,
+    #[serde(rename = "nbObject")]
+    pub nb_object: i8,
+    pub price: u64,
+}
+
+/// `PaddockInstancesInformations`, id 6034
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaddockInstancesInformations {
+    #[serde(rename = "maxOutdoorMount")]
+    pub max_outdoor_mount: u16,
+    #[serde(rename = "maxItems")]
+    pub max_items: u16,
+    pub paddocks: Vec<PaddockBuyableInformationsKind>,
+}
+
+/// `PaddockItem`, id 5588
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaddockItem {
+    #[serde(rename = "cellId")]
+    pub cell_id: u16,
+    #[serde(rename = "objectGID")]
+    pub object_gid: u32,
+    pub durability: ItemDurability,
+}
+
+/// `PaddockMoveItemRequestMessage`, id 125
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaddockMoveItemRequestMessage {
+    #[serde(rename = "oldCellId")]
+    pub old_cell_id: u16,
+    #[serde(rename = "newCellId")]
+    pub new_cell_id: u16,
+}
+
+/// `PaddockPropertiesMessage`, id 6321
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaddockPropertiesMessage {
+    pub properties: PaddockInstancesInformations,
+}
+
+/// `PaddockRemoveItemRequestMessage`, id 6112
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaddockRemoveItemRequestMessage {
+    #[serde(rename = "cellId")]
+    pub cell_id: u16,
+}
+
+/// `PaddockSellBuyDialogMessage`, id 7276
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaddockSellBuyDialogMessage {
+    pub bsell: bool,
+    #[serde(rename = "ownerId")]
+    pub owner_id: u32,
+    pub price: u64,
+}
+
+/// `PaddockSellRequestMessage`, id 4924
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaddockSellRequestMessage {
+    pub price: u64,
+    #[serde(rename = "forSale")]
+    pub for_sale: bool,
+}
+
+/// `PaddockToSellFilterMessage`, id 899
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaddockToSellFilterMessage {
+    #[serde(rename = "areaId")]
+    pub area_id: i32,
+    #[serde(rename = "atLeastNbMount")]
+    pub at_least_nb_mount: i8,
+    #[serde(rename = "atLeastNbMachine")]
+    pub at_least_nb_machine: i8,
+    #[serde(rename = "maxPrice")]
+    pub max_price: u64,
+    #[serde(rename = "orderBy")]
+    pub order_by: i8,
+}
+
+/// `PaddockToSellListMessage`, id 5837
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaddockToSellListMessage {
+    #[serde(rename = "pageIndex")]
+    pub page_index: u16,
+    #[serde(rename = "totalPage")]
+    pub total_page: u16,
+    #[serde(rename = "paddockList")]
+    pub paddock_list: Vec<PaddockInformationsForSell>,
+}
+
+/// `PaddockToSellListRequestMessage`, id 5628
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaddockToSellListRequestMessage {
+    #[serde(rename = "pageIndex")]
+    pub page_index: u16,
+}
+
+/// `PaginationAnswerAbstractMessage`, id 8699
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaginationAnswerAbstractMessage {
+    #[serde(deserialize_with = "float")]
+    pub offset: f64,
+    pub count: u32,
+    pub total: u32,
+}
+
+/// `PaginationRequestAbstractMessage`, id 8327
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PaginationRequestAbstractMessage {
+    #[serde(deserialize_with = "float")]
+    pub offset: f64,
+    pub count: u32,
+}
+
+/// `PartyAbdicateThroneMessage`, id 5378
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyAbdicateThroneMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+}
+
+/// `PartyAcceptInvitationMessage`, id 9715
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyAcceptInvitationMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+}
+
+/// `PartyCancelInvitationMessage`, id 3911
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyCancelInvitationMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    #[serde(rename = "guestId")]
+    pub guest_id: u64,
+}
+
+/// `PartyCancelInvitationNotificationMessage`, id 3506
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyCancelInvitationNotificationMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    #[serde(rename = "cancelerId")]
+    pub canceler_id: u64,
+    #[serde(rename = "guestId")]
+    pub guest_id: u64,
+}
+
+/// `PartyCannotJoinErrorMessage`, id 1360
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyCannotJoinErrorMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    pub reason: i8,
+}
+
+/// `PartyDeletedMessage`, id 7668
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyDeletedMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+}
+
+/// `PartyEntityBaseInformation`, id 1037
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyEntityBaseInformation {
+    #[serde(rename = "indexId")]
+    pub index_id: i8,
+    #[serde(rename = "entityModelId")]
+    pub entity_model_id: i8,
+    #[serde(rename = "entityLook")]
+    pub entity_look: EntityLook,
+}
+
+/// `PartyEntityMemberInformation`, id 266
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyEntityMemberInformation {
+    #[serde(rename = "indexId")]
+    pub index_id: i8,
+    #[serde(rename = "entityModelId")]
+    pub entity_model_id: i8,
+    #[serde(rename = "entityLook")]
+    pub entity_look: EntityLook,
+    pub initiative: u32,
+    #[serde(rename = "lifePoints")]
+    pub life_points: u32,
+    #[serde(rename = "maxLifePoints")]
+    pub max_life_points: u32,
+    pub prospecting: u32,
+    #[serde(rename = "regenRate")]
+    pub regen_rate: i8,
+}
+
+/// `PartyEntityUpdateLightMessage`, id 4271
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyEntityUpdateLightMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    pub id: u64,
+    #[serde(rename = "lifePoints")]
+    pub life_points: u32,
+    #[serde(rename = "maxLifePoints")]
+    pub max_life_points: u32,
+    pub prospecting: u32,
+    #[serde(rename = "regenRate")]
+    pub regen_rate: i8,
+    #[serde(rename = "indexId")]
+    pub index_id: i8,
+}
+
+/// `PartyFollowMemberRequestMessage`, id 229
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyFollowMemberRequestMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+}
+
+/// `PartyFollowStatusUpdateMessage`, id 1218
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyFollowStatusUpdateMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    pub success: bool,
+    #[serde(rename = "isFollowed")]
+    pub is_followed: bool,
+    #[serde(rename = "followedId")]
+    pub followed_id: u64,
+}
+
+/// `PartyFollowThisMemberRequestMessage`, id 7300
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyFollowThisMemberRequestMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+    pub enabled: bool,
+}
+
+/// `PartyGuestInformations`, id 8732
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyGuestInformations {
+    #[serde(rename = "guestId")]
+    pub guest_id: u64,
+    #[serde(rename = "hostId")]
+    pub host_id: u64,
+    pub name: String,
+    #[serde(rename = "guestLook")]
+    pub guest_look: EntityLook,
+    pub breed: i8,
+    pub sex: bool,
+    pub status: Box<PlayerStatusKind>,
+    pub entities: Vec<PartyEntityBaseInformation>,
+}
+
+/// `PartyInvitationArenaRequestMessage`, id 8035
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyInvitationArenaRequestMessage {
+    pub target: Box<AbstractPlayerSearchInformationKind>,
+}
+
+/// `PartyInvitationCancelledForGuestMessage`, id 9178
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyInvitationCancelledForGuestMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    #[serde(rename = "cancelerId")]
+    pub canceler_id: u64,
+}
+
+/// `PartyInvitationDetailsMessage`, id 634
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyInvitationDetailsMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    #[serde(rename = "partyType")]
+    pub party_type: i8,
+    #[serde(rename = "partyName")]
+    pub party_name: String,
+    #[serde(rename = "fromId")]
+    pub from_id: u64,
+    #[serde(rename = "fromName")]
+    pub from_name: String,
+    #[serde(rename = "leaderId")]
+    pub leader_id: u64,
+    pub members: Vec<PartyInvitationMemberInformationsKind>,
+    pub guests: Vec<PartyGuestInformations>,
+}
+
+/// `PartyInvitationDetailsRequestMessage`, id 5575
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyInvitationDetailsRequestMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+}
+
+/// `PartyInvitationDungeonDetailsMessage`, id 2147
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyInvitationDungeonDetailsMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    #[serde(rename = "partyType")]
+    pub party_type: i8,
+    #[serde(rename = "partyName")]
+    pub party_name: String,
+    #[serde(rename = "fromId")]
+    pub from_id: u64,
+    #[serde(rename = "fromName")]
+    pub from_name: String,
+    #[serde(rename = "leaderId")]
+    pub leader_id: u64,
+    pub members: Vec<PartyInvitationMemberInformationsKind>,
+    pub guests: Vec<PartyGuestInformations>,
+    #[serde(rename = "dungeonId")]
+    pub dungeon_id: u16,
+    #[serde(rename = "playersDungeonReady")]
+    pub players_dungeon_ready: Vec<bool>,
+}
+
+/// `PartyInvitationDungeonMessage`, id 5285
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyInvitationDungeonMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    #[serde(rename = "partyType")]
+    pub party_type: i8,
+    #[serde(rename = "partyName")]
+    pub party_name: String,
+    #[serde(rename = "maxParticipants")]
+    pub max_participants: i8,
+    #[serde(rename = "fromId")]
+    pub from_id: u64,
+    #[serde(rename = "fromName")]
+    pub from_name: String,
+    #[serde(rename = "toId")]
+    pub to_id: u64,
+    #[serde(rename = "dungeonId")]
+    pub dungeon_id: u16,
+}
+
+/// `PartyInvitationDungeonRequestMessage`, id 7151
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyInvitationDungeonRequestMessage {
+    pub target: Box<AbstractPlayerSearchInformationKind>,
+    #[serde(rename = "dungeonId")]
+    pub dungeon_id: u16,
+}
+
+/// `PartyInvitationMemberInformations`, id 8078
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyInvitationMemberInformations {
+    pub id: u64,
+    pub name: String,
+    pub level: u16,
+    #[serde(rename = "entityLook")]
+    pub entity_look: EntityLook,
+    pub breed: i8,
+    pub sex: bool,
+    #[serde(rename = "worldX")]
+    pub world_x: i16,
+    #[serde(rename = "worldY")]
+    pub world_y: i16,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+    pub entities: Vec<PartyEntityBaseInformation>,
+}
+
+/// `PartyInvitationMessage`, id 6989
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyInvitationMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    #[serde(rename = "partyType")]
+    pub party_type: i8,
+    #[serde(rename = "partyName")]
+    pub party_name: String,
+    #[serde(rename = "maxParticipants")]
+    pub max_participants: i8,
+    #[serde(rename = "fromId")]
+    pub from_id: u64,
+    #[serde(rename = "fromName")]
+    pub from_name: String,
+    #[serde(rename = "toId")]
+    pub to_id: u64,
+}
+
+/// `PartyInvitationRequestMessage`, id 4001
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyInvitationRequestMessage {
+    pub target: Box<AbstractPlayerSearchInformationKind>,
+}
+
+/// `PartyJoinMessage`, id 8846
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyJoinMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    #[serde(rename = "partyType")]
+    pub party_type: i8,
+    #[serde(rename = "partyLeaderId")]
+    pub party_leader_id: u64,
+    #[serde(rename = "maxParticipants")]
+    pub max_participants: i8,
+    pub members: Vec<PartyMemberInformationsKind>,
+    pub guests: Vec<PartyGuestInformations>,
+    pub restricted: bool,
+    #[serde(rename = "partyName")]
+    pub party_name: String,
+}
+
+/// `PartyKickRequestMessage`, id 8208
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyKickRequestMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+}
+
+/// `PartyKickedByMessage`, id 943
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyKickedByMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    #[serde(rename = "kickerId")]
+    pub kicker_id: u64,
+}
+
+/// `PartyLeaderUpdateMessage`, id 893
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyLeaderUpdateMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    #[serde(rename = "partyLeaderId")]
+    pub party_leader_id: u64,
+}
+
+/// `PartyLeaveMessage`, id 6383
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyLeaveMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+}
+
+/// `PartyLeaveRequestMessage`, id 7212
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyLeaveRequestMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+}
+
+/// `PartyLocateMembersMessage`, id 594
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyLocateMembersMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    pub geopositions: Vec<PartyMemberGeoPosition>,
+}
+
+/// `PartyLocateMembersRequestMessage`, id 3373
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyLocateMembersRequestMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+}
+
+/// `PartyLoyaltyStatusMessage`, id 6152
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyLoyaltyStatusMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    pub loyal: bool,
+}
+
+/// `PartyMemberArenaInformations`, id 8362
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyMemberArenaInformations {
+    pub id: u64,
+    pub name: String,
+    pub level: u16,
+    #[serde(rename = "entityLook")]
+    pub entity_look: EntityLook,
+    pub breed: i8,
+    pub sex: bool,
+    #[serde(rename = "lifePoints")]
+    pub life_points: u32,
+    #[serde(rename = "maxLifePoints")]
+    pub max_life_points: u32,
+    pub prospecting: u32,
+    #[serde(rename = "regenRate")]
+    pub regen_rate: i8,
+    pub initiative: u32,
+    #[serde(rename = "alignmentSide")]
+    pub alignment_side: i8,
+    #[serde(rename = "worldX")]
+    pub world_x: i16,
+    #[serde(rename = "worldY")]
+    pub world_y: i16,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+    pub status: Box<PlayerStatusKind>,
+    pub entities: Vec<PartyEntityBaseInformationKind>,
+    pub rank: u16,
+}
+
+/// `PartyMemberEjectedMessage`, id 4579
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyMemberEjectedMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    #[serde(rename = "leavingPlayerId")]
+    pub leaving_player_id: u64,
+    #[serde(rename = "kickerId")]
+    pub kicker_id: u64,
+}
+
+/// `PartyMemberGeoPosition`, id 8397
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyMemberGeoPosition {
+    #[serde(rename = "memberId")]
+    pub member_id: i32,
+    #[serde(rename = "worldX")]
+    pub world_x: i16,
+    #[serde(rename = "worldY")]
+    pub world_y: i16,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+}
+
+/// `PartyMemberInBreachFightMessage`, id 8104
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyMemberInBreachFightMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    pub reason: i8,
+    #[serde(rename = "memberId")]
+    pub member_id: u64,
+    #[serde(rename = "memberAccountId")]
+    pub member_account_id: i32,
+    #[serde(rename = "memberName")]
+    pub member_name: String,
+    #[serde(rename = "fightId")]
+    pub fight_id: u16,
+    #[serde(rename = "timeBeforeFightStart")]
+    pub time_before_fight_start: u16,
+    pub floor: u32,
+    pub room: i8,
+}
+
+/// `PartyMemberInStandardFightMessage`, id 4564
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyMemberInStandardFightMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    pub reason: i8,
+    #[serde(rename = "memberId")]
+    pub member_id: u64,
+    #[serde(rename = "memberAccountId")]
+    pub member_account_id: i32,
+    #[serde(rename = "memberName")]
+    pub member_name: String,
+    #[serde(rename = "fightId")]
+    pub fight_id: u16,
+    #[serde(rename = "timeBeforeFightStart")]
+    pub time_before_fight_start: u16,
+    #[serde(rename = "fightMap")]
+    pub fight_map: MapCoordinatesExtended,
+}
+
+/// `PartyMemberInformations`, id 7120
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyMemberInformations {
+    pub id: u64,
+    pub name: String,
+    pub level: u16,
+    #[serde(rename = "entityLook")]
+    pub entity_look: EntityLook,
+    pub breed: i8,
+    pub sex: bool,
+    #[serde(rename = "lifePoints")]
+    pub life_points: u32,
+    #[serde(rename = "maxLifePoints")]
+    pub max_life_points: u32,
+    pub prospecting: u32,
+    #[serde(rename = "regenRate")]
+    pub regen_rate: i8,
+    pub initiative: u32,
+    #[serde(rename = "alignmentSide")]
+    pub alignment_side: i8,
+    #[serde(rename = "worldX")]
+    pub world_x: i16,
+    #[serde(rename = "worldY")]
+    pub world_y: i16,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+    pub status: Box<PlayerStatusKind>,
+    pub entities: Vec<PartyEntityBaseInformationKind>,
+}
+
+/// `PartyMemberRemoveMessage`, id 6273
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyMemberRemoveMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    #[serde(rename = "leavingPlayerId")]
+    pub leaving_player_id: u64,
+}
+
+/// `PartyModifiableStatusMessage`, id 5630
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyModifiableStatusMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    pub enabled: bool,
+}
+
+/// `PartyNameSetErrorMessage`, id 5453
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyNameSetErrorMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    pub result: i8,
+}
+
+/// `PartyNameSetRequestMessage`, id 6682
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyNameSetRequestMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    #[serde(rename = "partyName")]
+    pub party_name: String,
+}
+
+/// `PartyNameUpdateMessage`, id 9692
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyNameUpdateMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    #[serde(rename = "partyName")]
+    pub party_name: String,
+}
+
+/// `PartyNewGuestMessage`, id 4130
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyNewGuestMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    pub guest: PartyGuestInformations,
+}
+
+/// `PartyNewMemberMessage`, id 4803
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyNewMemberMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    #[serde(rename = "memberInformations")]
+    pub member_informations: Box<PartyMemberInformationsKind>,
+}
+
+/// `PartyPledgeLoyaltyRequestMessage`, id 8902
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyPledgeLoyaltyRequestMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    pub loyal: bool,
+}
+
+/// `PartyRefuseInvitationMessage`, id 2934
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyRefuseInvitationMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+}
+
+/// `PartyRefuseInvitationNotificationMessage`, id 4073
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyRefuseInvitationNotificationMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    #[serde(rename = "guestId")]
+    pub guest_id: u64,
+}
+
+/// `PartyRestrictedMessage`, id 7588
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyRestrictedMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    pub restricted: bool,
+}
+
+/// `PartyStopFollowRequestMessage`, id 9227
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyStopFollowRequestMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+}
+
+/// `PartyUpdateLightMessage`, id 9898
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyUpdateLightMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    pub id: u64,
+    #[serde(rename = "lifePoints")]
+    pub life_points: u32,
+    #[serde(rename = "maxLifePoints")]
+    pub max_life_points: u32,
+    pub prospecting: u32,
+    #[serde(rename = "regenRate")]
+    pub regen_rate: i8,
+}
+
+/// `PartyUpdateMessage`, id 7326
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PartyUpdateMessage {
+    #[serde(rename = "partyId")]
+    pub party_id: u32,
+    #[serde(rename = "memberInformations")]
+    pub member_informations: Box<PartyMemberInformationsKind>,
+}
+
+/// `PauseDialogMessage`, id 3739
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PauseDialogMessage {
+    #[serde(rename = "dialogType")]
+    pub dialog_type: i8,
+}
+
+/// `PlayerNote`, id 1670
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlayerNote {
+    pub content: String,
+    #[serde(rename = "lastEditDate", deserialize_with = "float")]
+    pub last_edit_date: f64,
+}
+
+/// `PlayerSearchCharacterNameInformation`, id 8303
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlayerSearchCharacterNameInformation {
+    pub name: String,
+}
+
+/// `PlayerSearchTagInformation`, id 6999
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlayerSearchTagInformation {
+    pub tag: AccountTagInformation,
+}
+
+/// `PlayerStatus`, id 6911
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlayerStatus {
+    #[serde(rename = "statusId")]
+    pub status_id: i8,
+}
+
+/// `PlayerStatusExtended`, id 5742
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlayerStatusExtended {
+    #[serde(rename = "statusId")]
+    pub status_id: i8,
+    pub message: String,
+}
+
+/// `PlayerStatusUpdateErrorMessage`, id 5538
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PlayerStatusUpdateErrorMessage {}
+
+/// `PlayerStatusUpdateMessage`, id 7850
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlayerStatusUpdateMessage {
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+    pub status: Box<PlayerStatusKind>,
+}
+
+/// `PlayerStatusUpdateRequestMessage`, id 8545
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlayerStatusUpdateRequestMessage {
+    pub status: Box<PlayerStatusKind>,
+}
+
+/// `PopupWarningCloseRequestMessage`, id 5859
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PopupWarningCloseRequestMessage {}
+
+/// `PopupWarningClosedMessage`, id 3119
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PopupWarningClosedMessage {}
+
+/// `PopupWarningMessage`, id 3395
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PopupWarningMessage {
+    #[serde(rename = "lockDuration")]
+    pub lock_duration: i8,
+    pub author: String,
+    pub content: String,
+}
+
+/// `PortalDialogCreationMessage`, id 1297
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PortalDialogCreationMessage {
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    #[serde(rename = "npcId")]
+    pub npc_id: i32,
+    pub r#type: i32,
+}
+
+/// `PortalInformation`, id 2561
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PortalInformation {
+    #[serde(rename = "portalId")]
+    pub portal_id: i32,
+    #[serde(rename = "areaId")]
+    pub area_id: i16,
+}
+
+/// `PortalUseRequestMessage`, id 7262
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PortalUseRequestMessage {
+    #[serde(rename = "portalId")]
+    pub portal_id: u32,
+}
+
+/// `Preset`, id 5013
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Preset {
+    pub id: i16,
+}
+
+/// `PresetDeleteRequestMessage`, id 8274
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PresetDeleteRequestMessage {
+    #[serde(rename = "presetId")]
+    pub preset_id: i16,
+}
+
+/// `PresetDeleteResultMessage`, id 4975
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PresetDeleteResultMessage {
+    #[serde(rename = "presetId")]
+    pub preset_id: i16,
+    pub code: i8,
+}
+
+/// `PresetSaveErrorMessage`, id 77
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PresetSaveErrorMessage {
+    #[serde(rename = "presetId")]
+    pub preset_id: i16,
+    pub code: i8,
+}
+
+/// `PresetSavedMessage`, id 7489
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PresetSavedMessage {
+    #[serde(rename = "presetId")]
+    pub preset_id: i16,
+    pub preset: Box<PresetKind>,
+}
+
+/// `PresetUseRequestMessage`, id 3079
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PresetUseRequestMessage {
+    #[serde(rename = "presetId")]
+    pub preset_id: i16,
+}
+
+/// `PresetUseResultMessage`, id 834
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PresetUseResultMessage {
+    #[serde(rename = "presetId")]
+    pub preset_id: i16,
+    pub code: i8,
+}
+
+/// `PresetUseResultWithMissingIdsMessage`, id 6242
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PresetUseResultWithMissingIdsMessage {
+    #[serde(rename = "presetId")]
+    pub preset_id: i16,
+    pub code: i8,
+    #[serde(rename = "missingIds")]
+    pub missing_ids: Vec<u16>,
+}
+
+/// `PresetsContainerPreset`, id 5329
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PresetsContainerPreset {
+    pub id: i16,
+    pub presets: Vec<PresetKind>,
+}
+
+/// `PresetsMessage`, id 8776
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PresetsMessage {
+    pub presets: Vec<PresetKind>,
+}
+
+/// `PrismAddOrUpdateMessage`, id 2448
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PrismAddOrUpdateMessage {
+    pub prism: PrismGeolocalizedInformation,
+}
+
+/// `PrismAttackRequestMessage`, id 706
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PrismAttackRequestMessage {}
+
+/// `PrismAttackResultMessage`, id 2585
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PrismAttackResultMessage {
+    pub prism: PrismGeolocalizedInformation,
+    pub result: i8,
+}
+
+/// `PrismAttackedMessage`, id 7614
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PrismAttackedMessage {
+    pub prism: PrismGeolocalizedInformation,
+}
+
+/// `PrismExchangeRequestMessage`, id 2065
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PrismExchangeRequestMessage {}
+
+/// `PrismGeolocalizedInformation`, id 1529
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PrismGeolocalizedInformation {
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+    #[serde(rename = "allianceId")]
+    pub alliance_id: u32,
+    #[serde(rename = "worldX")]
+    pub world_x: i16,
+    #[serde(rename = "worldY")]
+    pub world_y: i16,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    pub prism: Box<PrismInformationKind>,
+}
+
+/// `PrismInformation`, id 269
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PrismInformation {
+    pub state: i8,
+    #[serde(rename = "placementDate")]
+    pub placement_date: i32,
+    #[serde(rename = "nuggetsCount")]
+    pub nuggets_count: u32,
+    pub durability: i32,
+    #[serde(rename = "nextEvolutionDate", deserialize_with = "float")]
+    pub next_evolution_date: f64,
+}
+
+/// `PrismRecycleTradeRequestMessage`, id 418
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PrismRecycleTradeRequestMessage {}
+
+/// `PrismRemoveMessage`, id 7386
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PrismRemoveMessage {
+    pub prism: PrismGeolocalizedInformation,
+}
+
+/// `PrismTeleportationRequestMessage`, id 2976
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PrismTeleportationRequestMessage {}
+
+/// `PrismsListMessage`, id 2243
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PrismsListMessage {
+    pub prisms: Vec<PrismGeolocalizedInformationKind>,
+}
+
+/// `ProtectedEntityWaitingForHelpInfo`, id 3140
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProtectedEntityWaitingForHelpInfo {
+    #[serde(rename = "timeLeftBeforeFight")]
+    pub time_left_before_fight: i32,
+    #[serde(rename = "waitTimeForPlacement")]
+    pub wait_time_for_placement: i32,
+    #[serde(rename = "nbPositionForDefensors")]
+    pub nb_position_for_defensors: i8,
+}
+
+/// `ProtocolRequired`, id 9922
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProtocolRequired {
+    pub version: String,
+}
+
+/// `PurchasableDialogMessage`, id 9556
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PurchasableDialogMessage {
+    #[serde(rename = "buyOrSell")]
+    pub buy_or_sell: bool,
+    #[serde(rename = "secondHand")]
+    pub second_hand: bool,
+    #[serde(rename = "purchasableId", deserialize_with = "float")]
+    pub purchasable_id: f64,
+    #[serde(rename = "purchasableInstanceId")]
+    pub purchasable_instance_id: i32,
+    pub price: u64,
+}
+
+/// `QuestActiveDetailedInformations`, id 8719
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuestActiveDetailedInformations {
+    #[serde(rename = "questId")]
+    pub quest_id: u16,
+    #[serde(rename = "stepId")]
+    pub step_id: u16,
+    pub objectives: Vec<QuestObjectiveInformationsKind>,
+}
+
+/// `QuestActiveInformations`, id 1335
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuestActiveInformations {
+    #[serde(rename = "questId")]
+    pub quest_id: u16,
+}
+
+/// `QuestListMessage`, id 7917
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuestListMessage {
+    #[serde(rename = "finishedQuestsIds")]
+    pub finished_quests_ids: Vec<u16>,
+    #[serde(rename = "finishedQuestsCounts")]
+    pub finished_quests_counts: Vec<u16>,
+    #[serde(rename = "activeQuests")]
+    pub active_quests: Vec<QuestActiveInformationsKind>,
+    #[serde(rename = "reinitDoneQuestsIds")]
+    pub reinit_done_quests_ids: Vec<u16>,
+}
+
+/// `QuestListRequestMessage`, id 8036
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct QuestListRequestMessage {}
+
+/// `QuestObjectiveInformations`, id 3632
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuestObjectiveInformations {
+    #[serde(rename = "objectiveId")]
+    pub objective_id: u16,
+    #[serde(rename = "objectiveStatus")]
+    pub objective_status: bool,
+    #[serde(rename = "dialogParams")]
+    pub dialog_params: Vec<String>,
+}
+
+/// `QuestObjectiveInformationsWithCompletion`, id 2966
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuestObjectiveInformationsWithCompletion {
+    #[serde(rename = "objectiveId")]
+    pub objective_id: u16,
+    #[serde(rename = "objectiveStatus")]
+    pub objective_status: bool,
+    #[serde(rename = "dialogParams")]
+    pub dialog_params: Vec<String>,
+    #[serde(rename = "curCompletion")]
+    pub cur_completion: u16,
+    #[serde(rename = "maxCompletion")]
+    pub max_completion: u16,
+}
+
+/// `QuestObjectiveValidatedMessage`, id 7688
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuestObjectiveValidatedMessage {
+    #[serde(rename = "questId")]
+    pub quest_id: u16,
+    #[serde(rename = "objectiveId")]
+    pub objective_id: u16,
+}
+
+/// `QuestObjectiveValidationMessage`, id 2512
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuestObjectiveValidationMessage {
+    #[serde(rename = "questId")]
+    pub quest_id: u16,
+    #[serde(rename = "objectiveId")]
+    pub objective_id: u16,
+}
+
+/// `QuestStartRequestMessage`, id 6045
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuestStartRequestMessage {
+    #[serde(rename = "questId")]
+    pub quest_id: u16,
+}
+
+/// `QuestStartedMessage`, id 8535
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuestStartedMessage {
+    #[serde(rename = "questId")]
+    pub quest_id: u16,
+}
+
+/// `QuestStepInfoMessage`, id 8324
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuestStepInfoMessage {
+    pub infos: Box<QuestActiveInformationsKind>,
+}
+
+/// `QuestStepInfoRequestMessage`, id 134
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuestStepInfoRequestMessage {
+    #[serde(rename = "questId")]
+    pub quest_id: u16,
+}
+
+/// `QuestStepStartedMessage`, id 4089
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuestStepStartedMessage {
+    #[serde(rename = "questId")]
+    pub quest_id: u16,
+    #[serde(rename = "stepId")]
+    pub step_id: u16,
+}
+
+/// `QuestStepValidatedMessage`, id 4500
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuestStepValidatedMessage {
+    #[serde(rename = "questId")]
+    pub quest_id: u16,
+    #[serde(rename = "stepId")]
+    pub step_id: u16,
+}
+
+/// `QuestValidatedMessage`, id 7335
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuestValidatedMessage {
+    #[serde(rename = "questId")]
+    pub quest_id: u16,
+}
+
+/// `QueueStatusMessage`, id 1695
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QueueStatusMessage {
+    pub position: i16,
+    pub total: i16,
+}
+
+/// `RankInformation`, id 4716
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RankInformation {
+    pub id: u32,
+    pub name: String,
+    pub order: u32,
+    #[serde(rename = "gfxId")]
+    pub gfx_id: u32,
+    pub modifiable: bool,
+    pub rights: Vec<u32>,
+}
+
+/// `RankMinimalInformation`, id 4763
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RankMinimalInformation {
+    pub id: u32,
+    pub name: String,
+}
+
+/// `RankPublicInformation`, id 1722
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RankPublicInformation {
+    pub id: u32,
+    pub name: String,
+    pub order: u32,
+    #[serde(rename = "gfxId")]
+    pub gfx_id: u32,
+}
+
+/// `RawDataMessage`, id 6253
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RawDataMessage {
+    pub content: Vec<i8>,
+}
+
+/// `RecruitmentInformationMessage`, id 8781
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RecruitmentInformationMessage {
+    #[serde(rename = "recruitmentData")]
+    pub recruitment_data: GuildRecruitmentInformation,
+}
+
+/// `RecycleResultMessage`, id 8972
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RecycleResultMessage {
+    #[serde(rename = "nuggetsForPrism")]
+    pub nuggets_for_prism: u32,
+    #[serde(rename = "nuggetsForPlayer")]
+    pub nuggets_for_player: u32,
+}
+
+/// `RecycledItem`, id 1966
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RecycledItem {
+    pub id: u32,
+    pub qty: u32,
+}
+
+/// `RefreshCharacterStatsMessage`, id 5675
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RefreshCharacterStatsMessage {
+    #[serde(rename = "fighterId", deserialize_with = "float")]
+    pub fighter_id: f64,
+    pub stats: GameFightCharacteristics,
+}
+
+/// `RefreshFollowedQuestsOrderRequestMessage`, id 8406
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RefreshFollowedQuestsOrderRequestMessage {
+    pub quests: Vec<u16>,
+}
+
+/// `ReleaseAccountMessage`, id 7665
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReleaseAccountMessage {}
+
+/// `ReloginTokenRequestMessage`, id 6738
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReloginTokenRequestMessage {}
+
+/// `ReloginTokenStatusMessage`, id 9206
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReloginTokenStatusMessage {
+    #[serde(rename = "validToken")]
+    pub valid_token: bool,
+    pub token: String,
+}
+
+/// `RemodelingInformation`, id 9177
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RemodelingInformation {
+    pub name: String,
+    pub breed: i8,
+    pub sex: bool,
+    #[serde(rename = "cosmeticId")]
+    pub cosmetic_id: u16,
+    pub colors: Vec<i32>,
+}
+
+/// `RemoveGuildRankRequestMessage`, id 1033
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RemoveGuildRankRequestMessage {
+    #[serde(rename = "rankId")]
+    pub rank_id: u32,
+    #[serde(rename = "newRankId")]
+    pub new_rank_id: u32,
+}
+
+/// `RemoveListenerOnSynchronizedStorageMessage`, id 2936
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RemoveListenerOnSynchronizedStorageMessage {
+    pub player: String,
+}
+
+/// `RemoveSpellModifierMessage`, id 139
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RemoveSpellModifierMessage {
+    #[serde(rename = "actorId", deserialize_with = "float")]
+    pub actor_id: f64,
+    #[serde(rename = "actionType")]
+    pub action_type: i8,
+    #[serde(rename = "modifierType")]
+    pub modifier_type: i8,
+    #[serde(rename = "spellId")]
+    pub spell_id: u16,
+}
+
+/// `RemoveTaxCollectorOrderedSpellMessage`, id 1063
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RemoveTaxCollectorOrderedSpellMessage {
+    #[serde(rename = "taxCollectorId", deserialize_with = "float")]
+    pub tax_collector_id: f64,
+    pub slot: i8,
+}
+
+/// `RemoveTaxCollectorPresetSpellMessage`, id 1083
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RemoveTaxCollectorPresetSpellMessage {
+    #[serde(rename = "presetId")]
+    pub preset_id: Uuid,
+    pub slot: i8,
+}
+
+/// `ReportRequestMessage`, id 6032
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReportRequestMessage {
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    pub categories: Vec<i8>,
+    pub description: String,
+}
+
+/// `ReportResponseMessage`, id 188
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReportResponseMessage {
+    pub success: bool,
+}
+
+/// `ResetCharacterStatsRequestMessage`, id 1013
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResetCharacterStatsRequestMessage {}
+
+/// `SelectedServerDataExtendedMessage`, id 357
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SelectedServerDataExtendedMessage {
+    #[serde(rename = "serverId")]
+    pub server_id: u16,
+    pub address: String,
+    pub ports: Vec<u16>,
+    #[serde(rename = "canCreateNewCharacter")]
+    pub can_create_new_character: bool,
+    pub ticket: Vec<i8>,
+    pub servers: Vec<GameServerInformations>,
+}
+
+/// `SelectedServerDataMessage`, id 9359
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SelectedServerDataMessage {
+    #[serde(rename = "serverId")]
+    pub server_id: u16,
+    pub address: String,
+    pub ports: Vec<u16>,
+    #[serde(rename = "canCreateNewCharacter")]
+    pub can_create_new_character: bool,
+    pub ticket: Vec<i8>,
+}
+
+/// `SelectedServerRefusedMessage`, id 2159
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SelectedServerRefusedMessage {
+    #[serde(rename = "serverId")]
+    pub server_id: u16,
+    pub error: i8,
+    #[serde(rename = "serverStatus")]
+    pub server_status: i8,
+}
+
+/// `SellerBuyerDescriptor`, id 1407
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SellerBuyerDescriptor {
+    pub quantities: Vec<u32>,
+    pub types: Vec<u32>,
+    #[serde(rename = "taxPercentage", deserialize_with = "float")]
+    pub tax_percentage: f32,
+    #[serde(rename = "taxModificationPercentage", deserialize_with = "float")]
+    pub tax_modification_percentage: f32,
+    #[serde(rename = "maxItemLevel")]
+    pub max_item_level: i8,
+    #[serde(rename = "maxItemPerAccount")]
+    pub max_item_per_account: u32,
+    #[serde(rename = "npcContextualId")]
+    pub npc_contextual_id: i32,
+    #[serde(rename = "unsoldDelay")]
+    pub unsold_delay: u16,
+}
+
+/// `SequenceEndMessage`, id 2945
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SequenceEndMessage {
+    #[serde(rename = "actionId")]
+    pub action_id: u16,
+    #[serde(rename = "authorId", deserialize_with = "float")]
+    pub author_id: f64,
+    #[serde(rename = "sequenceType")]
+    pub sequence_type: i8,
+}
+
+/// `SequenceNumberMessage`, id 9764
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SequenceNumberMessage {
+    pub number: i16,
+}
+
+/// `SequenceNumberRequestMessage`, id 3199
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SequenceNumberRequestMessage {}
+
+/// `SequenceStartMessage`, id 7442
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SequenceStartMessage {
+    #[serde(rename = "sequenceType")]
+    pub sequence_type: i8,
+    #[serde(rename = "authorId", deserialize_with = "float")]
+    pub author_id: f64,
+}
+
+/// `ServerExperienceModificatorMessage`, id 4503
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServerExperienceModificatorMessage {
+    #[serde(rename = "experiencePercent")]
+    pub experience_percent: u16,
+}
+
+/// `ServerOptionalFeaturesMessage`, id 6599
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServerOptionalFeaturesMessage {
+    pub features: Vec<i32>,
+}
+
+/// `ServerSelectionMessage`, id 2862
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServerSelectionMessage {
+    #[serde(rename = "serverId")]
+    pub server_id: u16,
+}
+
+/// `ServerSessionConstant`, id 9924
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServerSessionConstant {
+    pub id: u16,
+}
+
+/// `ServerSessionConstantInteger`, id 677
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServerSessionConstantInteger {
+    pub id: u16,
+    pub value: i32,
+}
+
+/// `ServerSessionConstantLong`, id 7900
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServerSessionConstantLong {
+    pub id: u16,
+    #[serde(deserialize_with = "float")]
+    pub value: f64,
+}
+
+/// `ServerSessionConstantString`, id 8828
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServerSessionConstantString {
+    pub id: u16,
+    pub value: String,
+}
+
+/// `ServerSessionConstantsMessage`, id 7848
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServerSessionConstantsMessage {
+    pub variables: Vec<ServerSessionConstantKind>,
+}
+
+/// `ServerSettingsMessage`, id 3136
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServerSettingsMessage {
+    #[serde(rename = "isMonoAccount")]
+    pub is_mono_account: bool,
+    #[serde(rename = "hasFreeAutopilot")]
+    pub has_free_autopilot: bool,
+    pub lang: String,
+    pub community: i8,
+    #[serde(rename = "gameType")]
+    pub game_type: i8,
+    #[serde(rename = "arenaLeaveBanTime")]
+    pub arena_leave_ban_time: u16,
+    #[serde(rename = "itemMaxLevel")]
+    pub item_max_level: i32,
+}
+
+/// `ServerStatusUpdateMessage`, id 2732
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServerStatusUpdateMessage {
+    pub server: GameServerInformations,
+}
+
+/// `ServersListMessage`, id 6921
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServersListMessage {
+    pub servers: Vec<GameServerInformations>,
+    #[serde(rename = "canCreateNewCharacter")]
+    pub can_create_new_character: bool,
+}
+
+/// `SetCharacterRestrictionsMessage`, id 7204
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SetCharacterRestrictionsMessage {
+    #[serde(rename = "actorId", deserialize_with = "float")]
+    pub actor_id: f64,
+    pub restrictions: ActorRestrictionsInformations,
+}
+
+/// `SetEnableAVARequestMessage`, id 1484
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SetEnableAVARequestMessage {
+    pub enable: bool,
+}
+
+/// `SetEnablePVPRequestMessage`, id 5149
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SetEnablePVPRequestMessage {
+    pub enable: bool,
+}
+
+/// `SetUpdateMessage`, id 7951
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SetUpdateMessage {
+    #[serde(rename = "setId")]
+    pub set_id: u16,
+    #[serde(rename = "setObjects")]
+    pub set_objects: Vec<u32>,
+    #[serde(rename = "setEffects")]
+    pub set_effects: Vec<ObjectEffectKind>,
+}
+
+/// `Shortcut`, id 938
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Shortcut {
+    pub slot: i8,
+}
+
+/// `ShortcutBarAddErrorMessage`, id 9174
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShortcutBarAddErrorMessage {
+    pub error: i8,
+}
+
+/// `ShortcutBarAddRequestMessage`, id 7966
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShortcutBarAddRequestMessage {
+    #[serde(rename = "barType")]
+    pub bar_type: i8,
+    pub shortcut: Box<ShortcutKind>,
+}
+
+/// `ShortcutBarContentMessage`, id 8634
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShortcutBarContentMessage {
+    #[serde(rename = "barType")]
+    pub bar_type: i8,
+    pub shortcuts: Vec<ShortcutKind>,
+}
+
+/// `ShortcutBarRefreshMessage`, id 1023
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShortcutBarRefreshMessage {
+    #[serde(rename = "barType")]
+    pub bar_type: i8,
+    pub shortcut: Box<ShortcutKind>,
+}
+
+/// `ShortcutBarRemoveErrorMessage`, id 6624
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShortcutBarRemoveErrorMessage {
+    pub error: i8,
+}
+
+/// `ShortcutBarRemoveRequestMessage`, id 4369
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShortcutBarRemoveRequestMessage {
+    #[serde(rename = "barType")]
+    pub bar_type: i8,
+    pub slot: i8,
+}
+
+/// `ShortcutBarRemovedMessage`, id 7794
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShortcutBarRemovedMessage {
+    #[serde(rename = "barType")]
+    pub bar_type: i8,
+    pub slot: i8,
+}
+
+/// `ShortcutBarReplacedMessage`, id 2242
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShortcutBarReplacedMessage {
+    #[serde(rename = "barType")]
+    pub bar_type: i8,
+    pub shortcut: Box<ShortcutKind>,
+}
+
+/// `ShortcutBarSwapErrorMessage`, id 2397
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShortcutBarSwapErrorMessage {
+    pub error: i8,
+}
+
+/// `ShortcutBarSwapRequestMessage`, id 9161
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShortcutBarSwapRequestMessage {
+    #[serde(rename = "barType")]
+    pub bar_type: i8,
+    #[serde(rename = "firstSlot")]
+    pub first_slot: i8,
+    #[serde(rename = "secondSlot")]
+    pub second_slot: i8,
+}
+
+/// `ShortcutEmote`, id 8764
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShortcutEmote {
+    pub slot: i8,
+    #[serde(rename = "emoteId")]
+    pub emote_id: i16,
+}
+
+/// `ShortcutEntitiesPreset`, id 4998
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShortcutEntitiesPreset {
+    pub slot: i8,
+    #[serde(rename = "presetId")]
+    pub preset_id: i16,
+}
+
+/// `ShortcutObject`, id 5821
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShortcutObject {
+    pub slot: i8,
+}
+
+/// `ShortcutObjectItem`, id 1397
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShortcutObjectItem {
+    pub slot: i8,
+    #[serde(rename = "itemUID")]
+    pub item_uid: i32,
+    #[serde(rename = "itemGID")]
+    pub item_gid: i32,
+}
+
+/// `ShortcutObjectPreset`, id 8087
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShortcutObjectPreset {
+    pub slot: i8,
+    #[serde(rename = "presetId")]
+    pub preset_id: i16,
+}
+
+/// `ShortcutSmiley`, id 3053
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShortcutSmiley {
+    pub slot: i8,
+    #[serde(rename = "smileyId")]
+    pub smiley_id: u16,
+}
+
+/// `ShortcutSpell`, id 1183
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShortcutSpell {
+    pub slot: i8,
+    #[serde(rename = "spellId")]
+    pub spell_id: u16,
+}
+
+/// `ShowCellMessage`, id 3414
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShowCellMessage {
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "cellId")]
+    pub cell_id: u16,
+}
+
+/// `ShowCellRequestMessage`, id 996
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShowCellRequestMessage {
+    #[serde(rename = "cellId")]
+    pub cell_id: u16,
+}
+
+/// `ShowCellSpectatorMessage`, id 2967
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShowCellSpectatorMessage {
+    #[serde(rename = "sourceId", deserialize_with = "float")]
+    pub source_id: f64,
+    #[serde(rename = "cellId")]
+    pub cell_id: u16,
+    #[serde(rename = "playerName")]
+    pub player_name: String,
+}
+
+/// `SimpleCharacterCharacteristicForPreset`, id 5762
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SimpleCharacterCharacteristicForPreset {
+    pub keyword: String,
+    pub base: u32,
+    pub additionnal: u32,
+}
+
+/// `SkillActionDescription`, id 2902
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SkillActionDescription {
+    #[serde(rename = "skillId")]
+    pub skill_id: u16,
+}
+
+/// `SkillActionDescriptionCollect`, id 4561
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SkillActionDescriptionCollect {
+    #[serde(rename = "skillId")]
+    pub skill_id: u16,
+    pub time: i8,
+    pub min: u16,
+    pub max: u16,
+}
+
+/// `SkillActionDescriptionCraft`, id 2567
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SkillActionDescriptionCraft {
+    #[serde(rename = "skillId")]
+    pub skill_id: u16,
+    pub probability: i8,
+}
+
+/// `SkillActionDescriptionTimed`, id 232
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SkillActionDescriptionTimed {
+    #[serde(rename = "skillId")]
+    pub skill_id: u16,
+    pub time: i8,
+}
+
+/// `SlaveNoLongerControledMessage`, id 6754
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SlaveNoLongerControledMessage {
+    #[serde(rename = "masterId", deserialize_with = "float")]
+    pub master_id: f64,
+    #[serde(rename = "slaveId", deserialize_with = "float")]
+    pub slave_id: f64,
+}
+
+/// `SlaveSwitchContextMessage`, id 4651
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SlaveSwitchContextMessage {
+    #[serde(rename = "masterId", deserialize_with = "float")]
+    pub master_id: f64,
+    #[serde(rename = "slaveId", deserialize_with = "float")]
+    pub slave_id: f64,
+    #[serde(rename = "slaveTurn")]
+    pub slave_turn: u16,
+    #[serde(rename = "slaveSpells")]
+    pub slave_spells: Vec<SpellItem>,
+    #[serde(rename = "slaveStats")]
+    pub slave_stats: CharacterCharacteristicsInformations,
+    pub shortcuts: Vec<ShortcutKind>,
+}
+
+/// `SocialApplicationInformation`, id 212
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SocialApplicationInformation {
+    #[serde(rename = "playerInfo")]
+    pub player_info: ApplicationPlayerInformation,
+    #[serde(rename = "applyText")]
+    pub apply_text: String,
+    #[serde(rename = "creationDate", deserialize_with = "float")]
+    pub creation_date: f64,
+}
+
+/// `SocialEmblem`, id 2676
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SocialEmblem {
+    #[serde(rename = "symbolShape")]
+    pub symbol_shape: u16,
+    #[serde(rename = "symbolColor")]
+    pub symbol_color: i32,
+    #[serde(rename = "backgroundShape")]
+    pub background_shape: i8,
+    #[serde(rename = "backgroundColor")]
+    pub background_color: i32,
+}
+
+/// `SocialFight`, id 596
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SocialFight {
+    #[serde(rename = "socialFightInfo")]
+    pub social_fight_info: SocialFightInfo,
+    pub attackers: Vec<CharacterMinimalPlusLookInformations>,
+    pub defenders: Vec<CharacterMinimalPlusLookInformations>,
+    pub phase: FightPhase,
+}
+
+/// `SocialFightInfo`, id 1612
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SocialFightInfo {
+    #[serde(rename = "fightId")]
+    pub fight_id: u16,
+    #[serde(rename = "fightType")]
+    pub fight_type: i8,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+}
+
+/// `SocialFightJoinRequestMessage`, id 7077
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SocialFightJoinRequestMessage {
+    #[serde(rename = "socialFightInfo")]
+    pub social_fight_info: SocialFightInfo,
+}
+
+/// `SocialFightLeaveRequestMessage`, id 508
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SocialFightLeaveRequestMessage {
+    #[serde(rename = "socialFightInfo")]
+    pub social_fight_info: SocialFightInfo,
+}
+
+/// `SocialFightTakePlaceRequestMessage`, id 2458
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SocialFightTakePlaceRequestMessage {
+    #[serde(rename = "socialFightInfo")]
+    pub social_fight_info: SocialFightInfo,
+    #[serde(rename = "replacedCharacterId")]
+    pub replaced_character_id: u64,
+}
+
+/// `SocialMember`, id 2956
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SocialMember {
+    pub id: u64,
+    pub name: String,
+    pub level: u16,
+    pub breed: i8,
+    pub sex: bool,
+    pub connected: i8,
+    #[serde(rename = "hoursSinceLastConnection")]
+    pub hours_since_last_connection: i16,
+    #[serde(rename = "accountId")]
+    pub account_id: i32,
+    pub status: Box<PlayerStatusKind>,
+    #[serde(rename = "rankId")]
+    pub rank_id: i32,
+    #[serde(rename = "enrollmentDate", deserialize_with = "float")]
+    pub enrollment_date: f64,
+}
+
+/// `SocialNoticeMessage`, id 3051
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SocialNoticeMessage {
+    pub content: String,
+    pub timestamp: i32,
+    #[serde(rename = "memberId")]
+    pub member_id: u64,
+    #[serde(rename = "memberName")]
+    pub member_name: String,
+}
+
+/// `SocialNoticeSetErrorMessage`, id 3283
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SocialNoticeSetErrorMessage {
+    pub reason: i8,
+}
+
+/// `SocialNoticeSetRequestMessage`, id 9228
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SocialNoticeSetRequestMessage {}
+
+/// `SocialRecruitmentInformation`, id 209
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SocialRecruitmentInformation {
+    #[serde(rename = "minLevelFacultative")]
+    pub min_level_facultative: bool,
+    #[serde(rename = "invalidatedByModeration")]
+    pub invalidated_by_moderation: bool,
+    #[serde(rename = "recruitmentAutoLocked")]
+    pub recruitment_auto_locked: bool,
+    #[serde(rename = "socialId")]
+    pub social_id: u32,
+    #[serde(rename = "recruitmentType")]
+    pub recruitment_type: i8,
+    #[serde(rename = "recruitmentTitle")]
+    pub recruitment_title: String,
+    #[serde(rename = "recruitmentText")]
+    pub recruitment_text: String,
+    #[serde(rename = "selectedLanguages")]
+    pub selected_languages: Vec<u32>,
+    #[serde(rename = "selectedCriterion")]
+    pub selected_criterion: Vec<u32>,
+    #[serde(rename = "minLevel")]
+    pub min_level: i16,
+    #[serde(rename = "lastEditPlayerName")]
+    pub last_edit_player_name: String,
+    #[serde(rename = "lastEditDate", deserialize_with = "float")]
+    pub last_edit_date: f64,
+}
+
+/// `SpawnCharacterInformation`, id 8865
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpawnCharacterInformation {
+    pub name: String,
+    pub level: u16,
+}
+
+/// `SpawnCompanionInformation`, id 5676
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpawnCompanionInformation {
+    #[serde(rename = "modelId")]
+    pub model_id: i8,
+    pub level: u16,
+    #[serde(rename = "summonerId", deserialize_with = "float")]
+    pub summoner_id: f64,
+    #[serde(rename = "ownerId", deserialize_with = "float")]
+    pub owner_id: f64,
+}
+
+/// `SpawnInformation`, id 1233
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SpawnInformation {}
+
+/// `SpawnMonsterInformation`, id 5840
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpawnMonsterInformation {
+    #[serde(rename = "creatureGenericId")]
+    pub creature_generic_id: u16,
+    #[serde(rename = "creatureGrade")]
+    pub creature_grade: i8,
+}
+
+/// `SpawnScaledMonsterInformation`, id 5954
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpawnScaledMonsterInformation {
+    #[serde(rename = "creatureGenericId")]
+    pub creature_generic_id: u16,
+    #[serde(rename = "creatureLevel")]
+    pub creature_level: i16,
+}
+
+/// `SpellForPreset`, id 7902
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpellForPreset {
+    #[serde(rename = "spellId")]
+    pub spell_id: u16,
+    pub shortcuts: Vec<i16>,
+}
+
+/// `SpellItem`, id 9854
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpellItem {
+    #[serde(rename = "spellId")]
+    pub spell_id: i32,
+    #[serde(rename = "spellLevel")]
+    pub spell_level: i16,
+}
+
+/// `SpellListMessage`, id 7127
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpellListMessage {
+    #[serde(rename = "spellPrevisualization")]
+    pub spell_previsualization: bool,
+    pub spells: Vec<SpellItem>,
+}
+
+/// `SpellModifierMessage`, id 2352
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpellModifierMessage {
+    #[serde(rename = "spellId")]
+    pub spell_id: u16,
+    #[serde(rename = "actionType")]
+    pub action_type: i8,
+    #[serde(rename = "modifierType")]
+    pub modifier_type: i8,
+    pub context: i32,
+    pub equipment: i32,
+}
+
+/// `SpellVariantActivationMessage`, id 3972
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpellVariantActivationMessage {
+    #[serde(rename = "spellId")]
+    pub spell_id: u16,
+    pub result: bool,
+}
+
+/// `SpellVariantActivationRequestMessage`, id 5428
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpellVariantActivationRequestMessage {
+    #[serde(rename = "spellId")]
+    pub spell_id: u16,
+}
+
+/// `SpellsPreset`, id 2601
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpellsPreset {
+    pub id: i16,
+    pub spells: Vec<SpellForPreset>,
+}
+
+/// `SpouseGetInformationsMessage`, id 954
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SpouseGetInformationsMessage {}
+
+/// `SpouseInformationsMessage`, id 8483
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpouseInformationsMessage {
+    pub spouse: Box<FriendSpouseInformationsKind>,
+}
+
+/// `SpouseStatusMessage`, id 8520
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpouseStatusMessage {
+    #[serde(rename = "hasSpouse")]
+    pub has_spouse: bool,
+}
+
+/// `StartExchangeTaxCollectorEquipmentMessage`, id 4302
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StartExchangeTaxCollectorEquipmentMessage {
+    #[serde(deserialize_with = "float")]
+    pub uid: f64,
+}
+
+/// `StartGuildChestContributionMessage`, id 4696
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StartGuildChestContributionMessage {}
+
+/// `StartListenAllianceFightMessage`, id 9408
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StartListenAllianceFightMessage {}
+
+/// `StartListenGuildChestStructureMessage`, id 5614
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StartListenGuildChestStructureMessage {}
+
+/// `StartListenNuggetsMessage`, id 2045
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StartListenNuggetsMessage {}
+
+/// `StartListenTaxCollectorPresetsUpdatesMessage`, id 2027
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StartListenTaxCollectorPresetsUpdatesMessage {}
+
+/// `StartListenTaxCollectorUpdatesMessage`, id 4786
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StartListenTaxCollectorUpdatesMessage {
+    #[serde(rename = "taxCollectorId", deserialize_with = "float")]
+    pub tax_collector_id: f64,
+}
+
+/// `StatedElement`, id 2912
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatedElement {
+    #[serde(rename = "elementId")]
+    pub element_id: i32,
+    #[serde(rename = "elementCellId")]
+    pub element_cell_id: u16,
+    #[serde(rename = "elementState")]
+    pub element_state: u32,
+    #[serde(rename = "onCurrentMap")]
+    pub on_current_map: bool,
+}
+
+/// `StatedElementUpdatedMessage`, id 5637
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatedElementUpdatedMessage {
+    #[serde(rename = "statedElement")]
+    pub stated_element: StatedElement,
+}
+
+/// `StatedMapUpdateMessage`, id 5303
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatedMapUpdateMessage {
+    #[serde(rename = "statedElements")]
+    pub stated_elements: Vec<StatedElement>,
+}
+
+/// `StatisticData`, id 4988
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StatisticData {}
+
+/// `StatisticDataBoolean`, id 7132
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatisticDataBoolean {
+    pub value: bool,
+}
+
+/// `StatisticDataByte`, id 4903
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatisticDataByte {
+    pub value: i8,
+}
+
+/// `StatisticDataInt`, id 5896
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatisticDataInt {
+    pub value: i32,
+}
+
+/// `StatisticDataShort`, id 3675
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatisticDataShort {
+    pub value: i16,
+}
+
+/// `StatisticDataString`, id 5056
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatisticDataString {
+    pub value: String,
+}
+
+/// `StatsPreset`, id 998
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatsPreset {
+    pub id: i16,
+    pub stats: Vec<SimpleCharacterCharacteristicForPreset>,
+}
+
+/// `StatsUpgradeRequestMessage`, id 7474
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatsUpgradeRequestMessage {
+    #[serde(rename = "useAdditionnal")]
+    pub use_additionnal: bool,
+    #[serde(rename = "statId")]
+    pub stat_id: i8,
+    #[serde(rename = "boostPoint")]
+    pub boost_point: u16,
+}
+
+/// `StatsUpgradeResultMessage`, id 8116
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatsUpgradeResultMessage {
+    pub result: i8,
+    #[serde(rename = "nbCharacBoost")]
+    pub nb_charac_boost: u16,
+}
+
+/// `StopGuildChestContributionMessage`, id 4233
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StopGuildChestContributionMessage {}
+
+/// `StopListenAllianceFightMessage`, id 7947
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StopListenAllianceFightMessage {}
+
+/// `StopListenGuildChestStructureMessage`, id 6118
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StopListenGuildChestStructureMessage {}
+
+/// `StopListenNuggetsMessage`, id 1678
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StopListenNuggetsMessage {}
+
+/// `StopListenTaxCollectorPresetsUpdatesMessage`, id 7013
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StopListenTaxCollectorPresetsUpdatesMessage {}
+
+/// `StopListenTaxCollectorUpdatesMessage`, id 3728
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StopListenTaxCollectorUpdatesMessage {
+    #[serde(rename = "taxCollectorId", deserialize_with = "float")]
+    pub tax_collector_id: f64,
+}
+
+/// `StopToListenRunningFightRequestMessage`, id 3087
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StopToListenRunningFightRequestMessage {}
+
+/// `StorageInventoryContentMessage`, id 1606
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StorageInventoryContentMessage {
+    pub objects: Vec<ObjectItem>,
+    pub kamas: u64,
+}
+
+/// `StorageKamasUpdateMessage`, id 7853
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StorageKamasUpdateMessage {
+    #[serde(rename = "kamasTotal")]
+    pub kamas_total: u64,
+}
+
+/// `StorageObjectRemoveMessage`, id 4270
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StorageObjectRemoveMessage {
+    #[serde(rename = "objectUID")]
+    pub object_uid: u32,
+}
+
+/// `StorageObjectUpdateMessage`, id 6136
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StorageObjectUpdateMessage {
+    pub object: ObjectItem,
+}
+
+/// `StorageObjectsRemoveMessage`, id 7352
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StorageObjectsRemoveMessage {
+    #[serde(rename = "objectUIDList")]
+    pub object_uid_list: Vec<u32>,
+}
+
+/// `StorageObjectsUpdateMessage`, id 6093
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StorageObjectsUpdateMessage {
+    #[serde(rename = "objectList")]
+    pub object_list: Vec<ObjectItem>,
+}
+
+/// `StorageTabInformation`, id 3410
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StorageTabInformation {
+    pub name: String,
+    #[serde(rename = "tabNumber")]
+    pub tab_number: u32,
+    pub picto: u32,
+    #[serde(rename = "openRight")]
+    pub open_right: u32,
+    #[serde(rename = "dropRight")]
+    pub drop_right: u32,
+    #[serde(rename = "takeRight")]
+    pub take_right: u32,
+    #[serde(rename = "dropTypeLimitation")]
+    pub drop_type_limitation: Vec<u32>,
+}
+
+/// `SubEntity`, id 9711
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SubEntity {
+    #[serde(rename = "bindingPointCategory")]
+    pub binding_point_category: i8,
+    #[serde(rename = "bindingPointIndex")]
+    pub binding_point_index: i8,
+    #[serde(rename = "subEntityLook")]
+    pub sub_entity_look: EntityLook,
+}
+
+/// `SubareaRewardRateMessage`, id 1743
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SubareaRewardRateMessage {
+    #[serde(rename = "subAreaRate")]
+    pub sub_area_rate: u16,
+}
+
+/// `SubscriptionLimitationMessage`, id 8855
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SubscriptionLimitationMessage {
+    pub reason: i8,
+}
+
+/// `SubscriptionZoneMessage`, id 3201
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SubscriptionZoneMessage {
+    pub active: bool,
+}
+
+/// `SurrenderAccepted`, id 5383
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SurrenderAccepted {}
+
+/// `SurrenderInfoRequestMessage`, id 4905
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SurrenderInfoRequestMessage {}
+
+/// `SurrenderInfoResponseMessage`, id 1727
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SurrenderInfoResponseMessage {
+    #[serde(rename = "hasSanction")]
+    pub has_sanction: bool,
+    #[serde(rename = "surrenderResponse")]
+    pub surrender_response: Box<SurrenderResponseKind>,
+    #[serde(rename = "surrenderVoteResponse")]
+    pub surrender_vote_response: Box<SurrenderVoteResponseKind>,
+}
+
+/// `SurrenderRefused`, id 6967
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SurrenderRefused {}
+
+/// `SurrenderRefusedBeforeTurn`, id 9503
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SurrenderRefusedBeforeTurn {
+    #[serde(rename = "minTurnForSurrender")]
+    pub min_turn_for_surrender: i32,
+}
+
+/// `SurrenderRefusedBeforeVote`, id 1799
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SurrenderRefusedBeforeVote {}
+
+/// `SurrenderResponse`, id 130
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SurrenderResponse {}
+
+/// `SurrenderStateMessage`, id 6810
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SurrenderStateMessage {
+    #[serde(rename = "canSurrender")]
+    pub can_surrender: bool,
+    #[serde(rename = "permitVote")]
+    pub permit_vote: bool,
+}
+
+/// `SurrenderVoteAccepted`, id 6159
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SurrenderVoteAccepted {}
+
+/// `SurrenderVoteCastMessage`, id 3387
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SurrenderVoteCastMessage {
+    pub vote: bool,
+}
+
+/// `SurrenderVoteEndMessage`, id 8875
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SurrenderVoteEndMessage {
+    #[serde(rename = "voteResult")]
+    pub vote_result: bool,
+}
+
+/// `SurrenderVoteRefused`, id 2267
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SurrenderVoteRefused {}
+
+/// `SurrenderVoteRefusedAlreadyAsked`, id 5204
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SurrenderVoteRefusedAlreadyAsked {}
+
+/// `SurrenderVoteRefusedBeforeTurn`, id 9203
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SurrenderVoteRefusedBeforeTurn {
+    #[serde(rename = "minTurnForSurrenderVote")]
+    pub min_turn_for_surrender_vote: i32,
+}
+
+/// `SurrenderVoteRefusedWaitBetweenVotes`, id 6764
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SurrenderVoteRefusedWaitBetweenVotes {
+    #[serde(rename = "nextVoteTimestamp")]
+    pub next_vote_timestamp: i32,
+}
+
+/// `SurrenderVoteResponse`, id 7519
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SurrenderVoteResponse {}
+
+/// `SurrenderVoteStartMessage`, id 8647
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SurrenderVoteStartMessage {
+    #[serde(rename = "alreadyCastedVote")]
+    pub already_casted_vote: bool,
+    #[serde(rename = "numberOfParticipants")]
+    pub number_of_participants: i32,
+    #[serde(rename = "castedVoteNumber")]
+    pub casted_vote_number: i32,
+    #[serde(rename = "voteDuration")]
+    pub vote_duration: i32,
+}
+
+/// `SurrenderVoteUpdateMessage`, id 1395
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SurrenderVoteUpdateMessage {}
+
+/// `SwitchArenaXpRewardsModeMessage`, id 8050
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SwitchArenaXpRewardsModeMessage {
+    #[serde(rename = "xpRewards")]
+    pub xp_rewards: bool,
+}
+
+/// `SymbioticObjectAssociateRequestMessage`, id 5354
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SymbioticObjectAssociateRequestMessage {
+    #[serde(rename = "symbioteUID")]
+    pub symbiote_uid: u32,
+    #[serde(rename = "symbiotePos")]
+    pub symbiote_pos: i8,
+    #[serde(rename = "hostUID")]
+    pub host_uid: u32,
+    #[serde(rename = "hostPos")]
+    pub host_pos: i8,
+}
+
+/// `SymbioticObjectAssociatedMessage`, id 2999
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SymbioticObjectAssociatedMessage {
+    #[serde(rename = "hostUID")]
+    pub host_uid: u32,
+}
+
+/// `SymbioticObjectErrorMessage`, id 7448
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SymbioticObjectErrorMessage {
+    pub reason: i8,
+    #[serde(rename = "errorCode")]
+    pub error_code: i8,
+}
+
+/// `SystemMessageDisplayMessage`, id 2735
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SystemMessageDisplayMessage {
+    #[serde(rename = "hangUp")]
+    pub hang_up: bool,
+    #[serde(rename = "msgId")]
+    pub msg_id: u16,
+    pub parameters: Vec<String>,
+}
+
+/// `TaxCollectorAddedMessage`, id 4517
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaxCollectorAddedMessage {
+    #[serde(rename = "callerId")]
+    pub caller_id: u64,
+    pub description: Box<TaxCollectorInformationsKind>,
+}
+
+/// `TaxCollectorAttackedMessage`, id 6169
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaxCollectorAttackedMessage {
+    #[serde(rename = "firstNameId")]
+    pub first_name_id: u16,
+    #[serde(rename = "lastNameId")]
+    pub last_name_id: u16,
+    #[serde(rename = "worldX")]
+    pub world_x: i16,
+    #[serde(rename = "worldY")]
+    pub world_y: i16,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+    pub alliance: BasicAllianceInformations,
+}
+
+/// `TaxCollectorAttackedResultMessage`, id 9154
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaxCollectorAttackedResultMessage {
+    #[serde(rename = "deadOrAlive")]
+    pub dead_or_alive: bool,
+    #[serde(rename = "basicInfos")]
+    pub basic_infos: TaxCollectorBasicInformations,
+    pub alliance: BasicAllianceInformations,
+}
+
+/// `TaxCollectorBasicInformations`, id 9542
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaxCollectorBasicInformations {
+    #[serde(rename = "firstNameId")]
+    pub first_name_id: u16,
+    #[serde(rename = "lastNameId")]
+    pub last_name_id: u16,
+    #[serde(rename = "worldX")]
+    pub world_x: i16,
+    #[serde(rename = "worldY")]
+    pub world_y: i16,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+}
+
+/// `TaxCollectorComplementaryInformations`, id 417
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TaxCollectorComplementaryInformations {}
+
+/// `TaxCollectorDialogQuestionBasicMessage`, id 6538
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaxCollectorDialogQuestionBasicMessage {
+    #[serde(rename = "allianceInfo")]
+    pub alliance_info: BasicAllianceInformations,
+}
+
+/// `TaxCollectorDialogQuestionExtendedMessage`, id 9077
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaxCollectorDialogQuestionExtendedMessage {
+    #[serde(rename = "allianceInfo")]
+    pub alliance_info: BasicAllianceInformations,
+    #[serde(rename = "maxPods")]
+    pub max_pods: u16,
+    pub prospecting: u16,
+    pub alliance: BasicNamedAllianceInformations,
+    #[serde(rename = "taxCollectorsCount")]
+    pub tax_collectors_count: i8,
+    #[serde(rename = "taxCollectorAttack")]
+    pub tax_collector_attack: i32,
+    pub pods: u32,
+    #[serde(rename = "itemsValue")]
+    pub items_value: u64,
+}
+
+/// `TaxCollectorEquipmentUpdateMessage`, id 6675
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaxCollectorEquipmentUpdateMessage {
+    #[serde(rename = "uniqueId", deserialize_with = "float")]
+    pub unique_id: f64,
+    pub object: ObjectItem,
+    pub added: bool,
+    pub characteristics: CharacterCharacteristics,
+}
+
+/// `TaxCollectorErrorMessage`, id 5221
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaxCollectorErrorMessage {
+    pub reason: i8,
+}
+
+/// `TaxCollectorHarvestedMessage`, id 5009
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaxCollectorHarvestedMessage {
+    #[serde(rename = "taxCollectorId", deserialize_with = "float")]
+    pub tax_collector_id: f64,
+    #[serde(rename = "harvesterId")]
+    pub harvester_id: u64,
+    #[serde(rename = "harvesterName")]
+    pub harvester_name: String,
+}
+
+/// `TaxCollectorInformations`, id 3347
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaxCollectorInformations {
+    #[serde(rename = "uniqueId", deserialize_with = "float")]
+    pub unique_id: f64,
+    #[serde(rename = "firstNameId")]
+    pub first_name_id: u16,
+    #[serde(rename = "lastNameId")]
+    pub last_name_id: u16,
+    #[serde(rename = "allianceIdentity")]
+    pub alliance_identity: AllianceInformation,
+    #[serde(rename = "additionalInfos")]
+    pub additional_infos: AdditionalTaxCollectorInformation,
+    #[serde(rename = "worldX")]
+    pub world_x: i16,
+    #[serde(rename = "worldY")]
+    pub world_y: i16,
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+    pub state: i8,
+    pub look: EntityLook,
+    pub complements: Vec<TaxCollectorComplementaryInformationsKind>,
+    pub characteristics: CharacterCharacteristics,
+    pub equipments: Vec<ObjectItem>,
+    pub spells: Vec<TaxCollectorOrderedSpell>,
+}
+
+/// `TaxCollectorLootInformations`, id 7791
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaxCollectorLootInformations {
+    pub pods: u32,
+    #[serde(rename = "itemsValue")]
+    pub items_value: u64,
+}
+
+/// `TaxCollectorMovement`, id 2442
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaxCollectorMovement {
+    #[serde(rename = "movementType")]
+    pub movement_type: i8,
+    #[serde(rename = "basicInfos")]
+    pub basic_infos: TaxCollectorBasicInformations,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+    #[serde(rename = "playerName")]
+    pub player_name: String,
+}
+
+/// `TaxCollectorMovementsOfflineMessage`, id 8947
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaxCollectorMovementsOfflineMessage {
+    pub movements: Vec<TaxCollectorMovement>,
+}
+
+/// `TaxCollectorOrderedSpell`, id 4122
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaxCollectorOrderedSpell {
+    #[serde(rename = "spellId")]
+    pub spell_id: u32,
+    pub slot: i8,
+}
+
+/// `TaxCollectorOrderedSpellUpdatedMessage`, id 490
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaxCollectorOrderedSpellUpdatedMessage {
+    #[serde(rename = "taxCollectorId", deserialize_with = "float")]
+    pub tax_collector_id: f64,
+    #[serde(rename = "taxCollectorSpells")]
+    pub tax_collector_spells: Vec<TaxCollectorOrderedSpell>,
+}
+
+/// `TaxCollectorPreset`, id 1589
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaxCollectorPreset {
+    #[serde(rename = "presetId")]
+    pub preset_id: Uuid,
+    pub spells: Vec<TaxCollectorOrderedSpell>,
+    pub characteristics: CharacterCharacteristics,
+}
+
+/// `TaxCollectorPresetSpellUpdatedMessage`, id 4921
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaxCollectorPresetSpellUpdatedMessage {
+    #[serde(rename = "presetId")]
+    pub preset_id: Uuid,
+    #[serde(rename = "taxCollectorSpells")]
+    pub tax_collector_spells: Vec<TaxCollectorOrderedSpell>,
+}
+
+/// `TaxCollectorPresetsMessage`, id 3825
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaxCollectorPresetsMessage {
+    pub presets: Vec<TaxCollectorPreset>,
+}
+
+/// `TaxCollectorRemovedMessage`, id 1782
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaxCollectorRemovedMessage {
+    #[serde(rename = "collectorId", deserialize_with = "float")]
+    pub collector_id: f64,
+}
+
+/// `TaxCollectorStateUpdateMessage`, id 6454
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaxCollectorStateUpdateMessage {
+    #[serde(rename = "uniqueId", deserialize_with = "float")]
+    pub unique_id: f64,
+    pub state: i8,
+}
+
+/// `TaxCollectorStaticInformations`, id 2371
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaxCollectorStaticInformations {
+    #[serde(rename = "firstNameId")]
+    pub first_name_id: u16,
+    #[serde(rename = "lastNameId")]
+    pub last_name_id: u16,
+    #[serde(rename = "allianceIdentity")]
+    pub alliance_identity: AllianceInformation,
+    #[serde(rename = "callerId")]
+    pub caller_id: u64,
+}
+
+/// `TaxCollectorWaitingForHelpInformations`, id 8414
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaxCollectorWaitingForHelpInformations {
+    #[serde(rename = "waitingForHelpInfo")]
+    pub waiting_for_help_info: ProtectedEntityWaitingForHelpInfo,
+}
+
+/// `TeleportBuddiesAnswerMessage`, id 1318
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TeleportBuddiesAnswerMessage {
+    pub accept: bool,
+}
+
+/// `TeleportBuddiesMessage`, id 9329
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TeleportBuddiesMessage {
+    #[serde(rename = "dungeonId")]
+    pub dungeon_id: u16,
+}
+
+/// `TeleportBuddiesRequestedMessage`, id 3508
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TeleportBuddiesRequestedMessage {
+    #[serde(rename = "dungeonId")]
+    pub dungeon_id: u16,
+    #[serde(rename = "inviterId")]
+    pub inviter_id: u64,
+    #[serde(rename = "invalidBuddiesIds")]
+    pub invalid_buddies_ids: Vec<u64>,
+}
+
+/// `TeleportDestination`, id 4498
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TeleportDestination {
+    pub r#type: i8,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    #[serde(rename = "subAreaId")]
+    pub sub_area_id: u16,
+    pub level: u16,
+    pub cost: u16,
+}
+
+/// `TeleportDestinationsMessage`, id 1365
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TeleportDestinationsMessage {
+    pub r#type: i8,
+    pub destinations: Vec<TeleportDestination>,
+}
+
+/// `TeleportHavenBagAnswerMessage`, id 4601
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TeleportHavenBagAnswerMessage {
+    pub accept: bool,
+}
+
+/// `TeleportHavenBagRequestMessage`, id 5171
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TeleportHavenBagRequestMessage {
+    #[serde(rename = "guestId")]
+    pub guest_id: u64,
+}
+
+/// `TeleportOnSameMapMessage`, id 7758
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TeleportOnSameMapMessage {
+    #[serde(rename = "targetId", deserialize_with = "float")]
+    pub target_id: f64,
+    #[serde(rename = "cellId")]
+    pub cell_id: u16,
+}
+
+/// `TeleportPlayerAnswerMessage`, id 9210
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TeleportPlayerAnswerMessage {
+    pub accept: bool,
+    #[serde(rename = "requesterId")]
+    pub requester_id: u64,
+}
+
+/// `TeleportPlayerCloseMessage`, id 6423
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TeleportPlayerCloseMessage {
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    #[serde(rename = "requesterId")]
+    pub requester_id: u64,
+}
+
+/// `TeleportPlayerOfferMessage`, id 511
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TeleportPlayerOfferMessage {
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    pub message: String,
+    #[serde(rename = "timeLeft")]
+    pub time_left: u32,
+    #[serde(rename = "requesterId")]
+    pub requester_id: u64,
+}
+
+/// `TeleportRequestMessage`, id 7069
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TeleportRequestMessage {
+    #[serde(rename = "sourceType")]
+    pub source_type: i8,
+    #[serde(rename = "destinationType")]
+    pub destination_type: i8,
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+}
+
+/// `TeleportToBuddyAnswerMessage`, id 2083
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TeleportToBuddyAnswerMessage {
+    #[serde(rename = "dungeonId")]
+    pub dungeon_id: u16,
+    #[serde(rename = "buddyId")]
+    pub buddy_id: u64,
+    pub accept: bool,
+}
+
+/// `TeleportToBuddyCloseMessage`, id 1575
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TeleportToBuddyCloseMessage {
+    #[serde(rename = "dungeonId")]
+    pub dungeon_id: u16,
+    #[serde(rename = "buddyId")]
+    pub buddy_id: u64,
+}
+
+/// `TeleportToBuddyOfferMessage`, id 1543
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TeleportToBuddyOfferMessage {
+    #[serde(rename = "dungeonId")]
+    pub dungeon_id: u16,
+    #[serde(rename = "buddyId")]
+    pub buddy_id: u64,
+    #[serde(rename = "timeLeft")]
+    pub time_left: u32,
+}
+
+/// `TextInformationMessage`, id 3946
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TextInformationMessage {
+    #[serde(rename = "msgType")]
+    pub msg_type: i8,
+    #[serde(rename = "msgId")]
+    pub msg_id: u16,
+    pub parameters: Vec<String>,
+}
+
+/// `TitleGainedMessage`, id 8086
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TitleGainedMessage {
+    #[serde(rename = "titleId")]
+    pub title_id: u16,
+}
+
+/// `TitleLostMessage`, id 3243
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TitleLostMessage {
+    #[serde(rename = "titleId")]
+    pub title_id: u16,
+}
+
+/// `TitleSelectErrorMessage`, id 4801
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TitleSelectErrorMessage {
+    pub reason: i8,
+}
+
+/// `TitleSelectRequestMessage`, id 7093
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TitleSelectRequestMessage {
+    #[serde(rename = "titleId")]
+    pub title_id: u16,
+}
+
+/// `TitleSelectedMessage`, id 4882
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TitleSelectedMessage {
+    #[serde(rename = "titleId")]
+    pub title_id: u16,
+}
+
+/// `TitlesAndOrnamentsListMessage`, id 740
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TitlesAndOrnamentsListMessage {
+    pub titles: Vec<u16>,
+    pub ornaments: Vec<u16>,
+    #[serde(rename = "activeTitle")]
+    pub active_title: u16,
+    #[serde(rename = "activeOrnament")]
+    pub active_ornament: u16,
+}
+
+/// `TitlesAndOrnamentsListRequestMessage`, id 3074
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TitlesAndOrnamentsListRequestMessage {}
+
+/// `TopTaxCollectorListMessage`, id 6626
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TopTaxCollectorListMessage {
+    #[serde(rename = "dungeonTaxCollectorsInformation")]
+    pub dungeon_tax_collectors_information: Vec<TaxCollectorInformationsKind>,
+    #[serde(rename = "worldTaxCollectorsInformation")]
+    pub world_tax_collectors_information: Vec<TaxCollectorInformationsKind>,
+}
+
+/// `TreasureHuntAvailableRetryCountUpdateMessage`, id 7732
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TreasureHuntAvailableRetryCountUpdateMessage {
+    #[serde(rename = "questType")]
+    pub quest_type: i8,
+    #[serde(rename = "availableRetryCount")]
+    pub available_retry_count: i32,
+}
+
+/// `TreasureHuntDigRequestAnswerFailedMessage`, id 1287
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TreasureHuntDigRequestAnswerFailedMessage {
+    #[serde(rename = "questType")]
+    pub quest_type: i8,
+    pub result: i8,
+    #[serde(rename = "wrongFlagCount")]
+    pub wrong_flag_count: i8,
+}
+
+/// `TreasureHuntDigRequestAnswerMessage`, id 4667
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TreasureHuntDigRequestAnswerMessage {
+    #[serde(rename = "questType")]
+    pub quest_type: i8,
+    pub result: i8,
+}
+
+/// `TreasureHuntDigRequestMessage`, id 7583
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TreasureHuntDigRequestMessage {
+    #[serde(rename = "questType")]
+    pub quest_type: i8,
+}
+
+/// `TreasureHuntFinishedMessage`, id 9011
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TreasureHuntFinishedMessage {
+    #[serde(rename = "questType")]
+    pub quest_type: i8,
+}
+
+/// `TreasureHuntFlag`, id 9807
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TreasureHuntFlag {
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+    pub state: i8,
+}
+
+/// `TreasureHuntFlagRemoveRequestMessage`, id 907
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TreasureHuntFlagRemoveRequestMessage {
+    #[serde(rename = "questType")]
+    pub quest_type: i8,
+    pub index: i8,
+}
+
+/// `TreasureHuntFlagRequestAnswerMessage`, id 7681
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TreasureHuntFlagRequestAnswerMessage {
+    #[serde(rename = "questType")]
+    pub quest_type: i8,
+    pub result: i8,
+    pub index: i8,
+}
+
+/// `TreasureHuntFlagRequestMessage`, id 7975
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TreasureHuntFlagRequestMessage {
+    #[serde(rename = "questType")]
+    pub quest_type: i8,
+    pub index: i8,
+}
+
+/// `TreasureHuntGiveUpRequestMessage`, id 622
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TreasureHuntGiveUpRequestMessage {
+    #[serde(rename = "questType")]
+    pub quest_type: i8,
+}
+
+/// `TreasureHuntLegendaryRequestMessage`, id 1750
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TreasureHuntLegendaryRequestMessage {
+    #[serde(rename = "legendaryId")]
+    pub legendary_id: u16,
+}
+
+/// `TreasureHuntMessage`, id 3389
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TreasureHuntMessage {
+    #[serde(rename = "questType")]
+    pub quest_type: i8,
+    #[serde(rename = "startMapId", deserialize_with = "float")]
+    pub start_map_id: f64,
+    #[serde(rename = "knownStepsList")]
+    pub known_steps_list: Vec<TreasureHuntStepKind>,
+    #[serde(rename = "totalStepCount")]
+    pub total_step_count: i8,
+    #[serde(rename = "checkPointCurrent")]
+    pub check_point_current: u32,
+    #[serde(rename = "checkPointTotal")]
+    pub check_point_total: u32,
+    #[serde(rename = "availableRetryCount")]
+    pub available_retry_count: i32,
+    pub flags: Vec<TreasureHuntFlag>,
+}
+
+/// `TreasureHuntRequestAnswerMessage`, id 7585
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TreasureHuntRequestAnswerMessage {
+    #[serde(rename = "questType")]
+    pub quest_type: i8,
+    pub result: i8,
+}
+
+/// `TreasureHuntShowLegendaryUIMessage`, id 9719
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TreasureHuntShowLegendaryUIMessage {
+    #[serde(rename = "availableLegendaryIds")]
+    pub available_legendary_ids: Vec<u16>,
+}
+
+/// `TreasureHuntStep`, id 7729
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TreasureHuntStep {}
+
+/// `TreasureHuntStepDig`, id 3722
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TreasureHuntStepDig {}
+
+/// `TreasureHuntStepFight`, id 538
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TreasureHuntStepFight {}
+
+/// `TreasureHuntStepFollowDirection`, id 6723
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TreasureHuntStepFollowDirection {
+    pub direction: i8,
+    #[serde(rename = "mapCount")]
+    pub map_count: u16,
+}
+
+/// `TreasureHuntStepFollowDirectionToHint`, id 8346
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TreasureHuntStepFollowDirectionToHint {
+    pub direction: i8,
+    #[serde(rename = "npcId")]
+    pub npc_id: u16,
+}
+
+/// `TreasureHuntStepFollowDirectionToPOI`, id 6499
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TreasureHuntStepFollowDirectionToPOI {
+    pub direction: i8,
+    #[serde(rename = "poiLabelId")]
+    pub poi_label_id: u16,
+}
+
+/// `TrustCertificate`, id 6961
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TrustCertificate {
+    pub id: i32,
+    pub hash: String,
+}
+
+/// `TrustStatusMessage`, id 3397
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TrustStatusMessage {
+    pub certified: bool,
+}
+
+/// `UnfollowQuestObjectiveRequestMessage`, id 6347
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UnfollowQuestObjectiveRequestMessage {
+    #[serde(rename = "questId")]
+    pub quest_id: u16,
+    #[serde(rename = "objectiveId")]
+    pub objective_id: i16,
+}
+
+/// `UpdateAllGuildRankRequestMessage`, id 4316
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UpdateAllGuildRankRequestMessage {
+    pub ranks: Vec<RankInformation>,
+}
+
+/// `UpdateGuildRankRequestMessage`, id 6891
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UpdateGuildRankRequestMessage {
+    pub rank: RankInformation,
+}
+
+/// `UpdateGuildRightsMessage`, id 3391
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UpdateGuildRightsMessage {
+    #[serde(rename = "rankId")]
+    pub rank_id: u32,
+    pub rights: Vec<u32>,
+}
+
+/// `UpdateLifePointsMessage`, id 1814
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UpdateLifePointsMessage {
+    #[serde(rename = "lifePoints")]
+    pub life_points: u32,
+    #[serde(rename = "maxLifePoints")]
+    pub max_life_points: u32,
+}
+
+/// `UpdateMapPlayersAgressableStatusMessage`, id 2209
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UpdateMapPlayersAgressableStatusMessage {
+    #[serde(rename = "playerAvAMessages")]
+    pub player_av_a_messages: Vec<AgressableStatusMessage>,
+}
+
+/// `UpdateMountBooleanCharacteristic`, id 650
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UpdateMountBooleanCharacteristic {
+    pub r#type: i8,
+    pub value: bool,
+}
+
+/// `UpdateMountCharacteristic`, id 6604
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UpdateMountCharacteristic {
+    pub r#type: i8,
+}
+
+/// `UpdateMountCharacteristicsMessage`, id 297
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UpdateMountCharacteristicsMessage {
+    #[serde(rename = "rideId")]
+    pub ride_id: u32,
+    #[serde(rename = "boostToUpdateList")]
+    pub boost_to_update_list: Vec<UpdateMountCharacteristicKind>,
+}
+
+/// `UpdateMountIntegerCharacteristic`, id 2135
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UpdateMountIntegerCharacteristic {
+    pub r#type: i8,
+    pub value: i32,
+}
+
+/// `UpdateRecruitmentInformationMessage`, id 350
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UpdateRecruitmentInformationMessage {
+    #[serde(rename = "recruitmentData")]
+    pub recruitment_data: GuildRecruitmentInformation,
+}
+
+/// `UpdateSelfAgressableStatusMessage`, id 9866
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UpdateSelfAgressableStatusMessage {
+    pub status: i8,
+    #[serde(rename = "probationTime", deserialize_with = "float")]
+    pub probation_time: f64,
+    #[serde(rename = "roleAvAId")]
+    pub role_av_a_id: i32,
+    #[serde(rename = "pictoScore")]
+    pub picto_score: i32,
+}
+
+/// `UpdatedStorageTabInformation`, id 265
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UpdatedStorageTabInformation {
+    pub name: String,
+    #[serde(rename = "tabNumber")]
+    pub tab_number: u32,
+    pub picto: u32,
+    #[serde(rename = "dropTypeLimitation")]
+    pub drop_type_limitation: Vec<u32>,
+}
+
+/// `Uuid`, id 2345
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Uuid {
+    #[serde(rename = "uuidString")]
+    pub uuid_string: String,
+}
+
+/// `Version`, id 6593
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Version {
+    pub major: i8,
+    pub minor: i8,
+    pub code: i8,
+    pub build: i32,
+    #[serde(rename = "buildType")]
+    pub build_type: i8,
+}
+
+/// `WarnOnPermaDeathMessage`, id 1393
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WarnOnPermaDeathMessage {
+    pub enable: bool,
+}
+
+/// `WarnOnPermaDeathStateMessage`, id 9321
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WarnOnPermaDeathStateMessage {
+    pub enable: bool,
+}
+
+/// `WatchInventoryContentMessage`, id 4610
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WatchInventoryContentMessage {
+    pub objects: Vec<ObjectItem>,
+    pub kamas: u64,
+}
+
+/// `WatchQuestListMessage`, id 1292
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WatchQuestListMessage {
+    #[serde(rename = "finishedQuestsIds")]
+    pub finished_quests_ids: Vec<u16>,
+    #[serde(rename = "finishedQuestsCounts")]
+    pub finished_quests_counts: Vec<u16>,
+    #[serde(rename = "activeQuests")]
+    pub active_quests: Vec<QuestActiveInformationsKind>,
+    #[serde(rename = "reinitDoneQuestsIds")]
+    pub reinit_done_quests_ids: Vec<u16>,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+}
+
+/// `WatchQuestStepInfoMessage`, id 2977
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WatchQuestStepInfoMessage {
+    pub infos: Box<QuestActiveInformationsKind>,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+}
+
+/// `WatchQuestStepInfoRequestMessage`, id 367
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WatchQuestStepInfoRequestMessage {
+    #[serde(rename = "questId")]
+    pub quest_id: u16,
+    #[serde(rename = "playerId")]
+    pub player_id: u64,
+}
+
+/// `WrapperObjectAssociatedMessage`, id 2853
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WrapperObjectAssociatedMessage {
+    #[serde(rename = "hostUID")]
+    pub host_uid: u32,
+}
+
+/// `WrapperObjectDissociateRequestMessage`, id 854
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WrapperObjectDissociateRequestMessage {
+    #[serde(rename = "hostUID")]
+    pub host_uid: u32,
+    #[serde(rename = "hostPos")]
+    pub host_pos: i8,
+}
+
+/// `WrapperObjectErrorMessage`, id 4313
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WrapperObjectErrorMessage {
+    pub reason: i8,
+    #[serde(rename = "errorCode")]
+    pub error_code: i8,
+}
+
+/// `ZaapDestinationsMessage`, id 4408
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ZaapDestinationsMessage {
+    pub r#type: i8,
+    pub destinations: Vec<TeleportDestination>,
+    #[serde(rename = "spawnMapId", deserialize_with = "float")]
+    pub spawn_map_id: f64,
+}
+
+/// `ZaapRespawnSaveRequestMessage`, id 3260
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ZaapRespawnSaveRequestMessage {}
+
+/// `ZaapRespawnUpdatedMessage`, id 3172
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ZaapRespawnUpdatedMessage {
+    #[serde(rename = "mapId", deserialize_with = "float")]
+    pub map_id: f64,
+}
+
+/// `TypeId<AbstractFightDispellableEffect>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum AbstractFightDispellableEffectKind {
+    AbstractFightDispellableEffect(AbstractFightDispellableEffect),
+    FightDetailedTemporaryBoostEffect(FightDetailedTemporaryBoostEffect),
+    FightTemporaryBoostEffect(FightTemporaryBoostEffect),
+    FightTemporaryBoostStateEffect(FightTemporaryBoostStateEffect),
+    FightTemporaryBoostWeaponDamagesEffect(FightTemporaryBoostWeaponDamagesEffect),
+    FightTemporarySpellBoostEffect(FightTemporarySpellBoostEffect),
+    FightTemporarySpellImmunityEffect(FightTemporarySpellImmunityEffect),
+    FightTriggeredEffect(FightTriggeredEffect),
+}
+
+impl Default for AbstractFightDispellableEffectKind {
+    fn default() -> Self {
+        AbstractFightDispellableEffectKind::AbstractFightDispellableEffect(Default::default())
+    }
+}
+
+/// `TypeId<AbstractPlayerSearchInformation>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum AbstractPlayerSearchInformationKind {
+    AbstractPlayerSearchInformation(AbstractPlayerSearchInformation),
+    PlayerSearchCharacterNameInformation(PlayerSearchCharacterNameInformation),
+    PlayerSearchTagInformation(PlayerSearchTagInformation),
+}
+
+impl Default for AbstractPlayerSearchInformationKind {
+    fn default() -> Self {
+        AbstractPlayerSearchInformationKind::AbstractPlayerSearchInformation(Default::default())
+    }
+}
+
+/// `TypeId<AbstractSocialGroupInfos>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum AbstractSocialGroupInfosKind {
+    AbstractSocialGroupInfos(AbstractSocialGroupInfos),
+    AllianceFactSheetInformation(AllianceFactSheetInformation),
+    AllianceInformation(AllianceInformation),
+    BasicAllianceInformations(BasicAllianceInformations),
+    BasicGuildInformations(BasicGuildInformations),
+    BasicNamedAllianceInformations(BasicNamedAllianceInformations),
+    GuildFactSheetInformations(GuildFactSheetInformations),
+    GuildInformations(GuildInformations),
+    GuildInsiderFactSheetInformations(GuildInsiderFactSheetInformations),
+}
+
+impl Default for AbstractSocialGroupInfosKind {
+    fn default() -> Self {
+        AbstractSocialGroupInfosKind::AbstractSocialGroupInfos(Default::default())
+    }
+}
+
+/// `TypeId<AchievementAchieved>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum AchievementAchievedKind {
+    AchievementAchieved(AchievementAchieved),
+    AchievementAchievedRewardable(AchievementAchievedRewardable),
+}
+
+impl Default for AchievementAchievedKind {
+    fn default() -> Self {
+        AchievementAchievedKind::AchievementAchieved(Default::default())
+    }
+}
+
+/// `TypeId<AcquaintanceInformation>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum AcquaintanceInformationKind {
+    AcquaintanceInformation(AcquaintanceInformation),
+    AcquaintanceOnlineInformation(AcquaintanceOnlineInformation),
+}
+
+impl Default for AcquaintanceInformationKind {
+    fn default() -> Self {
+        AcquaintanceInformationKind::AcquaintanceInformation(Default::default())
+    }
+}
+
+/// `TypeId<AllianceFactSheetInformation>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum AllianceFactSheetInformationKind {
+    AllianceFactSheetInformation(AllianceFactSheetInformation),
+}
+
+impl Default for AllianceFactSheetInformationKind {
+    fn default() -> Self {
+        AllianceFactSheetInformationKind::AllianceFactSheetInformation(Default::default())
+    }
+}
+
+/// `TypeId<BreachBranch>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum BreachBranchKind {
+    BreachBranch(BreachBranch),
+    ExtendedBreachBranch(ExtendedBreachBranch),
+    ExtendedLockedBreachBranch(ExtendedLockedBreachBranch),
+}
+
+impl Default for BreachBranchKind {
+    fn default() -> Self {
+        BreachBranchKind::BreachBranch(Default::default())
+    }
+}
+
+/// `TypeId<ChallengeTargetInformation>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum ChallengeTargetInformationKind {
+    ChallengeTargetInformation(ChallengeTargetInformation),
+    ChallengeTargetWithAttackerInformation(ChallengeTargetWithAttackerInformation),
+}
+
+impl Default for ChallengeTargetInformationKind {
+    fn default() -> Self {
+        ChallengeTargetInformationKind::ChallengeTargetInformation(Default::default())
+    }
+}
+
+/// `TypeId<CharacterBaseInformations>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum CharacterBaseInformationsKind {
+    CharacterBaseInformations(CharacterBaseInformations),
+    CharacterHardcoreOrEpicInformations(CharacterHardcoreOrEpicInformations),
+    PartyInvitationMemberInformations(PartyInvitationMemberInformations),
+    PartyMemberArenaInformations(PartyMemberArenaInformations),
+    PartyMemberInformations(PartyMemberInformations),
+}
+
+impl Default for CharacterBaseInformationsKind {
+    fn default() -> Self {
+        CharacterBaseInformationsKind::CharacterBaseInformations(Default::default())
+    }
+}
+
+/// `TypeId<CharacterCharacteristic>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum CharacterCharacteristicKind {
+    CharacterCharacteristic(CharacterCharacteristic),
+    CharacterCharacteristicDetailed(CharacterCharacteristicDetailed),
+    CharacterCharacteristicValue(CharacterCharacteristicValue),
+    CharacterUsableCharacteristicDetailed(CharacterUsableCharacteristicDetailed),
+}
+
+impl Default for CharacterCharacteristicKind {
+    fn default() -> Self {
+        CharacterCharacteristicKind::CharacterCharacteristic(Default::default())
+    }
+}
+
+/// `TypeId<DebtInformation>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum DebtInformationKind {
+    DebtInformation(DebtInformation),
+    KamaDebtInformation(KamaDebtInformation),
+}
+
+impl Default for DebtInformationKind {
+    fn default() -> Self {
+        DebtInformationKind::DebtInformation(Default::default())
+    }
+}
+
+/// `TypeId<EntityDispositionInformations>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum EntityDispositionInformationsKind {
+    EntityDispositionInformations(EntityDispositionInformations),
+    FightEntityDispositionInformations(FightEntityDispositionInformations),
+    IdentifiedEntityDispositionInformations(IdentifiedEntityDispositionInformations),
+}
+
+impl Default for EntityDispositionInformationsKind {
+    fn default() -> Self {
+        EntityDispositionInformationsKind::EntityDispositionInformations(Default::default())
+    }
+}
+
+/// `TypeId<ExtendedBreachBranch>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum ExtendedBreachBranchKind {
+    ExtendedBreachBranch(ExtendedBreachBranch),
+    ExtendedLockedBreachBranch(ExtendedLockedBreachBranch),
+}
+
+impl Default for ExtendedBreachBranchKind {
+    fn default() -> Self {
+        ExtendedBreachBranchKind::ExtendedBreachBranch(Default::default())
+    }
+}
+
+/// `TypeId<FightResultAdditionalData>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum FightResultAdditionalDataKind {
+    FightResultAdditionalData(FightResultAdditionalData),
+    FightResultExperienceData(FightResultExperienceData),
+    FightResultPvpData(FightResultPvpData),
+}
+
+impl Default for FightResultAdditionalDataKind {
+    fn default() -> Self {
+        FightResultAdditionalDataKind::FightResultAdditionalData(Default::default())
+    }
+}
+
+/// `TypeId<FightResultListEntry>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum FightResultListEntryKind {
+    FightResultFighterListEntry(FightResultFighterListEntry),
+    FightResultListEntry(FightResultListEntry),
+    FightResultMutantListEntry(FightResultMutantListEntry),
+    FightResultPlayerListEntry(FightResultPlayerListEntry),
+    FightResultTaxCollectorListEntry(FightResultTaxCollectorListEntry),
+}
+
+impl Default for FightResultListEntryKind {
+    fn default() -> Self {
+        FightResultListEntryKind::FightResultListEntry(Default::default())
+    }
+}
+
+/// `TypeId<FightTeamInformations>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum FightTeamInformationsKind {
+    FightAllianceTeamInformations(FightAllianceTeamInformations),
+    FightTeamInformations(FightTeamInformations),
+}
+
+impl Default for FightTeamInformationsKind {
+    fn default() -> Self {
+        FightTeamInformationsKind::FightTeamInformations(Default::default())
+    }
+}
+
+/// `TypeId<FightTeamMemberInformations>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum FightTeamMemberInformationsKind {
+    FightTeamMemberCharacterInformations(FightTeamMemberCharacterInformations),
+    FightTeamMemberEntityInformation(FightTeamMemberEntityInformation),
+    FightTeamMemberInformations(FightTeamMemberInformations),
+    FightTeamMemberMonsterInformations(FightTeamMemberMonsterInformations),
+    FightTeamMemberTaxCollectorInformations(FightTeamMemberTaxCollectorInformations),
+    FightTeamMemberWithAllianceCharacterInformations(FightTeamMemberWithAllianceCharacterInformations),
+}
+
+impl Default for FightTeamMemberInformationsKind {
+    fn default() -> Self {
+        FightTeamMemberInformationsKind::FightTeamMemberInformations(Default::default())
+    }
+}
+
+/// `TypeId<FriendInformations>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum FriendInformationsKind {
+    FriendInformations(FriendInformations),
+    FriendOnlineInformations(FriendOnlineInformations),
+}
+
+impl Default for FriendInformationsKind {
+    fn default() -> Self {
+        FriendInformationsKind::FriendInformations(Default::default())
+    }
+}
+
+/// `TypeId<FriendSpouseInformations>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum FriendSpouseInformationsKind {
+    FriendSpouseInformations(FriendSpouseInformations),
+    FriendSpouseOnlineInformations(FriendSpouseOnlineInformations),
+}
+
+impl Default for FriendSpouseInformationsKind {
+    fn default() -> Self {
+        FriendSpouseInformationsKind::FriendSpouseInformations(Default::default())
+    }
+}
+
+/// `TypeId<GameContextActorInformations>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum GameContextActorInformationsKind {
+    GameContextActorInformations(GameContextActorInformations),
+    GameFightAIInformations(GameFightAIInformations),
+    GameFightCharacterInformations(GameFightCharacterInformations),
+    GameFightEntityInformation(GameFightEntityInformation),
+    GameFightFighterInformations(GameFightFighterInformations),
+    GameFightFighterNamedInformations(GameFightFighterNamedInformations),
+    GameFightMonsterInformations(GameFightMonsterInformations),
+    GameFightMonsterWithAlignmentInformations(GameFightMonsterWithAlignmentInformations),
+    GameFightMutantInformations(GameFightMutantInformations),
+    GameFightTaxCollectorInformations(GameFightTaxCollectorInformations),
+    GameRolePlayActorInformations(GameRolePlayActorInformations),
+    GameRolePlayCharacterInformations(GameRolePlayCharacterInformations),
+    GameRolePlayGroupMonsterInformations(GameRolePlayGroupMonsterInformations),
+    GameRolePlayGroupMonsterWaveInformations(GameRolePlayGroupMonsterWaveInformations),
+    GameRolePlayHumanoidInformations(GameRolePlayHumanoidInformations),
+    GameRolePlayMountInformations(GameRolePlayMountInformations),
+    GameRolePlayMutantInformations(GameRolePlayMutantInformations),
+    GameRolePlayNamedActorInformations(GameRolePlayNamedActorInformations),
+    GameRolePlayNpcInformations(GameRolePlayNpcInformations),
+    GameRolePlayNpcWithQuestInformations(GameRolePlayNpcWithQuestInformations),
+    GameRolePlayPortalInformations(GameRolePlayPortalInformations),
+    GameRolePlayPrismInformations(GameRolePlayPrismInformations),
+    GameRolePlayTaxCollectorInformations(GameRolePlayTaxCollectorInformations),
+    GameRolePlayTreasureHintInformations(GameRolePlayTreasureHintInformations),
+}
+
+impl Default for GameContextActorInformationsKind {
+    fn default() -> Self {
+        GameContextActorInformationsKind::GameContextActorInformations(Default::default())
+    }
+}
+
+/// `TypeId<GameContextActorPositionInformations>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum GameContextActorPositionInformationsKind {
+    GameContextActorInformations(GameContextActorInformations),
+    GameContextActorPositionInformations(GameContextActorPositionInformations),
+    GameFightAIInformations(GameFightAIInformations),
+    GameFightCharacterInformations(GameFightCharacterInformations),
+    GameFightEntityInformation(GameFightEntityInformation),
+    GameFightFighterInformations(GameFightFighterInformations),
+    GameFightFighterNamedInformations(GameFightFighterNamedInformations),
+    GameFightMonsterInformations(GameFightMonsterInformations),
+    GameFightMonsterWithAlignmentInformations(GameFightMonsterWithAlignmentInformations),
+    GameFightMutantInformations(GameFightMutantInformations),
+    GameFightTaxCollectorInformations(GameFightTaxCollectorInformations),
+    GameRolePlayActorInformations(GameRolePlayActorInformations),
+    GameRolePlayCharacterInformations(GameRolePlayCharacterInformations),
+    GameRolePlayGroupMonsterInformations(GameRolePlayGroupMonsterInformations),
+    GameRolePlayGroupMonsterWaveInformations(GameRolePlayGroupMonsterWaveInformations),
+    GameRolePlayHumanoidInformations(GameRolePlayHumanoidInformations),
+    GameRolePlayMountInformations(GameRolePlayMountInformations),
+    GameRolePlayMutantInformations(GameRolePlayMutantInformations),
+    GameRolePlayNamedActorInformations(GameRolePlayNamedActorInformations),
+    GameRolePlayNpcInformations(GameRolePlayNpcInformations),
+    GameRolePlayNpcWithQuestInformations(GameRolePlayNpcWithQuestInformations),
+    GameRolePlayPortalInformations(GameRolePlayPortalInformations),
+    GameRolePlayPrismInformations(GameRolePlayPrismInformations),
+    GameRolePlayTaxCollectorInformations(GameRolePlayTaxCollectorInformations),
+    GameRolePlayTreasureHintInformations(GameRolePlayTreasureHintInformations),
+}
+
+impl Default for GameContextActorPositionInformationsKind {
+    fn default() -> Self {
+        GameContextActorPositionInformationsKind::GameContextActorPositionInformations(Default::default())
+    }
+}
+
+/// `TypeId<GameContextBasicSpawnInformation>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum GameContextBasicSpawnInformationKind {
+    GameContextBasicSpawnInformation(GameContextBasicSpawnInformation),
+}
+
+impl Default for GameContextBasicSpawnInformationKind {
+    fn default() -> Self {
+        GameContextBasicSpawnInformationKind::GameContextBasicSpawnInformation(Default::default())
+    }
+}
+
+/// `TypeId<GameContextSummonsInformation>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum GameContextSummonsInformationKind {
+    GameContextSummonsInformation(GameContextSummonsInformation),
+}
+
+impl Default for GameContextSummonsInformationKind {
+    fn default() -> Self {
+        GameContextSummonsInformationKind::GameContextSummonsInformation(Default::default())
+    }
+}
+
+/// `TypeId<GameFightCharacteristics>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum GameFightCharacteristicsKind {
+    GameFightCharacteristics(GameFightCharacteristics),
+}
+
+impl Default for GameFightCharacteristicsKind {
+    fn default() -> Self {
+        GameFightCharacteristicsKind::GameFightCharacteristics(Default::default())
+    }
+}
+
+/// `TypeId<GameFightFighterInformations>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum GameFightFighterInformationsKind {
+    GameFightAIInformations(GameFightAIInformations),
+    GameFightCharacterInformations(GameFightCharacterInformations),
+    GameFightEntityInformation(GameFightEntityInformation),
+    GameFightFighterInformations(GameFightFighterInformations),
+    GameFightFighterNamedInformations(GameFightFighterNamedInformations),
+    GameFightMonsterInformations(GameFightMonsterInformations),
+    GameFightMonsterWithAlignmentInformations(GameFightMonsterWithAlignmentInformations),
+    GameFightMutantInformations(GameFightMutantInformations),
+    GameFightTaxCollectorInformations(GameFightTaxCollectorInformations),
+}
+
+impl Default for GameFightFighterInformationsKind {
+    fn default() -> Self {
+        GameFightFighterInformationsKind::GameFightFighterInformations(Default::default())
+    }
+}
+
+/// `TypeId<GameFightFighterLightInformations>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum GameFightFighterLightInformationsKind {
+    GameFightFighterEntityLightInformation(GameFightFighterEntityLightInformation),
+    GameFightFighterLightInformations(GameFightFighterLightInformations),
+    GameFightFighterMonsterLightInformations(GameFightFighterMonsterLightInformations),
+    GameFightFighterNamedLightInformations(GameFightFighterNamedLightInformations),
+    GameFightFighterTaxCollectorLightInformations(GameFightFighterTaxCollectorLightInformations),
+}
+
+impl Default for GameFightFighterLightInformationsKind {
+    fn default() -> Self {
+        GameFightFighterLightInformationsKind::GameFightFighterLightInformations(Default::default())
+    }
+}
+
+/// `TypeId<GameRolePlayActorInformations>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum GameRolePlayActorInformationsKind {
+    GameRolePlayActorInformations(GameRolePlayActorInformations),
+    GameRolePlayCharacterInformations(GameRolePlayCharacterInformations),
+    GameRolePlayGroupMonsterInformations(GameRolePlayGroupMonsterInformations),
+    GameRolePlayGroupMonsterWaveInformations(GameRolePlayGroupMonsterWaveInformations),
+    GameRolePlayHumanoidInformations(GameRolePlayHumanoidInformations),
+    GameRolePlayMountInformations(GameRolePlayMountInformations),
+    GameRolePlayMutantInformations(GameRolePlayMutantInformations),
+    GameRolePlayNamedActorInformations(GameRolePlayNamedActorInformations),
+    GameRolePlayNpcInformations(GameRolePlayNpcInformations),
+    GameRolePlayNpcWithQuestInformations(GameRolePlayNpcWithQuestInformations),
+    GameRolePlayPortalInformations(GameRolePlayPortalInformations),
+    GameRolePlayPrismInformations(GameRolePlayPrismInformations),
+    GameRolePlayTaxCollectorInformations(GameRolePlayTaxCollectorInformations),
+    GameRolePlayTreasureHintInformations(GameRolePlayTreasureHintInformations),
+}
+
+impl Default for GameRolePlayActorInformationsKind {
+    fn default() -> Self {
+        GameRolePlayActorInformationsKind::GameRolePlayActorInformations(Default::default())
+    }
+}
+
+/// `TypeId<GroupMonsterStaticInformations>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum GroupMonsterStaticInformationsKind {
+    GroupMonsterStaticInformations(GroupMonsterStaticInformations),
+    GroupMonsterStaticInformationsWithAlternatives(GroupMonsterStaticInformationsWithAlternatives),
+}
+
+impl Default for GroupMonsterStaticInformationsKind {
+    fn default() -> Self {
+        GroupMonsterStaticInformationsKind::GroupMonsterStaticInformations(Default::default())
+    }
+}
+
+/// `TypeId<GuildFactSheetInformations>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum GuildFactSheetInformationsKind {
+    GuildFactSheetInformations(GuildFactSheetInformations),
+    GuildInsiderFactSheetInformations(GuildInsiderFactSheetInformations),
+}
+
+impl Default for GuildFactSheetInformationsKind {
+    fn default() -> Self {
+        GuildFactSheetInformationsKind::GuildFactSheetInformations(Default::default())
+    }
+}
+
+/// `TypeId<GuildLogbookEntryBasicInformation>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum GuildLogbookEntryBasicInformationKind {
+    GuildLevelUpActivity(GuildLevelUpActivity),
+    GuildLogbookChestActivity(GuildLogbookChestActivity),
+    GuildLogbookEntryBasicInformation(GuildLogbookEntryBasicInformation),
+    GuildPaddockActivity(GuildPaddockActivity),
+    GuildPlayerFlowActivity(GuildPlayerFlowActivity),
+    GuildPlayerRankUpdateActivity(GuildPlayerRankUpdateActivity),
+    GuildRankActivity(GuildRankActivity),
+    GuildUnlockNewTabActivity(GuildUnlockNewTabActivity),
+}
+
+impl Default for GuildLogbookEntryBasicInformationKind {
+    fn default() -> Self {
+        GuildLogbookEntryBasicInformationKind::GuildLogbookEntryBasicInformation(Default::default())
+    }
+}
+
+/// `TypeId<HouseInformations>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum HouseInformationsKind {
+    AccountHouseInformations(AccountHouseInformations),
+    HouseInformations(HouseInformations),
+    HouseInformationsForGuild(HouseInformationsForGuild),
+    HouseInformationsInside(HouseInformationsInside),
+    HouseOnMapInformations(HouseOnMapInformations),
+}
+
+impl Default for HouseInformationsKind {
+    fn default() -> Self {
+        HouseInformationsKind::HouseInformations(Default::default())
+    }
+}
+
+/// `TypeId<HouseInstanceInformations>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum HouseInstanceInformationsKind {
+    HouseGuildedInformations(HouseGuildedInformations),
+    HouseInstanceInformations(HouseInstanceInformations),
+}
+
+impl Default for HouseInstanceInformationsKind {
+    fn default() -> Self {
+        HouseInstanceInformationsKind::HouseInstanceInformations(Default::default())
+    }
+}
+
+/// `TypeId<HumanInformations>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum HumanInformationsKind {
+    HumanInformations(HumanInformations),
+}
+
+impl Default for HumanInformationsKind {
+    fn default() -> Self {
+        HumanInformationsKind::HumanInformations(Default::default())
+    }
+}
+
+/// `TypeId<HumanOption>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum HumanOptionKind {
+    HumanOption(HumanOption),
+    HumanOptionAlliance(HumanOptionAlliance),
+    HumanOptionEmote(HumanOptionEmote),
+    HumanOptionFollowers(HumanOptionFollowers),
+    HumanOptionGuild(HumanOptionGuild),
+    HumanOptionObjectUse(HumanOptionObjectUse),
+    HumanOptionOrnament(HumanOptionOrnament),
+    HumanOptionSkillUse(HumanOptionSkillUse),
+    HumanOptionSpeedMultiplier(HumanOptionSpeedMultiplier),
+    HumanOptionTitle(HumanOptionTitle),
+}
+
+impl Default for HumanOptionKind {
+    fn default() -> Self {
+        HumanOptionKind::HumanOption(Default::default())
+    }
+}
+
+/// `TypeId<IgnoredInformations>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum IgnoredInformationsKind {
+    IgnoredInformations(IgnoredInformations),
+    IgnoredOnlineInformations(IgnoredOnlineInformations),
+}
+
+impl Default for IgnoredInformationsKind {
+    fn default() -> Self {
+        IgnoredInformationsKind::IgnoredInformations(Default::default())
+    }
+}
+
+/// `TypeId<InteractiveElement>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum InteractiveElementKind {
+    InteractiveElement(InteractiveElement),
+    InteractiveElementWithAgeBonus(InteractiveElementWithAgeBonus),
+}
+
+impl Default for InteractiveElementKind {
+    fn default() -> Self {
+        InteractiveElementKind::InteractiveElement(Default::default())
+    }
+}
+
+/// `TypeId<InteractiveElementSkill>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum InteractiveElementSkillKind {
+    InteractiveElementNamedSkill(InteractiveElementNamedSkill),
+    InteractiveElementSkill(InteractiveElementSkill),
+}
+
+impl Default for InteractiveElementSkillKind {
+    fn default() -> Self {
+        InteractiveElementSkillKind::InteractiveElementSkill(Default::default())
+    }
+}
+
+/// `TypeId<MapCoordinates>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum MapCoordinatesKind {
+    MapCoordinates(MapCoordinates),
+    MapCoordinatesAndId(MapCoordinatesAndId),
+    MapCoordinatesExtended(MapCoordinatesExtended),
+}
+
+impl Default for MapCoordinatesKind {
+    fn default() -> Self {
+        MapCoordinatesKind::MapCoordinates(Default::default())
+    }
+}
+
+/// `TypeId<ObjectEffect>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum ObjectEffectKind {
+    ObjectEffect(ObjectEffect),
+    ObjectEffectCreature(ObjectEffectCreature),
+    ObjectEffectDate(ObjectEffectDate),
+    ObjectEffectDice(ObjectEffectDice),
+    ObjectEffectDuration(ObjectEffectDuration),
+    ObjectEffectInteger(ObjectEffectInteger),
+    ObjectEffectLadder(ObjectEffectLadder),
+    ObjectEffectMinMax(ObjectEffectMinMax),
+    ObjectEffectMount(ObjectEffectMount),
+    ObjectEffectString(ObjectEffectString),
+}
+
+impl Default for ObjectEffectKind {
+    fn default() -> Self {
+        ObjectEffectKind::ObjectEffect(Default::default())
+    }
+}
+
+/// `TypeId<PaddockBuyableInformations>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum PaddockBuyableInformationsKind {
+    PaddockBuyableInformations(PaddockBuyableInformations),
+    PaddockGuildedInformations(PaddockGuildedInformations),
+}
+
+impl Default for PaddockBuyableInformationsKind {
+    fn default() -> Self {
+        PaddockBuyableInformationsKind::PaddockBuyableInformations(Default::default())
+    }
+}
+
+/// `TypeId<PartyEntityBaseInformation>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum PartyEntityBaseInformationKind {
+    PartyEntityBaseInformation(PartyEntityBaseInformation),
+    PartyEntityMemberInformation(PartyEntityMemberInformation),
+}
+
+impl Default for PartyEntityBaseInformationKind {
+    fn default() -> Self {
+        PartyEntityBaseInformationKind::PartyEntityBaseInformation(Default::default())
+    }
+}
+
+/// `TypeId<PartyInvitationMemberInformations>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum PartyInvitationMemberInformationsKind {
+    PartyInvitationMemberInformations(PartyInvitationMemberInformations),
+}
+
+impl Default for PartyInvitationMemberInformationsKind {
+    fn default() -> Self {
+        PartyInvitationMemberInformationsKind::PartyInvitationMemberInformations(Default::default())
+    }
+}
+
+/// `TypeId<PartyMemberInformations>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum PartyMemberInformationsKind {
+    PartyMemberArenaInformations(PartyMemberArenaInformations),
+    PartyMemberInformations(PartyMemberInformations),
+}
+
+impl Default for PartyMemberInformationsKind {
+    fn default() -> Self {
+        PartyMemberInformationsKind::PartyMemberInformations(Default::default())
+    }
+}
+
+/// `TypeId<PlayerStatus>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum PlayerStatusKind {
+    PlayerStatus(PlayerStatus),
+    PlayerStatusExtended(PlayerStatusExtended),
+}
+
+impl Default for PlayerStatusKind {
+    fn default() -> Self {
+        PlayerStatusKind::PlayerStatus(Default::default())
+    }
+}
+
+/// `TypeId<PortalInformation>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum PortalInformationKind {
+    PortalInformation(PortalInformation),
+}
+
+impl Default for PortalInformationKind {
+    fn default() -> Self {
+        PortalInformationKind::PortalInformation(Default::default())
+    }
+}
+
+/// `TypeId<Preset>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum PresetKind {
+    EntitiesPreset(EntitiesPreset),
+    ForgettableSpellsPreset(ForgettableSpellsPreset),
+    FullStatsPreset(FullStatsPreset),
+    IconNamedPreset(IconNamedPreset),
+    ItemsPreset(ItemsPreset),
+    Preset(Preset),
+    PresetsContainerPreset(PresetsContainerPreset),
+    SpellsPreset(SpellsPreset),
+    StatsPreset(StatsPreset),
+}
+
+impl Default for PresetKind {
+    fn default() -> Self {
+        PresetKind::Preset(Default::default())
+    }
+}
+
+/// `TypeId<PrismGeolocalizedInformation>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum PrismGeolocalizedInformationKind {
+    PrismGeolocalizedInformation(PrismGeolocalizedInformation),
+}
+
+impl Default for PrismGeolocalizedInformationKind {
+    fn default() -> Self {
+        PrismGeolocalizedInformationKind::PrismGeolocalizedInformation(Default::default())
+    }
+}
+
+/// `TypeId<PrismInformation>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum PrismInformationKind {
+    AllianceInsiderPrismInformation(AllianceInsiderPrismInformation),
+    AlliancePrismInformation(AlliancePrismInformation),
+    PrismInformation(PrismInformation),
+}
+
+impl Default for PrismInformationKind {
+    fn default() -> Self {
+        PrismInformationKind::PrismInformation(Default::default())
+    }
+}
+
+/// `TypeId<QuestActiveInformations>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum QuestActiveInformationsKind {
+    QuestActiveDetailedInformations(QuestActiveDetailedInformations),
+    QuestActiveInformations(QuestActiveInformations),
+}
+
+impl Default for QuestActiveInformationsKind {
+    fn default() -> Self {
+        QuestActiveInformationsKind::QuestActiveInformations(Default::default())
+    }
+}
+
+/// `TypeId<QuestObjectiveInformations>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum QuestObjectiveInformationsKind {
+    QuestObjectiveInformations(QuestObjectiveInformations),
+    QuestObjectiveInformationsWithCompletion(QuestObjectiveInformationsWithCompletion),
+}
+
+impl Default for QuestObjectiveInformationsKind {
+    fn default() -> Self {
+        QuestObjectiveInformationsKind::QuestObjectiveInformations(Default::default())
+    }
+}
+
+/// `TypeId<ServerSessionConstant>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum ServerSessionConstantKind {
+    ServerSessionConstant(ServerSessionConstant),
+    ServerSessionConstantInteger(ServerSessionConstantInteger),
+    ServerSessionConstantLong(ServerSessionConstantLong),
+    ServerSessionConstantString(ServerSessionConstantString),
+}
+
+impl Default for ServerSessionConstantKind {
+    fn default() -> Self {
+        ServerSessionConstantKind::ServerSessionConstant(Default::default())
+    }
+}
+
+/// `TypeId<Shortcut>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum ShortcutKind {
+    Shortcut(Shortcut),
+    ShortcutEmote(ShortcutEmote),
+    ShortcutEntitiesPreset(ShortcutEntitiesPreset),
+    ShortcutObject(ShortcutObject),
+    ShortcutObjectItem(ShortcutObjectItem),
+    ShortcutObjectPreset(ShortcutObjectPreset),
+    ShortcutSmiley(ShortcutSmiley),
+    ShortcutSpell(ShortcutSpell),
+}
+
+impl Default for ShortcutKind {
+    fn default() -> Self {
+        ShortcutKind::Shortcut(Default::default())
+    }
+}
+
+/// `TypeId<SkillActionDescription>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum SkillActionDescriptionKind {
+    SkillActionDescription(SkillActionDescription),
+    SkillActionDescriptionCollect(SkillActionDescriptionCollect),
+    SkillActionDescriptionCraft(SkillActionDescriptionCraft),
+    SkillActionDescriptionTimed(SkillActionDescriptionTimed),
+}
+
+impl Default for SkillActionDescriptionKind {
+    fn default() -> Self {
+        SkillActionDescriptionKind::SkillActionDescription(Default::default())
+    }
+}
+
+/// `TypeId<SpawnInformation>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum SpawnInformationKind {
+    BaseSpawnMonsterInformation(BaseSpawnMonsterInformation),
+    SpawnCharacterInformation(SpawnCharacterInformation),
+    SpawnCompanionInformation(SpawnCompanionInformation),
+    SpawnInformation(SpawnInformation),
+    SpawnMonsterInformation(SpawnMonsterInformation),
+    SpawnScaledMonsterInformation(SpawnScaledMonsterInformation),
+}
+
+impl Default for SpawnInformationKind {
+    fn default() -> Self {
+        SpawnInformationKind::SpawnInformation(Default::default())
+    }
+}
+
+/// `TypeId<StatisticData>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum StatisticDataKind {
+    StatisticData(StatisticData),
+    StatisticDataBoolean(StatisticDataBoolean),
+    StatisticDataByte(StatisticDataByte),
+    StatisticDataInt(StatisticDataInt),
+    StatisticDataShort(StatisticDataShort),
+    StatisticDataString(StatisticDataString),
+}
+
+impl Default for StatisticDataKind {
+    fn default() -> Self {
+        StatisticDataKind::StatisticData(Default::default())
+    }
+}
+
+/// `TypeId<SurrenderResponse>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum SurrenderResponseKind {
+    SurrenderAccepted(SurrenderAccepted),
+    SurrenderRefused(SurrenderRefused),
+    SurrenderRefusedBeforeTurn(SurrenderRefusedBeforeTurn),
+    SurrenderRefusedBeforeVote(SurrenderRefusedBeforeVote),
+    SurrenderResponse(SurrenderResponse),
+}
+
+impl Default for SurrenderResponseKind {
+    fn default() -> Self {
+        SurrenderResponseKind::SurrenderResponse(Default::default())
+    }
+}
+
+/// `TypeId<SurrenderVoteResponse>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum SurrenderVoteResponseKind {
+    SurrenderVoteAccepted(SurrenderVoteAccepted),
+    SurrenderVoteRefused(SurrenderVoteRefused),
+    SurrenderVoteRefusedAlreadyAsked(SurrenderVoteRefusedAlreadyAsked),
+    SurrenderVoteRefusedBeforeTurn(SurrenderVoteRefusedBeforeTurn),
+    SurrenderVoteRefusedWaitBetweenVotes(SurrenderVoteRefusedWaitBetweenVotes),
+    SurrenderVoteResponse(SurrenderVoteResponse),
+}
+
+impl Default for SurrenderVoteResponseKind {
+    fn default() -> Self {
+        SurrenderVoteResponseKind::SurrenderVoteResponse(Default::default())
+    }
+}
+
+/// `TypeId<TaxCollectorComplementaryInformations>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum TaxCollectorComplementaryInformationsKind {
+    TaxCollectorComplementaryInformations(TaxCollectorComplementaryInformations),
+    TaxCollectorLootInformations(TaxCollectorLootInformations),
+    TaxCollectorWaitingForHelpInformations(TaxCollectorWaitingForHelpInformations),
+}
+
+impl Default for TaxCollectorComplementaryInformationsKind {
+    fn default() -> Self {
+        TaxCollectorComplementaryInformationsKind::TaxCollectorComplementaryInformations(Default::default())
+    }
+}
+
+/// `TypeId<TaxCollectorInformations>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum TaxCollectorInformationsKind {
+    TaxCollectorInformations(TaxCollectorInformations),
+}
+
+impl Default for TaxCollectorInformationsKind {
+    fn default() -> Self {
+        TaxCollectorInformationsKind::TaxCollectorInformations(Default::default())
+    }
+}
+
+/// `TypeId<TaxCollectorStaticInformations>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum TaxCollectorStaticInformationsKind {
+    TaxCollectorStaticInformations(TaxCollectorStaticInformations),
+}
+
+impl Default for TaxCollectorStaticInformationsKind {
+    fn default() -> Self {
+        TaxCollectorStaticInformationsKind::TaxCollectorStaticInformations(Default::default())
+    }
+}
+
+/// `TypeId<TreasureHuntStep>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum TreasureHuntStepKind {
+    TreasureHuntStep(TreasureHuntStep),
+    TreasureHuntStepDig(TreasureHuntStepDig),
+    TreasureHuntStepFight(TreasureHuntStepFight),
+    TreasureHuntStepFollowDirection(TreasureHuntStepFollowDirection),
+    TreasureHuntStepFollowDirectionToHint(TreasureHuntStepFollowDirectionToHint),
+    TreasureHuntStepFollowDirectionToPOI(TreasureHuntStepFollowDirectionToPOI),
+}
+
+impl Default for TreasureHuntStepKind {
+    fn default() -> Self {
+        TreasureHuntStepKind::TreasureHuntStep(Default::default())
+    }
+}
+
+/// `TypeId<UpdateMountCharacteristic>`, the class is named by `__type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__type")]
+pub enum UpdateMountCharacteristicKind {
+    UpdateMountBooleanCharacteristic(UpdateMountBooleanCharacteristic),
+    UpdateMountCharacteristic(UpdateMountCharacteristic),
+    UpdateMountIntegerCharacteristic(UpdateMountIntegerCharacteristic),
+}
+
+impl Default for UpdateMountCharacteristicKind {
+    fn default() -> Self {
+        UpdateMountCharacteristicKind::UpdateMountCharacteristic(Default::default())
+    }
+}
+
+/// Every message of the protocol
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum GameMessage {
+    AbstractGameActionFightTargetedAbilityMessage(AbstractGameActionFightTargetedAbilityMessage),
+    AbstractGameActionMessage(AbstractGameActionMessage),
+    AbstractGameActionWithAckMessage(AbstractGameActionWithAckMessage),
+    AbstractPartyEventMessage(AbstractPartyEventMessage),
+    AbstractPartyMemberInFightMessage(AbstractPartyMemberInFightMessage),
+    AbstractPartyMessage(AbstractPartyMessage),
+    AccessoryPreviewErrorMessage(AccessoryPreviewErrorMessage),
+    AccessoryPreviewMessage(AccessoryPreviewMessage),
+    AccessoryPreviewRequestMessage(AccessoryPreviewRequestMessage),
+    AccountCapabilitiesMessage(AccountCapabilitiesMessage),
+    AccountHouseMessage(AccountHouseMessage),
+    AccountInformationsUpdateMessage(AccountInformationsUpdateMessage),
+    AccountLinkRequiredMessage(AccountLinkRequiredMessage),
+    AccountLoggingKickedMessage(AccountLoggingKickedMessage),
+    AccountSubscriptionElapsedDurationMessage(AccountSubscriptionElapsedDurationMessage),
+    AchievementAlmostFinishedDetailedListMessage(AchievementAlmostFinishedDetailedListMessage),
+    AchievementAlmostFinishedDetailedListRequestMessage(AchievementAlmostFinishedDetailedListRequestMessage),
+    AchievementDetailedListMessage(AchievementDetailedListMessage),
+    AchievementDetailedListRequestMessage(AchievementDetailedListRequestMessage),
+    AchievementDetailsMessage(AchievementDetailsMessage),
+    AchievementDetailsRequestMessage(AchievementDetailsRequestMessage),
+    AchievementFinishedInformationMessage(AchievementFinishedInformationMessage),
+    AchievementFinishedMessage(AchievementFinishedMessage),
+    AchievementListMessage(AchievementListMessage),
+    AchievementRewardErrorMessage(AchievementRewardErrorMessage),
+    AchievementRewardRequestMessage(AchievementRewardRequestMessage),
+    AchievementRewardSuccessMessage(AchievementRewardSuccessMessage),
+    AchievementsPioneerRanksMessage(AchievementsPioneerRanksMessage),
+    AchievementsPioneerRanksRequestMessage(AchievementsPioneerRanksRequestMessage),
+    AcquaintanceAddedMessage(AcquaintanceAddedMessage),
+    AcquaintanceSearchErrorMessage(AcquaintanceSearchErrorMessage),
+    AcquaintanceSearchMessage(AcquaintanceSearchMessage),
+    AcquaintanceServerListMessage(AcquaintanceServerListMessage),
+    AcquaintancesGetListMessage(AcquaintancesGetListMessage),
+    AcquaintancesListMessage(AcquaintancesListMessage),
+    ActivityHideRequestMessage(ActivityHideRequestMessage),
+    ActivityLockRequestMessage(ActivityLockRequestMessage),
+    ActivitySuggestionsMessage(ActivitySuggestionsMessage),
+    ActivitySuggestionsRequestMessage(ActivitySuggestionsRequestMessage),
+    AddListenerOnSynchronizedStorageMessage(AddListenerOnSynchronizedStorageMessage),
+    AddTaxCollectorOrderedSpellMessage(AddTaxCollectorOrderedSpellMessage),
+    AddTaxCollectorPresetSpellMessage(AddTaxCollectorPresetSpellMessage),
+    AdminCommandMessage(AdminCommandMessage),
+    AdminQuietCommandMessage(AdminQuietCommandMessage),
+    AlignmentRankUpdateMessage(AlignmentRankUpdateMessage),
+    AlignmentWarEffortDonatePreviewMessage(AlignmentWarEffortDonatePreviewMessage),
+    AlignmentWarEffortDonateRequestMessage(AlignmentWarEffortDonateRequestMessage),
+    AlignmentWarEffortDonationResultMessage(AlignmentWarEffortDonationResultMessage),
+    AlignmentWarEffortProgressionMessage(AlignmentWarEffortProgressionMessage),
+    AlignmentWarEffortProgressionRequestMessage(AlignmentWarEffortProgressionRequestMessage),
+    AllianceAllRanksUpdateRequestMessage(AllianceAllRanksUpdateRequestMessage),
+    AllianceApplicationAnswerMessage(AllianceApplicationAnswerMessage),
+    AllianceApplicationDeletedMessage(AllianceApplicationDeletedMessage),
+    AllianceApplicationIsAnsweredMessage(AllianceApplicationIsAnsweredMessage),
+    AllianceApplicationListenMessage(AllianceApplicationListenMessage),
+    AllianceApplicationPresenceMessage(AllianceApplicationPresenceMessage),
+    AllianceApplicationReceivedMessage(AllianceApplicationReceivedMessage),
+    AllianceBulletinMessage(AllianceBulletinMessage),
+    AllianceBulletinSetErrorMessage(AllianceBulletinSetErrorMessage),
+    AllianceBulletinSetRequestMessage(AllianceBulletinSetRequestMessage),
+    AllianceChangeMemberRankMessage(AllianceChangeMemberRankMessage),
+    AllianceCreationResultMessage(AllianceCreationResultMessage),
+    AllianceCreationStartedMessage(AllianceCreationStartedMessage),
+    AllianceCreationValidMessage(AllianceCreationValidMessage),
+    AllianceDeleteApplicationRequestMessage(AllianceDeleteApplicationRequestMessage),
+    AllianceFactsErrorMessage(AllianceFactsErrorMessage),
+    AllianceFactsMessage(AllianceFactsMessage),
+    AllianceFactsRequestMessage(AllianceFactsRequestMessage),
+    AllianceFightFighterAddedMessage(AllianceFightFighterAddedMessage),
+    AllianceFightFighterRemovedMessage(AllianceFightFighterRemovedMessage),
+    AllianceFightFinishedMessage(AllianceFightFinishedMessage),
+    AllianceFightInfoMessage(AllianceFightInfoMessage),
+    AllianceFightPhaseUpdateMessage(AllianceFightPhaseUpdateMessage),
+    AllianceFightStartedMessage(AllianceFightStartedMessage),
+    AllianceGetPlayerApplicationMessage(AllianceGetPlayerApplicationMessage),
+    AllianceGetRecruitmentInformationMessage(AllianceGetRecruitmentInformationMessage),
+    AllianceInsiderInfoMessage(AllianceInsiderInfoMessage),
+    AllianceInsiderInfoRequestMessage(AllianceInsiderInfoRequestMessage),
+    AllianceInvitationAnswerMessage(AllianceInvitationAnswerMessage),
+    AllianceInvitationMessage(AllianceInvitationMessage),
+    AllianceInvitationStateRecrutedMessage(AllianceInvitationStateRecrutedMessage),
+    AllianceInvitationStateRecruterMessage(AllianceInvitationStateRecruterMessage),
+    AllianceInvitedMessage(AllianceInvitedMessage),
+    AllianceIsThereAnyApplicationMessage(AllianceIsThereAnyApplicationMessage),
+    AllianceJoinAutomaticallyRequestMessage(AllianceJoinAutomaticallyRequestMessage),
+    AllianceJoinedMessage(AllianceJoinedMessage),
+    AllianceKickRequestMessage(AllianceKickRequestMessage),
+    AllianceLeftMessage(AllianceLeftMessage),
+    AllianceListApplicationAnswerMessage(AllianceListApplicationAnswerMessage),
+    AllianceListApplicationModifiedMessage(AllianceListApplicationModifiedMessage),
+    AllianceListApplicationRequestMessage(AllianceListApplicationRequestMessage),
+    AllianceListMessage(AllianceListMessage),
+    AllianceMemberInformationUpdateMessage(AllianceMemberInformationUpdateMessage),
+    AllianceMemberLeavingMessage(AllianceMemberLeavingMessage),
+    AllianceMemberOnlineStatusMessage(AllianceMemberOnlineStatusMessage),
+    AllianceMemberStartWarningOnConnectionMessage(AllianceMemberStartWarningOnConnectionMessage),
+    AllianceMemberStopWarningOnConnectionMessage(AllianceMemberStopWarningOnConnectionMessage),
+    AllianceMembershipMessage(AllianceMembershipMessage),
+    AllianceModificationEmblemValidMessage(AllianceModificationEmblemValidMessage),
+    AllianceModificationNameAndTagValidMessage(AllianceModificationNameAndTagValidMessage),
+    AllianceModificationResultMessage(AllianceModificationResultMessage),
+    AllianceModificationStartedMessage(AllianceModificationStartedMessage),
+    AllianceModificationValidMessage(AllianceModificationValidMessage),
+    AllianceMotdMessage(AllianceMotdMessage),
+    AllianceMotdSetErrorMessage(AllianceMotdSetErrorMessage),
+    AllianceMotdSetRequestMessage(AllianceMotdSetRequestMessage),
+    AlliancePartialListMessage(AlliancePartialListMessage),
+    AlliancePlayerApplicationAbstractMessage(AlliancePlayerApplicationAbstractMessage),
+    AlliancePlayerApplicationInformationMessage(AlliancePlayerApplicationInformationMessage),
+    AlliancePlayerNoApplicationInformationMessage(AlliancePlayerNoApplicationInformationMessage),
+    AlliancePrismDialogQuestionMessage(AlliancePrismDialogQuestionMessage),
+    AllianceRankCreateRequestMessage(AllianceRankCreateRequestMessage),
+    AllianceRankRemoveRequestMessage(AllianceRankRemoveRequestMessage),
+    AllianceRankUpdateRequestMessage(AllianceRankUpdateRequestMessage),
+    AllianceRanksMessage(AllianceRanksMessage),
+    AllianceRanksRequestMessage(AllianceRanksRequestMessage),
+    AllianceRecruitmentInformationMessage(AllianceRecruitmentInformationMessage),
+    AllianceRecruitmentInvalidateMessage(AllianceRecruitmentInvalidateMessage),
+    AllianceRightsUpdateMessage(AllianceRightsUpdateMessage),
+    AllianceSubmitApplicationMessage(AllianceSubmitApplicationMessage),
+    AllianceSummaryMessage(AllianceSummaryMessage),
+    AllianceSummaryRequestMessage(AllianceSummaryRequestMessage),
+    AllianceUpdateApplicationMessage(AllianceUpdateApplicationMessage),
+    AllianceUpdateRecruitmentInformationMessage(AllianceUpdateRecruitmentInformationMessage),
+    AlmanachCalendarDateMessage(AlmanachCalendarDateMessage),
+    AlreadyConnectedMessage(AlreadyConnectedMessage),
+    AlterationAddedMessage(AlterationAddedMessage),
+    AlterationRemovedMessage(AlterationRemovedMessage),
+    AlterationsMessage(AlterationsMessage),
+    AlterationsUpdatedMessage(AlterationsUpdatedMessage),
+    AnomalyOpenedMessage(AnomalyOpenedMessage),
+    AnomalyStateMessage(AnomalyStateMessage),
+    AnomalySubareaInformationRequestMessage(AnomalySubareaInformationRequestMessage),
+    AnomalySubareaInformationResponseMessage(AnomalySubareaInformationResponseMessage),
+    ApplySpellModifierMessage(ApplySpellModifierMessage),
+    AreaFightModificatorUpdateMessage(AreaFightModificatorUpdateMessage),
+    ArenaFightAnswerAcknowledgementMessage(ArenaFightAnswerAcknowledgementMessage),
+    ArenaFighterIdleMessage(ArenaFighterIdleMessage),
+    ArenaFighterLeaveMessage(ArenaFighterLeaveMessage),
+    AtlasPointInformationsMessage(AtlasPointInformationsMessage),
+    AuthenticationTicketAcceptedMessage(AuthenticationTicketAcceptedMessage),
+    AuthenticationTicketMessage(AuthenticationTicketMessage),
+    AuthenticationTicketRefusedMessage(AuthenticationTicketRefusedMessage),
+    BasicAckMessage(BasicAckMessage),
+    BasicDateMessage(BasicDateMessage),
+    BasicLatencyStatsMessage(BasicLatencyStatsMessage),
+    BasicLatencyStatsRequestMessage(BasicLatencyStatsRequestMessage),
+    BasicNoOperationMessage(BasicNoOperationMessage),
+    BasicPingMessage(BasicPingMessage),
+    BasicPongMessage(BasicPongMessage),
+    BasicStatMessage(BasicStatMessage),
+    BasicStatWithDataMessage(BasicStatWithDataMessage),
+    BasicTimeMessage(BasicTimeMessage),
+    BasicWhoAmIRequestMessage(BasicWhoAmIRequestMessage),
+    BasicWhoIsMessage(BasicWhoIsMessage),
+    BasicWhoIsNoMatchMessage(BasicWhoIsNoMatchMessage),
+    BasicWhoIsRequestMessage(BasicWhoIsRequestMessage),
+    BreachBonusMessage(BreachBonusMessage),
+    BreachBranchesMessage(BreachBranchesMessage),
+    BreachBudgetMessage(BreachBudgetMessage),
+    BreachCharactersMessage(BreachCharactersMessage),
+    BreachEnterMessage(BreachEnterMessage),
+    BreachExitRequestMessage(BreachExitRequestMessage),
+    BreachExitResponseMessage(BreachExitResponseMessage),
+    BreachGameFightEndMessage(BreachGameFightEndMessage),
+    BreachInvitationAnswerMessage(BreachInvitationAnswerMessage),
+    BreachInvitationCloseMessage(BreachInvitationCloseMessage),
+    BreachInvitationOfferMessage(BreachInvitationOfferMessage),
+    BreachInvitationRequestMessage(BreachInvitationRequestMessage),
+    BreachInvitationResponseMessage(BreachInvitationResponseMessage),
+    BreachKickRequestMessage(BreachKickRequestMessage),
+    BreachKickResponseMessage(BreachKickResponseMessage),
+    BreachRewardBoughtMessage(BreachRewardBoughtMessage),
+    BreachRewardBuyMessage(BreachRewardBuyMessage),
+    BreachRewardsMessage(BreachRewardsMessage),
+    BreachRoomLockedMessage(BreachRoomLockedMessage),
+    BreachRoomUnlockRequestMessage(BreachRoomUnlockRequestMessage),
+    BreachRoomUnlockResultMessage(BreachRoomUnlockResultMessage),
+    BreachSavedMessage(BreachSavedMessage),
+    BreachStateMessage(BreachStateMessage),
+    BreachTeleportRequestMessage(BreachTeleportRequestMessage),
+    BreachTeleportResponseMessage(BreachTeleportResponseMessage),
+    BulletinMessage(BulletinMessage),
+    ChallengeAddMessage(ChallengeAddMessage),
+    ChallengeBonusChoiceMessage(ChallengeBonusChoiceMessage),
+    ChallengeBonusChoiceSelectedMessage(ChallengeBonusChoiceSelectedMessage),
+    ChallengeFightJoinRefusedMessage(ChallengeFightJoinRefusedMessage),
+    ChallengeListMessage(ChallengeListMessage),
+    ChallengeModSelectMessage(ChallengeModSelectMessage),
+    ChallengeModSelectedMessage(ChallengeModSelectedMessage),
+    ChallengeNumberMessage(ChallengeNumberMessage),
+    ChallengeProposalMessage(ChallengeProposalMessage),
+    ChallengeReadyMessage(ChallengeReadyMessage),
+    ChallengeResultMessage(ChallengeResultMessage),
+    ChallengeSelectedMessage(ChallengeSelectedMessage),
+    ChallengeSelectionMessage(ChallengeSelectionMessage),
+    ChallengeTargetsMessage(ChallengeTargetsMessage),
+    ChallengeTargetsRequestMessage(ChallengeTargetsRequestMessage),
+    ChallengeValidateMessage(ChallengeValidateMessage),
+    ChangeHavenBagRoomRequestMessage(ChangeHavenBagRoomRequestMessage),
+    ChangeMapMessage(ChangeMapMessage),
+    ChangeThemeRequestMessage(ChangeThemeRequestMessage),
+    ChannelEnablingChangeMessage(ChannelEnablingChangeMessage),
+    ChannelEnablingMessage(ChannelEnablingMessage),
+    CharacterAlignmentWarEffortProgressionMessage(CharacterAlignmentWarEffortProgressionMessage),
+    CharacterAlignmentWarEffortProgressionRequestMessage(CharacterAlignmentWarEffortProgressionRequestMessage),
+    CharacterCanBeCreatedRequestMessage(CharacterCanBeCreatedRequestMessage),
+    CharacterCanBeCreatedResultMessage(CharacterCanBeCreatedResultMessage),
+    CharacterCapabilitiesMessage(CharacterCapabilitiesMessage),
+    CharacterCreationRequestMessage(CharacterCreationRequestMessage),
+    CharacterCreationResultMessage(CharacterCreationResultMessage),
+    CharacterDeletionErrorMessage(CharacterDeletionErrorMessage),
+    CharacterDeletionPrepareMessage(CharacterDeletionPrepareMessage),
+    CharacterDeletionPrepareRequestMessage(CharacterDeletionPrepareRequestMessage),
+    CharacterDeletionRequestMessage(CharacterDeletionRequestMessage),
+    CharacterExperienceGainMessage(CharacterExperienceGainMessage),
+    CharacterFirstSelectionMessage(CharacterFirstSelectionMessage),
+    CharacterLevelUpInformationMessage(CharacterLevelUpInformationMessage),
+    CharacterLevelUpMessage(CharacterLevelUpMessage),
+    CharacterLoadingCompleteMessage(CharacterLoadingCompleteMessage),
+    CharacterNameSuggestionFailureMessage(CharacterNameSuggestionFailureMessage),
+    CharacterNameSuggestionRequestMessage(CharacterNameSuggestionRequestMessage),
+    CharacterNameSuggestionSuccessMessage(CharacterNameSuggestionSuccessMessage),
+    CharacterReplayRequestMessage(CharacterReplayRequestMessage),
+    CharacterReplayWithRemodelRequestMessage(CharacterReplayWithRemodelRequestMessage),
+    CharacterSelectedErrorMessage(CharacterSelectedErrorMessage),
+    CharacterSelectedForceMessage(CharacterSelectedForceMessage),
+    CharacterSelectedForceReadyMessage(CharacterSelectedForceReadyMessage),
+    CharacterSelectedSuccessMessage(CharacterSelectedSuccessMessage),
+    CharacterSelectionMessage(CharacterSelectionMessage),
+    CharacterSelectionWithRemodelMessage(CharacterSelectionWithRemodelMessage),
+    CharacterStatsListMessage(CharacterStatsListMessage),
+    CharactersListErrorMessage(CharactersListErrorMessage),
+    CharactersListMessage(CharactersListMessage),
+    CharactersListRequestMessage(CharactersListRequestMessage),
+    CharactersListWithRemodelingMessage(CharactersListWithRemodelingMessage),
+    ChatAbstractClientMessage(ChatAbstractClientMessage),
+    ChatAbstractServerMessage(ChatAbstractServerMessage),
+    ChatAdminServerMessage(ChatAdminServerMessage),
+    ChatClientMultiMessage(ChatClientMultiMessage),
+    ChatClientMultiWithObjectMessage(ChatClientMultiWithObjectMessage),
+    ChatClientPrivateMessage(ChatClientPrivateMessage),
+    ChatClientPrivateWithObjectMessage(ChatClientPrivateWithObjectMessage),
+    ChatCommunityChannelCommunityMessage(ChatCommunityChannelCommunityMessage),
+    ChatCommunityChannelSetCommunityRequestMessage(ChatCommunityChannelSetCommunityRequestMessage),
+    ChatErrorMessage(ChatErrorMessage),
+    ChatKolizeumServerMessage(ChatKolizeumServerMessage),
+    ChatServerCopyMessage(ChatServerCopyMessage),
+    ChatServerCopyWithObjectMessage(ChatServerCopyWithObjectMessage),
+    ChatServerMessage(ChatServerMessage),
+    ChatServerWithObjectMessage(ChatServerWithObjectMessage),
+    ChatSmileyExtraPackListMessage(ChatSmileyExtraPackListMessage),
+    ChatSmileyMessage(ChatSmileyMessage),
+    ChatSmileyRequestMessage(ChatSmileyRequestMessage),
+    CheckFileMessage(CheckFileMessage),
+    CheckFileRequestMessage(CheckFileRequestMessage),
+    CheckIntegrityMessage(CheckIntegrityMessage),
+    CinematicMessage(CinematicMessage),
+    ClientKeyMessage(ClientKeyMessage),
+    ClientUIOpenedByObjectMessage(ClientUIOpenedByObjectMessage),
+    ClientUIOpenedMessage(ClientUIOpenedMessage),
+    ClientYouAreDrunkMessage(ClientYouAreDrunkMessage),
+    CloseHavenBagFurnitureSequenceRequestMessage(CloseHavenBagFurnitureSequenceRequestMessage),
+    CompassResetMessage(CompassResetMessage),
+    CompassUpdateMessage(CompassUpdateMessage),
+    CompassUpdatePartyMemberMessage(CompassUpdatePartyMemberMessage),
+    CompassUpdatePvpSeekMessage(CompassUpdatePvpSeekMessage),
+    ConfirmationOfListeningTaxCollectorUpdatesMessage(ConfirmationOfListeningTaxCollectorUpdatesMessage),
+    ConsoleCommandsListMessage(ConsoleCommandsListMessage),
+    ConsoleEndMessage(ConsoleEndMessage),
+    ConsoleMessage(ConsoleMessage),
+    ConsumeAllGameActionItemMessage(ConsumeAllGameActionItemMessage),
+    ConsumeGameActionItemMessage(ConsumeGameActionItemMessage),
+    ContactAddFailureMessage(ContactAddFailureMessage),
+    ContactLookErrorMessage(ContactLookErrorMessage),
+    ContactLookMessage(ContactLookMessage),
+    ContactLookRequestByIdMessage(ContactLookRequestByIdMessage),
+    ContactLookRequestByNameMessage(ContactLookRequestByNameMessage),
+    ContactLookRequestMessage(ContactLookRequestMessage),
+    CreateGuildRankRequestMessage(CreateGuildRankRequestMessage),
+    CredentialsAcknowledgementMessage(CredentialsAcknowledgementMessage),
+    CurrentMapInstanceMessage(CurrentMapInstanceMessage),
+    CurrentMapMessage(CurrentMapMessage),
+    CurrentServerStatusUpdateMessage(CurrentServerStatusUpdateMessage),
+    DebtsDeleteMessage(DebtsDeleteMessage),
+    DebtsUpdateMessage(DebtsUpdateMessage),
+    DebugClearHighlightCellsMessage(DebugClearHighlightCellsMessage),
+    DebugHighlightCellsMessage(DebugHighlightCellsMessage),
+    DebugInClientMessage(DebugInClientMessage),
+    DecraftResultMessage(DecraftResultMessage),
+    DiceRollRequestMessage(DiceRollRequestMessage),
+    DisplayNumericalValuePaddockMessage(DisplayNumericalValuePaddockMessage),
+    DocumentReadingBeginMessage(DocumentReadingBeginMessage),
+    DumpedEntityStatsMessage(DumpedEntityStatsMessage),
+    DungeonKeyRingMessage(DungeonKeyRingMessage),
+    DungeonKeyRingUpdateMessage(DungeonKeyRingUpdateMessage),
+    DungeonPartyFinderAvailableDungeonsMessage(DungeonPartyFinderAvailableDungeonsMessage),
+    DungeonPartyFinderAvailableDungeonsRequestMessage(DungeonPartyFinderAvailableDungeonsRequestMessage),
+    DungeonPartyFinderListenErrorMessage(DungeonPartyFinderListenErrorMessage),
+    DungeonPartyFinderListenRequestMessage(DungeonPartyFinderListenRequestMessage),
+    DungeonPartyFinderRegisterErrorMessage(DungeonPartyFinderRegisterErrorMessage),
+    DungeonPartyFinderRegisterRequestMessage(DungeonPartyFinderRegisterRequestMessage),
+    DungeonPartyFinderRegisterSuccessMessage(DungeonPartyFinderRegisterSuccessMessage),
+    DungeonPartyFinderRoomContentMessage(DungeonPartyFinderRoomContentMessage),
+    DungeonPartyFinderRoomContentUpdateMessage(DungeonPartyFinderRoomContentUpdateMessage),
+    EditHavenBagCancelRequestMessage(EditHavenBagCancelRequestMessage),
+    EditHavenBagFinishedMessage(EditHavenBagFinishedMessage),
+    EditHavenBagRequestMessage(EditHavenBagRequestMessage),
+    EditHavenBagStartMessage(EditHavenBagStartMessage),
+    EmoteAddMessage(EmoteAddMessage),
+    EmoteListMessage(EmoteListMessage),
+    EmotePlayAbstractMessage(EmotePlayAbstractMessage),
+    EmotePlayErrorMessage(EmotePlayErrorMessage),
+    EmotePlayMassiveMessage(EmotePlayMassiveMessage),
+    EmotePlayMessage(EmotePlayMessage),
+    EmotePlayRequestMessage(EmotePlayRequestMessage),
+    EmoteRemoveMessage(EmoteRemoveMessage),
+    EnabledChannelsMessage(EnabledChannelsMessage),
+    EnterHavenBagRequestMessage(EnterHavenBagRequestMessage),
+    EntitiesInformationMessage(EntitiesInformationMessage),
+    EntityInformationMessage(EntityInformationMessage),
+    EntityTalkMessage(EntityTalkMessage),
+    ErrorMapNotFoundMessage(ErrorMapNotFoundMessage),
+    EvolutiveObjectRecycleResultMessage(EvolutiveObjectRecycleResultMessage),
+    ExchangeAcceptMessage(ExchangeAcceptMessage),
+    ExchangeBidHouseBuyMessage(ExchangeBidHouseBuyMessage),
+    ExchangeBidHouseBuyResultMessage(ExchangeBidHouseBuyResultMessage),
+    ExchangeBidHouseGenericItemAddedMessage(ExchangeBidHouseGenericItemAddedMessage),
+    ExchangeBidHouseGenericItemRemovedMessage(ExchangeBidHouseGenericItemRemovedMessage),
+    ExchangeBidHouseInListAddedMessage(ExchangeBidHouseInListAddedMessage),
+    ExchangeBidHouseInListRemovedMessage(ExchangeBidHouseInListRemovedMessage),
+    ExchangeBidHouseInListUpdatedMessage(ExchangeBidHouseInListUpdatedMessage),
+    ExchangeBidHouseItemAddOkMessage(ExchangeBidHouseItemAddOkMessage),
+    ExchangeBidHouseItemRemoveOkMessage(ExchangeBidHouseItemRemoveOkMessage),
+    ExchangeBidHouseListMessage(ExchangeBidHouseListMessage),
+    ExchangeBidHousePriceMessage(ExchangeBidHousePriceMessage),
+    ExchangeBidHouseSearchMessage(ExchangeBidHouseSearchMessage),
+    ExchangeBidHouseTypeMessage(ExchangeBidHouseTypeMessage),
+    ExchangeBidHouseUnsoldItemsMessage(ExchangeBidHouseUnsoldItemsMessage),
+    ExchangeBidPriceForSellerMessage(ExchangeBidPriceForSellerMessage),
+    ExchangeBidPriceMessage(ExchangeBidPriceMessage),
+    ExchangeBidSearchOkMessage(ExchangeBidSearchOkMessage),
+    ExchangeBuyMessage(ExchangeBuyMessage),
+    ExchangeBuyOkMessage(ExchangeBuyOkMessage),
+    ExchangeCraftCountModifiedMessage(ExchangeCraftCountModifiedMessage),
+    ExchangeCraftCountRequestMessage(ExchangeCraftCountRequestMessage),
+    ExchangeCraftPaymentModificationRequestMessage(ExchangeCraftPaymentModificationRequestMessage),
+    ExchangeCraftPaymentModifiedMessage(ExchangeCraftPaymentModifiedMessage),
+    ExchangeCraftResultMagicWithObjectDescMessage(ExchangeCraftResultMagicWithObjectDescMessage),
+    ExchangeCraftResultMessage(ExchangeCraftResultMessage),
+    ExchangeCraftResultWithObjectDescMessage(ExchangeCraftResultWithObjectDescMessage),
+    ExchangeCraftResultWithObjectIdMessage(ExchangeCraftResultWithObjectIdMessage),
+    ExchangeCrafterJobLevelupMessage(ExchangeCrafterJobLevelupMessage),
+    ExchangeErrorMessage(ExchangeErrorMessage),
+    ExchangeHandleMountsMessage(ExchangeHandleMountsMessage),
+    ExchangeIsReadyMessage(ExchangeIsReadyMessage),
+    ExchangeItemAutoCraftStopedMessage(ExchangeItemAutoCraftStopedMessage),
+    ExchangeKamaModifiedMessage(ExchangeKamaModifiedMessage),
+    ExchangeLeaveMessage(ExchangeLeaveMessage),
+    ExchangeMoneyMovementInformationMessage(ExchangeMoneyMovementInformationMessage),
+    ExchangeMountFreeFromPaddockMessage(ExchangeMountFreeFromPaddockMessage),
+    ExchangeMountStableErrorMessage(ExchangeMountStableErrorMessage),
+    ExchangeMountSterilizeFromPaddockMessage(ExchangeMountSterilizeFromPaddockMessage),
+    ExchangeMountsPaddockAddMessage(ExchangeMountsPaddockAddMessage),
+    ExchangeMountsPaddockRemoveMessage(ExchangeMountsPaddockRemoveMessage),
+    ExchangeMountsStableAddMessage(ExchangeMountsStableAddMessage),
+    ExchangeMountsStableBornAddMessage(ExchangeMountsStableBornAddMessage),
+    ExchangeMountsStableRemoveMessage(ExchangeMountsStableRemoveMessage),
+    ExchangeMountsTakenFromPaddockMessage(ExchangeMountsTakenFromPaddockMessage),
+    ExchangeMultiCraftCrafterCanUseHisRessourcesMessage(ExchangeMultiCraftCrafterCanUseHisRessourcesMessage),
+    ExchangeMultiCraftSetCrafterCanUseHisRessourcesMessage(ExchangeMultiCraftSetCrafterCanUseHisRessourcesMessage),
+    ExchangeObjectAddedMessage(ExchangeObjectAddedMessage),
+    ExchangeObjectMessage(ExchangeObjectMessage),
+    ExchangeObjectModifiedInBagMessage(ExchangeObjectModifiedInBagMessage),
+    ExchangeObjectModifiedMessage(ExchangeObjectModifiedMessage),
+    ExchangeObjectModifyPricedMessage(ExchangeObjectModifyPricedMessage),
+    ExchangeObjectMoveKamaMessage(ExchangeObjectMoveKamaMessage),
+    ExchangeObjectMoveMessage(ExchangeObjectMoveMessage),
+    ExchangeObjectMovePricedMessage(ExchangeObjectMovePricedMessage),
+    ExchangeObjectMoveToTabMessage(ExchangeObjectMoveToTabMessage),
+    ExchangeObjectPutInBagMessage(ExchangeObjectPutInBagMessage),
+    ExchangeObjectRemovedFromBagMessage(ExchangeObjectRemovedFromBagMessage),
+    ExchangeObjectRemovedMessage(ExchangeObjectRemovedMessage),
+    ExchangeObjectTransfertAllFromInvMessage(ExchangeObjectTransfertAllFromInvMessage),
+    ExchangeObjectTransfertAllToInvMessage(ExchangeObjectTransfertAllToInvMessage),
+    ExchangeObjectTransfertExistingFromInvMessage(ExchangeObjectTransfertExistingFromInvMessage),
+    ExchangeObjectTransfertExistingToInvMessage(ExchangeObjectTransfertExistingToInvMessage),
+    ExchangeObjectTransfertListFromInvMessage(ExchangeObjectTransfertListFromInvMessage),
+    ExchangeObjectTransfertListToInvMessage(ExchangeObjectTransfertListToInvMessage),
+    ExchangeObjectTransfertListWithQuantityToInvMessage(ExchangeObjectTransfertListWithQuantityToInvMessage),
+    ExchangeObjectUseInWorkshopMessage(ExchangeObjectUseInWorkshopMessage),
+    ExchangeObjectsAddedMessage(ExchangeObjectsAddedMessage),
+    ExchangeObjectsModifiedMessage(ExchangeObjectsModifiedMessage),
+    ExchangeObjectsRemovedMessage(ExchangeObjectsRemovedMessage),
+    ExchangeOfflineSoldItemsMessage(ExchangeOfflineSoldItemsMessage),
+    ExchangeOkMultiCraftMessage(ExchangeOkMultiCraftMessage),
+    ExchangePlayerMultiCraftRequestMessage(ExchangePlayerMultiCraftRequestMessage),
+    ExchangePlayerRequestMessage(ExchangePlayerRequestMessage),
+    ExchangePodsModifiedMessage(ExchangePodsModifiedMessage),
+    ExchangeReadyMessage(ExchangeReadyMessage),
+    ExchangeReplayStopMessage(ExchangeReplayStopMessage),
+    ExchangeRequestMessage(ExchangeRequestMessage),
+    ExchangeRequestOnMountStockMessage(ExchangeRequestOnMountStockMessage),
+    ExchangeRequestOnTaxCollectorMessage(ExchangeRequestOnTaxCollectorMessage),
+    ExchangeRequestedMessage(ExchangeRequestedMessage),
+    ExchangeRequestedTradeMessage(ExchangeRequestedTradeMessage),
+    ExchangeSellMessage(ExchangeSellMessage),
+    ExchangeSellOkMessage(ExchangeSellOkMessage),
+    ExchangeSetCraftRecipeMessage(ExchangeSetCraftRecipeMessage),
+    ExchangeStartOkCraftMessage(ExchangeStartOkCraftMessage),
+    ExchangeStartOkCraftWithInformationMessage(ExchangeStartOkCraftWithInformationMessage),
+    ExchangeStartOkEvolutiveObjectRecycleTradeMessage(ExchangeStartOkEvolutiveObjectRecycleTradeMessage),
+    ExchangeStartOkJobIndexMessage(ExchangeStartOkJobIndexMessage),
+    ExchangeStartOkMountMessage(ExchangeStartOkMountMessage),
+    ExchangeStartOkMountWithOutPaddockMessage(ExchangeStartOkMountWithOutPaddockMessage),
+    ExchangeStartOkMulticraftCrafterMessage(ExchangeStartOkMulticraftCrafterMessage),
+    ExchangeStartOkMulticraftCustomerMessage(ExchangeStartOkMulticraftCustomerMessage),
+    ExchangeStartOkNpcShopMessage(ExchangeStartOkNpcShopMessage),
+    ExchangeStartOkNpcTradeMessage(ExchangeStartOkNpcTradeMessage),
+    ExchangeStartOkRecycleTradeMessage(ExchangeStartOkRecycleTradeMessage),
+    ExchangeStartOkRunesTradeMessage(ExchangeStartOkRunesTradeMessage),
+    ExchangeStartedBidBuyerMessage(ExchangeStartedBidBuyerMessage),
+    ExchangeStartedBidSellerMessage(ExchangeStartedBidSellerMessage),
+    ExchangeStartedMessage(ExchangeStartedMessage),
+    ExchangeStartedMountStockMessage(ExchangeStartedMountStockMessage),
+    ExchangeStartedTaxCollectorEquipmentMessage(ExchangeStartedTaxCollectorEquipmentMessage),
+    ExchangeStartedTaxCollectorShopMessage(ExchangeStartedTaxCollectorShopMessage),
+    ExchangeStartedWithMultiTabStorageMessage(ExchangeStartedWithMultiTabStorageMessage),
+    ExchangeStartedWithPodsMessage(ExchangeStartedWithPodsMessage),
+    ExchangeStartedWithStorageMessage(ExchangeStartedWithStorageMessage),
+    ExchangeStoppedMessage(ExchangeStoppedMessage),
+    ExchangeTaxCollectorGetMessage(ExchangeTaxCollectorGetMessage),
+    ExchangeTypesExchangerDescriptionForUserMessage(ExchangeTypesExchangerDescriptionForUserMessage),
+    ExchangeTypesItemsExchangerDescriptionForUserMessage(ExchangeTypesItemsExchangerDescriptionForUserMessage),
+    ExchangeWaitingResultMessage(ExchangeWaitingResultMessage),
+    ExchangeWeightMessage(ExchangeWeightMessage),
+    ExitHavenBagRequestMessage(ExitHavenBagRequestMessage),
+    FighterStatsListMessage(FighterStatsListMessage),
+    FinishMoveListMessage(FinishMoveListMessage),
+    FinishMoveListRequestMessage(FinishMoveListRequestMessage),
+    FinishMoveSetRequestMessage(FinishMoveSetRequestMessage),
+    FocusedExchangeReadyMessage(FocusedExchangeReadyMessage),
+    FollowQuestObjectiveRequestMessage(FollowQuestObjectiveRequestMessage),
+    FollowedQuestsMessage(FollowedQuestsMessage),
+    ForceAccountErrorMessage(ForceAccountErrorMessage),
+    ForceAccountMessage(ForceAccountMessage),
+    ForceAccountStatusMessage(ForceAccountStatusMessage),
+    ForgettableSpellClientActionMessage(ForgettableSpellClientActionMessage),
+    ForgettableSpellDeleteMessage(ForgettableSpellDeleteMessage),
+    ForgettableSpellEquipmentSlotsMessage(ForgettableSpellEquipmentSlotsMessage),
+    ForgettableSpellListUpdateMessage(ForgettableSpellListUpdateMessage),
+    FriendAddFailureMessage(FriendAddFailureMessage),
+    FriendAddRequestMessage(FriendAddRequestMessage),
+    FriendAddedMessage(FriendAddedMessage),
+    FriendDeleteRequestMessage(FriendDeleteRequestMessage),
+    FriendDeleteResultMessage(FriendDeleteResultMessage),
+    FriendGuildSetWarnOnAchievementCompleteMessage(FriendGuildSetWarnOnAchievementCompleteMessage),
+    FriendGuildWarnOnAchievementCompleteStateMessage(FriendGuildWarnOnAchievementCompleteStateMessage),
+    FriendJoinRequestMessage(FriendJoinRequestMessage),
+    FriendSetStatusShareMessage(FriendSetStatusShareMessage),
+    FriendSetWarnOnConnectionMessage(FriendSetWarnOnConnectionMessage),
+    FriendSetWarnOnLevelGainMessage(FriendSetWarnOnLevelGainMessage),
+    FriendSpouseFollowWithCompassRequestMessage(FriendSpouseFollowWithCompassRequestMessage),
+    FriendSpouseJoinRequestMessage(FriendSpouseJoinRequestMessage),
+    FriendStatusShareStateMessage(FriendStatusShareStateMessage),
+    FriendUpdateMessage(FriendUpdateMessage),
+    FriendWarnOnConnectionStateMessage(FriendWarnOnConnectionStateMessage),
+    FriendWarnOnLevelGainStateMessage(FriendWarnOnLevelGainStateMessage),
+    FriendsGetListMessage(FriendsGetListMessage),
+    FriendsListMessage(FriendsListMessage),
+    GameActionAcknowledgementMessage(GameActionAcknowledgementMessage),
+    GameActionFightActivateGlyphTrapMessage(GameActionFightActivateGlyphTrapMessage),
+    GameActionFightCarryCharacterMessage(GameActionFightCarryCharacterMessage),
+    GameActionFightCastOnTargetRequestMessage(GameActionFightCastOnTargetRequestMessage),
+    GameActionFightCastRequestMessage(GameActionFightCastRequestMessage),
+    GameActionFightChangeLookMessage(GameActionFightChangeLookMessage),
+    GameActionFightCloseCombatMessage(GameActionFightCloseCombatMessage),
+    GameActionFightDeathMessage(GameActionFightDeathMessage),
+    GameActionFightDispellEffectMessage(GameActionFightDispellEffectMessage),
+    GameActionFightDispellMessage(GameActionFightDispellMessage),
+    GameActionFightDispellSpellMessage(GameActionFightDispellSpellMessage),
+    GameActionFightDispellableEffectMessage(GameActionFightDispellableEffectMessage),
+    GameActionFightDodgePointLossMessage(GameActionFightDodgePointLossMessage),
+    GameActionFightDropCharacterMessage(GameActionFightDropCharacterMessage),
+    GameActionFightExchangePositionsMessage(GameActionFightExchangePositionsMessage),
+    GameActionFightInvisibilityMessage(GameActionFightInvisibilityMessage),
+    GameActionFightInvisibleDetectedMessage(GameActionFightInvisibleDetectedMessage),
+    GameActionFightKillMessage(GameActionFightKillMessage),
+    GameActionFightLifeAndShieldPointsLostMessage(GameActionFightLifeAndShieldPointsLostMessage),
+    GameActionFightLifePointsGainMessage(GameActionFightLifePointsGainMessage),
+    GameActionFightLifePointsLostMessage(GameActionFightLifePointsLostMessage),
+    GameActionFightMarkCellsMessage(GameActionFightMarkCellsMessage),
+    GameActionFightModifyEffectsDurationMessage(GameActionFightModifyEffectsDurationMessage),
+    GameActionFightMultipleSummonMessage(GameActionFightMultipleSummonMessage),
+    GameActionFightNoSpellCastMessage(GameActionFightNoSpellCastMessage),
+    GameActionFightPointsVariationMessage(GameActionFightPointsVariationMessage),
+    GameActionFightReduceDamagesMessage(GameActionFightReduceDamagesMessage),
+    GameActionFightReflectDamagesMessage(GameActionFightReflectDamagesMessage),
+    GameActionFightReflectSpellMessage(GameActionFightReflectSpellMessage),
+    GameActionFightSlideMessage(GameActionFightSlideMessage),
+    GameActionFightSpellCastMessage(GameActionFightSpellCastMessage),
+    GameActionFightSpellCooldownVariationMessage(GameActionFightSpellCooldownVariationMessage),
+    GameActionFightSpellImmunityMessage(GameActionFightSpellImmunityMessage),
+    GameActionFightStealKamaMessage(GameActionFightStealKamaMessage),
+    GameActionFightSummonMessage(GameActionFightSummonMessage),
+    GameActionFightTackledMessage(GameActionFightTackledMessage),
+    GameActionFightTeleportOnSameMapMessage(GameActionFightTeleportOnSameMapMessage),
+    GameActionFightThrowCharacterMessage(GameActionFightThrowCharacterMessage),
+    GameActionFightTriggerEffectMessage(GameActionFightTriggerEffectMessage),
+    GameActionFightTriggerGlyphTrapMessage(GameActionFightTriggerGlyphTrapMessage),
+    GameActionFightUnmarkCellsMessage(GameActionFightUnmarkCellsMessage),
+    GameActionFightVanishMessage(GameActionFightVanishMessage),
+    GameActionItemAddMessage(GameActionItemAddMessage),
+    GameActionItemConsumedMessage(GameActionItemConsumedMessage),
+    GameActionItemListMessage(GameActionItemListMessage),
+    GameActionNoopMessage(GameActionNoopMessage),
+    GameActionSpamMessage(GameActionSpamMessage),
+    GameActionUpdateEffectTriggerCountMessage(GameActionUpdateEffectTriggerCountMessage),
+    GameCautiousMapMovementMessage(GameCautiousMapMovementMessage),
+    GameCautiousMapMovementRequestMessage(GameCautiousMapMovementRequestMessage),
+    GameContextCreateErrorMessage(GameContextCreateErrorMessage),
+    GameContextCreateMessage(GameContextCreateMessage),
+    GameContextCreateRequestMessage(GameContextCreateRequestMessage),
+    GameContextDestroyMessage(GameContextDestroyMessage),
+    GameContextKickMessage(GameContextKickMessage),
+    GameContextMoveElementMessage(GameContextMoveElementMessage),
+    GameContextMoveMultipleElementsMessage(GameContextMoveMultipleElementsMessage),
+    GameContextQuitMessage(GameContextQuitMessage),
+    GameContextReadyMessage(GameContextReadyMessage),
+    GameContextRefreshEntityLookMessage(GameContextRefreshEntityLookMessage),
+    GameContextRemoveElementMessage(GameContextRemoveElementMessage),
+    GameContextRemoveElementWithEventMessage(GameContextRemoveElementWithEventMessage),
+    GameContextRemoveMultipleElementsMessage(GameContextRemoveMultipleElementsMessage),
+    GameContextRemoveMultipleElementsWithEventsMessage(GameContextRemoveMultipleElementsWithEventsMessage),
+    GameDataPaddockObjectAddMessage(GameDataPaddockObjectAddMessage),
+    GameDataPaddockObjectListAddMessage(GameDataPaddockObjectListAddMessage),
+    GameDataPaddockObjectRemoveMessage(GameDataPaddockObjectRemoveMessage),
+    GameDataPlayFarmObjectAnimationMessage(GameDataPlayFarmObjectAnimationMessage),
+    GameEntitiesDispositionMessage(GameEntitiesDispositionMessage),
+    GameEntityDispositionErrorMessage(GameEntityDispositionErrorMessage),
+    GameEntityDispositionMessage(GameEntityDispositionMessage),
+    GameFightEndMessage(GameFightEndMessage),
+    GameFightHumanReadyStateMessage(GameFightHumanReadyStateMessage),
+    GameFightJoinMessage(GameFightJoinMessage),
+    GameFightJoinRequestMessage(GameFightJoinRequestMessage),
+    GameFightLeaveMessage(GameFightLeaveMessage),
+    GameFightNewRoundMessage(GameFightNewRoundMessage),
+    GameFightNewWaveMessage(GameFightNewWaveMessage),
+    GameFightOptionStateUpdateMessage(GameFightOptionStateUpdateMessage),
+    GameFightOptionToggleMessage(GameFightOptionToggleMessage),
+    GameFightPauseMessage(GameFightPauseMessage),
+    GameFightPlacementPositionRequestMessage(GameFightPlacementPositionRequestMessage),
+    GameFightPlacementPossiblePositionsMessage(GameFightPlacementPossiblePositionsMessage),
+    GameFightPlacementSwapPositionsAcceptMessage(GameFightPlacementSwapPositionsAcceptMessage),
+    GameFightPlacementSwapPositionsCancelMessage(GameFightPlacementSwapPositionsCancelMessage),
+    GameFightPlacementSwapPositionsCancelledMessage(GameFightPlacementSwapPositionsCancelledMessage),
+    GameFightPlacementSwapPositionsErrorMessage(GameFightPlacementSwapPositionsErrorMessage),
+    GameFightPlacementSwapPositionsMessage(GameFightPlacementSwapPositionsMessage),
+    GameFightPlacementSwapPositionsOfferMessage(GameFightPlacementSwapPositionsOfferMessage),
+    GameFightPlacementSwapPositionsRequestMessage(GameFightPlacementSwapPositionsRequestMessage),
+    GameFightReadyMessage(GameFightReadyMessage),
+    GameFightRefreshFighterMessage(GameFightRefreshFighterMessage),
+    GameFightRemoveTeamMemberMessage(GameFightRemoveTeamMemberMessage),
+    GameFightResumeMessage(GameFightResumeMessage),
+    GameFightResumeWithSlavesMessage(GameFightResumeWithSlavesMessage),
+    GameFightShowFighterMessage(GameFightShowFighterMessage),
+    GameFightShowFighterRandomStaticPoseMessage(GameFightShowFighterRandomStaticPoseMessage),
+    GameFightSpectateMessage(GameFightSpectateMessage),
+    GameFightSpectatePlayerRequestMessage(GameFightSpectatePlayerRequestMessage),
+    GameFightSpectatorJoinMessage(GameFightSpectatorJoinMessage),
+    GameFightStartMessage(GameFightStartMessage),
+    GameFightStartingMessage(GameFightStartingMessage),
+    GameFightSynchronizeMessage(GameFightSynchronizeMessage),
+    GameFightTurnEndMessage(GameFightTurnEndMessage),
+    GameFightTurnFinishMessage(GameFightTurnFinishMessage),
+    GameFightTurnListMessage(GameFightTurnListMessage),
+    GameFightTurnReadyMessage(GameFightTurnReadyMessage),
+    GameFightTurnReadyRequestMessage(GameFightTurnReadyRequestMessage),
+    GameFightTurnResumeMessage(GameFightTurnResumeMessage),
+    GameFightTurnStartMessage(GameFightTurnStartMessage),
+    GameFightTurnStartPlayingMessage(GameFightTurnStartPlayingMessage),
+    GameFightUpdateTeamMessage(GameFightUpdateTeamMessage),
+    GameMapChangeOrientationMessage(GameMapChangeOrientationMessage),
+    GameMapChangeOrientationRequestMessage(GameMapChangeOrientationRequestMessage),
+    GameMapChangeOrientationsMessage(GameMapChangeOrientationsMessage),
+    GameMapMovementCancelMessage(GameMapMovementCancelMessage),
+    GameMapMovementConfirmMessage(GameMapMovementConfirmMessage),
+    GameMapMovementMessage(GameMapMovementMessage),
+    GameMapMovementRequestMessage(GameMapMovementRequestMessage),
+    GameMapNoMovementMessage(GameMapNoMovementMessage),
+    GameRefreshMonsterBoostsMessage(GameRefreshMonsterBoostsMessage),
+    GameRolePlayAggressionMessage(GameRolePlayAggressionMessage),
+    GameRolePlayArenaFightAnswerMessage(GameRolePlayArenaFightAnswerMessage),
+    GameRolePlayArenaFightPropositionMessage(GameRolePlayArenaFightPropositionMessage),
+    GameRolePlayArenaFighterStatusMessage(GameRolePlayArenaFighterStatusMessage),
+    GameRolePlayArenaInvitationCandidatesAnswerMessage(GameRolePlayArenaInvitationCandidatesAnswerMessage),
+    GameRolePlayArenaLeagueRewardsMessage(GameRolePlayArenaLeagueRewardsMessage),
+    GameRolePlayArenaPlayerBehavioursMessage(GameRolePlayArenaPlayerBehavioursMessage),
+    GameRolePlayArenaRegisterMessage(GameRolePlayArenaRegisterMessage),
+    GameRolePlayArenaRegistrationStatusMessage(GameRolePlayArenaRegistrationStatusMessage),
+    GameRolePlayArenaRegistrationWarningMessage(GameRolePlayArenaRegistrationWarningMessage),
+    GameRolePlayArenaSwitchToFightServerMessage(GameRolePlayArenaSwitchToFightServerMessage),
+    GameRolePlayArenaSwitchToGameServerMessage(GameRolePlayArenaSwitchToGameServerMessage),
+    GameRolePlayArenaUnregisterMessage(GameRolePlayArenaUnregisterMessage),
+    GameRolePlayArenaUpdatePlayerInfosMessage(GameRolePlayArenaUpdatePlayerInfosMessage),
+    GameRolePlayAttackMonsterRequestMessage(GameRolePlayAttackMonsterRequestMessage),
+    GameRolePlayDelayedActionFinishedMessage(GameRolePlayDelayedActionFinishedMessage),
+    GameRolePlayDelayedActionMessage(GameRolePlayDelayedActionMessage),
+    GameRolePlayDelayedObjectUseMessage(GameRolePlayDelayedObjectUseMessage),
+    GameRolePlayFightRequestCanceledMessage(GameRolePlayFightRequestCanceledMessage),
+    GameRolePlayFreeSoulRequestMessage(GameRolePlayFreeSoulRequestMessage),
+    GameRolePlayGameOverMessage(GameRolePlayGameOverMessage),
+    GameRolePlayMonsterAngryAtPlayerMessage(GameRolePlayMonsterAngryAtPlayerMessage),
+    GameRolePlayMonsterNotAngryAtPlayerMessage(GameRolePlayMonsterNotAngryAtPlayerMessage),
+    GameRolePlayPlayerFightFriendlyAnswerMessage(GameRolePlayPlayerFightFriendlyAnswerMessage),
+    GameRolePlayPlayerFightFriendlyAnsweredMessage(GameRolePlayPlayerFightFriendlyAnsweredMessage),
+    GameRolePlayPlayerFightFriendlyRequestedMessage(GameRolePlayPlayerFightFriendlyRequestedMessage),
+    GameRolePlayPlayerFightRequestMessage(GameRolePlayPlayerFightRequestMessage),
+    GameRolePlayPlayerLifeStatusMessage(GameRolePlayPlayerLifeStatusMessage),
+    GameRolePlayRemoveChallengeMessage(GameRolePlayRemoveChallengeMessage),
+    GameRolePlayShowActorMessage(GameRolePlayShowActorMessage),
+    GameRolePlayShowActorWithEventMessage(GameRolePlayShowActorWithEventMessage),
+    GameRolePlayShowChallengeMessage(GameRolePlayShowChallengeMessage),
+    GameRolePlayShowMultipleActorsMessage(GameRolePlayShowMultipleActorsMessage),
+    GameRolePlaySpellAnimMessage(GameRolePlaySpellAnimMessage),
+    GameRolePlayTaxCollectorFightRequestMessage(GameRolePlayTaxCollectorFightRequestMessage),
+    GoldAddedMessage(GoldAddedMessage),
+    GroupTeleportPlayerAnswerMessage(GroupTeleportPlayerAnswerMessage),
+    GroupTeleportPlayerCloseMessage(GroupTeleportPlayerCloseMessage),
+    GroupTeleportPlayerOfferMessage(GroupTeleportPlayerOfferMessage),
+    GuestLimitationMessage(GuestLimitationMessage),
+    GuestModeMessage(GuestModeMessage),
+    GuidedModeQuitRequestMessage(GuidedModeQuitRequestMessage),
+    GuidedModeReturnRequestMessage(GuidedModeReturnRequestMessage),
+    GuildApplicationAnswerMessage(GuildApplicationAnswerMessage),
+    GuildApplicationDeletedMessage(GuildApplicationDeletedMessage),
+    GuildApplicationIsAnsweredMessage(GuildApplicationIsAnsweredMessage),
+    GuildApplicationListenMessage(GuildApplicationListenMessage),
+    GuildApplicationPresenceMessage(GuildApplicationPresenceMessage),
+    GuildApplicationReceivedMessage(GuildApplicationReceivedMessage),
+    GuildBulletinMessage(GuildBulletinMessage),
+    GuildBulletinSetErrorMessage(GuildBulletinSetErrorMessage),
+    GuildBulletinSetRequestMessage(GuildBulletinSetRequestMessage),
+    GuildChangeMemberParametersMessage(GuildChangeMemberParametersMessage),
+    GuildCharacsUpgradeRequestMessage(GuildCharacsUpgradeRequestMessage),
+    GuildChestTabContributionMessage(GuildChestTabContributionMessage),
+    GuildChestTabContributionsMessage(GuildChestTabContributionsMessage),
+    GuildChestTabLastContributionMessage(GuildChestTabLastContributionMessage),
+    GuildCreationResultMessage(GuildCreationResultMessage),
+    GuildCreationStartedMessage(GuildCreationStartedMessage),
+    GuildCreationValidMessage(GuildCreationValidMessage),
+    GuildDeleteApplicationRequestMessage(GuildDeleteApplicationRequestMessage),
+    GuildFactsErrorMessage(GuildFactsErrorMessage),
+    GuildFactsMessage(GuildFactsMessage),
+    GuildFactsRequestMessage(GuildFactsRequestMessage),
+    GuildGetChestTabContributionsRequestMessage(GuildGetChestTabContributionsRequestMessage),
+    GuildGetInformationsMessage(GuildGetInformationsMessage),
+    GuildGetPlayerApplicationMessage(GuildGetPlayerApplicationMessage),
+    GuildHouseRemoveMessage(GuildHouseRemoveMessage),
+    GuildHouseUpdateInformationMessage(GuildHouseUpdateInformationMessage),
+    GuildHousesInformationMessage(GuildHousesInformationMessage),
+    GuildInformationsGeneralMessage(GuildInformationsGeneralMessage),
+    GuildInformationsMemberUpdateMessage(GuildInformationsMemberUpdateMessage),
+    GuildInformationsMembersMessage(GuildInformationsMembersMessage),
+    GuildInformationsPaddocksMessage(GuildInformationsPaddocksMessage),
+    GuildInvitationAnswerMessage(GuildInvitationAnswerMessage),
+    GuildInvitationMessage(GuildInvitationMessage),
+    GuildInvitationStateRecrutedMessage(GuildInvitationStateRecrutedMessage),
+    GuildInvitationStateRecruterMessage(GuildInvitationStateRecruterMessage),
+    GuildInvitedMessage(GuildInvitedMessage),
+    GuildIsThereAnyApplicationMessage(GuildIsThereAnyApplicationMessage),
+    GuildJoinAutomaticallyRequestMessage(GuildJoinAutomaticallyRequestMessage),
+    GuildJoinedMessage(GuildJoinedMessage),
+    GuildKickRequestMessage(GuildKickRequestMessage),
+    GuildLeftMessage(GuildLeftMessage),
+    GuildLevelUpMessage(GuildLevelUpMessage),
+    GuildListApplicationAnswerMessage(GuildListApplicationAnswerMessage),
+    GuildListApplicationModifiedMessage(GuildListApplicationModifiedMessage),
+    GuildListApplicationRequestMessage(GuildListApplicationRequestMessage),
+    GuildListMessage(GuildListMessage),
+    GuildLogbookInformationMessage(GuildLogbookInformationMessage),
+    GuildLogbookInformationRequestMessage(GuildLogbookInformationRequestMessage),
+    GuildMemberLeavingMessage(GuildMemberLeavingMessage),
+    GuildMemberOnlineStatusMessage(GuildMemberOnlineStatusMessage),
+    GuildMemberStartWarnOnConnectionMessage(GuildMemberStartWarnOnConnectionMessage),
+    GuildMemberStopWarnOnConnectionMessage(GuildMemberStopWarnOnConnectionMessage),
+    GuildMembershipMessage(GuildMembershipMessage),
+    GuildModificationEmblemValidMessage(GuildModificationEmblemValidMessage),
+    GuildModificationNameValidMessage(GuildModificationNameValidMessage),
+    GuildModificationResultMessage(GuildModificationResultMessage),
+    GuildModificationStartedMessage(GuildModificationStartedMessage),
+    GuildModificationValidMessage(GuildModificationValidMessage),
+    GuildMotdMessage(GuildMotdMessage),
+    GuildMotdSetErrorMessage(GuildMotdSetErrorMessage),
+    GuildMotdSetRequestMessage(GuildMotdSetRequestMessage),
+    GuildPaddockBoughtMessage(GuildPaddockBoughtMessage),
+    GuildPaddockRemovedMessage(GuildPaddockRemovedMessage),
+    GuildPaddockTeleportRequestMessage(GuildPaddockTeleportRequestMessage),
+    GuildPlayerApplicationAbstractMessage(GuildPlayerApplicationAbstractMessage),
+    GuildPlayerApplicationInformationMessage(GuildPlayerApplicationInformationMessage),
+    GuildPlayerNoApplicationInformationMessage(GuildPlayerNoApplicationInformationMessage),
+    GuildRanksMessage(GuildRanksMessage),
+    GuildRanksRequestMessage(GuildRanksRequestMessage),
+    GuildRecruitmentInvalidateMessage(GuildRecruitmentInvalidateMessage),
+    GuildSelectChestTabRequestMessage(GuildSelectChestTabRequestMessage),
+    GuildSpellUpgradeRequestMessage(GuildSpellUpgradeRequestMessage),
+    GuildSubmitApplicationMessage(GuildSubmitApplicationMessage),
+    GuildSummaryMessage(GuildSummaryMessage),
+    GuildSummaryRequestMessage(GuildSummaryRequestMessage),
+    GuildUpdateApplicationMessage(GuildUpdateApplicationMessage),
+    GuildUpdateChestTabRequestMessage(GuildUpdateChestTabRequestMessage),
+    GuildUpdateNoteMessage(GuildUpdateNoteMessage),
+    HaapiApiKeyMessage(HaapiApiKeyMessage),
+    HaapiApiKeyRequestMessage(HaapiApiKeyRequestMessage),
+    HaapiAuthErrorMessage(HaapiAuthErrorMessage),
+    HaapiBufferListMessage(HaapiBufferListMessage),
+    HaapiBufferListRequestMessage(HaapiBufferListRequestMessage),
+    HaapiBuyValidationMessage(HaapiBuyValidationMessage),
+    HaapiCancelBidRequestMessage(HaapiCancelBidRequestMessage),
+    HaapiConfirmationMessage(HaapiConfirmationMessage),
+    HaapiConfirmationRequestMessage(HaapiConfirmationRequestMessage),
+    HaapiConsumeBufferRequestMessage(HaapiConsumeBufferRequestMessage),
+    HaapiSessionMessage(HaapiSessionMessage),
+    HaapiShopApiKeyMessage(HaapiShopApiKeyMessage),
+    HaapiShopApiKeyRequestMessage(HaapiShopApiKeyRequestMessage),
+    HaapiTokenMessage(HaapiTokenMessage),
+    HaapiTokenRequestMessage(HaapiTokenRequestMessage),
+    HaapiValidationMessage(HaapiValidationMessage),
+    HaapiValidationRequestMessage(HaapiValidationRequestMessage),
+    HavenBagDailyLoteryMessage(HavenBagDailyLoteryMessage),
+    HavenBagFurnituresMessage(HavenBagFurnituresMessage),
+    HavenBagFurnituresRequestMessage(HavenBagFurnituresRequestMessage),
+    HavenBagPackListMessage(HavenBagPackListMessage),
+    HavenBagPermissionsUpdateMessage(HavenBagPermissionsUpdateMessage),
+    HavenBagPermissionsUpdateRequestMessage(HavenBagPermissionsUpdateRequestMessage),
+    HavenBagRoomUpdateMessage(HavenBagRoomUpdateMessage),
+    HelloConnectMessage(HelloConnectMessage),
+    HelloGameMessage(HelloGameMessage),
+    HouseBuyRequestMessage(HouseBuyRequestMessage),
+    HouseBuyResultMessage(HouseBuyResultMessage),
+    HouseGuildNoneMessage(HouseGuildNoneMessage),
+    HouseGuildRightsMessage(HouseGuildRightsMessage),
+    HouseGuildRightsViewMessage(HouseGuildRightsViewMessage),
+    HouseGuildShareRequestMessage(HouseGuildShareRequestMessage),
+    HouseKickRequestMessage(HouseKickRequestMessage),
+    HouseLockFromInsideRequestMessage(HouseLockFromInsideRequestMessage),
+    HousePropertiesMessage(HousePropertiesMessage),
+    HouseSellFromInsideRequestMessage(HouseSellFromInsideRequestMessage),
+    HouseSellRequestMessage(HouseSellRequestMessage),
+    HouseSellingUpdateMessage(HouseSellingUpdateMessage),
+    HouseTeleportRequestMessage(HouseTeleportRequestMessage),
+    HouseToSellFilterMessage(HouseToSellFilterMessage),
+    HouseToSellListMessage(HouseToSellListMessage),
+    HouseToSellListRequestMessage(HouseToSellListRequestMessage),
+    IconNamedPresetSaveRequestMessage(IconNamedPresetSaveRequestMessage),
+    IconPresetSaveRequestMessage(IconPresetSaveRequestMessage),
+    IdentificationFailedBannedMessage(IdentificationFailedBannedMessage),
+    IdentificationFailedForBadVersionMessage(IdentificationFailedForBadVersionMessage),
+    IdentificationFailedMessage(IdentificationFailedMessage),
+    IdentificationMessage(IdentificationMessage),
+    IdentificationSuccessMessage(IdentificationSuccessMessage),
+    IdentificationSuccessWithLoginTokenMessage(IdentificationSuccessWithLoginTokenMessage),
+    IgnoredAddFailureMessage(IgnoredAddFailureMessage),
+    IgnoredAddRequestMessage(IgnoredAddRequestMessage),
+    IgnoredAddedMessage(IgnoredAddedMessage),
+    IgnoredDeleteRequestMessage(IgnoredDeleteRequestMessage),
+    IgnoredDeleteResultMessage(IgnoredDeleteResultMessage),
+    IgnoredGetListMessage(IgnoredGetListMessage),
+    IgnoredListMessage(IgnoredListMessage),
+    InteractiveElementUpdatedMessage(InteractiveElementUpdatedMessage),
+    InteractiveMapUpdateMessage(InteractiveMapUpdateMessage),
+    InteractiveUseEndedMessage(InteractiveUseEndedMessage),
+    InteractiveUseErrorMessage(InteractiveUseErrorMessage),
+    InteractiveUseRequestMessage(InteractiveUseRequestMessage),
+    InteractiveUseWithParamRequestMessage(InteractiveUseWithParamRequestMessage),
+    InteractiveUsedMessage(InteractiveUsedMessage),
+    InvalidPresetsMessage(InvalidPresetsMessage),
+    InventoryContentMessage(InventoryContentMessage),
+    InventoryWeightMessage(InventoryWeightMessage),
+    InviteInHavenBagClosedMessage(InviteInHavenBagClosedMessage),
+    InviteInHavenBagMessage(InviteInHavenBagMessage),
+    InviteInHavenBagOfferMessage(InviteInHavenBagOfferMessage),
+    ItemForPresetUpdateMessage(ItemForPresetUpdateMessage),
+    ItemNoMoreAvailableMessage(ItemNoMoreAvailableMessage),
+    JobAllowMultiCraftRequestMessage(JobAllowMultiCraftRequestMessage),
+    JobBookSubscribeRequestMessage(JobBookSubscribeRequestMessage),
+    JobBookSubscriptionMessage(JobBookSubscriptionMessage),
+    JobCrafterDirectoryAddMessage(JobCrafterDirectoryAddMessage),
+    JobCrafterDirectoryDefineSettingsMessage(JobCrafterDirectoryDefineSettingsMessage),
+    JobCrafterDirectoryEntryMessage(JobCrafterDirectoryEntryMessage),
+    JobCrafterDirectoryEntryRequestMessage(JobCrafterDirectoryEntryRequestMessage),
+    JobCrafterDirectoryListMessage(JobCrafterDirectoryListMessage),
+    JobCrafterDirectoryListRequestMessage(JobCrafterDirectoryListRequestMessage),
+    JobCrafterDirectoryRemoveMessage(JobCrafterDirectoryRemoveMessage),
+    JobCrafterDirectorySettingsMessage(JobCrafterDirectorySettingsMessage),
+    JobDescriptionMessage(JobDescriptionMessage),
+    JobExperienceMultiUpdateMessage(JobExperienceMultiUpdateMessage),
+    JobExperienceOtherPlayerUpdateMessage(JobExperienceOtherPlayerUpdateMessage),
+    JobExperienceUpdateMessage(JobExperienceUpdateMessage),
+    JobLevelUpMessage(JobLevelUpMessage),
+    JobMultiCraftAvailableSkillsMessage(JobMultiCraftAvailableSkillsMessage),
+    KamasUpdateMessage(KamasUpdateMessage),
+    KickHavenBagRequestMessage(KickHavenBagRequestMessage),
+    KnownZaapListMessage(KnownZaapListMessage),
+    KohUpdateMessage(KohUpdateMessage),
+    KothEndMessage(KothEndMessage),
+    LeaveDialogMessage(LeaveDialogMessage),
+    LeaveDialogRequestMessage(LeaveDialogRequestMessage),
+    LifePointsRegenBeginMessage(LifePointsRegenBeginMessage),
+    LifePointsRegenEndMessage(LifePointsRegenEndMessage),
+    ListMapNpcsQuestStatusUpdateMessage(ListMapNpcsQuestStatusUpdateMessage),
+    ListenersOfSynchronizedStorageMessage(ListenersOfSynchronizedStorageMessage),
+    LivingObjectChangeSkinRequestMessage(LivingObjectChangeSkinRequestMessage),
+    LivingObjectDissociateMessage(LivingObjectDissociateMessage),
+    LivingObjectMessageMessage(LivingObjectMessageMessage),
+    LivingObjectMessageRequestMessage(LivingObjectMessageRequestMessage),
+    LocalizedChatSmileyMessage(LocalizedChatSmileyMessage),
+    LockableChangeCodeMessage(LockableChangeCodeMessage),
+    LockableCodeResultMessage(LockableCodeResultMessage),
+    LockableShowCodeDialogMessage(LockableShowCodeDialogMessage),
+    LockableStateUpdateAbstractMessage(LockableStateUpdateAbstractMessage),
+    LockableStateUpdateHouseDoorMessage(LockableStateUpdateHouseDoorMessage),
+    LockableStateUpdateStorageMessage(LockableStateUpdateStorageMessage),
+    LockableUseCodeMessage(LockableUseCodeMessage),
+    LoginQueueStatusMessage(LoginQueueStatusMessage),
+    MapComplementaryInformationsAnomalyMessage(MapComplementaryInformationsAnomalyMessage),
+    MapComplementaryInformationsBreachMessage(MapComplementaryInformationsBreachMessage),
+    MapComplementaryInformationsDataInHavenBagMessage(MapComplementaryInformationsDataInHavenBagMessage),
+    MapComplementaryInformationsDataInHouseMessage(MapComplementaryInformationsDataInHouseMessage),
+    MapComplementaryInformationsDataMessage(MapComplementaryInformationsDataMessage),
+    MapComplementaryInformationsWithCoordsMessage(MapComplementaryInformationsWithCoordsMessage),
+    MapFightCountMessage(MapFightCountMessage),
+    MapFightStartPositionsUpdateMessage(MapFightStartPositionsUpdateMessage),
+    MapInformationsRequestMessage(MapInformationsRequestMessage),
+    MapObstacleUpdateMessage(MapObstacleUpdateMessage),
+    MapRunningFightDetailsExtendedMessage(MapRunningFightDetailsExtendedMessage),
+    MapRunningFightDetailsMessage(MapRunningFightDetailsMessage),
+    MapRunningFightDetailsRequestMessage(MapRunningFightDetailsRequestMessage),
+    MapRunningFightListMessage(MapRunningFightListMessage),
+    MapRunningFightListRequestMessage(MapRunningFightListRequestMessage),
+    MigratedServerListMessage(MigratedServerListMessage),
+    MimicryObjectAssociatedMessage(MimicryObjectAssociatedMessage),
+    MimicryObjectEraseRequestMessage(MimicryObjectEraseRequestMessage),
+    MimicryObjectErrorMessage(MimicryObjectErrorMessage),
+    MimicryObjectFeedAndAssociateRequestMessage(MimicryObjectFeedAndAssociateRequestMessage),
+    MimicryObjectPreviewMessage(MimicryObjectPreviewMessage),
+    MoodSmileyRequestMessage(MoodSmileyRequestMessage),
+    MoodSmileyResultMessage(MoodSmileyResultMessage),
+    MoodSmileyUpdateMessage(MoodSmileyUpdateMessage),
+    MountDataErrorMessage(MountDataErrorMessage),
+    MountDataMessage(MountDataMessage),
+    MountEmoteIconUsedOkMessage(MountEmoteIconUsedOkMessage),
+    MountEquipedErrorMessage(MountEquipedErrorMessage),
+    MountFeedRequestMessage(MountFeedRequestMessage),
+    MountHarnessColorsUpdateRequestMessage(MountHarnessColorsUpdateRequestMessage),
+    MountHarnessDissociateRequestMessage(MountHarnessDissociateRequestMessage),
+    MountInformationInPaddockRequestMessage(MountInformationInPaddockRequestMessage),
+    MountInformationRequestMessage(MountInformationRequestMessage),
+    MountReleaseRequestMessage(MountReleaseRequestMessage),
+    MountReleasedMessage(MountReleasedMessage),
+    MountRenameRequestMessage(MountRenameRequestMessage),
+    MountRenamedMessage(MountRenamedMessage),
+    MountRidingMessage(MountRidingMessage),
+    MountSetMessage(MountSetMessage),
+    MountSetXpRatioRequestMessage(MountSetXpRatioRequestMessage),
+    MountSterilizeRequestMessage(MountSterilizeRequestMessage),
+    MountSterilizedMessage(MountSterilizedMessage),
+    MountToggleRidingRequestMessage(MountToggleRidingRequestMessage),
+    MountUnSetMessage(MountUnSetMessage),
+    MountXpRatioMessage(MountXpRatioMessage),
+    MoveTaxCollectorOrderedSpellMessage(MoveTaxCollectorOrderedSpellMessage),
+    MoveTaxCollectorPresetSpellMessage(MoveTaxCollectorPresetSpellMessage),
+    MultiTabStorageMessage(MultiTabStorageMessage),
+    NetworkDataContainerMessage(NetworkDataContainerMessage),
+    NicknameAcceptedMessage(NicknameAcceptedMessage),
+    NicknameChoiceRequestMessage(NicknameChoiceRequestMessage),
+    NicknameRefusedMessage(NicknameRefusedMessage),
+    NicknameRegistrationMessage(NicknameRegistrationMessage),
+    NotificationByServerMessage(NotificationByServerMessage),
+    NotificationListMessage(NotificationListMessage),
+    NotificationResetMessage(NotificationResetMessage),
+    NotificationUpdateFlagMessage(NotificationUpdateFlagMessage),
+    NpcDialogCreationMessage(NpcDialogCreationMessage),
+    NpcDialogQuestionMessage(NpcDialogQuestionMessage),
+    NpcDialogReplyMessage(NpcDialogReplyMessage),
+    NpcGenericActionFailureMessage(NpcGenericActionFailureMessage),
+    NpcGenericActionRequestMessage(NpcGenericActionRequestMessage),
+    NuggetsDistributionMessage(NuggetsDistributionMessage),
+    NuggetsInformationMessage(NuggetsInformationMessage),
+    NumericWhoIsMessage(NumericWhoIsMessage),
+    NumericWhoIsRequestMessage(NumericWhoIsRequestMessage),
+    ObjectAddedMessage(ObjectAddedMessage),
+    ObjectAveragePricesErrorMessage(ObjectAveragePricesErrorMessage),
+    ObjectAveragePricesGetMessage(ObjectAveragePricesGetMessage),
+    ObjectAveragePricesMessage(ObjectAveragePricesMessage),
+    ObjectDeleteMessage(ObjectDeleteMessage),
+    ObjectDeletedMessage(ObjectDeletedMessage),
+    ObjectDropMessage(ObjectDropMessage),
+    ObjectErrorMessage(ObjectErrorMessage),
+    ObjectFeedMessage(ObjectFeedMessage),
+    ObjectGroundAddedMessage(ObjectGroundAddedMessage),
+    ObjectGroundListAddedMessage(ObjectGroundListAddedMessage),
+    ObjectGroundRemovedMessage(ObjectGroundRemovedMessage),
+    ObjectGroundRemovedMultipleMessage(ObjectGroundRemovedMultipleMessage),
+    ObjectJobAddedMessage(ObjectJobAddedMessage),
+    ObjectModifiedMessage(ObjectModifiedMessage),
+    ObjectMovementMessage(ObjectMovementMessage),
+    ObjectQuantityMessage(ObjectQuantityMessage),
+    ObjectSetPositionMessage(ObjectSetPositionMessage),
+    ObjectUseMessage(ObjectUseMessage),
+    ObjectUseMultipleMessage(ObjectUseMultipleMessage),
+    ObjectUseOnCellMessage(ObjectUseOnCellMessage),
+    ObjectUseOnCharacterMessage(ObjectUseOnCharacterMessage),
+    ObjectsAddedMessage(ObjectsAddedMessage),
+    ObjectsDeletedMessage(ObjectsDeletedMessage),
+    ObjectsQuantityMessage(ObjectsQuantityMessage),
+    ObtainedItemMessage(ObtainedItemMessage),
+    ObtainedItemWithBonusMessage(ObtainedItemWithBonusMessage),
+    OnConnectionEventMessage(OnConnectionEventMessage),
+    OpenGuideBookMessage(OpenGuideBookMessage),
+    OpenHavenBagFurnitureSequenceRequestMessage(OpenHavenBagFurnitureSequenceRequestMessage),
+    OpponentSurrenderMessage(OpponentSurrenderMessage),
+    OrnamentGainedMessage(OrnamentGainedMessage),
+    OrnamentLostMessage(OrnamentLostMessage),
+    OrnamentSelectErrorMessage(OrnamentSelectErrorMessage),
+    OrnamentSelectRequestMessage(OrnamentSelectRequestMessage),
+    OrnamentSelectedMessage(OrnamentSelectedMessage),
+    PaddockBuyRequestMessage(PaddockBuyRequestMessage),
+    PaddockBuyResultMessage(PaddockBuyResultMessage),
+    PaddockMoveItemRequestMessage(PaddockMoveItemRequestMessage),
+    PaddockPropertiesMessage(PaddockPropertiesMessage),
+    PaddockRemoveItemRequestMessage(PaddockRemoveItemRequestMessage),
+    PaddockSellBuyDialogMessage(PaddockSellBuyDialogMessage),
+    PaddockSellRequestMessage(PaddockSellRequestMessage),
+    PaddockToSellFilterMessage(PaddockToSellFilterMessage),
+    PaddockToSellListMessage(PaddockToSellListMessage),
+    PaddockToSellListRequestMessage(PaddockToSellListRequestMessage),
+    PaginationAnswerAbstractMessage(PaginationAnswerAbstractMessage),
+    PaginationRequestAbstractMessage(PaginationRequestAbstractMessage),
+    PartyAbdicateThroneMessage(PartyAbdicateThroneMessage),
+    PartyAcceptInvitationMessage(PartyAcceptInvitationMessage),
+    PartyCancelInvitationMessage(PartyCancelInvitationMessage),
+    PartyCancelInvitationNotificationMessage(PartyCancelInvitationNotificationMessage),
+    PartyCannotJoinErrorMessage(PartyCannotJoinErrorMessage),
+    PartyDeletedMessage(PartyDeletedMessage),
+    PartyEntityUpdateLightMessage(PartyEntityUpdateLightMessage),
+    PartyFollowMemberRequestMessage(PartyFollowMemberRequestMessage),
+    PartyFollowStatusUpdateMessage(PartyFollowStatusUpdateMessage),
+    PartyFollowThisMemberRequestMessage(PartyFollowThisMemberRequestMessage),
+    PartyInvitationArenaRequestMessage(PartyInvitationArenaRequestMessage),
+    PartyInvitationCancelledForGuestMessage(PartyInvitationCancelledForGuestMessage),
+    PartyInvitationDetailsMessage(PartyInvitationDetailsMessage),
+    PartyInvitationDetailsRequestMessage(PartyInvitationDetailsRequestMessage),
+    PartyInvitationDungeonDetailsMessage(PartyInvitationDungeonDetailsMessage),
+    PartyInvitationDungeonMessage(PartyInvitationDungeonMessage),
+    PartyInvitationDungeonRequestMessage(PartyInvitationDungeonRequestMessage),
+    PartyInvitationMessage(PartyInvitationMessage),
+    PartyInvitationRequestMessage(PartyInvitationRequestMessage),
+    PartyJoinMessage(PartyJoinMessage),
+    PartyKickRequestMessage(PartyKickRequestMessage),
+    PartyKickedByMessage(PartyKickedByMessage),
+    PartyLeaderUpdateMessage(PartyLeaderUpdateMessage),
+    PartyLeaveMessage(PartyLeaveMessage),
+    PartyLeaveRequestMessage(PartyLeaveRequestMessage),
+    PartyLocateMembersMessage(PartyLocateMembersMessage),
+    PartyLocateMembersRequestMessage(PartyLocateMembersRequestMessage),
+    PartyLoyaltyStatusMessage(PartyLoyaltyStatusMessage),
+    PartyMemberEjectedMessage(PartyMemberEjectedMessage),
+    PartyMemberInBreachFightMessage(PartyMemberInBreachFightMessage),
+    PartyMemberInStandardFightMessage(PartyMemberInStandardFightMessage),
+    PartyMemberRemoveMessage(PartyMemberRemoveMessage),
+    PartyModifiableStatusMessage(PartyModifiableStatusMessage),
+    PartyNameSetErrorMessage(PartyNameSetErrorMessage),
+    PartyNameSetRequestMessage(PartyNameSetRequestMessage),
+    PartyNameUpdateMessage(PartyNameUpdateMessage),
+    PartyNewGuestMessage(PartyNewGuestMessage),
+    PartyNewMemberMessage(PartyNewMemberMessage),
+    PartyPledgeLoyaltyRequestMessage(PartyPledgeLoyaltyRequestMessage),
+    PartyRefuseInvitationMessage(PartyRefuseInvitationMessage),
+    PartyRefuseInvitationNotificationMessage(PartyRefuseInvitationNotificationMessage),
+    PartyRestrictedMessage(PartyRestrictedMessage),
+    PartyStopFollowRequestMessage(PartyStopFollowRequestMessage),
+    PartyUpdateLightMessage(PartyUpdateLightMessage),
+    PartyUpdateMessage(PartyUpdateMessage),
+    PauseDialogMessage(PauseDialogMessage),
+    PlayerStatusUpdateErrorMessage(PlayerStatusUpdateErrorMessage),
+    PlayerStatusUpdateMessage(PlayerStatusUpdateMessage),
+    PlayerStatusUpdateRequestMessage(PlayerStatusUpdateRequestMessage),
+    PopupWarningCloseRequestMessage(PopupWarningCloseRequestMessage),
+    PopupWarningClosedMessage(PopupWarningClosedMessage),
+    PopupWarningMessage(PopupWarningMessage),
+    PortalDialogCreationMessage(PortalDialogCreationMessage),
+    PortalUseRequestMessage(PortalUseRequestMessage),
+    PresetDeleteRequestMessage(PresetDeleteRequestMessage),
+    PresetDeleteResultMessage(PresetDeleteResultMessage),
+    PresetSaveErrorMessage(PresetSaveErrorMessage),
+    PresetSavedMessage(PresetSavedMessage),
+    PresetUseRequestMessage(PresetUseRequestMessage),
+    PresetUseResultMessage(PresetUseResultMessage),
+    PresetUseResultWithMissingIdsMessage(PresetUseResultWithMissingIdsMessage),
+    PresetsMessage(PresetsMessage),
+    PrismAddOrUpdateMessage(PrismAddOrUpdateMessage),
+    PrismAttackRequestMessage(PrismAttackRequestMessage),
+    PrismAttackResultMessage(PrismAttackResultMessage),
+    PrismAttackedMessage(PrismAttackedMessage),
+    PrismExchangeRequestMessage(PrismExchangeRequestMessage),
+    PrismRecycleTradeRequestMessage(PrismRecycleTradeRequestMessage),
+    PrismRemoveMessage(PrismRemoveMessage),
+    PrismTeleportationRequestMessage(PrismTeleportationRequestMessage),
+    PrismsListMessage(PrismsListMessage),
+    ProtocolRequired(ProtocolRequired),
+    PurchasableDialogMessage(PurchasableDialogMessage),
+    QuestListMessage(QuestListMessage),
+    QuestListRequestMessage(QuestListRequestMessage),
+    QuestObjectiveValidatedMessage(QuestObjectiveValidatedMessage),
+    QuestObjectiveValidationMessage(QuestObjectiveValidationMessage),
+    QuestStartRequestMessage(QuestStartRequestMessage),
+    QuestStartedMessage(QuestStartedMessage),
+    QuestStepInfoMessage(QuestStepInfoMessage),
+    QuestStepInfoRequestMessage(QuestStepInfoRequestMessage),
+    QuestStepStartedMessage(QuestStepStartedMessage),
+    QuestStepValidatedMessage(QuestStepValidatedMessage),
+    QuestValidatedMessage(QuestValidatedMessage),
+    QueueStatusMessage(QueueStatusMessage),
+    RawDataMessage(RawDataMessage),
+    RecruitmentInformationMessage(RecruitmentInformationMessage),
+    RecycleResultMessage(RecycleResultMessage),
+    RefreshCharacterStatsMessage(RefreshCharacterStatsMessage),
+    RefreshFollowedQuestsOrderRequestMessage(RefreshFollowedQuestsOrderRequestMessage),
+    ReleaseAccountMessage(ReleaseAccountMessage),
+    ReloginTokenRequestMessage(ReloginTokenRequestMessage),
+    ReloginTokenStatusMessage(ReloginTokenStatusMessage),
+    RemoveGuildRankRequestMessage(RemoveGuildRankRequestMessage),
+    RemoveListenerOnSynchronizedStorageMessage(RemoveListenerOnSynchronizedStorageMessage),
+    RemoveSpellModifierMessage(RemoveSpellModifierMessage),
+    RemoveTaxCollectorOrderedSpellMessage(RemoveTaxCollectorOrderedSpellMessage),
+    RemoveTaxCollectorPresetSpellMessage(RemoveTaxCollectorPresetSpellMessage),
+    ReportRequestMessage(ReportRequestMessage),
+    ReportResponseMessage(ReportResponseMessage),
+    ResetCharacterStatsRequestMessage(ResetCharacterStatsRequestMessage),
+    SelectedServerDataExtendedMessage(SelectedServerDataExtendedMessage),
+    SelectedServerDataMessage(SelectedServerDataMessage),
+    SelectedServerRefusedMessage(SelectedServerRefusedMessage),
+    SequenceEndMessage(SequenceEndMessage),
+    SequenceNumberMessage(SequenceNumberMessage),
+    SequenceNumberRequestMessage(SequenceNumberRequestMessage),
+    SequenceStartMessage(SequenceStartMessage),
+    ServerExperienceModificatorMessage(ServerExperienceModificatorMessage),
+    ServerOptionalFeaturesMessage(ServerOptionalFeaturesMessage),
+    ServerSelectionMessage(ServerSelectionMessage),
+    ServerSessionConstantsMessage(ServerSessionConstantsMessage),
+    ServerSettingsMessage(ServerSettingsMessage),
+    ServerStatusUpdateMessage(ServerStatusUpdateMessage),
+    ServersListMessage(ServersListMessage),
+    SetCharacterRestrictionsMessage(SetCharacterRestrictionsMessage),
+    SetEnableAVARequestMessage(SetEnableAVARequestMessage),
+    SetEnablePVPRequestMessage(SetEnablePVPRequestMessage),
+    SetUpdateMessage(SetUpdateMessage),
+    ShortcutBarAddErrorMessage(ShortcutBarAddErrorMessage),
+    ShortcutBarAddRequestMessage(ShortcutBarAddRequestMessage),
+    ShortcutBarContentMessage(ShortcutBarContentMessage),
+    ShortcutBarRefreshMessage(ShortcutBarRefreshMessage),
+    ShortcutBarRemoveErrorMessage(ShortcutBarRemoveErrorMessage),
+    ShortcutBarRemoveRequestMessage(ShortcutBarRemoveRequestMessage),
+    ShortcutBarRemovedMessage(ShortcutBarRemovedMessage),
+    ShortcutBarReplacedMessage(ShortcutBarReplacedMessage),
+    ShortcutBarSwapErrorMessage(ShortcutBarSwapErrorMessage),
+    ShortcutBarSwapRequestMessage(ShortcutBarSwapRequestMessage),
+    ShowCellMessage(ShowCellMessage),
+    ShowCellRequestMessage(ShowCellRequestMessage),
+    ShowCellSpectatorMessage(ShowCellSpectatorMessage),
+    SlaveNoLongerControledMessage(SlaveNoLongerControledMessage),
+    SlaveSwitchContextMessage(SlaveSwitchContextMessage),
+    SocialFightJoinRequestMessage(SocialFightJoinRequestMessage),
+    SocialFightLeaveRequestMessage(SocialFightLeaveRequestMessage),
+    SocialFightTakePlaceRequestMessage(SocialFightTakePlaceRequestMessage),
+    SocialNoticeMessage(SocialNoticeMessage),
+    SocialNoticeSetErrorMessage(SocialNoticeSetErrorMessage),
+    SocialNoticeSetRequestMessage(SocialNoticeSetRequestMessage),
+    SpellListMessage(SpellListMessage),
+    SpellVariantActivationMessage(SpellVariantActivationMessage),
+    SpellVariantActivationRequestMessage(SpellVariantActivationRequestMessage),
+    SpouseGetInformationsMessage(SpouseGetInformationsMessage),
+    SpouseInformationsMessage(SpouseInformationsMessage),
+    SpouseStatusMessage(SpouseStatusMessage),
+    StartExchangeTaxCollectorEquipmentMessage(StartExchangeTaxCollectorEquipmentMessage),
+    StartGuildChestContributionMessage(StartGuildChestContributionMessage),
+    StartListenAllianceFightMessage(StartListenAllianceFightMessage),
+    StartListenGuildChestStructureMessage(StartListenGuildChestStructureMessage),
+    StartListenNuggetsMessage(StartListenNuggetsMessage),
+    StartListenTaxCollectorPresetsUpdatesMessage(StartListenTaxCollectorPresetsUpdatesMessage),
+    StartListenTaxCollectorUpdatesMessage(StartListenTaxCollectorUpdatesMessage),
+    StatedElementUpdatedMessage(StatedElementUpdatedMessage),
+    StatedMapUpdateMessage(StatedMapUpdateMessage),
+    StatsUpgradeRequestMessage(StatsUpgradeRequestMessage),
+    StatsUpgradeResultMessage(StatsUpgradeResultMessage),
+    StopGuildChestContributionMessage(StopGuildChestContributionMessage),
+    StopListenAllianceFightMessage(StopListenAllianceFightMessage),
+    StopListenGuildChestStructureMessage(StopListenGuildChestStructureMessage),
+    StopListenNuggetsMessage(StopListenNuggetsMessage),
+    StopListenTaxCollectorPresetsUpdatesMessage(StopListenTaxCollectorPresetsUpdatesMessage),
+    StopListenTaxCollectorUpdatesMessage(StopListenTaxCollectorUpdatesMessage),
+    StopToListenRunningFightRequestMessage(StopToListenRunningFightRequestMessage),
+    StorageInventoryContentMessage(StorageInventoryContentMessage),
+    StorageKamasUpdateMessage(StorageKamasUpdateMessage),
+    StorageObjectRemoveMessage(StorageObjectRemoveMessage),
+    StorageObjectUpdateMessage(StorageObjectUpdateMessage),
+    StorageObjectsRemoveMessage(StorageObjectsRemoveMessage),
+    StorageObjectsUpdateMessage(StorageObjectsUpdateMessage),
+    SubareaRewardRateMessage(SubareaRewardRateMessage),
+    SubscriptionLimitationMessage(SubscriptionLimitationMessage),
+    SubscriptionZoneMessage(SubscriptionZoneMessage),
+    SurrenderInfoRequestMessage(SurrenderInfoRequestMessage),
+    SurrenderInfoResponseMessage(SurrenderInfoResponseMessage),
+    SurrenderStateMessage(SurrenderStateMessage),
+    SurrenderVoteCastMessage(SurrenderVoteCastMessage),
+    SurrenderVoteEndMessage(SurrenderVoteEndMessage),
+    SurrenderVoteStartMessage(SurrenderVoteStartMessage),
+    SurrenderVoteUpdateMessage(SurrenderVoteUpdateMessage),
+    SwitchArenaXpRewardsModeMessage(SwitchArenaXpRewardsModeMessage),
+    SymbioticObjectAssociateRequestMessage(SymbioticObjectAssociateRequestMessage),
+    SymbioticObjectAssociatedMessage(SymbioticObjectAssociatedMessage),
+    SymbioticObjectErrorMessage(SymbioticObjectErrorMessage),
+    SystemMessageDisplayMessage(SystemMessageDisplayMessage),
+    TaxCollectorAddedMessage(TaxCollectorAddedMessage),
+    TaxCollectorAttackedMessage(TaxCollectorAttackedMessage),
+    TaxCollectorAttackedResultMessage(TaxCollectorAttackedResultMessage),
+    TaxCollectorDialogQuestionBasicMessage(TaxCollectorDialogQuestionBasicMessage),
+    TaxCollectorDialogQuestionExtendedMessage(TaxCollectorDialogQuestionExtendedMessage),
+    TaxCollectorEquipmentUpdateMessage(TaxCollectorEquipmentUpdateMessage),
+    TaxCollectorErrorMessage(TaxCollectorErrorMessage),
+    TaxCollectorHarvestedMessage(TaxCollectorHarvestedMessage),
+    TaxCollectorMovementsOfflineMessage(TaxCollectorMovementsOfflineMessage),
+    TaxCollectorOrderedSpellUpdatedMessage(TaxCollectorOrderedSpellUpdatedMessage),
+    TaxCollectorPresetSpellUpdatedMessage(TaxCollectorPresetSpellUpdatedMessage),
+    TaxCollectorPresetsMessage(TaxCollectorPresetsMessage),
+    TaxCollectorRemovedMessage(TaxCollectorRemovedMessage),
+    TaxCollectorStateUpdateMessage(TaxCollectorStateUpdateMessage),
+    TeleportBuddiesAnswerMessage(TeleportBuddiesAnswerMessage),
+    TeleportBuddiesMessage(TeleportBuddiesMessage),
+    TeleportBuddiesRequestedMessage(TeleportBuddiesRequestedMessage),
+    TeleportDestinationsMessage(TeleportDestinationsMessage),
+    TeleportHavenBagAnswerMessage(TeleportHavenBagAnswerMessage),
+    TeleportHavenBagRequestMessage(TeleportHavenBagRequestMessage),
+    TeleportOnSameMapMessage(TeleportOnSameMapMessage),
+    TeleportPlayerAnswerMessage(TeleportPlayerAnswerMessage),
+    TeleportPlayerCloseMessage(TeleportPlayerCloseMessage),
+    TeleportPlayerOfferMessage(TeleportPlayerOfferMessage),
+    TeleportRequestMessage(TeleportRequestMessage),
+    TeleportToBuddyAnswerMessage(TeleportToBuddyAnswerMessage),
+    TeleportToBuddyCloseMessage(TeleportToBuddyCloseMessage),
+    TeleportToBuddyOfferMessage(TeleportToBuddyOfferMessage),
+    TextInformationMessage(TextInformationMessage),
+    TitleGainedMessage(TitleGainedMessage),
+    TitleLostMessage(TitleLostMessage),
+    TitleSelectErrorMessage(TitleSelectErrorMessage),
+    TitleSelectRequestMessage(TitleSelectRequestMessage),
+    TitleSelectedMessage(TitleSelectedMessage),
+    TitlesAndOrnamentsListMessage(TitlesAndOrnamentsListMessage),
+    TitlesAndOrnamentsListRequestMessage(TitlesAndOrnamentsListRequestMessage),
+    TopTaxCollectorListMessage(TopTaxCollectorListMessage),
+    TreasureHuntAvailableRetryCountUpdateMessage(TreasureHuntAvailableRetryCountUpdateMessage),
+    TreasureHuntDigRequestAnswerFailedMessage(TreasureHuntDigRequestAnswerFailedMessage),
+    TreasureHuntDigRequestAnswerMessage(TreasureHuntDigRequestAnswerMessage),
+    TreasureHuntDigRequestMessage(TreasureHuntDigRequestMessage),
+    TreasureHuntFinishedMessage(TreasureHuntFinishedMessage),
+    TreasureHuntFlagRemoveRequestMessage(TreasureHuntFlagRemoveRequestMessage),
+    TreasureHuntFlagRequestAnswerMessage(TreasureHuntFlagRequestAnswerMessage),
+    TreasureHuntFlagRequestMessage(TreasureHuntFlagRequestMessage),
+    TreasureHuntGiveUpRequestMessage(TreasureHuntGiveUpRequestMessage),
+    TreasureHuntLegendaryRequestMessage(TreasureHuntLegendaryRequestMessage),
+    TreasureHuntMessage(TreasureHuntMessage),
+    TreasureHuntRequestAnswerMessage(TreasureHuntRequestAnswerMessage),
+    TreasureHuntShowLegendaryUIMessage(TreasureHuntShowLegendaryUIMessage),
+    TrustStatusMessage(TrustStatusMessage),
+    UnfollowQuestObjectiveRequestMessage(UnfollowQuestObjectiveRequestMessage),
+    UpdateAllGuildRankRequestMessage(UpdateAllGuildRankRequestMessage),
+    UpdateGuildRankRequestMessage(UpdateGuildRankRequestMessage),
+    UpdateGuildRightsMessage(UpdateGuildRightsMessage),
+    UpdateLifePointsMessage(UpdateLifePointsMessage),
+    UpdateMapPlayersAgressableStatusMessage(UpdateMapPlayersAgressableStatusMessage),
+    UpdateMountCharacteristicsMessage(UpdateMountCharacteristicsMessage),
+    UpdateRecruitmentInformationMessage(UpdateRecruitmentInformationMessage),
+    UpdateSelfAgressableStatusMessage(UpdateSelfAgressableStatusMessage),
+    WarnOnPermaDeathMessage(WarnOnPermaDeathMessage),
+    WarnOnPermaDeathStateMessage(WarnOnPermaDeathStateMessage),
+    WatchInventoryContentMessage(WatchInventoryContentMessage),
+    WatchQuestListMessage(WatchQuestListMessage),
+    WatchQuestStepInfoMessage(WatchQuestStepInfoMessage),
+    WatchQuestStepInfoRequestMessage(WatchQuestStepInfoRequestMessage),
+    WrapperObjectAssociatedMessage(WrapperObjectAssociatedMessage),
+    WrapperObjectDissociateRequestMessage(WrapperObjectDissociateRequestMessage),
+    WrapperObjectErrorMessage(WrapperObjectErrorMessage),
+    ZaapDestinationsMessage(ZaapDestinationsMessage),
+    ZaapRespawnSaveRequestMessage(ZaapRespawnSaveRequestMessage),
+    ZaapRespawnUpdatedMessage(ZaapRespawnUpdatedMessage),
+}
+
+impl GameMessage {
+    /// Decode the data of the message named `name`, missing fields get their default value
+    pub fn decode(name: &str, data: &Map<String, Value>) -> Result<Self, MessageError> {
+        let message = match name {
+            "AbstractGameActionFightTargetedAbilityMessage" => GameMessage::AbstractGameActionFightTargetedAbilityMessage(decode(name, data)?),
+            "AbstractGameActionMessage" => GameMessage::AbstractGameActionMessage(decode(name, data)?),
+            "AbstractGameActionWithAckMessage" => GameMessage::AbstractGameActionWithAckMessage(decode(name, data)?),
+            "AbstractPartyEventMessage" => GameMessage::AbstractPartyEventMessage(decode(name, data)?),
+            "AbstractPartyMemberInFightMessage" => GameMessage::AbstractPartyMemberInFightMessage(decode(name, data)?),
+            "AbstractPartyMessage" => GameMessage::AbstractPartyMessage(decode(name, data)?),
+            "AccessoryPreviewErrorMessage" => GameMessage::AccessoryPreviewErrorMessage(decode(name, data)?),
+            "AccessoryPreviewMessage" => GameMessage::AccessoryPreviewMessage(decode(name, data)?),
+            "AccessoryPreviewRequestMessage" => GameMessage::AccessoryPreviewRequestMessage(decode(name, data)?),
+            "AccountCapabilitiesMessage" => GameMessage::AccountCapabilitiesMessage(decode(name, data)?),
+            "AccountHouseMessage" => GameMessage::AccountHouseMessage(decode(name, data)?),
+            "AccountInformationsUpdateMessage" => GameMessage::AccountInformationsUpdateMessage(decode(name, data)?),
+            "AccountLinkRequiredMessage" => GameMessage::AccountLinkRequiredMessage(decode(name, data)?),
+            "AccountLoggingKickedMessage" => GameMessage::AccountLoggingKickedMessage(decode(name, data)?),
+            "AccountSubscriptionElapsedDurationMessage" => GameMessage::AccountSubscriptionElapsedDurationMessage(decode(name, data)?),
+            "AchievementAlmostFinishedDetailedListMessage" => GameMessage::AchievementAlmostFinishedDetailedListMessage(decode(name, data)?),
+            "AchievementAlmostFinishedDetailedListRequestMessage" => GameMessage::AchievementAlmostFinishedDetailedListRequestMessage(decode(name, data)?),
+            "AchievementDetailedListMessage" => GameMessage::AchievementDetailedListMessage(decode(name, data)?),
+            "AchievementDetailedListRequestMessage" => GameMessage::AchievementDetailedListRequestMessage(decode(name, data)?),
+            "AchievementDetailsMessage" => GameMessage::AchievementDetailsMessage(decode(name, data)?),
+            "AchievementDetailsRequestMessage" => GameMessage::AchievementDetailsRequestMessage(decode(name, data)?),
+            "AchievementFinishedInformationMessage" => GameMessage::AchievementFinishedInformationMessage(decode(name, data)?),
+            "AchievementFinishedMessage" => GameMessage::AchievementFinishedMessage(decode(name, data)?),
+            "AchievementListMessage" => GameMessage::AchievementListMessage(decode(name, data)?),
+            "AchievementRewardErrorMessage" => GameMessage::AchievementRewardErrorMessage(decode(name, data)?),
+            "AchievementRewardRequestMessage" => GameMessage::AchievementRewardRequestMessage(decode(name, data)?),
+            "AchievementRewardSuccessMessage" => GameMessage::AchievementRewardSuccessMessage(decode(name, data)?),
+            "AchievementsPioneerRanksMessage" => GameMessage::AchievementsPioneerRanksMessage(decode(name, data)?),
+            "AchievementsPioneerRanksRequestMessage" => GameMessage::AchievementsPioneerRanksRequestMessage(decode(name, data)?),
+            "AcquaintanceAddedMessage" => GameMessage::AcquaintanceAddedMessage(decode(name, data)?),
+            "AcquaintanceSearchErrorMessage" => GameMessage::AcquaintanceSearchErrorMessage(decode(name, data)?),
+            "AcquaintanceSearchMessage" => GameMessage::AcquaintanceSearchMessage(decode(name, data)?),
+            "AcquaintanceServerListMessage" => GameMessage::AcquaintanceServerListMessage(decode(name, data)?),
+            "AcquaintancesGetListMessage" => GameMessage::AcquaintancesGetListMessage(decode(name, data)?),
+            "AcquaintancesListMessage" => GameMessage::AcquaintancesListMessage(decode(name, data)?),
+            "ActivityHideRequestMessage" => GameMessage::ActivityHideRequestMessage(decode(name, data)?),
+            "ActivityLockRequestMessage" => GameMessage::ActivityLockRequestMessage(decode(name, data)?),
+            "ActivitySuggestionsMessage" => GameMessage::ActivitySuggestionsMessage(decode(name, data)?),
+            "ActivitySuggestionsRequestMessage" => GameMessage::ActivitySuggestionsRequestMessage(decode(name, data)?),
+            "AddListenerOnSynchronizedStorageMessage" => GameMessage::AddListenerOnSynchronizedStorageMessage(decode(name, data)?),
+            "AddTaxCollectorOrderedSpellMessage" => GameMessage::AddTaxCollectorOrderedSpellMessage(decode(name, data)?),
+            "AddTaxCollectorPresetSpellMessage" => GameMessage::AddTaxCollectorPresetSpellMessage(decode(name, data)?),
+            "AdminCommandMessage" => GameMessage::AdminCommandMessage(decode(name, data)?),
+            "AdminQuietCommandMessage" => GameMessage::AdminQuietCommandMessage(decode(name, data)?),
+            "AlignmentRankUpdateMessage" => GameMessage::AlignmentRankUpdateMessage(decode(name, data)?),
+            "AlignmentWarEffortDonatePreviewMessage" => GameMessage::AlignmentWarEffortDonatePreviewMessage(decode(name, data)?),
+            "AlignmentWarEffortDonateRequestMessage" => GameMessage::AlignmentWarEffortDonateRequestMessage(decode(name, data)?),
+            "AlignmentWarEffortDonationResultMessage" => GameMessage::AlignmentWarEffortDonationResultMessage(decode(name, data)?),
+            "AlignmentWarEffortProgressionMessage" => GameMessage::AlignmentWarEffortProgressionMessage(decode(name, data)?),
+            "AlignmentWarEffortProgressionRequestMessage" => GameMessage::AlignmentWarEffortProgressionRequestMessage(decode(name, data)?),
+            "AllianceAllRanksUpdateRequestMessage" => GameMessage::AllianceAllRanksUpdateRequestMessage(decode(name, data)?),
+            "AllianceApplicationAnswerMessage" => GameMessage::AllianceApplicationAnswerMessage(decode(name, data)?),
+            "AllianceApplicationDeletedMessage" => GameMessage::AllianceApplicationDeletedMessage(decode(name, data)?),
+            "AllianceApplicationIsAnsweredMessage" => GameMessage::AllianceApplicationIsAnsweredMessage(decode(name, data)?),
+            "AllianceApplicationListenMessage" => GameMessage::AllianceApplicationListenMessage(decode(name, data)?),
+            "AllianceApplicationPresenceMessage" => GameMessage::AllianceApplicationPresenceMessage(decode(name, data)?),
+            "AllianceApplicationReceivedMessage" => GameMessage::AllianceApplicationReceivedMessage(decode(name, data)?),
+            "AllianceBulletinMessage" => GameMessage::AllianceBulletinMessage(decode(name, data)?),
+            "AllianceBulletinSetErrorMessage" => GameMessage::AllianceBulletinSetErrorMessage(decode(name, data)?),
+            "AllianceBulletinSetRequestMessage" => GameMessage::AllianceBulletinSetRequestMessage(decode(name, data)?),
+            "AllianceChangeMemberRankMessage" => GameMessage::AllianceChangeMemberRankMessage(decode(name, data)?),
+            "AllianceCreationResultMessage" => GameMessage::AllianceCreationResultMessage(decode(name, data)?),
+            "AllianceCreationStartedMessage" => GameMessage::AllianceCreationStartedMessage(decode(name, data)?),
+            "AllianceCreationValidMessage" => GameMessage::AllianceCreationValidMessage(decode(name, data)?),
+            "AllianceDeleteApplicationRequestMessage" => GameMessage::AllianceDeleteApplicationRequestMessage(decode(name, data)?),
+            "AllianceFactsErrorMessage" => GameMessage::AllianceFactsErrorMessage(decode(name, data)?),
+            "AllianceFactsMessage" => GameMessage::AllianceFactsMessage(decode(name, data)?),
+            "AllianceFactsRequestMessage" => GameMessage::AllianceFactsRequestMessage(decode(name, data)?),
+            "AllianceFightFighterAddedMessage" => GameMessage::AllianceFightFighterAddedMessage(decode(name, data)?),
+            "AllianceFightFighterRemovedMessage" => GameMessage::AllianceFightFighterRemovedMessage(decode(name, data)?),
+            "AllianceFightFinishedMessage" => GameMessage::AllianceFightFinishedMessage(decode(name, data)?),
+            "AllianceFightInfoMessage" => GameMessage::AllianceFightInfoMessage(decode(name, data)?),
+            "AllianceFightPhaseUpdateMessage" => GameMessage::AllianceFightPhaseUpdateMessage(decode(name, data)?),
+            "AllianceFightStartedMessage" => GameMessage::AllianceFightStartedMessage(decode(name, data)?),
+            "AllianceGetPlayerApplicationMessage" => GameMessage::AllianceGetPlayerApplicationMessage(decode(name, data)?),
+            "AllianceGetRecruitmentInformationMessage" => GameMessage::AllianceGetRecruitmentInformationMessage(decode(name, data)?),
+            "AllianceInsiderInfoMessage" => GameMessage::AllianceInsiderInfoMessage(decode(name, data)?),
+            "AllianceInsiderInfoRequestMessage" => GameMessage::AllianceInsiderInfoRequestMessage(decode(name, data)?),
+            "AllianceInvitationAnswerMessage" => GameMessage::AllianceInvitationAnswerMessage(decode(name, data)?),
+            "AllianceInvitationMessage" => GameMessage::AllianceInvitationMessage(decode(name, data)?),
+            "AllianceInvitationStateRecrutedMessage" => GameMessage::AllianceInvitationStateRecrutedMessage(decode(name, data)?),
+            "AllianceInvitationStateRecruterMessage" => GameMessage::AllianceInvitationStateRecruterMessage(decode(name, data)?),
+            "AllianceInvitedMessage" => GameMessage::AllianceInvitedMessage(decode(name, data)?),
+            "AllianceIsThereAnyApplicationMessage" => GameMessage::AllianceIsThereAnyApplicationMessage(decode(name, data)?),
+            "AllianceJoinAutomaticallyRequestMessage" => GameMessage::AllianceJoinAutomaticallyRequestMessage(decode(name, data)?),
+            "AllianceJoinedMessage" => GameMessage::AllianceJoinedMessage(decode(name, data)?),
+            "AllianceKickRequestMessage" => GameMessage::AllianceKickRequestMessage(decode(name, data)?),
+            "AllianceLeftMessage" => GameMessage::AllianceLeftMessage(decode(name, data)?),
+            "AllianceListApplicationAnswerMessage" => GameMessage::AllianceListApplicationAnswerMessage(decode(name, data)?),
+            "AllianceListApplicationModifiedMessage" => GameMessage::AllianceListApplicationModifiedMessage(decode(name, data)?),
+            "AllianceListApplicationRequestMessage" => GameMessage::AllianceListApplicationRequestMessage(decode(name, data)?),
+            "AllianceListMessage" => GameMessage::AllianceListMessage(decode(name, data)?),
+            "AllianceMemberInformationUpdateMessage" => GameMessage::AllianceMemberInformationUpdateMessage(decode(name, data)?),
+            "AllianceMemberLeavingMessage" => GameMessage::AllianceMemberLeavingMessage(decode(name, data)?),
+            "AllianceMemberOnlineStatusMessage" => GameMessage::AllianceMemberOnlineStatusMessage(decode(name, data)?),
+            "AllianceMemberStartWarningOnConnectionMessage" => GameMessage::AllianceMemberStartWarningOnConnectionMessage(decode(name, data)?),
+            "AllianceMemberStopWarningOnConnectionMessage" => GameMessage::AllianceMemberStopWarningOnConnectionMessage(decode(name, data)?),
+            "AllianceMembershipMessage" => GameMessage::AllianceMembershipMessage(decode(name, data)?),
+            "AllianceModificationEmblemValidMessage" => GameMessage::AllianceModificationEmblemValidMessage(decode(name, data)?),
+            "AllianceModificationNameAndTagValidMessage" => GameMessage::AllianceModificationNameAndTagValidMessage(decode(name, data)?),
+            "AllianceModificationResultMessage" => GameMessage::AllianceModificationResultMessage(decode(name, data)?),
+            "AllianceModificationStartedMessage" => GameMessage::AllianceModificationStartedMessage(decode(name, data)?),
+            "AllianceModificationValidMessage" => GameMessage::AllianceModificationValidMessage(decode(name, data)?),
+            "AllianceMotdMessage" => GameMessage::AllianceMotdMessage(decode(name, data)?),
+            "AllianceMotdSetErrorMessage" => GameMessage::AllianceMotdSetErrorMessage(decode(name, data)?),
+            "AllianceMotdSetRequestMessage" => GameMessage::AllianceMotdSetRequestMessage(decode(name, data)?),
+            "AlliancePartialListMessage" => GameMessage::AlliancePartialListMessage(decode(name, data)?),
+            "AlliancePlayerApplicationAbstractMessage" => GameMessage::AlliancePlayerApplicationAbstractMessage(decode(name, data)?),
+            "AlliancePlayerApplicationInformationMessage" => GameMessage::AlliancePlayerApplicationInformationMessage(decode(name, data)?),
+            "AlliancePlayerNoApplicationInformationMessage" => GameMessage::AlliancePlayerNoApplicationInformationMessage(decode(name, data)?),
+            "AlliancePrismDialogQuestionMessage" => GameMessage::AlliancePrismDialogQuestionMessage(decode(name, data)?),
+            "AllianceRankCreateRequestMessage" => GameMessage::AllianceRankCreateRequestMessage(decode(name, data)?),
+            "AllianceRankRemoveRequestMessage" => GameMessage::AllianceRankRemoveRequestMessage(decode(name, data)?),
+            "AllianceRankUpdateRequestMessage" => GameMessage::AllianceRankUpdateRequestMessage(decode(name, data)?),
+            "AllianceRanksMessage" => GameMessage::AllianceRanksMessage(decode(name, data)?),
+            "AllianceRanksRequestMessage" => GameMessage::AllianceRanksRequestMessage(decode(name, data)?),
+            "AllianceRecruitmentInformationMessage" => GameMessage::AllianceRecruitmentInformationMessage(decode(name, data)?),
+            "AllianceRecruitmentInvalidateMessage" => GameMessage::AllianceRecruitmentInvalidateMessage(decode(name, data)?),
+            "AllianceRightsUpdateMessage" => GameMessage::AllianceRightsUpdateMessage(decode(name, data)?),
+            "AllianceSubmitApplicationMessage" => GameMessage::AllianceSubmitApplicationMessage(decode(name, data)?),
+            "AllianceSummaryMessage" => GameMessage::AllianceSummaryMessage(decode(name, data)?),
+            "AllianceSummaryRequestMessage" => GameMessage::AllianceSummaryRequestMessage(decode(name, data)?),
+            "AllianceUpdateApplicationMessage" => GameMessage::AllianceUpdateApplicationMessage(decode(name, data)?),
+            "AllianceUpdateRecruitmentInformationMessage" => GameMessage::AllianceUpdateRecruitmentInformationMessage(decode(name, data)?),
+            "AlmanachCalendarDateMessage" => GameMessage::AlmanachCalendarDateMessage(decode(name, data)?),
+            "AlreadyConnectedMessage" => GameMessage::AlreadyConnectedMessage(decode(name, data)?),
+            "AlterationAddedMessage" => GameMessage::AlterationAddedMessage(decode(name, data)?),
+            "AlterationRemovedMessage" => GameMessage::AlterationRemovedMessage(decode(name, data)?),
+            "AlterationsMessage" => GameMessage::AlterationsMessage(decode(name, data)?),
+            "AlterationsUpdatedMessage" => GameMessage::AlterationsUpdatedMessage(decode(name, data)?),
+            "AnomalyOpenedMessage" => GameMessage::AnomalyOpenedMessage(decode(name, data)?),
+            "AnomalyStateMessage" => GameMessage::AnomalyStateMessage(decode(name, data)?),
+            "AnomalySubareaInformationRequestMessage" => GameMessage::AnomalySubareaInformationRequestMessage(decode(name, data)?),
+            "AnomalySubareaInformationResponseMessage" => GameMessage::AnomalySubareaInformationResponseMessage(decode(name, data)?),
+            "ApplySpellModifierMessage" => GameMessage::ApplySpellModifierMessage(decode(name, data)?),
+            "AreaFightModificatorUpdateMessage" => GameMessage::AreaFightModificatorUpdateMessage(decode(name, data)?),
+            "ArenaFightAnswerAcknowledgementMessage" => GameMessage::ArenaFightAnswerAcknowledgementMessage(decode(name, data)?),
+            "ArenaFighterIdleMessage" => GameMessage::ArenaFighterIdleMessage(decode(name, data)?),
+            "ArenaFighterLeaveMessage" => GameMessage::ArenaFighterLeaveMessage(decode(name, data)?),
+            "AtlasPointInformationsMessage" => GameMessage::AtlasPointInformationsMessage(decode(name, data)?),
+            "AuthenticationTicketAcceptedMessage" => GameMessage::AuthenticationTicketAcceptedMessage(decode(name, data)?),
+            "AuthenticationTicketMessage" => GameMessage::AuthenticationTicketMessage(decode(name, data)?),
+            "AuthenticationTicketRefusedMessage" => GameMessage::AuthenticationTicketRefusedMessage(decode(name, data)?),
+            "BasicAckMessage" => GameMessage::BasicAckMessage(decode(name, data)?),
+            "BasicDateMessage" => GameMessage::BasicDateMessage(decode(name, data)?),
+            "BasicLatencyStatsMessage" => GameMessage::BasicLatencyStatsMessage(decode(name, data)?),
+            "BasicLatencyStatsRequestMessage" => GameMessage::BasicLatencyStatsRequestMessage(decode(name, data)?),
+            "BasicNoOperationMessage" => GameMessage::BasicNoOperationMessage(decode(name, data)?),
+            "BasicPingMessage" => GameMessage::BasicPingMessage(decode(name, data)?),
+            "BasicPongMessage" => GameMessage::BasicPongMessage(decode(name, data)?),
+            "BasicStatMessage" => GameMessage::BasicStatMessage(decode(name, data)?),
+            "BasicStatWithDataMessage" => GameMessage::BasicStatWithDataMessage(decode(name, data)?),
+            "BasicTimeMessage" => GameMessage::BasicTimeMessage(decode(name, data)?),
+            "BasicWhoAmIRequestMessage" => GameMessage::BasicWhoAmIRequestMessage(decode(name, data)?),
+            "BasicWhoIsMessage" => GameMessage::BasicWhoIsMessage(decode(name, data)?),
+            "BasicWhoIsNoMatchMessage" => GameMessage::BasicWhoIsNoMatchMessage(decode(name, data)?),
+            "BasicWhoIsRequestMessage" => GameMessage::BasicWhoIsRequestMessage(decode(name, data)?),
+            "BreachBonusMessage" => GameMessage::BreachBonusMessage(decode(name, data)?),
+            "BreachBranchesMessage" => GameMessage::BreachBranchesMessage(decode(name, data)?),
+            "BreachBudgetMessage" => GameMessage::BreachBudgetMessage(decode(name, data)?),
+            "BreachCharactersMessage" => GameMessage::BreachCharactersMessage(decode(name, data)?),
+            "BreachEnterMessage" => GameMessage::BreachEnterMessage(decode(name, data)?),
+            "BreachExitRequestMessage" => GameMessage::BreachExitRequestMessage(decode(name, data)?),
+            "BreachExitResponseMessage" => GameMessage::BreachExitResponseMessage(decode(name, data)?),
+            "BreachGameFightEndMessage" => GameMessage::BreachGameFightEndMessage(decode(name, data)?),
+            "BreachInvitationAnswerMessage" => GameMessage::BreachInvitationAnswerMessage(decode(name, data)?),
+            "BreachInvitationCloseMessage" => GameMessage::BreachInvitationCloseMessage(decode(name, data)?),
+            "BreachInvitationOfferMessage" => GameMessage::BreachInvitationOfferMessage(decode(name, data)?),
+            "BreachInvitationRequestMessage" => GameMessage::BreachInvitationRequestMessage(decode(name, data)?),
+            "BreachInvitationResponseMessage" => GameMessage::BreachInvitationResponseMessage(decode(name, data)?),
+            "BreachKickRequestMessage" => GameMessage::BreachKickRequestMessage(decode(name, data)?),
+            "BreachKickResponseMessage" => GameMessage::BreachKickResponseMessage(decode(name, data)?),
+            "BreachRewardBoughtMessage" => GameMessage::BreachRewardBoughtMessage(decode(name, data)?),
+            "BreachRewardBuyMessage" => GameMessage::BreachRewardBuyMessage(decode(name, data)?),
+            "BreachRewardsMessage" => GameMessage::BreachRewardsMessage(decode(name, data)?),
+            "BreachRoomLockedMessage" => GameMessage::BreachRoomLockedMessage(decode(name, data)?),
+            "BreachRoomUnlockRequestMessage" => GameMessage::BreachRoomUnlockRequestMessage(decode(name, data)?),
+            "BreachRoomUnlockResultMessage" => GameMessage::BreachRoomUnlockResultMessage(decode(name, data)?),
+            "BreachSavedMessage" => GameMessage::BreachSavedMessage(decode(name, data)?),
+            "BreachStateMessage" => GameMessage::BreachStateMessage(decode(name, data)?),
+            "BreachTeleportRequestMessage" => GameMessage::BreachTeleportRequestMessage(decode(name, data)?),
+            "BreachTeleportResponseMessage" => GameMessage::BreachTeleportResponseMessage(decode(name, data)?),
+            "BulletinMessage" => GameMessage::BulletinMessage(decode(name, data)?),
+            "ChallengeAddMessage" => GameMessage::ChallengeAddMessage(decode(name, data)?),
+            "ChallengeBonusChoiceMessage" => GameMessage::ChallengeBonusChoiceMessage(decode(name, data)?),
+            "ChallengeBonusChoiceSelectedMessage" => GameMessage::ChallengeBonusChoiceSelectedMessage(decode(name, data)?),
+            "ChallengeFightJoinRefusedMessage" => GameMessage::ChallengeFightJoinRefusedMessage(decode(name, data)?),
+            "ChallengeListMessage" => GameMessage::ChallengeListMessage(decode(name, data)?),
+            "ChallengeModSelectMessage" => GameMessage::ChallengeModSelectMessage(decode(name, data)?),
+            "ChallengeModSelectedMessage" => GameMessage::ChallengeModSelectedMessage(decode(name, data)?),
+            "ChallengeNumberMessage" => GameMessage::ChallengeNumberMessage(decode(name, data)?),
+            "ChallengeProposalMessage" => GameMessage::ChallengeProposalMessage(decode(name, data)?),
+            "ChallengeReadyMessage" => GameMessage::ChallengeReadyMessage(decode(name, data)?),
+            "ChallengeResultMessage" => GameMessage::ChallengeResultMessage(decode(name, data)?),
+            "ChallengeSelectedMessage" => GameMessage::ChallengeSelectedMessage(decode(name, data)?),
+            "ChallengeSelectionMessage" => GameMessage::ChallengeSelectionMessage(decode(name, data)?),
+            "ChallengeTargetsMessage" => GameMessage::ChallengeTargetsMessage(decode(name, data)?),
+            "ChallengeTargetsRequestMessage" => GameMessage::ChallengeTargetsRequestMessage(decode(name, data)?),
+            "ChallengeValidateMessage" => GameMessage::ChallengeValidateMessage(decode(name, data)?),
+            "ChangeHavenBagRoomRequestMessage" => GameMessage::ChangeHavenBagRoomRequestMessage(decode(name, data)?),
+            "ChangeMapMessage" => GameMessage::ChangeMapMessage(decode(name, data)?),
+            "ChangeThemeRequestMessage" => GameMessage::ChangeThemeRequestMessage(decode(name, data)?),
+            "ChannelEnablingChangeMessage" => GameMessage::ChannelEnablingChangeMessage(decode(name, data)?),
+            "ChannelEnablingMessage" => GameMessage::ChannelEnablingMessage(decode(name, data)?),
+            "CharacterAlignmentWarEffortProgressionMessage" => GameMessage::CharacterAlignmentWarEffortProgressionMessage(decode(name, data)?),
+            "CharacterAlignmentWarEffortProgressionRequestMessage" => GameMessage::CharacterAlignmentWarEffortProgressionRequestMessage(decode(name, data)?),
+            "CharacterCanBeCreatedRequestMessage" => GameMessage::CharacterCanBeCreatedRequestMessage(decode(name, data)?),
+            "CharacterCanBeCreatedResultMessage" => GameMessage::CharacterCanBeCreatedResultMessage(decode(name, data)?),
+            "CharacterCapabilitiesMessage" => GameMessage::CharacterCapabilitiesMessage(decode(name, data)?),
+            "CharacterCreationRequestMessage" => GameMessage::CharacterCreationRequestMessage(decode(name, data)?),
+            "CharacterCreationResultMessage" => GameMessage::CharacterCreationResultMessage(decode(name, data)?),
+            "CharacterDeletionErrorMessage" => GameMessage::CharacterDeletionErrorMessage(decode(name, data)?),
+            "CharacterDeletionPrepareMessage" => GameMessage::CharacterDeletionPrepareMessage(decode(name, data)?),
+            "CharacterDeletionPrepareRequestMessage" => GameMessage::CharacterDeletionPrepareRequestMessage(decode(name, data)?),
+            "CharacterDeletionRequestMessage" => GameMessage::CharacterDeletionRequestMessage(decode(name, data)?),
+            "CharacterExperienceGainMessage" => GameMessage::CharacterExperienceGainMessage(decode(name, data)?),
+            "CharacterFirstSelectionMessage" => GameMessage::CharacterFirstSelectionMessage(decode(name, data)?),
+            "CharacterLevelUpInformationMessage" => GameMessage::CharacterLevelUpInformationMessage(decode(name, data)?),
+            "CharacterLevelUpMessage" => GameMessage::CharacterLevelUpMessage(decode(name, data)?),
+            "CharacterLoadingCompleteMessage" => GameMessage::CharacterLoadingCompleteMessage(decode(name, data)?),
+            "CharacterNameSuggestionFailureMessage" => GameMessage::CharacterNameSuggestionFailureMessage(decode(name, data)?),
+            "CharacterNameSuggestionRequestMessage" => GameMessage::CharacterNameSuggestionRequestMessage(decode(name, data)?),
+            "CharacterNameSuggestionSuccessMessage" => GameMessage::CharacterNameSuggestionSuccessMessage(decode(name, data)?),
+            "CharacterReplayRequestMessage" => GameMessage::CharacterReplayRequestMessage(decode(name, data)?),
+            "CharacterReplayWithRemodelRequestMessage" => GameMessage::CharacterReplayWithRemodelRequestMessage(decode(name, data)?),
+            "CharacterSelectedErrorMessage" => GameMessage::CharacterSelectedErrorMessage(decode(name, data)?),
+            "CharacterSelectedForceMessage" => GameMessage::CharacterSelectedForceMessage(decode(name, data)?),
+            "CharacterSelectedForceReadyMessage" => GameMessage::CharacterSelectedForceReadyMessage(decode(name, data)?),
+            "CharacterSelectedSuccessMessage" => GameMessage::CharacterSelectedSuccessMessage(decode(name, data)?),
+            "CharacterSelectionMessage" => GameMessage::CharacterSelectionMessage(decode(name, data)?),
+            "CharacterSelectionWithRemodelMessage" => GameMessage::CharacterSelectionWithRemodelMessage(decode(name, data)?),
+            "CharacterStatsListMessage" => GameMessage::CharacterStatsListMessage(decode(name, data)?),
+            "CharactersListErrorMessage" => GameMessage::CharactersListErrorMessage(decode(name, data)?),
+            "CharactersListMessage" => GameMessage::CharactersListMessage(decode(name, data)?),
+            "CharactersListRequestMessage" => GameMessage::CharactersListRequestMessage(decode(name, data)?),
+            "CharactersListWithRemodelingMessage" => GameMessage::CharactersListWithRemodelingMessage(decode(name, data)?),
+            "ChatAbstractClientMessage" => GameMessage::ChatAbstractClientMessage(decode(name, data)?),
+            "ChatAbstractServerMessage" => GameMessage::ChatAbstractServerMessage(decode(name, data)?),
+            "ChatAdminServerMessage" => GameMessage::ChatAdminServerMessage(decode(name, data)?),
+            "ChatClientMultiMessage" => GameMessage::ChatClientMultiMessage(decode(name, data)?),
+            "ChatClientMultiWithObjectMessage" => GameMessage::ChatClientMultiWithObjectMessage(decode(name, data)?),
+            "ChatClientPrivateMessage" => GameMessage::ChatClientPrivateMessage(decode(name, data)?),
+            "ChatClientPrivateWithObjectMessage" => GameMessage::ChatClientPrivateWithObjectMessage(decode(name, data)?),
+            "ChatCommunityChannelCommunityMessage" => GameMessage::ChatCommunityChannelCommunityMessage(decode(name, data)?),
+            "ChatCommunityChannelSetCommunityRequestMessage" => GameMessage::ChatCommunityChannelSetCommunityRequestMessage(decode(name, data)?),
+            "ChatErrorMessage" => GameMessage::ChatErrorMessage(decode(name, data)?),
+            "ChatKolizeumServerMessage" => GameMessage::ChatKolizeumServerMessage(decode(name, data)?),
+            "ChatServerCopyMessage" => GameMessage::ChatServerCopyMessage(decode(name, data)?),
+            "ChatServerCopyWithObjectMessage" => GameMessage::ChatServerCopyWithObjectMessage(decode(name, data)?),
+            "ChatServerMessage" => GameMessage::ChatServerMessage(decode(name, data)?),
+            "ChatServerWithObjectMessage" => GameMessage::ChatServerWithObjectMessage(decode(name, data)?),
+            "ChatSmileyExtraPackListMessage" => GameMessage::ChatSmileyExtraPackListMessage(decode(name, data)?),
+            "ChatSmileyMessage" => GameMessage::ChatSmileyMessage(decode(name, data)?),
+            "ChatSmileyRequestMessage" => GameMessage::ChatSmileyRequestMessage(decode(name, data)?),
+            "CheckFileMessage" => GameMessage::CheckFileMessage(decode(name, data)?),
+            "CheckFileRequestMessage" => GameMessage::CheckFileRequestMessage(decode(name, data)?),
+            "CheckIntegrityMessage" => GameMessage::CheckIntegrityMessage(decode(name, data)?),
+            "CinematicMessage" => GameMessage::CinematicMessage(decode(name, data)?),
+            "ClientKeyMessage" => GameMessage::ClientKeyMessage(decode(name, data)?),
+            "ClientUIOpenedByObjectMessage" => GameMessage::ClientUIOpenedByObjectMessage(decode(name, data)?),
+            "ClientUIOpenedMessage" => GameMessage::ClientUIOpenedMessage(decode(name, data)?),
+            "ClientYouAreDrunkMessage" => GameMessage::ClientYouAreDrunkMessage(decode(name, data)?),
+            "CloseHavenBagFurnitureSequenceRequestMessage" => GameMessage::CloseHavenBagFurnitureSequenceRequestMessage(decode(name, data)?),
+            "CompassResetMessage" => GameMessage::CompassResetMessage(decode(name, data)?),
+            "CompassUpdateMessage" => GameMessage::CompassUpdateMessage(decode(name, data)?),
+            "CompassUpdatePartyMemberMessage" => GameMessage::CompassUpdatePartyMemberMessage(decode(name, data)?),
+            "CompassUpdatePvpSeekMessage" => GameMessage::CompassUpdatePvpSeekMessage(decode(name, data)?),
+            "ConfirmationOfListeningTaxCollectorUpdatesMessage" => GameMessage::ConfirmationOfListeningTaxCollectorUpdatesMessage(decode(name, data)?),
+            "ConsoleCommandsListMessage" => GameMessage::ConsoleCommandsListMessage(decode(name, data)?),
+            "ConsoleEndMessage" => GameMessage::ConsoleEndMessage(decode(name, data)?),
+            "ConsoleMessage" => GameMessage::ConsoleMessage(decode(name, data)?),
+            "ConsumeAllGameActionItemMessage" => GameMessage::ConsumeAllGameActionItemMessage(decode(name, data)?),
+            "ConsumeGameActionItemMessage" => GameMessage::ConsumeGameActionItemMessage(decode(name, data)?),
+            "ContactAddFailureMessage" => GameMessage::ContactAddFailureMessage(decode(name, data)?),
+            "ContactLookErrorMessage" => GameMessage::ContactLookErrorMessage(decode(name, data)?),
+            "ContactLookMessage" => GameMessage::ContactLookMessage(decode(name, data)?),
+            "ContactLookRequestByIdMessage" => GameMessage::ContactLookRequestByIdMessage(decode(name, data)?),
+            "ContactLookRequestByNameMessage" => GameMessage::ContactLookRequestByNameMessage(decode(name, data)?),
+            "ContactLookRequestMessage" => GameMessage::ContactLookRequestMessage(decode(name, data)?),
+            "CreateGuildRankRequestMessage" => GameMessage::CreateGuildRankRequestMessage(decode(name, data)?),
+            "CredentialsAcknowledgementMessage" => GameMessage::CredentialsAcknowledgementMessage(decode(name, data)?),
+            "CurrentMapInstanceMessage" => GameMessage::CurrentMapInstanceMessage(decode(name, data)?),
+            "CurrentMapMessage" => GameMessage::CurrentMapMessage(decode(name, data)?),
+            "CurrentServerStatusUpdateMessage" => GameMessage::CurrentServerStatusUpdateMessage(decode(name, data)?),
+            "DebtsDeleteMessage" => GameMessage::DebtsDeleteMessage(decode(name, data)?),
+            "DebtsUpdateMessage" => GameMessage::DebtsUpdateMessage(decode(name, data)?),
+            "DebugClearHighlightCellsMessage" => GameMessage::DebugClearHighlightCellsMessage(decode(name, data)?),
+            "DebugHighlightCellsMessage" => GameMessage::DebugHighlightCellsMessage(decode(name, data)?),
+            "DebugInClientMessage" => GameMessage::DebugInClientMessage(decode(name, data)?),
+            "DecraftResultMessage" => GameMessage::DecraftResultMessage(decode(name, data)?),
+            "DiceRollRequestMessage" => GameMessage::DiceRollRequestMessage(decode(name, data)?),
+            "DisplayNumericalValuePaddockMessage" => GameMessage::DisplayNumericalValuePaddockMessage(decode(name, data)?),
+            "DocumentReadingBeginMessage" => GameMessage::DocumentReadingBeginMessage(decode(name, data)?),
+            "DumpedEntityStatsMessage" => GameMessage::DumpedEntityStatsMessage(decode(name, data)?),
+            "DungeonKeyRingMessage" => GameMessage::DungeonKeyRingMessage(decode(name, data)?),
+            "DungeonKeyRingUpdateMessage" => GameMessage::DungeonKeyRingUpdateMessage(decode(name, data)?),
+            "DungeonPartyFinderAvailableDungeonsMessage" => GameMessage::DungeonPartyFinderAvailableDungeonsMessage(decode(name, data)?),
+            "DungeonPartyFinderAvailableDungeonsRequestMessage" => GameMessage::DungeonPartyFinderAvailableDungeonsRequestMessage(decode(name, data)?),
+            "DungeonPartyFinderListenErrorMessage" => GameMessage::DungeonPartyFinderListenErrorMessage(decode(name, data)?),
+            "DungeonPartyFinderListenRequestMessage" => GameMessage::DungeonPartyFinderListenRequestMessage(decode(name, data)?),
+            "DungeonPartyFinderRegisterErrorMessage" => GameMessage::DungeonPartyFinderRegisterErrorMessage(decode(name, data)?),
+            "DungeonPartyFinderRegisterRequestMessage" => GameMessage::DungeonPartyFinderRegisterRequestMessage(decode(name, data)?),
+            "DungeonPartyFinderRegisterSuccessMessage" => GameMessage::DungeonPartyFinderRegisterSuccessMessage(decode(name, data)?),
+            "DungeonPartyFinderRoomContentMessage" => GameMessage::DungeonPartyFinderRoomContentMessage(decode(name, data)?),
+            "DungeonPartyFinderRoomContentUpdateMessage" => GameMessage::DungeonPartyFinderRoomContentUpdateMessage(decode(name, data)?),
+            "EditHavenBagCancelRequestMessage" => GameMessage::EditHavenBagCancelRequestMessage(decode(name, data)?),
+            "EditHavenBagFinishedMessage" => GameMessage::EditHavenBagFinishedMessage(decode(name, data)?),
+            "EditHavenBagRequestMessage" => GameMessage::EditHavenBagRequestMessage(decode(name, data)?),
+            "EditHavenBagStartMessage" => GameMessage::EditHavenBagStartMessage(decode(name, data)?),
+            "EmoteAddMessage" => GameMessage::EmoteAddMessage(decode(name, data)?),
+            "EmoteListMessage" => GameMessage::EmoteListMessage(decode(name, data)?),
+            "EmotePlayAbstractMessage" => GameMessage::EmotePlayAbstractMessage(decode(name, data)?),
+            "EmotePlayErrorMessage" => GameMessage::EmotePlayErrorMessage(decode(name, data)?),
+            "EmotePlayMassiveMessage" => GameMessage::EmotePlayMassiveMessage(decode(name, data)?),
+            "EmotePlayMessage" => GameMessage::EmotePlayMessage(decode(name, data)?),
+            "EmotePlayRequestMessage" => GameMessage::EmotePlayRequestMessage(decode(name, data)?),
+            "EmoteRemoveMessage" => GameMessage::EmoteRemoveMessage(decode(name, data)?),
+            "EnabledChannelsMessage" => GameMessage::EnabledChannelsMessage(decode(name, data)?),
+            "EnterHavenBagRequestMessage" => GameMessage::EnterHavenBagRequestMessage(decode(name, data)?),
+            "EntitiesInformationMessage" => GameMessage::EntitiesInformationMessage(decode(name, data)?),
+            "EntityInformationMessage" => GameMessage::EntityInformationMessage(decode(name, data)?),
+            "EntityTalkMessage" => GameMessage::EntityTalkMessage(decode(name, data)?),
+            "ErrorMapNotFoundMessage" => GameMessage::ErrorMapNotFoundMessage(decode(name, data)?),
+            "EvolutiveObjectRecycleResultMessage" => GameMessage::EvolutiveObjectRecycleResultMessage(decode(name, data)?),
+            "ExchangeAcceptMessage" => GameMessage::ExchangeAcceptMessage(decode(name, data)?),
+            "ExchangeBidHouseBuyMessage" => GameMessage::ExchangeBidHouseBuyMessage(decode(name, data)?),
+            "ExchangeBidHouseBuyResultMessage" => GameMessage::ExchangeBidHouseBuyResultMessage(decode(name, data)?),
+            "ExchangeBidHouseGenericItemAddedMessage" => GameMessage::ExchangeBidHouseGenericItemAddedMessage(decode(name, data)?),
+            "ExchangeBidHouseGenericItemRemovedMessage" => GameMessage::ExchangeBidHouseGenericItemRemovedMessage(decode(name, data)?),
+            "ExchangeBidHouseInListAddedMessage" => GameMessage::ExchangeBidHouseInListAddedMessage(decode(name, data)?),
+            "ExchangeBidHouseInListRemovedMessage" => GameMessage::ExchangeBidHouseInListRemovedMessage(decode(name, data)?),
+            "ExchangeBidHouseInListUpdatedMessage" => GameMessage::ExchangeBidHouseInListUpdatedMessage(decode(name, data)?),
+            "ExchangeBidHouseItemAddOkMessage" => GameMessage::ExchangeBidHouseItemAddOkMessage(decode(name, data)?),
+            "ExchangeBidHouseItemRemoveOkMessage" => GameMessage::ExchangeBidHouseItemRemoveOkMessage(decode(name, data)?),
+            "ExchangeBidHouseListMessage" => GameMessage::ExchangeBidHouseListMessage(decode(name, data)?),
+            "ExchangeBidHousePriceMessage" => GameMessage::ExchangeBidHousePriceMessage(decode(name, data)?),
+            "ExchangeBidHouseSearchMessage" => GameMessage::ExchangeBidHouseSearchMessage(decode(name, data)?),
+            "ExchangeBidHouseTypeMessage" => GameMessage::ExchangeBidHouseTypeMessage(decode(name, data)?),
+            "ExchangeBidHouseUnsoldItemsMessage" => GameMessage::ExchangeBidHouseUnsoldItemsMessage(decode(name, data)?),
+            "ExchangeBidPriceForSellerMessage" => GameMessage::ExchangeBidPriceForSellerMessage(decode(name, data)?),
+            "ExchangeBidPriceMessage" => GameMessage::ExchangeBidPriceMessage(decode(name, data)?),
+            "ExchangeBidSearchOkMessage" => GameMessage::ExchangeBidSearchOkMessage(decode(name, data)?),
+            "ExchangeBuyMessage" => GameMessage::ExchangeBuyMessage(decode(name, data)?),
+            "ExchangeBuyOkMessage" => GameMessage::ExchangeBuyOkMessage(decode(name, data)?),
+            "ExchangeCraftCountModifiedMessage" => GameMessage::ExchangeCraftCountModifiedMessage(decode(name, data)?),
+            "ExchangeCraftCountRequestMessage" => GameMessage::ExchangeCraftCountRequestMessage(decode(name, data)?),
+            "ExchangeCraftPaymentModificationRequestMessage" => GameMessage::ExchangeCraftPaymentModificationRequestMessage(decode(name, data)?),
+            "ExchangeCraftPaymentModifiedMessage" => GameMessage::ExchangeCraftPaymentModifiedMessage(decode(name, data)?),
+            "ExchangeCraftResultMagicWithObjectDescMessage" => GameMessage::ExchangeCraftResultMagicWithObjectDescMessage(decode(name, data)?),
+            "ExchangeCraftResultMessage" => GameMessage::ExchangeCraftResultMessage(decode(name, data)?),
+            "ExchangeCraftResultWithObjectDescMessage" => GameMessage::ExchangeCraftResultWithObjectDescMessage(decode(name, data)?),
+            "ExchangeCraftResultWithObjectIdMessage" => GameMessage::ExchangeCraftResultWithObjectIdMessage(decode(name, data)?),
+            "ExchangeCrafterJobLevelupMessage" => GameMessage::ExchangeCrafterJobLevelupMessage(decode(name, data)?),
+            "ExchangeErrorMessage" => GameMessage::ExchangeErrorMessage(decode(name, data)?),
+            "ExchangeHandleMountsMessage" => GameMessage::ExchangeHandleMountsMessage(decode(name, data)?),
+            "ExchangeIsReadyMessage" => GameMessage::ExchangeIsReadyMessage(decode(name, data)?),
+            "ExchangeItemAutoCraftStopedMessage" => GameMessage::ExchangeItemAutoCraftStopedMessage(decode(name, data)?),
+            "ExchangeKamaModifiedMessage" => GameMessage::ExchangeKamaModifiedMessage(decode(name, data)?),
+            "ExchangeLeaveMessage" => GameMessage::ExchangeLeaveMessage(decode(name, data)?),
+            "ExchangeMoneyMovementInformationMessage" => GameMessage::ExchangeMoneyMovementInformationMessage(decode(name, data)?),
+            "ExchangeMountFreeFromPaddockMessage" => GameMessage::ExchangeMountFreeFromPaddockMessage(decode(name, data)?),
+            "ExchangeMountStableErrorMessage" => GameMessage::ExchangeMountStableErrorMessage(decode(name, data)?),
+            "ExchangeMountSterilizeFromPaddockMessage" => GameMessage::ExchangeMountSterilizeFromPaddockMessage(decode(name, data)?),
+            "ExchangeMountsPaddockAddMessage" => GameMessage::ExchangeMountsPaddockAddMessage(decode(name, data)?),
+            "ExchangeMountsPaddockRemoveMessage" => GameMessage::ExchangeMountsPaddockRemoveMessage(decode(name, data)?),
+            "ExchangeMountsStableAddMessage" => GameMessage::ExchangeMountsStableAddMessage(decode(name, data)?),
+            "ExchangeMountsStableBornAddMessage" => GameMessage::ExchangeMountsStableBornAddMessage(decode(name, data)?),
+            "ExchangeMountsStableRemoveMessage" => GameMessage::ExchangeMountsStableRemoveMessage(decode(name, data)?),
+            "ExchangeMountsTakenFromPaddockMessage" => GameMessage::ExchangeMountsTakenFromPaddockMessage(decode(name, data)?),
+            "ExchangeMultiCraftCrafterCanUseHisRessourcesMessage" => GameMessage::ExchangeMultiCraftCrafterCanUseHisRessourcesMessage(decode(name, data)?),
+            "ExchangeMultiCraftSetCrafterCanUseHisRessourcesMessage" => GameMessage::ExchangeMultiCraftSetCrafterCanUseHisRessourcesMessage(decode(name, data)?),
+            "ExchangeObjectAddedMessage" => GameMessage::ExchangeObjectAddedMessage(decode(name, data)?),
+            "ExchangeObjectMessage" => GameMessage::ExchangeObjectMessage(decode(name, data)?),
+            "ExchangeObjectModifiedInBagMessage" => GameMessage::ExchangeObjectModifiedInBagMessage(decode(name, data)?),
+            "ExchangeObjectModifiedMessage" => GameMessage::ExchangeObjectModifiedMessage(decode(name, data)?),
+            "ExchangeObjectModifyPricedMessage" => GameMessage::ExchangeObjectModifyPricedMessage(decode(name, data)?),
+            "ExchangeObjectMoveKamaMessage" => GameMessage::ExchangeObjectMoveKamaMessage(decode(name, data)?),
+            "ExchangeObjectMoveMessage" => GameMessage::ExchangeObjectMoveMessage(decode(name, data)?),
+            "ExchangeObjectMovePricedMessage" => GameMessage::ExchangeObjectMovePricedMessage(decode(name, data)?),
+            "ExchangeObjectMoveToTabMessage" => GameMessage::ExchangeObjectMoveToTabMessage(decode(name, data)?),
+            "ExchangeObjectPutInBagMessage" => GameMessage::ExchangeObjectPutInBagMessage(decode(name, data)?),
+            "ExchangeObjectRemovedFromBagMessage" => GameMessage::ExchangeObjectRemovedFromBagMessage(decode(name, data)?),
+            "ExchangeObjectRemovedMessage" => GameMessage::ExchangeObjectRemovedMessage(decode(name, data)?),
+            "ExchangeObjectTransfertAllFromInvMessage" => GameMessage::ExchangeObjectTransfertAllFromInvMessage(decode(name, data)?),
+            "ExchangeObjectTransfertAllToInvMessage" => GameMessage::ExchangeObjectTransfertAllToInvMessage(decode(name, data)?),
+            "ExchangeObjectTransfertExistingFromInvMessage" => GameMessage::ExchangeObjectTransfertExistingFromInvMessage(decode(name, data)?),
+            "ExchangeObjectTransfertExistingToInvMessage" => GameMessage::ExchangeObjectTransfertExistingToInvMessage(decode(name, data)?),
+            "ExchangeObjectTransfertListFromInvMessage" => GameMessage::ExchangeObjectTransfertListFromInvMessage(decode(name, data)?),
+            "ExchangeObjectTransfertListToInvMessage" => GameMessage::ExchangeObjectTransfertListToInvMessage(decode(name, data)?),
+            "ExchangeObjectTransfertListWithQuantityToInvMessage" => GameMessage::ExchangeObjectTransfertListWithQuantityToInvMessage(decode(name, data)?),
+            "ExchangeObjectUseInWorkshopMessage" => GameMessage::ExchangeObjectUseInWorkshopMessage(decode(name, data)?),
+            "ExchangeObjectsAddedMessage" => GameMessage::ExchangeObjectsAddedMessage(decode(name, data)?),
+            "ExchangeObjectsModifiedMessage" => GameMessage::ExchangeObjectsModifiedMessage(decode(name, data)?),
+            "ExchangeObjectsRemovedMessage" => GameMessage::ExchangeObjectsRemovedMessage(decode(name, data)?),
+            "ExchangeOfflineSoldItemsMessage" => GameMessage::ExchangeOfflineSoldItemsMessage(decode(name, data)?),
+            "ExchangeOkMultiCraftMessage" => GameMessage::ExchangeOkMultiCraftMessage(decode(name, data)?),
+            "ExchangePlayerMultiCraftRequestMessage" => GameMessage::ExchangePlayerMultiCraftRequestMessage(decode(name, data)?),
+            "ExchangePlayerRequestMessage" => GameMessage::ExchangePlayerRequestMessage(decode(name, data)?),
+            "ExchangePodsModifiedMessage" => GameMessage::ExchangePodsModifiedMessage(decode(name, data)?),
+            "ExchangeReadyMessage" => GameMessage::ExchangeReadyMessage(decode(name, data)?),
+            "ExchangeReplayStopMessage" => GameMessage::ExchangeReplayStopMessage(decode(name, data)?),
+            "ExchangeRequestMessage" => GameMessage::ExchangeRequestMessage(decode(name, data)?),
+            "ExchangeRequestOnMountStockMessage" => GameMessage::ExchangeRequestOnMountStockMessage(decode(name, data)?),
+            "ExchangeRequestOnTaxCollectorMessage" => GameMessage::ExchangeRequestOnTaxCollectorMessage(decode(name, data)?),
+            "ExchangeRequestedMessage" => GameMessage::ExchangeRequestedMessage(decode(name, data)?),
+            "ExchangeRequestedTradeMessage" => GameMessage::ExchangeRequestedTradeMessage(decode(name, data)?),
+            "ExchangeSellMessage" => GameMessage::ExchangeSellMessage(decode(name, data)?),
+            "ExchangeSellOkMessage" => GameMessage::ExchangeSellOkMessage(decode(name, data)?),
+            "ExchangeSetCraftRecipeMessage" => GameMessage::ExchangeSetCraftRecipeMessage(decode(name, data)?),
+            "ExchangeStartOkCraftMessage" => GameMessage::ExchangeStartOkCraftMessage(decode(name, data)?),
+            "ExchangeStartOkCraftWithInformationMessage" => GameMessage::ExchangeStartOkCraftWithInformationMessage(decode(name, data)?),
+            "ExchangeStartOkEvolutiveObjectRecycleTradeMessage" => GameMessage::ExchangeStartOkEvolutiveObjectRecycleTradeMessage(decode(name, data)?),
+            "ExchangeStartOkJobIndexMessage" => GameMessage::ExchangeStartOkJobIndexMessage(decode(name, data)?),
+            "ExchangeStartOkMountMessage" => GameMessage::ExchangeStartOkMountMessage(decode(name, data)?),
+            "ExchangeStartOkMountWithOutPaddockMessage" => GameMessage::ExchangeStartOkMountWithOutPaddockMessage(decode(name, data)?),
+            "ExchangeStartOkMulticraftCrafterMessage" => GameMessage::ExchangeStartOkMulticraftCrafterMessage(decode(name, data)?),
+            "ExchangeStartOkMulticraftCustomerMessage" => GameMessage::ExchangeStartOkMulticraftCustomerMessage(decode(name, data)?),
+            "ExchangeStartOkNpcShopMessage" => GameMessage::ExchangeStartOkNpcShopMessage(decode(name, data)?),
+            "ExchangeStartOkNpcTradeMessage" => GameMessage::ExchangeStartOkNpcTradeMessage(decode(name, data)?),
+            "ExchangeStartOkRecycleTradeMessage" => GameMessage::ExchangeStartOkRecycleTradeMessage(decode(name, data)?),
+            "ExchangeStartOkRunesTradeMessage" => GameMessage::ExchangeStartOkRunesTradeMessage(decode(name, data)?),
+            "ExchangeStartedBidBuyerMessage" => GameMessage::ExchangeStartedBidBuyerMessage(decode(name, data)?),
+            "ExchangeStartedBidSellerMessage" => GameMessage::ExchangeStartedBidSellerMessage(decode(name, data)?),
+            "ExchangeStartedMessage" => GameMessage::ExchangeStartedMessage(decode(name, data)?),
+            "ExchangeStartedMountStockMessage" => GameMessage::ExchangeStartedMountStockMessage(decode(name, data)?),
+            "ExchangeStartedTaxCollectorEquipmentMessage" => GameMessage::ExchangeStartedTaxCollectorEquipmentMessage(decode(name, data)?),
+            "ExchangeStartedTaxCollectorShopMessage" => GameMessage::ExchangeStartedTaxCollectorShopMessage(decode(name, data)?),
+            "ExchangeStartedWithMultiTabStorageMessage" => GameMessage::ExchangeStartedWithMultiTabStorageMessage(decode(name, data)?),
+            "ExchangeStartedWithPodsMessage" => GameMessage::ExchangeStartedWithPodsMessage(decode(name, data)?),
+            "ExchangeStartedWithStorageMessage" => GameMessage::ExchangeStartedWithStorageMessage(decode(name, data)?),
+            "ExchangeStoppedMessage" => GameMessage::ExchangeStoppedMessage(decode(name, data)?),
+            "ExchangeTaxCollectorGetMessage" => GameMessage::ExchangeTaxCollectorGetMessage(decode(name, data)?),
+            "ExchangeTypesExchangerDescriptionForUserMessage" => GameMessage::ExchangeTypesExchangerDescriptionForUserMessage(decode(name, data)?),
+            "ExchangeTypesItemsExchangerDescriptionForUserMessage" => GameMessage::ExchangeTypesItemsExchangerDescriptionForUserMessage(decode(name, data)?),
+            "ExchangeWaitingResultMessage" => GameMessage::ExchangeWaitingResultMessage(decode(name, data)?),
+            "ExchangeWeightMessage" => GameMessage::ExchangeWeightMessage(decode(name, data)?),
+            "ExitHavenBagRequestMessage" => GameMessage::ExitHavenBagRequestMessage(decode(name, data)?),
+            "FighterStatsListMessage" => GameMessage::FighterStatsListMessage(decode(name, data)?),
+            "FinishMoveListMessage" => GameMessage::FinishMoveListMessage(decode(name, data)?),
+            "FinishMoveListRequestMessage" => GameMessage::FinishMoveListRequestMessage(decode(name, data)?),
+            "FinishMoveSetRequestMessage" => GameMessage::FinishMoveSetRequestMessage(decode(name, data)?),
+            "FocusedExchangeReadyMessage" => GameMessage::FocusedExchangeReadyMessage(decode(name, data)?),
+            "FollowQuestObjectiveRequestMessage" => GameMessage::FollowQuestObjectiveRequestMessage(decode(name, data)?),
+            "FollowedQuestsMessage" => GameMessage::FollowedQuestsMessage(decode(name, data)?),
+            "ForceAccountErrorMessage" => GameMessage::ForceAccountErrorMessage(decode(name, data)?),
+            "ForceAccountMessage" => GameMessage::ForceAccountMessage(decode(name, data)?),
+            "ForceAccountStatusMessage" => GameMessage::ForceAccountStatusMessage(decode(name, data)?),
+            "ForgettableSpellClientActionMessage" => GameMessage::ForgettableSpellClientActionMessage(decode(name, data)?),
+            "ForgettableSpellDeleteMessage" => GameMessage::ForgettableSpellDeleteMessage(decode(name, data)?),
+            "ForgettableSpellEquipmentSlotsMessage" => GameMessage::ForgettableSpellEquipmentSlotsMessage(decode(name, data)?),
+            "ForgettableSpellListUpdateMessage" => GameMessage::ForgettableSpellListUpdateMessage(decode(name, data)?),
+            "FriendAddFailureMessage" => GameMessage::FriendAddFailureMessage(decode(name, data)?),
+            "FriendAddRequestMessage" => GameMessage::FriendAddRequestMessage(decode(name, data)?),
+            "FriendAddedMessage" => GameMessage::FriendAddedMessage(decode(name, data)?),
+            "FriendDeleteRequestMessage" => GameMessage::FriendDeleteRequestMessage(decode(name, data)?),
+            "FriendDeleteResultMessage" => GameMessage::FriendDeleteResultMessage(decode(name, data)?),
+            "FriendGuildSetWarnOnAchievementCompleteMessage" => GameMessage::FriendGuildSetWarnOnAchievementCompleteMessage(decode(name, data)?),
+            "FriendGuildWarnOnAchievementCompleteStateMessage" => GameMessage::FriendGuildWarnOnAchievementCompleteStateMessage(decode(name, data)?),
+            "FriendJoinRequestMessage" => GameMessage::FriendJoinRequestMessage(decode(name, data)?),
+            "FriendSetStatusShareMessage" => GameMessage::FriendSetStatusShareMessage(decode(name, data)?),
+            "FriendSetWarnOnConnectionMessage" => GameMessage::FriendSetWarnOnConnectionMessage(decode(name, data)?),
+            "FriendSetWarnOnLevelGainMessage" => GameMessage::FriendSetWarnOnLevelGainMessage(decode(name, data)?),
+            "FriendSpouseFollowWithCompassRequestMessage" => GameMessage::FriendSpouseFollowWithCompassRequestMessage(decode(name, data)?),
+            "FriendSpouseJoinRequestMessage" => GameMessage::FriendSpouseJoinRequestMessage(decode(name, data)?),
+            "FriendStatusShareStateMessage" => GameMessage::FriendStatusShareStateMessage(decode(name, data)?),
+            "FriendUpdateMessage" => GameMessage::FriendUpdateMessage(decode(name, data)?),
+            "FriendWarnOnConnectionStateMessage" => GameMessage::FriendWarnOnConnectionStateMessage(decode(name, data)?),
+            "FriendWarnOnLevelGainStateMessage" => GameMessage::FriendWarnOnLevelGainStateMessage(decode(name, data)?),
+            "FriendsGetListMessage" => GameMessage::FriendsGetListMessage(decode(name, data)?),
+            "FriendsListMessage" => GameMessage::FriendsListMessage(decode(name, data)?),
+            "GameActionAcknowledgementMessage" => GameMessage::GameActionAcknowledgementMessage(decode(name, data)?),
+            "GameActionFightActivateGlyphTrapMessage" => GameMessage::GameActionFightActivateGlyphTrapMessage(decode(name, data)?),
+            "GameActionFightCarryCharacterMessage" => GameMessage::GameActionFightCarryCharacterMessage(decode(name, data)?),
+            "GameActionFightCastOnTargetRequestMessage" => GameMessage::GameActionFightCastOnTargetRequestMessage(decode(name, data)?),
+            "GameActionFightCastRequestMessage" => GameMessage::GameActionFightCastRequestMessage(decode(name, data)?),
+            "GameActionFightChangeLookMessage" => GameMessage::GameActionFightChangeLookMessage(decode(name, data)?),
+            "GameActionFightCloseCombatMessage" => GameMessage::GameActionFightCloseCombatMessage(decode(name, data)?),
+            "GameActionFightDeathMessage" => GameMessage::GameActionFightDeathMessage(decode(name, data)?),
+            "GameActionFightDispellEffectMessage" => GameMessage::GameActionFightDispellEffectMessage(decode(name, data)?),
+            "GameActionFightDispellMessage" => GameMessage::GameActionFightDispellMessage(decode(name, data)?),
+            "GameActionFightDispellSpellMessage" => GameMessage::GameActionFightDispellSpellMessage(decode(name, data)?),
+            "GameActionFightDispellableEffectMessage" => GameMessage::GameActionFightDispellableEffectMessage(decode(name, data)?),
+            "GameActionFightDodgePointLossMessage" => GameMessage::GameActionFightDodgePointLossMessage(decode(name, data)?),
+            "GameActionFightDropCharacterMessage" => GameMessage::GameActionFightDropCharacterMessage(decode(name, data)?),
+            "GameActionFightExchangePositionsMessage" => GameMessage::GameActionFightExchangePositionsMessage(decode(name, data)?),
+            "GameActionFightInvisibilityMessage" => GameMessage::GameActionFightInvisibilityMessage(decode(name, data)?),
+            "GameActionFightInvisibleDetectedMessage" => GameMessage::GameActionFightInvisibleDetectedMessage(decode(name, data)?),
+            "GameActionFightKillMessage" => GameMessage::GameActionFightKillMessage(decode(name, data)?),
+            "GameActionFightLifeAndShieldPointsLostMessage" => GameMessage::GameActionFightLifeAndShieldPointsLostMessage(decode(name, data)?),
+            "GameActionFightLifePointsGainMessage" => GameMessage::GameActionFightLifePointsGainMessage(decode(name, data)?),
+            "GameActionFightLifePointsLostMessage" => GameMessage::GameActionFightLifePointsLostMessage(decode(name, data)?),
+            "GameActionFightMarkCellsMessage" => GameMessage::GameActionFightMarkCellsMessage(decode(name, data)?),
+            "GameActionFightModifyEffectsDurationMessage" => GameMessage::GameActionFightModifyEffectsDurationMessage(decode(name, data)?),
+            "GameActionFightMultipleSummonMessage" => GameMessage::GameActionFightMultipleSummonMessage(decode(name, data)?),
+            "GameActionFightNoSpellCastMessage" => GameMessage::GameActionFightNoSpellCastMessage(decode(name, data)?),
+            "GameActionFightPointsVariationMessage" => GameMessage::GameActionFightPointsVariationMessage(decode(name, data)?),
+            "GameActionFightReduceDamagesMessage" => GameMessage::GameActionFightReduceDamagesMessage(decode(name, data)?),
+            "GameActionFightReflectDamagesMessage" => GameMessage::GameActionFightReflectDamagesMessage(decode(name, data)?),
+            "GameActionFightReflectSpellMessage" => GameMessage::GameActionFightReflectSpellMessage(decode(name, data)?),
+            "GameActionFightSlideMessage" => GameMessage::GameActionFightSlideMessage(decode(name, data)?),
+            "GameActionFightSpellCastMessage" => GameMessage::GameActionFightSpellCastMessage(decode(name, data)?),
+            "GameActionFightSpellCooldownVariationMessage" => GameMessage::GameActionFightSpellCooldownVariationMessage(decode(name, data)?),
+            "GameActionFightSpellImmunityMessage" => GameMessage::GameActionFightSpellImmunityMessage(decode(name, data)?),
+            "GameActionFightStealKamaMessage" => GameMessage::GameActionFightStealKamaMessage(decode(name, data)?),
+            "GameActionFightSummonMessage" => GameMessage::GameActionFightSummonMessage(decode(name, data)?),
+            "GameActionFightTackledMessage" => GameMessage::GameActionFightTackledMessage(decode(name, data)?),
+            "GameActionFightTeleportOnSameMapMessage" => GameMessage::GameActionFightTeleportOnSameMapMessage(decode(name, data)?),
+            "GameActionFightThrowCharacterMessage" => GameMessage::GameActionFightThrowCharacterMessage(decode(name, data)?),
+            "GameActionFightTriggerEffectMessage" => GameMessage::GameActionFightTriggerEffectMessage(decode(name, data)?),
+            "GameActionFightTriggerGlyphTrapMessage" => GameMessage::GameActionFightTriggerGlyphTrapMessage(decode(name, data)?),
+            "GameActionFightUnmarkCellsMessage" => GameMessage::GameActionFightUnmarkCellsMessage(decode(name, data)?),
+            "GameActionFightVanishMessage" => GameMessage::GameActionFightVanishMessage(decode(name, data)?),
+            "GameActionItemAddMessage" => GameMessage::GameActionItemAddMessage(decode(name, data)?),
+            "GameActionItemConsumedMessage" => GameMessage::GameActionItemConsumedMessage(decode(name, data)?),
+            "GameActionItemListMessage" => GameMessage::GameActionItemListMessage(decode(name, data)?),
+            "GameActionNoopMessage" => GameMessage::GameActionNoopMessage(decode(name, data)?),
+            "GameActionSpamMessage" => GameMessage::GameActionSpamMessage(decode(name, data)?),
+            "GameActionUpdateEffectTriggerCountMessage" => GameMessage::GameActionUpdateEffectTriggerCountMessage(decode(name, data)?),
+            "GameCautiousMapMovementMessage" => GameMessage::GameCautiousMapMovementMessage(decode(name, data)?),
+            "GameCautiousMapMovementRequestMessage" => GameMessage::GameCautiousMapMovementRequestMessage(decode(name, data)?),
+            "GameContextCreateErrorMessage" => GameMessage::GameContextCreateErrorMessage(decode(name, data)?),
+            "GameContextCreateMessage" => GameMessage::GameContextCreateMessage(decode(name, data)?),
+            "GameContextCreateRequestMessage" => GameMessage::GameContextCreateRequestMessage(decode(name, data)?),
+            "GameContextDestroyMessage" => GameMessage::GameContextDestroyMessage(decode(name, data)?),
+            "GameContextKickMessage" => GameMessage::GameContextKickMessage(decode(name, data)?),
+            "GameContextMoveElementMessage" => GameMessage::GameContextMoveElementMessage(decode(name, data)?),
+            "GameContextMoveMultipleElementsMessage" => GameMessage::GameContextMoveMultipleElementsMessage(decode(name, data)?),
+            "GameContextQuitMessage" => GameMessage::GameContextQuitMessage(decode(name, data)?),
+            "GameContextReadyMessage" => GameMessage::GameContextReadyMessage(decode(name, data)?),
+            "GameContextRefreshEntityLookMessage" => GameMessage::GameContextRefreshEntityLookMessage(decode(name, data)?),
+            "GameContextRemoveElementMessage" => GameMessage::GameContextRemoveElementMessage(decode(name, data)?),
+            "GameContextRemoveElementWithEventMessage" => GameMessage::GameContextRemoveElementWithEventMessage(decode(name, data)?),
+            "GameContextRemoveMultipleElementsMessage" => GameMessage::GameContextRemoveMultipleElementsMessage(decode(name, data)?),
+            "GameContextRemoveMultipleElementsWithEventsMessage" => GameMessage::GameContextRemoveMultipleElementsWithEventsMessage(decode(name, data)?),
+            "GameDataPaddockObjectAddMessage" => GameMessage::GameDataPaddockObjectAddMessage(decode(name, data)?),
+            "GameDataPaddockObjectListAddMessage" => GameMessage::GameDataPaddockObjectListAddMessage(decode(name, data)?),
+            "GameDataPaddockObjectRemoveMessage" => GameMessage::GameDataPaddockObjectRemoveMessage(decode(name, data)?),
+            "GameDataPlayFarmObjectAnimationMessage" => GameMessage::GameDataPlayFarmObjectAnimationMessage(decode(name, data)?),
+            "GameEntitiesDispositionMessage" => GameMessage::GameEntitiesDispositionMessage(decode(name, data)?),
+            "GameEntityDispositionErrorMessage" => GameMessage::GameEntityDispositionErrorMessage(decode(name, data)?),
+            "GameEntityDispositionMessage" => GameMessage::GameEntityDispositionMessage(decode(name, data)?),
+            "GameFightEndMessage" => GameMessage::GameFightEndMessage(decode(name, data)?),
+            "GameFightHumanReadyStateMessage" => GameMessage::GameFightHumanReadyStateMessage(decode(name, data)?),
+            "GameFightJoinMessage" => GameMessage::GameFightJoinMessage(decode(name, data)?),
+            "GameFightJoinRequestMessage" => GameMessage::GameFightJoinRequestMessage(decode(name, data)?),
+            "GameFightLeaveMessage" => GameMessage::GameFightLeaveMessage(decode(name, data)?),
+            "GameFightNewRoundMessage" => GameMessage::GameFightNewRoundMessage(decode(name, data)?),
+            "GameFightNewWaveMessage" => GameMessage::GameFightNewWaveMessage(decode(name, data)?),
+            "GameFightOptionStateUpdateMessage" => GameMessage::GameFightOptionStateUpdateMessage(decode(name, data)?),
+            "GameFightOptionToggleMessage" => GameMessage::GameFightOptionToggleMessage(decode(name, data)?),
+            "GameFightPauseMessage" => GameMessage::GameFightPauseMessage(decode(name, data)?),
+            "GameFightPlacementPositionRequestMessage" => GameMessage::GameFightPlacementPositionRequestMessage(decode(name, data)?),
+            "GameFightPlacementPossiblePositionsMessage" => GameMessage::GameFightPlacementPossiblePositionsMessage(decode(name, data)?),
+            "GameFightPlacementSwapPositionsAcceptMessage" => GameMessage::GameFightPlacementSwapPositionsAcceptMessage(decode(name, data)?),
+            "GameFightPlacementSwapPositionsCancelMessage" => GameMessage::GameFightPlacementSwapPositionsCancelMessage(decode(name, data)?),
+            "GameFightPlacementSwapPositionsCancelledMessage" => GameMessage::GameFightPlacementSwapPositionsCancelledMessage(decode(name, data)?),
+            "GameFightPlacementSwapPositionsErrorMessage" => GameMessage::GameFightPlacementSwapPositionsErrorMessage(decode(name, data)?),
+            "GameFightPlacementSwapPositionsMessage" => GameMessage::GameFightPlacementSwapPositionsMessage(decode(name, data)?),
+            "GameFightPlacementSwapPositionsOfferMessage" => GameMessage::GameFightPlacementSwapPositionsOfferMessage(decode(name, data)?),
+            "GameFightPlacementSwapPositionsRequestMessage" => GameMessage::GameFightPlacementSwapPositionsRequestMessage(decode(name, data)?),
+            "GameFightReadyMessage" => GameMessage::GameFightReadyMessage(decode(name, data)?),
+            "GameFightRefreshFighterMessage" => GameMessage::GameFightRefreshFighterMessage(decode(name, data)?),
+            "GameFightRemoveTeamMemberMessage" => GameMessage::GameFightRemoveTeamMemberMessage(decode(name, data)?),
+            "GameFightResumeMessage" => GameMessage::GameFightResumeMessage(decode(name, data)?),
+            "GameFightResumeWithSlavesMessage" => GameMessage::GameFightResumeWithSlavesMessage(decode(name, data)?),
+            "GameFightShowFighterMessage" => GameMessage::GameFightShowFighterMessage(decode(name, data)?),
+            "GameFightShowFighterRandomStaticPoseMessage" => GameMessage::GameFightShowFighterRandomStaticPoseMessage(decode(name, data)?),
+            "GameFightSpectateMessage" => GameMessage::GameFightSpectateMessage(decode(name, data)?),
+            "GameFightSpectatePlayerRequestMessage" => GameMessage::GameFightSpectatePlayerRequestMessage(decode(name, data)?),
+            "GameFightSpectatorJoinMessage" => GameMessage::GameFightSpectatorJoinMessage(decode(name, data)?),
+            "GameFightStartMessage" => GameMessage::GameFightStartMessage(decode(name, data)?),
+            "GameFightStartingMessage" => GameMessage::GameFightStartingMessage(decode(name, data)?),
+            "GameFightSynchronizeMessage" => GameMessage::GameFightSynchronizeMessage(decode(name, data)?),
+            "GameFightTurnEndMessage" => GameMessage::GameFightTurnEndMessage(decode(name, data)?),
+            "GameFightTurnFinishMessage" => GameMessage::GameFightTurnFinishMessage(decode(name, data)?),
+            "GameFightTurnListMessage" => GameMessage::GameFightTurnListMessage(decode(name, data)?),
+            "GameFightTurnReadyMessage" => GameMessage::GameFightTurnReadyMessage(decode(name, data)?),
+            "GameFightTurnReadyRequestMessage" => GameMessage::GameFightTurnReadyRequestMessage(decode(name, data)?),
+            "GameFightTurnResumeMessage" => GameMessage::GameFightTurnResumeMessage(decode(name, data)?),
+            "GameFightTurnStartMessage" => GameMessage::GameFightTurnStartMessage(decode(name, data)?),
+            "GameFightTurnStartPlayingMessage" => GameMessage::GameFightTurnStartPlayingMessage(decode(name, data)?),
+            "GameFightUpdateTeamMessage" => GameMessage::GameFightUpdateTeamMessage(decode(name, data)?),
+            "GameMapChangeOrientationMessage" => GameMessage::GameMapChangeOrientationMessage(decode(name, data)?),
+            "GameMapChangeOrientationRequestMessage" => GameMessage::GameMapChangeOrientationRequestMessage(decode(name, data)?),
+            "GameMapChangeOrientationsMessage" => GameMessage::GameMapChangeOrientationsMessage(decode(name, data)?),
+            "GameMapMovementCancelMessage" => GameMessage::GameMapMovementCancelMessage(decode(name, data)?),
+            "GameMapMovementConfirmMessage" => GameMessage::GameMapMovementConfirmMessage(decode(name, data)?),
+            "GameMapMovementMessage" => GameMessage::GameMapMovementMessage(decode(name, data)?),
+            "GameMapMovementRequestMessage" => GameMessage::GameMapMovementRequestMessage(decode(name, data)?),
+            "GameMapNoMovementMessage" => GameMessage::GameMapNoMovementMessage(decode(name, data)?),
+            "GameRefreshMonsterBoostsMessage" => GameMessage::GameRefreshMonsterBoostsMessage(decode(name, data)?),
+            "GameRolePlayAggressionMessage" => GameMessage::GameRolePlayAggressionMessage(decode(name, data)?),
+            "GameRolePlayArenaFightAnswerMessage" => GameMessage::GameRolePlayArenaFightAnswerMessage(decode(name, data)?),
+            "GameRolePlayArenaFightPropositionMessage" => GameMessage::GameRolePlayArenaFightPropositionMessage(decode(name, data)?),
+            "GameRolePlayArenaFighterStatusMessage" => GameMessage::GameRolePlayArenaFighterStatusMessage(decode(name, data)?),
+            "GameRolePlayArenaInvitationCandidatesAnswerMessage" => GameMessage::GameRolePlayArenaInvitationCandidatesAnswerMessage(decode(name, data)?),
+            "GameRolePlayArenaLeagueRewardsMessage" => GameMessage::GameRolePlayArenaLeagueRewardsMessage(decode(name, data)?),
+            "GameRolePlayArenaPlayerBehavioursMessage" => GameMessage::GameRolePlayArenaPlayerBehavioursMessage(decode(name, data)?),
+            "GameRolePlayArenaRegisterMessage" => GameMessage::GameRolePlayArenaRegisterMessage(decode(name, data)?),
+            "GameRolePlayArenaRegistrationStatusMessage" => GameMessage::GameRolePlayArenaRegistrationStatusMessage(decode(name, data)?),
+            "GameRolePlayArenaRegistrationWarningMessage" => GameMessage::GameRolePlayArenaRegistrationWarningMessage(decode(name, data)?),
+            "GameRolePlayArenaSwitchToFightServerMessage" => GameMessage::GameRolePlayArenaSwitchToFightServerMessage(decode(name, data)?),
+            "GameRolePlayArenaSwitchToGameServerMessage" => GameMessage::GameRolePlayArenaSwitchToGameServerMessage(decode(name, data)?),
+            "GameRolePlayArenaUnregisterMessage" => GameMessage::GameRolePlayArenaUnregisterMessage(decode(name, data)?),
+            "GameRolePlayArenaUpdatePlayerInfosMessage" => GameMessage::GameRolePlayArenaUpdatePlayerInfosMessage(decode(name, data)?),
+            "GameRolePlayAttackMonsterRequestMessage" => GameMessage::GameRolePlayAttackMonsterRequestMessage(decode(name, data)?),
+            "GameRolePlayDelayedActionFinishedMessage" => GameMessage::GameRolePlayDelayedActionFinishedMessage(decode(name, data)?),
+            "GameRolePlayDelayedActionMessage" => GameMessage::GameRolePlayDelayedActionMessage(decode(name, data)?),
+            "GameRolePlayDelayedObjectUseMessage" => GameMessage::GameRolePlayDelayedObjectUseMessage(decode(name, data)?),
+            "GameRolePlayFightRequestCanceledMessage" => GameMessage::GameRolePlayFightRequestCanceledMessage(decode(name, data)?),
+            "GameRolePlayFreeSoulRequestMessage" => GameMessage::GameRolePlayFreeSoulRequestMessage(decode(name, data)?),
+            "GameRolePlayGameOverMessage" => GameMessage::GameRolePlayGameOverMessage(decode(name, data)?),
+            "GameRolePlayMonsterAngryAtPlayerMessage" => GameMessage::GameRolePlayMonsterAngryAtPlayerMessage(decode(name, data)?),
+            "GameRolePlayMonsterNotAngryAtPlayerMessage" => GameMessage::GameRolePlayMonsterNotAngryAtPlayerMessage(decode(name, data)?),
+            "GameRolePlayPlayerFightFriendlyAnswerMessage" => GameMessage::GameRolePlayPlayerFightFriendlyAnswerMessage(decode(name, data)?),
+            "GameRolePlayPlayerFightFriendlyAnsweredMessage" => GameMessage::GameRolePlayPlayerFightFriendlyAnsweredMessage(decode(name, data)?),
+            "GameRolePlayPlayerFightFriendlyRequestedMessage" => GameMessage::GameRolePlayPlayerFightFriendlyRequestedMessage(decode(name, data)?),
+            "GameRolePlayPlayerFightRequestMessage" => GameMessage::GameRolePlayPlayerFightRequestMessage(decode(name, data)?),
+            "GameRolePlayPlayerLifeStatusMessage" => GameMessage::GameRolePlayPlayerLifeStatusMessage(decode(name, data)?),
+            "GameRolePlayRemoveChallengeMessage" => GameMessage::GameRolePlayRemoveChallengeMessage(decode(name, data)?),
+            "GameRolePlayShowActorMessage" => GameMessage::GameRolePlayShowActorMessage(decode(name, data)?),
+            "GameRolePlayShowActorWithEventMessage" => GameMessage::GameRolePlayShowActorWithEventMessage(decode(name, data)?),
+            "GameRolePlayShowChallengeMessage" => GameMessage::GameRolePlayShowChallengeMessage(decode(name, data)?),
+            "GameRolePlayShowMultipleActorsMessage" => GameMessage::GameRolePlayShowMultipleActorsMessage(decode(name, data)?),
+            "GameRolePlaySpellAnimMessage" => GameMessage::GameRolePlaySpellAnimMessage(decode(name, data)?),
+            "GameRolePlayTaxCollectorFightRequestMessage" => GameMessage::GameRolePlayTaxCollectorFightRequestMessage(decode(name, data)?),
+            "GoldAddedMessage" => GameMessage::GoldAddedMessage(decode(name, data)?),
+            "GroupTeleportPlayerAnswerMessage" => GameMessage::GroupTeleportPlayerAnswerMessage(decode(name, data)?),
+            "GroupTeleportPlayerCloseMessage" => GameMessage::GroupTeleportPlayerCloseMessage(decode(name, data)?),
+            "GroupTeleportPlayerOfferMessage" => GameMessage::GroupTeleportPlayerOfferMessage(decode(name, data)?),
+            "GuestLimitationMessage" => GameMessage::GuestLimitationMessage(decode(name, data)?),
+            "GuestModeMessage" => GameMessage::GuestModeMessage(decode(name, data)?),
+            "GuidedModeQuitRequestMessage" => GameMessage::GuidedModeQuitRequestMessage(decode(name, data)?),
+            "GuidedModeReturnRequestMessage" => GameMessage::GuidedModeReturnRequestMessage(decode(name, data)?),
+            "GuildApplicationAnswerMessage" => GameMessage::GuildApplicationAnswerMessage(decode(name, data)?),
+            "GuildApplicationDeletedMessage" => GameMessage::GuildApplicationDeletedMessage(decode(name, data)?),
+            "GuildApplicationIsAnsweredMessage" => GameMessage::GuildApplicationIsAnsweredMessage(decode(name, data)?),
+            "GuildApplicationListenMessage" => GameMessage::GuildApplicationListenMessage(decode(name, data)?),
+            "GuildApplicationPresenceMessage" => GameMessage::GuildApplicationPresenceMessage(decode(name, data)?),
+            "GuildApplicationReceivedMessage" => GameMessage::GuildApplicationReceivedMessage(decode(name, data)?),
+            "GuildBulletinMessage" => GameMessage::GuildBulletinMessage(decode(name, data)?),
+            "GuildBulletinSetErrorMessage" => GameMessage::GuildBulletinSetErrorMessage(decode(name, data)?),
+            "GuildBulletinSetRequestMessage" => GameMessage::GuildBulletinSetRequestMessage(decode(name, data)?),
+            "GuildChangeMemberParametersMessage" => GameMessage::GuildChangeMemberParametersMessage(decode(name, data)?),
+            "GuildCharacsUpgradeRequestMessage" => GameMessage::GuildCharacsUpgradeRequestMessage(decode(name, data)?),
+            "GuildChestTabContributionMessage" => GameMessage::GuildChestTabContributionMessage(decode(name, data)?),
+            "GuildChestTabContributionsMessage" => GameMessage::GuildChestTabContributionsMessage(decode(name, data)?),
+            "GuildChestTabLastContributionMessage" => GameMessage::GuildChestTabLastContributionMessage(decode(name, data)?),
+            "GuildCreationResultMessage" => GameMessage::GuildCreationResultMessage(decode(name, data)?),
+            "GuildCreationStartedMessage" => GameMessage::GuildCreationStartedMessage(decode(name, data)?),
+            "GuildCreationValidMessage" => GameMessage::GuildCreationValidMessage(decode(name, data)?),
+            "GuildDeleteApplicationRequestMessage" => GameMessage::GuildDeleteApplicationRequestMessage(decode(name, data)?),
+            "GuildFactsErrorMessage" => GameMessage::GuildFactsErrorMessage(decode(name, data)?),
+            "GuildFactsMessage" => GameMessage::GuildFactsMessage(decode(name, data)?),
+            "GuildFactsRequestMessage" => GameMessage::GuildFactsRequestMessage(decode(name, data)?),
+            "GuildGetChestTabContributionsRequestMessage" => GameMessage::GuildGetChestTabContributionsRequestMessage(decode(name, data)?),
+            "GuildGetInformationsMessage" => GameMessage::GuildGetInformationsMessage(decode(name, data)?),
+            "GuildGetPlayerApplicationMessage" => GameMessage::GuildGetPlayerApplicationMessage(decode(name, data)?),
+            "GuildHouseRemoveMessage" => GameMessage::GuildHouseRemoveMessage(decode(name, data)?),
+            "GuildHouseUpdateInformationMessage" => GameMessage::GuildHouseUpdateInformationMessage(decode(name, data)?),
+            "GuildHousesInformationMessage" => GameMessage::GuildHousesInformationMessage(decode(name, data)?),
+            "GuildInformationsGeneralMessage" => GameMessage::GuildInformationsGeneralMessage(decode(name, data)?),
+            "GuildInformationsMemberUpdateMessage" => GameMessage::GuildInformationsMemberUpdateMessage(decode(name, data)?),
+            "GuildInformationsMembersMessage" => GameMessage::GuildInformationsMembersMessage(decode(name, data)?),
+            "GuildInformationsPaddocksMessage" => GameMessage::GuildInformationsPaddocksMessage(decode(name, data)?),
+            "GuildInvitationAnswerMessage" => GameMessage::GuildInvitationAnswerMessage(decode(name, data)?),
+            "GuildInvitationMessage" => GameMessage::GuildInvitationMessage(decode(name, data)?),
+            "GuildInvitationStateRecrutedMessage" => GameMessage::GuildInvitationStateRecrutedMessage(decode(name, data)?),
+            "GuildInvitationStateRecruterMessage" => GameMessage::GuildInvitationStateRecruterMessage(decode(name, data)?),
+            "GuildInvitedMessage" => GameMessage::GuildInvitedMessage(decode(name, data)?),
+            "GuildIsThereAnyApplicationMessage" => GameMessage::GuildIsThereAnyApplicationMessage(decode(name, data)?),
+            "GuildJoinAutomaticallyRequestMessage" => GameMessage::GuildJoinAutomaticallyRequestMessage(decode(name, data)?),
+            "GuildJoinedMessage" => GameMessage::GuildJoinedMessage(decode(name, data)?),
+            "GuildKickRequestMessage" => GameMessage::GuildKickRequestMessage(decode(name, data)?),
+            "GuildLeftMessage" => GameMessage::GuildLeftMessage(decode(name, data)?),
+            "GuildLevelUpMessage" => GameMessage::GuildLevelUpMessage(decode(name, data)?),
+            "GuildListApplicationAnswerMessage" => GameMessage::GuildListApplicationAnswerMessage(decode(name, data)?),
+            "GuildListApplicationModifiedMessage" => GameMessage::GuildListApplicationModifiedMessage(decode(name, data)?),
+            "GuildListApplicationRequestMessage" => GameMessage::GuildListApplicationRequestMessage(decode(name, data)?),
+            "GuildListMessage" => GameMessage::GuildListMessage(decode(name, data)?),
+            "GuildLogbookInformationMessage" => GameMessage::GuildLogbookInformationMessage(decode(name, data)?),
+            "GuildLogbookInformationRequestMessage" => GameMessage::GuildLogbookInformationRequestMessage(decode(name, data)?),
+            "GuildMemberLeavingMessage" => GameMessage::GuildMemberLeavingMessage(decode(name, data)?),
+            "GuildMemberOnlineStatusMessage" => GameMessage::GuildMemberOnlineStatusMessage(decode(name, data)?),
+            "GuildMemberStartWarnOnConnectionMessage" => GameMessage::GuildMemberStartWarnOnConnectionMessage(decode(name, data)?),
+            "GuildMemberStopWarnOnConnectionMessage" => GameMessage::GuildMemberStopWarnOnConnectionMessage(decode(name, data)?),
+            "GuildMembershipMessage" => GameMessage::GuildMembershipMessage(decode(name, data)?),
+            "GuildModificationEmblemValidMessage" => GameMessage::GuildModificationEmblemValidMessage(decode(name, data)?),
+            "GuildModificationNameValidMessage" => GameMessage::GuildModificationNameValidMessage(decode(name, data)?),
+            "GuildModificationResultMessage" => GameMessage::GuildModificationResultMessage(decode(name, data)?),
+            "GuildModificationStartedMessage" => GameMessage::GuildModificationStartedMessage(decode(name, data)?),
+            "GuildModificationValidMessage" => GameMessage::GuildModificationValidMessage(decode(name, data)?),
+            "GuildMotdMessage" => GameMessage::GuildMotdMessage(decode(name, data)?),
+            "GuildMotdSetErrorMessage" => GameMessage::GuildMotdSetErrorMessage(decode(name, data)?),
+            "GuildMotdSetRequestMessage" => GameMessage::GuildMotdSetRequestMessage(decode(name, data)?),
+            "GuildPaddockBoughtMessage" => GameMessage::GuildPaddockBoughtMessage(decode(name, data)?),
+            "GuildPaddockRemovedMessage" => GameMessage::GuildPaddockRemovedMessage(decode(name, data)?),
+            "GuildPaddockTeleportRequestMessage" => GameMessage::GuildPaddockTeleportRequestMessage(decode(name, data)?),
+            "GuildPlayerApplicationAbstractMessage" => GameMessage::GuildPlayerApplicationAbstractMessage(decode(name, data)?),
+            "GuildPlayerApplicationInformationMessage" => GameMessage::GuildPlayerApplicationInformationMessage(decode(name, data)?),
+            "GuildPlayerNoApplicationInformationMessage" => GameMessage::GuildPlayerNoApplicationInformationMessage(decode(name, data)?),
+            "GuildRanksMessage" => GameMessage::GuildRanksMessage(decode(name, data)?),
+            "GuildRanksRequestMessage" => GameMessage::GuildRanksRequestMessage(decode(name, data)?),
+            "GuildRecruitmentInvalidateMessage" => GameMessage::GuildRecruitmentInvalidateMessage(decode(name, data)?),
+            "GuildSelectChestTabRequestMessage" => GameMessage::GuildSelectChestTabRequestMessage(decode(name, data)?),
+            "GuildSpellUpgradeRequestMessage" => GameMessage::GuildSpellUpgradeRequestMessage(decode(name, data)?),
+            "GuildSubmitApplicationMessage" => GameMessage::GuildSubmitApplicationMessage(decode(name, data)?),
+            "GuildSummaryMessage" => GameMessage::GuildSummaryMessage(decode(name, data)?),
+            "GuildSummaryRequestMessage" => GameMessage::GuildSummaryRequestMessage(decode(name, data)?),
+            "GuildUpdateApplicationMessage" => GameMessage::GuildUpdateApplicationMessage(decode(name, data)?),
+            "GuildUpdateChestTabRequestMessage" => GameMessage::GuildUpdateChestTabRequestMessage(decode(name, data)?),
+            "GuildUpdateNoteMessage" => GameMessage::GuildUpdateNoteMessage(decode(name, data)?),
+            "HaapiApiKeyMessage" => GameMessage::HaapiApiKeyMessage(decode(name, data)?),
+            "HaapiApiKeyRequestMessage" => GameMessage::HaapiApiKeyRequestMessage(decode(name, data)?),
+            "HaapiAuthErrorMessage" => GameMessage::HaapiAuthErrorMessage(decode(name, data)?),
+            "HaapiBufferListMessage" => GameMessage::HaapiBufferListMessage(decode(name, data)?),
+            "HaapiBufferListRequestMessage" => GameMessage::HaapiBufferListRequestMessage(decode(name, data)?),
+            "HaapiBuyValidationMessage" => GameMessage::HaapiBuyValidationMessage(decode(name, data)?),
+            "HaapiCancelBidRequestMessage" => GameMessage::HaapiCancelBidRequestMessage(decode(name, data)?),
+            "HaapiConfirmationMessage" => GameMessage::HaapiConfirmationMessage(decode(name, data)?),
+            "HaapiConfirmationRequestMessage" => GameMessage::HaapiConfirmationRequestMessage(decode(name, data)?),
+            "HaapiConsumeBufferRequestMessage" => GameMessage::HaapiConsumeBufferRequestMessage(decode(name, data)?),
+            "HaapiSessionMessage" => GameMessage::HaapiSessionMessage(decode(name, data)?),
+            "HaapiShopApiKeyMessage" => GameMessage::HaapiShopApiKeyMessage(decode(name, data)?),
+            "HaapiShopApiKeyRequestMessage" => GameMessage::HaapiShopApiKeyRequestMessage(decode(name, data)?),
+            "HaapiTokenMessage" => GameMessage::HaapiTokenMessage(decode(name, data)?),
+            "HaapiTokenRequestMessage" => GameMessage::HaapiTokenRequestMessage(decode(name, data)?),
+            "HaapiValidationMessage" => GameMessage::HaapiValidationMessage(decode(name, data)?),
+            "HaapiValidationRequestMessage" => GameMessage::HaapiValidationRequestMessage(decode(name, data)?),
+            "HavenBagDailyLoteryMessage" => GameMessage::HavenBagDailyLoteryMessage(decode(name, data)?),
+            "HavenBagFurnituresMessage" => GameMessage::HavenBagFurnituresMessage(decode(name, data)?),
+            "HavenBagFurnituresRequestMessage" => GameMessage::HavenBagFurnituresRequestMessage(decode(name, data)?),
+            "HavenBagPackListMessage" => GameMessage::HavenBagPackListMessage(decode(name, data)?),
+            "HavenBagPermissionsUpdateMessage" => GameMessage::HavenBagPermissionsUpdateMessage(decode(name, data)?),
+            "HavenBagPermissionsUpdateRequestMessage" => GameMessage::HavenBagPermissionsUpdateRequestMessage(decode(name, data)?),
+            "HavenBagRoomUpdateMessage" => GameMessage::HavenBagRoomUpdateMessage(decode(name, data)?),
+            "HelloConnectMessage" => GameMessage::HelloConnectMessage(decode(name, data)?),
+            "HelloGameMessage" => GameMessage::HelloGameMessage(decode(name, data)?),
+            "HouseBuyRequestMessage" => GameMessage::HouseBuyRequestMessage(decode(name, data)?),
+            "HouseBuyResultMessage" => GameMessage::HouseBuyResultMessage(decode(name, data)?),
+            "HouseGuildNoneMessage" => GameMessage::HouseGuildNoneMessage(decode(name, data)?),
+            "HouseGuildRightsMessage" => GameMessage::HouseGuildRightsMessage(decode(name, data)?),
+            "HouseGuildRightsViewMessage" => GameMessage::HouseGuildRightsViewMessage(decode(name, data)?),
+            "HouseGuildShareRequestMessage" => GameMessage::HouseGuildShareRequestMessage(decode(name, data)?),
+            "HouseKickRequestMessage" => GameMessage::HouseKickRequestMessage(decode(name, data)?),
+            "HouseLockFromInsideRequestMessage" => GameMessage::HouseLockFromInsideRequestMessage(decode(name, data)?),
+            "HousePropertiesMessage" => GameMessage::HousePropertiesMessage(decode(name, data)?),
+            "HouseSellFromInsideRequestMessage" => GameMessage::HouseSellFromInsideRequestMessage(decode(name, data)?),
+            "HouseSellRequestMessage" => GameMessage::HouseSellRequestMessage(decode(name, data)?),
+            "HouseSellingUpdateMessage" => GameMessage::HouseSellingUpdateMessage(decode(name, data)?),
+            "HouseTeleportRequestMessage" => GameMessage::HouseTeleportRequestMessage(decode(name, data)?),
+            "HouseToSellFilterMessage" => GameMessage::HouseToSellFilterMessage(decode(name, data)?),
+            "HouseToSellListMessage" => GameMessage::HouseToSellListMessage(decode(name, data)?),
+            "HouseToSellListRequestMessage" => GameMessage::HouseToSellListRequestMessage(decode(name, data)?),
+            "IconNamedPresetSaveRequestMessage" => GameMessage::IconNamedPresetSaveRequestMessage(decode(name, data)?),
+            "IconPresetSaveRequestMessage" => GameMessage::IconPresetSaveRequestMessage(decode(name, data)?),
+            "IdentificationFailedBannedMessage" => GameMessage::IdentificationFailedBannedMessage(decode(name, data)?),
+            "IdentificationFailedForBadVersionMessage" => GameMessage::IdentificationFailedForBadVersionMessage(decode(name, data)?),
+            "IdentificationFailedMessage" => GameMessage::IdentificationFailedMessage(decode(name, data)?),
+            "IdentificationMessage" => GameMessage::IdentificationMessage(decode(name, data)?),
+            "IdentificationSuccessMessage" => GameMessage::IdentificationSuccessMessage(decode(name, data)?),
+            "IdentificationSuccessWithLoginTokenMessage" => GameMessage::IdentificationSuccessWithLoginTokenMessage(decode(name, data)?),
+            "IgnoredAddFailureMessage" => GameMessage::IgnoredAddFailureMessage(decode(name, data)?),
+            "IgnoredAddRequestMessage" => GameMessage::IgnoredAddRequestMessage(decode(name, data)?),
+            "IgnoredAddedMessage" => GameMessage::IgnoredAddedMessage(decode(name, data)?),
+            "IgnoredDeleteRequestMessage" => GameMessage::IgnoredDeleteRequestMessage(decode(name, data)?),
+            "IgnoredDeleteResultMessage" => GameMessage::IgnoredDeleteResultMessage(decode(name, data)?),
+            "IgnoredGetListMessage" => GameMessage::IgnoredGetListMessage(decode(name, data)?),
+            "IgnoredListMessage" => GameMessage::IgnoredListMessage(decode(name, data)?),
+            "InteractiveElementUpdatedMessage" => GameMessage::InteractiveElementUpdatedMessage(decode(name, data)?),
+            "InteractiveMapUpdateMessage" => GameMessage::InteractiveMapUpdateMessage(decode(name, data)?),
+            "InteractiveUseEndedMessage" => GameMessage::InteractiveUseEndedMessage(decode(name, data)?),
+            "InteractiveUseErrorMessage" => GameMessage::InteractiveUseErrorMessage(decode(name, data)?),
+            "InteractiveUseRequestMessage" => GameMessage::InteractiveUseRequestMessage(decode(name, data)?),
+            "InteractiveUseWithParamRequestMessage" => GameMessage::InteractiveUseWithParamRequestMessage(decode(name, data)?),
+            "InteractiveUsedMessage" => GameMessage::InteractiveUsedMessage(decode(name, data)?),
+            "InvalidPresetsMessage" => GameMessage::InvalidPresetsMessage(decode(name, data)?),
+            "InventoryContentMessage" => GameMessage::InventoryContentMessage(decode(name, data)?),
+            "InventoryWeightMessage" => GameMessage::InventoryWeightMessage(decode(name, data)?),
+            "InviteInHavenBagClosedMessage" => GameMessage::InviteInHavenBagClosedMessage(decode(name, data)?),
+            "InviteInHavenBagMessage" => GameMessage::InviteInHavenBagMessage(decode(name, data)?),
+            "InviteInHavenBagOfferMessage" => GameMessage::InviteInHavenBagOfferMessage(decode(name, data)?),
+            "ItemForPresetUpdateMessage" => GameMessage::ItemForPresetUpdateMessage(decode(name, data)?),
+            "ItemNoMoreAvailableMessage" => GameMessage::ItemNoMoreAvailableMessage(decode(name, data)?),
+            "JobAllowMultiCraftRequestMessage" => GameMessage::JobAllowMultiCraftRequestMessage(decode(name, data)?),
+            "JobBookSubscribeRequestMessage" => GameMessage::JobBookSubscribeRequestMessage(decode(name, data)?),
+            "JobBookSubscriptionMessage" => GameMessage::JobBookSubscriptionMessage(decode(name, data)?),
+            "JobCrafterDirectoryAddMessage" => GameMessage::JobCrafterDirectoryAddMessage(decode(name, data)?),
+            "JobCrafterDirectoryDefineSettingsMessage" => GameMessage::JobCrafterDirectoryDefineSettingsMessage(decode(name, data)?),
+            "JobCrafterDirectoryEntryMessage" => GameMessage::JobCrafterDirectoryEntryMessage(decode(name, data)?),
+            "JobCrafterDirectoryEntryRequestMessage" => GameMessage::JobCrafterDirectoryEntryRequestMessage(decode(name, data)?),
+            "JobCrafterDirectoryListMessage" => GameMessage::JobCrafterDirectoryListMessage(decode(name, data)?),
+            "JobCrafterDirectoryListRequestMessage" => GameMessage::JobCrafterDirectoryListRequestMessage(decode(name, data)?),
+            "JobCrafterDirectoryRemoveMessage" => GameMessage::JobCrafterDirectoryRemoveMessage(decode(name, data)?),
+            "JobCrafterDirectorySettingsMessage" => GameMessage::JobCrafterDirectorySettingsMessage(decode(name, data)?),
+            "JobDescriptionMessage" => GameMessage::JobDescriptionMessage(decode(name, data)?),
+            "JobExperienceMultiUpdateMessage" => GameMessage::JobExperienceMultiUpdateMessage(decode(name, data)?),
+            "JobExperienceOtherPlayerUpdateMessage" => GameMessage::JobExperienceOtherPlayerUpdateMessage(decode(name, data)?),
+            "JobExperienceUpdateMessage" => GameMessage::JobExperienceUpdateMessage(decode(name, data)?),
+            "JobLevelUpMessage" => GameMessage::JobLevelUpMessage(decode(name, data)?),
+            "JobMultiCraftAvailableSkillsMessage" => GameMessage::JobMultiCraftAvailableSkillsMessage(decode(name, data)?),
+            "KamasUpdateMessage" => GameMessage::KamasUpdateMessage(decode(name, data)?),
+            "KickHavenBagRequestMessage" => GameMessage::KickHavenBagRequestMessage(decode(name, data)?),
+            "KnownZaapListMessage" => GameMessage::KnownZaapListMessage(decode(name, data)?),
+            "KohUpdateMessage" => GameMessage::KohUpdateMessage(decode(name, data)?),
+            "KothEndMessage" => GameMessage::KothEndMessage(decode(name, data)?),
+            "LeaveDialogMessage" => GameMessage::LeaveDialogMessage(decode(name, data)?),
+            "LeaveDialogRequestMessage" => GameMessage::LeaveDialogRequestMessage(decode(name, data)?),
+            "LifePointsRegenBeginMessage" => GameMessage::LifePointsRegenBeginMessage(decode(name, data)?),
+            "LifePointsRegenEndMessage" => GameMessage::LifePointsRegenEndMessage(decode(name, data)?),
+            "ListMapNpcsQuestStatusUpdateMessage" => GameMessage::ListMapNpcsQuestStatusUpdateMessage(decode(name, data)?),
+            "ListenersOfSynchronizedStorageMessage" => GameMessage::ListenersOfSynchronizedStorageMessage(decode(name, data)?),
+            "LivingObjectChangeSkinRequestMessage" => GameMessage::LivingObjectChangeSkinRequestMessage(decode(name, data)?),
+            "LivingObjectDissociateMessage" => GameMessage::LivingObjectDissociateMessage(decode(name, data)?),
+            "LivingObjectMessageMessage" => GameMessage::LivingObjectMessageMessage(decode(name, data)?),
+            "LivingObjectMessageRequestMessage" => GameMessage::LivingObjectMessageRequestMessage(decode(name, data)?),
+            "LocalizedChatSmileyMessage" => GameMessage::LocalizedChatSmileyMessage(decode(name, data)?),
+            "LockableChangeCodeMessage" => GameMessage::LockableChangeCodeMessage(decode(name, data)?),
+            "LockableCodeResultMessage" => GameMessage::LockableCodeResultMessage(decode(name, data)?),
+            "LockableShowCodeDialogMessage" => GameMessage::LockableShowCodeDialogMessage(decode(name, data)?),
+            "LockableStateUpdateAbstractMessage" => GameMessage::LockableStateUpdateAbstractMessage(decode(name, data)?),
+            "LockableStateUpdateHouseDoorMessage" => GameMessage::LockableStateUpdateHouseDoorMessage(decode(name, data)?),
+            "LockableStateUpdateStorageMessage" => GameMessage::LockableStateUpdateStorageMessage(decode(name, data)?),
+            "LockableUseCodeMessage" => GameMessage::LockableUseCodeMessage(decode(name, data)?),
+            "LoginQueueStatusMessage" => GameMessage::LoginQueueStatusMessage(decode(name, data)?),
+            "MapComplementaryInformationsAnomalyMessage" => GameMessage::MapComplementaryInformationsAnomalyMessage(decode(name, data)?),
+            "MapComplementaryInformationsBreachMessage" => GameMessage::MapComplementaryInformationsBreachMessage(decode(name, data)?),
+            "MapComplementaryInformationsDataInHavenBagMessage" => GameMessage::MapComplementaryInformationsDataInHavenBagMessage(decode(name, data)?),
+            "MapComplementaryInformationsDataInHouseMessage" => GameMessage::MapComplementaryInformationsDataInHouseMessage(decode(name, data)?),
+            "MapComplementaryInformationsDataMessage" => GameMessage::MapComplementaryInformationsDataMessage(decode(name, data)?),
+            "MapComplementaryInformationsWithCoordsMessage" => GameMessage::MapComplementaryInformationsWithCoordsMessage(decode(name, data)?),
+            "MapFightCountMessage" => GameMessage::MapFightCountMessage(decode(name, data)?),
+            "MapFightStartPositionsUpdateMessage" => GameMessage::MapFightStartPositionsUpdateMessage(decode(name, data)?),
+            "MapInformationsRequestMessage" => GameMessage::MapInformationsRequestMessage(decode(name, data)?),
+            "MapObstacleUpdateMessage" => GameMessage::MapObstacleUpdateMessage(decode(name, data)?),
+            "MapRunningFightDetailsExtendedMessage" => GameMessage::MapRunningFightDetailsExtendedMessage(decode(name, data)?),
+            "MapRunningFightDetailsMessage" => GameMessage::MapRunningFightDetailsMessage(decode(name, data)?),
+            "MapRunningFightDetailsRequestMessage" => GameMessage::MapRunningFightDetailsRequestMessage(decode(name, data)?),
+            "MapRunningFightListMessage" => GameMessage::MapRunningFightListMessage(decode(name, data)?),
+            "MapRunningFightListRequestMessage" => GameMessage::MapRunningFightListRequestMessage(decode(name, data)?),
+            "MigratedServerListMessage" => GameMessage::MigratedServerListMessage(decode(name, data)?),
+            "MimicryObjectAssociatedMessage" => GameMessage::MimicryObjectAssociatedMessage(decode(name, data)?),
+            "MimicryObjectEraseRequestMessage" => GameMessage::MimicryObjectEraseRequestMessage(decode(name, data)?),
+            "MimicryObjectErrorMessage" => GameMessage::MimicryObjectErrorMessage(decode(name, data)?),
+            "MimicryObjectFeedAndAssociateRequestMessage" => GameMessage::MimicryObjectFeedAndAssociateRequestMessage(decode(name, data)?),
+            "MimicryObjectPreviewMessage" => GameMessage::MimicryObjectPreviewMessage(decode(name, data)?),
+            "MoodSmileyRequestMessage" => GameMessage::MoodSmileyRequestMessage(decode(name, data)?),
+            "MoodSmileyResultMessage" => GameMessage::MoodSmileyResultMessage(decode(name, data)?),
+            "MoodSmileyUpdateMessage" => GameMessage::MoodSmileyUpdateMessage(decode(name, data)?),
+            "MountDataErrorMessage" => GameMessage::MountDataErrorMessage(decode(name, data)?),
+            "MountDataMessage" => GameMessage::MountDataMessage(decode(name, data)?),
+            "MountEmoteIconUsedOkMessage" => GameMessage::MountEmoteIconUsedOkMessage(decode(name, data)?),
+            "MountEquipedErrorMessage" => GameMessage::MountEquipedErrorMessage(decode(name, data)?),
+            "MountFeedRequestMessage" => GameMessage::MountFeedRequestMessage(decode(name, data)?),
+            "MountHarnessColorsUpdateRequestMessage" => GameMessage::MountHarnessColorsUpdateRequestMessage(decode(name, data)?),
+            "MountHarnessDissociateRequestMessage" => GameMessage::MountHarnessDissociateRequestMessage(decode(name, data)?),
+            "MountInformationInPaddockRequestMessage" => GameMessage::MountInformationInPaddockRequestMessage(decode(name, data)?),
+            "MountInformationRequestMessage" => GameMessage::MountInformationRequestMessage(decode(name, data)?),
+            "MountReleaseRequestMessage" => GameMessage::MountReleaseRequestMessage(decode(name, data)?),
+            "MountReleasedMessage" => GameMessage::MountReleasedMessage(decode(name, data)?),
+            "MountRenameRequestMessage" => GameMessage::MountRenameRequestMessage(decode(name, data)?),
+            "MountRenamedMessage" => GameMessage::MountRenamedMessage(decode(name, data)?),
+            "MountRidingMessage" => GameMessage::MountRidingMessage(decode(name, data)?),
+            "MountSetMessage" => GameMessage::MountSetMessage(decode(name, data)?),
+            "MountSetXpRatioRequestMessage" => GameMessage::MountSetXpRatioRequestMessage(decode(name, data)?),
+            "MountSterilizeRequestMessage" => GameMessage::MountSterilizeRequestMessage(decode(name, data)?),
+            "MountSterilizedMessage" => GameMessage::MountSterilizedMessage(decode(name, data)?),
+            "MountToggleRidingRequestMessage" => GameMessage::MountToggleRidingRequestMessage(decode(name, data)?),
+            "MountUnSetMessage" => GameMessage::MountUnSetMessage(decode(name, data)?),
+            "MountXpRatioMessage" => GameMessage::MountXpRatioMessage(decode(name, data)?),
+            "MoveTaxCollectorOrderedSpellMessage" => GameMessage::MoveTaxCollectorOrderedSpellMessage(decode(name, data)?),
+            "MoveTaxCollectorPresetSpellMessage" => GameMessage::MoveTaxCollectorPresetSpellMessage(decode(name, data)?),
+            "MultiTabStorageMessage" => GameMessage::MultiTabStorageMessage(decode(name, data)?),
+            "NetworkDataContainerMessage" => GameMessage::NetworkDataContainerMessage(decode(name, data)?),
+            "NicknameAcceptedMessage" => GameMessage::NicknameAcceptedMessage(decode(name, data)?),
+            "NicknameChoiceRequestMessage" => GameMessage::NicknameChoiceRequestMessage(decode(name, data)?),
+            "NicknameRefusedMessage" => GameMessage::NicknameRefusedMessage(decode(name, data)?),
+            "NicknameRegistrationMessage" => GameMessage::NicknameRegistrationMessage(decode(name, data)?),
+            "NotificationByServerMessage" => GameMessage::NotificationByServerMessage(decode(name, data)?),
+            "NotificationListMessage" => GameMessage::NotificationListMessage(decode(name, data)?),
+            "NotificationResetMessage" => GameMessage::NotificationResetMessage(decode(name, data)?),
+            "NotificationUpdateFlagMessage" => GameMessage::NotificationUpdateFlagMessage(decode(name, data)?),
+            "NpcDialogCreationMessage" => GameMessage::NpcDialogCreationMessage(decode(name, data)?),
+            "NpcDialogQuestionMessage" => GameMessage::NpcDialogQuestionMessage(decode(name, data)?),
+            "NpcDialogReplyMessage" => GameMessage::NpcDialogReplyMessage(decode(name, data)?),
+            "NpcGenericActionFailureMessage" => GameMessage::NpcGenericActionFailureMessage(decode(name, data)?),
+            "NpcGenericActionRequestMessage" => GameMessage::NpcGenericActionRequestMessage(decode(name, data)?),
+            "NuggetsDistributionMessage" => GameMessage::NuggetsDistributionMessage(decode(name, data)?),
+            "NuggetsInformationMessage" => GameMessage::NuggetsInformationMessage(decode(name, data)?),
+            "NumericWhoIsMessage" => GameMessage::NumericWhoIsMessage(decode(name, data)?),
+            "NumericWhoIsRequestMessage" => GameMessage::NumericWhoIsRequestMessage(decode(name, data)?),
+            "ObjectAddedMessage" => GameMessage::ObjectAddedMessage(decode(name, data)?),
+            "ObjectAveragePricesErrorMessage" => GameMessage::ObjectAveragePricesErrorMessage(decode(name, data)?),
+            "ObjectAveragePricesGetMessage" => GameMessage::ObjectAveragePricesGetMessage(decode(name, data)?),
+            "ObjectAveragePricesMessage" => GameMessage::ObjectAveragePricesMessage(decode(name, data)?),
+            "ObjectDeleteMessage" => GameMessage::ObjectDeleteMessage(decode(name, data)?),
+            "ObjectDeletedMessage" => GameMessage::ObjectDeletedMessage(decode(name, data)?),
+            "ObjectDropMessage" => GameMessage::ObjectDropMessage(decode(name, data)?),
+            "ObjectErrorMessage" => GameMessage::ObjectErrorMessage(decode(name, data)?),
+            "ObjectFeedMessage" => GameMessage::ObjectFeedMessage(decode(name, data)?),
+            "ObjectGroundAddedMessage" => GameMessage::ObjectGroundAddedMessage(decode(name, data)?),
+            "ObjectGroundListAddedMessage" => GameMessage::ObjectGroundListAddedMessage(decode(name, data)?),
+            "ObjectGroundRemovedMessage" => GameMessage::ObjectGroundRemovedMessage(decode(name, data)?),
+            "ObjectGroundRemovedMultipleMessage" => GameMessage::ObjectGroundRemovedMultipleMessage(decode(name, data)?),
+            "ObjectJobAddedMessage" => GameMessage::ObjectJobAddedMessage(decode(name, data)?),
+            "ObjectModifiedMessage" => GameMessage::ObjectModifiedMessage(decode(name, data)?),
+            "ObjectMovementMessage" => GameMessage::ObjectMovementMessage(decode(name, data)?),
+            "ObjectQuantityMessage" => GameMessage::ObjectQuantityMessage(decode(name, data)?),
+            "ObjectSetPositionMessage" => GameMessage::ObjectSetPositionMessage(decode(name, data)?),
+            "ObjectUseMessage" => GameMessage::ObjectUseMessage(decode(name, data)?),
+            "ObjectUseMultipleMessage" => GameMessage::ObjectUseMultipleMessage(decode(name, data)?),
+            "ObjectUseOnCellMessage" => GameMessage::ObjectUseOnCellMessage(decode(name, data)?),
+            "ObjectUseOnCharacterMessage" => GameMessage::ObjectUseOnCharacterMessage(decode(name, data)?),
+            "ObjectsAddedMessage" => GameMessage::ObjectsAddedMessage(decode(name, data)?),
+            "ObjectsDeletedMessage" => GameMessage::ObjectsDeletedMessage(decode(name, data)?),
+            "ObjectsQuantityMessage" => GameMessage::ObjectsQuantityMessage(decode(name, data)?),
+            "ObtainedItemMessage" => GameMessage::ObtainedItemMessage(decode(name, data)?),
+            "ObtainedItemWithBonusMessage" => GameMessage::ObtainedItemWithBonusMessage(decode(name, data)?),
+            "OnConnectionEventMessage" => GameMessage::OnConnectionEventMessage(decode(name, data)?),
+            "OpenGuideBookMessage" => GameMessage::OpenGuideBookMessage(decode(name, data)?),
+            "OpenHavenBagFurnitureSequenceRequestMessage" => GameMessage::OpenHavenBagFurnitureSequenceRequestMessage(decode(name, data)?),
+            "OpponentSurrenderMessage" => GameMessage::OpponentSurrenderMessage(decode(name, data)?),
+            "OrnamentGainedMessage" => GameMessage::OrnamentGainedMessage(decode(name, data)?),
+            "OrnamentLostMessage" => GameMessage::OrnamentLostMessage(decode(name, data)?),
+            "OrnamentSelectErrorMessage" => GameMessage::OrnamentSelectErrorMessage(decode(name, data)?),
+            "OrnamentSelectRequestMessage" => GameMessage::OrnamentSelectRequestMessage(decode(name, data)?),
+            "OrnamentSelectedMessage" => GameMessage::OrnamentSelectedMessage(decode(name, data)?),
+            "PaddockBuyRequestMessage" => GameMessage::PaddockBuyRequestMessage(decode(name, data)?),
+            "PaddockBuyResultMessage" => GameMessage::PaddockBuyResultMessage(decode(name, data)?),
+            "PaddockMoveItemRequestMessage" => GameMessage::PaddockMoveItemRequestMessage(decode(name, data)?),
+            "PaddockPropertiesMessage" => GameMessage::PaddockPropertiesMessage(decode(name, data)?),
+            "PaddockRemoveItemRequestMessage" => GameMessage::PaddockRemoveItemRequestMessage(decode(name, data)?),
+            "PaddockSellBuyDialogMessage" => GameMessage::PaddockSellBuyDialogMessage(decode(name, data)?),
+            "PaddockSellRequestMessage" => GameMessage::PaddockSellRequestMessage(decode(name, data)?),
+            "PaddockToSellFilterMessage" => GameMessage::PaddockToSellFilterMessage(decode(name, data)?),
+            "PaddockToSellListMessage" => GameMessage::PaddockToSellListMessage(decode(name, data)?),
+            "PaddockToSellListRequestMessage" => GameMessage::PaddockToSellListRequestMessage(decode(name, data)?),
+            "PaginationAnswerAbstractMessage" => GameMessage::PaginationAnswerAbstractMessage(decode(name, data)?),
+            "PaginationRequestAbstractMessage" => GameMessage::PaginationRequestAbstractMessage(decode(name, data)?),
+            "PartyAbdicateThroneMessage" => GameMessage::PartyAbdicateThroneMessage(decode(name, data)?),
+            "PartyAcceptInvitationMessage" => GameMessage::PartyAcceptInvitationMessage(decode(name, data)?),
+            "PartyCancelInvitationMessage" => GameMessage::PartyCancelInvitationMessage(decode(name, data)?),
+            "PartyCancelInvitationNotificationMessage" => GameMessage::PartyCancelInvitationNotificationMessage(decode(name, data)?),
+            "PartyCannotJoinErrorMessage" => GameMessage::PartyCannotJoinErrorMessage(decode(name, data)?),
+            "PartyDeletedMessage" => GameMessage::PartyDeletedMessage(decode(name, data)?),
+            "PartyEntityUpdateLightMessage" => GameMessage::PartyEntityUpdateLightMessage(decode(name, data)?),
+            "PartyFollowMemberRequestMessage" => GameMessage::PartyFollowMemberRequestMessage(decode(name, data)?),
+            "PartyFollowStatusUpdateMessage" => GameMessage::PartyFollowStatusUpdateMessage(decode(name, data)?),
+            "PartyFollowThisMemberRequestMessage" => GameMessage::PartyFollowThisMemberRequestMessage(decode(name, data)?),
+            "PartyInvitationArenaRequestMessage" => GameMessage::PartyInvitationArenaRequestMessage(decode(name, data)?),
+            "PartyInvitationCancelledForGuestMessage" => GameMessage::PartyInvitationCancelledForGuestMessage(decode(name, data)?),
+            "PartyInvitationDetailsMessage" => GameMessage::PartyInvitationDetailsMessage(decode(name, data)?),
+            "PartyInvitationDetailsRequestMessage" => GameMessage::PartyInvitationDetailsRequestMessage(decode(name, data)?),
+            "PartyInvitationDungeonDetailsMessage" => GameMessage::PartyInvitationDungeonDetailsMessage(decode(name, data)?),
+            "PartyInvitationDungeonMessage" => GameMessage::PartyInvitationDungeonMessage(decode(name, data)?),
+            "PartyInvitationDungeonRequestMessage" => GameMessage::PartyInvitationDungeonRequestMessage(decode(name, data)?),
+            "PartyInvitationMessage" => GameMessage::PartyInvitationMessage(decode(name, data)?),
+            "PartyInvitationRequestMessage" => GameMessage::PartyInvitationRequestMessage(decode(name, data)?),
+            "PartyJoinMessage" => GameMessage::PartyJoinMessage(decode(name, data)?),
+            "PartyKickRequestMessage" => GameMessage::PartyKickRequestMessage(decode(name, data)?),
+            "PartyKickedByMessage" => GameMessage::PartyKickedByMessage(decode(name, data)?),
+            "PartyLeaderUpdateMessage" => GameMessage::PartyLeaderUpdateMessage(decode(name, data)?),
+            "PartyLeaveMessage" => GameMessage::PartyLeaveMessage(decode(name, data)?),
+            "PartyLeaveRequestMessage" => GameMessage::PartyLeaveRequestMessage(decode(name, data)?),
+            "PartyLocateMembersMessage" => GameMessage::PartyLocateMembersMessage(decode(name, data)?),
+            "PartyLocateMembersRequestMessage" => GameMessage::PartyLocateMembersRequestMessage(decode(name, data)?),
+            "PartyLoyaltyStatusMessage" => GameMessage::PartyLoyaltyStatusMessage(decode(name, data)?),
+            "PartyMemberEjectedMessage" => GameMessage::PartyMemberEjectedMessage(decode(name, data)?),
+            "PartyMemberInBreachFightMessage" => GameMessage::PartyMemberInBreachFightMessage(decode(name, data)?),
+            "PartyMemberInStandardFightMessage" => GameMessage::PartyMemberInStandardFightMessage(decode(name, data)?),
+            "PartyMemberRemoveMessage" => GameMessage::PartyMemberRemoveMessage(decode(name, data)?),
+            "PartyModifiableStatusMessage" => GameMessage::PartyModifiableStatusMessage(decode(name, data)?),
+            "PartyNameSetErrorMessage" => GameMessage::PartyNameSetErrorMessage(decode(name, data)?),
+            "PartyNameSetRequestMessage" => GameMessage::PartyNameSetRequestMessage(decode(name, data)?),
+            "PartyNameUpdateMessage" => GameMessage::PartyNameUpdateMessage(decode(name, data)?),
+            "PartyNewGuestMessage" => GameMessage::PartyNewGuestMessage(decode(name, data)?),
+            "PartyNewMemberMessage" => GameMessage::PartyNewMemberMessage(decode(name, data)?),
+            "PartyPledgeLoyaltyRequestMessage" => GameMessage::PartyPledgeLoyaltyRequestMessage(decode(name, data)?),
+            "PartyRefuseInvitationMessage" => GameMessage::PartyRefuseInvitationMessage(decode(name, data)?),
+            "PartyRefuseInvitationNotificationMessage" => GameMessage::PartyRefuseInvitationNotificationMessage(decode(name, data)?),
+            "PartyRestrictedMessage" => GameMessage::PartyRestrictedMessage(decode(name, data)?),
+            "PartyStopFollowRequestMessage" => GameMessage::PartyStopFollowRequestMessage(decode(name, data)?),
+            "PartyUpdateLightMessage" => GameMessage::PartyUpdateLightMessage(decode(name, data)?),
+            "PartyUpdateMessage" => GameMessage::PartyUpdateMessage(decode(name, data)?),
+            "PauseDialogMessage" => GameMessage::PauseDialogMessage(decode(name, data)?),
+            "PlayerStatusUpdateErrorMessage" => GameMessage::PlayerStatusUpdateErrorMessage(decode(name, data)?),
+            "PlayerStatusUpdateMessage" => GameMessage::PlayerStatusUpdateMessage(decode(name, data)?),
+            "PlayerStatusUpdateRequestMessage" => GameMessage::PlayerStatusUpdateRequestMessage(decode(name, data)?),
+            "PopupWarningCloseRequestMessage" => GameMessage::PopupWarningCloseRequestMessage(decode(name, data)?),
+            "PopupWarningClosedMessage" => GameMessage::PopupWarningClosedMessage(decode(name, data)?),
+            "PopupWarningMessage" => GameMessage::PopupWarningMessage(decode(name, data)?),
+            "PortalDialogCreationMessage" => GameMessage::PortalDialogCreationMessage(decode(name, data)?),
+            "PortalUseRequestMessage" => GameMessage::PortalUseRequestMessage(decode(name, data)?),
+            "PresetDeleteRequestMessage" => GameMessage::PresetDeleteRequestMessage(decode(name, data)?),
+            "PresetDeleteResultMessage" => GameMessage::PresetDeleteResultMessage(decode(name, data)?),
+            "PresetSaveErrorMessage" => GameMessage::PresetSaveErrorMessage(decode(name, data)?),
+            "PresetSavedMessage" => GameMessage::PresetSavedMessage(decode(name, data)?),
+            "PresetUseRequestMessage" => GameMessage::PresetUseRequestMessage(decode(name, data)?),
+            "PresetUseResultMessage" => GameMessage::PresetUseResultMessage(decode(name, data)?),
+            "PresetUseResultWithMissingIdsMessage" => GameMessage::PresetUseResultWithMissingIdsMessage(decode(name, data)?),
+            "PresetsMessage" => GameMessage::PresetsMessage(decode(name, data)?),
+            "PrismAddOrUpdateMessage" => GameMessage::PrismAddOrUpdateMessage(decode(name, data)?),
+            "PrismAttackRequestMessage" => GameMessage::PrismAttackRequestMessage(decode(name, data)?),
+            "PrismAttackResultMessage" => GameMessage::PrismAttackResultMessage(decode(name, data)?),
+            "PrismAttackedMessage" => GameMessage::PrismAttackedMessage(decode(name, data)?),
+            "PrismExchangeRequestMessage" => GameMessage::PrismExchangeRequestMessage(decode(name, data)?),
+            "PrismRecycleTradeRequestMessage" => GameMessage::PrismRecycleTradeRequestMessage(decode(name, data)?),
+            "PrismRemoveMessage" => GameMessage::PrismRemoveMessage(decode(name, data)?),
+            "PrismTeleportationRequestMessage" => GameMessage::PrismTeleportationRequestMessage(decode(name, data)?),
+            "PrismsListMessage" => GameMessage::PrismsListMessage(decode(name, data)?),
+            "ProtocolRequired" => GameMessage::ProtocolRequired(decode(name, data)?),
+            "PurchasableDialogMessage" => GameMessage::PurchasableDialogMessage(decode(name, data)?),
+            "QuestListMessage" => GameMessage::QuestListMessage(decode(name, data)?),
+            "QuestListRequestMessage" => GameMessage::QuestListRequestMessage(decode(name, data)?),
+            "QuestObjectiveValidatedMessage" => GameMessage::QuestObjectiveValidatedMessage(decode(name, data)?),
+            "QuestObjectiveValidationMessage" => GameMessage::QuestObjectiveValidationMessage(decode(name, data)?),
+            "QuestStartRequestMessage" => GameMessage::QuestStartRequestMessage(decode(name, data)?),
+            "QuestStartedMessage" => GameMessage::QuestStartedMessage(decode(name, data)?),
+            "QuestStepInfoMessage" => GameMessage::QuestStepInfoMessage(decode(name, data)?),
+            "QuestStepInfoRequestMessage" => GameMessage::QuestStepInfoRequestMessage(decode(name, data)?),
+            "QuestStepStartedMessage" => GameMessage::QuestStepStartedMessage(decode(name, data)?),
+            "QuestStepValidatedMessage" => GameMessage::QuestStepValidatedMessage(decode(name, data)?),
+            "QuestValidatedMessage" => GameMessage::QuestValidatedMessage(decode(name, data)?),
+            "QueueStatusMessage" => GameMessage::QueueStatusMessage(decode(name, data)?),
+            "RawDataMessage" => GameMessage::RawDataMessage(decode(name, data)?),
+            "RecruitmentInformationMessage" => GameMessage::RecruitmentInformationMessage(decode(name, data)?),
+            "RecycleResultMessage" => GameMessage::RecycleResultMessage(decode(name, data)?),
+            "RefreshCharacterStatsMessage" => GameMessage::RefreshCharacterStatsMessage(decode(name, data)?),
+            "RefreshFollowedQuestsOrderRequestMessage" => GameMessage::RefreshFollowedQuestsOrderRequestMessage(decode(name, data)?),
+            "ReleaseAccountMessage" => GameMessage::ReleaseAccountMessage(decode(name, data)?),
+            "ReloginTokenRequestMessage" => GameMessage::ReloginTokenRequestMessage(decode(name, data)?),
+            "ReloginTokenStatusMessage" => GameMessage::ReloginTokenStatusMessage(decode(name, data)?),
+            "RemoveGuildRankRequestMessage" => GameMessage::RemoveGuildRankRequestMessage(decode(name, data)?),
+            "RemoveListenerOnSynchronizedStorageMessage" => GameMessage::RemoveListenerOnSynchronizedStorageMessage(decode(name, data)?),
+            "RemoveSpellModifierMessage" => GameMessage::RemoveSpellModifierMessage(decode(name, data)?),
+            "RemoveTaxCollectorOrderedSpellMessage" => GameMessage::RemoveTaxCollectorOrderedSpellMessage(decode(name, data)?),
+            "RemoveTaxCollectorPresetSpellMessage" => GameMessage::RemoveTaxCollectorPresetSpellMessage(decode(name, data)?),
+            "ReportRequestMessage" => GameMessage::ReportRequestMessage(decode(name, data)?),
+            "ReportResponseMessage" => GameMessage::ReportResponseMessage(decode(name, data)?),
+            "ResetCharacterStatsRequestMessage" => GameMessage::ResetCharacterStatsRequestMessage(decode(name, data)?),
+            "SelectedServerDataExtendedMessage" => GameMessage::SelectedServerDataExtendedMessage(decode(name, data)?),
+            "SelectedServerDataMessage" => GameMessage::SelectedServerDataMessage(decode(name, data)?),
+            "SelectedServerRefusedMessage" => GameMessage::SelectedServerRefusedMessage(decode(name, data)?),
+            "SequenceEndMessage" => GameMessage::SequenceEndMessage(decode(name, data)?),
+            "SequenceNumberMessage" => GameMessage::SequenceNumberMessage(decode(name, data)?),
+            "SequenceNumberRequestMessage" => GameMessage::SequenceNumberRequestMessage(decode(name, data)?),
+            "SequenceStartMessage" => GameMessage::SequenceStartMessage(decode(name, data)?),
+            "ServerExperienceModificatorMessage" => GameMessage::ServerExperienceModificatorMessage(decode(name, data)?),
+            "ServerOptionalFeaturesMessage" => GameMessage::ServerOptionalFeaturesMessage(decode(name, data)?),
+            "ServerSelectionMessage" => GameMessage::ServerSelectionMessage(decode(name, data)?),
+            "ServerSessionConstantsMessage" => GameMessage::ServerSessionConstantsMessage(decode(name, data)?),
+            "ServerSettingsMessage" => GameMessage::ServerSettingsMessage(decode(name, data)?),
+            "ServerStatusUpdateMessage" => GameMessage::ServerStatusUpdateMessage(decode(name, data)?),
+            "ServersListMessage" => GameMessage::ServersListMessage(decode(name, data)?),
+            "SetCharacterRestrictionsMessage" => GameMessage::SetCharacterRestrictionsMessage(decode(name, data)?),
+            "SetEnableAVARequestMessage" => GameMessage::SetEnableAVARequestMessage(decode(name, data)?),
+            "SetEnablePVPRequestMessage" => GameMessage::SetEnablePVPRequestMessage(decode(name, data)?),
+            "SetUpdateMessage" => GameMessage::SetUpdateMessage(decode(name, data)?),
+            "ShortcutBarAddErrorMessage" => GameMessage::ShortcutBarAddErrorMessage(decode(name, data)?),
+            "ShortcutBarAddRequestMessage" => GameMessage::ShortcutBarAddRequestMessage(decode(name, data)?),
+            "ShortcutBarContentMessage" => GameMessage::ShortcutBarContentMessage(decode(name, data)?),
+            "ShortcutBarRefreshMessage" => GameMessage::ShortcutBarRefreshMessage(decode(name, data)?),
+            "ShortcutBarRemoveErrorMessage" => GameMessage::ShortcutBarRemoveErrorMessage(decode(name, data)?),
+            "ShortcutBarRemoveRequestMessage" => GameMessage::ShortcutBarRemoveRequestMessage(decode(name, data)?),
+            "ShortcutBarRemovedMessage" => GameMessage::ShortcutBarRemovedMessage(decode(name, data)?),
+            "ShortcutBarReplacedMessage" => GameMessage::ShortcutBarReplacedMessage(decode(name, data)?),
+            "ShortcutBarSwapErrorMessage" => GameMessage::ShortcutBarSwapErrorMessage(decode(name, data)?),
+            "ShortcutBarSwapRequestMessage" => GameMessage::ShortcutBarSwapRequestMessage(decode(name, data)?),
+            "ShowCellMessage" => GameMessage::ShowCellMessage(decode(name, data)?),
+            "ShowCellRequestMessage" => GameMessage::ShowCellRequestMessage(decode(name, data)?),
+            "ShowCellSpectatorMessage" => GameMessage::ShowCellSpectatorMessage(decode(name, data)?),
+            "SlaveNoLongerControledMessage" => GameMessage::SlaveNoLongerControledMessage(decode(name, data)?),
+            "SlaveSwitchContextMessage" => GameMessage::SlaveSwitchContextMessage(decode(name, data)?),
+            "SocialFightJoinRequestMessage" => GameMessage::SocialFightJoinRequestMessage(decode(name, data)?),
+            "SocialFightLeaveRequestMessage" => GameMessage::SocialFightLeaveRequestMessage(decode(name, data)?),
+            "SocialFightTakePlaceRequestMessage" => GameMessage::SocialFightTakePlaceRequestMessage(decode(name, data)?),
+            "SocialNoticeMessage" => GameMessage::SocialNoticeMessage(decode(name, data)?),
+            "SocialNoticeSetErrorMessage" => GameMessage::SocialNoticeSetErrorMessage(decode(name, data)?),
+            "SocialNoticeSetRequestMessage" => GameMessage::SocialNoticeSetRequestMessage(decode(name, data)?),
+            "SpellListMessage" => GameMessage::SpellListMessage(decode(name, data)?),
+            "SpellVariantActivationMessage" => GameMessage::SpellVariantActivationMessage(decode(name, data)?),
+            "SpellVariantActivationRequestMessage" => GameMessage::SpellVariantActivationRequestMessage(decode(name, data)?),
+            "SpouseGetInformationsMessage" => GameMessage::SpouseGetInformationsMessage(decode(name, data)?),
+            "SpouseInformationsMessage" => GameMessage::SpouseInformationsMessage(decode(name, data)?),
+            "SpouseStatusMessage" => GameMessage::SpouseStatusMessage(decode(name, data)?),
+            "StartExchangeTaxCollectorEquipmentMessage" => GameMessage::StartExchangeTaxCollectorEquipmentMessage(decode(name, data)?),
+            "StartGuildChestContributionMessage" => GameMessage::StartGuildChestContributionMessage(decode(name, data)?),
+            "StartListenAllianceFightMessage" => GameMessage::StartListenAllianceFightMessage(decode(name, data)?),
+            "StartListenGuildChestStructureMessage" => GameMessage::StartListenGuildChestStructureMessage(decode(name, data)?),
+            "StartListenNuggetsMessage" => GameMessage::StartListenNuggetsMessage(decode(name, data)?),
+            "StartListenTaxCollectorPresetsUpdatesMessage" => GameMessage::StartListenTaxCollectorPresetsUpdatesMessage(decode(name, data)?),
+            "StartListenTaxCollectorUpdatesMessage" => GameMessage::StartListenTaxCollectorUpdatesMessage(decode(name, data)?),
+            "StatedElementUpdatedMessage" => GameMessage::StatedElementUpdatedMessage(decode(name, data)?),
+            "StatedMapUpdateMessage" => GameMessage::StatedMapUpdateMessage(decode(name, data)?),
+            "StatsUpgradeRequestMessage" => GameMessage::StatsUpgradeRequestMessage(decode(name, data)?),
+            "StatsUpgradeResultMessage" => GameMessage::StatsUpgradeResultMessage(decode(name, data)?),
+            "StopGuildChestContributionMessage" => GameMessage::StopGuildChestContributionMessage(decode(name, data)?),
+            "StopListenAllianceFightMessage" => GameMessage::StopListenAllianceFightMessage(decode(name, data)?),
+            "StopListenGuildChestStructureMessage" => GameMessage::StopListenGuildChestStructureMessage(decode(name, data)?),
+            "StopListenNuggetsMessage" => GameMessage::StopListenNuggetsMessage(decode(name, data)?),
+            "StopListenTaxCollectorPresetsUpdatesMessage" => GameMessage::StopListenTaxCollectorPresetsUpdatesMessage(decode(name, data)?),
+            "StopListenTaxCollectorUpdatesMessage" => GameMessage::StopListenTaxCollectorUpdatesMessage(decode(name, data)?),
+            "StopToListenRunningFightRequestMessage" => GameMessage::StopToListenRunningFightRequestMessage(decode(name, data)?),
+            "StorageInventoryContentMessage" => GameMessage::StorageInventoryContentMessage(decode(name, data)?),
+            "StorageKamasUpdateMessage" => GameMessage::StorageKamasUpdateMessage(decode(name, data)?),
+            "StorageObjectRemoveMessage" => GameMessage::StorageObjectRemoveMessage(decode(name, data)?),
+            "StorageObjectUpdateMessage" => GameMessage::StorageObjectUpdateMessage(decode(name, data)?),
+            "StorageObjectsRemoveMessage" => GameMessage::StorageObjectsRemoveMessage(decode(name, data)?),
+            "StorageObjectsUpdateMessage" => GameMessage::StorageObjectsUpdateMessage(decode(name, data)?),
+            "SubareaRewardRateMessage" => GameMessage::SubareaRewardRateMessage(decode(name, data)?),
+            "SubscriptionLimitationMessage" => GameMessage::SubscriptionLimitationMessage(decode(name, data)?),
+            "SubscriptionZoneMessage" => GameMessage::SubscriptionZoneMessage(decode(name, data)?),
+            "SurrenderInfoRequestMessage" => GameMessage::SurrenderInfoRequestMessage(decode(name, data)?),
+            "SurrenderInfoResponseMessage" => GameMessage::SurrenderInfoResponseMessage(decode(name, data)?),
+            "SurrenderStateMessage" => GameMessage::SurrenderStateMessage(decode(name, data)?),
+            "SurrenderVoteCastMessage" => GameMessage::SurrenderVoteCastMessage(decode(name, data)?),
+            "SurrenderVoteEndMessage" => GameMessage::SurrenderVoteEndMessage(decode(name, data)?),
+            "SurrenderVoteStartMessage" => GameMessage::SurrenderVoteStartMessage(decode(name, data)?),
+            "SurrenderVoteUpdateMessage" => GameMessage::SurrenderVoteUpdateMessage(decode(name, data)?),
+            "SwitchArenaXpRewardsModeMessage" => GameMessage::SwitchArenaXpRewardsModeMessage(decode(name, data)?),
+            "SymbioticObjectAssociateRequestMessage" => GameMessage::SymbioticObjectAssociateRequestMessage(decode(name, data)?),
+            "SymbioticObjectAssociatedMessage" => GameMessage::SymbioticObjectAssociatedMessage(decode(name, data)?),
+            "SymbioticObjectErrorMessage" => GameMessage::SymbioticObjectErrorMessage(decode(name, data)?),
+            "SystemMessageDisplayMessage" => GameMessage::SystemMessageDisplayMessage(decode(name, data)?),
+            "TaxCollectorAddedMessage" => GameMessage::TaxCollectorAddedMessage(decode(name, data)?),
+            "TaxCollectorAttackedMessage" => GameMessage::TaxCollectorAttackedMessage(decode(name, data)?),
+            "TaxCollectorAttackedResultMessage" => GameMessage::TaxCollectorAttackedResultMessage(decode(name, data)?),
+            "TaxCollectorDialogQuestionBasicMessage" => GameMessage::TaxCollectorDialogQuestionBasicMessage(decode(name, data)?),
+            "TaxCollectorDialogQuestionExtendedMessage" => GameMessage::TaxCollectorDialogQuestionExtendedMessage(decode(name, data)?),
+            "TaxCollectorEquipmentUpdateMessage" => GameMessage::TaxCollectorEquipmentUpdateMessage(decode(name, data)?),
+            "TaxCollectorErrorMessage" => GameMessage::TaxCollectorErrorMessage(decode(name, data)?),
+            "TaxCollectorHarvestedMessage" => GameMessage::TaxCollectorHarvestedMessage(decode(name, data)?),
+            "TaxCollectorMovementsOfflineMessage" => GameMessage::TaxCollectorMovementsOfflineMessage(decode(name, data)?),
+            "TaxCollectorOrderedSpellUpdatedMessage" => GameMessage::TaxCollectorOrderedSpellUpdatedMessage(decode(name, data)?),
+            "TaxCollectorPresetSpellUpdatedMessage" => GameMessage::TaxCollectorPresetSpellUpdatedMessage(decode(name, data)?),
+            "TaxCollectorPresetsMessage" => GameMessage::TaxCollectorPresetsMessage(decode(name, data)?),
+            "TaxCollectorRemovedMessage" => GameMessage::TaxCollectorRemovedMessage(decode(name, data)?),
+            "TaxCollectorStateUpdateMessage" => GameMessage::TaxCollectorStateUpdateMessage(decode(name, data)?),
+            "TeleportBuddiesAnswerMessage" => GameMessage::TeleportBuddiesAnswerMessage(decode(name, data)?),
+            "TeleportBuddiesMessage" => GameMessage::TeleportBuddiesMessage(decode(name, data)?),
+            "TeleportBuddiesRequestedMessage" => GameMessage::TeleportBuddiesRequestedMessage(decode(name, data)?),
+            "TeleportDestinationsMessage" => GameMessage::TeleportDestinationsMessage(decode(name, data)?),
+            "TeleportHavenBagAnswerMessage" => GameMessage::TeleportHavenBagAnswerMessage(decode(name, data)?),
+            "TeleportHavenBagRequestMessage" => GameMessage::TeleportHavenBagRequestMessage(decode(name, data)?),
+            "TeleportOnSameMapMessage" => GameMessage::TeleportOnSameMapMessage(decode(name, data)?),
+            "TeleportPlayerAnswerMessage" => GameMessage::TeleportPlayerAnswerMessage(decode(name, data)?),
+            "TeleportPlayerCloseMessage" => GameMessage::TeleportPlayerCloseMessage(decode(name, data)?),
+            "TeleportPlayerOfferMessage" => GameMessage::TeleportPlayerOfferMessage(decode(name, data)?),
+            "TeleportRequestMessage" => GameMessage::TeleportRequestMessage(decode(name, data)?),
+            "TeleportToBuddyAnswerMessage" => GameMessage::TeleportToBuddyAnswerMessage(decode(name, data)?),
+            "TeleportToBuddyCloseMessage" => GameMessage::TeleportToBuddyCloseMessage(decode(name, data)?),
+            "TeleportToBuddyOfferMessage" => GameMessage::TeleportToBuddyOfferMessage(decode(name, data)?),
+            "TextInformationMessage" => GameMessage::TextInformationMessage(decode(name, data)?),
+            "TitleGainedMessage" => GameMessage::TitleGainedMessage(decode(name, data)?),
+            "TitleLostMessage" => GameMessage::TitleLostMessage(decode(name, data)?),
+            "TitleSelectErrorMessage" => GameMessage::TitleSelectErrorMessage(decode(name, data)?),
+            "TitleSelectRequestMessage" => GameMessage::TitleSelectRequestMessage(decode(name, data)?),
+            "TitleSelectedMessage" => GameMessage::TitleSelectedMessage(decode(name, data)?),
+            "TitlesAndOrnamentsListMessage" => GameMessage::TitlesAndOrnamentsListMessage(decode(name, data)?),
+            "TitlesAndOrnamentsListRequestMessage" => GameMessage::TitlesAndOrnamentsListRequestMessage(decode(name, data)?),
+            "TopTaxCollectorListMessage" => GameMessage::TopTaxCollectorListMessage(decode(name, data)?),
+            "TreasureHuntAvailableRetryCountUpdateMessage" => GameMessage::TreasureHuntAvailableRetryCountUpdateMessage(decode(name, data)?),
+            "TreasureHuntDigRequestAnswerFailedMessage" => GameMessage::TreasureHuntDigRequestAnswerFailedMessage(decode(name, data)?),
+            "TreasureHuntDigRequestAnswerMessage" => GameMessage::TreasureHuntDigRequestAnswerMessage(decode(name, data)?),
+            "TreasureHuntDigRequestMessage" => GameMessage::TreasureHuntDigRequestMessage(decode(name, data)?),
+            "TreasureHuntFinishedMessage" => GameMessage::TreasureHuntFinishedMessage(decode(name, data)?),
+            "TreasureHuntFlagRemoveRequestMessage" => GameMessage::TreasureHuntFlagRemoveRequestMessage(decode(name, data)?),
+            "TreasureHuntFlagRequestAnswerMessage" => GameMessage::TreasureHuntFlagRequestAnswerMessage(decode(name, data)?),
+            "TreasureHuntFlagRequestMessage" => GameMessage::TreasureHuntFlagRequestMessage(decode(name, data)?),
+            "TreasureHuntGiveUpRequestMessage" => GameMessage::TreasureHuntGiveUpRequestMessage(decode(name, data)?),
+            "TreasureHuntLegendaryRequestMessage" => GameMessage::TreasureHuntLegendaryRequestMessage(decode(name, data)?),
+            "TreasureHuntMessage" => GameMessage::TreasureHuntMessage(decode(name, data)?),
+            "TreasureHuntRequestAnswerMessage" => GameMessage::TreasureHuntRequestAnswerMessage(decode(name, data)?),
+            "TreasureHuntShowLegendaryUIMessage" => GameMessage::TreasureHuntShowLegendaryUIMessage(decode(name, data)?),
+            "TrustStatusMessage" => GameMessage::TrustStatusMessage(decode(name, data)?),
+            "UnfollowQuestObjectiveRequestMessage" => GameMessage::UnfollowQuestObjectiveRequestMessage(decode(name, data)?),
+            "UpdateAllGuildRankRequestMessage" => GameMessage::UpdateAllGuildRankRequestMessage(decode(name, data)?),
+            "UpdateGuildRankRequestMessage" => GameMessage::UpdateGuildRankRequestMessage(decode(name, data)?),
+            "UpdateGuildRightsMessage" => GameMessage::UpdateGuildRightsMessage(decode(name, data)?),
+            "UpdateLifePointsMessage" => GameMessage::UpdateLifePointsMessage(decode(name, data)?),
+            "UpdateMapPlayersAgressableStatusMessage" => GameMessage::UpdateMapPlayersAgressableStatusMessage(decode(name, data)?),
+            "UpdateMountCharacteristicsMessage" => GameMessage::UpdateMountCharacteristicsMessage(decode(name, data)?),
+            "UpdateRecruitmentInformationMessage" => GameMessage::UpdateRecruitmentInformationMessage(decode(name, data)?),
+            "UpdateSelfAgressableStatusMessage" => GameMessage::UpdateSelfAgressableStatusMessage(decode(name, data)?),
+            "WarnOnPermaDeathMessage" => GameMessage::WarnOnPermaDeathMessage(decode(name, data)?),
+            "WarnOnPermaDeathStateMessage" => GameMessage::WarnOnPermaDeathStateMessage(decode(name, data)?),
+            "WatchInventoryContentMessage" => GameMessage::WatchInventoryContentMessage(decode(name, data)?),
+            "WatchQuestListMessage" => GameMessage::WatchQuestListMessage(decode(name, data)?),
+            "WatchQuestStepInfoMessage" => GameMessage::WatchQuestStepInfoMessage(decode(name, data)?),
+            "WatchQuestStepInfoRequestMessage" => GameMessage::WatchQuestStepInfoRequestMessage(decode(name, data)?),
+            "WrapperObjectAssociatedMessage" => GameMessage::WrapperObjectAssociatedMessage(decode(name, data)?),
+            "WrapperObjectDissociateRequestMessage" => GameMessage::WrapperObjectDissociateRequestMessage(decode(name, data)?),
+            "WrapperObjectErrorMessage" => GameMessage::WrapperObjectErrorMessage(decode(name, data)?),
+            "ZaapDestinationsMessage" => GameMessage::ZaapDestinationsMessage(decode(name, data)?),
+            "ZaapRespawnSaveRequestMessage" => GameMessage::ZaapRespawnSaveRequestMessage(decode(name, data)?),
+            "ZaapRespawnUpdatedMessage" => GameMessage::ZaapRespawnUpdatedMessage(decode(name, data)?),
+            _ => return Err(MessageError::UnknownMessage(name.to_string())),
+        };
+        Ok(message)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            GameMessage::AbstractGameActionFightTargetedAbilityMessage(_) => "AbstractGameActionFightTargetedAbilityMessage",
+            GameMessage::AbstractGameActionMessage(_) => "AbstractGameActionMessage",
+            GameMessage::AbstractGameActionWithAckMessage(_) => "AbstractGameActionWithAckMessage",
+            GameMessage::AbstractPartyEventMessage(_) => "AbstractPartyEventMessage",
+            GameMessage::AbstractPartyMemberInFightMessage(_) => "AbstractPartyMemberInFightMessage",
+            GameMessage::AbstractPartyMessage(_) => "AbstractPartyMessage",
+            GameMessage::AccessoryPreviewErrorMessage(_) => "AccessoryPreviewErrorMessage",
+            GameMessage::AccessoryPreviewMessage(_) => "AccessoryPreviewMessage",
+            GameMessage::AccessoryPreviewRequestMessage(_) => "AccessoryPreviewRequestMessage",
+            GameMessage::AccountCapabilitiesMessage(_) => "AccountCapabilitiesMessage",
+            GameMessage::AccountHouseMessage(_) => "AccountHouseMessage",
+            GameMessage::AccountInformationsUpdateMessage(_) => "AccountInformationsUpdateMessage",
+            GameMessage::AccountLinkRequiredMessage(_) => "AccountLinkRequiredMessage",
+            GameMessage::AccountLoggingKickedMessage(_) => "AccountLoggingKickedMessage",
+            GameMessage::AccountSubscriptionElapsedDurationMessage(_) => "AccountSubscriptionElapsedDurationMessage",
+            GameMessage::AchievementAlmostFinishedDetailedListMessage(_) => "AchievementAlmostFinishedDetailedListMessage",
+            GameMessage::AchievementAlmostFinishedDetailedListRequestMessage(_) => "AchievementAlmostFinishedDetailedListRequestMessage",
+            GameMessage::AchievementDetailedListMessage(_) => "AchievementDetailedListMessage",
+            GameMessage::AchievementDetailedListRequestMessage(_) => "AchievementDetailedListRequestMessage",
+            GameMessage::AchievementDetailsMessage(_) => "AchievementDetailsMessage",
+            GameMessage::AchievementDetailsRequestMessage(_) => "AchievementDetailsRequestMessage",
+            GameMessage::AchievementFinishedInformationMessage(_) => "AchievementFinishedInformationMessage",
+            GameMessage::AchievementFinishedMessage(_) => "AchievementFinishedMessage",
+            GameMessage::AchievementListMessage(_) => "AchievementListMessage",
+            GameMessage::AchievementRewardErrorMessage(_) => "AchievementRewardErrorMessage",
+            GameMessage::AchievementRewardRequestMessage(_) => "AchievementRewardRequestMessage",
+            GameMessage::AchievementRewardSuccessMessage(_) => "AchievementRewardSuccessMessage",
+            GameMessage::AchievementsPioneerRanksMessage(_) => "AchievementsPioneerRanksMessage",
+            GameMessage::AchievementsPioneerRanksRequestMessage(_) => "AchievementsPioneerRanksRequestMessage",
+            GameMessage::AcquaintanceAddedMessage(_) => "AcquaintanceAddedMessage",
+            GameMessage::AcquaintanceSearchErrorMessage(_) => "AcquaintanceSearchErrorMessage",
+            GameMessage::AcquaintanceSearchMessage(_) => "AcquaintanceSearchMessage",
+            GameMessage::AcquaintanceServerListMessage(_) => "AcquaintanceServerListMessage",
+            GameMessage::AcquaintancesGetListMessage(_) => "AcquaintancesGetListMessage",
+            GameMessage::AcquaintancesListMessage(_) => "AcquaintancesListMessage",
+            GameMessage::ActivityHideRequestMessage(_) => "ActivityHideRequestMessage",
+            GameMessage::ActivityLockRequestMessage(_) => "ActivityLockRequestMessage",
+            GameMessage::ActivitySuggestionsMessage(_) => "ActivitySuggestionsMessage",
+            GameMessage::ActivitySuggestionsRequestMessage(_) => "ActivitySuggestionsRequestMessage",
+            GameMessage::AddListenerOnSynchronizedStorageMessage(_) => "AddListenerOnSynchronizedStorageMessage",
+            GameMessage::AddTaxCollectorOrderedSpellMessage(_) => "AddTaxCollectorOrderedSpellMessage",
+            GameMessage::AddTaxCollectorPresetSpellMessage(_) => "AddTaxCollectorPresetSpellMessage",
+            GameMessage::AdminCommandMessage(_) => "AdminCommandMessage",
+            GameMessage::AdminQuietCommandMessage(_) => "AdminQuietCommandMessage",
+            GameMessage::AlignmentRankUpdateMessage(_) => "AlignmentRankUpdateMessage",
+            GameMessage::AlignmentWarEffortDonatePreviewMessage(_) => "AlignmentWarEffortDonatePreviewMessage",
+            GameMessage::AlignmentWarEffortDonateRequestMessage(_) => "AlignmentWarEffortDonateRequestMessage",
+            GameMessage::AlignmentWarEffortDonationResultMessage(_) => "AlignmentWarEffortDonationResultMessage",
+            GameMessage::AlignmentWarEffortProgressionMessage(_) => "AlignmentWarEffortProgressionMessage",
+            GameMessage::AlignmentWarEffortProgressionRequestMessage(_) => "AlignmentWarEffortProgressionRequestMessage",
+            GameMessage::AllianceAllRanksUpdateRequestMessage(_) => "AllianceAllRanksUpdateRequestMessage",
+            GameMessage::AllianceApplicationAnswerMessage(_) => "AllianceApplicationAnswerMessage",
+            GameMessage::AllianceApplicationDeletedMessage(_) => "AllianceApplicationDeletedMessage",
+            GameMessage::AllianceApplicationIsAnsweredMessage(_) => "AllianceApplicationIsAnsweredMessage",
+            GameMessage::AllianceApplicationListenMessage(_) => "AllianceApplicationListenMessage",
+            GameMessage::AllianceApplicationPresenceMessage(_) => "AllianceApplicationPresenceMessage",
+            GameMessage::AllianceApplicationReceivedMessage(_) => "AllianceApplicationReceivedMessage",
+            GameMessage::AllianceBulletinMessage(_) => "AllianceBulletinMessage",
+            GameMessage::AllianceBulletinSetErrorMessage(_) => "AllianceBulletinSetErrorMessage",
+            GameMessage::AllianceBulletinSetRequestMessage(_) => "AllianceBulletinSetRequestMessage",
+            GameMessage::AllianceChangeMemberRankMessage(_) => "AllianceChangeMemberRankMessage",
+            GameMessage::AllianceCreationResultMessage(_) => "AllianceCreationResultMessage",
+            GameMessage::AllianceCreationStartedMessage(_) => "AllianceCreationStartedMessage",
+            GameMessage::AllianceCreationValidMessage(_) => "AllianceCreationValidMessage",
+            GameMessage::AllianceDeleteApplicationRequestMessage(_) => "AllianceDeleteApplicationRequestMessage",
+            GameMessage::AllianceFactsErrorMessage(_) => "AllianceFactsErrorMessage",
+            GameMessage::AllianceFactsMessage(_) => "AllianceFactsMessage",
+            GameMessage::AllianceFactsRequestMessage(_) => "AllianceFactsRequestMessage",
+            GameMessage::AllianceFightFighterAddedMessage(_) => "AllianceFightFighterAddedMessage",
+            GameMessage::AllianceFightFighterRemovedMessage(_) => "AllianceFightFighterRemovedMessage",
+            GameMessage::AllianceFightFinishedMessage(_) => "AllianceFightFinishedMessage",
+            GameMessage::AllianceFightInfoMessage(_) => "AllianceFightInfoMessage",
+            GameMessage::AllianceFightPhaseUpdateMessage(_) => "AllianceFightPhaseUpdateMessage",
+            GameMessage::AllianceFightStartedMessage(_) => "AllianceFightStartedMessage",
+            GameMessage::AllianceGetPlayerApplicationMessage(_) => "AllianceGetPlayerApplicationMessage",
+            GameMessage::AllianceGetRecruitmentInformationMessage(_) => "AllianceGetRecruitmentInformationMessage",
+            GameMessage::AllianceInsiderInfoMessage(_) => "AllianceInsiderInfoMessage",
+            GameMessage::AllianceInsiderInfoRequestMessage(_) => "AllianceInsiderInfoRequestMessage",
+            GameMessage::AllianceInvitationAnswerMessage(_) => "AllianceInvitationAnswerMessage",
+            GameMessage::AllianceInvitationMessage(_) => "AllianceInvitationMessage",
+            GameMessage::AllianceInvitationStateRecrutedMessage(_) => "AllianceInvitationStateRecrutedMessage",
+            GameMessage::AllianceInvitationStateRecruterMessage(_) => "AllianceInvitationStateRecruterMessage",
+            GameMessage::AllianceInvitedMessage(_) => "AllianceInvitedMessage",
+            GameMessage::AllianceIsThereAnyApplicationMessage(_) => "AllianceIsThereAnyApplicationMessage",
+            GameMessage::AllianceJoinAutomaticallyRequestMessage(_) => "AllianceJoinAutomaticallyRequestMessage",
+            GameMessage::AllianceJoinedMessage(_) => "AllianceJoinedMessage",
+            GameMessage::AllianceKickRequestMessage(_) => "AllianceKickRequestMessage",
+            GameMessage::AllianceLeftMessage(_) => "AllianceLeftMessage",
+            GameMessage::AllianceListApplicationAnswerMessage(_) => "AllianceListApplicationAnswerMessage",
+            GameMessage::AllianceListApplicationModifiedMessage(_) => "AllianceListApplicationModifiedMessage",
+            GameMessage::AllianceListApplicationRequestMessage(_) => "AllianceListApplicationRequestMessage",
+            GameMessage::AllianceListMessage(_) => "AllianceListMessage",
+            GameMessage::AllianceMemberInformationUpdateMessage(_) => "AllianceMemberInformationUpdateMessage",
+            GameMessage::AllianceMemberLeavingMessage(_) => "AllianceMemberLeavingMessage",
+            GameMessage::AllianceMemberOnlineStatusMessage(_) => "AllianceMemberOnlineStatusMessage",
+            GameMessage::AllianceMemberStartWarningOnConnectionMessage(_) => "AllianceMemberStartWarningOnConnectionMessage",
+            GameMessage::AllianceMemberStopWarningOnConnectionMessage(_) => "AllianceMemberStopWarningOnConnectionMessage",
+            GameMessage::AllianceMembershipMessage(_) => "AllianceMembershipMessage",
+            GameMessage::AllianceModificationEmblemValidMessage(_) => "AllianceModificationEmblemValidMessage",
+            GameMessage::AllianceModificationNameAndTagValidMessage(_) => "AllianceModificationNameAndTagValidMessage",
+            GameMessage::AllianceModificationResultMessage(_) => "AllianceModificationResultMessage",
+            GameMessage::AllianceModificationStartedMessage(_) => "AllianceModificationStartedMessage",
+            GameMessage::AllianceModificationValidMessage(_) => "AllianceModificationValidMessage",
+            GameMessage::AllianceMotdMessage(_) => "AllianceMotdMessage",
+            GameMessage::AllianceMotdSetErrorMessage(_) => "AllianceMotdSetErrorMessage",
+            GameMessage::AllianceMotdSetRequestMessage(_) => "AllianceMotdSetRequestMessage",
+            GameMessage::AlliancePartialListMessage(_) => "AlliancePartialListMessage",
+            GameMessage::AlliancePlayerApplicationAbstractMessage(_) => "AlliancePlayerApplicationAbstractMessage",
+            GameMessage::AlliancePlayerApplicationInformationMessage(_) => "AlliancePlayerApplicationInformationMessage",
+            GameMessage::AlliancePlayerNoApplicationInformationMessage(_) => "AlliancePlayerNoApplicationInformationMessage",
+            GameMessage::AlliancePrismDialogQuestionMessage(_) => "AlliancePrismDialogQuestionMessage",
+            GameMessage::AllianceRankCreateRequestMessage(_) => "AllianceRankCreateRequestMessage",
+            GameMessage::AllianceRankRemoveRequestMessage(_) => "AllianceRankRemoveRequestMessage",
+            GameMessage::AllianceRankUpdateRequestMessage(_) => "AllianceRankUpdateRequestMessage",
+            GameMessage::AllianceRanksMessage(_) => "AllianceRanksMessage",
+            GameMessage::AllianceRanksRequestMessage(_) => "AllianceRanksRequestMessage",
+            GameMessage::AllianceRecruitmentInformationMessage(_) => "AllianceRecruitmentInformationMessage",
+            GameMessage::AllianceRecruitmentInvalidateMessage(_) => "AllianceRecruitmentInvalidateMessage",
+            GameMessage::AllianceRightsUpdateMessage(_) => "AllianceRightsUpdateMessage",
+            GameMessage::AllianceSubmitApplicationMessage(_) => "AllianceSubmitApplicationMessage",
+            GameMessage::AllianceSummaryMessage(_) => "AllianceSummaryMessage",
+            GameMessage::AllianceSummaryRequestMessage(_) => "AllianceSummaryRequestMessage",
+            GameMessage::AllianceUpdateApplicationMessage(_) => "AllianceUpdateApplicationMessage",
+            GameMessage::AllianceUpdateRecruitmentInformationMessage(_) => "AllianceUpdateRecruitmentInformationMessage",
+            GameMessage::AlmanachCalendarDateMessage(_) => "AlmanachCalendarDateMessage",
+            GameMessage::AlreadyConnectedMessage(_) => "AlreadyConnectedMessage",
+            GameMessage::AlterationAddedMessage(_) => "AlterationAddedMessage",
+            GameMessage::AlterationRemovedMessage(_) => "AlterationRemovedMessage",
+            GameMessage::AlterationsMessage(_) => "AlterationsMessage",
+            GameMessage::AlterationsUpdatedMessage(_) => "AlterationsUpdatedMessage",
+            GameMessage::AnomalyOpenedMessage(_) => "AnomalyOpenedMessage",
+            GameMessage::AnomalyStateMessage(_) => "AnomalyStateMessage",
+            GameMessage::AnomalySubareaInformationRequestMessage(_) => "AnomalySubareaInformationRequestMessage",
+            GameMessage::AnomalySubareaInformationResponseMessage(_) => "AnomalySubareaInformationResponseMessage",
+            GameMessage::ApplySpellModifierMessage(_) => "ApplySpellModifierMessage",
+            GameMessage::AreaFightModificatorUpdateMessage(_) => "AreaFightModificatorUpdateMessage",
+            GameMessage::ArenaFightAnswerAcknowledgementMessage(_) => "ArenaFightAnswerAcknowledgementMessage",
+            GameMessage::ArenaFighterIdleMessage(_) => "ArenaFighterIdleMessage",
+            GameMessage::ArenaFighterLeaveMessage(_) => "ArenaFighterLeaveMessage",
+            GameMessage::AtlasPointInformationsMessage(_) => "AtlasPointInformationsMessage",
+            GameMessage::AuthenticationTicketAcceptedMessage(_) => "AuthenticationTicketAcceptedMessage",
+            GameMessage::AuthenticationTicketMessage(_) => "AuthenticationTicketMessage",
+            GameMessage::AuthenticationTicketRefusedMessage(_) => "AuthenticationTicketRefusedMessage",
+            GameMessage::BasicAckMessage(_) => "BasicAckMessage",
+            GameMessage::BasicDateMessage(_) => "BasicDateMessage",
+            GameMessage::BasicLatencyStatsMessage(_) => "BasicLatencyStatsMessage",
+            GameMessage::BasicLatencyStatsRequestMessage(_) => "BasicLatencyStatsRequestMessage",
+            GameMessage::BasicNoOperationMessage(_) => "BasicNoOperationMessage",
+            GameMessage::BasicPingMessage(_) => "BasicPingMessage",
+            GameMessage::BasicPongMessage(_) => "BasicPongMessage",
+            GameMessage::BasicStatMessage(_) => "BasicStatMessage",
+            GameMessage::BasicStatWithDataMessage(_) => "BasicStatWithDataMessage",
+            GameMessage::BasicTimeMessage(_) => "BasicTimeMessage",
+            GameMessage::BasicWhoAmIRequestMessage(_) => "BasicWhoAmIRequestMessage",
+            GameMessage::BasicWhoIsMessage(_) => "BasicWhoIsMessage",
+            GameMessage::BasicWhoIsNoMatchMessage(_) => "BasicWhoIsNoMatchMessage",
+            GameMessage::BasicWhoIsRequestMessage(_) => "BasicWhoIsRequestMessage",
+            GameMessage::BreachBonusMessage(_) => "BreachBonusMessage",
+            GameMessage::BreachBranchesMessage(_) => "BreachBranchesMessage",
+            GameMessage::BreachBudgetMessage(_) => "BreachBudgetMessage",
+            GameMessage::BreachCharactersMessage(_) => "BreachCharactersMessage",
+            GameMessage::BreachEnterMessage(_) => "BreachEnterMessage",
+            GameMessage::BreachExitRequestMessage(_) => "BreachExitRequestMessage",
+            GameMessage::BreachExitResponseMessage(_) => "BreachExitResponseMessage",
+            GameMessage::BreachGameFightEndMessage(_) => "BreachGameFightEndMessage",
+            GameMessage::BreachInvitationAnswerMessage(_) => "BreachInvitationAnswerMessage",
+            GameMessage::BreachInvitationCloseMessage(_) => "BreachInvitationCloseMessage",
+            GameMessage::BreachInvitationOfferMessage(_) => "BreachInvitationOfferMessage",
+            GameMessage::BreachInvitationRequestMessage(_) => "BreachInvitationRequestMessage",
+            GameMessage::BreachInvitationResponseMessage(_) => "BreachInvitationResponseMessage",
+            GameMessage::BreachKickRequestMessage(_) => "BreachKickRequestMessage",
+            GameMessage::BreachKickResponseMessage(_) => "BreachKickResponseMessage",
+            GameMessage::BreachRewardBoughtMessage(_) => "BreachRewardBoughtMessage",
+            GameMessage::BreachRewardBuyMessage(_) => "BreachRewardBuyMessage",
+            GameMessage::BreachRewardsMessage(_) => "BreachRewardsMessage",
+            GameMessage::BreachRoomLockedMessage(_) => "BreachRoomLockedMessage",
+            GameMessage::BreachRoomUnlockRequestMessage(_) => "BreachRoomUnlockRequestMessage",
+            GameMessage::BreachRoomUnlockResultMessage(_) => "BreachRoomUnlockResultMessage",
+            GameMessage::BreachSavedMessage(_) => "BreachSavedMessage",
+            GameMessage::BreachStateMessage(_) => "BreachStateMessage",
+            GameMessage::BreachTeleportRequestMessage(_) => "BreachTeleportRequestMessage",
+            GameMessage::BreachTeleportResponseMessage(_) => "BreachTeleportResponseMessage",
+            GameMessage::BulletinMessage(_) => "BulletinMessage",
+            GameMessage::ChallengeAddMessage(_) => "ChallengeAddMessage",
+            GameMessage::ChallengeBonusChoiceMessage(_) => "ChallengeBonusChoiceMessage",
+            GameMessage::ChallengeBonusChoiceSelectedMessage(_) => "ChallengeBonusChoiceSelectedMessage",
+            GameMessage::ChallengeFightJoinRefusedMessage(_) => "ChallengeFightJoinRefusedMessage",
+            GameMessage::ChallengeListMessage(_) => "ChallengeListMessage",
+            GameMessage::ChallengeModSelectMessage(_) => "ChallengeModSelectMessage",
+            GameMessage::ChallengeModSelectedMessage(_) => "ChallengeModSelectedMessage",
+            GameMessage::ChallengeNumberMessage(_) => "ChallengeNumberMessage",
+            GameMessage::ChallengeProposalMessage(_) => "ChallengeProposalMessage",
+            GameMessage::ChallengeReadyMessage(_) => "ChallengeReadyMessage",
+            GameMessage::ChallengeResultMessage(_) => "ChallengeResultMessage",
+            GameMessage::ChallengeSelectedMessage(_) => "ChallengeSelectedMessage",
+            GameMessage::ChallengeSelectionMessage(_) => "ChallengeSelectionMessage",
+            GameMessage::ChallengeTargetsMessage(_) => "ChallengeTargetsMessage",
+            GameMessage::ChallengeTargetsRequestMessage(_) => "ChallengeTargetsRequestMessage",
+            GameMessage::ChallengeValidateMessage(_) => "ChallengeValidateMessage",
+            GameMessage::ChangeHavenBagRoomRequestMessage(_) => "ChangeHavenBagRoomRequestMessage",
+            GameMessage::ChangeMapMessage(_) => "ChangeMapMessage",
+            GameMessage::ChangeThemeRequestMessage(_) => "ChangeThemeRequestMessage",
+            GameMessage::ChannelEnablingChangeMessage(_) => "ChannelEnablingChangeMessage",
+            GameMessage::ChannelEnablingMessage(_) => "ChannelEnablingMessage",
+            GameMessage::CharacterAlignmentWarEffortProgressionMessage(_) => "CharacterAlignmentWarEffortProgressionMessage",
+            GameMessage::CharacterAlignmentWarEffortProgressionRequestMessage(_) => "CharacterAlignmentWarEffortProgressionRequestMessage",
+            GameMessage::CharacterCanBeCreatedRequestMessage(_) => "CharacterCanBeCreatedRequestMessage",
+            GameMessage::CharacterCanBeCreatedResultMessage(_) => "CharacterCanBeCreatedResultMessage",
+            GameMessage::CharacterCapabilitiesMessage(_) => "CharacterCapabilitiesMessage",
+            GameMessage::CharacterCreationRequestMessage(_) => "CharacterCreationRequestMessage",
+            GameMessage::CharacterCreationResultMessage(_) => "CharacterCreationResultMessage",
+            GameMessage::CharacterDeletionErrorMessage(_) => "CharacterDeletionErrorMessage",
+            GameMessage::CharacterDeletionPrepareMessage(_) => "CharacterDeletionPrepareMessage",
+            GameMessage::CharacterDeletionPrepareRequestMessage(_) => "CharacterDeletionPrepareRequestMessage",
+            GameMessage::CharacterDeletionRequestMessage(_) => "CharacterDeletionRequestMessage",
+            GameMessage::CharacterExperienceGainMessage(_) => "CharacterExperienceGainMessage",
+            GameMessage::CharacterFirstSelectionMessage(_) => "CharacterFirstSelectionMessage",
+            GameMessage::CharacterLevelUpInformationMessage(_) => "CharacterLevelUpInformationMessage",
+            GameMessage::CharacterLevelUpMessage(_) => "CharacterLevelUpMessage",
+            GameMessage::CharacterLoadingCompleteMessage(_) => "CharacterLoadingCompleteMessage",
+            GameMessage::CharacterNameSuggestionFailureMessage(_) => "CharacterNameSuggestionFailureMessage",
+            GameMessage::CharacterNameSuggestionRequestMessage(_) => "CharacterNameSuggestionRequestMessage",
+            GameMessage::CharacterNameSuggestionSuccessMessage(_) => "CharacterNameSuggestionSuccessMessage",
+            GameMessage::CharacterReplayRequestMessage(_) => "CharacterReplayRequestMessage",
+            GameMessage::CharacterReplayWithRemodelRequestMessage(_) => "CharacterReplayWithRemodelRequestMessage",
+            GameMessage::CharacterSelectedErrorMessage(_) => "CharacterSelectedErrorMessage",
+            GameMessage::CharacterSelectedForceMessage(_) => "CharacterSelectedForceMessage",
+            GameMessage::CharacterSelectedForceReadyMessage(_) => "CharacterSelectedForceReadyMessage",
+            GameMessage::CharacterSelectedSuccessMessage(_) => "CharacterSelectedSuccessMessage",
+            GameMessage::CharacterSelectionMessage(_) => "CharacterSelectionMessage",
+            GameMessage::CharacterSelectionWithRemodelMessage(_) => "CharacterSelectionWithRemodelMessage",
+            GameMessage::CharacterStatsListMessage(_) => "CharacterStatsListMessage",
+            GameMessage::CharactersListErrorMessage(_) => "CharactersListErrorMessage",
+            GameMessage::CharactersListMessage(_) => "CharactersListMessage",
+            GameMessage::CharactersListRequestMessage(_) => "CharactersListRequestMessage",
+            GameMessage::CharactersListWithRemodelingMessage(_) => "CharactersListWithRemodelingMessage",
+            GameMessage::ChatAbstractClientMessage(_) => "ChatAbstractClientMessage",
+            GameMessage::ChatAbstractServerMessage(_) => "ChatAbstractServerMessage",
+            GameMessage::ChatAdminServerMessage(_) => "ChatAdminServerMessage",
+            GameMessage::ChatClientMultiMessage(_) => "ChatClientMultiMessage",
+            GameMessage::ChatClientMultiWithObjectMessage(_) => "ChatClientMultiWithObjectMessage",
+            GameMessage::ChatClientPrivateMessage(_) => "ChatClientPrivateMessage",
+            GameMessage::ChatClientPrivateWithObjectMessage(_) => "ChatClientPrivateWithObjectMessage",
+            GameMessage::ChatCommunityChannelCommunityMessage(_) => "ChatCommunityChannelCommunityMessage",
+            GameMessage::ChatCommunityChannelSetCommunityRequestMessage(_) => "ChatCommunityChannelSetCommunityRequestMessage",
+            GameMessage::ChatErrorMessage(_) => "ChatErrorMessage",
+            GameMessage::ChatKolizeumServerMessage(_) => "ChatKolizeumServerMessage",
+            GameMessage::ChatServerCopyMessage(_) => "ChatServerCopyMessage",
+            GameMessage::ChatServerCopyWithObjectMessage(_) => "ChatServerCopyWithObjectMessage",
+            GameMessage::ChatServerMessage(_) => "ChatServerMessage",
+            GameMessage::ChatServerWithObjectMessage(_) => "ChatServerWithObjectMessage",
+            GameMessage::ChatSmileyExtraPackListMessage(_) => "ChatSmileyExtraPackListMessage",
+            GameMessage::ChatSmileyMessage(_) => "ChatSmileyMessage",
+            GameMessage::ChatSmileyRequestMessage(_) => "ChatSmileyRequestMessage",
+            GameMessage::CheckFileMessage(_) => "CheckFileMessage",
+            GameMessage::CheckFileRequestMessage(_) => "CheckFileRequestMessage",
+            GameMessage::CheckIntegrityMessage(_) => "CheckIntegrityMessage",
+            GameMessage::CinematicMessage(_) => "CinematicMessage",
+            GameMessage::ClientKeyMessage(_) => "ClientKeyMessage",
+            GameMessage::ClientUIOpenedByObjectMessage(_) => "ClientUIOpenedByObjectMessage",
+            GameMessage::ClientUIOpenedMessage(_) => "ClientUIOpenedMessage",
+            GameMessage::ClientYouAreDrunkMessage(_) => "ClientYouAreDrunkMessage",
+            GameMessage::CloseHavenBagFurnitureSequenceRequestMessage(_) => "CloseHavenBagFurnitureSequenceRequestMessage",
+            GameMessage::CompassResetMessage(_) => "CompassResetMessage",
+            GameMessage::CompassUpdateMessage(_) => "CompassUpdateMessage",
+            GameMessage::CompassUpdatePartyMemberMessage(_) => "CompassUpdatePartyMemberMessage",
+            GameMessage::CompassUpdatePvpSeekMessage(_) => "CompassUpdatePvpSeekMessage",
+            GameMessage::ConfirmationOfListeningTaxCollectorUpdatesMessage(_) => "ConfirmationOfListeningTaxCollectorUpdatesMessage",
+            GameMessage::ConsoleCommandsListMessage(_) => "ConsoleCommandsListMessage",
+            GameMessage::ConsoleEndMessage(_) => "ConsoleEndMessage",
+            GameMessage::ConsoleMessage(_) => "ConsoleMessage",
+            GameMessage::ConsumeAllGameActionItemMessage(_) => "ConsumeAllGameActionItemMessage",
+            GameMessage::ConsumeGameActionItemMessage(_) => "ConsumeGameActionItemMessage",
+            GameMessage::ContactAddFailureMessage(_) => "ContactAddFailureMessage",
+            GameMessage::ContactLookErrorMessage(_) => "ContactLookErrorMessage",
+            GameMessage::ContactLookMessage(_) => "ContactLookMessage",
+            GameMessage::ContactLookRequestByIdMessage(_) => "ContactLookRequestByIdMessage",
+            GameMessage::ContactLookRequestByNameMessage(_) => "ContactLookRequestByNameMessage",
+            GameMessage::ContactLookRequestMessage(_) => "ContactLookRequestMessage",
+            GameMessage::CreateGuildRankRequestMessage(_) => "CreateGuildRankRequestMessage",
+            GameMessage::CredentialsAcknowledgementMessage(_) => "CredentialsAcknowledgementMessage",
+            GameMessage::CurrentMapInstanceMessage(_) => "CurrentMapInstanceMessage",
+            GameMessage::CurrentMapMessage(_) => "CurrentMapMessage",
+            GameMessage::CurrentServerStatusUpdateMessage(_) => "CurrentServerStatusUpdateMessage",
+            GameMessage::DebtsDeleteMessage(_) => "DebtsDeleteMessage",
+            GameMessage::DebtsUpdateMessage(_) => "DebtsUpdateMessage",
+            GameMessage::DebugClearHighlightCellsMessage(_) => "DebugClearHighlightCellsMessage",
+            GameMessage::DebugHighlightCellsMessage(_) => "DebugHighlightCellsMessage",
+            GameMessage::DebugInClientMessage(_) => "DebugInClientMessage",
+            GameMessage::DecraftResultMessage(_) => "DecraftResultMessage",
+            GameMessage::DiceRollRequestMessage(_) => "DiceRollRequestMessage",
+            GameMessage::DisplayNumericalValuePaddockMessage(_) => "DisplayNumericalValuePaddockMessage",
+            GameMessage::DocumentReadingBeginMessage(_) => "DocumentReadingBeginMessage",
+            GameMessage::DumpedEntityStatsMessage(_) => "DumpedEntityStatsMessage",
+            GameMessage::DungeonKeyRingMessage(_) => "DungeonKeyRingMessage",
+            GameMessage::DungeonKeyRingUpdateMessage(_) => "DungeonKeyRingUpdateMessage",
+            GameMessage::DungeonPartyFinderAvailableDungeonsMessage(_) => "DungeonPartyFinderAvailableDungeonsMessage",
+            GameMessage::DungeonPartyFinderAvailableDungeonsRequestMessage(_) => "DungeonPartyFinderAvailableDungeonsRequestMessage",
+            GameMessage::DungeonPartyFinderListenErrorMessage(_) => "DungeonPartyFinderListenErrorMessage",
+            GameMessage::DungeonPartyFinderListenRequestMessage(_) => "DungeonPartyFinderListenRequestMessage",
+            GameMessage::DungeonPartyFinderRegisterErrorMessage(_) => "DungeonPartyFinderRegisterErrorMessage",
+            GameMessage::DungeonPartyFinderRegisterRequestMessage(_) => "DungeonPartyFinderRegisterRequestMessage",
+            GameMessage::DungeonPartyFinderRegisterSuccessMessage(_) => "DungeonPartyFinderRegisterSuccessMessage",
+            GameMessage::DungeonPartyFinderRoomContentMessage(_) => "DungeonPartyFinderRoomContentMessage",
+            GameMessage::DungeonPartyFinderRoomContentUpdateMessage(_) => "DungeonPartyFinderRoomContentUpdateMessage",
+            GameMessage::EditHavenBagCancelRequestMessage(_) => "EditHavenBagCancelRequestMessage",
+            GameMessage::EditHavenBagFinishedMessage(_) => "EditHavenBagFinishedMessage",
+            GameMessage::EditHavenBagRequestMessage(_) => "EditHavenBagRequestMessage",
+            GameMessage::EditHavenBagStartMessage(_) => "EditHavenBagStartMessage",
+            GameMessage::EmoteAddMessage(_) => "EmoteAddMessage",
+            GameMessage::EmoteListMessage(_) => "EmoteListMessage",
+            GameMessage::EmotePlayAbstractMessage(_) => "EmotePlayAbstractMessage",
+            GameMessage::EmotePlayErrorMessage(_) => "EmotePlayErrorMessage",
+            GameMessage::EmotePlayMassiveMessage(_) => "EmotePlayMassiveMessage",
+            GameMessage::EmotePlayMessage(_) => "EmotePlayMessage",
+            GameMessage::EmotePlayRequestMessage(_) => "EmotePlayRequestMessage",
+            GameMessage::EmoteRemoveMessage(_) => "EmoteRemoveMessage",
+            GameMessage::EnabledChannelsMessage(_) => "EnabledChannelsMessage",
+            GameMessage::EnterHavenBagRequestMessage(_) => "EnterHavenBagRequestMessage",
+            GameMessage::EntitiesInformationMessage(_) => "EntitiesInformationMessage",
+            GameMessage::EntityInformationMessage(_) => "EntityInformationMessage",
+            GameMessage::EntityTalkMessage(_) => "EntityTalkMessage",
+            GameMessage::ErrorMapNotFoundMessage(_) => "ErrorMapNotFoundMessage",
+            GameMessage::EvolutiveObjectRecycleResultMessage(_) => "EvolutiveObjectRecycleResultMessage",
+            GameMessage::ExchangeAcceptMessage(_) => "ExchangeAcceptMessage",
+            GameMessage::ExchangeBidHouseBuyMessage(_) => "ExchangeBidHouseBuyMessage",
+            GameMessage::ExchangeBidHouseBuyResultMessage(_) => "ExchangeBidHouseBuyResultMessage",
+            GameMessage::ExchangeBidHouseGenericItemAddedMessage(_) => "ExchangeBidHouseGenericItemAddedMessage",
+            GameMessage::ExchangeBidHouseGenericItemRemovedMessage(_) => "ExchangeBidHouseGenericItemRemovedMessage",
+            GameMessage::ExchangeBidHouseInListAddedMessage(_) => "ExchangeBidHouseInListAddedMessage",
+            GameMessage::ExchangeBidHouseInListRemovedMessage(_) => "ExchangeBidHouseInListRemovedMessage",
+            GameMessage::ExchangeBidHouseInListUpdatedMessage(_) => "ExchangeBidHouseInListUpdatedMessage",
+            GameMessage::ExchangeBidHouseItemAddOkMessage(_) => "ExchangeBidHouseItemAddOkMessage",
+            GameMessage::ExchangeBidHouseItemRemoveOkMessage(_) => "ExchangeBidHouseItemRemoveOkMessage",
+            GameMessage::ExchangeBidHouseListMessage(_) => "ExchangeBidHouseListMessage",
+            GameMessage::ExchangeBidHousePriceMessage(_) => "ExchangeBidHousePriceMessage",
+            GameMessage::ExchangeBidHouseSearchMessage(_) => "ExchangeBidHouseSearchMessage",
+            GameMessage::ExchangeBidHouseTypeMessage(_) => "ExchangeBidHouseTypeMessage",
+            GameMessage::ExchangeBidHouseUnsoldItemsMessage(_) => "ExchangeBidHouseUnsoldItemsMessage",
+            GameMessage::ExchangeBidPriceForSellerMessage(_) => "ExchangeBidPriceForSellerMessage",
+            GameMessage::ExchangeBidPriceMessage(_) => "ExchangeBidPriceMessage",
+            GameMessage::ExchangeBidSearchOkMessage(_) => "ExchangeBidSearchOkMessage",
+            GameMessage::ExchangeBuyMessage(_) => "ExchangeBuyMessage",
+            GameMessage::ExchangeBuyOkMessage(_) => "ExchangeBuyOkMessage",
+            GameMessage::ExchangeCraftCountModifiedMessage(_) => "ExchangeCraftCountModifiedMessage",
+            GameMessage::ExchangeCraftCountRequestMessage(_) => "ExchangeCraftCountRequestMessage",
+            GameMessage::ExchangeCraftPaymentModificationRequestMessage(_) => "ExchangeCraftPaymentModificationRequestMessage",
+            GameMessage::ExchangeCraftPaymentModifiedMessage(_) => "ExchangeCraftPaymentModifiedMessage",
+            GameMessage::ExchangeCraftResultMagicWithObjectDescMessage(_) => "ExchangeCraftResultMagicWithObjectDescMessage",
+            GameMessage::ExchangeCraftResultMessage(_) => "ExchangeCraftResultMessage",
+            GameMessage::ExchangeCraftResultWithObjectDescMessage(_) => "ExchangeCraftResultWithObjectDescMessage",
+            GameMessage::ExchangeCraftResultWithObjectIdMessage(_) => "ExchangeCraftResultWithObjectIdMessage",
+            GameMessage::ExchangeCrafterJobLevelupMessage(_) => "ExchangeCrafterJobLevelupMessage",
+            GameMessage::ExchangeErrorMessage(_) => "ExchangeErrorMessage",
+            GameMessage::ExchangeHandleMountsMessage(_) => "ExchangeHandleMountsMessage",
+            GameMessage::ExchangeIsReadyMessage(_) => "ExchangeIsReadyMessage",
+            GameMessage::ExchangeItemAutoCraftStopedMessage(_) => "ExchangeItemAutoCraftStopedMessage",
+            GameMessage::ExchangeKamaModifiedMessage(_) => "ExchangeKamaModifiedMessage",
+            GameMessage::ExchangeLeaveMessage(_) => "ExchangeLeaveMessage",
+            GameMessage::ExchangeMoneyMovementInformationMessage(_) => "ExchangeMoneyMovementInformationMessage",
+            GameMessage::ExchangeMountFreeFromPaddockMessage(_) => "ExchangeMountFreeFromPaddockMessage",
+            GameMessage::ExchangeMountStableErrorMessage(_) => "ExchangeMountStableErrorMessage",
+            GameMessage::ExchangeMountSterilizeFromPaddockMessage(_) => "ExchangeMountSterilizeFromPaddockMessage",
+            GameMessage::ExchangeMountsPaddockAddMessage(_) => "ExchangeMountsPaddockAddMessage",
+            GameMessage::ExchangeMountsPaddockRemoveMessage(_) => "ExchangeMountsPaddockRemoveMessage",
+            GameMessage::ExchangeMountsStableAddMessage(_) => "ExchangeMountsStableAddMessage",
+            GameMessage::ExchangeMountsStableBornAddMessage(_) => "ExchangeMountsStableBornAddMessage",
+            GameMessage::ExchangeMountsStableRemoveMessage(_) => "ExchangeMountsStableRemoveMessage",
+            GameMessage::ExchangeMountsTakenFromPaddockMessage(_) => "ExchangeMountsTakenFromPaddockMessage",
+            GameMessage::ExchangeMultiCraftCrafterCanUseHisRessourcesMessage(_) => "ExchangeMultiCraftCrafterCanUseHisRessourcesMessage",
+            GameMessage::ExchangeMultiCraftSetCrafterCanUseHisRessourcesMessage(_) => "ExchangeMultiCraftSetCrafterCanUseHisRessourcesMessage",
+            GameMessage::ExchangeObjectAddedMessage(_) => "ExchangeObjectAddedMessage",
+            GameMessage::ExchangeObjectMessage(_) => "ExchangeObjectMessage",
+            GameMessage::ExchangeObjectModifiedInBagMessage(_) => "ExchangeObjectModifiedInBagMessage",
+            GameMessage::ExchangeObjectModifiedMessage(_) => "ExchangeObjectModifiedMessage",
+            GameMessage::ExchangeObjectModifyPricedMessage(_) => "ExchangeObjectModifyPricedMessage",
+            GameMessage::ExchangeObjectMoveKamaMessage(_) => "ExchangeObjectMoveKamaMessage",
+            GameMessage::ExchangeObjectMoveMessage(_) => "ExchangeObjectMoveMessage",
+            GameMessage::ExchangeObjectMovePricedMessage(_) => "ExchangeObjectMovePricedMessage",
+            GameMessage::ExchangeObjectMoveToTabMessage(_) => "ExchangeObjectMoveToTabMessage",
+            GameMessage::ExchangeObjectPutInBagMessage(_) => "ExchangeObjectPutInBagMessage",
+            GameMessage::ExchangeObjectRemovedFromBagMessage(_) => "ExchangeObjectRemovedFromBagMessage",
+            GameMessage::ExchangeObjectRemovedMessage(_) => "ExchangeObjectRemovedMessage",
+            GameMessage::ExchangeObjectTransfertAllFromInvMessage(_) => "ExchangeObjectTransfertAllFromInvMessage",
+            GameMessage::ExchangeObjectTransfertAllToInvMessage(_) => "ExchangeObjectTransfertAllToInvMessage",
+            GameMessage::ExchangeObjectTransfertExistingFromInvMessage(_) => "ExchangeObjectTransfertExistingFromInvMessage",
+            GameMessage::ExchangeObjectTransfertExistingToInvMessage(_) => "ExchangeObjectTransfertExistingToInvMessage",
+            GameMessage::ExchangeObjectTransfertListFromInvMessage(_) => "ExchangeObjectTransfertListFromInvMessage",
+            GameMessage::ExchangeObjectTransfertListToInvMessage(_) => "ExchangeObjectTransfertListToInvMessage",
+            GameMessage::ExchangeObjectTransfertListWithQuantityToInvMessage(_) => "ExchangeObjectTransfertListWithQuantityToInvMessage",
+            GameMessage::ExchangeObjectUseInWorkshopMessage(_) => "ExchangeObjectUseInWorkshopMessage",
+            GameMessage::ExchangeObjectsAddedMessage(_) => "ExchangeObjectsAddedMessage",
+            GameMessage::ExchangeObjectsModifiedMessage(_) => "ExchangeObjectsModifiedMessage",
+            GameMessage::ExchangeObjectsRemovedMessage(_) => "ExchangeObjectsRemovedMessage",
+            GameMessage::ExchangeOfflineSoldItemsMessage(_) => "ExchangeOfflineSoldItemsMessage",
+            GameMessage::ExchangeOkMultiCraftMessage(_) => "ExchangeOkMultiCraftMessage",
+            GameMessage::ExchangePlayerMultiCraftRequestMessage(_) => "ExchangePlayerMultiCraftRequestMessage",
+            GameMessage::ExchangePlayerRequestMessage(_) => "ExchangePlayerRequestMessage",
+            GameMessage::ExchangePodsModifiedMessage(_) => "ExchangePodsModifiedMessage",
+            GameMessage::ExchangeReadyMessage(_) => "ExchangeReadyMessage",
+            GameMessage::ExchangeReplayStopMessage(_) => "ExchangeReplayStopMessage",
+            GameMessage::ExchangeRequestMessage(_) => "ExchangeRequestMessage",
+            GameMessage::ExchangeRequestOnMountStockMessage(_) => "ExchangeRequestOnMountStockMessage",
+            GameMessage::ExchangeRequestOnTaxCollectorMessage(_) => "ExchangeRequestOnTaxCollectorMessage",
+            GameMessage::ExchangeRequestedMessage(_) => "ExchangeRequestedMessage",
+            GameMessage::ExchangeRequestedTradeMessage(_) => "ExchangeRequestedTradeMessage",
+            GameMessage::ExchangeSellMessage(_) => "ExchangeSellMessage",
+            GameMessage::ExchangeSellOkMessage(_) => "ExchangeSellOkMessage",
+            GameMessage::ExchangeSetCraftRecipeMessage(_) => "ExchangeSetCraftRecipeMessage",
+            GameMessage::ExchangeStartOkCraftMessage(_) => "ExchangeStartOkCraftMessage",
+            GameMessage::ExchangeStartOkCraftWithInformationMessage(_) => "ExchangeStartOkCraftWithInformationMessage",
+            GameMessage::ExchangeStartOkEvolutiveObjectRecycleTradeMessage(_) => "ExchangeStartOkEvolutiveObjectRecycleTradeMessage",
+            GameMessage::ExchangeStartOkJobIndexMessage(_) => "ExchangeStartOkJobIndexMessage",
+            GameMessage::ExchangeStartOkMountMessage(_) => "ExchangeStartOkMountMessage",
+            GameMessage::ExchangeStartOkMountWithOutPaddockMessage(_) => "ExchangeStartOkMountWithOutPaddockMessage",
+            GameMessage::ExchangeStartOkMulticraftCrafterMessage(_) => "ExchangeStartOkMulticraftCrafterMessage",
+            GameMessage::ExchangeStartOkMulticraftCustomerMessage(_) => "ExchangeStartOkMulticraftCustomerMessage",
+            GameMessage::ExchangeStartOkNpcShopMessage(_) => "ExchangeStartOkNpcShopMessage",
+            GameMessage::ExchangeStartOkNpcTradeMessage(_) => "ExchangeStartOkNpcTradeMessage",
+            GameMessage::ExchangeStartOkRecycleTradeMessage(_) => "ExchangeStartOkRecycleTradeMessage",
+            GameMessage::ExchangeStartOkRunesTradeMessage(_) => "ExchangeStartOkRunesTradeMessage",
+            GameMessage::ExchangeStartedBidBuyerMessage(_) => "ExchangeStartedBidBuyerMessage",
+            GameMessage::ExchangeStartedBidSellerMessage(_) => "ExchangeStartedBidSellerMessage",
+            GameMessage::ExchangeStartedMessage(_) => "ExchangeStartedMessage",
+            GameMessage::ExchangeStartedMountStockMessage(_) => "ExchangeStartedMountStockMessage",
+            GameMessage::ExchangeStartedTaxCollectorEquipmentMessage(_) => "ExchangeStartedTaxCollectorEquipmentMessage",
+            GameMessage::ExchangeStartedTaxCollectorShopMessage(_) => "ExchangeStartedTaxCollectorShopMessage",
+            GameMessage::ExchangeStartedWithMultiTabStorageMessage(_) => "ExchangeStartedWithMultiTabStorageMessage",
+            GameMessage::ExchangeStartedWithPodsMessage(_) => "ExchangeStartedWithPodsMessage",
+            GameMessage::ExchangeStartedWithStorageMessage(_) => "ExchangeStartedWithStorageMessage",
+            GameMessage::ExchangeStoppedMessage(_) => "ExchangeStoppedMessage",
+            GameMessage::ExchangeTaxCollectorGetMessage(_) => "ExchangeTaxCollectorGetMessage",
+            GameMessage::ExchangeTypesExchangerDescriptionForUserMessage(_) => "ExchangeTypesExchangerDescriptionForUserMessage",
+            GameMessage::ExchangeTypesItemsExchangerDescriptionForUserMessage(_) => "ExchangeTypesItemsExchangerDescriptionForUserMessage",
+            GameMessage::ExchangeWaitingResultMessage(_) => "ExchangeWaitingResultMessage",
+            GameMessage::ExchangeWeightMessage(_) => "ExchangeWeightMessage",
+            GameMessage::ExitHavenBagRequestMessage(_) => "ExitHavenBagRequestMessage",
+            GameMessage::FighterStatsListMessage(_) => "FighterStatsListMessage",
+            GameMessage::FinishMoveListMessage(_) => "FinishMoveListMessage",
+            GameMessage::FinishMoveListRequestMessage(_) => "FinishMoveListRequestMessage",
+            GameMessage::FinishMoveSetRequestMessage(_) => "FinishMoveSetRequestMessage",
+            GameMessage::FocusedExchangeReadyMessage(_) => "FocusedExchangeReadyMessage",
+            GameMessage::FollowQuestObjectiveRequestMessage(_) => "FollowQuestObjectiveRequestMessage",
+            GameMessage::FollowedQuestsMessage(_) => "FollowedQuestsMessage",
+            GameMessage::ForceAccountErrorMessage(_) => "ForceAccountErrorMessage",
+            GameMessage::ForceAccountMessage(_) => "ForceAccountMessage",
+            GameMessage::ForceAccountStatusMessage(_) => "ForceAccountStatusMessage",
+            GameMessage::ForgettableSpellClientActionMessage(_) => "ForgettableSpellClientActionMessage",
+            GameMessage::ForgettableSpellDeleteMessage(_) => "ForgettableSpellDeleteMessage",
+            GameMessage::ForgettableSpellEquipmentSlotsMessage(_) => "ForgettableSpellEquipmentSlotsMessage",
+            GameMessage::ForgettableSpellListUpdateMessage(_) => "ForgettableSpellListUpdateMessage",
+            GameMessage::FriendAddFailureMessage(_) => "FriendAddFailureMessage",
+            GameMessage::FriendAddRequestMessage(_) => "FriendAddRequestMessage",
+            GameMessage::FriendAddedMessage(_) => "FriendAddedMessage",
+            GameMessage::FriendDeleteRequestMessage(_) => "FriendDeleteRequestMessage",
+            GameMessage::FriendDeleteResultMessage(_) => "FriendDeleteResultMessage",
+            GameMessage::FriendGuildSetWarnOnAchievementCompleteMessage(_) => "FriendGuildSetWarnOnAchievementCompleteMessage",
+            GameMessage::FriendGuildWarnOnAchievementCompleteStateMessage(_) => "FriendGuildWarnOnAchievementCompleteStateMessage",
+            GameMessage::FriendJoinRequestMessage(_) => "FriendJoinRequestMessage",
+            GameMessage::FriendSetStatusShareMessage(_) => "FriendSetStatusShareMessage",
+            GameMessage::FriendSetWarnOnConnectionMessage(_) => "FriendSetWarnOnConnectionMessage",
+            GameMessage::FriendSetWarnOnLevelGainMessage(_) => "FriendSetWarnOnLevelGainMessage",
+            GameMessage::FriendSpouseFollowWithCompassRequestMessage(_) => "FriendSpouseFollowWithCompassRequestMessage",
+            GameMessage::FriendSpouseJoinRequestMessage(_) => "FriendSpouseJoinRequestMessage",
+            GameMessage::FriendStatusShareStateMessage(_) => "FriendStatusShareStateMessage",
+            GameMessage::FriendUpdateMessage(_) => "FriendUpdateMessage",
+            GameMessage::FriendWarnOnConnectionStateMessage(_) => "FriendWarnOnConnectionStateMessage",
+            GameMessage::FriendWarnOnLevelGainStateMessage(_) => "FriendWarnOnLevelGainStateMessage",
+            GameMessage::FriendsGetListMessage(_) => "FriendsGetListMessage",
+            GameMessage::FriendsListMessage(_) => "FriendsListMessage",
+            GameMessage::GameActionAcknowledgementMessage(_) => "GameActionAcknowledgementMessage",
+            GameMessage::GameActionFightActivateGlyphTrapMessage(_) => "GameActionFightActivateGlyphTrapMessage",
+            GameMessage::GameActionFightCarryCharacterMessage(_) => "GameActionFightCarryCharacterMessage",
+            GameMessage::GameActionFightCastOnTargetRequestMessage(_) => "GameActionFightCastOnTargetRequestMessage",
+            GameMessage::GameActionFightCastRequestMessage(_) => "GameActionFightCastRequestMessage",
+            GameMessage::GameActionFightChangeLookMessage(_) => "GameActionFightChangeLookMessage",
+            GameMessage::GameActionFightCloseCombatMessage(_) => "GameActionFightCloseCombatMessage",
+            GameMessage::GameActionFightDeathMessage(_) => "GameActionFightDeathMessage",
+            GameMessage::GameActionFightDispellEffectMessage(_) => "GameActionFightDispellEffectMessage",
+            GameMessage::GameActionFightDispellMessage(_) => "GameActionFightDispellMessage",
+            GameMessage::GameActionFightDispellSpellMessage(_) => "GameActionFightDispellSpellMessage",
+            GameMessage::GameActionFightDispellableEffectMessage(_) => "GameActionFightDispellableEffectMessage",
+            GameMessage::GameActionFightDodgePointLossMessage(_) => "GameActionFightDodgePointLossMessage",
+            GameMessage::GameActionFightDropCharacterMessage(_) => "GameActionFightDropCharacterMessage",
+            GameMessage::GameActionFightExchangePositionsMessage(_) => "GameActionFightExchangePositionsMessage",
+            GameMessage::GameActionFightInvisibilityMessage(_) => "GameActionFightInvisibilityMessage",
+            GameMessage::GameActionFightInvisibleDetectedMessage(_) => "GameActionFightInvisibleDetectedMessage",
+            GameMessage::GameActionFightKillMessage(_) => "GameActionFightKillMessage",
+            GameMessage::GameActionFightLifeAndShieldPointsLostMessage(_) => "GameActionFightLifeAndShieldPointsLostMessage",
+            GameMessage::GameActionFightLifePointsGainMessage(_) => "GameActionFightLifePointsGainMessage",
+            GameMessage::GameActionFightLifePointsLostMessage(_) => "GameActionFightLifePointsLostMessage",
+            GameMessage::GameActionFightMarkCellsMessage(_) => "GameActionFightMarkCellsMessage",
+            GameMessage::GameActionFightModifyEffectsDurationMessage(_) => "GameActionFightModifyEffectsDurationMessage",
+            GameMessage::GameActionFightMultipleSummonMessage(_) => "GameActionFightMultipleSummonMessage",
+            GameMessage::GameActionFightNoSpellCastMessage(_) => "GameActionFightNoSpellCastMessage",
+            GameMessage::GameActionFightPointsVariationMessage(_) => "GameActionFightPointsVariationMessage",
+            GameMessage::GameActionFightReduceDamagesMessage(_) => "GameActionFightReduceDamagesMessage",
+            GameMessage::GameActionFightReflectDamagesMessage(_) => "GameActionFightReflectDamagesMessage",
+            GameMessage::GameActionFightReflectSpellMessage(_) => "GameActionFightReflectSpellMessage",
+            GameMessage::GameActionFightSlideMessage(_) => "GameActionFightSlideMessage",
+            GameMessage::GameActionFightSpellCastMessage(_) => "GameActionFightSpellCastMessage",
+            GameMessage::GameActionFightSpellCooldownVariationMessage(_) => "GameActionFightSpellCooldownVariationMessage",
+            GameMessage::GameActionFightSpellImmunityMessage(_) => "GameActionFightSpellImmunityMessage",
+            GameMessage::GameActionFightStealKamaMessage(_) => "GameActionFightStealKamaMessage",
+            GameMessage::GameActionFightSummonMessage(_) => "GameActionFightSummonMessage",
+            GameMessage::GameActionFightTackledMessage(_) => "GameActionFightTackledMessage",
+            GameMessage::GameActionFightTeleportOnSameMapMessage(_) => "GameActionFightTeleportOnSameMapMessage",
+            GameMessage::GameActionFightThrowCharacterMessage(_) => "GameActionFightThrowCharacterMessage",
+            GameMessage::GameActionFightTriggerEffectMessage(_) => "GameActionFightTriggerEffectMessage",
+            GameMessage::GameActionFightTriggerGlyphTrapMessage(_) => "GameActionFightTriggerGlyphTrapMessage",
+            GameMessage::GameActionFightUnmarkCellsMessage(_) => "GameActionFightUnmarkCellsMessage",
+            GameMessage::GameActionFightVanishMessage(_) => "GameActionFightVanishMessage",
+            GameMessage::GameActionItemAddMessage(_) => "GameActionItemAddMessage",
+            GameMessage::GameActionItemConsumedMessage(_) => "GameActionItemConsumedMessage",
+            GameMessage::GameActionItemListMessage(_) => "GameActionItemListMessage",
+            GameMessage::GameActionNoopMessage(_) => "GameActionNoopMessage",
+            GameMessage::GameActionSpamMessage(_) => "GameActionSpamMessage",
+            GameMessage::GameActionUpdateEffectTriggerCountMessage(_) => "GameActionUpdateEffectTriggerCountMessage",
+            GameMessage::GameCautiousMapMovementMessage(_) => "GameCautiousMapMovementMessage",
+            GameMessage::GameCautiousMapMovementRequestMessage(_) => "GameCautiousMapMovementRequestMessage",
+            GameMessage::GameContextCreateErrorMessage(_) => "GameContextCreateErrorMessage",
+            GameMessage::GameContextCreateMessage(_) => "GameContextCreateMessage",
+            GameMessage::GameContextCreateRequestMessage(_) => "GameContextCreateRequestMessage",
+            GameMessage::GameContextDestroyMessage(_) => "GameContextDestroyMessage",
+            GameMessage::GameContextKickMessage(_) => "GameContextKickMessage",
+            GameMessage::GameContextMoveElementMessage(_) => "GameContextMoveElementMessage",
+            GameMessage::GameContextMoveMultipleElementsMessage(_) => "GameContextMoveMultipleElementsMessage",
+            GameMessage::GameContextQuitMessage(_) => "GameContextQuitMessage",
+            GameMessage::GameContextReadyMessage(_) => "GameContextReadyMessage",
+            GameMessage::GameContextRefreshEntityLookMessage(_) => "GameContextRefreshEntityLookMessage",
+            GameMessage::GameContextRemoveElementMessage(_) => "GameContextRemoveElementMessage",
+            GameMessage::GameContextRemoveElementWithEventMessage(_) => "GameContextRemoveElementWithEventMessage",
+            GameMessage::GameContextRemoveMultipleElementsMessage(_) => "GameContextRemoveMultipleElementsMessage",
+            GameMessage::GameContextRemoveMultipleElementsWithEventsMessage(_) => "GameContextRemoveMultipleElementsWithEventsMessage",
+            GameMessage::GameDataPaddockObjectAddMessage(_) => "GameDataPaddockObjectAddMessage",
+            GameMessage::GameDataPaddockObjectListAddMessage(_) => "GameDataPaddockObjectListAddMessage",
+            GameMessage::GameDataPaddockObjectRemoveMessage(_) => "GameDataPaddockObjectRemoveMessage",
+            GameMessage::GameDataPlayFarmObjectAnimationMessage(_) => "GameDataPlayFarmObjectAnimationMessage",
+            GameMessage::GameEntitiesDispositionMessage(_) => "GameEntitiesDispositionMessage",
+            GameMessage::GameEntityDispositionErrorMessage(_) => "GameEntityDispositionErrorMessage",
+            GameMessage::GameEntityDispositionMessage(_) => "GameEntityDispositionMessage",
+            GameMessage::GameFightEndMessage(_) => "GameFightEndMessage",
+            GameMessage::GameFightHumanReadyStateMessage(_) => "GameFightHumanReadyStateMessage",
+            GameMessage::GameFightJoinMessage(_) => "GameFightJoinMessage",
+            GameMessage::GameFightJoinRequestMessage(_) => "GameFightJoinRequestMessage",
+            GameMessage::GameFightLeaveMessage(_) => "GameFightLeaveMessage",
+            GameMessage::GameFightNewRoundMessage(_) => "GameFightNewRoundMessage",
+            GameMessage::GameFightNewWaveMessage(_) => "GameFightNewWaveMessage",
+            GameMessage::GameFightOptionStateUpdateMessage(_) => "GameFightOptionStateUpdateMessage",
+            GameMessage::GameFightOptionToggleMessage(_) => "GameFightOptionToggleMessage",
+            GameMessage::GameFightPauseMessage(_) => "GameFightPauseMessage",
+            GameMessage::GameFightPlacementPositionRequestMessage(_) => "GameFightPlacementPositionRequestMessage",
+            GameMessage::GameFightPlacementPossiblePositionsMessage(_) => "GameFightPlacementPossiblePositionsMessage",
+            GameMessage::GameFightPlacementSwapPositionsAcceptMessage(_) => "GameFightPlacementSwapPositionsAcceptMessage",
+            GameMessage::GameFightPlacementSwapPositionsCancelMessage(_) => "GameFightPlacementSwapPositionsCancelMessage",
+            GameMessage::GameFightPlacementSwapPositionsCancelledMessage(_) => "GameFightPlacementSwapPositionsCancelledMessage",
+            GameMessage::GameFightPlacementSwapPositionsErrorMessage(_) => "GameFightPlacementSwapPositionsErrorMessage",
+            GameMessage::GameFightPlacementSwapPositionsMessage(_) => "GameFightPlacementSwapPositionsMessage",
+            GameMessage::GameFightPlacementSwapPositionsOfferMessage(_) => "GameFightPlacementSwapPositionsOfferMessage",
+            GameMessage::GameFightPlacementSwapPositionsRequestMessage(_) => "GameFightPlacementSwapPositionsRequestMessage",
+            GameMessage::GameFightReadyMessage(_) => "GameFightReadyMessage",
+            GameMessage::GameFightRefreshFighterMessage(_) => "GameFightRefreshFighterMessage",
+            GameMessage::GameFightRemoveTeamMemberMessage(_) => "GameFightRemoveTeamMemberMessage",
+            GameMessage::GameFightResumeMessage(_) => "GameFightResumeMessage",
+            GameMessage::GameFightResumeWithSlavesMessage(_) => "GameFightResumeWithSlavesMessage",
+            GameMessage::GameFightShowFighterMessage(_) => "GameFightShowFighterMessage",
+            GameMessage::GameFightShowFighterRandomStaticPoseMessage(_) => "GameFightShowFighterRandomStaticPoseMessage",
+            GameMessage::GameFightSpectateMessage(_) => "GameFightSpectateMessage",
+            GameMessage::GameFightSpectatePlayerRequestMessage(_) => "GameFightSpectatePlayerRequestMessage",
+            GameMessage::GameFightSpectatorJoinMessage(_) => "GameFightSpectatorJoinMessage",
+            GameMessage::GameFightStartMessage(_) => "GameFightStartMessage",
+            GameMessage::GameFightStartingMessage(_) => "GameFightStartingMessage",
+            GameMessage::GameFightSynchronizeMessage(_) => "GameFightSynchronizeMessage",
+            GameMessage::GameFightTurnEndMessage(_) => "GameFightTurnEndMessage",
+            GameMessage::GameFightTurnFinishMessage(_) => "GameFightTurnFinishMessage",
+            GameMessage::GameFightTurnListMessage(_) => "GameFightTurnListMessage",
+            GameMessage::GameFightTurnReadyMessage(_) => "GameFightTurnReadyMessage",
+            GameMessage::GameFightTurnReadyRequestMessage(_) => "GameFightTurnReadyRequestMessage",
+            GameMessage::GameFightTurnResumeMessage(_) => "GameFightTurnResumeMessage",
+            GameMessage::GameFightTurnStartMessage(_) => "GameFightTurnStartMessage",
+            GameMessage::GameFightTurnStartPlayingMessage(_) => "GameFightTurnStartPlayingMessage",
+            GameMessage::GameFightUpdateTeamMessage(_) => "GameFightUpdateTeamMessage",
+            GameMessage::GameMapChangeOrientationMessage(_) => "GameMapChangeOrientationMessage",
+            GameMessage::GameMapChangeOrientationRequestMessage(_) => "GameMapChangeOrientationRequestMessage",
+            GameMessage::GameMapChangeOrientationsMessage(_) => "GameMapChangeOrientationsMessage",
+            GameMessage::GameMapMovementCancelMessage(_) => "GameMapMovementCancelMessage",
+            GameMessage::GameMapMovementConfirmMessage(_) => "GameMapMovementConfirmMessage",
+            GameMessage::GameMapMovementMessage(_) => "GameMapMovementMessage",
+            GameMessage::GameMapMovementRequestMessage(_) => "GameMapMovementRequestMessage",
+            GameMessage::GameMapNoMovementMessage(_) => "GameMapNoMovementMessage",
+            GameMessage::GameRefreshMonsterBoostsMessage(_) => "GameRefreshMonsterBoostsMessage",
+            GameMessage::GameRolePlayAggressionMessage(_) => "GameRolePlayAggressionMessage",
+            GameMessage::GameRolePlayArenaFightAnswerMessage(_) => "GameRolePlayArenaFightAnswerMessage",
+            GameMessage::GameRolePlayArenaFightPropositionMessage(_) => "GameRolePlayArenaFightPropositionMessage",
+            GameMessage::GameRolePlayArenaFighterStatusMessage(_) => "GameRolePlayArenaFighterStatusMessage",
+            GameMessage::GameRolePlayArenaInvitationCandidatesAnswerMessage(_) => "GameRolePlayArenaInvitationCandidatesAnswerMessage",
+            GameMessage::GameRolePlayArenaLeagueRewardsMessage(_) => "GameRolePlayArenaLeagueRewardsMessage",
+            GameMessage::GameRolePlayArenaPlayerBehavioursMessage(_) => "GameRolePlayArenaPlayerBehavioursMessage",
+            GameMessage::GameRolePlayArenaRegisterMessage(_) => "GameRolePlayArenaRegisterMessage",
+            GameMessage::GameRolePlayArenaRegistrationStatusMessage(_) => "GameRolePlayArenaRegistrationStatusMessage",
+            GameMessage::GameRolePlayArenaRegistrationWarningMessage(_) => "GameRolePlayArenaRegistrationWarningMessage",
+            GameMessage::GameRolePlayArenaSwitchToFightServerMessage(_) => "GameRolePlayArenaSwitchToFightServerMessage",
+            GameMessage::GameRolePlayArenaSwitchToGameServerMessage(_) => "GameRolePlayArenaSwitchToGameServerMessage",
+            GameMessage::GameRolePlayArenaUnregisterMessage(_) => "GameRolePlayArenaUnregisterMessage",
+            GameMessage::GameRolePlayArenaUpdatePlayerInfosMessage(_) => "GameRolePlayArenaUpdatePlayerInfosMessage",
+            GameMessage::GameRolePlayAttackMonsterRequestMessage(_) => "GameRolePlayAttackMonsterRequestMessage",
+            GameMessage::GameRolePlayDelayedActionFinishedMessage(_) => "GameRolePlayDelayedActionFinishedMessage",
+            GameMessage::GameRolePlayDelayedActionMessage(_) => "GameRolePlayDelayedActionMessage",
+            GameMessage::GameRolePlayDelayedObjectUseMessage(_) => "GameRolePlayDelayedObjectUseMessage",
+            GameMessage::GameRolePlayFightRequestCanceledMessage(_) => "GameRolePlayFightRequestCanceledMessage",
+            GameMessage::GameRolePlayFreeSoulRequestMessage(_) => "GameRolePlayFreeSoulRequestMessage",
+            GameMessage::GameRolePlayGameOverMessage(_) => "GameRolePlayGameOverMessage",
+            GameMessage::GameRolePlayMonsterAngryAtPlayerMessage(_) => "GameRolePlayMonsterAngryAtPlayerMessage",
+            GameMessage::GameRolePlayMonsterNotAngryAtPlayerMessage(_) => "GameRolePlayMonsterNotAngryAtPlayerMessage",
+            GameMessage::GameRolePlayPlayerFightFriendlyAnswerMessage(_) => "GameRolePlayPlayerFightFriendlyAnswerMessage",
+            GameMessage::GameRolePlayPlayerFightFriendlyAnsweredMessage(_) => "GameRolePlayPlayerFightFriendlyAnsweredMessage",
+            GameMessage::GameRolePlayPlayerFightFriendlyRequestedMessage(_) => "GameRolePlayPlayerFightFriendlyRequestedMessage",
+            GameMessage::GameRolePlayPlayerFightRequestMessage(_) => "GameRolePlayPlayerFightRequestMessage",
+            GameMessage::GameRolePlayPlayerLifeStatusMessage(_) => "GameRolePlayPlayerLifeStatusMessage",
+            GameMessage::GameRolePlayRemoveChallengeMessage(_) => "GameRolePlayRemoveChallengeMessage",
+            GameMessage::GameRolePlayShowActorMessage(_) => "GameRolePlayShowActorMessage",
+            GameMessage::GameRolePlayShowActorWithEventMessage(_) => "GameRolePlayShowActorWithEventMessage",
+            GameMessage::GameRolePlayShowChallengeMessage(_) => "GameRolePlayShowChallengeMessage",
+            GameMessage::GameRolePlayShowMultipleActorsMessage(_) => "GameRolePlayShowMultipleActorsMessage",
+            GameMessage::GameRolePlaySpellAnimMessage(_) => "GameRolePlaySpellAnimMessage",
+            GameMessage::GameRolePlayTaxCollectorFightRequestMessage(_) => "GameRolePlayTaxCollectorFightRequestMessage",
+            GameMessage::GoldAddedMessage(_) => "GoldAddedMessage",
+            GameMessage::GroupTeleportPlayerAnswerMessage(_) => "GroupTeleportPlayerAnswerMessage",
+            GameMessage::GroupTeleportPlayerCloseMessage(_) => "GroupTeleportPlayerCloseMessage",
+            GameMessage::GroupTeleportPlayerOfferMessage(_) => "GroupTeleportPlayerOfferMessage",
+            GameMessage::GuestLimitationMessage(_) => "GuestLimitationMessage",
+            GameMessage::GuestModeMessage(_) => "GuestModeMessage",
+            GameMessage::GuidedModeQuitRequestMessage(_) => "GuidedModeQuitRequestMessage",
+            GameMessage::GuidedModeReturnRequestMessage(_) => "GuidedModeReturnRequestMessage",
+            GameMessage::GuildApplicationAnswerMessage(_) => "GuildApplicationAnswerMessage",
+            GameMessage::GuildApplicationDeletedMessage(_) => "GuildApplicationDeletedMessage",
+            GameMessage::GuildApplicationIsAnsweredMessage(_) => "GuildApplicationIsAnsweredMessage",
+            GameMessage::GuildApplicationListenMessage(_) => "GuildApplicationListenMessage",
+            GameMessage::GuildApplicationPresenceMessage(_) => "GuildApplicationPresenceMessage",
+            GameMessage::GuildApplicationReceivedMessage(_) => "GuildApplicationReceivedMessage",
+            GameMessage::GuildBulletinMessage(_) => "GuildBulletinMessage",
+            GameMessage::GuildBulletinSetErrorMessage(_) => "GuildBulletinSetErrorMessage",
+            GameMessage::GuildBulletinSetRequestMessage(_) => "GuildBulletinSetRequestMessage",
+            GameMessage::GuildChangeMemberParametersMessage(_) => "GuildChangeMemberParametersMessage",
+            GameMessage::GuildCharacsUpgradeRequestMessage(_) => "GuildCharacsUpgradeRequestMessage",
+            GameMessage::GuildChestTabContributionMessage(_) => "GuildChestTabContributionMessage",
+            GameMessage::GuildChestTabContributionsMessage(_) => "GuildChestTabContributionsMessage",
+            GameMessage::GuildChestTabLastContributionMessage(_) => "GuildChestTabLastContributionMessage",
+            GameMessage::GuildCreationResultMessage(_) => "GuildCreationResultMessage",
+            GameMessage::GuildCreationStartedMessage(_) => "GuildCreationStartedMessage",
+            GameMessage::GuildCreationValidMessage(_) => "GuildCreationValidMessage",
+            GameMessage::GuildDeleteApplicationRequestMessage(_) => "GuildDeleteApplicationRequestMessage",
+            GameMessage::GuildFactsErrorMessage(_) => "GuildFactsErrorMessage",
+            GameMessage::GuildFactsMessage(_) => "GuildFactsMessage",
+            GameMessage::GuildFactsRequestMessage(_) => "GuildFactsRequestMessage",
+            GameMessage::GuildGetChestTabContributionsRequestMessage(_) => "GuildGetChestTabContributionsRequestMessage",
+            GameMessage::GuildGetInformationsMessage(_) => "GuildGetInformationsMessage",
+            GameMessage::GuildGetPlayerApplicationMessage(_) => "GuildGetPlayerApplicationMessage",
+            GameMessage::GuildHouseRemoveMessage(_) => "GuildHouseRemoveMessage",
+            GameMessage::GuildHouseUpdateInformationMessage(_) => "GuildHouseUpdateInformationMessage",
+            GameMessage::GuildHousesInformationMessage(_) => "GuildHousesInformationMessage",
+            GameMessage::GuildInformationsGeneralMessage(_) => "GuildInformationsGeneralMessage",
+            GameMessage::GuildInformationsMemberUpdateMessage(_) => "GuildInformationsMemberUpdateMessage",
+            GameMessage::GuildInformationsMembersMessage(_) => "GuildInformationsMembersMessage",
+            GameMessage::GuildInformationsPaddocksMessage(_) => "GuildInformationsPaddocksMessage",
+            GameMessage::GuildInvitationAnswerMessage(_) => "GuildInvitationAnswerMessage",
+            GameMessage::GuildInvitationMessage(_) => "GuildInvitationMessage",
+            GameMessage::GuildInvitationStateRecrutedMessage(_) => "GuildInvitationStateRecrutedMessage",
+            GameMessage::GuildInvitationStateRecruterMessage(_) => "GuildInvitationStateRecruterMessage",
+            GameMessage::GuildInvitedMessage(_) => "GuildInvitedMessage",
+            GameMessage::GuildIsThereAnyApplicationMessage(_) => "GuildIsThereAnyApplicationMessage",
+            GameMessage::GuildJoinAutomaticallyRequestMessage(_) => "GuildJoinAutomaticallyRequestMessage",
+            GameMessage::GuildJoinedMessage(_) => "GuildJoinedMessage",
+            GameMessage::GuildKickRequestMessage(_) => "GuildKickRequestMessage",
+            GameMessage::GuildLeftMessage(_) => "GuildLeftMessage",
+            GameMessage::GuildLevelUpMessage(_) => "GuildLevelUpMessage",
+            GameMessage::GuildListApplicationAnswerMessage(_) => "GuildListApplicationAnswerMessage",
+            GameMessage::GuildListApplicationModifiedMessage(_) => "GuildListApplicationModifiedMessage",
+            GameMessage::GuildListApplicationRequestMessage(_) => "GuildListApplicationRequestMessage",
+            GameMessage::GuildListMessage(_) => "GuildListMessage",
+            GameMessage::GuildLogbookInformationMessage(_) => "GuildLogbookInformationMessage",
+            GameMessage::GuildLogbookInformationRequestMessage(_) => "GuildLogbookInformationRequestMessage",
+            GameMessage::GuildMemberLeavingMessage(_) => "GuildMemberLeavingMessage",
+            GameMessage::GuildMemberOnlineStatusMessage(_) => "GuildMemberOnlineStatusMessage",
+            GameMessage::GuildMemberStartWarnOnConnectionMessage(_) => "GuildMemberStartWarnOnConnectionMessage",
+            GameMessage::GuildMemberStopWarnOnConnectionMessage(_) => "GuildMemberStopWarnOnConnectionMessage",
+            GameMessage::GuildMembershipMessage(_) => "GuildMembershipMessage",
+            GameMessage::GuildModificationEmblemValidMessage(_) => "GuildModificationEmblemValidMessage",
+            GameMessage::GuildModificationNameValidMessage(_) => "GuildModificationNameValidMessage",
+            GameMessage::GuildModificationResultMessage(_) => "GuildModificationResultMessage",
+            GameMessage::GuildModificationStartedMessage(_) => "GuildModificationStartedMessage",
+            GameMessage::GuildModificationValidMessage(_) => "GuildModificationValidMessage",
+            GameMessage::GuildMotdMessage(_) => "GuildMotdMessage",
+            GameMessage::GuildMotdSetErrorMessage(_) => "GuildMotdSetErrorMessage",
+            GameMessage::GuildMotdSetRequestMessage(_) => "GuildMotdSetRequestMessage",
+            GameMessage::GuildPaddockBoughtMessage(_) => "GuildPaddockBoughtMessage",
+            GameMessage::GuildPaddockRemovedMessage(_) => "GuildPaddockRemovedMessage",
+            GameMessage::GuildPaddockTeleportRequestMessage(_) => "GuildPaddockTeleportRequestMessage",
+            GameMessage::GuildPlayerApplicationAbstractMessage(_) => "GuildPlayerApplicationAbstractMessage",
+            GameMessage::GuildPlayerApplicationInformationMessage(_) => "GuildPlayerApplicationInformationMessage",
+            GameMessage::GuildPlayerNoApplicationInformationMessage(_) => "GuildPlayerNoApplicationInformationMessage",
+            GameMessage::GuildRanksMessage(_) => "GuildRanksMessage",
+            GameMessage::GuildRanksRequestMessage(_) => "GuildRanksRequestMessage",
+            GameMessage::GuildRecruitmentInvalidateMessage(_) => "GuildRecruitmentInvalidateMessage",
+            GameMessage::GuildSelectChestTabRequestMessage(_) => "GuildSelectChestTabRequestMessage",
+            GameMessage::GuildSpellUpgradeRequestMessage(_) => "GuildSpellUpgradeRequestMessage",
+            GameMessage::GuildSubmitApplicationMessage(_) => "GuildSubmitApplicationMessage",
+            GameMessage::GuildSummaryMessage(_) => "GuildSummaryMessage",
+            GameMessage::GuildSummaryRequestMessage(_) => "GuildSummaryRequestMessage",
+            GameMessage::GuildUpdateApplicationMessage(_) => "GuildUpdateApplicationMessage",
+            GameMessage::GuildUpdateChestTabRequestMessage(_) => "GuildUpdateChestTabRequestMessage",
+            GameMessage::GuildUpdateNoteMessage(_) => "GuildUpdateNoteMessage",
+            GameMessage::HaapiApiKeyMessage(_) => "HaapiApiKeyMessage",
+            GameMessage::HaapiApiKeyRequestMessage(_) => "HaapiApiKeyRequestMessage",
+            GameMessage::HaapiAuthErrorMessage(_) => "HaapiAuthErrorMessage",
+            GameMessage::HaapiBufferListMessage(_) => "HaapiBufferListMessage",
+            GameMessage::HaapiBufferListRequestMessage(_) => "HaapiBufferListRequestMessage",
+            GameMessage::HaapiBuyValidationMessage(_) => "HaapiBuyValidationMessage",
+            GameMessage::HaapiCancelBidRequestMessage(_) => "HaapiCancelBidRequestMessage",
+            GameMessage::HaapiConfirmationMessage(_) => "HaapiConfirmationMessage",
+            GameMessage::HaapiConfirmationRequestMessage(_) => "HaapiConfirmationRequestMessage",
+            GameMessage::HaapiConsumeBufferRequestMessage(_) => "HaapiConsumeBufferRequestMessage",
+            GameMessage::HaapiSessionMessage(_) => "HaapiSessionMessage",
+            GameMessage::HaapiShopApiKeyMessage(_) => "HaapiShopApiKeyMessage",
+            GameMessage::HaapiShopApiKeyRequestMessage(_) => "HaapiShopApiKeyRequestMessage",
+            GameMessage::HaapiTokenMessage(_) => "HaapiTokenMessage",
+            GameMessage::HaapiTokenRequestMessage(_) => "HaapiTokenRequestMessage",
+            GameMessage::HaapiValidationMessage(_) => "HaapiValidationMessage",
+            GameMessage::HaapiValidationRequestMessage(_) => "HaapiValidationRequestMessage",
+            GameMessage::HavenBagDailyLoteryMessage(_) => "HavenBagDailyLoteryMessage",
+            GameMessage::HavenBagFurnituresMessage(_) => "HavenBagFurnituresMessage",
+            GameMessage::HavenBagFurnituresRequestMessage(_) => "HavenBagFurnituresRequestMessage",
+            GameMessage::HavenBagPackListMessage(_) => "HavenBagPackListMessage",
+            GameMessage::HavenBagPermissionsUpdateMessage(_) => "HavenBagPermissionsUpdateMessage",
+            GameMessage::HavenBagPermissionsUpdateRequestMessage(_) => "HavenBagPermissionsUpdateRequestMessage",
+            GameMessage::HavenBagRoomUpdateMessage(_) => "HavenBagRoomUpdateMessage",
+            GameMessage::HelloConnectMessage(_) => "HelloConnectMessage",
+            GameMessage::HelloGameMessage(_) => "HelloGameMessage",
+            GameMessage::HouseBuyRequestMessage(_) => "HouseBuyRequestMessage",
+            GameMessage::HouseBuyResultMessage(_) => "HouseBuyResultMessage",
+            GameMessage::HouseGuildNoneMessage(_) => "HouseGuildNoneMessage",
+            GameMessage::HouseGuildRightsMessage(_) => "HouseGuildRightsMessage",
+            GameMessage::HouseGuildRightsViewMessage(_) => "HouseGuildRightsViewMessage",
+            GameMessage::HouseGuildShareRequestMessage(_) => "HouseGuildShareRequestMessage",
+            GameMessage::HouseKickRequestMessage(_) => "HouseKickRequestMessage",
+            GameMessage::HouseLockFromInsideRequestMessage(_) => "HouseLockFromInsideRequestMessage",
+            GameMessage::HousePropertiesMessage(_) => "HousePropertiesMessage",
+            GameMessage::HouseSellFromInsideRequestMessage(_) => "HouseSellFromInsideRequestMessage",
+            GameMessage::HouseSellRequestMessage(_) => "HouseSellRequestMessage",
+            GameMessage::HouseSellingUpdateMessage(_) => "HouseSellingUpdateMessage",
+            GameMessage::HouseTeleportRequestMessage(_) => "HouseTeleportRequestMessage",
+            GameMessage::HouseToSellFilterMessage(_) => "HouseToSellFilterMessage",
+            GameMessage::HouseToSellListMessage(_) => "HouseToSellListMessage",
+            GameMessage::HouseToSellListRequestMessage(_) => "HouseToSellListRequestMessage",
+            GameMessage::IconNamedPresetSaveRequestMessage(_) => "IconNamedPresetSaveRequestMessage",
+            GameMessage::IconPresetSaveRequestMessage(_) => "IconPresetSaveRequestMessage",
+            GameMessage::IdentificationFailedBannedMessage(_) => "IdentificationFailedBannedMessage",
+            GameMessage::IdentificationFailedForBadVersionMessage(_) => "IdentificationFailedForBadVersionMessage",
+            GameMessage::IdentificationFailedMessage(_) => "IdentificationFailedMessage",
+            GameMessage::IdentificationMessage(_) => "IdentificationMessage",
+            GameMessage::IdentificationSuccessMessage(_) => "IdentificationSuccessMessage",
+            GameMessage::IdentificationSuccessWithLoginTokenMessage(_) => "IdentificationSuccessWithLoginTokenMessage",
+            GameMessage::IgnoredAddFailureMessage(_) => "IgnoredAddFailureMessage",
+            GameMessage::IgnoredAddRequestMessage(_) => "IgnoredAddRequestMessage",
+            GameMessage::IgnoredAddedMessage(_) => "IgnoredAddedMessage",
+            GameMessage::IgnoredDeleteRequestMessage(_) => "IgnoredDeleteRequestMessage",
+            GameMessage::IgnoredDeleteResultMessage(_) => "IgnoredDeleteResultMessage",
+            GameMessage::IgnoredGetListMessage(_) => "IgnoredGetListMessage",
+            GameMessage::IgnoredListMessage(_) => "IgnoredListMessage",
+            GameMessage::InteractiveElementUpdatedMessage(_) => "InteractiveElementUpdatedMessage",
+            GameMessage::InteractiveMapUpdateMessage(_) => "InteractiveMapUpdateMessage",
+            GameMessage::InteractiveUseEndedMessage(_) => "InteractiveUseEndedMessage",
+            GameMessage::InteractiveUseErrorMessage(_) => "InteractiveUseErrorMessage",
+            GameMessage::InteractiveUseRequestMessage(_) => "InteractiveUseRequestMessage",
+            GameMessage::InteractiveUseWithParamRequestMessage(_) => "InteractiveUseWithParamRequestMessage",
+            GameMessage::InteractiveUsedMessage(_) => "InteractiveUsedMessage",
+            GameMessage::InvalidPresetsMessage(_) => "InvalidPresetsMessage",
+            GameMessage::InventoryContentMessage(_) => "InventoryContentMessage",
+            GameMessage::InventoryWeightMessage(_) => "InventoryWeightMessage",
+            GameMessage::InviteInHavenBagClosedMessage(_) => "InviteInHavenBagClosedMessage",
+            GameMessage::InviteInHavenBagMessage(_) => "InviteInHavenBagMessage",
+            GameMessage::InviteInHavenBagOfferMessage(_) => "InviteInHavenBagOfferMessage",
+            GameMessage::ItemForPresetUpdateMessage(_) => "ItemForPresetUpdateMessage",
+            GameMessage::ItemNoMoreAvailableMessage(_) => "ItemNoMoreAvailableMessage",
+            GameMessage::JobAllowMultiCraftRequestMessage(_) => "JobAllowMultiCraftRequestMessage",
+            GameMessage::JobBookSubscribeRequestMessage(_) => "JobBookSubscribeRequestMessage",
+            GameMessage::JobBookSubscriptionMessage(_) => "JobBookSubscriptionMessage",
+            GameMessage::JobCrafterDirectoryAddMessage(_) => "JobCrafterDirectoryAddMessage",
+            GameMessage::JobCrafterDirectoryDefineSettingsMessage(_) => "JobCrafterDirectoryDefineSettingsMessage",
+            GameMessage::JobCrafterDirectoryEntryMessage(_) => "JobCrafterDirectoryEntryMessage",
+            GameMessage::JobCrafterDirectoryEntryRequestMessage(_) => "JobCrafterDirectoryEntryRequestMessage",
+            GameMessage::JobCrafterDirectoryListMessage(_) => "JobCrafterDirectoryListMessage",
+            GameMessage::JobCrafterDirectoryListRequestMessage(_) => "JobCrafterDirectoryListRequestMessage",
+            GameMessage::JobCrafterDirectoryRemoveMessage(_) => "JobCrafterDirectoryRemoveMessage",
+            GameMessage::JobCrafterDirectorySettingsMessage(_) => "JobCrafterDirectorySettingsMessage",
+            GameMessage::JobDescriptionMessage(_) => "JobDescriptionMessage",
+            GameMessage::JobExperienceMultiUpdateMessage(_) => "JobExperienceMultiUpdateMessage",
+            GameMessage::JobExperienceOtherPlayerUpdateMessage(_) => "JobExperienceOtherPlayerUpdateMessage",
+            GameMessage::JobExperienceUpdateMessage(_) => "JobExperienceUpdateMessage",
+            GameMessage::JobLevelUpMessage(_) => "JobLevelUpMessage",
+            GameMessage::JobMultiCraftAvailableSkillsMessage(_) => "JobMultiCraftAvailableSkillsMessage",
+            GameMessage::KamasUpdateMessage(_) => "KamasUpdateMessage",
+            GameMessage::KickHavenBagRequestMessage(_) => "KickHavenBagRequestMessage",
+            GameMessage::KnownZaapListMessage(_) => "KnownZaapListMessage",
+            GameMessage::KohUpdateMessage(_) => "KohUpdateMessage",
+            GameMessage::KothEndMessage(_) => "KothEndMessage",
+            GameMessage::LeaveDialogMessage(_) => "LeaveDialogMessage",
+            GameMessage::LeaveDialogRequestMessage(_) => "LeaveDialogRequestMessage",
+            GameMessage::LifePointsRegenBeginMessage(_) => "LifePointsRegenBeginMessage",
+            GameMessage::LifePointsRegenEndMessage(_) => "LifePointsRegenEndMessage",
+            GameMessage::ListMapNpcsQuestStatusUpdateMessage(_) => "ListMapNpcsQuestStatusUpdateMessage",
+            GameMessage::ListenersOfSynchronizedStorageMessage(_) => "ListenersOfSynchronizedStorageMessage",
+            GameMessage::LivingObjectChangeSkinRequestMessage(_) => "LivingObjectChangeSkinRequestMessage",
+            GameMessage::LivingObjectDissociateMessage(_) => "LivingObjectDissociateMessage",
+            GameMessage::LivingObjectMessageMessage(_) => "LivingObjectMessageMessage",
+            GameMessage::LivingObjectMessageRequestMessage(_) => "LivingObjectMessageRequestMessage",
+            GameMessage::LocalizedChatSmileyMessage(_) => "LocalizedChatSmileyMessage",
+            GameMessage::LockableChangeCodeMessage(_) => "LockableChangeCodeMessage",
+            GameMessage::LockableCodeResultMessage(_) => "LockableCodeResultMessage",
+            GameMessage::LockableShowCodeDialogMessage(_) => "LockableShowCodeDialogMessage",
+            GameMessage::LockableStateUpdateAbstractMessage(_) => "LockableStateUpdateAbstractMessage",
+            GameMessage::LockableStateUpdateHouseDoorMessage(_) => "LockableStateUpdateHouseDoorMessage",
+            GameMessage::LockableStateUpdateStorageMessage(_) => "LockableStateUpdateStorageMessage",
+            GameMessage::LockableUseCodeMessage(_) => "LockableUseCodeMessage",
+            GameMessage::LoginQueueStatusMessage(_) => "LoginQueueStatusMessage",
+            GameMessage::MapComplementaryInformationsAnomalyMessage(_) => "MapComplementaryInformationsAnomalyMessage",
+            GameMessage::MapComplementaryInformationsBreachMessage(_) => "MapComplementaryInformationsBreachMessage",
+            GameMessage::MapComplementaryInformationsDataInHavenBagMessage(_) => "MapComplementaryInformationsDataInHavenBagMessage",
+            GameMessage::MapComplementaryInformationsDataInHouseMessage(_) => "MapComplementaryInformationsDataInHouseMessage",
+            GameMessage::MapComplementaryInformationsDataMessage(_) => "MapComplementaryInformationsDataMessage",
+            GameMessage::MapComplementaryInformationsWithCoordsMessage(_) => "MapComplementaryInformationsWithCoordsMessage",
+            GameMessage::MapFightCountMessage(_) => "MapFightCountMessage",
+            GameMessage::MapFightStartPositionsUpdateMessage(_) => "MapFightStartPositionsUpdateMessage",
+            GameMessage::MapInformationsRequestMessage(_) => "MapInformationsRequestMessage",
+            GameMessage::MapObstacleUpdateMessage(_) => "MapObstacleUpdateMessage",
+            GameMessage::MapRunningFightDetailsExtendedMessage(_) => "MapRunningFightDetailsExtendedMessage",
+            GameMessage::MapRunningFightDetailsMessage(_) => "MapRunningFightDetailsMessage",
+            GameMessage::MapRunningFightDetailsRequestMessage(_) => "MapRunningFightDetailsRequestMessage",
+            GameMessage::MapRunningFightListMessage(_) => "MapRunningFightListMessage",
+            GameMessage::MapRunningFightListRequestMessage(_) => "MapRunningFightListRequestMessage",
+            GameMessage::MigratedServerListMessage(_) => "MigratedServerListMessage",
+            GameMessage::MimicryObjectAssociatedMessage(_) => "MimicryObjectAssociatedMessage",
+            GameMessage::MimicryObjectEraseRequestMessage(_) => "MimicryObjectEraseRequestMessage",
+            GameMessage::MimicryObjectErrorMessage(_) => "MimicryObjectErrorMessage",
+            GameMessage::MimicryObjectFeedAndAssociateRequestMessage(_) => "MimicryObjectFeedAndAssociateRequestMessage",
+            GameMessage::MimicryObjectPreviewMessage(_) => "MimicryObjectPreviewMessage",
+            GameMessage::MoodSmileyRequestMessage(_) => "MoodSmileyRequestMessage",
+            GameMessage::MoodSmileyResultMessage(_) => "MoodSmileyResultMessage",
+            GameMessage::MoodSmileyUpdateMessage(_) => "MoodSmileyUpdateMessage",
+            GameMessage::MountDataErrorMessage(_) => "MountDataErrorMessage",
+            GameMessage::MountDataMessage(_) => "MountDataMessage",
+            GameMessage::MountEmoteIconUsedOkMessage(_) => "MountEmoteIconUsedOkMessage",
+            GameMessage::MountEquipedErrorMessage(_) => "MountEquipedErrorMessage",
+            GameMessage::MountFeedRequestMessage(_) => "MountFeedRequestMessage",
+            GameMessage::MountHarnessColorsUpdateRequestMessage(_) => "MountHarnessColorsUpdateRequestMessage",
+            GameMessage::MountHarnessDissociateRequestMessage(_) => "MountHarnessDissociateRequestMessage",
+            GameMessage::MountInformationInPaddockRequestMessage(_) => "MountInformationInPaddockRequestMessage",
+            GameMessage::MountInformationRequestMessage(_) => "MountInformationRequestMessage",
+            GameMessage::MountReleaseRequestMessage(_) => "MountReleaseRequestMessage",
+            GameMessage::MountReleasedMessage(_) => "MountReleasedMessage",
+            GameMessage::MountRenameRequestMessage(_) => "MountRenameRequestMessage",
+            GameMessage::MountRenamedMessage(_) => "MountRenamedMessage",
+            GameMessage::MountRidingMessage(_) => "MountRidingMessage",
+            GameMessage::MountSetMessage(_) => "MountSetMessage",
+            GameMessage::MountSetXpRatioRequestMessage(_) => "MountSetXpRatioRequestMessage",
+            GameMessage::MountSterilizeRequestMessage(_) => "MountSterilizeRequestMessage",
+            GameMessage::MountSterilizedMessage(_) => "MountSterilizedMessage",
+            GameMessage::MountToggleRidingRequestMessage(_) => "MountToggleRidingRequestMessage",
+            GameMessage::MountUnSetMessage(_) => "MountUnSetMessage",
+            GameMessage::MountXpRatioMessage(_) => "MountXpRatioMessage",
+            GameMessage::MoveTaxCollectorOrderedSpellMessage(_) => "MoveTaxCollectorOrderedSpellMessage",
+            GameMessage::MoveTaxCollectorPresetSpellMessage(_) => "MoveTaxCollectorPresetSpellMessage",
+            GameMessage::MultiTabStorageMessage(_) => "MultiTabStorageMessage",
+            GameMessage::NetworkDataContainerMessage(_) => "NetworkDataContainerMessage",
+            GameMessage::NicknameAcceptedMessage(_) => "NicknameAcceptedMessage",
+            GameMessage::NicknameChoiceRequestMessage(_) => "NicknameChoiceRequestMessage",
+            GameMessage::NicknameRefusedMessage(_) => "NicknameRefusedMessage",
+            GameMessage::NicknameRegistrationMessage(_) => "NicknameRegistrationMessage",
+            GameMessage::NotificationByServerMessage(_) => "NotificationByServerMessage",
+            GameMessage::NotificationListMessage(_) => "NotificationListMessage",
+            GameMessage::NotificationResetMessage(_) => "NotificationResetMessage",
+            GameMessage::NotificationUpdateFlagMessage(_) => "NotificationUpdateFlagMessage",
+            GameMessage::NpcDialogCreationMessage(_) => "NpcDialogCreationMessage",
+            GameMessage::NpcDialogQuestionMessage(_) => "NpcDialogQuestionMessage",
+            GameMessage::NpcDialogReplyMessage(_) => "NpcDialogReplyMessage",
+            GameMessage::NpcGenericActionFailureMessage(_) => "NpcGenericActionFailureMessage",
+            GameMessage::NpcGenericActionRequestMessage(_) => "NpcGenericActionRequestMessage",
+            GameMessage::NuggetsDistributionMessage(_) => "NuggetsDistributionMessage",
+            GameMessage::NuggetsInformationMessage(_) => "NuggetsInformationMessage",
+            GameMessage::NumericWhoIsMessage(_) => "NumericWhoIsMessage",
+            GameMessage::NumericWhoIsRequestMessage(_) => "NumericWhoIsRequestMessage",
+            GameMessage::ObjectAddedMessage(_) => "ObjectAddedMessage",
+            GameMessage::ObjectAveragePricesErrorMessage(_) => "ObjectAveragePricesErrorMessage",
+            GameMessage::ObjectAveragePricesGetMessage(_) => "ObjectAveragePricesGetMessage",
+            GameMessage::ObjectAveragePricesMessage(_) => "ObjectAveragePricesMessage",
+            GameMessage::ObjectDeleteMessage(_) => "ObjectDeleteMessage",
+            GameMessage::ObjectDeletedMessage(_) => "ObjectDeletedMessage",
+            GameMessage::ObjectDropMessage(_) => "ObjectDropMessage",
+            GameMessage::ObjectErrorMessage(_) => "ObjectErrorMessage",
+            GameMessage::ObjectFeedMessage(_) => "ObjectFeedMessage",
+            GameMessage::ObjectGroundAddedMessage(_) => "ObjectGroundAddedMessage",
+            GameMessage::ObjectGroundListAddedMessage(_) => "ObjectGroundListAddedMessage",
+            GameMessage::ObjectGroundRemovedMessage(_) => "ObjectGroundRemovedMessage",
+            GameMessage::ObjectGroundRemovedMultipleMessage(_) => "ObjectGroundRemovedMultipleMessage",
+            GameMessage::ObjectJobAddedMessage(_) => "ObjectJobAddedMessage",
+            GameMessage::ObjectModifiedMessage(_) => "ObjectModifiedMessage",
+            GameMessage::ObjectMovementMessage(_) => "ObjectMovementMessage",
+            GameMessage::ObjectQuantityMessage(_) => "ObjectQuantityMessage",
+            GameMessage::ObjectSetPositionMessage(_) => "ObjectSetPositionMessage",
+            GameMessage::ObjectUseMessage(_) => "ObjectUseMessage",
+            GameMessage::ObjectUseMultipleMessage(_) => "ObjectUseMultipleMessage",
+            GameMessage::ObjectUseOnCellMessage(_) => "ObjectUseOnCellMessage",
+            GameMessage::ObjectUseOnCharacterMessage(_) => "ObjectUseOnCharacterMessage",
+            GameMessage::ObjectsAddedMessage(_) => "ObjectsAddedMessage",
+            GameMessage::ObjectsDeletedMessage(_) => "ObjectsDeletedMessage",
+            GameMessage::ObjectsQuantityMessage(_) => "ObjectsQuantityMessage",
+            GameMessage::ObtainedItemMessage(_) => "ObtainedItemMessage",
+            GameMessage::ObtainedItemWithBonusMessage(_) => "ObtainedItemWithBonusMessage",
+            GameMessage::OnConnectionEventMessage(_) => "OnConnectionEventMessage",
+            GameMessage::OpenGuideBookMessage(_) => "OpenGuideBookMessage",
+            GameMessage::OpenHavenBagFurnitureSequenceRequestMessage(_) => "OpenHavenBagFurnitureSequenceRequestMessage",
+            GameMessage::OpponentSurrenderMessage(_) => "OpponentSurrenderMessage",
+            GameMessage::OrnamentGainedMessage(_) => "OrnamentGainedMessage",
+            GameMessage::OrnamentLostMessage(_) => "OrnamentLostMessage",
+            GameMessage::OrnamentSelectErrorMessage(_) => "OrnamentSelectErrorMessage",
+            GameMessage::OrnamentSelectRequestMessage(_) => "OrnamentSelectRequestMessage",
+            GameMessage::OrnamentSelectedMessage(_) => "OrnamentSelectedMessage",
+            GameMessage::PaddockBuyRequestMessage(_) => "PaddockBuyRequestMessage",
+            GameMessage::PaddockBuyResultMessage(_) => "PaddockBuyResultMessage",
+            GameMessage::PaddockMoveItemRequestMessage(_) => "PaddockMoveItemRequestMessage",
+            GameMessage::PaddockPropertiesMessage(_) => "PaddockPropertiesMessage",
+            GameMessage::PaddockRemoveItemRequestMessage(_) => "PaddockRemoveItemRequestMessage",
+            GameMessage::PaddockSellBuyDialogMessage(_) => "PaddockSellBuyDialogMessage",
+            GameMessage::PaddockSellRequestMessage(_) => "PaddockSellRequestMessage",
+            GameMessage::PaddockToSellFilterMessage(_) => "PaddockToSellFilterMessage",
+            GameMessage::PaddockToSellListMessage(_) => "PaddockToSellListMessage",
+            GameMessage::PaddockToSellListRequestMessage(_) => "PaddockToSellListRequestMessage",
+            GameMessage::PaginationAnswerAbstractMessage(_) => "PaginationAnswerAbstractMessage",
+            GameMessage::PaginationRequestAbstractMessage(_) => "PaginationRequestAbstractMessage",
+            GameMessage::PartyAbdicateThroneMessage(_) => "PartyAbdicateThroneMessage",
+            GameMessage::PartyAcceptInvitationMessage(_) => "PartyAcceptInvitationMessage",
+            GameMessage::PartyCancelInvitationMessage(_) => "PartyCancelInvitationMessage",
+            GameMessage::PartyCancelInvitationNotificationMessage(_) => "PartyCancelInvitationNotificationMessage",
+            GameMessage::PartyCannotJoinErrorMessage(_) => "PartyCannotJoinErrorMessage",
+            GameMessage::PartyDeletedMessage(_) => "PartyDeletedMessage",
+            GameMessage::PartyEntityUpdateLightMessage(_) => "PartyEntityUpdateLightMessage",
+            GameMessage::PartyFollowMemberRequestMessage(_) => "PartyFollowMemberRequestMessage",
+            GameMessage::PartyFollowStatusUpdateMessage(_) => "PartyFollowStatusUpdateMessage",
+            GameMessage::PartyFollowThisMemberRequestMessage(_) => "PartyFollowThisMemberRequestMessage",
+            GameMessage::PartyInvitationArenaRequestMessage(_) => "PartyInvitationArenaRequestMessage",
+            GameMessage::PartyInvitationCancelledForGuestMessage(_) => "PartyInvitationCancelledForGuestMessage",
+            GameMessage::PartyInvitationDetailsMessage(_) => "PartyInvitationDetailsMessage",
+            GameMessage::PartyInvitationDetailsRequestMessage(_) => "PartyInvitationDetailsRequestMessage",
+            GameMessage::PartyInvitationDungeonDetailsMessage(_) => "PartyInvitationDungeonDetailsMessage",
+            GameMessage::PartyInvitationDungeonMessage(_) => "PartyInvitationDungeonMessage",
+            GameMessage::PartyInvitationDungeonRequestMessage(_) => "PartyInvitationDungeonRequestMessage",
+            GameMessage::PartyInvitationMessage(_) => "PartyInvitationMessage",
+            GameMessage::PartyInvitationRequestMessage(_) => "PartyInvitationRequestMessage",
+            GameMessage::PartyJoinMessage(_) => "PartyJoinMessage",
+            GameMessage::PartyKickRequestMessage(_) => "PartyKickRequestMessage",
+            GameMessage::PartyKickedByMessage(_) => "PartyKickedByMessage",
+            GameMessage::PartyLeaderUpdateMessage(_) => "PartyLeaderUpdateMessage",
+            GameMessage::PartyLeaveMessage(_) => "PartyLeaveMessage",
+            GameMessage::PartyLeaveRequestMessage(_) => "PartyLeaveRequestMessage",
+            GameMessage::PartyLocateMembersMessage(_) => "PartyLocateMembersMessage",
+            GameMessage::PartyLocateMembersRequestMessage(_) => "PartyLocateMembersRequestMessage",
+            GameMessage::PartyLoyaltyStatusMessage(_) => "PartyLoyaltyStatusMessage",
+            GameMessage::PartyMemberEjectedMessage(_) => "PartyMemberEjectedMessage",
+            GameMessage::PartyMemberInBreachFightMessage(_) => "PartyMemberInBreachFightMessage",
+            GameMessage::PartyMemberInStandardFightMessage(_) => "PartyMemberInStandardFightMessage",
+            GameMessage::PartyMemberRemoveMessage(_) => "PartyMemberRemoveMessage",
+            GameMessage::PartyModifiableStatusMessage(_) => "PartyModifiableStatusMessage",
+            GameMessage::PartyNameSetErrorMessage(_) => "PartyNameSetErrorMessage",
+            GameMessage::PartyNameSetRequestMessage(_) => "PartyNameSetRequestMessage",
+            GameMessage::PartyNameUpdateMessage(_) => "PartyNameUpdateMessage",
+            GameMessage::PartyNewGuestMessage(_) => "PartyNewGuestMessage",
+            GameMessage::PartyNewMemberMessage(_) => "PartyNewMemberMessage",
+            GameMessage::PartyPledgeLoyaltyRequestMessage(_) => "PartyPledgeLoyaltyRequestMessage",
+            GameMessage::PartyRefuseInvitationMessage(_) => "PartyRefuseInvitationMessage",
+            GameMessage::PartyRefuseInvitationNotificationMessage(_) => "PartyRefuseInvitationNotificationMessage",
+            GameMessage::PartyRestrictedMessage(_) => "PartyRestrictedMessage",
+            GameMessage::PartyStopFollowRequestMessage(_) => "PartyStopFollowRequestMessage",
+            GameMessage::PartyUpdateLightMessage(_) => "PartyUpdateLightMessage",
+            GameMessage::PartyUpdateMessage(_) => "PartyUpdateMessage",
+            GameMessage::PauseDialogMessage(_) => "PauseDialogMessage",
+            GameMessage::PlayerStatusUpdateErrorMessage(_) => "PlayerStatusUpdateErrorMessage",
+            GameMessage::PlayerStatusUpdateMessage(_) => "PlayerStatusUpdateMessage",
+            GameMessage::PlayerStatusUpdateRequestMessage(_) => "PlayerStatusUpdateRequestMessage",
+            GameMessage::PopupWarningCloseRequestMessage(_) => "PopupWarningCloseRequestMessage",
+            GameMessage::PopupWarningClosedMessage(_) => "PopupWarningClosedMessage",
+            GameMessage::PopupWarningMessage(_) => "PopupWarningMessage",
+            GameMessage::PortalDialogCreationMessage(_) => "PortalDialogCreationMessage",
+            GameMessage::PortalUseRequestMessage(_) => "PortalUseRequestMessage",
+            GameMessage::PresetDeleteRequestMessage(_) => "PresetDeleteRequestMessage",
+            GameMessage::PresetDeleteResultMessage(_) => "PresetDeleteResultMessage",
+            GameMessage::PresetSaveErrorMessage(_) => "PresetSaveErrorMessage",
+            GameMessage::PresetSavedMessage(_) => "PresetSavedMessage",
+            GameMessage::PresetUseRequestMessage(_) => "PresetUseRequestMessage",
+            GameMessage::PresetUseResultMessage(_) => "PresetUseResultMessage",
+            GameMessage::PresetUseResultWithMissingIdsMessage(_) => "PresetUseResultWithMissingIdsMessage",
+            GameMessage::PresetsMessage(_) => "PresetsMessage",
+            GameMessage::PrismAddOrUpdateMessage(_) => "PrismAddOrUpdateMessage",
+            GameMessage::PrismAttackRequestMessage(_) => "PrismAttackRequestMessage",
+            GameMessage::PrismAttackResultMessage(_) => "PrismAttackResultMessage",
+            GameMessage::PrismAttackedMessage(_) => "PrismAttackedMessage",
+            GameMessage::PrismExchangeRequestMessage(_) => "PrismExchangeRequestMessage",
+            GameMessage::PrismRecycleTradeRequestMessage(_) => "PrismRecycleTradeRequestMessage",
+            GameMessage::PrismRemoveMessage(_) => "PrismRemoveMessage",
+            GameMessage::PrismTeleportationRequestMessage(_) => "PrismTeleportationRequestMessage",
+            GameMessage::PrismsListMessage(_) => "PrismsListMessage",
+            GameMessage::ProtocolRequired(_) => "ProtocolRequired",
+            GameMessage::PurchasableDialogMessage(_) => "PurchasableDialogMessage",
+            GameMessage::QuestListMessage(_) => "QuestListMessage",
+            GameMessage::QuestListRequestMessage(_) => "QuestListRequestMessage",
+            GameMessage::QuestObjectiveValidatedMessage(_) => "QuestObjectiveValidatedMessage",
+            GameMessage::QuestObjectiveValidationMessage(_) => "QuestObjectiveValidationMessage",
+            GameMessage::QuestStartRequestMessage(_) => "QuestStartRequestMessage",
+            GameMessage::QuestStartedMessage(_) => "QuestStartedMessage",
+            GameMessage::QuestStepInfoMessage(_) => "QuestStepInfoMessage",
+            GameMessage::QuestStepInfoRequestMessage(_) => "QuestStepInfoRequestMessage",
+            GameMessage::QuestStepStartedMessage(_) => "QuestStepStartedMessage",
+            GameMessage::QuestStepValidatedMessage(_) => "QuestStepValidatedMessage",
+            GameMessage::QuestValidatedMessage(_) => "QuestValidatedMessage",
+            GameMessage::QueueStatusMessage(_) => "QueueStatusMessage",
+            GameMessage::RawDataMessage(_) => "RawDataMessage",
+            GameMessage::RecruitmentInformationMessage(_) => "RecruitmentInformationMessage",
+            GameMessage::RecycleResultMessage(_) => "RecycleResultMessage",
+            GameMessage::RefreshCharacterStatsMessage(_) => "RefreshCharacterStatsMessage",
+            GameMessage::RefreshFollowedQuestsOrderRequestMessage(_) => "RefreshFollowedQuestsOrderRequestMessage",
+            GameMessage::ReleaseAccountMessage(_) => "ReleaseAccountMessage",
+            GameMessage::ReloginTokenRequestMessage(_) => "ReloginTokenRequestMessage",
+            GameMessage::ReloginTokenStatusMessage(_) => "ReloginTokenStatusMessage",
+            GameMessage::RemoveGuildRankRequestMessage(_) => "RemoveGuildRankRequestMessage",
+            GameMessage::RemoveListenerOnSynchronizedStorageMessage(_) => "RemoveListenerOnSynchronizedStorageMessage",
+            GameMessage::RemoveSpellModifierMessage(_) => "RemoveSpellModifierMessage",
+            GameMessage::RemoveTaxCollectorOrderedSpellMessage(_) => "RemoveTaxCollectorOrderedSpellMessage",
+            GameMessage::RemoveTaxCollectorPresetSpellMessage(_) => "RemoveTaxCollectorPresetSpellMessage",
+            GameMessage::ReportRequestMessage(_) => "ReportRequestMessage",
+            GameMessage::ReportResponseMessage(_) => "ReportResponseMessage",
+            GameMessage::ResetCharacterStatsRequestMessage(_) => "ResetCharacterStatsRequestMessage",
+            GameMessage::SelectedServerDataExtendedMessage(_) => "SelectedServerDataExtendedMessage",
+            GameMessage::SelectedServerDataMessage(_) => "SelectedServerDataMessage",
+            GameMessage::SelectedServerRefusedMessage(_) => "SelectedServerRefusedMessage",
+            GameMessage::SequenceEndMessage(_) => "SequenceEndMessage",
+            GameMessage::SequenceNumberMessage(_) => "SequenceNumberMessage",
+            GameMessage::SequenceNumberRequestMessage(_) => "SequenceNumberRequestMessage",
+            GameMessage::SequenceStartMessage(_) => "SequenceStartMessage",
+            GameMessage::ServerExperienceModificatorMessage(_) => "ServerExperienceModificatorMessage",
+            GameMessage::ServerOptionalFeaturesMessage(_) => "ServerOptionalFeaturesMessage",
+            GameMessage::ServerSelectionMessage(_) => "ServerSelectionMessage",
+            GameMessage::ServerSessionConstantsMessage(_) => "ServerSessionConstantsMessage",
+            GameMessage::ServerSettingsMessage(_) => "ServerSettingsMessage",
+            GameMessage::ServerStatusUpdateMessage(_) => "ServerStatusUpdateMessage",
+            GameMessage::ServersListMessage(_) => "ServersListMessage",
+            GameMessage::SetCharacterRestrictionsMessage(_) => "SetCharacterRestrictionsMessage",
+            GameMessage::SetEnableAVARequestMessage(_) => "SetEnableAVARequestMessage",
+            GameMessage::SetEnablePVPRequestMessage(_) => "SetEnablePVPRequestMessage",
+            GameMessage::SetUpdateMessage(_) => "SetUpdateMessage",
+            GameMessage::ShortcutBarAddErrorMessage(_) => "ShortcutBarAddErrorMessage",
+            GameMessage::ShortcutBarAddRequestMessage(_) => "ShortcutBarAddRequestMessage",
+            GameMessage::ShortcutBarContentMessage(_) => "ShortcutBarContentMessage",
+            GameMessage::ShortcutBarRefreshMessage(_) => "ShortcutBarRefreshMessage",
+            GameMessage::ShortcutBarRemoveErrorMessage(_) => "ShortcutBarRemoveErrorMessage",
+            GameMessage::ShortcutBarRemoveRequestMessage(_) => "ShortcutBarRemoveRequestMessage",
+            GameMessage::ShortcutBarRemovedMessage(_) => "ShortcutBarRemovedMessage",
+            GameMessage::ShortcutBarReplacedMessage(_) => "ShortcutBarReplacedMessage",
+            GameMessage::ShortcutBarSwapErrorMessage(_) => "ShortcutBarSwapErrorMessage",
+            GameMessage::ShortcutBarSwapRequestMessage(_) => "ShortcutBarSwapRequestMessage",
+            GameMessage::ShowCellMessage(_) => "ShowCellMessage",
+            GameMessage::ShowCellRequestMessage(_) => "ShowCellRequestMessage",
+            GameMessage::ShowCellSpectatorMessage(_) => "ShowCellSpectatorMessage",
+            GameMessage::SlaveNoLongerControledMessage(_) => "SlaveNoLongerControledMessage",
+            GameMessage::SlaveSwitchContextMessage(_) => "SlaveSwitchContextMessage",
+            GameMessage::SocialFightJoinRequestMessage(_) => "SocialFightJoinRequestMessage",
+            GameMessage::SocialFightLeaveRequestMessage(_) => "SocialFightLeaveRequestMessage",
+            GameMessage::SocialFightTakePlaceRequestMessage(_) => "SocialFightTakePlaceRequestMessage",
+            GameMessage::SocialNoticeMessage(_) => "SocialNoticeMessage",
+            GameMessage::SocialNoticeSetErrorMessage(_) => "SocialNoticeSetErrorMessage",
+            GameMessage::SocialNoticeSetRequestMessage(_) => "SocialNoticeSetRequestMessage",
+            GameMessage::SpellListMessage(_) => "SpellListMessage",
+            GameMessage::SpellVariantActivationMessage(_) => "SpellVariantActivationMessage",
+            GameMessage::SpellVariantActivationRequestMessage(_) => "SpellVariantActivationRequestMessage",
+            GameMessage::SpouseGetInformationsMessage(_) => "SpouseGetInformationsMessage",
+            GameMessage::SpouseInformationsMessage(_) => "SpouseInformationsMessage",
+            GameMessage::SpouseStatusMessage(_) => "SpouseStatusMessage",
+            GameMessage::StartExchangeTaxCollectorEquipmentMessage(_) => "StartExchangeTaxCollectorEquipmentMessage",
+            GameMessage::StartGuildChestContributionMessage(_) => "StartGuildChestContributionMessage",
+            GameMessage::StartListenAllianceFightMessage(_) => "StartListenAllianceFightMessage",
+            GameMessage::StartListenGuildChestStructureMessage(_) => "StartListenGuildChestStructureMessage",
+            GameMessage::StartListenNuggetsMessage(_) => "StartListenNuggetsMessage",
+            GameMessage::StartListenTaxCollectorPresetsUpdatesMessage(_) => "StartListenTaxCollectorPresetsUpdatesMessage",
+            GameMessage::StartListenTaxCollectorUpdatesMessage(_) => "StartListenTaxCollectorUpdatesMessage",
+            GameMessage::StatedElementUpdatedMessage(_) => "StatedElementUpdatedMessage",
+            GameMessage::StatedMapUpdateMessage(_) => "StatedMapUpdateMessage",
+            GameMessage::StatsUpgradeRequestMessage(_) => "StatsUpgradeRequestMessage",
+            GameMessage::StatsUpgradeResultMessage(_) => "StatsUpgradeResultMessage",
+            GameMessage::StopGuildChestContributionMessage(_) => "StopGuildChestContributionMessage",
+            GameMessage::StopListenAllianceFightMessage(_) => "StopListenAllianceFightMessage",
+            GameMessage::StopListenGuildChestStructureMessage(_) => "StopListenGuildChestStructureMessage",
+            GameMessage::StopListenNuggetsMessage(_) => "StopListenNuggetsMessage",
+            GameMessage::StopListenTaxCollectorPresetsUpdatesMessage(_) => "StopListenTaxCollectorPresetsUpdatesMessage",
+            GameMessage::StopListenTaxCollectorUpdatesMessage(_) => "StopListenTaxCollectorUpdatesMessage",
+            GameMessage::StopToListenRunningFightRequestMessage(_) => "StopToListenRunningFightRequestMessage",
+            GameMessage::StorageInventoryContentMessage(_) => "StorageInventoryContentMessage",
+            GameMessage::StorageKamasUpdateMessage(_) => "StorageKamasUpdateMessage",
+            GameMessage::StorageObjectRemoveMessage(_) => "StorageObjectRemoveMessage",
+            GameMessage::StorageObjectUpdateMessage(_) => "StorageObjectUpdateMessage",
+            GameMessage::StorageObjectsRemoveMessage(_) => "StorageObjectsRemoveMessage",
+            GameMessage::StorageObjectsUpdateMessage(_) => "StorageObjectsUpdateMessage",
+            GameMessage::SubareaRewardRateMessage(_) => "SubareaRewardRateMessage",
+            GameMessage::SubscriptionLimitationMessage(_) => "SubscriptionLimitationMessage",
+            GameMessage::SubscriptionZoneMessage(_) => "SubscriptionZoneMessage",
+            GameMessage::SurrenderInfoRequestMessage(_) => "SurrenderInfoRequestMessage",
+            GameMessage::SurrenderInfoResponseMessage(_) => "SurrenderInfoResponseMessage",
+            GameMessage::SurrenderStateMessage(_) => "SurrenderStateMessage",
+            GameMessage::SurrenderVoteCastMessage(_) => "SurrenderVoteCastMessage",
+            GameMessage::SurrenderVoteEndMessage(_) => "SurrenderVoteEndMessage",
+            GameMessage::SurrenderVoteStartMessage(_) => "SurrenderVoteStartMessage",
+            GameMessage::SurrenderVoteUpdateMessage(_) => "SurrenderVoteUpdateMessage",
+            GameMessage::SwitchArenaXpRewardsModeMessage(_) => "SwitchArenaXpRewardsModeMessage",
+            GameMessage::SymbioticObjectAssociateRequestMessage(_) => "SymbioticObjectAssociateRequestMessage",
+            GameMessage::SymbioticObjectAssociatedMessage(_) => "SymbioticObjectAssociatedMessage",
+            GameMessage::SymbioticObjectErrorMessage(_) => "SymbioticObjectErrorMessage",
+            GameMessage::SystemMessageDisplayMessage(_) => "SystemMessageDisplayMessage",
+            GameMessage::TaxCollectorAddedMessage(_) => "TaxCollectorAddedMessage",
+            GameMessage::TaxCollectorAttackedMessage(_) => "TaxCollectorAttackedMessage",
+            GameMessage::TaxCollectorAttackedResultMessage(_) => "TaxCollectorAttackedResultMessage",
+            GameMessage::TaxCollectorDialogQuestionBasicMessage(_) => "TaxCollectorDialogQuestionBasicMessage",
+            GameMessage::TaxCollectorDialogQuestionExtendedMessage(_) => "TaxCollectorDialogQuestionExtendedMessage",
+            GameMessage::TaxCollectorEquipmentUpdateMessage(_) => "TaxCollectorEquipmentUpdateMessage",
+            GameMessage::TaxCollectorErrorMessage(_) => "TaxCollectorErrorMessage",
+            GameMessage::TaxCollectorHarvestedMessage(_) => "TaxCollectorHarvestedMessage",
+            GameMessage::TaxCollectorMovementsOfflineMessage(_) => "TaxCollectorMovementsOfflineMessage",
+            GameMessage::TaxCollectorOrderedSpellUpdatedMessage(_) => "TaxCollectorOrderedSpellUpdatedMessage",
+            GameMessage::TaxCollectorPresetSpellUpdatedMessage(_) => "TaxCollectorPresetSpellUpdatedMessage",
+            GameMessage::TaxCollectorPresetsMessage(_) => "TaxCollectorPresetsMessage",
+            GameMessage::TaxCollectorRemovedMessage(_) => "TaxCollectorRemovedMessage",
+            GameMessage::TaxCollectorStateUpdateMessage(_) => "TaxCollectorStateUpdateMessage",
+            GameMessage::TeleportBuddiesAnswerMessage(_) => "TeleportBuddiesAnswerMessage",
+            GameMessage::TeleportBuddiesMessage(_) => "TeleportBuddiesMessage",
+            GameMessage::TeleportBuddiesRequestedMessage(_) => "TeleportBuddiesRequestedMessage",
+            GameMessage::TeleportDestinationsMessage(_) => "TeleportDestinationsMessage",
+            GameMessage::TeleportHavenBagAnswerMessage(_) => "TeleportHavenBagAnswerMessage",
+            GameMessage::TeleportHavenBagRequestMessage(_) => "TeleportHavenBagRequestMessage",
+            GameMessage::TeleportOnSameMapMessage(_) => "TeleportOnSameMapMessage",
+            GameMessage::TeleportPlayerAnswerMessage(_) => "TeleportPlayerAnswerMessage",
+            GameMessage::TeleportPlayerCloseMessage(_) => "TeleportPlayerCloseMessage",
+            GameMessage::TeleportPlayerOfferMessage(_) => "TeleportPlayerOfferMessage",
+            GameMessage::TeleportRequestMessage(_) => "TeleportRequestMessage",
+            GameMessage::TeleportToBuddyAnswerMessage(_) => "TeleportToBuddyAnswerMessage",
+            GameMessage::TeleportToBuddyCloseMessage(_) => "TeleportToBuddyCloseMessage",
+            GameMessage::TeleportToBuddyOfferMessage(_) => "TeleportToBuddyOfferMessage",
+            GameMessage::TextInformationMessage(_) => "TextInformationMessage",
+            GameMessage::TitleGainedMessage(_) => "TitleGainedMessage",
+            GameMessage::TitleLostMessage(_) => "TitleLostMessage",
+            GameMessage::TitleSelectErrorMessage(_) => "TitleSelectErrorMessage",
+            GameMessage::TitleSelectRequestMessage(_) => "TitleSelectRequestMessage",
+            GameMessage::TitleSelectedMessage(_) => "TitleSelectedMessage",
+            GameMessage::TitlesAndOrnamentsListMessage(_) => "TitlesAndOrnamentsListMessage",
+            GameMessage::TitlesAndOrnamentsListRequestMessage(_) => "TitlesAndOrnamentsListRequestMessage",
+            GameMessage::TopTaxCollectorListMessage(_) => "TopTaxCollectorListMessage",
+            GameMessage::TreasureHuntAvailableRetryCountUpdateMessage(_) => "TreasureHuntAvailableRetryCountUpdateMessage",
+            GameMessage::TreasureHuntDigRequestAnswerFailedMessage(_) => "TreasureHuntDigRequestAnswerFailedMessage",
+            GameMessage::TreasureHuntDigRequestAnswerMessage(_) => "TreasureHuntDigRequestAnswerMessage",
+            GameMessage::TreasureHuntDigRequestMessage(_) => "TreasureHuntDigRequestMessage",
+            GameMessage::TreasureHuntFinishedMessage(_) => "TreasureHuntFinishedMessage",
+            GameMessage::TreasureHuntFlagRemoveRequestMessage(_) => "TreasureHuntFlagRemoveRequestMessage",
+            GameMessage::TreasureHuntFlagRequestAnswerMessage(_) => "TreasureHuntFlagRequestAnswerMessage",
+            GameMessage::TreasureHuntFlagRequestMessage(_) => "TreasureHuntFlagRequestMessage",
+            GameMessage::TreasureHuntGiveUpRequestMessage(_) => "TreasureHuntGiveUpRequestMessage",
+            GameMessage::TreasureHuntLegendaryRequestMessage(_) => "TreasureHuntLegendaryRequestMessage",
+            GameMessage::TreasureHuntMessage(_) => "TreasureHuntMessage",
+            GameMessage::TreasureHuntRequestAnswerMessage(_) => "TreasureHuntRequestAnswerMessage",
+            GameMessage::TreasureHuntShowLegendaryUIMessage(_) => "TreasureHuntShowLegendaryUIMessage",
+            GameMessage::TrustStatusMessage(_) => "TrustStatusMessage",
+            GameMessage::UnfollowQuestObjectiveRequestMessage(_) => "UnfollowQuestObjectiveRequestMessage",
+            GameMessage::UpdateAllGuildRankRequestMessage(_) => "UpdateAllGuildRankRequestMessage",
+            GameMessage::UpdateGuildRankRequestMessage(_) => "UpdateGuildRankRequestMessage",
+            GameMessage::UpdateGuildRightsMessage(_) => "UpdateGuildRightsMessage",
+            GameMessage::UpdateLifePointsMessage(_) => "UpdateLifePointsMessage",
+            GameMessage::UpdateMapPlayersAgressableStatusMessage(_) => "UpdateMapPlayersAgressableStatusMessage",
+            GameMessage::UpdateMountCharacteristicsMessage(_) => "UpdateMountCharacteristicsMessage",
+            GameMessage::UpdateRecruitmentInformationMessage(_) => "UpdateRecruitmentInformationMessage",
+            GameMessage::UpdateSelfAgressableStatusMessage(_) => "UpdateSelfAgressableStatusMessage",
+            GameMessage::WarnOnPermaDeathMessage(_) => "WarnOnPermaDeathMessage",
+            GameMessage::WarnOnPermaDeathStateMessage(_) => "WarnOnPermaDeathStateMessage",
+            GameMessage::WatchInventoryContentMessage(_) => "WatchInventoryContentMessage",
+            GameMessage::WatchQuestListMessage(_) => "WatchQuestListMessage",
+            GameMessage::WatchQuestStepInfoMessage(_) => "WatchQuestStepInfoMessage",
+            GameMessage::WatchQuestStepInfoRequestMessage(_) => "WatchQuestStepInfoRequestMessage",
+            GameMessage::WrapperObjectAssociatedMessage(_) => "WrapperObjectAssociatedMessage",
+            GameMessage::WrapperObjectDissociateRequestMessage(_) => "WrapperObjectDissociateRequestMessage",
+            GameMessage::WrapperObjectErrorMessage(_) => "WrapperObjectErrorMessage",
+            GameMessage::ZaapDestinationsMessage(_) => "ZaapDestinationsMessage",
+            GameMessage::ZaapRespawnSaveRequestMessage(_) => "ZaapRespawnSaveRequestMessage",
+            GameMessage::ZaapRespawnUpdatedMessage(_) => "ZaapRespawnUpdatedMessage",
+        }
+    }
+}
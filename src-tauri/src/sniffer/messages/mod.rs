@@ -0,0 +1,165 @@
+//! Typed messages, generated from the protocol by `cargo run --bin dtools-codegen`.
+//!
+//! The structs are filled from the data of a decoded `Packet` and matched by class name,
+//! ids change with every game version. The fields missing from the packet, e.g. the ones not
+//! selected with `PacketListener::subscribe_fields`, get their default value.
+
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+use super::parser::packet::Packet;
+
+#[rustfmt::skip]
+mod generated;
+
+pub use generated::*;
+
+#[derive(Debug, Error)]
+pub enum MessageError {
+    #[error("Unknown message {0}")]
+    UnknownMessage(String),
+    #[error("Invalid data for {name}: {source}")]
+    InvalidData {
+        name: String,
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+impl GameMessage {
+    pub fn from_packet(packet: &Packet) -> Result<Self, MessageError> {
+        GameMessage::decode(&packet.name, &packet.data)
+    }
+
+    /// Data of a `Packet`, polymorphic values are named by `__type` as the parser does
+    pub fn to_data(&self) -> Map<String, Value> {
+        match serde_json::to_value(self) {
+            Ok(Value::Object(data)) => data,
+            _ => unreachable!("messages are serialized as maps"),
+        }
+    }
+}
+
+fn decode<'a, T: Deserialize<'a>>(
+    name: &str,
+    data: &'a Map<String, Value>,
+) -> Result<T, MessageError> {
+    T::deserialize(data).map_err(|source| MessageError::InvalidData {
+        name: name.to_string(),
+        source,
+    })
+}
+
+trait Float: Copy {
+    const NAN: Self;
+}
+
+impl Float for f32 {
+    const NAN: Self = f32::NAN;
+}
+
+impl Float for f64 {
+    const NAN: Self = f64::NAN;
+}
+
+/// `null` becomes NaN, the infinities the value may have been are lost
+fn float<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Float + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or(T::NAN))
+}
+
+/// Same as `float` for each value
+fn floats<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Float + Deserialize<'de>,
+{
+    let values = Vec::<Option<T>>::deserialize(deserializer)?;
+    Ok(values
+        .into_iter()
+        .map(|value| value.unwrap_or(T::NAN))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sniffer::{
+        offline::{frame_file, FIXTURE_CAPTURE},
+        parser::packet::PacketParser,
+        protocol::protocol::ProtocolManager,
+    };
+
+    #[test]
+    fn test_capture() {
+        let mut protocol = ProtocolManager::new();
+        protocol.init("tests/fixtures/").unwrap();
+
+        let mut chat = 0;
+        for message in frame_file(FIXTURE_CAPTURE).unwrap() {
+            let metadata = message.unwrap().metadata;
+            let Ok(packet) = PacketParser::from_metadata(&metadata).parse(&protocol) else {
+                continue;
+            };
+            // Types share their ids with the messages
+            let message = match GameMessage::from_packet(&packet) {
+                Ok(message) => message,
+                Err(MessageError::UnknownMessage(_)) => continue,
+                Err(err) => panic!("{}", err),
+            };
+            assert_eq!(message.name(), packet.name);
+            // NaN is not equal to itself, compare the JSON values
+            assert_eq!(message.to_data(), packet.data, "{}", packet.name);
+
+            if let GameMessage::ChatServerMessage(message) = message {
+                assert_eq!(Value::from(message.sender_name), packet.data["senderName"]);
+                chat += 1;
+            }
+        }
+        assert!(chat > 0);
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut data = Map::new();
+        assert!(matches!(
+            GameMessage::decode("MapCoordinates", &data),
+            Err(MessageError::UnknownMessage(_))
+        ));
+
+        // CompassUpdateMessage { type: Byte, coords: TypeId<MapCoordinates> }
+        data.insert("type".to_string(), Value::from("north"));
+        let error = GameMessage::decode("CompassUpdateMessage", &data);
+        assert_eq!(
+            error.unwrap_err().to_string(),
+            "Invalid data for CompassUpdateMessage: invalid type: string \"north\", expected i8"
+        );
+
+        // Only the selected fields are in the packet, the others get their default value
+        data.insert("type".to_string(), Value::from(3));
+        let message = GameMessage::decode("CompassUpdateMessage", &data).unwrap();
+        let GameMessage::CompassUpdateMessage(message) = message else {
+            panic!("unexpected message {:?}", message);
+        };
+        assert_eq!(message.r#type, 3);
+        assert_eq!(*message.coords, MapCoordinatesKind::default());
+
+        data.insert(
+            "coords".to_string(),
+            serde_json::json!({ "__type": "MapCoordinatesAndId", "worldX": 4, "worldY": 7, "mapId": 1234.0 }),
+        );
+        let message = GameMessage::decode("CompassUpdateMessage", &data).unwrap();
+        let GameMessage::CompassUpdateMessage(message) = message else {
+            panic!("unexpected message {:?}", message);
+        };
+        assert_eq!(message.r#type, 3);
+        assert!(matches!(
+            *message.coords,
+            MapCoordinatesKind::MapCoordinatesAndId(MapCoordinatesAndId { world_y: 7, .. })
+        ));
+    }
+}
@@ -1,4 +1,11 @@
 pub mod config;
+pub mod device;
+pub mod filter;
+pub mod messages;
 pub mod network;
+pub mod offline;
 pub mod parser;
+pub mod pipeline;
 pub mod protocol;
+pub mod recorder;
+pub mod stats;
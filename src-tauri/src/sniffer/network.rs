@@ -1,152 +1,902 @@
-use std::{collections::HashMap, sync::RwLock, time::SystemTime};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        RwLock, Weak,
+    },
+    task::{Context, Poll},
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
 
 use core::fmt::Debug;
 use pcap::{Activated, Capture};
+use serde::{Deserialize, Serialize};
 use std::sync::{Arc, Mutex};
+use tauri_specta::Event;
 use thiserror::Error;
-use tracing::{debug, info, warn};
+use tokio::sync::mpsc::{self, error::TrySendError};
+use tracing::{debug, error, info, warn};
 
 use crate::{
     node::Node,
     sniffer::parser::{
-        metadata::{PacketHeader, PacketMetadata, ParseResult},
+        framer::{frame_timestamp, MessageFramer},
+        metadata::PacketMetadata,
         packet::PacketParser,
-        wrapper::DataWrapper,
+        selection::FieldSelection,
     },
 };
 
-use super::{parser::packet::Packet, protocol::protocol::EventId};
+use super::{
+    config::NetworkConfig,
+    device,
+    filter::EventFilter,
+    parser::packet::Packet,
+    pipeline::{Pipeline, Stage},
+    protocol::protocol::{EventId, ProtocolManager},
+    recorder::{self, Recorder},
+    stats::CaptureStats,
+};
+
+/// Receives the packets of the events it subscribed to, implemented by closures.
+/// Every subscription runs its handler on its own thread, away from the capture loop
+pub trait PacketHandler: Send {
+    fn handle(&mut self, packet: &Packet, node: &Node);
+}
+
+impl<F> PacketHandler for F
+where
+    F: FnMut(&Packet, &Node) + Send,
+{
+    fn handle(&mut self, packet: &Packet, node: &Node) {
+        self(packet, node)
+    }
+}
+
+/// Name of the subscriber, several subscriptions can share it
+pub type ListenerId = String;
+
+/// Subscriptions, with the ones matching each id resolved once per protocol
+#[derive(Debug, Default)]
+struct Subscriptions {
+    list: Vec<Subscription>,
+    /// Indices in `list`, cleared when the subscriptions or the protocol change
+    resolved: HashMap<EventId, Vec<usize>>,
+    generation: u64,
+}
+
+impl Subscriptions {
+    fn push(&mut self, subscription: Subscription) {
+        self.list.push(subscription);
+        self.resolved.clear();
+    }
+
+    fn retain(&mut self, keep: impl FnMut(&Subscription) -> bool) {
+        self.list.retain(keep);
+        self.resolved.clear();
+    }
+
+    fn matching(
+        &mut self,
+        id: EventId,
+        protocol: &ProtocolManager,
+    ) -> impl Iterator<Item = &Subscription> {
+        if self.generation != protocol.generation() {
+            self.generation = protocol.generation();
+            self.resolved.clear();
+        }
+
+        let list = &self.list;
+        let indices = self.resolved.entry(id).or_insert_with(|| {
+            (0..list.len())
+                .filter(|index| list[*index].event.matches(id, protocol))
+                .collect()
+        });
+        indices.iter().map(|index| &list[*index])
+    }
+}
+
+/// Default number of packets a subscriber can fall behind before new ones are dropped
+pub const QUEUE_CAPACITY: usize = 1024;
 
-pub type Listener = fn(&Packet, &Node);
-pub type ListenerId = &'static str;
-pub type Subscription = (ListenerId, Listener);
+/// What happens to a packet when the queue of a subscriber is full
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Overflow {
+    /// Drop the packet and count it in `CaptureStats::dropped_messages`
+    #[default]
+    Drop,
+    /// Wait for the subscriber, which holds up the whole capture. Only for captures that can
+    /// be slowed down (e.g. a file), a live capture would lose frames instead
+    Wait,
+}
+
+#[derive(Debug)]
+struct Delivery {
+    packet: Arc<Packet>,
+    /// Weak so the queues don't keep the node alive
+    node: Weak<Node>,
+}
+
+/// Listeners only receive the fields they subscribed to, and the fields of the other listeners
+/// of the same message
+#[derive(Debug)]
+pub struct Subscription {
+    key: u64,
+    event: EventFilter,
+    listener_id: ListenerId,
+    selection: FieldSelection,
+    queue: mpsc::Sender<Delivery>,
+    overflow: Overflow,
+}
+
+/// Removes its subscription when dropped, the handler stops once its queue is drained
+#[derive(Debug)]
+#[must_use = "the subscription is removed when the token is dropped"]
+pub struct SubscriptionToken {
+    event: EventFilter,
+    key: u64,
+    subscriptions: Option<Weak<Mutex<Subscriptions>>>,
+}
+
+impl SubscriptionToken {
+    pub fn event(&self) -> &EventFilter {
+        &self.event
+    }
+
+    /// Keep the subscription until `PacketListener::unsubscribe` is called
+    pub fn detach(mut self) {
+        self.subscriptions = None;
+    }
+}
+
+impl Drop for SubscriptionToken {
+    fn drop(&mut self) {
+        let Some(subscriptions) = self.subscriptions.take().and_then(|weak| weak.upgrade()) else {
+            return;
+        };
+        subscriptions
+            .lock()
+            .unwrap()
+            .retain(|subscription| subscription.key != self.key);
+    }
+}
+
+/// Read timeout of live captures, so the capture loop can notice it has to stop
+const CAPTURE_READ_TIMEOUT_MS: i32 = 500;
+/// How often the capture statistics are sent to the UI
+const STATS_EVENT_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type, tauri_specta::Event)]
+#[serde(tag = "state", rename_all = "camelCase")]
+pub enum CaptureState {
+    Idle,
+    /// Probing the devices for the one carrying the game traffic
+    Detecting,
+    /// `interface` is `None` when the capture doesn't come from a device (e.g. a file)
+    Capturing {
+        interface: Option<String>,
+    },
+    Replaying {
+        path: String,
+    },
+    Errored {
+        message: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub enum ReplaySpeed {
+    /// Wait between frames like in the original capture
+    Original,
+    /// Feed the frames as fast as possible
+    Fast,
+}
+
+#[derive(Debug)]
+struct CaptureSession {
+    stop: Arc<AtomicBool>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct SessionOptions {
+    /// Frames can be written to the recorder
+    recordable: bool,
+    /// Respect the time between frames, used to replay files
+    paced: bool,
+}
+
+/// Sleeps so that frames are handled with the same delays as when they were captured
+#[derive(Debug, Default)]
+struct Pacer {
+    start: Option<(u64, Instant)>,
+}
+
+impl Pacer {
+    fn wait(&mut self, timestamp: u64, stop: &AtomicBool) {
+        let (first_timestamp, started_at) = *self.start.get_or_insert((timestamp, Instant::now()));
+        let target = started_at + Duration::from_micros(timestamp.saturating_sub(first_timestamp));
+
+        // Sleep in small steps so a stop request is not delayed by a long pause in the capture
+        while !stop.load(Ordering::Relaxed) {
+            let now = Instant::now();
+            if now >= target {
+                break;
+            }
+            thread::sleep((target - now).min(Duration::from_millis(100)));
+        }
+    }
+}
 
 #[derive(Debug)]
 pub struct PacketListener {
-    subscriptions: Arc<Mutex<HashMap<EventId, Vec<Subscription>>>>,
+    subscriptions: Arc<Mutex<Subscriptions>>,
+    next_key: AtomicU64,
     node: Option<Arc<Node>>,
+    session: Option<CaptureSession>,
+    state: Arc<RwLock<CaptureState>>,
+    recorder: Arc<Mutex<Option<Recorder>>>,
+    stats: Arc<Mutex<CaptureStats>>,
+    pipeline: Arc<Mutex<Pipeline>>,
     pub last_packet_time: Arc<RwLock<u128>>,
 }
 
+impl Default for PacketListener {
+    fn default() -> Self {
+        PacketListener::new()
+    }
+}
+
 impl PacketListener {
     pub fn new() -> PacketListener {
-        return PacketListener {
-            subscriptions: Arc::new(Mutex::new(HashMap::new())),
+        PacketListener {
+            subscriptions: Arc::new(Mutex::new(Subscriptions::default())),
+            next_key: AtomicU64::new(0),
             node: None,
+            session: None,
+            state: Arc::new(RwLock::new(CaptureState::Idle)),
+            recorder: Arc::new(Mutex::new(None)),
+            stats: Arc::new(Mutex::new(CaptureStats::default())),
+            pipeline: Arc::new(Mutex::new(Pipeline::new())),
             last_packet_time: Arc::new(RwLock::new(0)),
-        };
+        }
     }
 
     pub fn set_node(&mut self, node: Arc<Node>) {
         self.node = Some(node);
     }
 
-    pub fn subscribe(&mut self, event: EventId, listener_id: ListenerId, listener: Listener) {
+    /// `event` can be an id, or an `EventFilter` to match messages by name
+    pub fn subscribe(
+        &mut self,
+        event: impl Into<EventFilter>,
+        listener_id: impl Into<ListenerId>,
+        listener: impl PacketHandler + 'static,
+    ) -> SubscriptionToken {
+        let events = [event.into()];
+        let mut tokens = self.subscribe_many(events, listener_id, FieldSelection::All, listener);
+        tokens.pop().unwrap()
+    }
+
+    /// Only decode the given field paths (e.g. `actors.name`), the rest of the message is skipped
+    pub fn subscribe_fields(
+        &mut self,
+        event: impl Into<EventFilter>,
+        listener_id: impl Into<ListenerId>,
+        fields: &[&str],
+        listener: impl PacketHandler + 'static,
+    ) -> SubscriptionToken {
+        let selection = FieldSelection::from_paths(fields);
+        let mut tokens = self.subscribe_many([event.into()], listener_id, selection, listener);
+        tokens.pop().unwrap()
+    }
+
+    /// Send the packets of every filter in `events` to the same handler, which runs on a single
+    /// thread and so receives them one at a time, in the order they were captured
+    pub fn subscribe_many(
+        &mut self,
+        events: impl IntoIterator<Item = impl Into<EventFilter>>,
+        listener_id: impl Into<ListenerId>,
+        selection: FieldSelection,
+        listener: impl PacketHandler + 'static,
+    ) -> Vec<SubscriptionToken> {
+        let listener_id = listener_id.into();
+        let sender = self.spawn_handler(&listener_id, listener);
+        events
+            .into_iter()
+            .map(|event| {
+                self.subscribe_queue(
+                    event.into(),
+                    listener_id.clone(),
+                    selection.clone(),
+                    sender.clone(),
+                    Overflow::Drop,
+                )
+            })
+            .collect()
+    }
+
+    /// Receive the packets of `events` in a single queue of `capacity` packets,
+    /// the subscriptions are removed when the stream is dropped
+    pub fn stream(
+        &mut self,
+        listener_id: impl Into<ListenerId>,
+        events: impl IntoIterator<Item = impl Into<EventFilter>>,
+        selection: FieldSelection,
+        capacity: usize,
+        overflow: Overflow,
+    ) -> PacketStream {
+        let listener_id = listener_id.into();
+        let (sender, receiver) = mpsc::channel(capacity);
+        let tokens = events
+            .into_iter()
+            .map(|event| {
+                self.subscribe_queue(
+                    event.into(),
+                    listener_id.clone(),
+                    selection.clone(),
+                    sender.clone(),
+                    overflow,
+                )
+            })
+            .collect();
+        PacketStream {
+            receiver,
+            _tokens: tokens,
+        }
+    }
+
+    fn spawn_handler(
+        &self,
+        listener_id: &str,
+        mut listener: impl PacketHandler + 'static,
+    ) -> mpsc::Sender<Delivery> {
+        let (sender, mut receiver) = mpsc::channel::<Delivery>(QUEUE_CAPACITY);
+        // Ends when the subscriptions, and so the senders, are removed
+        let res = thread::Builder::new()
+            .name(format!("listener-{}", listener_id))
+            .spawn(move || {
+                while let Some(delivery) = receiver.blocking_recv() {
+                    // The node is only gone when the app is closing
+                    if let Some(node) = delivery.node.upgrade() {
+                        listener.handle(&delivery.packet, &node);
+                    }
+                }
+            });
+        if let Err(err) = res {
+            error!("Failed to start listener {:?}: {}", listener_id, err);
+        }
+        sender
+    }
+
+    fn subscribe_queue(
+        &mut self,
+        event: EventFilter,
+        listener_id: ListenerId,
+        selection: FieldSelection,
+        queue: mpsc::Sender<Delivery>,
+        overflow: Overflow,
+    ) -> SubscriptionToken {
         info!("Subscribing to event: {:?} for {:?}", event, listener_id);
-        self.subscriptions
-            .lock()
-            .unwrap()
-            .entry(event)
-            .or_default()
-            .push((listener_id, listener));
+        let key = self.next_key.fetch_add(1, Ordering::Relaxed);
+        self.subscriptions.lock().unwrap().push(Subscription {
+            key,
+            event: event.clone(),
+            listener_id,
+            selection,
+            queue,
+            overflow,
+        });
+        SubscriptionToken {
+            event,
+            key,
+            subscriptions: Some(Arc::downgrade(&self.subscriptions)),
+        }
     }
 
-    pub fn unsubscribe(&mut self, event: &EventId, listener_id: ListenerId) {
+    /// Remove every subscription of `listener_id` to `event`, detached ones included
+    pub fn unsubscribe(&mut self, event: impl Into<EventFilter>, listener_id: &str) {
+        let event = event.into();
         info!(
             "Unsubscribing from event: {:?} for {:?}",
             event, listener_id
         );
-        self.subscriptions
-            .lock()
-            .unwrap()
-            .get_mut(event)
-            .map(|listeners| listeners.retain(|(id, _)| id != &listener_id));
+        self.subscriptions.lock().unwrap().retain(|subscription| {
+            subscription.event != event || subscription.listener_id != listener_id
+        });
     }
 
-    pub fn notify(&self, event: &Packet) {
+    pub fn notify(&self, event: Packet) {
+        let node = self.node.as_ref().unwrap();
         PacketListener::_notify(
-            &self.subscriptions.lock().unwrap(),
-            event,
-            &self.node.as_ref().unwrap(),
+            &mut self.subscriptions.lock().unwrap(),
+            &node.protocol.read().unwrap(),
+            Arc::new(event),
+            &Arc::downgrade(node),
+            &self.stats,
         );
     }
 
-    fn _notify(subscriptions: &HashMap<EventId, Vec<Subscription>>, packet: &Packet, node: &Node) {
-        let listeners = subscriptions.get(&packet.id);
-        if let Some(listeners) = listeners {
-            for (_, listener) in listeners {
-                listener(packet, node);
+    /// Only waits for the subscriptions with `Overflow::Wait`, the other ones lose the packets
+    /// that don't fit in their queue
+    fn _notify(
+        subscriptions: &mut Subscriptions,
+        protocol_manager: &ProtocolManager,
+        packet: Arc<Packet>,
+        node: &Weak<Node>,
+        stats: &Mutex<CaptureStats>,
+    ) {
+        for subscription in subscriptions.matching(packet.id, protocol_manager) {
+            let delivery = Delivery {
+                packet: packet.clone(),
+                node: node.clone(),
+            };
+            match subscription.queue.try_send(delivery) {
+                Ok(()) => {}
+                Err(TrySendError::Full(delivery)) if subscription.overflow == Overflow::Wait => {
+                    // Only fails once the receiver is gone
+                    let _ = subscription.queue.blocking_send(delivery);
+                }
+                Err(TrySendError::Full(_)) => {
+                    debug!("Queue of {:?} is full", subscription.listener_id);
+                    stats
+                        .lock()
+                        .unwrap()
+                        .record_dropped_message(&subscription.listener_id);
+                }
+                // The handler stopped, its subscription is being removed
+                Err(TrySendError::Closed(_)) => {}
             }
         }
     }
 
-    pub fn has_subscriptions_for(&self, event: &EventId, listener_id: ListenerId) -> bool {
+    /// Whether `listener_id` subscribed to this exact filter, not to the messages it matches
+    pub fn has_subscriptions_for(&self, event: impl Into<EventFilter>, listener_id: &str) -> bool {
+        let event = event.into();
         let subscriptions = self.subscriptions.lock().unwrap();
-        subscriptions.get(event).map_or(false, |listeners| {
-            listeners.iter().any(|(id, _)| id == &listener_id)
+        subscriptions.list.iter().any(|subscription| {
+            subscription.event == event && subscription.listener_id == listener_id
         })
     }
 
-    pub fn has_subscriptions(&self, event: &EventId) -> bool {
-        return PacketListener::_has_subscriptions(&self.subscriptions.lock().unwrap(), event);
+    /// Whether the messages with this id are sent to a listener
+    pub fn has_subscriptions(&self, event: &EventId, protocol_manager: &ProtocolManager) -> bool {
+        return PacketListener::_has_subscriptions(
+            &mut self.subscriptions.lock().unwrap(),
+            protocol_manager,
+            event,
+        );
     }
 
     fn _has_subscriptions(
-        subscriptions: &HashMap<EventId, Vec<Subscription>>,
+        subscriptions: &mut Subscriptions,
+        protocol_manager: &ProtocolManager,
         event: &EventId,
     ) -> bool {
         return subscriptions
-            .get(event)
-            .map_or(false, |listeners| !listeners.is_empty());
+            .matching(*event, protocol_manager)
+            .next()
+            .is_some();
+    }
+
+    /// Union of the fields needed by the listeners of `event`
+    fn _selection(
+        subscriptions: &mut Subscriptions,
+        protocol_manager: &ProtocolManager,
+        event: &EventId,
+    ) -> FieldSelection {
+        let mut selection = FieldSelection::Only(HashMap::new());
+        for subscription in subscriptions.matching(*event, protocol_manager) {
+            if subscription.selection.is_all() {
+                return FieldSelection::All;
+            }
+            selection.merge(&subscription.selection);
+        }
+        selection
+    }
+
+    /// Run `stage` on the messages before they reach the listeners, see `pipeline` for the
+    /// priorities of the built-in stages
+    pub fn add_stage(&mut self, priority: i32, stage: impl Stage + 'static) {
+        info!("Adding pipeline stage {:?} ({})", stage.name(), priority);
+        self.pipeline.lock().unwrap().add(priority, stage);
+    }
+
+    pub fn remove_stage(&mut self, name: &str) -> bool {
+        self.pipeline.lock().unwrap().remove(name)
+    }
+
+    pub fn get_state(&self) -> CaptureState {
+        self.state.read().unwrap().clone()
     }
 
-    pub fn run(&self) -> Result<(), PacketListenerError> {
+    /// Statistics of the current (or last) capture session
+    pub fn get_stats(&self) -> CaptureStats {
+        self.stats.lock().unwrap().clone()
+    }
+
+    pub fn is_running(&self) -> bool {
+        matches!(
+            *self.state.read().unwrap(),
+            CaptureState::Detecting
+                | CaptureState::Capturing { .. }
+                | CaptureState::Replaying { .. }
+        )
+    }
+
+    /// Open the configured (or detected) device and start capturing
+    pub fn run(&mut self) -> Result<(), PacketListenerError> {
         if self.node.is_none() {
             return Err(PacketListenerError::InvalidCaptureDevice);
         }
+        if self.is_running() {
+            debug!("Packet listener is already running");
+            return Ok(());
+        }
+
+        let options = SessionOptions {
+            recordable: true,
+            ..Default::default()
+        };
+        let network = self.network_config();
+        let interface = match network.interface.clone() {
+            Some(interface) if device::device_exists(&interface) => interface,
+            interface => {
+                if let Some(interface) = interface {
+                    warn!("Interface {} not found, looking for another one", interface);
+                }
+                // Probing takes seconds, the session does it so that the listener isn't blocked
+                return self.spawn_session(CaptureState::Detecting, options, move |node| {
+                    PacketListener::detect_device(node, &network)
+                });
+            }
+        };
+
+        match PacketListener::open_device(&interface, &network.filter()) {
+            Ok(cap) => self.start_session(
+                cap,
+                CaptureState::Capturing {
+                    interface: Some(interface),
+                },
+                options,
+            ),
+            Err(err) => {
+                error!("Failed to start packet listener: {}", err);
+                PacketListener::set_state(
+                    &self.state,
+                    self.node.as_deref(),
+                    CaptureState::Errored {
+                        message: err.to_string(),
+                    },
+                );
+                Err(err)
+            }
+        }
+    }
 
-        let config = self.node.as_ref().unwrap().config.config.read().unwrap();
-        let interface = config.network.interface.as_str();
-        let port = config.network.port;
+    pub fn stop(&mut self) {
+        if let Some(session) = self.session.take() {
+            info!("Stopping packet listener");
+            session.stop.store(true, Ordering::Relaxed);
+        }
+        PacketListener::set_state(&self.state, self.node.as_deref(), CaptureState::Idle);
+    }
+
+    /// Stop the current capture and feed a saved capture file through the listeners
+    pub fn replay(
+        &mut self,
+        path: impl AsRef<Path>,
+        speed: ReplaySpeed,
+    ) -> Result<(), PacketListenerError> {
+        if self.node.is_none() {
+            return Err(PacketListenerError::InvalidCaptureDevice);
+        }
 
+        let path = path.as_ref();
+        let cap = Capture::from_file(path)
+            .map_err(|err| PacketListenerError::FailedToOpenFile(path.to_path_buf(), err))?;
+
+        info!("Replaying {} ({:?})", path.display(), speed);
+        self.stop();
+        self.start_session(
+            cap.into(),
+            CaptureState::Replaying {
+                path: path.display().to_string(),
+            },
+            SessionOptions {
+                paced: speed == ReplaySpeed::Original,
+                ..Default::default()
+            },
+        )
+    }
+
+    pub fn recordings_dir(&self) -> Option<PathBuf> {
+        self.node
+            .as_ref()
+            .map(|node| node.data_dir.join(recorder::RECORDINGS_DIR))
+    }
+
+    /// Write the frames of live captures to `data_dir/recordings`
+    pub fn start_recording(&self) -> Result<(), PacketListenerError> {
+        let dir = self
+            .recordings_dir()
+            .ok_or(PacketListenerError::InvalidCaptureDevice)?;
+
+        let mut recorder = self.recorder.lock().unwrap();
+        if recorder.is_none() {
+            info!("Recording captures to {}", dir.display());
+            *recorder = Some(Recorder::new(dir).map_err(PacketListenerError::FailedToRecord)?);
+        }
+        Ok(())
+    }
+
+    pub fn stop_recording(&self) {
+        if self.recorder.lock().unwrap().take().is_some() {
+            info!("Recording stopped");
+        }
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.recorder.lock().unwrap().is_some()
+    }
+
+    /// Stop the current capture and start a new one, e.g. after the network config changed
+    pub fn restart(&mut self) -> Result<(), PacketListenerError> {
+        self.stop();
+        self.run()
+    }
+
+    fn network_config(&self) -> NetworkConfig {
+        let node = self.node.as_ref().unwrap();
+        node.config.config.read().unwrap().network.clone()
+    }
+
+    /// Open the device with the game traffic, saved to the config so that the next captures
+    /// don't probe again
+    fn detect_device(
+        node: &Node,
+        network: &NetworkConfig,
+    ) -> Result<(Capture<dyn Activated>, CaptureState), PacketListenerError> {
+        let filter = network.filter();
+        let interface = match device::find_game_device(&filter)? {
+            Some(interface) => {
+                if network.interface.is_none() {
+                    let res = node.config.update_config_sync(|config| {
+                        config.network.interface.get_or_insert(interface.clone());
+                    });
+                    if let Err(err) = res {
+                        warn!("Failed to save the detected interface: {:?}", err);
+                    }
+                }
+                interface
+            }
+            None => {
+                warn!("No game traffic found, using the default device");
+                device::default_device()?
+            }
+        };
+
+        let cap = PacketListener::open_device(&interface, &filter)?;
+        let state = CaptureState::Capturing {
+            interface: Some(interface),
+        };
+        Ok((cap, state))
+    }
+
+    fn open_device(
+        interface: &str,
+        filter: &str,
+    ) -> Result<Capture<dyn Activated>, PacketListenerError> {
         info!(
-            "Starting sniffer on interface: {} and port: {}",
-            interface, port
+            "Starting sniffer on interface: {} with filter: {}",
+            interface, filter
         );
 
         let mut cap = Capture::from_device(interface)
-            .unwrap()
-            .immediate_mode(true)
-            .open()
-            .expect("Failed to open device");
-        cap.direction(pcap::Direction::In).unwrap();
+            .and_then(|cap| {
+                cap.immediate_mode(true)
+                    .timeout(CAPTURE_READ_TIMEOUT_MS)
+                    .open()
+            })
+            .map_err(|err| PacketListenerError::FailedToOpenDevice(interface.to_string(), err))?;
 
-        cap.filter(format!("tcp port {}", port).as_str(), false)
-            .unwrap();
+        cap.filter(filter, true)
+            .map_err(|err| PacketListenerError::InvalidFilter(filter.to_string(), err))?;
+
+        Ok(cap.into())
+    }
+
+    fn set_state(state: &RwLock<CaptureState>, node: Option<&Node>, new_state: CaptureState) {
+        {
+            let mut state = state.write().unwrap();
+            if *state == new_state {
+                return;
+            }
+            debug!("Capture state: {:?}", new_state);
+            *state = new_state.clone();
+        }
+
+        if let Some(handle) = node.and_then(|node| node.handle.as_ref()) {
+            if let Err(err) = new_state.emit(handle) {
+                warn!("Failed to emit capture state: {:?}", err);
+            }
+        }
+    }
+
+    fn emit_stats(stats: &Mutex<CaptureStats>, node: &Node) {
+        let stats = stats.lock().unwrap().clone();
+        if let Some(handle) = node.handle.as_ref() {
+            if let Err(err) = stats.emit(handle) {
+                warn!("Failed to emit capture stats: {:?}", err);
+            }
+        }
+    }
 
-        self.run_with_capture(cap.into())
+    fn handle_metadata(
+        subscriptions: &Mutex<Subscriptions>,
+        protocol_manager: &RwLock<ProtocolManager>,
+        pipeline: &Mutex<Pipeline>,
+        node: &Weak<Node>,
+        stats: &Mutex<CaptureStats>,
+        mut metadata: PacketMetadata,
+    ) {
+        // The ids of the name filters are resolved with the protocol the packet is decoded with
+        let protocol_manager = protocol_manager.read().unwrap();
+
+        // Stages see every message, and may change the id the listeners are looked up with
+        if let Some(stage) = pipeline.lock().unwrap().process_message(&mut metadata) {
+            stats.lock().unwrap().record_filtered_message(stage);
+            return;
+        }
+
+        let selection = {
+            let mut subscriptions = subscriptions.lock().unwrap();
+            if !PacketListener::_has_subscriptions(
+                &mut subscriptions,
+                &protocol_manager,
+                &metadata.id,
+            ) {
+                return;
+            }
+            PacketListener::_selection(&mut subscriptions, &protocol_manager, &metadata.id)
+        };
+
+        let mut parser = PacketParser::from_metadata(&metadata);
+        match parser.parse_fields(&protocol_manager, &selection) {
+            Ok(mut packet) => {
+                stats.lock().unwrap().messages_decoded += 1;
+                if let Some(stage) = pipeline.lock().unwrap().process_packet(&mut packet) {
+                    stats.lock().unwrap().record_filtered_message(stage);
+                    return;
+                }
+                PacketListener::_notify(
+                    &mut subscriptions.lock().unwrap(),
+                    &protocol_manager,
+                    Arc::new(packet),
+                    node,
+                    stats,
+                );
+            }
+            Err(err) => {
+                warn!("Failed to parse packet: {:?} for {:?}", err, metadata.id);
+                stats.lock().unwrap().record_decode_failure(metadata.id);
+            }
+        }
     }
 
     pub fn run_with_capture(
-        &self,
-        mut cap: Capture<dyn Activated>,
+        &mut self,
+        cap: Capture<dyn Activated>,
     ) -> Result<(), PacketListenerError> {
         if self.node.is_none() {
             return Err(PacketListenerError::InvalidCaptureDevice);
         }
 
+        self.stop();
+        self.start_session(
+            cap,
+            CaptureState::Capturing { interface: None },
+            SessionOptions::default(),
+        )
+    }
+
+    fn start_session(
+        &mut self,
+        cap: Capture<dyn Activated>,
+        session_state: CaptureState,
+        options: SessionOptions,
+    ) -> Result<(), PacketListenerError> {
+        let state = session_state.clone();
+        self.spawn_session(session_state, options, move |_| Ok((cap, state)))
+    }
+
+    /// `open` runs on the session thread and gives the capture with the state of the session
+    /// once it is open
+    fn spawn_session(
+        &mut self,
+        session_state: CaptureState,
+        options: SessionOptions,
+        open: impl FnOnce(&Node) -> Result<(Capture<dyn Activated>, CaptureState), PacketListenerError>
+            + Send
+            + 'static,
+    ) -> Result<(), PacketListenerError> {
         debug!("Running packet listener");
         let subscriptions = self.subscriptions.clone();
         let procol_manager = self.node.as_ref().unwrap().protocol.clone();
         let node = self.node.clone().unwrap();
+        let weak_node = Arc::downgrade(&node);
         let last_packet_time = self.last_packet_time.clone();
+        let network = node.config.config.read().unwrap().network.clone();
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let session_stop = stop.clone();
+        let state = self.state.clone();
+        let recorder = self.recorder.clone();
+        let stats = self.stats.clone();
+        let pipeline = self.pipeline.clone();
+        *stats.lock().unwrap() = CaptureStats::default();
+        self.session = Some(CaptureSession { stop });
+        PacketListener::set_state(&self.state, Some(&node), session_state);
+
+        tauri::async_runtime::spawn_blocking(move || {
+            let (mut cap, capture_state) = match open(&node) {
+                Ok(opened) => opened,
+                Err(err) => {
+                    error!("Failed to start packet listener: {}", err);
+                    if !session_stop.load(Ordering::Relaxed) {
+                        let message = err.to_string();
+                        let new_state = CaptureState::Errored { message };
+                        PacketListener::set_state(&state, Some(&node), new_state);
+                    }
+                    return;
+                }
+            };
+            if session_stop.load(Ordering::Relaxed) {
+                return;
+            }
+            PacketListener::set_state(&state, Some(&node), capture_state);
 
-        tauri::async_runtime::spawn(async move {
-            let buffer = &mut DataWrapper::new(Vec::new());
-            let mut last_packet_header: Option<PacketHeader> = None;
+            let link_type = cap.get_datalink();
+            debug!("Capture datalink: {:?}", link_type);
+            let mut pacer = Pacer::default();
+            let mut framer = MessageFramer::new(link_type, network);
+            let mut last_stats_event = Instant::now();
+
+            let result = loop {
+                if session_stop.load(Ordering::Relaxed) {
+                    break Ok(());
+                }
+
+                if last_stats_event.elapsed() >= STATS_EVENT_INTERVAL {
+                    last_stats_event = Instant::now();
+                    PacketListener::emit_stats(&stats, &node);
+                }
+
+                let packet = match cap.next_packet() {
+                    Ok(packet) => packet,
+                    Err(pcap::Error::TimeoutExpired) => continue,
+                    // End of a capture file
+                    Err(pcap::Error::NoMorePackets) => break Ok(()),
+                    Err(err) => break Err(err),
+                };
+
+                let timestamp = frame_timestamp(packet.header);
+                if options.paced {
+                    pacer.wait(timestamp, &session_stop);
+                }
+                if options.recordable {
+                    if let Some(recorder) = recorder.lock().unwrap().as_mut() {
+                        let res =
+                            recorder.write(link_type, timestamp, packet.header.len, packet.data);
+                        if let Err(err) = res {
+                            warn!("Failed to record frame: {:?}", err);
+                        }
+                    }
+                }
 
-            while let Ok(packet) = cap.next_packet() {
-                let data = packet.data.to_vec();
                 let now = SystemTime::now();
 
                 *last_packet_time.write().unwrap() = now
@@ -154,126 +904,527 @@ impl PacketListener {
                     .unwrap()
                     .as_millis();
 
-                let packet_header = PacketHeader::from_vec(&data);
-                if packet_header.is_err() {
-                    warn!("Failed to parse packet header: {:?}", packet_header);
-                    continue;
+                let messages =
+                    framer.push_frame(packet.data, timestamp, &mut stats.lock().unwrap());
+                for metadata in messages {
+                    PacketListener::handle_metadata(
+                        &subscriptions,
+                        &procol_manager,
+                        &pipeline,
+                        &weak_node,
+                        &stats,
+                        metadata,
+                    );
                 }
-                let header = packet_header.unwrap();
-
-                let mut reorder = false;
-                if let Some(ref _last_packet_header) = last_packet_header {
-                    if _last_packet_header.source_ip != header.source_ip {
-                    } else if _last_packet_header.seq_num < header.seq_num {
-                        buffer.reorder(header.body.clone()); // TODO: remove clone
-                        reorder = true;
-                    }
-                }
-
-                if !reorder {
-                    buffer.extend_from_slice(&header.body);
-                }
-                let metadata = PacketMetadata::from_buffer(buffer.get_remaining().to_vec());
+            };
 
-                match metadata {
-                    Err(err) => match err {
-                        ParseResult::Incomplete => {
-                            // warn!("Incomplete packet: {:?}", err);
-                            last_packet_header = Some(header);
-                        }
-                        _ => {
-                            warn!("Failed to parse metadata: {:?}", err);
-                            buffer.clear();
-                        }
-                    },
-                    Ok(metadata) => {
-                        buffer.clear(); // TODO: adapt to other ranges
-                                        // debug!("Parsed metadata: {:?}", metadata.id);
-                        last_packet_header = None;
-                        if PacketListener::_has_subscriptions(
-                            &subscriptions.lock().unwrap(),
-                            &metadata.id,
-                        ) {
-                            let mut parser = PacketParser::from_metadata(&metadata);
-                            match parser.parse(&procol_manager.read().unwrap()) {
-                                Ok(packet) => {
-                                    PacketListener::_notify(
-                                        &subscriptions.lock().unwrap(),
-                                        &packet,
-                                        &node,
-                                    );
-                                }
-                                Err(err) => {
-                                    warn!(
-                                        "Failed to parse packet: {:?} for {:?}",
-                                        err, metadata.id
-                                    );
-                                }
-                            }
-                        }
-                    }
-                };
+            // A stopped session must not override the state of the one that replaced it
+            if session_stop.load(Ordering::Relaxed) {
+                return;
             }
+            let new_state = match result {
+                Ok(()) => CaptureState::Idle,
+                Err(err) => {
+                    error!("Capture stopped: {}", err);
+                    CaptureState::Errored {
+                        message: err.to_string(),
+                    }
+                }
+            };
+            PacketListener::emit_stats(&stats, &node);
+            PacketListener::set_state(&state, Some(&node), new_state);
         });
 
         Ok(())
     }
 }
 
+/// Packets of the events given to `PacketListener::stream`, in the order they were decoded
+pub struct PacketStream {
+    /// Dropped before the tokens, so a capture waiting for the queue stops holding the
+    /// subscriptions they remove themselves from
+    receiver: mpsc::Receiver<Delivery>,
+    _tokens: Vec<SubscriptionToken>,
+}
+
+impl Debug for PacketStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PacketStream")
+            .field("queued", &self.receiver.len())
+            .field("subscriptions", &self._tokens.len())
+            .finish()
+    }
+}
+
+impl PacketStream {
+    /// `None` once every subscription of the stream was removed with `PacketListener::unsubscribe`
+    pub async fn recv(&mut self) -> Option<Arc<Packet>> {
+        self.receiver.recv().await.map(|delivery| delivery.packet)
+    }
+
+    /// Must not be called from an async context
+    pub fn blocking_recv(&mut self) -> Option<Arc<Packet>> {
+        self.receiver
+            .blocking_recv()
+            .map(|delivery| delivery.packet)
+    }
+
+    pub fn try_recv(&mut self) -> Option<Arc<Packet>> {
+        self.receiver
+            .try_recv()
+            .ok()
+            .map(|delivery| delivery.packet)
+    }
+}
+
+impl futures_core::Stream for PacketStream {
+    type Item = Arc<Packet>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver
+            .poll_recv(cx)
+            .map(|delivery| delivery.map(|delivery| delivery.packet))
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum PacketListenerError {
-    #[error("Failed to open device")]
-    FailedToOpenDevice,
+    #[error("Failed to open device {0}: {1}")]
+    FailedToOpenDevice(String, #[source] pcap::Error),
     #[error("Invalid capture device")]
     InvalidCaptureDevice,
+    #[error("Failed to list capture devices: {0}")]
+    FailedToListDevices(#[source] pcap::Error),
+    #[error("No capture device found")]
+    NoDeviceFound,
+    #[error("Invalid capture filter {0}: {1}")]
+    InvalidFilter(String, #[source] pcap::Error),
+    #[error("Failed to open capture file {0}: {1}")]
+    FailedToOpenFile(PathBuf, #[source] pcap::Error),
+    #[error("Failed to record capture: {0}")]
+    FailedToRecord(#[source] std::io::Error),
 }
 
 #[cfg(test)]
 mod tests {
-    use std::path::Path;
+    use std::{fs, path::Path, sync::atomic::AtomicUsize};
 
     use super::*;
+    use crate::sniffer::{
+        offline::{frame_file, FIXTURE_CAPTURE},
+        parser::metadata::PacketDirection,
+        pipeline::{Flow, PacketFn, FILTER_PRIORITY, TRANSFORM_PRIORITY},
+    };
 
     #[test]
     fn test_packet_listener() {
         let mut listener = PacketListener::new();
 
-        assert_eq!(listener.subscriptions.lock().unwrap().len(), 0);
+        assert_eq!(listener.subscriptions.lock().unwrap().list.len(), 0);
 
         let listener_id = "test";
         let listener_fn = |_event: &Packet, _: &Node| {};
         let event = 0;
 
-        listener.subscribe(event.clone(), listener_id, listener_fn);
-        assert_eq!(listener.subscriptions.lock().unwrap().len(), 1);
+        let _token = listener.subscribe(event, listener_id, listener_fn);
+        assert_eq!(listener.subscriptions.lock().unwrap().list.len(), 1);
+        assert!(listener.has_subscriptions(&event, &ProtocolManager::new()));
+
+        listener.unsubscribe(event, listener_id);
+        assert_eq!(listener.subscriptions.lock().unwrap().list.len(), 0);
+        assert!(!listener.has_subscriptions(&event, &ProtocolManager::new()));
+    }
+
+    #[test]
+    fn test_subscription_fields() {
+        let mut listener = PacketListener::new();
+        let listener_fn = |_event: &Packet, _: &Node| {};
+        let protocol = ProtocolManager::new();
+        let selection = |listener: &PacketListener, event| {
+            PacketListener::_selection(
+                &mut listener.subscriptions.lock().unwrap(),
+                &protocol,
+                &event,
+            )
+        };
+
+        let _chat = listener.subscribe_fields(1, "chat", &["channel", "content"], listener_fn);
+        let _cli = listener.subscribe_fields(1, "cli", &["senderName"], listener_fn);
         assert_eq!(
-            listener
-                .subscriptions
-                .lock()
-                .unwrap()
-                .get(&event)
-                .unwrap()
-                .len(),
-            1
+            selection(&listener, 1),
+            FieldSelection::from_paths(["channel", "content", "senderName"])
         );
 
-        listener.unsubscribe(&event, listener_id);
-        assert_eq!(listener.subscriptions.lock().unwrap().len(), 1);
-        assert_eq!(
-            listener
-                .subscriptions
-                .lock()
-                .unwrap()
-                .get(&event)
+        let _all = listener.subscribe(1, "all", listener_fn);
+        assert!(selection(&listener, 1).is_all());
+        listener.unsubscribe(1, "all");
+        assert!(!selection(&listener, 1).is_all());
+    }
+
+    #[test]
+    fn test_subscription_token() {
+        let mut listener = PacketListener::new();
+        let count = |listener: &PacketListener| listener.subscriptions.lock().unwrap().list.len();
+
+        // Ids can be built at runtime, the handlers can own their state
+        let mut seen = Vec::new();
+        let token =
+            listener.subscribe(1, format!("tab-{}", 1), move |packet: &Packet, _: &Node| {
+                seen.push(packet.id);
+            });
+        let other = listener.subscribe(1, "tab-2", |_: &Packet, _: &Node| {});
+        assert_eq!(token.event(), &EventFilter::Id(1));
+        assert!(listener.has_subscriptions_for(1, "tab-1"));
+        assert_eq!(count(&listener), 2);
+
+        drop(token);
+        assert!(!listener.has_subscriptions_for(1, "tab-1"));
+        assert!(listener.has_subscriptions_for(1, "tab-2"));
+
+        other.detach();
+        assert_eq!(count(&listener), 1);
+        listener.unsubscribe(1, "tab-2");
+        assert_eq!(count(&listener), 0);
+
+        // The listener can go away before its tokens
+        let token = listener.subscribe(1, "late", |_: &Packet, _: &Node| {});
+        drop(listener);
+        drop(token);
+    }
+
+    #[test]
+    fn test_subscribe_many() {
+        let mut listener = PacketListener::new();
+        let tokens = listener.subscribe_many(
+            [1, 2],
+            "many",
+            FieldSelection::All,
+            |_: &Packet, _: &Node| {},
+        );
+        assert_eq!(tokens.len(), 2);
+
+        // One handler, one queue, so its packets can't be reordered
+        let subscriptions = listener.subscriptions.lock().unwrap();
+        assert_eq!(subscriptions.list.len(), 2);
+        assert!(subscriptions.list[0]
+            .queue
+            .same_channel(&subscriptions.list[1].queue));
+        drop(subscriptions);
+
+        drop(tokens);
+        assert_eq!(listener.subscriptions.lock().unwrap().list.len(), 0);
+    }
+
+    fn publish(listener: &PacketListener, protocol: &ProtocolManager, id: u16) {
+        let name = protocol.get_protocol(&id).map(|schema| schema.name.clone());
+        let packet = Packet {
+            id,
+            name: name.unwrap_or(format!("Message{}", id)),
+            direction: PacketDirection::In,
+            data: Default::default(),
+        };
+        PacketListener::_notify(
+            &mut listener.subscriptions.lock().unwrap(),
+            protocol,
+            Arc::new(packet),
+            &Weak::new(),
+            &listener.stats,
+        );
+    }
+
+    #[test]
+    fn test_stream() {
+        let mut listener = PacketListener::new();
+        let protocol = ProtocolManager::new();
+        let mut stream = listener.stream(
+            "stream",
+            [1, 2],
+            FieldSelection::All,
+            QUEUE_CAPACITY,
+            Overflow::Drop,
+        );
+        assert!(listener.has_subscriptions_for(1, "stream"));
+        assert!(listener.has_subscriptions_for(2, "stream"));
+
+        for id in [1, 3, 2] {
+            publish(&listener, &protocol, id);
+        }
+        assert_eq!(stream.try_recv().unwrap().id, 1);
+        assert_eq!(stream.try_recv().unwrap().id, 2);
+        assert!(stream.try_recv().is_none());
+
+        // Queued packets are still received once the subscriptions are removed
+        publish(&listener, &protocol, 2);
+        listener.unsubscribe(1, "stream");
+        listener.unsubscribe(2, "stream");
+        assert_eq!(stream.blocking_recv().unwrap().id, 2);
+        assert!(stream.blocking_recv().is_none());
+
+        let stream = listener.stream(
+            "dropped",
+            [1],
+            FieldSelection::All,
+            QUEUE_CAPACITY,
+            Overflow::Drop,
+        );
+        drop(stream);
+        assert!(!listener.has_subscriptions_for(1, "dropped"));
+    }
+
+    #[test]
+    fn test_queue_overflow() {
+        let mut listener = PacketListener::new();
+        let protocol = ProtocolManager::new();
+        let mut slow = listener.stream("slow", [1], FieldSelection::All, 2, Overflow::Drop);
+        let mut fast = listener.stream(
+            "fast",
+            [1],
+            FieldSelection::All,
+            QUEUE_CAPACITY,
+            Overflow::Drop,
+        );
+
+        // The capture is never blocked by a full queue
+        for _ in 0..5 {
+            publish(&listener, &protocol, 1);
+        }
+        let stats = listener.get_stats();
+        assert_eq!(stats.dropped_messages.get("slow"), Some(&3));
+        assert_eq!(stats.dropped_messages.get("fast"), None);
+        assert_eq!(stats.total_dropped_messages(), 3);
+
+        assert_eq!(std::iter::from_fn(|| slow.try_recv()).count(), 2);
+        assert_eq!(std::iter::from_fn(|| fast.try_recv()).count(), 5);
+    }
+
+    #[test]
+    fn test_queue_wait() {
+        let mut listener = PacketListener::new();
+        let protocol = ProtocolManager::new();
+        let mut stream = listener.stream("file", [1], FieldSelection::All, 1, Overflow::Wait);
+
+        thread::scope(|scope| {
+            scope.spawn(|| {
+                for _ in 0..5 {
+                    publish(&listener, &protocol, 1);
+                }
+            });
+            for _ in 0..5 {
+                assert_eq!(stream.blocking_recv().unwrap().id, 1);
+            }
+        });
+        assert_eq!(listener.get_stats().total_dropped_messages(), 0);
+    }
+
+    #[test]
+    fn test_event_filters() {
+        let mut listener = PacketListener::new();
+        let mut protocol = ProtocolManager::new();
+        protocol.init("tests/fixtures/").unwrap();
+        let id = |protocol: &ProtocolManager, name: &str| {
+            *protocol
+                .get_protocol_id_by_class(&name.to_string())
                 .unwrap()
-                .len(),
-            0
+        };
+        let chat = id(&protocol, "ChatServerMessage");
+        let death = id(&protocol, "GameActionFightDeathMessage");
+        let exchange = id(&protocol, "ExchangeAcceptMessage");
+
+        let filters = [
+            EventFilter::from_pattern("ChatServerMessage"),
+            EventFilter::from_pattern("Exchange*"),
+            EventFilter::Subclass("AbstractGameActionMessage".to_string()),
+        ];
+        let mut stream = listener.stream(
+            "names",
+            filters,
+            FieldSelection::All,
+            QUEUE_CAPACITY,
+            Overflow::Drop,
+        );
+        let mut all = listener.stream(
+            "all",
+            [EventFilter::All],
+            FieldSelection::All,
+            16,
+            Overflow::Drop,
         );
+        for id in [chat, death, exchange, id(&protocol, "BasicPingMessage")] {
+            publish(&listener, &protocol, id);
+        }
+        let names = std::iter::from_fn(|| stream.try_recv())
+            .map(|packet| packet.name.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [
+                "ChatServerMessage",
+                "GameActionFightDeathMessage",
+                "ExchangeAcceptMessage"
+            ]
+        );
+        assert_eq!(std::iter::from_fn(|| all.try_recv()).count(), 4);
+
+        // A new version of the game gives another id to the chat messages
+        let dir = std::env::temp_dir().join(format!("dtools-filters-{}", std::process::id()));
+        let events_dir = dir.join(crate::constants::EXTRACTOR_DIR);
+        fs::create_dir_all(&events_dir).unwrap();
+        let events_file = Path::new("tests/fixtures/")
+            .join(crate::constants::EXTRACTOR_DIR)
+            .join(crate::constants::EVENTS_FILE);
+        let mut events: Vec<serde_json::Value> =
+            serde_json::from_str(&fs::read_to_string(events_file).unwrap()).unwrap();
+        let new_chat = u16::MAX - 1;
+        for event in events.iter_mut() {
+            if event["class_name"] == "ChatServerMessage" {
+                event["id"] = serde_json::Value::from(new_chat.to_string());
+            }
+        }
+        let content = serde_json::to_string(&events).unwrap();
+        fs::write(events_dir.join(crate::constants::EVENTS_FILE), content).unwrap();
+        protocol.init(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        publish(&listener, &protocol, chat);
+        assert!(stream.try_recv().is_none());
+        publish(&listener, &protocol, new_chat);
+        assert_eq!(stream.try_recv().unwrap().id, new_chat);
+    }
+
+    #[test]
+    fn test_pipeline() {
+        let mut listener = PacketListener::new();
+        let mut protocol = ProtocolManager::new();
+        protocol.init("tests/fixtures/").unwrap();
+        let protocol = RwLock::new(protocol);
+
+        let mut stream = listener.stream(
+            "all",
+            [EventFilter::All],
+            FieldSelection::All,
+            1 << 16,
+            Overflow::Drop,
+        );
+        listener.add_stage(
+            FILTER_PRIORITY,
+            PacketFn::new("no-chat", |packet: &mut Packet| {
+                match packet.name == "ChatServerMessage" {
+                    true => Flow::Drop,
+                    false => Flow::Continue,
+                }
+            }),
+        );
+        listener.add_stage(
+            TRANSFORM_PRIORITY,
+            PacketFn::new("enrich", |packet: &mut Packet| {
+                let name = serde_json::Value::from(packet.name.clone());
+                packet.data.insert("name".to_string(), name);
+                Flow::Continue
+            }),
+        );
+
+        for message in frame_file(FIXTURE_CAPTURE).unwrap() {
+            let metadata = message.unwrap().metadata;
+            PacketListener::handle_metadata(
+                &listener.subscriptions,
+                &protocol,
+                &listener.pipeline,
+                &Weak::new(),
+                &listener.stats,
+                metadata,
+            );
+        }
+
+        let packets = std::iter::from_fn(|| stream.try_recv()).collect::<Vec<_>>();
+        assert!(!packets.is_empty());
+        for packet in packets {
+            assert_ne!(packet.name, "ChatServerMessage");
+            assert_eq!(packet.data["name"], packet.name.as_str());
+        }
+        let stats = listener.get_stats();
+        assert!(stats.filtered_messages["no-chat"] > 0);
+        assert!(!stats.filtered_messages.contains_key("enrich"));
+    }
+
+    /// Counts every framed message, and moves the chat messages to an unknown id
+    struct Retag {
+        seen: Arc<AtomicUsize>,
+        chat: EventId,
+    }
+
+    impl Stage for Retag {
+        fn name(&self) -> &str {
+            "retag"
+        }
+
+        fn on_message(&mut self, metadata: &mut PacketMetadata) -> Flow {
+            self.seen.fetch_add(1, Ordering::Relaxed);
+            if metadata.id == self.chat {
+                metadata.id = u16::MAX;
+            }
+            Flow::Continue
+        }
+    }
+
+    #[test]
+    fn test_message_stages() {
+        let mut listener = PacketListener::new();
+        let mut protocol = ProtocolManager::new();
+        protocol.init("tests/fixtures/").unwrap();
+        let chat = *protocol
+            .get_protocol_id_by_class(&"ChatServerMessage".to_string())
+            .unwrap();
+        let protocol = RwLock::new(protocol);
+
+        let mut stream =
+            listener.stream("chat", [chat], FieldSelection::All, 1 << 16, Overflow::Drop);
+        let seen = Arc::new(AtomicUsize::new(0));
+        listener.add_stage(
+            TRANSFORM_PRIORITY,
+            Retag {
+                seen: seen.clone(),
+                chat,
+            },
+        );
+
+        let mut framed = 0;
+        for message in frame_file(FIXTURE_CAPTURE).unwrap() {
+            framed += 1;
+            PacketListener::handle_metadata(
+                &listener.subscriptions,
+                &protocol,
+                &listener.pipeline,
+                &Weak::new(),
+                &listener.stats,
+                message.unwrap().metadata,
+            );
+        }
+
+        // The messages without listeners go through the stages too
+        assert_eq!(seen.load(Ordering::Relaxed), framed);
+        // The retagged messages have no listener, so they are not decoded
+        assert!(stream.try_recv().is_none());
+        assert_eq!(listener.get_stats().total_decode_failures(), 0);
+    }
+
+    #[test]
+    fn test_capture_state() {
+        let mut listener = PacketListener::new();
+        assert_eq!(listener.get_state(), CaptureState::Idle);
+        assert!(!listener.is_running());
+
+        assert!(matches!(
+            listener.run(),
+            Err(PacketListenerError::InvalidCaptureDevice)
+        ));
+
+        listener.stop();
+        assert_eq!(listener.get_state(), CaptureState::Idle);
     }
 
     #[tokio::test]
     async fn test_with_capture() {
-        let cap = Capture::from_file("tests/fixtures/cap.pcap").unwrap();
+        let cap = Capture::from_file(FIXTURE_CAPTURE).unwrap();
         let path = "tests/fixtures/".to_string();
         let path = Path::new(&path);
         let node = Node::new(path, None, false).await;
@@ -281,25 +1432,15 @@ mod tests {
             panic!("Failed to create node: {:?}", err);
         }
         let node = node.unwrap();
-        let listener_fn = |event: &Packet, node: &Node| {
-            let key = event.id.to_string();
-            let mut store = node.store.lock().unwrap();
-            match store.get(&key) {
-                Some(count) => {
-                    let count = count.parse::<u32>().unwrap();
-                    let count = count + 1;
-                    let count = count.to_string();
-                    store.insert(key, count);
-                }
-                None => {
-                    store.insert(key, "1".to_string());
-                }
-            }
+        let counts = Arc::new(Mutex::new(HashMap::<EventId, u32>::new()));
+        let listener_counts = counts.clone();
+        let listener_fn = move |event: &Packet, _: &Node| {
+            *listener_counts.lock().unwrap().entry(event.id).or_default() += 1;
         };
 
         let mut listener = node.packet_listener.lock().unwrap();
         let id = "test";
-        listener.subscribe(1338, id, listener_fn);
+        let _token = listener.subscribe(1338, id, listener_fn);
 
         let res = listener.run_with_capture(cap.into());
         if let Err(err) = res {
@@ -307,6 +1448,10 @@ mod tests {
         }
 
         tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
-        info!("Store: {:?}", node.store.lock().unwrap());
+        info!("Counts: {:?}", counts.lock().unwrap());
+
+        let stats = listener.get_stats();
+        assert!(stats.frames > 0);
+        assert!(stats.messages_framed > 0);
     }
 }
@@ -0,0 +1,303 @@
+use std::{
+    collections::VecDeque,
+    path::{Path, PathBuf},
+};
+
+use pcap::{Capture, Linktype};
+use thiserror::Error;
+
+use super::{
+    config::NetworkConfig,
+    parser::{
+        framer::{frame_timestamp, MessageFramer},
+        metadata::PacketMetadata,
+        packet::{Packet, PacketError, PacketParser},
+    },
+    protocol::protocol::{EventId, ProtocolManager},
+    stats::CaptureStats,
+};
+
+/// A captured frame, starting with its link layer header
+#[derive(Debug, Clone)]
+pub struct Frame {
+    /// Microseconds since the unix epoch
+    pub timestamp: u64,
+    pub data: Vec<u8>,
+}
+
+/// A game message cut from the stream, not decoded yet
+#[derive(Debug, Clone)]
+pub struct FramedMessage {
+    /// Timestamp of the frame that completed the message
+    pub timestamp: u64,
+    pub metadata: PacketMetadata,
+}
+
+#[derive(Debug, Clone)]
+pub struct DecodedPacket {
+    /// Timestamp of the frame that completed the message
+    pub timestamp: u64,
+    pub packet: Packet,
+}
+
+/// Capture used by the tests
+#[cfg(test)]
+pub(crate) const FIXTURE_CAPTURE: &str = "tests/fixtures/cap.pcap";
+
+type Frames<'a> = Box<dyn Iterator<Item = Result<Frame, pcap::Error>> + 'a>;
+
+/// Cuts the game messages of captured frames, messages are returned in capture order
+pub struct OfflineFramer<'a> {
+    frames: Frames<'a>,
+    link_type: Linktype,
+    framer: MessageFramer,
+    stats: CaptureStats,
+    /// Messages completed by the last frame that were not returned yet
+    pending: VecDeque<FramedMessage>,
+    done: bool,
+}
+
+/// Decodes captured frames without a `Node`, messages are returned in capture order
+pub struct OfflineDecoder<'a> {
+    messages: OfflineFramer<'a>,
+    protocol: &'a ProtocolManager,
+}
+
+/// Decode every game message of a capture file (pcap/pcapng)
+pub fn decode_file<'a>(
+    path: impl AsRef<Path>,
+    protocol: &'a ProtocolManager,
+) -> Result<OfflineDecoder<'a>, DecodeError> {
+    Ok(OfflineDecoder::new(frame_file(path)?, protocol))
+}
+
+/// Cut the game messages of a capture file (pcap/pcapng) without decoding them
+pub fn frame_file(path: impl AsRef<Path>) -> Result<OfflineFramer<'static>, DecodeError> {
+    let path = path.as_ref();
+    let mut cap = Capture::from_file(path)
+        .map_err(|err| DecodeError::FailedToOpenFile(path.to_path_buf(), err))?;
+    let link_type = cap.get_datalink();
+
+    let frames = std::iter::from_fn(move || match cap.next_packet() {
+        Ok(packet) => Some(Ok(Frame {
+            timestamp: frame_timestamp(packet.header),
+            data: packet.data.to_vec(),
+        })),
+        Err(pcap::Error::NoMorePackets) => None,
+        Err(err) => Some(Err(err)),
+    });
+    Ok(OfflineFramer::new(Box::new(frames), link_type))
+}
+
+/// Decode every game message of `frames`, which all use the `link_type` link layer
+pub fn decode_frames<'a>(
+    frames: impl IntoIterator<Item = Frame> + 'a,
+    link_type: Linktype,
+    protocol: &'a ProtocolManager,
+) -> OfflineDecoder<'a> {
+    let frames = frames.into_iter().map(Ok);
+    let messages = OfflineFramer::new(Box::new(frames), link_type);
+    OfflineDecoder::new(messages, protocol)
+}
+
+impl<'a> OfflineFramer<'a> {
+    fn new(frames: Frames<'a>, link_type: Linktype) -> Self {
+        OfflineFramer {
+            frames,
+            link_type,
+            framer: MessageFramer::new(link_type, NetworkConfig::default()),
+            stats: CaptureStats::default(),
+            pending: VecDeque::new(),
+            done: false,
+        }
+    }
+
+    /// Game server ports used to tell apart server and client messages, the default config
+    /// is used otherwise
+    pub fn with_network(mut self, network: NetworkConfig) -> Self {
+        self.framer = MessageFramer::new(self.link_type, network);
+        self
+    }
+
+    pub fn stats(&self) -> &CaptureStats {
+        &self.stats
+    }
+}
+
+impl Iterator for OfflineFramer<'_> {
+    type Item = Result<FramedMessage, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(message) = self.pending.pop_front() {
+                return Some(Ok(message));
+            }
+            if self.done {
+                return None;
+            }
+
+            match self.frames.next() {
+                Some(Ok(frame)) => {
+                    let messages =
+                        self.framer
+                            .push_frame(&frame.data, frame.timestamp, &mut self.stats);
+                    self.pending
+                        .extend(messages.into_iter().map(|metadata| FramedMessage {
+                            timestamp: frame.timestamp,
+                            metadata,
+                        }));
+                }
+                Some(Err(err)) => {
+                    self.done = true;
+                    return Some(Err(DecodeError::FailedToReadFrame(err)));
+                }
+                None => {
+                    self.done = true;
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+impl<'a> OfflineDecoder<'a> {
+    fn new(messages: OfflineFramer<'a>, protocol: &'a ProtocolManager) -> Self {
+        OfflineDecoder { messages, protocol }
+    }
+
+    /// See `OfflineFramer::with_network`
+    pub fn with_network(mut self, network: NetworkConfig) -> Self {
+        self.messages = self.messages.with_network(network);
+        self
+    }
+
+    pub fn stats(&self) -> &CaptureStats {
+        self.messages.stats()
+    }
+
+    fn decode(&mut self, message: FramedMessage) -> Result<DecodedPacket, DecodeError> {
+        let FramedMessage {
+            timestamp,
+            metadata,
+        } = message;
+        let stats = &mut self.messages.stats;
+        let mut parser = PacketParser::from_metadata(&metadata);
+        match parser.parse(self.protocol) {
+            Ok(packet) => {
+                stats.messages_decoded += 1;
+                Ok(DecodedPacket { timestamp, packet })
+            }
+            Err(source) => {
+                stats.record_decode_failure(metadata.id);
+                Err(DecodeError::FailedToDecode {
+                    timestamp,
+                    id: metadata.id,
+                    source,
+                })
+            }
+        }
+    }
+}
+
+impl Iterator for OfflineDecoder<'_> {
+    type Item = Result<DecodedPacket, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.messages.next()? {
+            Ok(message) => Some(self.decode(message)),
+            Err(err) => Some(Err(err)),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum DecodeError {
+    #[error("Failed to open capture file {0}: {1}")]
+    FailedToOpenFile(PathBuf, #[source] pcap::Error),
+    #[error("Failed to read frame: {0}")]
+    FailedToReadFrame(#[source] pcap::Error),
+    #[error("Failed to decode message {id}: {source}")]
+    FailedToDecode {
+        timestamp: u64,
+        id: EventId,
+        #[source]
+        source: PacketError,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn protocol() -> ProtocolManager {
+        let mut protocol = ProtocolManager::new();
+        protocol.init("tests/fixtures/").unwrap();
+        protocol
+    }
+
+    #[test]
+    fn test_decode_frames() {
+        // ChatServerMessage sent by the server, split over two frames
+        let frame = "9c760e4f1efc8c97ea31a276080045000078ca9740003906152eac41f3a5c0a8012b15b3c5b016b74d1cdf5e09cf801800083eaa00000101080a96b2b20290e8d54514614105000e56656e6420737475666620616972660953cd0008387a6d71616d32654231d39501260000001253616e637475732d42656e65646963747573000002e0ba5b";
+        let frame = (0..frame.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&frame[i..i + 2], 16).unwrap())
+            .collect::<Vec<u8>>();
+        // 14 (ethernet) + 20 (ipv4) + 32 (tcp)
+        let payload_start = 66;
+        let split = payload_start + 10;
+
+        let mut first = frame[..split].to_vec();
+        let total_length = (split - 14) as u16;
+        first[16..18].copy_from_slice(&total_length.to_be_bytes());
+
+        let mut second = frame[..payload_start].to_vec();
+        second.extend_from_slice(&frame[split..]);
+        let total_length = (second.len() - 14) as u16;
+        second[16..18].copy_from_slice(&total_length.to_be_bytes());
+        let seq = u32::from_be_bytes(second[38..42].try_into().unwrap()) + 10;
+        second[38..42].copy_from_slice(&seq.to_be_bytes());
+
+        let frames = vec![
+            Frame {
+                timestamp: 1,
+                data: first,
+            },
+            Frame {
+                timestamp: 2,
+                data: second,
+            },
+        ];
+
+        let protocol = protocol();
+        let mut decoder = decode_frames(frames, Linktype::ETHERNET, &protocol);
+        let decoded = decoder.next().unwrap().unwrap();
+        assert_eq!(decoded.timestamp, 2);
+        assert_eq!(decoded.packet.name, "ChatServerMessage");
+        assert!(decoder.next().is_none());
+
+        let stats = decoder.stats();
+        assert_eq!(stats.frames, 2);
+        // Only the first frame ends in the middle of a message
+        assert_eq!(stats.incomplete, 1);
+        assert_eq!(stats.messages_framed, 1);
+        assert_eq!(stats.messages_decoded, 1);
+    }
+
+    #[test]
+    fn test_decode_file() {
+        let protocol = protocol();
+        let decoder = decode_file(FIXTURE_CAPTURE, &protocol).unwrap();
+
+        let packets = decoder.filter_map(|res| res.ok()).collect::<Vec<_>>();
+        assert!(!packets.is_empty());
+        assert!(packets
+            .windows(2)
+            .all(|pair| pair[0].timestamp <= pair[1].timestamp));
+
+        assert!(matches!(
+            decode_file("tests/fixtures/missing.pcap", &protocol),
+            Err(DecodeError::FailedToOpenFile(..))
+        ));
+    }
+}
@@ -1,3 +1,8 @@
+pub mod encoder;
+pub mod framer;
 pub mod metadata;
 pub mod packet;
+pub mod selection;
+pub mod stream;
 pub mod wrapper;
+pub mod writer;
@@ -0,0 +1,534 @@
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+use crate::sniffer::protocol::{
+    plan::{DecodingPlan, PlanFieldName, PlanStep, PlanType},
+    protocol::{EventId, EventName, FieldName, ProtocolManager, ProtocolVarType, VectorLength},
+};
+
+use super::{
+    metadata::{PacketDirection, PacketMetadata},
+    packet::{Packet, BOOLEANS_PER_BYTE, TYPE_NAME_KEY},
+    writer::{DataWriter, WriteError},
+};
+
+/// Largest content that fits in the 3 bytes of the biggest size type
+const MAX_CONTENT_SIZE: usize = 0xff_ffff;
+
+/// Inverse of `PacketParser`, follows the same decoding plans so that decoding the output gives
+/// the packet back
+pub struct PacketEncoder<'a> {
+    protocol_manager: &'a ProtocolManager,
+}
+
+impl<'a> PacketEncoder<'a> {
+    pub fn new(protocol_manager: &'a ProtocolManager) -> Self {
+        PacketEncoder { protocol_manager }
+    }
+
+    /// Framed message, client messages get an instance id of 0
+    pub fn encode(&self, packet: &Packet) -> Result<Vec<u8>, EncodeError> {
+        self.encode_metadata(packet)?.to_bytes()
+    }
+
+    pub fn encode_metadata(&self, packet: &Packet) -> Result<PacketMetadata, EncodeError> {
+        let data = self.encode_content(packet)?;
+        Ok(PacketMetadata {
+            size: data.len() as u32,
+            data,
+            id: packet.id,
+            direction: packet.direction,
+            instance_id: match packet.direction {
+                PacketDirection::Out => Some(0),
+                _ => None,
+            },
+        })
+    }
+
+    /// Content of the message, without its header
+    pub fn encode_content(&self, packet: &Packet) -> Result<Vec<u8>, EncodeError> {
+        let plan = self
+            .protocol_manager
+            .get_plan(&packet.id)
+            .ok_or(EncodeError::UnknownPacketType(packet.id))?;
+        let mut writer = DataWriter::new();
+        self.encode_plan(&mut writer, plan, &packet.data)?;
+        Ok(writer.into_inner())
+    }
+
+    fn encode_plan(
+        &self,
+        writer: &mut DataWriter,
+        plan: &DecodingPlan,
+        data: &Map<String, Value>,
+    ) -> Result<(), EncodeError> {
+        for step in &plan.steps {
+            match step {
+                PlanStep::PackedBooleans(names) => {
+                    write_packed_booleans(writer, &plan.name, names, data)?
+                }
+                PlanStep::Field(name, plan_type) => {
+                    let value = data.get(name.as_ref()).ok_or_else(|| {
+                        EncodeError::MissingField(plan.name.clone(), name.to_string())
+                    })?;
+                    self.encode_type(writer, plan_type, value)?;
+                }
+                PlanStep::UnknownParent(parent) => {
+                    return Err(EncodeError::UnknownClass(parent.clone()))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn encode_type(
+        &self,
+        writer: &mut DataWriter,
+        plan_type: &PlanType,
+        value: &Value,
+    ) -> Result<(), EncodeError> {
+        match plan_type {
+            PlanType::Primitive(var_type) => write_primitive(writer, var_type, value),
+            PlanType::Class(id) => {
+                let plan = self
+                    .protocol_manager
+                    .get_plan(id)
+                    .ok_or(EncodeError::UnknownTypeId(*id))?;
+                self.encode_plan(writer, plan, as_object(value)?)
+            }
+            PlanType::TypeId(base) => {
+                let object = as_object(value)?;
+                let plan = self.polymorphic_plan(object, *base)?;
+                writer.write_unsigned_short(plan.id);
+                self.encode_plan(writer, plan, object)
+            }
+            PlanType::Vector(vector) => {
+                let values = value
+                    .as_array()
+                    .ok_or_else(|| invalid_value("array", value))?;
+                match &vector.length {
+                    VectorLength::Fixed(length) if *length != values.len() => {
+                        return Err(EncodeError::WrongLength {
+                            expected: *length,
+                            found: values.len(),
+                        })
+                    }
+                    VectorLength::Fixed(_) => {}
+                    VectorLength::Prefixed(length_type) => {
+                        write_length(writer, length_type, values.len())?
+                    }
+                }
+                for value in values {
+                    self.encode_type(writer, &vector.types, value)?;
+                }
+                Ok(())
+            }
+            PlanType::Unresolved(name) => Err(EncodeError::UnknownClass(name.clone())),
+        }
+    }
+
+    /// The concrete class is named by `TYPE_NAME_KEY`, as the parser writes it
+    fn polymorphic_plan(
+        &self,
+        object: &Map<String, Value>,
+        base: EventId,
+    ) -> Result<&'a DecodingPlan, EncodeError> {
+        let name = object
+            .get(TYPE_NAME_KEY)
+            .and_then(Value::as_str)
+            .ok_or(EncodeError::MissingTypeName(base))?;
+        let plan = self
+            .protocol_manager
+            .get_protocol_id_by_class(&name.to_string())
+            .and_then(|id| self.protocol_manager.get_plan(id))
+            .ok_or_else(|| EncodeError::UnknownClass(name.to_string()))?;
+        if !plan.is_subclass_of(base) {
+            return Err(EncodeError::UnexpectedClass(name.to_string(), base));
+        }
+        Ok(plan)
+    }
+}
+
+impl PacketMetadata {
+    /// Frame the content as `from_slice` reads it, with the smallest size type that fits
+    pub fn to_bytes(&self) -> Result<Vec<u8>, EncodeError> {
+        let size = self.data.len();
+        let size_type: u16 = match size {
+            0 => 0,
+            1..=0xff => 1,
+            0x100..=0xffff => 2,
+            0x1_0000..=MAX_CONTENT_SIZE => 3,
+            _ => return Err(EncodeError::MessageTooLarge(size)),
+        };
+        if self.id > u16::MAX >> 2 {
+            return Err(EncodeError::InvalidMessageId(self.id));
+        }
+
+        let mut writer = DataWriter::new();
+        writer.write_unsigned_short(self.id << 2 | size_type);
+        if self.direction == PacketDirection::Out {
+            writer.write_unsigned_int(self.instance_id.unwrap_or_default());
+        }
+        writer.write_bytes(&(size as u32).to_be_bytes()[4 - size_type as usize..]);
+        writer.write_bytes(&self.data);
+        Ok(writer.into_inner())
+    }
+}
+
+fn as_object(value: &Value) -> Result<&Map<String, Value>, EncodeError> {
+    value
+        .as_object()
+        .ok_or_else(|| invalid_value("object", value))
+}
+
+fn invalid_value(expected: impl ToString, value: &Value) -> EncodeError {
+    EncodeError::InvalidValue {
+        expected: expected.to_string(),
+        value: value.clone(),
+    }
+}
+
+fn write_packed_booleans(
+    writer: &mut DataWriter,
+    class: &EventName,
+    names: &[PlanFieldName],
+    data: &Map<String, Value>,
+) -> Result<(), EncodeError> {
+    for chunk in names.chunks(BOOLEANS_PER_BYTE) {
+        let mut flags = 0u8;
+        for (bit, name) in chunk.iter().enumerate() {
+            let value = data
+                .get(name.as_ref())
+                .ok_or_else(|| EncodeError::MissingField(class.clone(), name.to_string()))?;
+            if value
+                .as_bool()
+                .ok_or_else(|| invalid_value(ProtocolVarType::Boolean, value))?
+            {
+                flags |= 1 << bit;
+            }
+        }
+        writer.write_byte(flags);
+    }
+    Ok(())
+}
+
+/// Integer of `var_type` that fits in `T`
+fn integer<T: TryFrom<i128>>(var_type: &ProtocolVarType, value: &Value) -> Result<T, EncodeError> {
+    let number = match (value.as_i64(), value.as_u64()) {
+        (Some(number), _) => number as i128,
+        (_, Some(number)) => number as i128,
+        _ => return Err(invalid_value(var_type, value)),
+    };
+    T::try_from(number).map_err(|_| invalid_value(var_type, value))
+}
+
+/// `null` is written as NaN, the parser gives `null` back for it
+fn float(var_type: &ProtocolVarType, value: &Value) -> Result<f64, EncodeError> {
+    match value {
+        Value::Null => Ok(f64::NAN),
+        value => value.as_f64().ok_or_else(|| invalid_value(var_type, value)),
+    }
+}
+
+fn write_primitive(
+    writer: &mut DataWriter,
+    var_type: &ProtocolVarType,
+    value: &Value,
+) -> Result<(), EncodeError> {
+    match var_type {
+        ProtocolVarType::String => writer.write_utf(
+            value
+                .as_str()
+                .ok_or_else(|| invalid_value(var_type, value))?,
+        )?,
+        ProtocolVarType::VarInt | ProtocolVarType::VarUhInt => {
+            writer.write_var_int(integer(var_type, value)?)
+        }
+        ProtocolVarType::VarLong | ProtocolVarType::VarUhLong => {
+            writer.write_var_long(integer(var_type, value)?)
+        }
+        ProtocolVarType::VarShort | ProtocolVarType::VarUhShort => {
+            writer.write_var_short(integer(var_type, value)?)
+        }
+        ProtocolVarType::Byte => writer.write_signed_byte(integer(var_type, value)?),
+        ProtocolVarType::UnsignedByte => writer.write_byte(integer(var_type, value)?),
+        ProtocolVarType::Short => writer.write_short(integer(var_type, value)?),
+        ProtocolVarType::UnsignedShort => writer.write_unsigned_short(integer(var_type, value)?),
+        ProtocolVarType::Int => writer.write_int(integer(var_type, value)?),
+        ProtocolVarType::UnsignedInt => writer.write_unsigned_int(integer(var_type, value)?),
+        ProtocolVarType::Long => writer.write_long(integer(var_type, value)?),
+        ProtocolVarType::UnsignedLong => writer.write_unsigned_long(integer(var_type, value)?),
+        ProtocolVarType::Float => writer.write_float(float(var_type, value)? as f32),
+        ProtocolVarType::Double => writer.write_double(float(var_type, value)?),
+        ProtocolVarType::None => {}
+        ProtocolVarType::Boolean => writer.write_boolean(
+            value
+                .as_bool()
+                .ok_or_else(|| invalid_value(var_type, value))?,
+        ),
+        _ => return Err(EncodeError::UnsupportedType(var_type.clone())),
+    }
+    Ok(())
+}
+
+fn write_length(
+    writer: &mut DataWriter,
+    length_type: &ProtocolVarType,
+    length: usize,
+) -> Result<(), EncodeError> {
+    let too_long = || EncodeError::VectorTooLong(length, length_type.clone());
+    match length_type {
+        ProtocolVarType::Byte | ProtocolVarType::UnsignedByte => {
+            writer.write_byte(u8::try_from(length).map_err(|_| too_long())?)
+        }
+        ProtocolVarType::Short | ProtocolVarType::UnsignedShort => {
+            writer.write_unsigned_short(u16::try_from(length).map_err(|_| too_long())?)
+        }
+        ProtocolVarType::Int | ProtocolVarType::UnsignedInt => {
+            writer.write_unsigned_int(u32::try_from(length).map_err(|_| too_long())?)
+        }
+        ProtocolVarType::VarShort | ProtocolVarType::VarUhShort => {
+            writer.write_var_short(u16::try_from(length).map_err(|_| too_long())?)
+        }
+        ProtocolVarType::VarInt | ProtocolVarType::VarUhInt => {
+            writer.write_var_int(u32::try_from(length).map_err(|_| too_long())?)
+        }
+        _ => return Err(EncodeError::UnsupportedType(length_type.clone())),
+    }
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+pub enum EncodeError {
+    #[error("Unknown packet type {0}")]
+    UnknownPacketType(EventId),
+    #[error("Unknown type id {0}")]
+    UnknownTypeId(EventId),
+    #[error("Unknown class {0}")]
+    UnknownClass(EventName),
+    #[error("Class {0} does not inherit from type id {1}")]
+    UnexpectedClass(EventName, EventId),
+    #[error("Missing `{}` to name the class of a type id {0} value", TYPE_NAME_KEY)]
+    MissingTypeName(EventId),
+    #[error("Missing field {0}.{1}")]
+    MissingField(EventName, FieldName),
+    #[error("Expected {expected}, found {value}")]
+    InvalidValue { expected: String, value: Value },
+    #[error("Expected {expected} elements, found {found}")]
+    WrongLength { expected: usize, found: usize },
+    #[error("Vector of {0} elements does not fit a {1} length")]
+    VectorTooLong(usize, ProtocolVarType),
+    #[error("Type {0} can't be encoded")]
+    UnsupportedType(ProtocolVarType),
+    #[error("Message id {0} does not fit in the header")]
+    InvalidMessageId(u16),
+    #[error("Message of {0} bytes is too large")]
+    MessageTooLarge(usize),
+    #[error("Error while writing attribute: {0}")]
+    WriteError(#[from] WriteError),
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::sniffer::{
+        offline::{frame_file, FIXTURE_CAPTURE},
+        parser::{packet::PacketParser, wrapper::DataWrapper},
+    };
+
+    fn protocol() -> ProtocolManager {
+        let mut protocol_manager = ProtocolManager::new();
+        protocol_manager.init("tests/fixtures/").unwrap();
+        protocol_manager
+    }
+
+    fn decode(protocol_manager: &ProtocolManager, id: u16, data: Vec<u8>) -> Packet {
+        let mut parser = PacketParser::new(id, DataWrapper::new(data));
+        let packet = parser.parse(protocol_manager).unwrap();
+        assert!(parser.data.get_remaining().is_empty());
+        packet
+    }
+
+    /// Value with every field set, vectors get one element until `depth` runs out
+    fn sample(protocol_manager: &ProtocolManager, plan: &DecodingPlan, depth: usize) -> Value {
+        let mut data = Map::new();
+        for step in &plan.steps {
+            match step {
+                PlanStep::PackedBooleans(names) => {
+                    for (i, name) in names.iter().enumerate() {
+                        data.insert(name.to_string(), Value::Bool(i % 3 == 0));
+                    }
+                }
+                PlanStep::Field(name, plan_type) => {
+                    let value = sample_type(protocol_manager, plan_type, depth);
+                    data.insert(name.to_string(), value);
+                }
+                PlanStep::UnknownParent(_) => {}
+            }
+        }
+        Value::Object(data)
+    }
+
+    fn sample_type(
+        protocol_manager: &ProtocolManager,
+        plan_type: &PlanType,
+        depth: usize,
+    ) -> Value {
+        match plan_type {
+            PlanType::Primitive(ProtocolVarType::String) => json!("abc"),
+            PlanType::Primitive(ProtocolVarType::Float | ProtocolVarType::Double) => json!(1.5),
+            PlanType::Primitive(ProtocolVarType::Boolean) => json!(true),
+            PlanType::Primitive(ProtocolVarType::None) => Value::Null,
+            PlanType::Primitive(ProtocolVarType::Byte) => json!(-3),
+            PlanType::Primitive(_) => json!(200),
+            PlanType::Class(id) => sample(
+                protocol_manager,
+                protocol_manager.get_plan(id).unwrap(),
+                depth,
+            ),
+            PlanType::TypeId(id) => {
+                let plan = protocol_manager.get_plan(id).unwrap();
+                let mut value = sample(protocol_manager, plan, depth);
+                value[TYPE_NAME_KEY] = json!(plan.name);
+                value
+            }
+            PlanType::Vector(vector) => {
+                let length = match vector.length {
+                    VectorLength::Fixed(length) => length,
+                    VectorLength::Prefixed(_) => (depth > 0) as usize,
+                };
+                let values = (0..length)
+                    .map(|_| sample_type(protocol_manager, &vector.types, depth.saturating_sub(1)))
+                    .collect();
+                Value::Array(values)
+            }
+            PlanType::Unresolved(_) => Value::Null,
+        }
+    }
+
+    #[test]
+    fn test_round_trip_schemas() {
+        let protocol_manager = protocol();
+        let encoder = PacketEncoder::new(&protocol_manager);
+
+        let mut encoded = 0;
+        for id in protocol_manager.ids() {
+            let plan = protocol_manager.get_plan(id).unwrap();
+            let Value::Object(data) = sample(&protocol_manager, plan, 2) else {
+                unreachable!()
+            };
+            let packet = Packet {
+                id: *id,
+                name: plan.name.clone(),
+                direction: PacketDirection::In,
+                data,
+            };
+            let bytes = encoder.encode(&packet).unwrap();
+
+            let metadata = PacketMetadata::from_buffer(bytes).unwrap();
+            assert_eq!(metadata.id, *id);
+            let decoded = decode(&protocol_manager, *id, metadata.data);
+            assert_eq!(decoded.name, packet.name);
+            assert_eq!(decoded.data, packet.data, "{}", packet.name);
+            encoded += 1;
+        }
+        assert!(encoded > 0);
+    }
+
+    #[test]
+    fn test_round_trip_capture() {
+        let protocol_manager = protocol();
+        let encoder = PacketEncoder::new(&protocol_manager);
+
+        let mut encoded = 0;
+        for message in frame_file(FIXTURE_CAPTURE).unwrap() {
+            let metadata = message.unwrap().metadata;
+            let mut parser = PacketParser::from_metadata(&metadata);
+            let Ok(packet) = parser.parse(&protocol_manager) else {
+                continue;
+            };
+            // Some messages carry bytes the decoder leaves unread
+            let consumed = metadata.data.len() - parser.data.remaining();
+            let content = encoder.encode_content(&packet).unwrap();
+            assert_eq!(content, metadata.data[..consumed], "{}", packet.name);
+            encoded += 1;
+        }
+        assert!(encoded > 0);
+    }
+
+    #[test]
+    fn test_framing() {
+        for (size, header_size) in [(0, 2), (1, 3), (0xff, 3), (0x100, 4), (0x1_0000, 5)] {
+            let metadata = PacketMetadata {
+                data: vec![7; size],
+                id: 1650,
+                size: size as u32,
+                direction: PacketDirection::In,
+                instance_id: None,
+            };
+            let bytes = metadata.to_bytes().unwrap();
+            assert_eq!(bytes.len(), header_size + size);
+            let (framed, used) = PacketMetadata::from_slice(&bytes, PacketDirection::In).unwrap();
+            assert_eq!(
+                (framed.id, framed.data, used),
+                (1650, metadata.data, bytes.len())
+            );
+        }
+
+        let metadata = PacketMetadata {
+            data: vec![0xff],
+            id: 1,
+            size: 1,
+            direction: PacketDirection::Out,
+            instance_id: Some(42),
+        };
+        assert_eq!(
+            metadata.to_bytes().unwrap(),
+            [0x00, 0x05, 0x00, 0x00, 0x00, 0x2a, 0x01, 0xff]
+        );
+
+        let metadata = PacketMetadata {
+            data: vec![0; MAX_CONTENT_SIZE + 1],
+            ..metadata
+        };
+        assert!(matches!(
+            metadata.to_bytes(),
+            Err(EncodeError::MessageTooLarge(_))
+        ));
+    }
+
+    #[test]
+    fn test_encode_errors() {
+        let protocol_manager = protocol();
+        let encoder = PacketEncoder::new(&protocol_manager);
+        let packet = |data: Value| Packet {
+            id: 1650,
+            name: "CompassUpdateMessage".to_string(),
+            direction: PacketDirection::In,
+            data: data.as_object().unwrap().clone(),
+        };
+
+        // CompassUpdateMessage { type: Byte, coords: TypeId<MapCoordinates> }
+        let coords = json!({ TYPE_NAME_KEY: "MapCoordinatesAndId", "worldX": 4, "worldY": 7, "mapId": 1234.0 });
+        let bytes = encoder
+            .encode_content(&packet(json!({ "type": 3, "coords": coords })))
+            .unwrap();
+        assert_eq!(&bytes[..3], [3, 0x25, 0xc2]);
+
+        assert!(matches!(
+            encoder.encode_content(&packet(json!({ "type": 3 }))),
+            Err(EncodeError::MissingField(_, field)) if field == "coords"
+        ));
+        assert!(matches!(
+            encoder.encode_content(&packet(json!({ "type": 300, "coords": coords }))),
+            Err(EncodeError::InvalidValue { .. })
+        ));
+        let coords = json!({ TYPE_NAME_KEY: "ServerSessionConstantString", "id": 1, "value": "a" });
+        assert!(matches!(
+            encoder.encode_content(&packet(json!({ "type": 3, "coords": coords }))),
+            Err(EncodeError::UnexpectedClass(..))
+        ));
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use pcap::Linktype;
+use tracing::warn;
+
+use crate::sniffer::{config::NetworkConfig, stats::CaptureStats};
+
+use super::{
+    metadata::{FlowKey, PacketHeader, PacketMetadata, ParseResult},
+    stream::StreamReassembler,
+    wrapper::DataWrapper,
+};
+
+/// Timestamp of a captured frame in microseconds since the unix epoch
+pub fn frame_timestamp(header: &pcap::PacketHeader) -> u64 {
+    (header.ts.tv_sec as u64) * 1_000_000 + header.ts.tv_usec as u64
+}
+
+/// Turns captured frames into game messages: link/IP/TCP headers, stream reassembly
+/// then message framing, each connection keeps its own partial message
+#[derive(Debug)]
+pub struct MessageFramer {
+    link_type: Linktype,
+    network: NetworkConfig,
+    reassembler: StreamReassembler,
+    buffers: HashMap<FlowKey, DataWrapper>,
+}
+
+impl MessageFramer {
+    pub fn new(link_type: Linktype, network: NetworkConfig) -> Self {
+        MessageFramer {
+            link_type,
+            network,
+            reassembler: StreamReassembler::new(),
+            buffers: HashMap::new(),
+        }
+    }
+
+    /// Messages completed by this frame, in stream order. `timestamp` is the capture time of
+    /// the frame in microseconds, the connections idle for too long are dropped
+    pub fn push_frame(
+        &mut self,
+        frame: &[u8],
+        timestamp: u64,
+        stats: &mut CaptureStats,
+    ) -> Vec<PacketMetadata> {
+        stats.record_frame(frame.len());
+        for (key, lost) in self.reassembler.evict_idle(timestamp) {
+            self.buffers.remove(&key);
+            stats.lost_bytes += lost;
+        }
+
+        let header = match PacketHeader::from_link(frame, self.link_type) {
+            Ok(header) => header,
+            Err(err) => {
+                warn!("Failed to parse packet header: {:?}", err);
+                stats.header_failures += 1;
+                return Vec::new();
+            }
+        };
+
+        let direction = header.direction(&self.network);
+        let (key, chunk) = self.reassembler.process(&header, timestamp);
+        stats.lost_bytes += chunk.lost;
+        if chunk.closed && chunk.data.is_empty() {
+            self.buffers.remove(&key);
+            return Vec::new();
+        }
+
+        let buffer = self
+            .buffers
+            .entry(key.clone())
+            .or_insert_with(|| DataWrapper::new(Vec::new()));
+        if chunk.resync {
+            buffer.clear();
+        }
+        if chunk.data.is_empty() {
+            return Vec::new();
+        }
+        buffer.extend_from_slice(&chunk.data);
+
+        // A segment can hold several messages, the last one may continue in the next segment
+        let mut messages = Vec::new();
+        loop {
+            match PacketMetadata::from_slice(buffer.get_remaining(), direction) {
+                Err(ParseResult::Incomplete) => {
+                    // Nothing to wait for when the segment ended with a complete message
+                    if buffer.remaining() > 0 {
+                        stats.incomplete += 1;
+                    }
+                    break;
+                }
+                Err(err) => {
+                    // Desynced, the next segment is expected to start with a message
+                    warn!("Failed to parse metadata: {:?}", err);
+                    stats.invalid += 1;
+                    buffer.clear();
+                    break;
+                }
+                Ok((metadata, used)) => {
+                    buffer.set_pos(buffer.pos + used);
+                    stats.messages_framed += 1;
+                    messages.push(metadata);
+                }
+            }
+        }
+        buffer.compact();
+
+        if chunk.closed {
+            self.buffers.remove(&key);
+        }
+        messages
+    }
+}
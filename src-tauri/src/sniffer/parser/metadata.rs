@@ -1,13 +1,23 @@
 use anyhow::Result;
+use pcap::Linktype;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-#[derive(Debug, Clone)]
+use crate::sniffer::config::NetworkConfig;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
 pub enum PacketDirection {
+    /// Server to client
     In,
+    /// Client to server
     Out,
     Unknown,
 }
 
+/// Game messages stay far below this, a bigger size comes from a desynced stream
+const MAX_MESSAGE_SIZE: usize = 1 << 20;
+
 #[derive(Debug, Error)]
 pub enum ParseResult {
     #[error("Packet is invalid")]
@@ -16,95 +26,528 @@ pub enum ParseResult {
     Incomplete,
     #[error("Missing header")]
     MissingHeader(usize),
+    #[error("Unsupported link type {0}")]
+    UnsupportedLinkType(i32),
+    #[error("Unsupported ethertype {0:#06x}")]
+    UnsupportedEtherType(u16),
+    #[error("Unsupported IP protocol {0}")]
+    UnsupportedIpProtocol(u8),
 }
 
 #[derive(Debug, Clone)]
 pub struct PacketMetadata {
     pub data: Vec<u8>,
     pub id: u16,
-    pub size: u16,
+    pub size: u32,
+    pub direction: PacketDirection,
+    /// Only set on client messages
+    pub instance_id: Option<u32>,
 }
 
+pub const TCP_FLAG_FIN: u8 = 0x01;
+pub const TCP_FLAG_SYN: u8 = 0x02;
+pub const TCP_FLAG_RST: u8 = 0x04;
+
 #[derive(Debug, Clone)]
 pub struct PacketHeader {
     pub source_port: u16,
     pub destination_port: u16,
     pub source_ip: Vec<u8>,
-    pub seq_num: u16,
+    pub destination_ip: Vec<u8>,
+    pub seq_num: u32,
+    pub tcp_flags: u8,
     pub tcp_payload_start: usize,
     pub body: Vec<u8>,
 }
 
+const ETHERTYPE_IPV4: u16 = 0x0800;
+const ETHERTYPE_IPV6: u16 = 0x86DD;
+const ETHERTYPE_VLAN: u16 = 0x8100;
+const ETHERTYPE_QINQ: u16 = 0x88A8;
+
+const IP_PROTOCOL_TCP: u8 = 6;
+
+fn read_u16(data: &[u8], at: usize) -> Result<u16, ParseResult> {
+    match data.get(at..at + 2) {
+        Some(bytes) => Ok(u16::from_be_bytes([bytes[0], bytes[1]])),
+        None => Err(ParseResult::MissingHeader(data.len())),
+    }
+}
+
 impl PacketHeader {
-    pub fn from_vec(data: &Vec<u8>) -> Result<Self, ParseResult> {
+    pub fn from_vec(data: &[u8]) -> Result<Self, ParseResult> {
+        PacketHeader::from_link(data, Linktype::ETHERNET)
+    }
+
+    /// Parse a captured frame, `link_type` is the datalink of the capture it comes from
+    pub fn from_link(data: &[u8], link_type: Linktype) -> Result<Self, ParseResult> {
         // Structure of a packet:
-        // Ethernet header (14 bytes)
-        // IP header (20 bytes)
-        // TCP header (20 bytes)
+        // Link layer header (Ethernet, Linux cooked capture, loopback, or nothing)
+        // IP header (IPv4 or IPv6)
+        // TCP header
         // Data
 
-        if data.len() < 54 {
+        let (ethertype, ip_start) = match link_type {
+            Linktype::ETHERNET => {
+                let mut ethertype = read_u16(data, 12)?;
+                let mut ip_start = 14;
+                // 802.1Q tags (possibly stacked) sit between the MAC addresses and the ethertype
+                while ethertype == ETHERTYPE_VLAN || ethertype == ETHERTYPE_QINQ {
+                    ethertype = read_u16(data, ip_start + 2)?;
+                    ip_start += 4;
+                }
+                (Some(ethertype), ip_start)
+            }
+            Linktype::LINUX_SLL => (Some(read_u16(data, 14)?), 16),
+            Linktype::LINUX_SLL2 => (Some(read_u16(data, 0)?), 20),
+            // The address family is in host byte order, the IP version tells us more
+            Linktype::NULL | Linktype::LOOP => (None, 4),
+            Linktype::RAW | Linktype::IPV4 | Linktype::IPV6 => (None, 0),
+            _ => return Err(ParseResult::UnsupportedLinkType(link_type.0)),
+        };
+
+        let version = match data.get(ip_start) {
+            Some(byte) => byte >> 4,
+            None => return Err(ParseResult::MissingHeader(data.len())),
+        };
+        match (ethertype, version) {
+            (Some(ETHERTYPE_IPV4) | None, 4) => PacketHeader::from_ipv4(data, ip_start),
+            (Some(ETHERTYPE_IPV6) | None, 6) => PacketHeader::from_ipv6(data, ip_start),
+            (Some(ETHERTYPE_IPV4 | ETHERTYPE_IPV6) | None, _) => Err(ParseResult::Invalid),
+            (Some(ethertype), _) => Err(ParseResult::UnsupportedEtherType(ethertype)),
+        }
+    }
+
+    fn from_ipv4(data: &[u8], ip_start: usize) -> Result<Self, ParseResult> {
+        if data.len() < ip_start + 20 {
+            return Err(ParseResult::MissingHeader(data.len()));
+        }
+
+        let ip_header_length = ((data[ip_start] & 0x0F) as usize) * 4;
+        if ip_header_length < 20 {
+            return Err(ParseResult::Invalid);
+        }
+        let ip_total_length = read_u16(data, ip_start + 2)? as usize;
+        let protocol = data[ip_start + 9];
+        if protocol != IP_PROTOCOL_TCP {
+            return Err(ParseResult::UnsupportedIpProtocol(protocol));
+        }
+
+        let ip_source = &data[ip_start + 12..ip_start + 16];
+        let ip_destination = &data[ip_start + 16..ip_start + 20];
+
+        // Ethernet frames are padded to 60 bytes, the IP total length tells us where the payload ends.
+        // Segments sent by this host are captured before the offloading fills it (TSO), it is 0
+        let ip_end = match ip_total_length < ip_header_length {
+            true => data.len(),
+            false => ip_start + ip_total_length,
+        };
+        PacketHeader::from_tcp(
+            data,
+            ip_start + ip_header_length,
+            ip_end,
+            ip_source,
+            ip_destination,
+        )
+    }
+
+    fn from_ipv6(data: &[u8], ip_start: usize) -> Result<Self, ParseResult> {
+        if data.len() < ip_start + 40 {
+            return Err(ParseResult::MissingHeader(data.len()));
+        }
+
+        let payload_length = read_u16(data, ip_start + 4)? as usize;
+        let ip_source = &data[ip_start + 8..ip_start + 24];
+        let ip_destination = &data[ip_start + 24..ip_start + 40];
+
+        // Skip the extension headers until we reach TCP
+        let mut next_header = data[ip_start + 6];
+        let mut tcp_start = ip_start + 40;
+        loop {
+            match next_header {
+                IP_PROTOCOL_TCP => break,
+                // Hop-by-hop, routing and destination options
+                0 | 43 | 60 => {
+                    let header = data
+                        .get(tcp_start..tcp_start + 2)
+                        .ok_or(ParseResult::MissingHeader(data.len()))?;
+                    next_header = header[0];
+                    tcp_start += (header[1] as usize + 1) * 8;
+                }
+                // Fragment
+                44 => {
+                    next_header = *data
+                        .get(tcp_start)
+                        .ok_or(ParseResult::MissingHeader(data.len()))?;
+                    tcp_start += 8;
+                }
+                protocol => return Err(ParseResult::UnsupportedIpProtocol(protocol)),
+            }
+        }
+
+        // Same as the IPv4 total length, 0 for the segments offloaded by this host
+        let ip_end = match payload_length {
+            0 => data.len(),
+            _ => ip_start + 40 + payload_length,
+        };
+        PacketHeader::from_tcp(data, tcp_start, ip_end, ip_source, ip_destination)
+    }
+
+    fn from_tcp(
+        data: &[u8],
+        tcp_start: usize,
+        ip_end: usize,
+        ip_source: &[u8],
+        ip_destination: &[u8],
+    ) -> Result<Self, ParseResult> {
+        if data.len() < tcp_start + 20 {
             return Err(ParseResult::MissingHeader(data.len()));
         }
 
-        let eth_header_length = 14;
-        let ip_header_length = ((data[eth_header_length] & 0x0F) as usize) * 4;
-        let seq_num =
-            u16::from_be_bytes([data[eth_header_length + 4], data[eth_header_length + 5]]);
-        let ip_source = &data[eth_header_length + 12..eth_header_length + 16];
-        let tcp_start = eth_header_length + ip_header_length;
         let tcp_header_length = ((data[tcp_start + 12] >> 4) as usize) * 4;
+        if tcp_header_length < 20 {
+            return Err(ParseResult::Invalid);
+        }
         let tcp_payload_start = tcp_start + tcp_header_length;
+        if data.len() < tcp_payload_start {
+            // Packet is too short to contain a TCP payload
+            return Err(ParseResult::MissingHeader(data.len()));
+        }
 
         let source_port = u16::from_be_bytes([data[tcp_start], data[tcp_start + 1]]);
         let destination_port = u16::from_be_bytes([data[tcp_start + 2], data[tcp_start + 3]]);
+        let seq_num = u32::from_be_bytes([
+            data[tcp_start + 4],
+            data[tcp_start + 5],
+            data[tcp_start + 6],
+            data[tcp_start + 7],
+        ]);
+        let tcp_flags = data[tcp_start + 13];
+
+        let payload_end = ip_end.clamp(tcp_payload_start, data.len());
 
-        if data.len() < tcp_payload_start {
-            // Packet is too short to contain a TCP payload
-            return Err(ParseResult::Invalid);
-        }
         Ok(PacketHeader {
             source_port,
             destination_port,
             source_ip: ip_source.to_vec(),
+            destination_ip: ip_destination.to_vec(),
             seq_num,
+            tcp_flags,
             tcp_payload_start,
-            body: data[tcp_payload_start..].to_vec(),
+            body: data[tcp_payload_start..payload_end].to_vec(),
         })
     }
+
+    pub fn flow_key(&self) -> FlowKey {
+        FlowKey {
+            source_ip: self.source_ip.clone(),
+            source_port: self.source_port,
+            destination_ip: self.destination_ip.clone(),
+            destination_port: self.destination_port,
+        }
+    }
+
+    pub fn is_syn(&self) -> bool {
+        self.tcp_flags & TCP_FLAG_SYN != 0
+    }
+
+    pub fn is_fin(&self) -> bool {
+        self.tcp_flags & TCP_FLAG_FIN != 0
+    }
+
+    pub fn is_rst(&self) -> bool {
+        self.tcp_flags & TCP_FLAG_RST != 0
+    }
+
+    pub fn direction(&self, network: &NetworkConfig) -> PacketDirection {
+        if network.is_server_port(self.source_port) {
+            PacketDirection::In
+        } else if network.is_server_port(self.destination_port) {
+            PacketDirection::Out
+        } else {
+            PacketDirection::Unknown
+        }
+    }
+}
+
+/// One direction of a TCP connection
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FlowKey {
+    pub source_ip: Vec<u8>,
+    pub source_port: u16,
+    pub destination_ip: Vec<u8>,
+    pub destination_port: u16,
 }
 
 impl PacketMetadata {
     pub fn from_buffer(body: Vec<u8>) -> Result<Self, ParseResult> {
-        if body.len() < 3 {
-            return Err(ParseResult::Invalid);
+        PacketMetadata::from_slice(&body, PacketDirection::In).map(|(metadata, _)| metadata)
+    }
+
+    /// Parse the first message of `body` and return it with the number of bytes it used,
+    /// anything after that belongs to the next message
+    pub fn from_slice(
+        body: &[u8],
+        direction: PacketDirection,
+    ) -> Result<(Self, usize), ParseResult> {
+        if body.len() < 2 {
+            return Err(ParseResult::Incomplete);
         }
 
         //  [header sur 2 octets][taille du contenu sur 1, 2 ou 3 octets][contenu]
         // (id_du_message) << 2 + type de taille
+        // Client messages have the instance id (4 octets) between the header and the size
 
         let header = u16::from_be_bytes([body[0], body[1]]);
         let id = header >> 2;
         let size_type = header & 0b11;
-        let content_size = match size_type {
-            0 => 0,                                                           // 0 bytes
-            1 => u32::from_be_bytes([0, 0, 0, body[2]]) as usize,             // 1 bytes
-            2 => u32::from_be_bytes([0, 0, body[2], body[3]]) as usize,       // 2 bytes
-            3 => u32::from_be_bytes([0, body[2], body[3], body[4]]) as usize, // 3 bytes
-            _ => return Err(ParseResult::Invalid),
+        // No message has this id, the stream is read from the middle of a message
+        if id == 0 {
+            return Err(ParseResult::Invalid);
+        }
+
+        let size_start = match direction {
+            PacketDirection::Out => 6,
+            _ => 2,
+        };
+        let content_start = size_start + size_type as usize;
+        if body.len() < content_start {
+            return Err(ParseResult::Incomplete);
+        }
+
+        let instance_id = match direction {
+            PacketDirection::Out => Some(u32::from_be_bytes([body[2], body[3], body[4], body[5]])),
+            _ => None,
         };
 
-        let content_start = 2 + size_type as usize;
-        if body.len() < (content_start + content_size) {
+        let content_size = body[size_start..content_start]
+            .iter()
+            .fold(0, |size, byte| size << 8 | *byte as usize);
+        if content_size > MAX_MESSAGE_SIZE {
+            return Err(ParseResult::Invalid);
+        }
+
+        let content_end = content_start + content_size;
+        if body.len() < content_end {
             return Err(ParseResult::Incomplete);
         }
 
-        let body = body[content_start..content_start + content_size].to_vec();
+        Ok((
+            PacketMetadata {
+                data: body[content_start..content_end].to_vec(),
+                id,
+                size: content_size as u32,
+                direction,
+                instance_id,
+            },
+            content_end,
+        ))
+    }
+}
 
-        Ok(PacketMetadata {
-            data: body,
-            id,
-            size: content_size as u16,
-        })
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_slice() {
+        // id 1 with 2 bytes, id 2 without content, then the start of id 3
+        let body = [0x00, 0x05, 0x02, 0xaa, 0xbb, 0x00, 0x08, 0x00, 0x0d, 0x04];
+
+        let (first, used) = PacketMetadata::from_slice(&body, PacketDirection::In).unwrap();
+        assert_eq!(first.id, 1);
+        assert_eq!(first.data, vec![0xaa, 0xbb]);
+        assert_eq!(used, 5);
+
+        let (second, used) = PacketMetadata::from_slice(&body[5..], PacketDirection::In).unwrap();
+        assert_eq!(second.id, 2);
+        assert_eq!(second.size, 0);
+        assert_eq!(used, 2);
+
+        assert!(matches!(
+            PacketMetadata::from_slice(&body[7..], PacketDirection::In),
+            Err(ParseResult::Incomplete)
+        ));
+        assert!(matches!(
+            PacketMetadata::from_slice(&body[9..], PacketDirection::In),
+            Err(ParseResult::Incomplete)
+        ));
+    }
+
+    #[test]
+    fn test_from_slice_invalid() {
+        // id 0 with 1 byte of content
+        assert!(matches!(
+            PacketMetadata::from_slice(&[0x00, 0x01, 0x01, 0xff], PacketDirection::In),
+            Err(ParseResult::Invalid)
+        ));
+
+        // id 1 with a 3 bytes size, the content doesn't have to be there
+        let size = |size: usize| {
+            let mut body = vec![0x00, 0x07];
+            body.extend_from_slice(&size.to_be_bytes()[5..]);
+            PacketMetadata::from_slice(&body, PacketDirection::In)
+        };
+        assert!(matches!(
+            size(MAX_MESSAGE_SIZE),
+            Err(ParseResult::Incomplete)
+        ));
+        assert!(matches!(
+            size(MAX_MESSAGE_SIZE + 1),
+            Err(ParseResult::Invalid)
+        ));
+    }
+
+    #[test]
+    fn test_from_slice_client() {
+        // id 1 with instance id 42 and 1 byte of content
+        let body = [0x00, 0x05, 0x00, 0x00, 0x00, 0x2a, 0x01, 0xff];
+
+        let (metadata, used) = PacketMetadata::from_slice(&body, PacketDirection::Out).unwrap();
+        assert_eq!(metadata.id, 1);
+        assert_eq!(metadata.instance_id, Some(42));
+        assert_eq!(metadata.data, vec![0xff]);
+        assert_eq!(used, body.len());
+
+        assert!(matches!(
+            PacketMetadata::from_slice(&body[..4], PacketDirection::Out),
+            Err(ParseResult::Incomplete)
+        ));
+    }
+
+    fn tcp_segment(payload: &[u8]) -> Vec<u8> {
+        let mut tcp = vec![0; 20];
+        tcp[0..2].copy_from_slice(&5555u16.to_be_bytes());
+        tcp[2..4].copy_from_slice(&40000u16.to_be_bytes());
+        tcp[4..8].copy_from_slice(&1234u32.to_be_bytes());
+        tcp[12] = 5 << 4;
+        tcp[13] = TCP_FLAG_FIN;
+        tcp.extend_from_slice(payload);
+        tcp
+    }
+
+    fn ipv4_packet(payload: &[u8]) -> Vec<u8> {
+        let tcp = tcp_segment(payload);
+        let mut ip = vec![0; 20];
+        ip[0] = 0x45;
+        ip[2..4].copy_from_slice(&(20 + tcp.len() as u16).to_be_bytes());
+        ip[9] = IP_PROTOCOL_TCP;
+        ip[12..16].copy_from_slice(&[1, 2, 3, 4]);
+        ip[16..20].copy_from_slice(&[5, 6, 7, 8]);
+        ip.extend_from_slice(&tcp);
+        ip
+    }
+
+    fn ipv6_packet(payload: &[u8]) -> Vec<u8> {
+        let tcp = tcp_segment(payload);
+        let mut ip = vec![0; 40];
+        ip[0] = 0x60;
+        // one hop-by-hop extension header before TCP
+        ip[4..6].copy_from_slice(&(8 + tcp.len() as u16).to_be_bytes());
+        ip[6] = 0;
+        ip[8..24].copy_from_slice(&[1; 16]);
+        ip[24..40].copy_from_slice(&[2; 16]);
+        ip.extend_from_slice(&[IP_PROTOCOL_TCP, 0, 0, 0, 0, 0, 0, 0]);
+        ip.extend_from_slice(&tcp);
+        ip
+    }
+
+    fn assert_header(header: PacketHeader, ip_len: usize) {
+        assert_eq!(header.source_port, 5555);
+        assert_eq!(header.destination_port, 40000);
+        assert_eq!(header.seq_num, 1234);
+        assert_eq!(header.source_ip.len(), ip_len);
+        assert!(header.is_fin());
+        assert_eq!(header.body, vec![0xaa, 0xbb]);
+        assert_eq!(
+            header.direction(&NetworkConfig::default()),
+            PacketDirection::In
+        );
+    }
+
+    #[test]
+    fn test_header_link_types() {
+        let payload = [0xaa, 0xbb];
+
+        let mut ethernet = vec![0; 12];
+        ethernet.extend_from_slice(&ETHERTYPE_VLAN.to_be_bytes());
+        ethernet.extend_from_slice(&[0, 1]);
+        ethernet.extend_from_slice(&ETHERTYPE_IPV4.to_be_bytes());
+        ethernet.extend_from_slice(&ipv4_packet(&payload));
+        // padding added by the link layer
+        ethernet.extend_from_slice(&[0; 6]);
+        assert_header(
+            PacketHeader::from_link(&ethernet, Linktype::ETHERNET).unwrap(),
+            4,
+        );
+
+        let mut sll = vec![0; 14];
+        sll.extend_from_slice(&ETHERTYPE_IPV6.to_be_bytes());
+        sll.extend_from_slice(&ipv6_packet(&payload));
+        assert_header(
+            PacketHeader::from_link(&sll, Linktype::LINUX_SLL).unwrap(),
+            16,
+        );
+
+        let mut sll2 = ETHERTYPE_IPV4.to_be_bytes().to_vec();
+        sll2.extend_from_slice(&[0; 18]);
+        sll2.extend_from_slice(&ipv4_packet(&payload));
+        assert_header(
+            PacketHeader::from_link(&sll2, Linktype::LINUX_SLL2).unwrap(),
+            4,
+        );
+
+        let mut null = 2u32.to_ne_bytes().to_vec();
+        null.extend_from_slice(&ipv4_packet(&payload));
+        assert_header(PacketHeader::from_link(&null, Linktype::NULL).unwrap(), 4);
+
+        assert_header(
+            PacketHeader::from_link(&ipv6_packet(&payload), Linktype::RAW).unwrap(),
+            16,
+        );
+    }
+
+    #[test]
+    fn test_header_errors() {
+        let packet = ipv4_packet(&[0xaa, 0xbb]);
+
+        for len in 0..40 {
+            assert!(matches!(
+                PacketHeader::from_link(&packet[..len], Linktype::RAW),
+                Err(ParseResult::MissingHeader(_))
+            ));
+        }
+
+        let mut udp = packet.clone();
+        udp[9] = 17;
+        assert!(matches!(
+            PacketHeader::from_link(&udp, Linktype::RAW),
+            Err(ParseResult::UnsupportedIpProtocol(17))
+        ));
+
+        let mut arp = vec![0; 12];
+        arp.extend_from_slice(&0x0806u16.to_be_bytes());
+        arp.extend_from_slice(&packet);
+        assert!(matches!(
+            PacketHeader::from_link(&arp, Linktype::ETHERNET),
+            Err(ParseResult::UnsupportedEtherType(0x0806))
+        ));
+
+        assert!(matches!(
+            PacketHeader::from_link(&packet, Linktype(147)),
+            Err(ParseResult::UnsupportedLinkType(147))
+        ));
+    }
+
+    #[test]
+    fn test_header_offloaded_length() {
+        // Segments captured on the sending host before TSO filled the lengths
+        let mut ipv4 = ipv4_packet(&[0xaa, 0xbb]);
+        ipv4[2..4].copy_from_slice(&[0, 0]);
+        assert_header(PacketHeader::from_link(&ipv4, Linktype::RAW).unwrap(), 4);
+
+        let mut ipv6 = ipv6_packet(&[0xaa, 0xbb]);
+        ipv6[4..6].copy_from_slice(&[0, 0]);
+        assert_header(PacketHeader::from_link(&ipv6, Linktype::RAW).unwrap(), 16);
     }
 }
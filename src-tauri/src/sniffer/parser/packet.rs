@@ -1,23 +1,35 @@
-use indexmap::IndexMap;
+use serde::Serialize;
 use serde_json::{Map, Number, Value};
 use thiserror::Error;
 use tracing::debug;
 
-use crate::sniffer::protocol::protocol::{
-    EventId, EventName, ProtocolManager, ProtocolSchema, ProtocolVarType,
+use crate::sniffer::protocol::{
+    plan::{DecodingPlan, PlanStep, PlanType},
+    protocol::{
+        EventId, EventName, ProtocolManager, ProtocolSchema, ProtocolVarType,
+        ProtocolVarTypeVector, VectorLength,
+    },
 };
 
 use super::{
-    metadata::PacketMetadata,
+    metadata::{PacketDirection, PacketMetadata},
+    selection::FieldSelection,
     wrapper::{DataWrapper, ParseError},
 };
 
 type PacketData = Map<String, Value>;
 
-#[derive(Debug, Clone)]
+/// Key holding the concrete class of polymorphic values (`TypeId<...>` and `TypeIdVector<...>`)
+pub const TYPE_NAME_KEY: &str = "__type";
+
+/// BooleanByteWrapper flags
+pub(crate) const BOOLEANS_PER_BYTE: usize = 8;
+
+#[derive(Debug, Clone, Serialize)]
 pub struct Packet {
     pub id: u16,
     pub name: EventName,
+    pub direction: PacketDirection,
     pub data: PacketData,
 }
 
@@ -26,31 +38,136 @@ impl Packet {}
 #[derive(Debug, Clone)]
 pub struct PacketParser {
     pub id: u16,
+    pub direction: PacketDirection,
     pub data: DataWrapper,
 }
 
 impl PacketParser {
     pub fn new(id: u16, data: DataWrapper) -> Self {
-        PacketParser { id, data }
+        PacketParser {
+            id,
+            direction: PacketDirection::Unknown,
+            data,
+        }
     }
 
     pub fn from_metadata(meta: &PacketMetadata) -> Self {
-        PacketParser::new(meta.id, DataWrapper::new(meta.data.clone()))
+        PacketParser {
+            id: meta.id,
+            direction: meta.direction,
+            data: DataWrapper::new(meta.data.clone()),
+        }
     }
 
     pub fn parse(&mut self, protocol_manager: &ProtocolManager) -> Result<Packet, PacketError> {
+        match protocol_manager.get_plan(&self.id) {
+            Some(plan) => {
+                let data = self.decode_plan(protocol_manager, plan)?;
+                Ok(self.packet(plan.name.clone(), data))
+            }
+            None => Err(PacketError::UnknownPacketType(self.id)),
+        }
+    }
+
+    /// Walks the schema instead of using the precompiled plan, kept as the reference decoder
+    pub fn parse_with_schema(
+        &mut self,
+        protocol_manager: &ProtocolManager,
+    ) -> Result<Packet, PacketError> {
         match protocol_manager.get_protocol(&self.id) {
             Some(event) => {
                 let data = self.parse_packet_data(protocol_manager, event)?;
+                Ok(self.packet(event.name.clone(), data))
+            }
+            None => Err(PacketError::UnknownPacketType(self.id)),
+        }
+    }
+
+    fn packet(&self, name: EventName, data: PacketData) -> Packet {
+        // check if there is any data left
+        if !self.data.get_remaining().is_empty() {
+            debug!("Data left after parsing: {:?}", self.data.get_remaining());
+        }
+
+        Packet {
+            id: self.id,
+            name,
+            direction: self.direction,
+            data,
+        }
+    }
+
+    fn decode_plan(
+        &mut self,
+        protocol_manager: &ProtocolManager,
+        plan: &DecodingPlan,
+    ) -> Result<PacketData, PacketError> {
+        let mut data = Map::with_capacity(plan.steps.len());
+        for step in &plan.steps {
+            match step {
+                PlanStep::PackedBooleans(names) => self.read_packed_booleans(names, &mut data)?,
+                PlanStep::Field(name, plan_type) => {
+                    let value = self.decode_type(protocol_manager, plan_type)?;
+                    data.insert(name.to_string(), value);
+                }
+                PlanStep::UnknownParent(parent) => {
+                    return Err(PacketError::UnknownParentType(parent.clone()))
+                }
+            }
+        }
+        Ok(data)
+    }
+
+    fn decode_type(
+        &mut self,
+        protocol_manager: &ProtocolManager,
+        plan_type: &PlanType,
+    ) -> Result<Value, PacketError> {
+        match plan_type {
+            PlanType::Primitive(var_type) => self.read_primitive(var_type),
+            PlanType::Class(id) => {
+                let plan = protocol_manager
+                    .get_plan(id)
+                    .ok_or(PacketError::UnknownTypeId(*id))?;
+                Ok(Value::Object(self.decode_plan(protocol_manager, plan)?))
+            }
+            PlanType::TypeId(base) => {
+                let plan = self.read_polymorphic_plan(protocol_manager, *base)?;
+                let mut value = Map::new();
+                value.insert(TYPE_NAME_KEY.to_string(), Value::String(plan.name.clone()));
+                value.extend(self.decode_plan(protocol_manager, plan)?);
+                Ok(Value::Object(value))
+            }
+            PlanType::Vector(vector) => {
+                let length = self.read_vector_length(&vector.length)?;
 
-                // check if there is any data left
-                if !self.data.get_remaining().is_empty() {
-                    debug!("Data left after parsing: {:?}", self.data.get_remaining());
+                // The length comes from the network, don't trust it for the allocation
+                let mut values = Vec::with_capacity(length.min(self.data.remaining()));
+                for _ in 0..length {
+                    values.push(self.decode_type(protocol_manager, &vector.types)?);
                 }
+                Ok(Value::Array(values))
+            }
+            PlanType::Unresolved(name) => Err(PacketError::UnknownParentType(name.clone())),
+        }
+    }
 
+    /// Only decode the `selection` fields, the reading stops after the last one
+    pub fn parse_fields(
+        &mut self,
+        protocol_manager: &ProtocolManager,
+        selection: &FieldSelection,
+    ) -> Result<Packet, PacketError> {
+        if selection.is_all() {
+            return self.parse(protocol_manager);
+        }
+        match protocol_manager.get_plan(&self.id) {
+            Some(plan) => {
+                let data = self.decode_plan_fields(protocol_manager, plan, selection, true)?;
                 Ok(Packet {
                     id: self.id,
-                    name: event.name.clone(),
+                    name: plan.name.clone(),
+                    direction: self.direction,
                     data,
                 })
             }
@@ -58,6 +175,217 @@ impl PacketParser {
         }
     }
 
+    /// Nested classes are read up to their end so the next value can be decoded, unless
+    /// `stop_early`
+    fn decode_plan_fields(
+        &mut self,
+        protocol_manager: &ProtocolManager,
+        plan: &DecodingPlan,
+        selection: &FieldSelection,
+        stop_early: bool,
+    ) -> Result<PacketData, PacketError> {
+        if selection.is_all() {
+            return self.decode_plan(protocol_manager, plan);
+        }
+
+        let is_selected = |step: &PlanStep| match step {
+            PlanStep::PackedBooleans(names) => {
+                names.iter().any(|name| selection.get(name).is_some())
+            }
+            PlanStep::Field(name, _) => selection.get(name).is_some(),
+            PlanStep::UnknownParent(_) => true,
+        };
+        let end = match stop_early {
+            true => plan
+                .steps
+                .iter()
+                .rposition(is_selected)
+                .map_or(0, |i| i + 1),
+            false => plan.steps.len(),
+        };
+
+        let mut data = Map::new();
+        for step in &plan.steps[..end] {
+            match step {
+                PlanStep::PackedBooleans(names) if is_selected(step) => {
+                    self.read_packed_booleans(names, &mut data)?;
+                    data.retain(|name, _| selection.get(name).is_some());
+                }
+                PlanStep::Field(name, plan_type) => match selection.get(name) {
+                    Some(selection) => {
+                        let value =
+                            self.decode_type_fields(protocol_manager, plan_type, selection)?;
+                        data.insert(name.to_string(), value);
+                    }
+                    None => self.skip_type(protocol_manager, plan_type)?,
+                },
+                _ => self.skip_step(protocol_manager, step)?,
+            }
+        }
+        Ok(data)
+    }
+
+    fn decode_type_fields(
+        &mut self,
+        protocol_manager: &ProtocolManager,
+        plan_type: &PlanType,
+        selection: &FieldSelection,
+    ) -> Result<Value, PacketError> {
+        if selection.is_all() {
+            return self.decode_type(protocol_manager, plan_type);
+        }
+        match plan_type {
+            PlanType::Class(id) => {
+                let plan = protocol_manager
+                    .get_plan(id)
+                    .ok_or(PacketError::UnknownTypeId(*id))?;
+                let data = self.decode_plan_fields(protocol_manager, plan, selection, false)?;
+                Ok(Value::Object(data))
+            }
+            PlanType::TypeId(base) => {
+                let plan = self.read_polymorphic_plan(protocol_manager, *base)?;
+                let mut value = Map::new();
+                value.insert(TYPE_NAME_KEY.to_string(), Value::String(plan.name.clone()));
+                value.extend(self.decode_plan_fields(protocol_manager, plan, selection, false)?);
+                Ok(Value::Object(value))
+            }
+            PlanType::Vector(vector) => {
+                let length = self.read_vector_length(&vector.length)?;
+                let mut values = Vec::with_capacity(length.min(self.data.remaining()));
+                for _ in 0..length {
+                    values.push(self.decode_type_fields(
+                        protocol_manager,
+                        &vector.types,
+                        selection,
+                    )?);
+                }
+                Ok(Value::Array(values))
+            }
+            // Nothing to select inside
+            _ => self.decode_type(protocol_manager, plan_type),
+        }
+    }
+
+    fn skip_step(
+        &mut self,
+        protocol_manager: &ProtocolManager,
+        step: &PlanStep,
+    ) -> Result<(), PacketError> {
+        match step {
+            PlanStep::PackedBooleans(names) => {
+                self.data.skip(names.len().div_ceil(BOOLEANS_PER_BYTE))?
+            }
+            PlanStep::Field(_, plan_type) => self.skip_type(protocol_manager, plan_type)?,
+            PlanStep::UnknownParent(parent) => {
+                return Err(PacketError::UnknownParentType(parent.clone()))
+            }
+        }
+        Ok(())
+    }
+
+    /// Move past a value without building it
+    fn skip_type(
+        &mut self,
+        protocol_manager: &ProtocolManager,
+        plan_type: &PlanType,
+    ) -> Result<(), PacketError> {
+        match plan_type {
+            PlanType::Primitive(var_type) => self.skip_primitive(var_type),
+            PlanType::Class(id) => {
+                let plan = protocol_manager
+                    .get_plan(id)
+                    .ok_or(PacketError::UnknownTypeId(*id))?;
+                self.skip_plan(protocol_manager, plan)
+            }
+            PlanType::TypeId(base) => {
+                let plan = self.read_polymorphic_plan(protocol_manager, *base)?;
+                self.skip_plan(protocol_manager, plan)
+            }
+            PlanType::Vector(vector) => {
+                let length = self.read_vector_length(&vector.length)?;
+                let size = match &vector.types {
+                    PlanType::Primitive(var_type) => var_type.fixed_size(),
+                    _ => None,
+                };
+                match size {
+                    // The length comes from the network, the multiplication may overflow
+                    Some(size) => self
+                        .data
+                        .skip(length.saturating_mul(size))
+                        .map_err(Into::into),
+                    None => {
+                        for _ in 0..length {
+                            self.skip_type(protocol_manager, &vector.types)?;
+                        }
+                        Ok(())
+                    }
+                }
+            }
+            PlanType::Unresolved(name) => Err(PacketError::UnknownParentType(name.clone())),
+        }
+    }
+
+    fn skip_plan(
+        &mut self,
+        protocol_manager: &ProtocolManager,
+        plan: &DecodingPlan,
+    ) -> Result<(), PacketError> {
+        for step in &plan.steps {
+            self.skip_step(protocol_manager, step)?;
+        }
+        Ok(())
+    }
+
+    fn skip_primitive(&mut self, var_type: &ProtocolVarType) -> Result<(), PacketError> {
+        if let Some(size) = var_type.fixed_size() {
+            return self.data.skip(size).map_err(Into::into);
+        }
+        match var_type {
+            ProtocolVarType::String => {
+                let len = self.data.read_unsigned_short()? as usize;
+                self.data.skip(len)?;
+            }
+            ProtocolVarType::VarInt | ProtocolVarType::VarUhInt => {
+                self.data.read_var_int()?;
+            }
+            ProtocolVarType::VarLong | ProtocolVarType::VarUhLong => {
+                self.data.read_var_long()?;
+            }
+            ProtocolVarType::VarShort | ProtocolVarType::VarUhShort => {
+                self.data.read_var_short()?;
+            }
+            _ => return Err(PacketError::FailedToParseAttribute(var_type.clone())),
+        }
+        Ok(())
+    }
+
+    /// Id of the concrete class of a polymorphic value, which must inherit from `base`
+    fn read_polymorphic_plan<'a>(
+        &mut self,
+        protocol_manager: &'a ProtocolManager,
+        base: EventId,
+    ) -> Result<&'a DecodingPlan, PacketError> {
+        let type_id = self.data.read_unsigned_short()?;
+        let plan = protocol_manager
+            .get_plan(&type_id)
+            .ok_or(PacketError::UnknownTypeId(type_id))?;
+        if !plan.is_subclass_of(base) {
+            let base = protocol_manager
+                .get_plan(&base)
+                .map(|base| base.name.clone())
+                .unwrap_or_default();
+            return Err(PacketError::UnexpectedTypeId(type_id, base));
+        }
+        Ok(plan)
+    }
+
+    fn read_vector_length(&mut self, length: &VectorLength) -> Result<usize, PacketError> {
+        match length {
+            VectorLength::Fixed(length) => Ok(*length),
+            VectorLength::Prefixed(length_type) => self.read_length(length_type),
+        }
+    }
+
     fn parse_packet_data(
         &mut self,
         protocol_manager: &ProtocolManager,
@@ -66,7 +394,7 @@ impl PacketParser {
         let mut data = Map::new();
 
         if let Some(parent) = &event.parent {
-            let parent_type = protocol_manager.get_protocol_by_class(&parent);
+            let parent_type = protocol_manager.get_protocol_by_class(parent);
             if parent_type.is_none() {
                 return Err(PacketError::UnknownParentType(parent.clone()));
             }
@@ -77,7 +405,7 @@ impl PacketParser {
                 data.extend(parent_data);
             }
         }
-        data.extend(self.parse_packet_attributes(protocol_manager, &event.attributes)?);
+        data.extend(self.parse_packet_attributes(protocol_manager, event)?);
 
         Ok(data)
     }
@@ -85,11 +413,17 @@ impl PacketParser {
     fn parse_packet_attributes(
         &mut self,
         protocol_manager: &ProtocolManager,
-        attributes: &IndexMap<String, ProtocolVarType>,
+        event: &ProtocolSchema,
     ) -> Result<PacketData, PacketError> {
         let mut data = Map::new();
 
-        for (name, var_type) in attributes {
+        let packed_booleans = event.packed_booleans();
+        self.read_packed_booleans(packed_booleans, &mut data)?;
+
+        for (name, var_type) in &event.attributes {
+            if packed_booleans.contains(name) {
+                continue;
+            }
             // info!("Parsing attribute: {} {:?}", name, var_type);
             let value = self.parse_attribute(protocol_manager, var_type)?;
             // debug!("Parsed attribute: {} {:?} => {:?}", name, var_type, value);
@@ -99,90 +433,164 @@ impl PacketParser {
         Ok(data)
     }
 
+    fn read_packed_booleans(
+        &mut self,
+        names: &[impl AsRef<str>],
+        data: &mut PacketData,
+    ) -> Result<(), PacketError> {
+        let mut flags = 0;
+        for (i, name) in names.iter().enumerate() {
+            let bit = i % BOOLEANS_PER_BYTE;
+            if bit == 0 {
+                flags = self.data.read_byte()?;
+            }
+            let value = Value::Bool(flags & (1 << bit) != 0);
+            data.insert(name.as_ref().to_string(), value);
+        }
+        Ok(())
+    }
+
     fn parse_attribute(
         &mut self,
         protocol_manager: &ProtocolManager,
         var_type: &ProtocolVarType,
     ) -> Result<Value, PacketError> {
-        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-            let res = match var_type {
-                ProtocolVarType::Byte => {
-                    let value = self.data.read_byte();
-                    Value::Number(Number::from(value))
-                }
-                ProtocolVarType::String => {
-                    let value = self.data.read_utf();
-                    Value::String(value)
-                }
-                ProtocolVarType::VarInt => {
-                    let value = self.data.read_var_int();
-                    Value::Number(Number::from(value))
-                }
-                ProtocolVarType::VarShort => {
-                    let value = self.data.read_var_short()?;
-                    Value::Number(Number::from(value))
-                }
-                ProtocolVarType::VarLong => {
-                    let value = self.data.read_var_long()?;
-                    Value::Number(Number::from(value))
-                }
-                ProtocolVarType::Int => {
-                    let value = self.data.read_int();
-                    Value::Number(Number::from(value))
-                }
-                ProtocolVarType::Short => {
-                    let value = self.data.read_unsigned_short();
-                    Value::Number(Number::from(value))
-                }
-                ProtocolVarType::None => Value::Null,
-                ProtocolVarType::Boolean => {
-                    let value = self.data.read_byte() == 1;
-                    Value::Bool(value)
-                }
-                ProtocolVarType::Double => {
-                    let value = self.data.read_double();
-                    Value::Number(Number::from_f64(value as f64).unwrap())
-                }
-                ProtocolVarType::Other(name) => {
-                    return self.parse_complexe_type(protocol_manager, name, var_type);
-                }
-            };
-            Ok(res)
-        }))
-        .unwrap_or_else(|_| Err(PacketError::FailedToParseAttribute(var_type.clone())))
+        match var_type {
+            ProtocolVarType::Class(_) | ProtocolVarType::TypeId(_) | ProtocolVarType::Vector(_) => {
+                self.parse_complexe_type(protocol_manager, var_type)
+            }
+            _ => self.read_primitive(var_type),
+        }
+    }
+
+    fn read_primitive(&mut self, var_type: &ProtocolVarType) -> Result<Value, PacketError> {
+        let res = match var_type {
+            ProtocolVarType::String => Value::String(self.data.read_utf()?),
+            ProtocolVarType::VarInt | ProtocolVarType::VarUhInt => {
+                Value::from(self.data.read_var_int()?)
+            }
+            ProtocolVarType::VarLong | ProtocolVarType::VarUhLong => {
+                Value::from(self.data.read_var_long()?)
+            }
+            ProtocolVarType::VarShort | ProtocolVarType::VarUhShort => {
+                Value::from(self.data.read_var_short()?)
+            }
+            ProtocolVarType::Byte => Value::from(self.data.read_signed_byte()?),
+            ProtocolVarType::UnsignedByte => Value::from(self.data.read_byte()?),
+            ProtocolVarType::Short => Value::from(self.data.read_short()?),
+            ProtocolVarType::UnsignedShort => Value::from(self.data.read_unsigned_short()?),
+            ProtocolVarType::Int => Value::from(self.data.read_int()?),
+            ProtocolVarType::UnsignedInt => Value::from(self.data.read_unsigned_int()?),
+            ProtocolVarType::Long => Value::from(self.data.read_long()?),
+            ProtocolVarType::UnsignedLong => Value::from(self.data.read_unsigned_long()?),
+            // NaN and infinities have no JSON representation
+            ProtocolVarType::Float => {
+                Number::from_f64(self.data.read_float()? as f64).map_or(Value::Null, Value::Number)
+            }
+            ProtocolVarType::Double => {
+                Number::from_f64(self.data.read_double()?).map_or(Value::Null, Value::Number)
+            }
+            ProtocolVarType::None => Value::Null,
+            ProtocolVarType::Boolean => Value::Bool(self.data.read_boolean()?),
+            _ => return Err(PacketError::FailedToParseAttribute(var_type.clone())),
+        };
+        Ok(res)
     }
 
     pub fn parse_complexe_type(
         &mut self,
         protocol_manager: &ProtocolManager,
-        name: &String,
         var_type: &ProtocolVarType,
     ) -> Result<Value, PacketError> {
-        if let Some(vector) = var_type.parse_vector() {
-            match vector.length {
-                ProtocolVarType::Short => {
-                    let length = self.data.read_unsigned_short(); // array length is signed
-                    let mut values = Vec::with_capacity(length as usize);
-                    for _ in 0..length {
-                        let value = self.parse_attribute(protocol_manager, &vector.types)?;
-                        values.push(value);
-                    }
-                    return Ok(Value::Array(values));
+        match var_type {
+            ProtocolVarType::Vector(vector) => self.parse_vector(protocol_manager, vector),
+            ProtocolVarType::TypeId(base) => self.parse_polymorphic(protocol_manager, base),
+            ProtocolVarType::Class(name) => {
+                let schema = protocol_manager.get_protocol_by_class(name);
+                if schema.is_none() {
+                    return Err(PacketError::UnknownParentType(name.clone()));
                 }
-                _ => {}
+                let schema = schema.unwrap();
+                let value = self.parse_packet_data(protocol_manager, schema)?;
+                Ok(Value::Object(value))
             }
-            return Err(PacketError::FailedToParseAttribute(var_type.clone()));
-        } else if let Some(type_id) = var_type.parse_type_id() {
-            return self.parse_attribute(protocol_manager, &type_id);
-        } else {
-            let schema = protocol_manager.get_protocol_by_class(name);
-            if schema.is_none() {
-                return Err(PacketError::UnknownParentType(name.clone()));
+            _ => Err(PacketError::FailedToParseAttribute(var_type.clone())),
+        }
+    }
+
+    fn parse_vector(
+        &mut self,
+        protocol_manager: &ProtocolManager,
+        vector: &ProtocolVarTypeVector,
+    ) -> Result<Value, PacketError> {
+        let length = match &vector.length {
+            VectorLength::Fixed(length) => *length,
+            VectorLength::Prefixed(length_type) => self.read_length(length_type)?,
+        };
+
+        // The length comes from the network, don't trust it for the allocation
+        let mut values = Vec::with_capacity(length.min(self.data.remaining()));
+        for _ in 0..length {
+            let value = match vector.type_id {
+                true => self.parse_polymorphic(protocol_manager, &vector.types)?,
+                false => self.parse_attribute(protocol_manager, &vector.types)?,
+            };
+            values.push(value);
+        }
+        Ok(Value::Array(values))
+    }
+
+    fn read_length(&mut self, length_type: &ProtocolVarType) -> Result<usize, PacketError> {
+        let length = match length_type {
+            ProtocolVarType::Byte | ProtocolVarType::UnsignedByte => {
+                self.data.read_byte()? as usize
+            }
+            // array length is signed in the game, but never negative
+            ProtocolVarType::Short | ProtocolVarType::UnsignedShort => {
+                self.data.read_unsigned_short()? as usize
+            }
+            ProtocolVarType::Int | ProtocolVarType::UnsignedInt => {
+                self.data.read_unsigned_int()? as usize
+            }
+            ProtocolVarType::VarShort | ProtocolVarType::VarUhShort => {
+                self.data.read_var_short()? as usize
             }
-            let schema = schema.unwrap();
-            let value = self.parse_packet_data(protocol_manager, schema)?;
-            Ok(Value::Object(value))
+            ProtocolVarType::VarInt | ProtocolVarType::VarUhInt => {
+                self.data.read_var_int()? as usize
+            }
+            _ => return Err(PacketError::FailedToParseAttribute(length_type.clone())),
+        };
+        Ok(length)
+    }
+
+    /// The value is prefixed with the id of its concrete class, which must inherit from `base`
+    fn parse_polymorphic(
+        &mut self,
+        protocol_manager: &ProtocolManager,
+        base: &ProtocolVarType,
+    ) -> Result<Value, PacketError> {
+        let base = match base {
+            ProtocolVarType::Class(name) => name,
+            _ => return Err(PacketError::FailedToParseAttribute(base.clone())),
+        };
+
+        let type_id = self.data.read_unsigned_short()?;
+        let schema = protocol_manager.get_protocol(&type_id);
+        if schema.is_none() {
+            return Err(PacketError::UnknownTypeId(type_id));
         }
+        let schema = schema.unwrap();
+        if !protocol_manager.is_subclass_of(schema, base) {
+            return Err(PacketError::UnexpectedTypeId(type_id, base.clone()));
+        }
+
+        let mut value = Map::new();
+        value.insert(
+            TYPE_NAME_KEY.to_string(),
+            Value::String(schema.name.clone()),
+        );
+        value.extend(self.parse_packet_data(protocol_manager, schema)?);
+        Ok(Value::Object(value))
     }
 }
 
@@ -192,9 +600,13 @@ pub enum PacketError {
     UnknownParentType(EventName),
     #[error("Unknown packet type")]
     UnknownPacketType(EventId),
+    #[error("Unknown type id {0}")]
+    UnknownTypeId(EventId),
+    #[error("Type id {0} is not a {1}")]
+    UnexpectedTypeId(EventId, EventName),
     #[error("Failed to parse attribute")]
     FailedToParseAttribute(ProtocolVarType),
-    #[error("Error while parsing attribute")]
+    #[error("Error while parsing attribute: {0}")]
     ParseError(#[from] ParseError),
 }
 
@@ -205,7 +617,10 @@ mod tests {
     use crate::node::Node;
 
     use super::*;
-    use crate::sniffer::parser::metadata::PacketHeader;
+    use crate::sniffer::{
+        offline::{frame_file, FIXTURE_CAPTURE},
+        parser::{metadata::PacketHeader, selection::FieldSelection},
+    };
     use tracing::info;
 
     #[test]
@@ -246,4 +661,252 @@ mod tests {
             info!("Packet: {:?}", packet);
         }
     }
+
+    #[test]
+    fn test_parse_type_id() {
+        let mut procol_manager = ProtocolManager::new();
+        procol_manager.init("tests/fixtures/").unwrap();
+
+        // CompassUpdateMessage { type: Byte, coords: TypeId<MapCoordinates> }
+        let mut data = vec![3];
+        data.extend_from_slice(&9666u16.to_be_bytes()); // MapCoordinatesAndId
+        data.extend_from_slice(&[0, 4, 0, 7]);
+        data.extend_from_slice(&1234.0f64.to_be_bytes());
+        let mut parser = PacketParser::new(1650, DataWrapper::new(data));
+        let packet = parser.parse(&procol_manager).unwrap();
+        let coords = &packet.data["coords"];
+        assert_eq!(coords[TYPE_NAME_KEY], "MapCoordinatesAndId");
+        assert_eq!(coords["worldY"], 7);
+        assert_eq!(coords["mapId"], 1234.0);
+
+        // ServerSessionConstantsMessage { variables: TypeIdVector<Short,ServerSessionConstant> }
+        let mut data = vec![0, 2];
+        data.extend_from_slice(&677u16.to_be_bytes()); // ServerSessionConstantInteger
+        data.extend_from_slice(&[1, 0, 0, 0, 42]);
+        data.extend_from_slice(&8828u16.to_be_bytes()); // ServerSessionConstantString
+        data.extend_from_slice(&[2, 0, 3, b'a', b'b', b'c']);
+        let mut parser = PacketParser::new(7848, DataWrapper::new(data));
+        let packet = parser.parse(&procol_manager).unwrap();
+        let variables = packet.data["variables"].as_array().unwrap();
+        assert_eq!(variables[0][TYPE_NAME_KEY], "ServerSessionConstantInteger");
+        assert_eq!(variables[0]["value"], 42);
+        assert_eq!(variables[1][TYPE_NAME_KEY], "ServerSessionConstantString");
+        assert_eq!(variables[1]["value"], "abc");
+        assert!(parser.data.get_remaining().is_empty());
+
+        // MapCoordinates is not a ServerSessionConstant
+        let mut data = vec![0, 1];
+        data.extend_from_slice(&1446u16.to_be_bytes());
+        let mut parser = PacketParser::new(7848, DataWrapper::new(data));
+        assert!(matches!(
+            parser.parse(&procol_manager),
+            Err(PacketError::UnexpectedTypeId(1446, _))
+        ));
+    }
+
+    #[test]
+    fn test_parse_packed_booleans() {
+        let mut procol_manager = ProtocolManager::new();
+        procol_manager.init("tests/fixtures/").unwrap();
+
+        // ActorRestrictionsInformations, 19 booleans packed in 3 bytes
+        let data = vec![0b0000_0101, 0b1000_0000, 0b0000_0100];
+        let mut parser = PacketParser::new(2944, DataWrapper::new(data));
+        let packet = parser.parse(&procol_manager).unwrap();
+        assert_eq!(packet.data["cantBeAggressed"], true);
+        assert_eq!(packet.data["cantBeChallenged"], false);
+        assert_eq!(packet.data["cantTrade"], true);
+        assert_eq!(packet.data["cantUseInteractive"], true);
+        assert_eq!(packet.data["cantAttackMonster"], true);
+        assert_eq!(packet.data["cantChangeZone"], false);
+        assert!(parser.data.get_remaining().is_empty());
+
+        // A lone boolean keeps its own byte, at its position
+        let schema = procol_manager
+            .get_protocol_by_class(&"GameRolePlayNpcInformations".to_string())
+            .unwrap();
+        assert!(schema.packed_booleans().is_empty());
+    }
+
+    #[test]
+    fn test_parse_primitives() {
+        let mut procol_manager = ProtocolManager::new();
+        procol_manager.init("tests/fixtures/").unwrap();
+
+        let schema = procol_manager
+            .get_protocol_by_class(&"SellerBuyerDescriptor".to_string())
+            .unwrap();
+        assert_eq!(schema.attributes["taxPercentage"], ProtocolVarType::Float);
+
+        // PaginationAnswerAbstractMessage { offset: Double, count: UnsignedInt, total: UnsignedInt }
+        let id = *procol_manager
+            .get_protocol_id_by_class(&"PaginationAnswerAbstractMessage".to_string())
+            .unwrap();
+        let mut data = 12.0f64.to_be_bytes().to_vec();
+        data.extend_from_slice(&3_000_000_000u32.to_be_bytes());
+        data.extend_from_slice(&4_000_000_000u32.to_be_bytes());
+        let mut parser = PacketParser::new(id, DataWrapper::new(data));
+        let packet = parser.parse(&procol_manager).unwrap();
+        assert_eq!(packet.data["count"], 3_000_000_000u32);
+        assert_eq!(packet.data["total"], 4_000_000_000u32);
+
+        // ConsoleEndMessage { consoleUuid: Uuid }, an Uuid is sent as a string
+        let id = *procol_manager
+            .get_protocol_id_by_class(&"ConsoleEndMessage".to_string())
+            .unwrap();
+        let uuid = "0c7d9ab8-9b0b-4d0e-8d5c-1b0a1c2c3d4e";
+        let mut data = (uuid.len() as u16).to_be_bytes().to_vec();
+        data.extend_from_slice(uuid.as_bytes());
+        data.push(1);
+        let mut parser = PacketParser::new(id, DataWrapper::new(data));
+        let packet = parser.parse(&procol_manager).unwrap();
+        assert_eq!(packet.data["consoleUuid"]["uuidString"], uuid);
+        assert_eq!(packet.data["isSuccess"], true);
+    }
+
+    #[test]
+    fn test_parse_vector_lengths() {
+        let mut procol_manager = ProtocolManager::new();
+        procol_manager.init("tests/fixtures/").unwrap();
+
+        // CharacterCreationRequestMessage { name, breed, sex, colors: Vector<5,Int>, cosmeticId }
+        let mut data = vec![0, 1, b'a', 8, 1];
+        for color in [1i32, 2, 3, 4, -1] {
+            data.extend_from_slice(&color.to_be_bytes());
+        }
+        data.push(42);
+        let mut parser = PacketParser::new(324, DataWrapper::new(data));
+        let packet = parser.parse(&procol_manager).unwrap();
+        assert_eq!(packet.data["colors"], serde_json::json!([1, 2, 3, 4, -1]));
+        assert_eq!(packet.data["cosmeticId"], 42);
+
+        // Nested vectors with a VarInt length
+        let var_type = "Vector<VarInt,Vector<Short,Byte>>".parse().unwrap();
+        let data = vec![2, 0, 1, 7, 0, 2, 8, 9];
+        let mut parser = PacketParser::new(0, DataWrapper::new(data));
+        let value = parser.parse_attribute(&procol_manager, &var_type).unwrap();
+        assert_eq!(value, serde_json::json!([[7], [8, 9]]));
+        assert!(parser.data.get_remaining().is_empty());
+
+        // A length that is not an integer is rejected when the protocol is loaded
+        assert!("Vector<String,Byte>".parse::<ProtocolVarType>().is_err());
+    }
+
+    #[test]
+    fn test_parse_truncated() {
+        let mut procol_manager = ProtocolManager::new();
+        procol_manager.init("tests/fixtures/").unwrap();
+
+        // CompassUpdateMessage cut in the middle of its coordinates
+        let mut data = vec![3];
+        data.extend_from_slice(&9666u16.to_be_bytes());
+        data.extend_from_slice(&[0, 4, 0]);
+        let mut parser = PacketParser::new(1650, DataWrapper::new(data));
+        assert!(matches!(
+            parser.parse(&procol_manager),
+            Err(PacketError::ParseError(ParseError::Truncated {
+                offset: 5,
+                needed: 2,
+                remaining: 1
+            }))
+        ));
+
+        // A vector length far larger than the message
+        let mut parser = PacketParser::new(7848, DataWrapper::new(vec![0xff, 0xff]));
+        assert!(matches!(
+            parser.parse(&procol_manager),
+            Err(PacketError::ParseError(ParseError::Truncated {
+                offset: 2,
+                ..
+            }))
+        ));
+
+        // ChatServerMessage with an invalid UTF-8 content
+        let id = *procol_manager
+            .get_protocol_id_by_class(&"ChatServerMessage".to_string())
+            .unwrap();
+        let mut parser = PacketParser::new(id, DataWrapper::new(vec![4, 0, 1, 0xff]));
+        assert!(matches!(
+            parser.parse(&procol_manager),
+            Err(PacketError::ParseError(ParseError::InvalidUtf8 {
+                offset: 3,
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn test_plan_matches_schema() {
+        let mut procol_manager = ProtocolManager::new();
+        procol_manager.init("tests/fixtures/").unwrap();
+
+        let mut decoded = 0;
+        for message in frame_file(FIXTURE_CAPTURE).unwrap() {
+            let metadata = message.unwrap().metadata;
+            let planned = PacketParser::from_metadata(&metadata).parse(&procol_manager);
+            let walked = PacketParser::from_metadata(&metadata).parse_with_schema(&procol_manager);
+            match (planned, walked) {
+                (Ok(planned), Ok(walked)) => {
+                    assert_eq!(planned.name, walked.name);
+                    assert_eq!(planned.data, walked.data);
+                    decoded += 1;
+                }
+                (Err(planned), Err(walked)) => {
+                    assert_eq!(planned.to_string(), walked.to_string())
+                }
+                (planned, walked) => panic!(
+                    "message {} decoded differently: {:?} / {:?}",
+                    metadata.id, planned, walked
+                ),
+            }
+        }
+        assert!(decoded > 0);
+    }
+
+    #[test]
+    fn test_parse_fields() {
+        let mut procol_manager = ProtocolManager::new();
+        procol_manager.init("tests/fixtures/").unwrap();
+
+        let mut nested = 0;
+        for message in frame_file(FIXTURE_CAPTURE).unwrap() {
+            let metadata = message.unwrap().metadata;
+            let Ok(full) = PacketParser::from_metadata(&metadata).parse(&procol_manager) else {
+                continue;
+            };
+            let names = full.data.keys().collect::<Vec<_>>();
+            let (Some(first), Some(last)) = (names.first(), names.last()) else {
+                continue;
+            };
+
+            let selection = FieldSelection::from_paths([first, last]);
+            let mut parser = PacketParser::from_metadata(&metadata);
+            let partial = parser.parse_fields(&procol_manager, &selection).unwrap();
+            let mut expected = full.data.clone();
+            expected.retain(|name, _| name == *first || name == *last);
+            assert_eq!(partial.data, expected, "{}", full.name);
+
+            // A path through a vector of classes keeps a single attribute of each element
+            let vector = full.data.iter().find_map(|(name, value)| {
+                let element = value.as_array()?.first()?.as_object()?;
+                let field = element.keys().find(|key| *key != TYPE_NAME_KEY)?;
+                Some((name, field))
+            });
+            if let Some((name, field)) = vector {
+                let path = format!("{}.{}", name, field);
+                let selection = FieldSelection::from_paths([path]);
+                let mut parser = PacketParser::from_metadata(&metadata);
+                let partial = parser.parse_fields(&procol_manager, &selection).unwrap();
+                let elements = partial.data[name.as_str()].as_array().unwrap();
+                let expected = full.data[name.as_str()].as_array().unwrap();
+                assert_eq!(elements.len(), expected.len());
+                for (element, expected) in elements.iter().zip(expected) {
+                    assert_eq!(element[field.as_str()], expected[field.as_str()]);
+                    assert!(element.as_object().unwrap().len() <= 2);
+                }
+                nested += 1;
+            }
+        }
+        assert!(nested > 0);
+    }
 }
@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+
+use crate::sniffer::protocol::protocol::FieldName;
+
+/// Separator of the field names in a path, e.g. `actors.contextualId`
+pub const PATH_SEPARATOR: char = '.';
+
+static ALL: FieldSelection = FieldSelection::All;
+
+/// Fields of a message to decode, the others are skipped without building values.
+/// A path going through a vector applies to each of its elements, a path ending on a class
+/// selects the whole class
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldSelection {
+    All,
+    Only(HashMap<FieldName, FieldSelection>),
+}
+
+impl FieldSelection {
+    pub fn from_paths<S: AsRef<str>>(paths: impl IntoIterator<Item = S>) -> Self {
+        let mut selection = FieldSelection::Only(HashMap::new());
+        for path in paths {
+            selection.insert(path.as_ref());
+        }
+        selection
+    }
+
+    fn insert(&mut self, path: &str) {
+        let FieldSelection::Only(fields) = self else {
+            return;
+        };
+        let (field, rest) = match path.split_once(PATH_SEPARATOR) {
+            Some((field, rest)) => (field, Some(rest)),
+            None => (path, None),
+        };
+        match rest {
+            Some(rest) => fields
+                .entry(field.to_string())
+                .or_insert_with(|| FieldSelection::Only(HashMap::new()))
+                .insert(rest),
+            None => {
+                fields.insert(field.to_string(), FieldSelection::All);
+            }
+        }
+    }
+
+    /// Union of both selections
+    pub fn merge(&mut self, other: &FieldSelection) {
+        match (self, other) {
+            (FieldSelection::All, _) => {}
+            (this, FieldSelection::All) => *this = FieldSelection::All,
+            (FieldSelection::Only(fields), FieldSelection::Only(others)) => {
+                for (field, selection) in others {
+                    match fields.get_mut(field) {
+                        Some(existing) => existing.merge(selection),
+                        None => {
+                            fields.insert(field.clone(), selection.clone());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn is_all(&self) -> bool {
+        matches!(self, FieldSelection::All)
+    }
+
+    /// Selection inside `field`, `None` when it is not selected
+    pub fn get(&self, field: &str) -> Option<&FieldSelection> {
+        match self {
+            FieldSelection::All => Some(&ALL),
+            FieldSelection::Only(fields) => fields.get(field),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_paths() {
+        let selection = FieldSelection::from_paths(["channel", "actors.look.bonesId", "actors"]);
+        assert_eq!(selection.get("channel"), Some(&FieldSelection::All));
+        // The whole field wins over one of its paths
+        assert_eq!(selection.get("actors"), Some(&FieldSelection::All));
+        assert_eq!(selection.get("content"), None);
+
+        let selection = FieldSelection::from_paths(["actors.look.bonesId", "actors.name"]);
+        let actors = selection.get("actors").unwrap();
+        assert_eq!(actors.get("name"), Some(&FieldSelection::All));
+        assert!(!actors.get("look").unwrap().is_all());
+        assert_eq!(
+            actors.get("look").unwrap().get("bonesId"),
+            Some(&FieldSelection::All)
+        );
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut selection = FieldSelection::from_paths(["channel", "actors.name"]);
+        selection.merge(&FieldSelection::from_paths(["content", "actors.look"]));
+        assert_eq!(
+            selection,
+            FieldSelection::from_paths(["channel", "content", "actors.name", "actors.look"])
+        );
+
+        selection.merge(&FieldSelection::All);
+        assert!(selection.is_all());
+        assert_eq!(selection.get("anything"), Some(&FieldSelection::All));
+    }
+}
@@ -0,0 +1,349 @@
+use std::collections::{BTreeMap, HashMap};
+
+use tracing::{debug, warn};
+
+use super::metadata::{FlowKey, PacketHeader};
+
+/// Maximum number of out of order bytes kept for a single flow before giving up on the gap
+const MAX_PENDING_BYTES: usize = 1024 * 1024;
+/// Flows without a segment for this long (in microseconds of capture time) are dropped, their
+/// FIN/RST was never seen: the client crashed, the capture started mid-connection, ...
+const STREAM_IDLE_TIMEOUT: u64 = 5 * 60 * 1_000_000;
+/// How often the idle flows are looked for
+const EVICTION_INTERVAL: u64 = 10 * 1_000_000;
+
+#[derive(Debug, Default)]
+pub struct StreamReassembler {
+    streams: HashMap<FlowKey, TcpStream>,
+    /// Capture time of the last look for idle flows
+    last_eviction: u64,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct StreamChunk {
+    /// Bytes that are now in order and can be given to the message framer
+    pub data: Vec<u8>,
+    /// Some bytes were lost, anything buffered before this chunk can't be trusted anymore
+    pub resync: bool,
+    /// The flow is closed (FIN/RST), its buffers can be dropped
+    pub closed: bool,
+    /// Bytes that will never be delivered: a gap that was skipped, or the segments waiting
+    /// behind a gap when the flow closed
+    pub lost: u64,
+}
+
+/// Reassembly state for one direction of a TCP connection
+#[derive(Debug, Default)]
+pub struct TcpStream {
+    /// Next expected sequence number
+    next_seq: Option<u32>,
+    /// Number of bytes delivered so far, used as the base for `pending` offsets
+    delivered: u64,
+    /// Out of order segments, keyed by their offset in the stream
+    pending: BTreeMap<u64, Vec<u8>>,
+    pending_bytes: usize,
+    /// Capture time of the last segment, in microseconds
+    last_seen: u64,
+}
+
+impl StreamReassembler {
+    pub fn new() -> Self {
+        StreamReassembler {
+            streams: HashMap::new(),
+            last_eviction: 0,
+        }
+    }
+
+    /// `timestamp` is the capture time of the segment, in microseconds
+    pub fn process(&mut self, header: &PacketHeader, timestamp: u64) -> (FlowKey, StreamChunk) {
+        let key = header.flow_key();
+
+        if header.is_rst() {
+            debug!("Connection reset: {:?}", key);
+            let stream = self.streams.remove(&key);
+            return (
+                key,
+                StreamChunk {
+                    closed: true,
+                    lost: stream.map_or(0, |stream| stream.pending_bytes as u64),
+                    ..Default::default()
+                },
+            );
+        }
+
+        let stream = self.streams.entry(key.clone()).or_default();
+        stream.last_seen = timestamp;
+        let mut chunk = stream.push(header.seq_num, header.is_syn(), &header.body);
+
+        if header.is_fin() {
+            debug!("Connection closed: {:?}", key);
+            // The gap before the pending segments can't be filled anymore
+            let stream = self.streams.remove(&key).unwrap();
+            chunk.lost += stream.pending_bytes as u64;
+            chunk.closed = true;
+        }
+
+        (key, chunk)
+    }
+
+    /// Drop the flows idle for too long, returns them with the bytes they lost.
+    /// Only looks every `EVICTION_INTERVAL` of capture time
+    pub fn evict_idle(&mut self, now: u64) -> Vec<(FlowKey, u64)> {
+        if now.saturating_sub(self.last_eviction) < EVICTION_INTERVAL {
+            return Vec::new();
+        }
+        self.last_eviction = now;
+
+        let idle = self
+            .streams
+            .iter()
+            .filter(|(_, stream)| now.saturating_sub(stream.last_seen) > STREAM_IDLE_TIMEOUT)
+            .map(|(key, _)| key.clone())
+            .collect::<Vec<_>>();
+        idle.into_iter()
+            .map(|key| {
+                debug!("Connection idle: {:?}", key);
+                let stream = self.streams.remove(&key).unwrap();
+                (key, stream.pending_bytes as u64)
+            })
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.streams.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.streams.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.streams.clear();
+    }
+}
+
+impl TcpStream {
+    pub fn push(&mut self, seq: u32, syn: bool, payload: &[u8]) -> StreamChunk {
+        let mut chunk = StreamChunk::default();
+
+        if syn {
+            // SYN consumes one sequence number, a new SYN means a new connection on the same ports
+            if self.next_seq.is_some() {
+                chunk.resync = true;
+            }
+            self.reset();
+            self.next_seq = Some(seq.wrapping_add(1));
+            if payload.is_empty() {
+                return chunk;
+            }
+        }
+
+        let seq = if syn { seq.wrapping_add(1) } else { seq };
+        let next_seq = *self.next_seq.get_or_insert(seq);
+        if payload.is_empty() {
+            return chunk;
+        }
+
+        // Distance from the expected sequence number, wrapping arithmetic handles the 2^32 rollover
+        let diff = seq.wrapping_sub(next_seq) as i32;
+        let offset = self.delivered as i64 + diff as i64;
+
+        if offset < 0 {
+            // Data from before we started listening
+            let skip = (-offset) as usize;
+            if skip >= payload.len() {
+                return chunk;
+            }
+            return self.push_at(0, &payload[skip..], chunk);
+        }
+
+        self.push_at(offset as u64, payload, chunk)
+    }
+
+    fn push_at(&mut self, offset: u64, payload: &[u8], mut chunk: StreamChunk) -> StreamChunk {
+        let end = offset + payload.len() as u64;
+
+        if end <= self.delivered {
+            // Retransmission of data we already have
+            return chunk;
+        }
+
+        if offset > self.delivered {
+            // Gap, keep the segment until the missing bytes arrive
+            let previous = self.pending.get(&offset).map_or(0, |data| data.len());
+            if payload.len() > previous {
+                self.pending.insert(offset, payload.to_vec());
+                self.pending_bytes += payload.len() - previous;
+            }
+
+            if self.pending_bytes > MAX_PENDING_BYTES {
+                let first = *self.pending.keys().next().unwrap();
+                warn!(
+                    "Missing segment never arrived, skipping {} bytes",
+                    first - self.delivered
+                );
+                chunk.lost += first - self.delivered;
+                self.next_seq = self
+                    .next_seq
+                    .map(|seq| seq.wrapping_add((first - self.delivered) as u32));
+                self.delivered = first;
+                chunk.resync = true;
+                self.drain_pending(&mut chunk);
+            }
+            return chunk;
+        }
+
+        // Overlap with data we already delivered, only keep the new part
+        let skip = (self.delivered - offset) as usize;
+        self.deliver(&payload[skip..], &mut chunk);
+        self.drain_pending(&mut chunk);
+        chunk
+    }
+
+    fn drain_pending(&mut self, chunk: &mut StreamChunk) {
+        while let Some((&offset, _)) = self.pending.iter().next() {
+            if offset > self.delivered {
+                break;
+            }
+            let data = self.pending.remove(&offset).unwrap();
+            self.pending_bytes -= data.len();
+            let end = offset + data.len() as u64;
+            if end <= self.delivered {
+                continue;
+            }
+            let skip = (self.delivered - offset) as usize;
+            self.deliver(&data[skip..], chunk);
+        }
+    }
+
+    fn deliver(&mut self, data: &[u8], chunk: &mut StreamChunk) {
+        chunk.data.extend_from_slice(data);
+        self.delivered += data.len() as u64;
+        self.next_seq = self.next_seq.map(|seq| seq.wrapping_add(data.len() as u32));
+    }
+
+    fn reset(&mut self) {
+        self.next_seq = None;
+        self.delivered = 0;
+        self.pending.clear();
+        self.pending_bytes = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sniffer::parser::metadata::TCP_FLAG_FIN;
+
+    #[test]
+    fn test_in_order() {
+        let mut stream = TcpStream::default();
+        assert_eq!(stream.push(100, false, &[1, 2, 3]).data, vec![1, 2, 3]);
+        assert_eq!(stream.push(103, false, &[4, 5]).data, vec![4, 5]);
+    }
+
+    #[test]
+    fn test_out_of_order() {
+        let mut stream = TcpStream::default();
+        assert_eq!(stream.push(100, false, &[1, 2]).data, vec![1, 2]);
+        assert!(stream.push(104, false, &[5, 6]).data.is_empty());
+        assert_eq!(stream.push(102, false, &[3, 4]).data, vec![3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_retransmit_and_overlap() {
+        let mut stream = TcpStream::default();
+        assert_eq!(stream.push(100, false, &[1, 2, 3]).data, vec![1, 2, 3]);
+        assert!(stream.push(100, false, &[1, 2, 3]).data.is_empty());
+        assert_eq!(stream.push(102, false, &[3, 4, 5]).data, vec![4, 5]);
+
+        // pending segments overlapping each other
+        assert!(stream.push(108, false, &[9, 10]).data.is_empty());
+        assert!(stream.push(107, false, &[8, 9]).data.is_empty());
+        assert_eq!(stream.push(105, false, &[6, 7]).data, vec![6, 7, 8, 9, 10]);
+    }
+
+    #[test]
+    fn test_syn() {
+        let mut stream = TcpStream::default();
+        assert!(stream.push(99, true, &[]).data.is_empty());
+        assert_eq!(stream.push(100, false, &[1]).data, vec![1]);
+
+        // new connection on the same ports
+        let chunk = stream.push(5000, true, &[]);
+        assert!(chunk.resync);
+        assert_eq!(stream.push(5001, false, &[2]).data, vec![2]);
+    }
+
+    #[test]
+    fn test_sequence_wraparound() {
+        let mut stream = TcpStream::default();
+        assert_eq!(stream.push(u32::MAX - 1, false, &[1, 2]).data, vec![1, 2]);
+        assert!(stream.push(2, false, &[5]).data.is_empty());
+        assert_eq!(stream.push(0, false, &[3, 4]).data, vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn test_gap_is_skipped() {
+        let mut stream = TcpStream::default();
+        assert_eq!(stream.push(0, false, &[1]).data, vec![1]);
+        let segment = vec![0; MAX_PENDING_BYTES / 2 + 1];
+        assert!(stream.push(10, false, &segment).data.is_empty());
+        let chunk = stream.push(10 + segment.len() as u32, false, &segment);
+        assert!(chunk.resync);
+        assert_eq!(chunk.lost, 9);
+        assert_eq!(chunk.data.len(), segment.len() * 2);
+    }
+
+    fn header(seq_num: u32, tcp_flags: u8, body: &[u8]) -> PacketHeader {
+        PacketHeader {
+            source_port: 5555,
+            destination_port: 40000,
+            source_ip: vec![1, 2, 3, 4],
+            destination_ip: vec![5, 6, 7, 8],
+            seq_num,
+            tcp_flags,
+            tcp_payload_start: 0,
+            body: body.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_fin_with_pending() {
+        let mut reassembler = StreamReassembler::new();
+        let (_, chunk) = reassembler.process(&header(100, 0, &[1, 2]), 0);
+        assert_eq!(chunk.data, vec![1, 2]);
+        assert!(reassembler
+            .process(&header(110, 0, &[9; 4]), 0)
+            .1
+            .data
+            .is_empty());
+
+        // The in order bytes are delivered, the ones behind the gap are lost
+        let (_, chunk) = reassembler.process(&header(102, TCP_FLAG_FIN, &[3]), 0);
+        assert_eq!(chunk.data, vec![3]);
+        assert!(chunk.closed);
+        assert_eq!(chunk.lost, 4);
+        assert!(reassembler.is_empty());
+    }
+
+    #[test]
+    fn test_idle_eviction() {
+        let mut reassembler = StreamReassembler::new();
+        reassembler.process(&header(100, 0, &[1]), EVICTION_INTERVAL);
+        assert!(reassembler
+            .process(&header(110, 0, &[9; 4]), EVICTION_INTERVAL)
+            .1
+            .data
+            .is_empty());
+        assert!(reassembler.evict_idle(EVICTION_INTERVAL).is_empty());
+
+        let mut other = header(0, 0, &[1]);
+        other.source_port = 5556;
+        let later = EVICTION_INTERVAL + STREAM_IDLE_TIMEOUT + 1;
+        reassembler.process(&other, later);
+        let evicted = reassembler.evict_idle(later);
+        assert_eq!(evicted, vec![(header(0, 0, &[]).flow_key(), 4)]);
+        assert_eq!(reassembler.len(), 1);
+    }
+}
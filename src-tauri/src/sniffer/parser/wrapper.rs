@@ -40,21 +40,10 @@ impl DataWrapper {
         debug!("Remaining: {:?}", self.get_remaining());
     }
 
-    pub fn reorder(&mut self, buffer: Vec<u8>) {
-        let buffer_len = buffer.len();
-        let cut_off = self.data.len().saturating_sub(buffer_len) as usize;
-
-        let mut new_data = Vec::with_capacity(self.data.len() + buffer_len);
-        for i in 0..cut_off {
-            new_data.push(self.data[i]);
-        }
-        for b in buffer {
-            new_data.push(b);
-        }
-        for i in cut_off..self.data.len() {
-            new_data.push(self.data[i]);
-        }
-        self.data = new_data;
+    /// Drop the bytes that have already been read
+    pub fn compact(&mut self) {
+        self.data.drain(..self.pos);
+        self.pos = 0;
     }
 
     pub fn clear(&mut self) {
@@ -62,107 +51,264 @@ impl DataWrapper {
         self.data.clear();
     }
 
-    pub fn read_byte(&mut self) -> u8 {
-        let value = self.data[self.pos];
-        let value = u8::from_be_bytes([value]); // TODO: check if needed
-        self.pos += 1;
-        value
+    /// Next `len` bytes, nothing is consumed when there are not enough of them
+    pub fn read_bytes(&mut self, len: usize) -> Result<&[u8], ParseError> {
+        let start = self.pos;
+        let end = match start.checked_add(len) {
+            Some(end) if end <= self.data.len() => end,
+            _ => {
+                return Err(ParseError::Truncated {
+                    offset: start,
+                    needed: len,
+                    remaining: self.remaining(),
+                })
+            }
+        };
+        self.pos = end;
+        Ok(&self.data[start..end])
     }
 
-    pub fn read_int(&mut self) -> u32 {
-        let value = u32::from_be_bytes([
-            self.data[self.pos],
-            self.data[self.pos + 1],
-            self.data[self.pos + 2],
-            self.data[self.pos + 3],
-        ]);
-        self.pos += 4;
-        value
+    pub fn skip(&mut self, len: usize) -> Result<(), ParseError> {
+        self.read_bytes(len).map(|_| ())
     }
 
-    pub fn read_var_int(&mut self) -> u32 {
-        let mut value = 0;
-        for i in (0..32).step_by(7) {
-            let byte = self.read_byte();
-            value |= ((byte & 0x7f) as u32) << i;
-            if byte & 0x80 == 0 {
-                return value;
-            }
-        }
-        panic!("Too much data");
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], ParseError> {
+        let mut bytes = [0; N];
+        bytes.copy_from_slice(self.read_bytes(N)?);
+        Ok(bytes)
     }
 
-    pub fn read_short(&mut self) -> i16 {
-        let value = i16::from_be_bytes([self.data[self.pos], self.data[self.pos + 1]]);
-        self.pos += 2;
-        value
+    pub fn read_byte(&mut self) -> Result<u8, ParseError> {
+        Ok(u8::from_be_bytes(self.read_array()?))
     }
-    pub fn read_unsigned_short(&mut self) -> u16 {
-        let value = u16::from_be_bytes([self.data[self.pos], self.data[self.pos + 1]]);
-        self.pos += 2;
-        value
+
+    pub fn read_signed_byte(&mut self) -> Result<i8, ParseError> {
+        Ok(i8::from_be_bytes(self.read_array()?))
     }
 
-    pub fn read_float(&mut self) -> f32 {
-        let value = f32::from_be_bytes([
-            self.data[self.pos],
-            self.data[self.pos + 1],
-            self.data[self.pos + 2],
-            self.data[self.pos + 3],
-        ]);
-        self.pos += 4;
-        value
+    pub fn read_boolean(&mut self) -> Result<bool, ParseError> {
+        Ok(self.read_byte()? != 0)
     }
 
-    pub fn read_var_short(&mut self) -> Result<u16, ParseError> {
+    pub fn read_int(&mut self) -> Result<i32, ParseError> {
+        Ok(i32::from_be_bytes(self.read_array()?))
+    }
+
+    pub fn read_unsigned_int(&mut self) -> Result<u32, ParseError> {
+        Ok(u32::from_be_bytes(self.read_array()?))
+    }
+
+    pub fn read_long(&mut self) -> Result<i64, ParseError> {
+        Ok(i64::from_be_bytes(self.read_array()?))
+    }
+
+    pub fn read_unsigned_long(&mut self) -> Result<u64, ParseError> {
+        Ok(u64::from_be_bytes(self.read_array()?))
+    }
+
+    pub fn read_short(&mut self) -> Result<i16, ParseError> {
+        Ok(i16::from_be_bytes(self.read_array()?))
+    }
+
+    pub fn read_unsigned_short(&mut self) -> Result<u16, ParseError> {
+        Ok(u16::from_be_bytes(self.read_array()?))
+    }
+
+    pub fn read_float(&mut self) -> Result<f32, ParseError> {
+        Ok(f32::from_be_bytes(self.read_array()?))
+    }
+
+    pub fn read_double(&mut self) -> Result<f64, ParseError> {
+        Ok(f64::from_be_bytes(self.read_array()?))
+    }
+
+    /// 7 bits per byte, least significant group first, the high bit is set when more bytes follow
+    fn read_var(&mut self, bits: u32) -> Result<u64, ParseError> {
+        let offset = self.pos;
         let mut value = 0;
-        for i in (0..16).step_by(7) {
-            let byte = self.read_byte();
-            value += ((byte & 0x7f) as u16) << i;
+        for shift in (0..bits).step_by(7) {
+            let byte = self.read_byte()?;
+            value |= ((byte & 0x7f) as u64) << shift;
             if byte & 0x80 == 0 {
                 return Ok(value);
             }
         }
-        return Err(ParseError::TooMuchData);
+        Err(ParseError::TooMuchData { offset, bits })
+    }
+
+    pub fn read_var_int(&mut self) -> Result<u32, ParseError> {
+        Ok(self.read_var(32)? as u32)
+    }
+
+    pub fn read_var_short(&mut self) -> Result<u16, ParseError> {
+        Ok(self.read_var(16)? as u16)
     }
 
     pub fn read_var_long(&mut self) -> Result<u64, ParseError> {
-        let mut value = 0;
-        for i in (0..64).step_by(7) {
-            let byte = self.read_byte();
-            value |= ((byte & 0x7f) as u64) << i;
-            if byte & 0x80 == 0 {
-                return Ok(value);
-            }
+        self.read_var(64)
+    }
+
+    pub fn read_utf(&mut self) -> Result<String, ParseError> {
+        let len = self.read_unsigned_short()? as usize;
+        self.read_utf_bytes(len)
+    }
+
+    pub fn read_utf_bytes(&mut self, len: usize) -> Result<String, ParseError> {
+        let offset = self.pos;
+        let bytes = self.read_bytes(len)?;
+        match std::str::from_utf8(bytes) {
+            Ok(value) => Ok(value.to_string()),
+            Err(source) => Err(ParseError::InvalidUtf8 { offset, source }),
         }
-        return Err(ParseError::TooMuchData);
-    }
-
-    pub fn read_utf(&mut self) -> String {
-        let len = self.read_unsigned_short() as usize;
-        let value = String::from_utf8(self.data[self.pos..self.pos + len].to_vec()).unwrap();
-        self.pos += len;
-        value
-    }
-
-    pub fn read_double(&mut self) -> f64 {
-        let value = f64::from_be_bytes([
-            self.data[self.pos],
-            self.data[self.pos + 1],
-            self.data[self.pos + 2],
-            self.data[self.pos + 3],
-            self.data[self.pos + 4],
-            self.data[self.pos + 5],
-            self.data[self.pos + 6],
-            self.data[self.pos + 7],
-        ]);
-        self.pos += 8;
-        value
     }
 }
 
-#[derive(Debug, Error)]
+#[derive(Debug, Error, PartialEq)]
 pub enum ParseError {
-    #[error("Too much data")]
-    TooMuchData,
+    #[error("Not enough data at offset {offset}: {needed} bytes needed, {remaining} remaining")]
+    Truncated {
+        offset: usize,
+        needed: usize,
+        remaining: usize,
+    },
+    #[error("Var int at offset {offset} is longer than {bits} bits")]
+    TooMuchData { offset: usize, bits: u32 },
+    #[error("Invalid UTF-8 string at offset {offset}: {source}")]
+    InvalidUtf8 {
+        offset: usize,
+        #[source]
+        source: std::str::Utf8Error,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn var_encode(mut value: u64) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                bytes.push(byte);
+                return bytes;
+            }
+            bytes.push(byte | 0x80);
+        }
+    }
+
+    #[test]
+    fn test_fixed_size_round_trip() {
+        let mut data = Vec::new();
+        data.extend_from_slice(&(-5i8).to_be_bytes());
+        data.extend_from_slice(&200u8.to_be_bytes());
+        data.extend_from_slice(&(-1234i16).to_be_bytes());
+        data.extend_from_slice(&60000u16.to_be_bytes());
+        data.extend_from_slice(&(-123456i32).to_be_bytes());
+        data.extend_from_slice(&4_000_000_000u32.to_be_bytes());
+        data.extend_from_slice(&(-9_000_000_000i64).to_be_bytes());
+        data.extend_from_slice(&u64::MAX.to_be_bytes());
+        data.extend_from_slice(&1.5f32.to_be_bytes());
+        data.extend_from_slice(&(-2.25f64).to_be_bytes());
+        data.extend_from_slice(&[1, 0]);
+
+        let mut wrapper = DataWrapper::new(data);
+        assert_eq!(wrapper.read_signed_byte().unwrap(), -5);
+        assert_eq!(wrapper.read_byte().unwrap(), 200);
+        assert_eq!(wrapper.read_short().unwrap(), -1234);
+        assert_eq!(wrapper.read_unsigned_short().unwrap(), 60000);
+        assert_eq!(wrapper.read_int().unwrap(), -123456);
+        assert_eq!(wrapper.read_unsigned_int().unwrap(), 4_000_000_000);
+        assert_eq!(wrapper.read_long().unwrap(), -9_000_000_000);
+        assert_eq!(wrapper.read_unsigned_long().unwrap(), u64::MAX);
+        assert_eq!(wrapper.read_float().unwrap(), 1.5);
+        assert_eq!(wrapper.read_double().unwrap(), -2.25);
+        assert!(wrapper.read_boolean().unwrap());
+        assert!(!wrapper.read_boolean().unwrap());
+        assert_eq!(wrapper.remaining(), 0);
+    }
+
+    #[test]
+    fn test_var_round_trip() {
+        for value in [0u16, 1, 127, 128, 300, u16::MAX] {
+            let mut wrapper = DataWrapper::new(var_encode(value as u64));
+            assert_eq!(wrapper.read_var_short().unwrap(), value);
+        }
+        for value in [0u32, 127, 128, 16384, u32::MAX] {
+            let mut wrapper = DataWrapper::new(var_encode(value as u64));
+            assert_eq!(wrapper.read_var_int().unwrap(), value);
+        }
+        for value in [0u64, 128, 1 << 40, u64::MAX] {
+            let mut wrapper = DataWrapper::new(var_encode(value));
+            assert_eq!(wrapper.read_var_long().unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_utf_round_trip() {
+        let text = "Hélo {map,-12,-6,1}";
+        let mut data = (text.len() as u16).to_be_bytes().to_vec();
+        data.extend_from_slice(text.as_bytes());
+        data.extend_from_slice(b"abc");
+
+        let mut wrapper = DataWrapper::new(data);
+        assert_eq!(wrapper.read_utf().unwrap(), text);
+        assert_eq!(wrapper.read_utf_bytes(3).unwrap(), "abc");
+        assert_eq!(wrapper.remaining(), 0);
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut wrapper = DataWrapper::new(vec![1, 2, 3]);
+        assert_eq!(wrapper.read_byte().unwrap(), 1);
+        assert_eq!(
+            wrapper.read_int(),
+            Err(ParseError::Truncated {
+                offset: 1,
+                needed: 4,
+                remaining: 2
+            })
+        );
+        // Nothing is consumed by a failed read
+        assert_eq!(wrapper.read_unsigned_short().unwrap(), 0x0203);
+
+        let mut wrapper = DataWrapper::new(vec![0xff, 0xff, 0xff]);
+        assert_eq!(
+            wrapper.read_var_short(),
+            Err(ParseError::TooMuchData {
+                offset: 0,
+                bits: 16
+            })
+        );
+        let mut wrapper = DataWrapper::new(vec![0, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01]);
+        wrapper.read_byte().unwrap();
+        assert_eq!(
+            wrapper.read_var_int(),
+            Err(ParseError::TooMuchData {
+                offset: 1,
+                bits: 32
+            })
+        );
+        let mut wrapper = DataWrapper::new(vec![0x80]);
+        assert!(matches!(
+            wrapper.read_var_long(),
+            Err(ParseError::Truncated { offset: 1, .. })
+        ));
+
+        let mut wrapper = DataWrapper::new(vec![0, 2, 0xc3, 0x28]);
+        assert!(matches!(
+            wrapper.read_utf(),
+            Err(ParseError::InvalidUtf8 { offset: 2, .. })
+        ));
+        let mut wrapper = DataWrapper::new(vec![0, 5, b'a']);
+        assert!(matches!(
+            wrapper.read_utf(),
+            Err(ParseError::Truncated {
+                offset: 2,
+                needed: 5,
+                remaining: 1
+            })
+        ));
+    }
 }
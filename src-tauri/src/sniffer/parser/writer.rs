@@ -0,0 +1,184 @@
+use thiserror::Error;
+
+/// Counterpart of `DataWrapper`, values are written big endian
+#[derive(Debug, Clone, Default)]
+pub struct DataWriter {
+    pub data: Vec<u8>,
+}
+
+impl DataWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn into_inner(self) -> Vec<u8> {
+        self.data
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.data.extend_from_slice(bytes);
+    }
+
+    pub fn write_byte(&mut self, value: u8) {
+        self.data.push(value);
+    }
+
+    pub fn write_signed_byte(&mut self, value: i8) {
+        self.write_bytes(&value.to_be_bytes());
+    }
+
+    pub fn write_boolean(&mut self, value: bool) {
+        self.write_byte(value as u8);
+    }
+
+    pub fn write_int(&mut self, value: i32) {
+        self.write_bytes(&value.to_be_bytes());
+    }
+
+    pub fn write_unsigned_int(&mut self, value: u32) {
+        self.write_bytes(&value.to_be_bytes());
+    }
+
+    pub fn write_long(&mut self, value: i64) {
+        self.write_bytes(&value.to_be_bytes());
+    }
+
+    pub fn write_unsigned_long(&mut self, value: u64) {
+        self.write_bytes(&value.to_be_bytes());
+    }
+
+    pub fn write_short(&mut self, value: i16) {
+        self.write_bytes(&value.to_be_bytes());
+    }
+
+    pub fn write_unsigned_short(&mut self, value: u16) {
+        self.write_bytes(&value.to_be_bytes());
+    }
+
+    pub fn write_float(&mut self, value: f32) {
+        self.write_bytes(&value.to_be_bytes());
+    }
+
+    pub fn write_double(&mut self, value: f64) {
+        self.write_bytes(&value.to_be_bytes());
+    }
+
+    /// 7 bits per byte, least significant group first, the high bit is set when more bytes follow
+    fn write_var(&mut self, mut value: u64) {
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                self.write_byte(byte);
+                return;
+            }
+            self.write_byte(byte | 0x80);
+        }
+    }
+
+    pub fn write_var_int(&mut self, value: u32) {
+        self.write_var(value as u64);
+    }
+
+    pub fn write_var_short(&mut self, value: u16) {
+        self.write_var(value as u64);
+    }
+
+    pub fn write_var_long(&mut self, value: u64) {
+        self.write_var(value);
+    }
+
+    /// Prefixed with its length in bytes
+    pub fn write_utf(&mut self, value: &str) -> Result<(), WriteError> {
+        let len = u16::try_from(value.len()).map_err(|_| WriteError::StringTooLong(value.len()))?;
+        self.write_unsigned_short(len);
+        self.write_utf_bytes(value);
+        Ok(())
+    }
+
+    pub fn write_utf_bytes(&mut self, value: &str) {
+        self.write_bytes(value.as_bytes());
+    }
+}
+
+#[derive(Debug, Error, PartialEq)]
+pub enum WriteError {
+    #[error("String of {0} bytes is too long to be prefixed with its length")]
+    StringTooLong(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sniffer::parser::wrapper::DataWrapper;
+
+    #[test]
+    fn test_round_trip() {
+        let mut writer = DataWriter::new();
+        writer.write_signed_byte(-5);
+        writer.write_byte(200);
+        writer.write_short(-1234);
+        writer.write_unsigned_short(60000);
+        writer.write_int(-123456);
+        writer.write_unsigned_int(4_000_000_000);
+        writer.write_long(-9_000_000_000);
+        writer.write_unsigned_long(u64::MAX);
+        writer.write_float(1.5);
+        writer.write_double(-2.25);
+        writer.write_boolean(true);
+        writer.write_var_short(u16::MAX);
+        writer.write_var_int(300);
+        writer.write_var_long(1 << 40);
+        writer.write_utf("Hélo").unwrap();
+
+        let mut wrapper = DataWrapper::new(writer.into_inner());
+        assert_eq!(wrapper.read_signed_byte().unwrap(), -5);
+        assert_eq!(wrapper.read_byte().unwrap(), 200);
+        assert_eq!(wrapper.read_short().unwrap(), -1234);
+        assert_eq!(wrapper.read_unsigned_short().unwrap(), 60000);
+        assert_eq!(wrapper.read_int().unwrap(), -123456);
+        assert_eq!(wrapper.read_unsigned_int().unwrap(), 4_000_000_000);
+        assert_eq!(wrapper.read_long().unwrap(), -9_000_000_000);
+        assert_eq!(wrapper.read_unsigned_long().unwrap(), u64::MAX);
+        assert_eq!(wrapper.read_float().unwrap(), 1.5);
+        assert_eq!(wrapper.read_double().unwrap(), -2.25);
+        assert!(wrapper.read_boolean().unwrap());
+        assert_eq!(wrapper.read_var_short().unwrap(), u16::MAX);
+        assert_eq!(wrapper.read_var_int().unwrap(), 300);
+        assert_eq!(wrapper.read_var_long().unwrap(), 1 << 40);
+        assert_eq!(wrapper.read_utf().unwrap(), "Hélo");
+        assert_eq!(wrapper.remaining(), 0);
+    }
+
+    #[test]
+    fn test_var_encoding() {
+        let mut writer = DataWriter::new();
+        writer.write_var_int(0);
+        writer.write_var_int(127);
+        writer.write_var_int(128);
+        writer.write_var_int(u32::MAX);
+        assert_eq!(
+            writer.into_inner(),
+            [0, 0x7f, 0x80, 0x01, 0xff, 0xff, 0xff, 0xff, 0x0f]
+        );
+    }
+
+    #[test]
+    fn test_string_too_long() {
+        let mut writer = DataWriter::new();
+        let value = "a".repeat(u16::MAX as usize + 1);
+        assert_eq!(
+            writer.write_utf(&value),
+            Err(WriteError::StringTooLong(value.len()))
+        );
+        assert!(writer.is_empty());
+    }
+}
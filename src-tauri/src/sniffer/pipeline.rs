@@ -0,0 +1,475 @@
+//! Stages run by the capture between the framing and the listeners.
+//!
+//! Every framed message goes through `Stage::on_message`, listeners or not. Only the messages
+//! with listeners left after it are decoded, and go through `Stage::on_packet` before they are
+//! sent to the listeners.
+//!
+//! No stage is added by default, the filters lose real messages: `Deduplicate` drops repeated
+//! contents and `RateLimit` drops the messages over its limit.
+
+use std::{
+    collections::{
+        hash_map::{DefaultHasher, RandomState},
+        HashMap, HashSet, VecDeque,
+    },
+    fmt::Debug,
+    hash::{BuildHasher, Hash, Hasher},
+    time::{Duration, Instant},
+};
+
+use serde_json::{Map, Value};
+use tracing::debug;
+
+use super::{
+    parser::{
+        metadata::PacketMetadata,
+        packet::{Packet, TYPE_NAME_KEY},
+    },
+    protocol::protocol::EventId,
+};
+
+/// Stages dropping messages, e.g. deduplication and rate limiting
+pub const FILTER_PRIORITY: i32 = 100;
+/// Stages changing the packets, e.g. anonymization and enrichment
+pub const TRANSFORM_PRIORITY: i32 = 200;
+/// Stages looking at what is sent to the listeners, e.g. logging
+pub const OBSERVE_PRIORITY: i32 = 300;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flow {
+    Continue,
+    /// The message is not decoded or not sent to the listeners, the next stages don't see it
+    Drop,
+}
+
+/// Stages run on the capture thread, a slow stage delays every message
+pub trait Stage: Send {
+    /// Reported in the capture statistics when the stage drops a message
+    fn name(&self) -> &str;
+
+    /// Called for every message, the listeners are looked up with the id it leaves
+    fn on_message(&mut self, _metadata: &mut PacketMetadata) -> Flow {
+        Flow::Continue
+    }
+
+    /// Only the fields selected by the listeners of the message are decoded
+    fn on_packet(&mut self, _packet: &mut Packet) -> Flow {
+        Flow::Continue
+    }
+}
+
+/// Stages ordered by priority, stages with the same priority run in the order they were added
+#[derive(Default)]
+pub struct Pipeline {
+    stages: Vec<(i32, Box<dyn Stage>)>,
+}
+
+impl Debug for Pipeline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.stages()).finish()
+    }
+}
+
+impl Pipeline {
+    pub fn new() -> Self {
+        Pipeline::default()
+    }
+
+    pub fn add(&mut self, priority: i32, stage: impl Stage + 'static) {
+        let index = self
+            .stages
+            .partition_point(|(existing, _)| *existing <= priority);
+        self.stages.insert(index, (priority, Box::new(stage)));
+    }
+
+    /// Remove the stages with this name, returns whether there was one
+    pub fn remove(&mut self, name: &str) -> bool {
+        let len = self.stages.len();
+        self.stages.retain(|(_, stage)| stage.name() != name);
+        self.stages.len() != len
+    }
+
+    /// Priorities and names of the stages, in the order they run
+    pub fn stages(&self) -> impl Iterator<Item = (i32, &str)> {
+        self.stages
+            .iter()
+            .map(|(priority, stage)| (*priority, stage.name()))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+
+    /// Name of the stage that dropped the message, if any
+    pub fn process_message(&mut self, metadata: &mut PacketMetadata) -> Option<&str> {
+        for (_, stage) in self.stages.iter_mut() {
+            if stage.on_message(metadata) == Flow::Drop {
+                return Some(stage.name());
+            }
+        }
+        None
+    }
+
+    /// Name of the stage that dropped the packet, if any
+    pub fn process_packet(&mut self, packet: &mut Packet) -> Option<&str> {
+        for (_, stage) in self.stages.iter_mut() {
+            if stage.on_packet(packet) == Flow::Drop {
+                return Some(stage.name());
+            }
+        }
+        None
+    }
+}
+
+/// Drops the messages with the same id, direction and content as one of the last `window`
+/// messages. Identical messages are often real ones (a repeated chat line, the same movement,
+/// pings), they are dropped too: only add it for listeners that want each content once.
+/// TCP retransmissions never get here, the stream reassembly already dropped them
+#[derive(Debug)]
+pub struct Deduplicate {
+    window: usize,
+    recent: VecDeque<u64>,
+}
+
+impl Deduplicate {
+    pub fn new(window: usize) -> Self {
+        Deduplicate {
+            window,
+            recent: VecDeque::with_capacity(window),
+        }
+    }
+}
+
+impl Stage for Deduplicate {
+    fn name(&self) -> &str {
+        "deduplicate"
+    }
+
+    fn on_message(&mut self, metadata: &mut PacketMetadata) -> Flow {
+        let mut hasher = DefaultHasher::new();
+        metadata.id.hash(&mut hasher);
+        metadata.direction.hash(&mut hasher);
+        metadata.data.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        if self.recent.contains(&hash) {
+            return Flow::Drop;
+        }
+        if self.recent.len() >= self.window {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(hash);
+        Flow::Continue
+    }
+}
+
+/// Lets at most `max` messages of each id through every `period`
+#[derive(Debug)]
+pub struct RateLimit {
+    max: u32,
+    period: Duration,
+    /// Start of the current period and messages let through since then
+    windows: HashMap<EventId, (Instant, u32)>,
+}
+
+impl RateLimit {
+    pub fn new(max: u32, period: Duration) -> Self {
+        RateLimit {
+            max,
+            period,
+            windows: HashMap::new(),
+        }
+    }
+}
+
+impl Stage for RateLimit {
+    fn name(&self) -> &str {
+        "rate-limit"
+    }
+
+    fn on_message(&mut self, metadata: &mut PacketMetadata) -> Flow {
+        let now = Instant::now();
+        let (start, count) = self.windows.entry(metadata.id).or_insert((now, 0));
+        if now.duration_since(*start) >= self.period {
+            *start = now;
+            *count = 0;
+        }
+        if *count >= self.max {
+            return Flow::Drop;
+        }
+        *count += 1;
+        Flow::Continue
+    }
+}
+
+/// Hides the values of the given fields at any depth of the packets, with every value nested in
+/// them. Strings are replaced by a hash so the same value can still be recognized, other values
+/// are zeroed.
+///
+/// The hash is keyed with a random key drawn for each `Anonymize`, and never saved: the hashes
+/// can't be reversed by hashing known names, and can't be matched between two sessions.
+#[derive(Debug)]
+pub struct Anonymize {
+    fields: HashSet<String>,
+    key: RandomState,
+}
+
+impl Anonymize {
+    pub fn new<S: Into<String>>(fields: impl IntoIterator<Item = S>) -> Self {
+        Anonymize {
+            fields: fields.into_iter().map(Into::into).collect(),
+            key: RandomState::new(),
+        }
+    }
+
+    fn hide(&self, value: &mut Value) {
+        match value {
+            Value::String(string) => {
+                *string = format!("{:016x}", self.key.hash_one(&*string));
+            }
+            Value::Number(_) => *value = Value::from(0),
+            Value::Bool(flag) => *flag = false,
+            Value::Array(values) => values.iter_mut().for_each(|value| self.hide(value)),
+            Value::Object(fields) => {
+                // The class of polymorphic values is not part of the data
+                fields
+                    .iter_mut()
+                    .filter(|(name, _)| *name != TYPE_NAME_KEY)
+                    .for_each(|(_, value)| self.hide(value));
+            }
+            Value::Null => {}
+        }
+    }
+
+    fn walk_fields(&self, fields: &mut Map<String, Value>) {
+        for (name, value) in fields.iter_mut() {
+            if self.fields.contains(name) {
+                self.hide(value);
+            } else {
+                self.walk(value);
+            }
+        }
+    }
+
+    fn walk(&self, value: &mut Value) {
+        match value {
+            Value::Object(fields) => self.walk_fields(fields),
+            Value::Array(values) => values.iter_mut().for_each(|value| self.walk(value)),
+            _ => {}
+        }
+    }
+}
+
+impl Stage for Anonymize {
+    fn name(&self) -> &str {
+        "anonymize"
+    }
+
+    fn on_packet(&mut self, packet: &mut Packet) -> Flow {
+        self.walk_fields(&mut packet.data);
+        Flow::Continue
+    }
+}
+
+/// Logs the packets sent to the listeners, at the debug level
+#[derive(Debug, Default)]
+pub struct Log;
+
+impl Stage for Log {
+    fn name(&self) -> &str {
+        "log"
+    }
+
+    fn on_packet(&mut self, packet: &mut Packet) -> Flow {
+        debug!("{} ({}): {:?}", packet.name, packet.id, packet.data);
+        Flow::Continue
+    }
+}
+
+/// Stage made of a closure over the decoded packets, e.g. to enrich them with game data
+pub struct PacketFn<F> {
+    name: String,
+    f: F,
+}
+
+impl<F> PacketFn<F>
+where
+    F: FnMut(&mut Packet) -> Flow + Send,
+{
+    pub fn new(name: impl Into<String>, f: F) -> Self {
+        PacketFn {
+            name: name.into(),
+            f,
+        }
+    }
+}
+
+impl<F> Debug for PacketFn<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PacketFn")
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+impl<F> Stage for PacketFn<F>
+where
+    F: FnMut(&mut Packet) -> Flow + Send,
+{
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn on_packet(&mut self, packet: &mut Packet) -> Flow {
+        (self.f)(packet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::sniffer::parser::metadata::PacketDirection;
+
+    fn metadata(id: EventId, data: &[u8]) -> PacketMetadata {
+        PacketMetadata {
+            data: data.to_vec(),
+            id,
+            size: data.len() as u32,
+            direction: PacketDirection::In,
+            instance_id: None,
+        }
+    }
+
+    fn packet(data: Value) -> Packet {
+        Packet {
+            id: 1,
+            name: "ChatServerMessage".to_string(),
+            direction: PacketDirection::In,
+            data: data.as_object().unwrap().clone(),
+        }
+    }
+
+    #[test]
+    fn test_order() {
+        let mut pipeline = Pipeline::new();
+        let stage = |name: &'static str| {
+            PacketFn::new(name, move |packet: &mut Packet| {
+                packet.name.push_str(name);
+                Flow::Continue
+            })
+        };
+        pipeline.add(OBSERVE_PRIORITY, stage("c"));
+        pipeline.add(FILTER_PRIORITY, stage("a"));
+        pipeline.add(TRANSFORM_PRIORITY, stage("b1"));
+        pipeline.add(TRANSFORM_PRIORITY, stage("b2"));
+        assert_eq!(
+            pipeline.stages().collect::<Vec<_>>(),
+            [
+                (FILTER_PRIORITY, "a"),
+                (TRANSFORM_PRIORITY, "b1"),
+                (TRANSFORM_PRIORITY, "b2"),
+                (OBSERVE_PRIORITY, "c")
+            ]
+        );
+
+        let mut chat = packet(json!({}));
+        assert_eq!(pipeline.process_packet(&mut chat), None);
+        assert_eq!(chat.name, "ChatServerMessageab1b2c");
+
+        // The next stages don't see dropped packets
+        pipeline.add(
+            FILTER_PRIORITY,
+            PacketFn::new("drop", |_: &mut Packet| Flow::Drop),
+        );
+        let mut chat = packet(json!({}));
+        assert_eq!(pipeline.process_packet(&mut chat), Some("drop"));
+        assert_eq!(chat.name, "ChatServerMessagea");
+
+        assert!(pipeline.remove("drop"));
+        assert!(!pipeline.remove("drop"));
+        assert_eq!(pipeline.process_packet(&mut chat), None);
+    }
+
+    #[test]
+    fn test_deduplicate() {
+        let mut pipeline = Pipeline::new();
+        pipeline.add(FILTER_PRIORITY, Deduplicate::new(2));
+        let mut process =
+            |id, data: &[u8]| pipeline.process_message(&mut metadata(id, data)).is_none();
+
+        assert!(process(1, &[1, 2]));
+        assert!(!process(1, &[1, 2]));
+        assert!(process(2, &[1, 2]));
+        assert!(process(1, &[3]));
+        // Out of the window
+        assert!(process(1, &[1, 2]));
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let mut pipeline = Pipeline::new();
+        pipeline.add(
+            FILTER_PRIORITY,
+            RateLimit::new(2, Duration::from_secs(3600)),
+        );
+        let mut process = |id| pipeline.process_message(&mut metadata(id, &[])).is_none();
+
+        assert!(process(1));
+        assert!(process(1));
+        assert!(!process(1));
+        assert!(process(2));
+
+        let mut pipeline = Pipeline::new();
+        pipeline.add(FILTER_PRIORITY, RateLimit::new(1, Duration::ZERO));
+        assert!(pipeline.process_message(&mut metadata(1, &[])).is_none());
+        assert!(pipeline.process_message(&mut metadata(1, &[])).is_none());
+    }
+
+    #[test]
+    fn test_anonymize() {
+        let mut pipeline = Pipeline::new();
+        pipeline.add(
+            TRANSFORM_PRIORITY,
+            Anonymize::new(["senderName", "accountId", "character"]),
+        );
+        let mut chat = packet(json!({
+            "senderName": "Player",
+            "content": "Hello",
+            "senderAccountId": 12,
+            "objects": [{ "accountId": 12, "names": ["Player"] }],
+            "character": {
+                "__type": "CharacterMinimalInformations",
+                "name": "Player",
+                "level": 200,
+                "options": [{ "visible": true }],
+            },
+        }));
+        let mut other = packet(json!({ "senderName": "Player" }));
+        pipeline.process_packet(&mut chat);
+        pipeline.process_packet(&mut other);
+
+        assert_ne!(chat.data["senderName"], "Player");
+        assert_eq!(chat.data["senderName"], other.data["senderName"]);
+        assert_eq!(chat.data["content"], "Hello");
+        assert_eq!(chat.data["senderAccountId"], 12);
+        assert_eq!(chat.data["objects"][0]["accountId"], 0);
+        assert_eq!(chat.data["objects"][0]["names"], json!(["Player"]));
+
+        // Every value of a hidden object is hidden, its class is kept
+        let character = &chat.data["character"];
+        assert_eq!(character["__type"], "CharacterMinimalInformations");
+        assert_eq!(character["name"], chat.data["senderName"]);
+        assert_eq!(character["level"], 0);
+        assert_eq!(character["options"], json!([{ "visible": false }]));
+
+        // Another session uses another key
+        let mut pipeline = Pipeline::new();
+        pipeline.add(TRANSFORM_PRIORITY, Anonymize::new(["senderName"]));
+        let mut next = packet(json!({ "senderName": "Player" }));
+        pipeline.process_packet(&mut next);
+        assert_ne!(next.data["senderName"], "Player");
+        assert_ne!(next.data["senderName"], chat.data["senderName"]);
+    }
+}
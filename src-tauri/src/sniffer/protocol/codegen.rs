@@ -0,0 +1,378 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::Write,
+};
+
+use thiserror::Error;
+
+use super::{
+    plan::{DecodingPlan, PlanStep, PlanType},
+    protocol::{EventId, EventName, FieldName, ProtocolManager, ProtocolVarType},
+};
+
+/// Root of every message, the other classes are types used in the messages
+pub const MESSAGE_ROOT: &str = "NetworkMessage";
+
+const HEADER: &str = "\
+// @generated by `cargo run --bin dtools-codegen`, do not edit
+#![allow(clippy::large_enum_variant)]
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::{decode, float, floats, MessageError};
+";
+
+/// Reserved words that can't be raw identifiers
+const PATH_KEYWORDS: &[&str] = &["crate", "self", "super", "Self"];
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "static",
+    "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual",
+    "where", "while", "yield",
+];
+
+#[derive(Debug, Error)]
+pub enum CodegenError {
+    #[error("Class {0} is missing from the protocol")]
+    MissingClass(EventName),
+    #[error("{class}.{field} has the same Rust name as another field")]
+    DuplicateField { class: EventName, field: FieldName },
+}
+
+/// Rust source of a struct for every class and of the `GameMessage` enum, see `sniffer::messages`
+pub fn generate(protocol: &ProtocolManager) -> Result<String, CodegenError> {
+    let root = MESSAGE_ROOT.to_string();
+    if protocol.get_protocol_by_class(&root).is_none() {
+        return Err(CodegenError::MissingClass(root));
+    }
+
+    // Sorted by name so that the output only changes with the protocol
+    let plans = protocol
+        .ids()
+        .filter_map(|id| protocol.get_plan(id))
+        .map(|plan| (plan.name.as_str(), plan))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut polymorphic = BTreeMap::new();
+    for plan in plans.values() {
+        for step in &plan.steps {
+            if let PlanStep::Field(_, plan_type) = step {
+                collect_polymorphic(protocol, plan_type, &mut polymorphic);
+            }
+        }
+    }
+
+    let mut code = HEADER.to_string();
+    for plan in plans.values() {
+        write_struct(&mut code, protocol, plan)?;
+    }
+    for (name, base) in &polymorphic {
+        let variants = plans
+            .values()
+            .filter(|plan| plan.is_subclass_of(*base))
+            .map(|plan| plan.name.as_str());
+        write_polymorphic(&mut code, name, variants);
+    }
+    let messages = plans
+        .values()
+        .filter(|plan| plan.name != root)
+        .filter(|plan| {
+            let schema = protocol.get_protocol(&plan.id).unwrap();
+            protocol.is_subclass_of(schema, &root)
+        })
+        .map(|plan| plan.name.as_str())
+        .collect::<Vec<_>>();
+    write_messages(&mut code, &messages);
+
+    Ok(code)
+}
+
+/// Enum of the classes a `TypeId` value can be
+fn polymorphic_name(base: &str) -> String {
+    format!("{}Kind", base)
+}
+
+fn collect_polymorphic<'a>(
+    protocol: &'a ProtocolManager,
+    plan_type: &PlanType,
+    polymorphic: &mut BTreeMap<&'a str, EventId>,
+) {
+    match plan_type {
+        PlanType::TypeId(id) => {
+            if let Some(plan) = protocol.get_plan(id) {
+                polymorphic.insert(&plan.name, *id);
+            }
+        }
+        PlanType::Vector(vector) => collect_polymorphic(protocol, &vector.types, polymorphic),
+        _ => {}
+    }
+}
+
+fn write_struct(
+    code: &mut String,
+    protocol: &ProtocolManager,
+    plan: &DecodingPlan,
+) -> Result<(), CodegenError> {
+    let mut fields = Vec::new();
+    for step in &plan.steps {
+        match step {
+            PlanStep::PackedBooleans(names) => {
+                fields.extend(names.iter().map(|name| (name, "bool".to_string(), None)))
+            }
+            PlanStep::Field(name, plan_type) => fields.push((
+                name,
+                rust_type(protocol, plan_type),
+                float_decoder(plan_type),
+            )),
+            // Decoding fails before the data reaches the struct
+            PlanStep::UnknownParent(_) => {}
+        }
+    }
+
+    let mut idents = HashSet::new();
+    writeln!(code).unwrap();
+    writeln!(code, "/// `{}`, id {}", plan.name, plan.id).unwrap();
+    writeln!(
+        code,
+        "#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]"
+    )
+    .unwrap();
+    if fields.is_empty() {
+        writeln!(code, "pub struct {} {{}}", plan.name).unwrap();
+        return Ok(());
+    }
+    // The listeners can select only some of the fields
+    writeln!(code, "#[serde(default)]").unwrap();
+    writeln!(code, "pub struct {} {{", plan.name).unwrap();
+    for (name, rust_type, decoder) in fields {
+        let ident = field_ident(name);
+        if !idents.insert(ident.clone()) {
+            return Err(CodegenError::DuplicateField {
+                class: plan.name.clone(),
+                field: name.to_string(),
+            });
+        }
+
+        let mut attributes = Vec::new();
+        if ident.trim_start_matches("r#") != name.as_ref() {
+            attributes.push(format!("rename = \"{}\"", name));
+        }
+        if let Some(decoder) = decoder {
+            attributes.push(format!("deserialize_with = \"{}\"", decoder));
+        }
+        if !attributes.is_empty() {
+            writeln!(code, "    #[serde({})]", attributes.join(", ")).unwrap();
+        }
+        writeln!(code, "    pub {}: {},", ident, rust_type).unwrap();
+    }
+    writeln!(code, "}}").unwrap();
+    Ok(())
+}
+
+fn write_polymorphic<'a>(code: &mut String, base: &str, variants: impl Iterator<Item = &'a str>) {
+    writeln!(code).unwrap();
+    writeln!(
+        code,
+        "/// `TypeId<{}>`, the class is named by `__type`",
+        base
+    )
+    .unwrap();
+    writeln!(
+        code,
+        "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]"
+    )
+    .unwrap();
+    writeln!(code, "#[serde(tag = \"__type\")]").unwrap();
+    writeln!(code, "pub enum {} {{", polymorphic_name(base)).unwrap();
+    for variant in variants {
+        writeln!(code, "    {}({}),", variant, variant).unwrap();
+    }
+    writeln!(code, "}}").unwrap();
+
+    // For the structs missing this field, the base class is one of the variants
+    writeln!(code).unwrap();
+    writeln!(code, "impl Default for {} {{", polymorphic_name(base)).unwrap();
+    writeln!(code, "    fn default() -> Self {{").unwrap();
+    writeln!(
+        code,
+        "        {}::{}(Default::default())",
+        polymorphic_name(base),
+        base
+    )
+    .unwrap();
+    writeln!(code, "    }}").unwrap();
+    writeln!(code, "}}").unwrap();
+}
+
+fn write_messages(code: &mut String, messages: &[&str]) {
+    writeln!(code).unwrap();
+    writeln!(code, "/// Every message of the protocol").unwrap();
+    writeln!(code, "#[derive(Debug, Clone, PartialEq, Serialize)]").unwrap();
+    writeln!(code, "#[serde(untagged)]").unwrap();
+    writeln!(code, "pub enum GameMessage {{").unwrap();
+    for message in messages {
+        writeln!(code, "    {}({}),", message, message).unwrap();
+    }
+    writeln!(code, "}}").unwrap();
+
+    writeln!(code).unwrap();
+    writeln!(code, "impl GameMessage {{").unwrap();
+    writeln!(
+        code,
+        "    /// Decode the data of the message named `name`, missing fields get their default value"
+    )
+    .unwrap();
+    writeln!(
+        code,
+        "    pub fn decode(name: &str, data: &Map<String, Value>) -> Result<Self, MessageError> {{"
+    )
+    .unwrap();
+    writeln!(code, "        let message = match name {{").unwrap();
+    for message in messages {
+        writeln!(
+            code,
+            "            \"{}\" => GameMessage::{}(decode(name, data)?),",
+            message, message
+        )
+        .unwrap();
+    }
+    writeln!(
+        code,
+        "            _ => return Err(MessageError::UnknownMessage(name.to_string())),"
+    )
+    .unwrap();
+    writeln!(code, "        }};").unwrap();
+    writeln!(code, "        Ok(message)").unwrap();
+    writeln!(code, "    }}").unwrap();
+    writeln!(code).unwrap();
+    writeln!(code, "    pub fn name(&self) -> &'static str {{").unwrap();
+    writeln!(code, "        match self {{").unwrap();
+    for message in messages {
+        writeln!(
+            code,
+            "            GameMessage::{}(_) => \"{}\",",
+            message, message
+        )
+        .unwrap();
+    }
+    writeln!(code, "        }}").unwrap();
+    writeln!(code, "    }}").unwrap();
+    writeln!(code, "}}").unwrap();
+}
+
+fn rust_type(protocol: &ProtocolManager, plan_type: &PlanType) -> String {
+    match plan_type {
+        PlanType::Primitive(primitive) => primitive_type(primitive).to_string(),
+        PlanType::Class(id) => match protocol.get_plan(id) {
+            Some(plan) => plan.name.clone(),
+            None => "Value".to_string(),
+        },
+        // Boxed, a class can contain one of its subclasses
+        PlanType::TypeId(id) => match protocol.get_plan(id) {
+            Some(plan) => format!("Box<{}>", polymorphic_name(&plan.name)),
+            None => "Value".to_string(),
+        },
+        PlanType::Vector(vector) => match &vector.types {
+            PlanType::TypeId(id) => match protocol.get_plan(id) {
+                Some(plan) => format!("Vec<{}>", polymorphic_name(&plan.name)),
+                None => "Vec<Value>".to_string(),
+            },
+            types => format!("Vec<{}>", rust_type(protocol, types)),
+        },
+        PlanType::Unresolved(_) => "Value".to_string(),
+    }
+}
+
+/// Same integer types as `DataWrapper`, the Var* types are read unsigned
+fn primitive_type(primitive: &ProtocolVarType) -> &'static str {
+    match primitive {
+        ProtocolVarType::String => "String",
+        ProtocolVarType::VarInt | ProtocolVarType::VarUhInt => "u32",
+        ProtocolVarType::VarLong | ProtocolVarType::VarUhLong => "u64",
+        ProtocolVarType::VarShort | ProtocolVarType::VarUhShort => "u16",
+        ProtocolVarType::Byte => "i8",
+        ProtocolVarType::UnsignedByte => "u8",
+        ProtocolVarType::Short => "i16",
+        ProtocolVarType::UnsignedShort => "u16",
+        ProtocolVarType::Int => "i32",
+        ProtocolVarType::UnsignedInt => "u32",
+        ProtocolVarType::Long => "i64",
+        ProtocolVarType::UnsignedLong => "u64",
+        ProtocolVarType::Float => "f32",
+        ProtocolVarType::Double => "f64",
+        ProtocolVarType::None => "()",
+        ProtocolVarType::Boolean => "bool",
+        _ => "Value",
+    }
+}
+
+/// Float fields are `null` in the packets when the value was NaN or infinite, they need a
+/// deserializer turning it back into a number
+fn float_decoder(plan_type: &PlanType) -> Option<&'static str> {
+    let is_float = |plan_type: &PlanType| {
+        matches!(
+            plan_type,
+            PlanType::Primitive(ProtocolVarType::Float | ProtocolVarType::Double)
+        )
+    };
+    match plan_type {
+        plan_type if is_float(plan_type) => Some("float"),
+        PlanType::Vector(vector) if is_float(&vector.types) => Some("floats"),
+        _ => None,
+    }
+}
+
+/// `objectUID` becomes `object_uid`
+fn field_ident(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut ident = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            if previous.is_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_uppercase() && next_is_lower)
+            {
+                ident.push('_');
+            }
+        }
+        ident.extend(c.to_lowercase());
+    }
+
+    if PATH_KEYWORDS.contains(&ident.as_str()) {
+        ident.push('_');
+    } else if KEYWORDS.contains(&ident.as_str()) {
+        ident.insert_str(0, "r#");
+    }
+    ident
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_ident() {
+        assert_eq!(field_ident("senderName"), "sender_name");
+        assert_eq!(field_ident("objectUID"), "object_uid");
+        assert_eq!(field_ident("objectsUIDAndQty"), "objects_uid_and_qty");
+        assert_eq!(field_ident("jobXP"), "job_xp");
+        assert_eq!(field_ident("type"), "r#type");
+        assert_eq!(field_ident("self"), "self_");
+    }
+
+    #[test]
+    fn test_generated_is_up_to_date() {
+        let mut protocol = ProtocolManager::new();
+        protocol.init("tests/fixtures/").unwrap();
+        let code = generate(&protocol).unwrap();
+        assert!(
+            code == include_str!("../messages/generated.rs"),
+            "run `cargo run --bin dtools-codegen` to update src/sniffer/messages/generated.rs"
+        );
+    }
+}
@@ -1 +1,6 @@
+pub mod codegen;
+pub mod plan;
+#[allow(clippy::module_inception)]
 pub mod protocol;
+pub mod type_expr;
+pub mod validation;
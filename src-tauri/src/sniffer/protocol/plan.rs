@@ -0,0 +1,282 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use super::protocol::{EventId, EventName, ProtocolSchema, ProtocolVarType, VectorLength};
+
+/// Field name shared by every plan with a field of that name
+pub type PlanFieldName = Arc<str>;
+
+/// Attribute type with every class resolved to its id, which indexes the `PlanTable`
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlanType {
+    Primitive(ProtocolVarType),
+    Class(EventId),
+    /// Prefixed with the id of the concrete class, which inherits from this one
+    TypeId(EventId),
+    Vector(Box<PlanVector>),
+    /// The class is missing from the protocol, decoding fails when it is reached
+    Unresolved(EventName),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlanVector {
+    pub length: VectorLength,
+    pub types: PlanType,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlanStep {
+    /// BooleanByteWrapper flags, 8 per byte
+    PackedBooleans(Vec<PlanFieldName>),
+    Field(PlanFieldName, PlanType),
+    /// The parent class is missing from the protocol, decoding fails when it is reached
+    UnknownParent(EventName),
+}
+
+/// Flat list of reads for a class, the attributes of its parents come first
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodingPlan {
+    pub id: EventId,
+    pub name: EventName,
+    /// Ids of the class and of all its ancestors
+    pub lineage: Vec<EventId>,
+    pub steps: Vec<PlanStep>,
+}
+
+impl DecodingPlan {
+    pub fn is_subclass_of(&self, id: EventId) -> bool {
+        self.lineage.contains(&id)
+    }
+}
+
+/// Marks the ids without a plan in `PlanTable::indices`
+const NO_PLAN: u32 = u32::MAX;
+
+/// Plans indexed by id, nested classes and polymorphic values are found without hashing
+#[derive(Debug, Default)]
+pub struct PlanTable {
+    /// Position in `plans` of each id
+    indices: Vec<u32>,
+    plans: Vec<DecodingPlan>,
+}
+
+impl PlanTable {
+    pub fn get(&self, id: EventId) -> Option<&DecodingPlan> {
+        let index = *self.indices.get(id as usize)?;
+        self.plans.get(index as usize)
+    }
+}
+
+struct PlanCompiler<'a> {
+    protocol_by_id: &'a HashMap<EventId, ProtocolSchema>,
+    protocol_id_by_name: &'a HashMap<EventName, EventId>,
+    protocol_by_name: &'a HashMap<EventName, ProtocolSchema>,
+    names: RefCell<HashSet<PlanFieldName>>,
+}
+
+/// Compile every schema with an id once, the indexes must not contain inheritance cycles.
+/// `protocol_by_name` has the classes only reachable by name, they can still be parents
+pub fn compile(
+    protocol_by_id: &HashMap<EventId, ProtocolSchema>,
+    protocol_id_by_name: &HashMap<EventName, EventId>,
+    protocol_by_name: &HashMap<EventName, ProtocolSchema>,
+) -> PlanTable {
+    let compiler = PlanCompiler {
+        protocol_by_id,
+        protocol_id_by_name,
+        protocol_by_name,
+        names: RefCell::new(HashSet::new()),
+    };
+
+    let len = protocol_by_id.keys().max().map_or(0, |id| *id as usize + 1);
+    let mut table = PlanTable {
+        indices: vec![NO_PLAN; len],
+        plans: Vec::with_capacity(protocol_by_id.len()),
+    };
+    for (id, schema) in protocol_by_id {
+        table.indices[*id as usize] = table.plans.len() as u32;
+        table.plans.push(compiler.compile_schema(*id, schema));
+    }
+    table
+}
+
+impl PlanCompiler<'_> {
+    fn intern(&self, name: &str) -> PlanFieldName {
+        let mut names = self.names.borrow_mut();
+        if let Some(name) = names.get(name) {
+            return name.clone();
+        }
+        let name = PlanFieldName::from(name);
+        names.insert(name.clone());
+        name
+    }
+
+    /// The id is `None` for the classes only reachable by name
+    fn resolve(&self, class: &EventName) -> Option<(Option<EventId>, &ProtocolSchema)> {
+        match self.protocol_id_by_name.get(class) {
+            Some(id) => Some((Some(*id), self.protocol_by_id.get(id)?)),
+            None => Some((None, self.protocol_by_name.get(class)?)),
+        }
+    }
+
+    fn compile_schema(&self, id: EventId, schema: &ProtocolSchema) -> DecodingPlan {
+        let mut lineage = vec![id];
+        let mut current = schema;
+        while let Some((parent_id, parent)) = current
+            .parent
+            .as_ref()
+            .and_then(|parent| self.resolve(parent))
+        {
+            lineage.extend(parent_id);
+            current = parent;
+        }
+
+        let mut steps = Vec::new();
+        self.compile_steps(schema, &mut steps);
+
+        DecodingPlan {
+            id,
+            name: schema.name.clone(),
+            lineage,
+            steps,
+        }
+    }
+
+    fn compile_steps(&self, schema: &ProtocolSchema, steps: &mut Vec<PlanStep>) {
+        if let Some(parent) = &schema.parent {
+            match self.resolve(parent) {
+                // Classes without an id (NetworkMessage) have nothing to read
+                Some((_, parent)) if parent.id.is_some() => self.compile_steps(parent, steps),
+                Some(_) => {}
+                None => steps.push(PlanStep::UnknownParent(parent.clone())),
+            }
+        }
+
+        let packed_booleans = schema.packed_booleans();
+        if !packed_booleans.is_empty() {
+            let names = packed_booleans.iter().map(|name| self.intern(name));
+            steps.push(PlanStep::PackedBooleans(names.collect()));
+        }
+        for (name, var_type) in &schema.attributes {
+            if packed_booleans.contains(name) {
+                continue;
+            }
+            steps.push(PlanStep::Field(
+                self.intern(name),
+                self.compile_type(var_type),
+            ));
+        }
+    }
+
+    fn compile_type(&self, var_type: &ProtocolVarType) -> PlanType {
+        match var_type {
+            ProtocolVarType::Class(name) => self.compile_class(name, PlanType::Class),
+            ProtocolVarType::TypeId(base) => self.compile_polymorphic(base),
+            ProtocolVarType::Vector(vector) => {
+                let types = match vector.type_id {
+                    true => self.compile_polymorphic(&vector.types),
+                    false => self.compile_type(&vector.types),
+                };
+                PlanType::Vector(Box::new(PlanVector {
+                    length: vector.length.clone(),
+                    types,
+                }))
+            }
+            primitive => PlanType::Primitive(primitive.clone()),
+        }
+    }
+
+    fn compile_polymorphic(&self, base: &ProtocolVarType) -> PlanType {
+        match base {
+            ProtocolVarType::Class(name) => self.compile_class(name, PlanType::TypeId),
+            // Rejected when the types are parsed
+            other => PlanType::Unresolved(other.to_string()),
+        }
+    }
+
+    fn compile_class(&self, name: &EventName, plan_type: fn(EventId) -> PlanType) -> PlanType {
+        match self.protocol_id_by_name.get(name) {
+            Some(id) => plan_type(*id),
+            None => PlanType::Unresolved(name.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sniffer::protocol::protocol::ProtocolManager;
+
+    use super::*;
+
+    #[test]
+    fn test_compile() {
+        let mut protocol = ProtocolManager::new();
+        protocol.init("tests/fixtures/").unwrap();
+        let id = |name: &str| {
+            *protocol
+                .get_protocol_id_by_class(&name.to_string())
+                .unwrap()
+        };
+
+        // ChatServerMessage inlines ChatAbstractServerMessage, NetworkMessage has no attributes
+        let plan = protocol.get_plan(&id("ChatServerMessage")).unwrap();
+        let fields = plan
+            .steps
+            .iter()
+            .map(|step| match step {
+                PlanStep::Field(name, _) => name.as_ref(),
+                _ => panic!("unexpected step {:?}", step),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            [
+                "channel",
+                "content",
+                "timestamp",
+                "fingerprint",
+                "senderId",
+                "senderName",
+                "prefix",
+                "senderAccountId"
+            ]
+        );
+        assert!(plan.is_subclass_of(id("ChatAbstractServerMessage")));
+
+        // CompassUpdateMessage { type: Byte, coords: TypeId<MapCoordinates> }
+        let plan = protocol.get_plan(&1650).unwrap();
+        assert_eq!(
+            plan.steps[1],
+            PlanStep::Field("coords".into(), PlanType::TypeId(id("MapCoordinates")))
+        );
+        let coordinates = protocol.get_plan(&id("MapCoordinatesExtended")).unwrap();
+        assert_eq!(
+            coordinates.lineage,
+            [
+                id("MapCoordinatesExtended"),
+                id("MapCoordinatesAndId"),
+                id("MapCoordinates")
+            ]
+        );
+
+        // ActorRestrictionsInformations, every attribute is a packed boolean
+        let plan = protocol.get_plan(&2944).unwrap();
+        assert!(matches!(
+            plan.steps.as_slice(),
+            [PlanStep::PackedBooleans(names)] if names.len() == 19
+        ));
+
+        // Every plan shares the same name
+        let channel = |name: &str| match &protocol.get_plan(&id(name)).unwrap().steps[0] {
+            PlanStep::Field(name, _) => name.clone(),
+            step => panic!("unexpected step {:?}", step),
+        };
+        assert!(Arc::ptr_eq(
+            &channel("ChatServerMessage"),
+            &channel("ChatServerWithObjectMessage")
+        ));
+    }
+}
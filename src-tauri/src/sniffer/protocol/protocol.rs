@@ -1,17 +1,23 @@
 use indexmap::IndexMap;
 use std::{
-    collections::HashMap,
+    collections::{hash_map::Entry, HashMap, HashSet},
     fmt::{Debug, Display},
-    path::Path,
+    path::{Path, PathBuf},
 };
 use thiserror::Error;
 
 use serde::*;
 use serde_aux::field_attributes::deserialize_option_number_from_string;
-use tracing::info;
+use tracing::{info, warn};
 
 use crate::constants::{EVENTS_FILE, EXTRACTOR_DIR};
 
+use super::{
+    plan::{self, DecodingPlan, PlanTable},
+    type_expr::TypeParseError,
+    validation::{self, SchemaIssue, ValidationReport},
+};
+
 pub type FieldName = String;
 pub type EventName = String;
 
@@ -25,172 +31,384 @@ pub enum KnownEvent {
 
 impl Display for KnownEvent {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        return Debug::fmt(self, f);
+        Debug::fmt(self, f)
     }
 }
 
-#[derive(Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+/// Attribute types, parsed from the names emitted by the extractor (see `type_expr`).
+/// Primitives are named after the CustomDataWrapper methods.
+/// `Uuid` is a network type of its own (`uuidString`), it is decoded through its schema.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum ProtocolVarType {
     String,
+    /// The extractor uses the same name for readVarInt and readVarUhInt, read as unsigned
     VarInt,
+    VarUhInt,
     VarLong,
+    VarUhLong,
     VarShort,
+    VarUhShort,
+    Byte,
+    UnsignedByte,
     Short,
+    UnsignedShort,
     Int,
-    Byte,
+    UnsignedInt,
+    Long,
+    UnsignedLong,
+    Float,
+    Double,
     None,
     Boolean,
-    Double,
 
-    #[serde(untagged)]
-    Other(EventName),
+    /// Network type decoded with its own schema
+    Class(EventName),
+    /// The value is prefixed with the id of its concrete class, which inherits from this one
+    TypeId(Box<ProtocolVarType>),
+    Vector(Box<ProtocolVarTypeVector>),
 }
 
 impl ProtocolVarType {
+    pub fn primitive(name: &str) -> Option<Self> {
+        let var_type = match name {
+            "String" => ProtocolVarType::String,
+            "VarInt" => ProtocolVarType::VarInt,
+            "VarUhInt" => ProtocolVarType::VarUhInt,
+            "VarLong" => ProtocolVarType::VarLong,
+            "VarUhLong" => ProtocolVarType::VarUhLong,
+            "VarShort" => ProtocolVarType::VarShort,
+            "VarUhShort" => ProtocolVarType::VarUhShort,
+            "Byte" => ProtocolVarType::Byte,
+            "UnsignedByte" => ProtocolVarType::UnsignedByte,
+            "Short" => ProtocolVarType::Short,
+            "UnsignedShort" => ProtocolVarType::UnsignedShort,
+            "Int" => ProtocolVarType::Int,
+            "UnsignedInt" => ProtocolVarType::UnsignedInt,
+            "Long" => ProtocolVarType::Long,
+            "UnsignedLong" => ProtocolVarType::UnsignedLong,
+            "Float" => ProtocolVarType::Float,
+            "Double" => ProtocolVarType::Double,
+            "None" => ProtocolVarType::None,
+            "Boolean" => ProtocolVarType::Boolean,
+            _ => return None,
+        };
+        Some(var_type)
+    }
+
     pub fn is_primitive(&self) -> bool {
-        match self {
-            ProtocolVarType::String
-            | ProtocolVarType::VarInt
-            | ProtocolVarType::VarLong
-            | ProtocolVarType::VarShort
-            | ProtocolVarType::Short
-            | ProtocolVarType::Int
-            | ProtocolVarType::Boolean
-            | ProtocolVarType::None
-            | ProtocolVarType::Double
-            | ProtocolVarType::Byte => true,
-            _ => false,
-        }
+        !self.is_complex()
     }
 
-    // The goal is to decompose Vector<ProcolVarType, ProtocolVarType> into a single ProtocolVarType
-    // same for TypeId<ProtocolVarType> and HashMap<ProtocolVarType, ProtocolVarType>
-    //
     pub fn is_complex(&self) -> bool {
-        !self.is_primitive()
+        matches!(
+            self,
+            ProtocolVarType::Class(_) | ProtocolVarType::TypeId(_) | ProtocolVarType::Vector(_)
+        )
     }
 
-    pub fn parse_vector(&self) -> Option<ProtocolVarTypeVector> {
-        match self {
-            ProtocolVarType::Other(name) => {
-                let is_vector = name.starts_with("Vector<"); // TODO: clean this up, it's ugly
-                let is_type_id_vector = name.starts_with("TypeIdVector<");
-                if is_vector || is_type_id_vector {
-                    let name = match is_vector {
-                        true => name.trim_start_matches("Vector<").trim_end_matches(">"),
-                        false => name
-                            .trim_start_matches("TypeIdVector<")
-                            .trim_end_matches(">"),
-                    };
-                    let mut parts = name.split(",");
-                    let a = parts.next().unwrap().trim();
-                    let b = parts.next().unwrap().trim();
-
-                    let a = serde_plain::from_str::<ProtocolVarType>(a).unwrap();
-                    let b = serde_plain::from_str::<ProtocolVarType>(b).unwrap();
-
-                    return Some(ProtocolVarTypeVector {
-                        length: a,
-                        types: b,
-                    });
-                }
-                None
-            }
-            _ => None,
-        }
+    /// Size on the wire of the types that don't depend on their value
+    pub fn fixed_size(&self) -> Option<usize> {
+        let size = match self {
+            ProtocolVarType::None => 0,
+            ProtocolVarType::Byte | ProtocolVarType::UnsignedByte | ProtocolVarType::Boolean => 1,
+            ProtocolVarType::Short | ProtocolVarType::UnsignedShort => 2,
+            ProtocolVarType::Int | ProtocolVarType::UnsignedInt | ProtocolVarType::Float => 4,
+            ProtocolVarType::Long | ProtocolVarType::UnsignedLong | ProtocolVarType::Double => 8,
+            _ => return None,
+        };
+        Some(size)
     }
 
-    pub fn parse_type_id(&self) -> Option<ProtocolVarType> {
+    /// Integer types that can prefix a vector with its length
+    pub fn is_length(&self) -> bool {
+        matches!(
+            self,
+            ProtocolVarType::Byte
+                | ProtocolVarType::UnsignedByte
+                | ProtocolVarType::Short
+                | ProtocolVarType::UnsignedShort
+                | ProtocolVarType::Int
+                | ProtocolVarType::UnsignedInt
+                | ProtocolVarType::VarShort
+                | ProtocolVarType::VarUhShort
+                | ProtocolVarType::VarInt
+                | ProtocolVarType::VarUhInt
+        )
+    }
+}
+
+/// Same syntax as the extractor, `Vector<VarShort,TypeId<PlayerStatus>>`
+impl Display for ProtocolVarType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            ProtocolVarType::Other(name) => {
-                if name.starts_with("TypeId<") {
-                    let name = name.trim_start_matches("TypeId<").trim_end_matches(">");
-                    let a = serde_plain::from_str::<ProtocolVarType>(name).unwrap();
-                    return Some(a);
+            ProtocolVarType::Class(name) => write!(f, "{}", name),
+            ProtocolVarType::TypeId(base) => write!(f, "TypeId<{}>", base),
+            ProtocolVarType::Vector(vector) => {
+                let container = match vector.type_id {
+                    true => "TypeIdVector",
+                    false => "Vector",
+                };
+                match &vector.length {
+                    VectorLength::Fixed(length) => {
+                        write!(f, "{}<{},{}>", container, length, vector.types)
+                    }
+                    VectorLength::Prefixed(length) => {
+                        write!(f, "{}<{},{}>", container, length, vector.types)
+                    }
                 }
-                None
             }
-            _ => None,
+            _ => Debug::fmt(self, f),
         }
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VectorLength {
+    /// Fixed size arrays, e.g. `Vector<5,Int>`
+    Fixed(usize),
+    /// The length is written before the values with this type
+    Prefixed(ProtocolVarType),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ProtocolVarTypeVector {
-    pub length: ProtocolVarType,
+    pub length: VectorLength,
     pub types: ProtocolVarType,
+    /// Each value is prefixed with the id of its concrete type (`TypeIdVector`)
+    pub type_id: bool,
 }
+
+/// Schema as written by the extractor, attribute types are still raw strings
 #[derive(Deserialize, Debug)]
-pub struct ProtocolSchema {
+struct RawProtocolSchema {
     #[serde(deserialize_with = "deserialize_option_number_from_string")]
+    id: Option<EventId>,
+    class_name: EventName,
+    superclass: Option<EventName>,
+    attributes: IndexMap<FieldName, String>,
+    #[serde(default)]
+    packed_booleans: Option<Vec<FieldName>>,
+}
+
+#[derive(Debug)]
+pub struct ProtocolSchema {
     pub id: Option<EventId>,
-    #[serde(rename = "class_name")]
     pub name: EventName,
-    #[serde(rename = "superclass")]
     pub parent: Option<EventName>,
     pub attributes: IndexMap<FieldName, ProtocolVarType>,
+    /// Boolean attributes packed as bit flags (BooleanByteWrapper), 8 per byte, in attribute order.
+    /// They are read before the other attributes of the class. Inferred when the extractor
+    /// doesn't provide it
+    pub packed_booleans: Option<Vec<FieldName>>,
+}
+
+impl TryFrom<RawProtocolSchema> for ProtocolSchema {
+    type Error = ProtocolError;
+
+    fn try_from(raw: RawProtocolSchema) -> Result<Self, Self::Error> {
+        let mut attributes = IndexMap::with_capacity(raw.attributes.len());
+        for (field, type_name) in raw.attributes {
+            let var_type =
+                type_name
+                    .parse()
+                    .map_err(|source| ProtocolError::InvalidAttributeType {
+                        class: raw.class_name.clone(),
+                        field: field.clone(),
+                        type_name: type_name.clone(),
+                        source,
+                    })?;
+            attributes.insert(field, var_type);
+        }
+
+        let mut schema = ProtocolSchema {
+            id: raw.id,
+            name: raw.class_name,
+            parent: raw.superclass,
+            attributes,
+            packed_booleans: raw.packed_booleans,
+        };
+        if schema.packed_booleans.is_none() {
+            schema.packed_booleans = Some(schema.infer_packed_booleans());
+        }
+        Ok(schema)
+    }
+}
+
+impl ProtocolSchema {
+    pub fn packed_booleans(&self) -> &[FieldName] {
+        self.packed_booleans.as_deref().unwrap_or_default()
+    }
+
+    /// The game serializer packs the booleans of a class as soon as it has more than one
+    fn infer_packed_booleans(&self) -> Vec<FieldName> {
+        let booleans = self
+            .attributes
+            .iter()
+            .filter(|(_, var_type)| **var_type == ProtocolVarType::Boolean)
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+
+        if booleans.len() > 1 {
+            return booleans;
+        }
+        Vec::new()
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtocolConfig {
+    /// Refuse to load a protocol with schema problems instead of logging them
+    pub strict: bool,
 }
 
 #[derive(Debug)]
 pub struct ProtocolManager {
     protocol_by_id: HashMap<EventId, ProtocolSchema>,
     protocol_id_by_name: HashMap<EventName, EventId>,
+    /// Classes only reachable by name: without an id (NetworkMessage), or whose id belongs to
+    /// an earlier class
+    protocol_by_name: HashMap<EventName, ProtocolSchema>,
+    plans: PlanTable,
+    config: ProtocolConfig,
+    report: ValidationReport,
+    /// Incremented by every `init`, the ids may have changed
+    generation: u64,
 }
 
+/// The classes with invalid attribute types are left out and reported as issues
 fn load_protocol(
     protocol_file_path: impl AsRef<Path>,
-) -> Result<HashMap<EventId, ProtocolSchema>, std::io::Error> {
+) -> Result<(Vec<ProtocolSchema>, Vec<SchemaIssue>), ProtocolError> {
     let protocol_file_path = protocol_file_path.as_ref();
     let protocol_file_path = protocol_file_path.join(EXTRACTOR_DIR).join(EVENTS_FILE);
 
-    let mut event_by_id = HashMap::new();
-
-    assert!(
-        protocol_file_path.exists(),
-        "Protocol file not found at {}",
-        protocol_file_path.display()
-    );
+    if !protocol_file_path.exists() {
+        return Err(ProtocolError::MissingProtocolFile(protocol_file_path));
+    }
 
     let content = std::fs::read_to_string(&protocol_file_path)?;
-    let protocol: Vec<ProtocolSchema> = serde_json::from_str(&content)?;
-
-    let mut without_id_count = 0;
-    for event in protocol {
-        if let Some(id) = event.id {
-            event_by_id.insert(id, event);
-        } else {
-            // There should be only one event without an id (NetworkMessage)
-            assert_eq!(without_id_count, 0);
-            without_id_count += 1;
-            event_by_id.insert(0, event);
+    let protocol: Vec<RawProtocolSchema> = serde_json::from_str(&content)?;
+
+    let mut schemas = Vec::with_capacity(protocol.len());
+    let mut issues = Vec::new();
+    for raw in protocol {
+        match ProtocolSchema::try_from(raw) {
+            Ok(schema) => schemas.push(schema),
+            Err(ProtocolError::InvalidAttributeType {
+                class,
+                field,
+                type_name,
+                source,
+            }) => issues.push(SchemaIssue::InvalidAttributeType {
+                class,
+                field,
+                type_name,
+                error: source,
+            }),
+            Err(err) => return Err(err),
         }
     }
-    return Ok(event_by_id);
+    Ok((schemas, issues))
+}
+
+impl Default for ProtocolManager {
+    fn default() -> Self {
+        ProtocolManager::new()
+    }
 }
 
 impl ProtocolManager {
     pub fn new() -> Self {
-        return ProtocolManager {
+        ProtocolManager::with_config(ProtocolConfig::default())
+    }
+
+    pub fn with_config(config: ProtocolConfig) -> Self {
+        ProtocolManager {
             protocol_by_id: HashMap::new(),
             protocol_id_by_name: HashMap::new(),
-        };
+            protocol_by_name: HashMap::new(),
+            plans: PlanTable::default(),
+            config,
+            report: ValidationReport::default(),
+            generation: 0,
+        }
     }
 
     pub fn init(&mut self, protocol_file_path: impl AsRef<Path>) -> Result<&Self, ProtocolError> {
-        let protocol_by_id = load_protocol(protocol_file_path)?;
-        let protocol_id_by_name: HashMap<EventName, EventId> =
-            protocol_by_id
-                .iter()
-                .fold(HashMap::new(), |mut map, (id, event)| {
-                    map.insert(event.name.clone(), *id);
-                    return map;
-                });
+        let (protocol, mut issues) = load_protocol(protocol_file_path)?;
+
+        let mut report = validation::validate(&protocol);
+        issues.append(&mut report.issues);
+        report.issues = issues;
+        if !report.is_valid() {
+            if self.config.strict {
+                return Err(ProtocolError::InvalidSchema(report));
+            }
+            for issue in &report.issues {
+                warn!("Protocol schema: {}", issue);
+            }
+        }
+
+        // Their parent chain never ends, the packets using them would never be decoded
+        let cyclic = report
+            .issues
+            .iter()
+            .filter_map(|issue| match issue {
+                SchemaIssue::InheritanceCycle { classes } => Some(classes.clone()),
+                _ => None,
+            })
+            .flatten()
+            .collect::<HashSet<_>>();
+
+        // Same as the validation, the first definition of an id and of a name wins
+        let mut protocol_by_id = HashMap::new();
+        let mut protocol_id_by_name = HashMap::new();
+        let mut protocol_by_name = HashMap::new();
+        let mut names = HashSet::new();
+        for event in protocol {
+            if cyclic.contains(&event.name) || !names.insert(event.name.clone()) {
+                continue;
+            }
+            match event.id.map(|id| protocol_by_id.entry(id)) {
+                Some(Entry::Vacant(entry)) => {
+                    protocol_id_by_name.insert(event.name.clone(), *entry.key());
+                    entry.insert(event);
+                }
+                _ => {
+                    protocol_by_name.insert(event.name.clone(), event);
+                }
+            }
+        }
 
         info!("Loaded {} protocols", protocol_by_id.len());
 
+        self.plans = plan::compile(&protocol_by_id, &protocol_id_by_name, &protocol_by_name);
         self.protocol_by_id = protocol_by_id;
         self.protocol_id_by_name = protocol_id_by_name;
+        self.protocol_by_name = protocol_by_name;
+        self.report = report;
+        self.generation += 1;
+
+        Ok(self)
+    }
+
+    /// Problems found in the schema by the last `init`
+    pub fn validation_report(&self) -> &ValidationReport {
+        &self.report
+    }
 
-        return Ok(self);
+    /// Changes when a protocol is loaded, to invalidate what was derived from the previous one
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = &EventId> {
+        self.protocol_by_id.keys()
+    }
+
+    pub fn get_plan(&self, id: &EventId) -> Option<&DecodingPlan> {
+        self.plans.get(*id)
     }
 
     pub fn get_protocol(&self, id: &EventId) -> Option<&ProtocolSchema> {
@@ -201,16 +419,199 @@ impl ProtocolManager {
         if let Some(id) = self.protocol_id_by_name.get(class) {
             return self.get_protocol(id);
         }
-        None
+        self.protocol_by_name.get(class)
     }
 
     pub fn get_protocol_id_by_class(&self, class: &EventName) -> Option<&EventId> {
         self.protocol_id_by_name.get(class)
     }
+
+    /// Whether `schema` is `class` or inherits from it
+    pub fn is_subclass_of(&self, schema: &ProtocolSchema, class: &EventName) -> bool {
+        let mut current = Some(schema);
+        while let Some(schema) = current {
+            if &schema.name == class {
+                return true;
+            }
+            current = schema
+                .parent
+                .as_ref()
+                .and_then(|parent| self.get_protocol_by_class(parent));
+        }
+        false
+    }
 }
 
 #[derive(Error, Debug)]
 pub enum ProtocolError {
     #[error(transparent)]
     IoError(#[from] std::io::Error),
+    #[error("Invalid protocol file: {0}")]
+    InvalidProtocolFile(#[from] serde_json::Error),
+    #[error("Protocol file not found at {}", .0.display())]
+    MissingProtocolFile(PathBuf),
+    #[error("{0}")]
+    InvalidSchema(ValidationReport),
+    #[error("Invalid type `{type_name}` for {class}.{field}: {source}")]
+    InvalidAttributeType {
+        class: EventName,
+        field: FieldName,
+        type_name: String,
+        #[source]
+        source: TypeParseError,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sniffer::protocol::plan::PlanStep;
+
+    fn raw_schema(attributes: &[(&str, &str)]) -> RawProtocolSchema {
+        RawProtocolSchema {
+            id: Some(1),
+            class_name: "TestMessage".to_string(),
+            superclass: None,
+            attributes: attributes
+                .iter()
+                .map(|(field, var_type)| (field.to_string(), var_type.to_string()))
+                .collect(),
+            packed_booleans: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_attributes() {
+        let schema = ProtocolSchema::try_from(raw_schema(&[
+            ("name", "String"),
+            ("look", "EntityLook"),
+            ("flags", "Vector<Short,Vector<5,Boolean>>"),
+        ]))
+        .unwrap();
+        assert_eq!(schema.attributes["name"], ProtocolVarType::String);
+        assert_eq!(
+            schema.attributes["look"],
+            ProtocolVarType::Class("EntityLook".to_string())
+        );
+        assert!(matches!(
+            schema.attributes["flags"],
+            ProtocolVarType::Vector(_)
+        ));
+
+        let err = ProtocolSchema::try_from(raw_schema(&[
+            ("name", "String"),
+            ("values", "Vector<Short,Int"),
+        ]))
+        .unwrap_err();
+        assert!(matches!(
+            &err,
+            ProtocolError::InvalidAttributeType { class, field, source: TypeParseError::UnexpectedEnd, .. }
+                if class == "TestMessage" && field == "values"
+        ));
+        assert_eq!(
+            err.to_string(),
+            "Invalid type `Vector<Short,Int` for TestMessage.values: Unexpected end of type"
+        );
+    }
+
+    #[test]
+    fn test_validation() {
+        let mut protocol = ProtocolManager::with_config(ProtocolConfig { strict: true });
+        protocol.init("tests/fixtures/").unwrap();
+        assert!(protocol.validation_report().is_valid());
+
+        assert!(matches!(
+            protocol.init("tests/fixtures/missing"),
+            Err(ProtocolError::MissingProtocolFile(_))
+        ));
+    }
+
+    #[test]
+    fn test_invalid_attribute_types() {
+        let dir = std::env::temp_dir().join(format!("dtools-protocol-{}", std::process::id()));
+        let events_dir = dir.join(EXTRACTOR_DIR);
+        std::fs::create_dir_all(&events_dir).unwrap();
+        let events = serde_json::json!([
+            { "id": "1", "class_name": "ValidMessage", "superclass": null,
+              "attributes": { "name": "String" } },
+            { "id": "2", "class_name": "BrokenMessage", "superclass": null,
+              "attributes": { "name": "String", "values": "Vector<Short,Int" } },
+        ]);
+        std::fs::write(events_dir.join(EVENTS_FILE), events.to_string()).unwrap();
+
+        let mut protocol = ProtocolManager::new();
+        protocol.init(&dir).unwrap();
+        assert_eq!(
+            protocol.validation_report().issues,
+            vec![SchemaIssue::InvalidAttributeType {
+                class: "BrokenMessage".to_string(),
+                field: "values".to_string(),
+                type_name: "Vector<Short,Int".to_string(),
+                error: TypeParseError::UnexpectedEnd,
+            }]
+        );
+        assert!(protocol.get_protocol(&1).is_some());
+        assert!(protocol.get_protocol(&2).is_none());
+
+        let mut strict = ProtocolManager::with_config(ProtocolConfig { strict: true });
+        let err = strict.init(&dir).unwrap_err();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(matches!(err, ProtocolError::InvalidSchema(report) if report.issues.len() == 1));
+    }
+
+    #[test]
+    fn test_duplicate_definitions() {
+        let dir = std::env::temp_dir().join(format!("dtools-duplicates-{}", std::process::id()));
+        let events_dir = dir.join(EXTRACTOR_DIR);
+        std::fs::create_dir_all(&events_dir).unwrap();
+        let events = serde_json::json!([
+            { "id": "", "class_name": "NetworkMessage", "superclass": null, "attributes": {} },
+            { "id": "", "class_name": "NetworkType", "superclass": null, "attributes": {} },
+            { "id": "1", "class_name": "FirstMessage", "superclass": "NetworkMessage",
+              "attributes": { "name": "String" } },
+            // Loses its id to FirstMessage, still the class named SecondMessage
+            { "id": "1", "class_name": "SecondMessage", "superclass": "NetworkMessage",
+              "attributes": { "value": "Int" } },
+            { "id": "2", "class_name": "SecondMessage", "superclass": "NetworkMessage",
+              "attributes": {} },
+            { "id": "3", "class_name": "ThirdMessage", "superclass": "SecondMessage",
+              "attributes": {} },
+        ]);
+        std::fs::write(events_dir.join(EVENTS_FILE), events.to_string()).unwrap();
+
+        let mut protocol = ProtocolManager::new();
+        protocol.init(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        // The classes without an id can't be reached by id, id 0 is not a message
+        let mut ids = protocol.ids().copied().collect::<Vec<_>>();
+        ids.sort();
+        assert_eq!(ids, [1, 3]);
+        assert!(protocol.get_protocol(&0).is_none());
+        assert!(protocol.get_plan(&0).is_none());
+        for class in ["NetworkMessage", "NetworkType"] {
+            let schema = protocol.get_protocol_by_class(&class.to_string()).unwrap();
+            assert_eq!(schema.id, None);
+        }
+
+        let first = protocol.get_protocol(&1).unwrap();
+        assert_eq!(first.name, "FirstMessage");
+        assert!(protocol.is_subclass_of(first, &"NetworkMessage".to_string()));
+        let second = protocol
+            .get_protocol_by_class(&"SecondMessage".to_string())
+            .unwrap();
+        assert!(second.attributes.contains_key("value"));
+        assert_eq!(
+            protocol.get_protocol_id_by_class(&"SecondMessage".to_string()),
+            None
+        );
+
+        // Parents only reachable by name are still read
+        let third = protocol.get_plan(&3).unwrap();
+        assert_eq!(third.lineage, [3]);
+        assert!(matches!(
+            third.steps.as_slice(),
+            [PlanStep::Field(name, _)] if name.as_ref() == "value"
+        ));
+    }
 }
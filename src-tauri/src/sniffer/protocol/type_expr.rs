@@ -0,0 +1,317 @@
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use super::protocol::{ProtocolVarType, ProtocolVarTypeVector, VectorLength};
+
+/// Generic argument, `Vector<5,Int>` has a fixed length as its first argument
+enum TypeArg {
+    Length(usize),
+    Type(ProtocolVarType),
+}
+
+/// Recursive descent parser for the attribute types written by the extractor, e.g.
+/// `Vector<VarShort,TypeId<GameRolePlayActorInformations>>`
+struct TypeParser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> TypeParser<'a> {
+    fn new(input: &'a str) -> Self {
+        TypeParser { input, pos: 0 }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.input[self.pos..].chars().next()
+    }
+
+    fn next(&mut self) -> Result<char, TypeParseError> {
+        let c = self.peek().ok_or(TypeParseError::UnexpectedEnd)?;
+        self.pos += c.len_utf8();
+        Ok(c)
+    }
+
+    fn ident(&mut self) -> Result<&'a str, TypeParseError> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let rest = &self.input[start..];
+        let len = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return match rest.chars().next() {
+                Some(c) => Err(TypeParseError::UnexpectedChar(start, c)),
+                None => Err(TypeParseError::UnexpectedEnd),
+            };
+        }
+        self.pos += len;
+        Ok(&rest[..len])
+    }
+
+    /// A whole type expression, nothing may follow it
+    fn parse(mut self) -> Result<ProtocolVarType, TypeParseError> {
+        let var_type = match self.parse_arg()? {
+            TypeArg::Type(var_type) => var_type,
+            TypeArg::Length(length) => return Err(TypeParseError::UnexpectedLength(length)),
+        };
+        match self.peek() {
+            Some(c) => Err(TypeParseError::UnexpectedChar(self.pos, c)),
+            None => Ok(var_type),
+        }
+    }
+
+    fn parse_arg(&mut self) -> Result<TypeArg, TypeParseError> {
+        let name = self.ident()?;
+        if let Ok(length) = name.parse::<usize>() {
+            return Ok(TypeArg::Length(length));
+        }
+
+        let mut args = Vec::new();
+        if self.peek() == Some('<') {
+            self.next()?;
+            loop {
+                args.push(self.parse_arg()?);
+                let pos = self.pos;
+                match self.next()? {
+                    ',' => continue,
+                    '>' => break,
+                    c => return Err(TypeParseError::UnexpectedChar(pos, c)),
+                }
+            }
+        }
+        build_type(name, args).map(TypeArg::Type)
+    }
+}
+
+fn build_type(name: &str, args: Vec<TypeArg>) -> Result<ProtocolVarType, TypeParseError> {
+    let expect_args = |expected: usize| {
+        if args.len() != expected {
+            return Err(TypeParseError::WrongArgumentCount {
+                container: name.to_string(),
+                expected,
+                found: args.len(),
+            });
+        }
+        Ok(())
+    };
+
+    match name {
+        "Vector" | "TypeIdVector" => {
+            expect_args(2)?;
+            let type_id = name == "TypeIdVector";
+            let mut args = args.into_iter();
+
+            let length = match args.next() {
+                Some(TypeArg::Length(length)) => VectorLength::Fixed(length),
+                Some(TypeArg::Type(var_type)) if var_type.is_length() => {
+                    VectorLength::Prefixed(var_type)
+                }
+                Some(TypeArg::Type(var_type)) => {
+                    return Err(TypeParseError::InvalidLength(var_type.to_string()))
+                }
+                None => unreachable!(),
+            };
+            let types = match args.next() {
+                Some(TypeArg::Type(var_type)) => var_type,
+                Some(TypeArg::Length(length)) => {
+                    return Err(TypeParseError::UnexpectedLength(length))
+                }
+                None => unreachable!(),
+            };
+            if type_id {
+                expect_class(&types)?;
+            }
+
+            Ok(ProtocolVarType::Vector(Box::new(ProtocolVarTypeVector {
+                length,
+                types,
+                type_id,
+            })))
+        }
+        "TypeId" => {
+            expect_args(1)?;
+            match args.into_iter().next() {
+                Some(TypeArg::Type(var_type)) => {
+                    expect_class(&var_type)?;
+                    Ok(ProtocolVarType::TypeId(Box::new(var_type)))
+                }
+                Some(TypeArg::Length(length)) => Err(TypeParseError::UnexpectedLength(length)),
+                None => unreachable!(),
+            }
+        }
+        _ if !args.is_empty() => Err(TypeParseError::UnknownContainer(name.to_string())),
+        _ => Ok(ProtocolVarType::primitive(name)
+            .unwrap_or_else(|| ProtocolVarType::Class(name.to_string()))),
+    }
+}
+
+/// Polymorphic values are prefixed with a class id
+fn expect_class(var_type: &ProtocolVarType) -> Result<(), TypeParseError> {
+    match var_type {
+        ProtocolVarType::Class(_) => Ok(()),
+        _ => Err(TypeParseError::ExpectedClass(var_type.to_string())),
+    }
+}
+
+impl FromStr for ProtocolVarType {
+    type Err = TypeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TypeParser::new(s).parse()
+    }
+}
+
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum TypeParseError {
+    #[error("Unexpected end of type")]
+    UnexpectedEnd,
+    #[error("Unexpected '{1}' at offset {0}")]
+    UnexpectedChar(usize, char),
+    #[error("Unexpected length {0}, only the first argument of a vector can be a length")]
+    UnexpectedLength(usize),
+    #[error("Unknown container {0}")]
+    UnknownContainer(String),
+    #[error("{container} expects {expected} type arguments, found {found}")]
+    WrongArgumentCount {
+        container: String,
+        expected: usize,
+        found: usize,
+    },
+    #[error("{0} can't be used as a vector length")]
+    InvalidLength(String),
+    #[error("Expected a class, found {0}")]
+    ExpectedClass(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn var_type(name: &str) -> ProtocolVarType {
+        name.parse().unwrap()
+    }
+
+    fn class(name: &str) -> ProtocolVarType {
+        ProtocolVarType::Class(name.to_string())
+    }
+
+    #[test]
+    fn test_parse_vector() {
+        let ProtocolVarType::Vector(vector) = var_type("Vector<Short,Int>") else {
+            panic!("not a vector");
+        };
+        assert_eq!(
+            vector.length,
+            VectorLength::Prefixed(ProtocolVarType::Short)
+        );
+        assert_eq!(vector.types, ProtocolVarType::Int);
+        assert!(!vector.type_id);
+
+        let ProtocolVarType::Vector(vector) = var_type("Vector<5,Int>") else {
+            panic!("not a vector");
+        };
+        assert_eq!(vector.length, VectorLength::Fixed(5));
+
+        let ProtocolVarType::Vector(vector) = var_type("TypeIdVector<VarInt,HumanOption>") else {
+            panic!("not a vector");
+        };
+        assert_eq!(
+            vector.length,
+            VectorLength::Prefixed(ProtocolVarType::VarInt)
+        );
+        assert_eq!(vector.types, class("HumanOption"));
+        assert!(vector.type_id);
+
+        let ProtocolVarType::Vector(vector) = var_type("Vector<Short, Vector<VarShort, Int>>")
+        else {
+            panic!("not a vector");
+        };
+        assert_eq!(vector.types, var_type("Vector<VarShort,Int>"));
+        let ProtocolVarType::Vector(inner) = &vector.types else {
+            panic!("not a vector");
+        };
+        assert_eq!(
+            inner.length,
+            VectorLength::Prefixed(ProtocolVarType::VarShort)
+        );
+
+        assert_eq!(var_type("EntityLook"), class("EntityLook"));
+        assert_eq!(var_type("Int"), ProtocolVarType::Int);
+    }
+
+    #[test]
+    fn test_parse_type_id() {
+        assert_eq!(
+            var_type("TypeId<PlayerStatus>"),
+            ProtocolVarType::TypeId(Box::new(class("PlayerStatus")))
+        );
+        assert_eq!(
+            var_type("Vector<Short,TypeId<PlayerStatus>>").to_string(),
+            "Vector<Short,TypeId<PlayerStatus>>"
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |name: &str| name.parse::<ProtocolVarType>().unwrap_err();
+
+        assert_eq!(error(""), TypeParseError::UnexpectedEnd);
+        assert_eq!(error("Vector<Short,Int"), TypeParseError::UnexpectedEnd);
+        assert_eq!(
+            error("Vector<Short,Int>>"),
+            TypeParseError::UnexpectedChar(17, '>')
+        );
+        assert_eq!(
+            error("Vector<Short;Int>"),
+            TypeParseError::UnexpectedChar(12, ';')
+        );
+        assert_eq!(
+            error("Vector<,Int>"),
+            TypeParseError::UnexpectedChar(7, ',')
+        );
+        assert_eq!(error("5"), TypeParseError::UnexpectedLength(5));
+        assert_eq!(
+            error("Vector<Short,5>"),
+            TypeParseError::UnexpectedLength(5)
+        );
+        assert_eq!(
+            error("Vector<Short>"),
+            TypeParseError::WrongArgumentCount {
+                container: "Vector".to_string(),
+                expected: 2,
+                found: 1
+            }
+        );
+        assert_eq!(
+            error("TypeId<A,B>"),
+            TypeParseError::WrongArgumentCount {
+                container: "TypeId".to_string(),
+                expected: 1,
+                found: 2
+            }
+        );
+        assert_eq!(
+            error("HashMap<Int,String>"),
+            TypeParseError::UnknownContainer("HashMap".to_string())
+        );
+        assert_eq!(
+            error("Vector<String,Byte>"),
+            TypeParseError::InvalidLength("String".to_string())
+        );
+        assert_eq!(
+            error("TypeId<Int>"),
+            TypeParseError::ExpectedClass("Int".to_string())
+        );
+        assert_eq!(
+            error("TypeIdVector<Short,Vector<Short,Int>>"),
+            TypeParseError::ExpectedClass("Vector<Short,Int>".to_string())
+        );
+    }
+}
@@ -0,0 +1,252 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::Display,
+};
+
+use thiserror::Error;
+
+use super::{
+    protocol::{EventId, EventName, FieldName, ProtocolSchema, ProtocolVarType},
+    type_expr::TypeParseError,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SchemaIssue {
+    #[error("Id {id} is used by {}", classes.join(", "))]
+    DuplicateId {
+        id: EventId,
+        classes: Vec<EventName>,
+    },
+    #[error("Class {0} is defined {1} times")]
+    DuplicateName(EventName, usize),
+    #[error("Class {class} inherits from unknown class {parent}")]
+    UnknownParent { class: EventName, parent: EventName },
+    #[error("{class}.{field} references unknown class {referenced}")]
+    UnknownClass {
+        class: EventName,
+        field: FieldName,
+        referenced: EventName,
+    },
+    #[error("Inheritance cycle: {}", classes.join(" -> "))]
+    InheritanceCycle { classes: Vec<EventName> },
+    /// The class is left out of the protocol
+    #[error("Invalid type `{type_name}` for {class}.{field}: {error}")]
+    InvalidAttributeType {
+        class: EventName,
+        field: FieldName,
+        type_name: String,
+        error: TypeParseError,
+    },
+}
+
+/// Every problem found in the schema graph, in a stable order
+#[derive(Debug, Clone, Default)]
+pub struct ValidationReport {
+    pub issues: Vec<SchemaIssue>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl Display for ValidationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} problem(s) in the protocol schema", self.issues.len())?;
+        for issue in &self.issues {
+            write!(f, "\n - {}", issue)?;
+        }
+        Ok(())
+    }
+}
+
+/// Check the whole schema graph before it is indexed: ids and names must be unique and every
+/// parent and attribute type must resolve to a known class
+pub fn validate(schemas: &[ProtocolSchema]) -> ValidationReport {
+    let mut issues = Vec::new();
+
+    let mut classes_by_id: BTreeMap<EventId, Vec<EventName>> = BTreeMap::new();
+    for (id, name) in schemas
+        .iter()
+        .filter_map(|schema| Some((schema.id?, &schema.name)))
+    {
+        classes_by_id.entry(id).or_default().push(name.clone());
+    }
+    for (id, classes) in classes_by_id {
+        if classes.len() > 1 {
+            issues.push(SchemaIssue::DuplicateId { id, classes });
+        }
+    }
+
+    // The first definition wins, as when the protocol is indexed
+    let mut by_name: HashMap<&EventName, &ProtocolSchema> = HashMap::new();
+    let mut name_count: HashMap<&EventName, usize> = HashMap::new();
+    for schema in schemas {
+        by_name.entry(&schema.name).or_insert(schema);
+        *name_count.entry(&schema.name).or_default() += 1;
+    }
+    let mut reported = HashSet::new();
+    for schema in schemas {
+        let count = name_count[&schema.name];
+        if count > 1 && reported.insert(&schema.name) {
+            issues.push(SchemaIssue::DuplicateName(schema.name.clone(), count));
+        }
+    }
+
+    for schema in schemas {
+        if let Some(parent) = &schema.parent {
+            if !by_name.contains_key(parent) {
+                issues.push(SchemaIssue::UnknownParent {
+                    class: schema.name.clone(),
+                    parent: parent.clone(),
+                });
+            }
+        }
+
+        for (field, var_type) in &schema.attributes {
+            let mut referenced = Vec::new();
+            collect_classes(var_type, &mut referenced);
+            for class in referenced {
+                if !by_name.contains_key(class) {
+                    issues.push(SchemaIssue::UnknownClass {
+                        class: schema.name.clone(),
+                        field: field.clone(),
+                        referenced: class.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    issues.extend(
+        find_cycles(schemas, &by_name)
+            .into_iter()
+            .map(|classes| SchemaIssue::InheritanceCycle { classes }),
+    );
+
+    ValidationReport { issues }
+}
+
+fn collect_classes<'a>(var_type: &'a ProtocolVarType, classes: &mut Vec<&'a EventName>) {
+    match var_type {
+        ProtocolVarType::Class(name) => classes.push(name),
+        ProtocolVarType::TypeId(base) => collect_classes(base, classes),
+        ProtocolVarType::Vector(vector) => collect_classes(&vector.types, classes),
+        _ => {}
+    }
+}
+
+/// Each cycle is reported once, starting from its first class in file order
+fn find_cycles(
+    schemas: &[ProtocolSchema],
+    by_name: &HashMap<&EventName, &ProtocolSchema>,
+) -> Vec<Vec<EventName>> {
+    let mut cycles = Vec::new();
+    // Classes whose ancestors are already known to end (or to join a reported cycle)
+    let mut done: HashSet<&EventName> = HashSet::new();
+
+    for schema in schemas {
+        let mut path: Vec<&EventName> = Vec::new();
+        let mut current = Some(&schema.name);
+        while let Some(name) = current {
+            if done.contains(name) {
+                break;
+            }
+            if let Some(start) = path.iter().position(|visited| *visited == name) {
+                cycles.push(path[start..].iter().map(|name| (*name).clone()).collect());
+                break;
+            }
+            path.push(name);
+            current = by_name.get(name).and_then(|schema| schema.parent.as_ref());
+        }
+        done.extend(path);
+    }
+    cycles
+}
+
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+
+    use super::*;
+
+    fn schema(
+        id: Option<EventId>,
+        name: &str,
+        parent: Option<&str>,
+        attributes: &[(&str, &str)],
+    ) -> ProtocolSchema {
+        ProtocolSchema {
+            id,
+            name: name.to_string(),
+            parent: parent.map(str::to_string),
+            attributes: attributes
+                .iter()
+                .map(|(field, var_type)| (field.to_string(), var_type.parse().unwrap()))
+                .collect::<IndexMap<_, _>>(),
+            packed_booleans: None,
+        }
+    }
+
+    #[test]
+    fn test_valid() {
+        let schemas = vec![
+            schema(None, "NetworkMessage", None, &[]),
+            schema(Some(1), "Look", None, &[("id", "VarShort")]),
+            schema(Some(2), "SubLook", Some("Look"), &[]),
+            schema(
+                Some(3),
+                "LookMessage",
+                Some("NetworkMessage"),
+                &[("looks", "TypeIdVector<Short,Look>"), ("main", "Look")],
+            ),
+        ];
+        let report = validate(&schemas);
+        assert!(report.is_valid(), "{}", report);
+    }
+
+    #[test]
+    fn test_issues() {
+        let schemas = vec![
+            schema(None, "NetworkMessage", None, &[]),
+            schema(Some(1), "A", Some("C"), &[]),
+            schema(Some(2), "B", Some("A"), &[]),
+            schema(Some(3), "C", Some("B"), &[]),
+            schema(Some(3), "D", Some("Missing"), &[]),
+            schema(
+                Some(4),
+                "E",
+                None,
+                &[("a", "A"), ("b", "Vector<Short,TypeId<Unknown>>")],
+            ),
+            schema(Some(5), "E", None, &[]),
+        ];
+        let report = validate(&schemas);
+        assert_eq!(
+            report.issues,
+            vec![
+                SchemaIssue::DuplicateId {
+                    id: 3,
+                    classes: vec!["C".to_string(), "D".to_string()]
+                },
+                SchemaIssue::DuplicateName("E".to_string(), 2),
+                SchemaIssue::UnknownParent {
+                    class: "D".to_string(),
+                    parent: "Missing".to_string()
+                },
+                SchemaIssue::UnknownClass {
+                    class: "E".to_string(),
+                    field: "b".to_string(),
+                    referenced: "Unknown".to_string()
+                },
+                SchemaIssue::InheritanceCycle {
+                    classes: vec!["A".to_string(), "C".to_string(), "B".to_string()]
+                },
+            ]
+        );
+        assert!(report
+            .to_string()
+            .starts_with("5 problem(s) in the protocol schema\n - Id 3 is used by C, D"));
+    }
+}
@@ -0,0 +1,321 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use pcap::Linktype;
+use tracing::{debug, info};
+
+/// A new file is started once the current one reaches this size
+const MAX_RECORDING_FILE_SIZE: u64 = 64 * 1024 * 1024;
+
+pub const RECORDINGS_DIR: &str = "recordings";
+pub const RECORDING_EXTENSION: &str = "pcapng";
+
+const BLOCK_SECTION_HEADER: u32 = 0x0A0D0D0A;
+const BLOCK_INTERFACE_DESCRIPTION: u32 = 0x00000001;
+const BLOCK_ENHANCED_PACKET: u32 = 0x00000006;
+const BYTE_ORDER_MAGIC: u32 = 0x1A2B3C4D;
+
+/// Minimal pcapng writer: one section, one interface, microsecond timestamps
+#[derive(Debug)]
+pub struct PcapngWriter<W: Write> {
+    writer: W,
+    written: u64,
+}
+
+impl<W: Write> PcapngWriter<W> {
+    pub fn new(mut writer: W, link_type: Linktype) -> io::Result<Self> {
+        let mut written = 0;
+
+        // Section header block
+        let mut body = Vec::with_capacity(16);
+        body.extend_from_slice(&BYTE_ORDER_MAGIC.to_le_bytes());
+        body.extend_from_slice(&1u16.to_le_bytes()); // major version
+        body.extend_from_slice(&0u16.to_le_bytes()); // minor version
+        body.extend_from_slice(&(-1i64).to_le_bytes()); // section length, unknown
+        written += write_block(&mut writer, BLOCK_SECTION_HEADER, &body)?;
+
+        // Interface description block
+        let mut body = Vec::with_capacity(8);
+        body.extend_from_slice(&(link_type.0 as u16).to_le_bytes());
+        body.extend_from_slice(&0u16.to_le_bytes()); // reserved
+        body.extend_from_slice(&0u32.to_le_bytes()); // snaplen, no limit
+        written += write_block(&mut writer, BLOCK_INTERFACE_DESCRIPTION, &body)?;
+
+        Ok(PcapngWriter { writer, written })
+    }
+
+    /// `timestamp` is in microseconds since the unix epoch
+    pub fn write_packet(
+        &mut self,
+        timestamp: u64,
+        original_len: u32,
+        data: &[u8],
+    ) -> io::Result<()> {
+        let mut body = Vec::with_capacity(20 + data.len() + 3);
+        body.extend_from_slice(&0u32.to_le_bytes()); // interface id
+        body.extend_from_slice(&((timestamp >> 32) as u32).to_le_bytes());
+        body.extend_from_slice(&(timestamp as u32).to_le_bytes());
+        body.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        body.extend_from_slice(&original_len.to_le_bytes());
+        body.extend_from_slice(data);
+        self.written += write_block(&mut self.writer, BLOCK_ENHANCED_PACKET, &body)?;
+        Ok(())
+    }
+
+    pub fn written(&self) -> u64 {
+        self.written
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Write a block padded to 32 bits, returns the number of bytes written
+fn write_block(writer: &mut impl Write, block_type: u32, body: &[u8]) -> io::Result<u64> {
+    let padding = (4 - body.len() % 4) % 4;
+    let total_length = (12 + body.len() + padding) as u32;
+
+    writer.write_all(&block_type.to_le_bytes())?;
+    writer.write_all(&total_length.to_le_bytes())?;
+    writer.write_all(body)?;
+    writer.write_all(&[0; 3][..padding])?;
+    writer.write_all(&total_length.to_le_bytes())?;
+    Ok(total_length as u64)
+}
+
+/// Writes the captured frames to `<dir>/capture-<start>-<index>.pcapng`, starting a new file
+/// when the current one is too big
+#[derive(Debug)]
+pub struct Recorder {
+    dir: PathBuf,
+    started_at: u64,
+    index: u32,
+    current: Option<(Linktype, PathBuf, PcapngWriter<BufWriter<File>>)>,
+}
+
+impl Recorder {
+    pub fn new(dir: impl AsRef<Path>) -> io::Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)?;
+        let started_at = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        Ok(Recorder {
+            dir,
+            started_at,
+            index: 0,
+            current: None,
+        })
+    }
+
+    pub fn current_file(&self) -> Option<&Path> {
+        self.current.as_ref().map(|(_, path, _)| path.as_path())
+    }
+
+    pub fn write(
+        &mut self,
+        link_type: Linktype,
+        timestamp: u64,
+        original_len: u32,
+        data: &[u8],
+    ) -> io::Result<()> {
+        let rotate = match &self.current {
+            None => true,
+            Some((current_link_type, _, writer)) => {
+                *current_link_type != link_type || writer.written() >= MAX_RECORDING_FILE_SIZE
+            }
+        };
+        if rotate {
+            self.rotate(link_type)?;
+        }
+
+        let (_, _, writer) = self.current.as_mut().unwrap();
+        writer.write_packet(timestamp, original_len, data)
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        match self.current.as_mut() {
+            Some((_, _, writer)) => writer.flush(),
+            None => Ok(()),
+        }
+    }
+
+    fn rotate(&mut self, link_type: Linktype) -> io::Result<()> {
+        self.flush()?;
+
+        // A recorder started in the same second may have used these names already
+        let (path, file) = loop {
+            let path = self.dir.join(format!(
+                "capture-{}-{}.{}",
+                self.started_at, self.index, RECORDING_EXTENSION
+            ));
+            self.index += 1;
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(file) => break (path, file),
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(err) => return Err(err),
+            }
+        };
+        info!("Recording capture to {}", path.display());
+
+        let writer = PcapngWriter::new(BufWriter::new(file), link_type)?;
+        self.current = Some((link_type, path, writer));
+        Ok(())
+    }
+}
+
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        if let Err(err) = self.flush() {
+            debug!("Failed to flush recording: {:?}", err);
+        }
+    }
+}
+
+/// Recordings found in `dir`, oldest first
+pub fn list_recordings(dir: impl AsRef<Path>) -> io::Result<Vec<PathBuf>> {
+    let dir = dir.as_ref();
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut recordings = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == RECORDING_EXTENSION)
+        })
+        .collect::<Vec<_>>();
+    recordings.sort_by(|a, b| {
+        recording_order(a)
+            .cmp(&recording_order(b))
+            .then_with(|| a.cmp(b))
+    });
+    Ok(recordings)
+}
+
+/// `(start, index)` of `capture-<start>-<index>.pcapng`, so that `-10` comes after `-2`
+fn recording_order(path: &Path) -> Option<(u64, u32)> {
+    let stem = path.file_stem()?.to_str()?;
+    let (started_at, index) = stem.strip_prefix("capture-")?.split_once('-')?;
+    Some((started_at.parse().ok()?, index.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use pcap::Capture;
+
+    use super::*;
+    use crate::sniffer::parser::framer::frame_timestamp;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("dtools-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_pcapng_layout() {
+        let mut writer = PcapngWriter::new(Vec::new(), Linktype::ETHERNET).unwrap();
+        writer.write_packet(1_000_001, 5, &[1, 2, 3, 4, 5]).unwrap();
+        let bytes = writer.writer.clone();
+
+        assert_eq!(writer.written(), bytes.len() as u64);
+        assert_eq!(&bytes[0..4], &BLOCK_SECTION_HEADER.to_le_bytes());
+        assert_eq!(&bytes[8..12], &BYTE_ORDER_MAGIC.to_le_bytes());
+
+        // section header (28) + interface description (20) + packet (32 + 8)
+        assert_eq!(bytes.len(), 28 + 20 + 40);
+        let packet = &bytes[48..];
+        assert_eq!(&packet[0..4], &BLOCK_ENHANCED_PACKET.to_le_bytes());
+        assert_eq!(&packet[4..8], &40u32.to_le_bytes());
+        assert_eq!(&packet[36..40], &40u32.to_le_bytes());
+        assert_eq!(&packet[28..33], &[1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let dir = temp_dir("recorder");
+        let mut recorder = Recorder::new(&dir).unwrap();
+        let frames = [(1_000_001, vec![1, 2, 3, 4, 5]), (2_500_000, vec![6; 64])];
+        for (timestamp, data) in &frames {
+            recorder
+                .write(Linktype::ETHERNET, *timestamp, data.len() as u32, data)
+                .unwrap();
+        }
+        let path = recorder.current_file().unwrap().to_path_buf();
+        drop(recorder);
+
+        let mut capture = Capture::from_file(&path).unwrap();
+        assert_eq!(capture.get_datalink(), Linktype::ETHERNET);
+        for (timestamp, data) in &frames {
+            let packet = capture.next_packet().unwrap();
+            assert_eq!(frame_timestamp(packet.header), *timestamp);
+            assert_eq!(packet.data, data.as_slice());
+        }
+        assert!(matches!(
+            capture.next_packet(),
+            Err(pcap::Error::NoMorePackets)
+        ));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_restart_in_same_second() {
+        let dir = temp_dir("restart");
+        let mut first = Recorder::new(&dir).unwrap();
+        first.write(Linktype::ETHERNET, 1, 3, &[1, 2, 3]).unwrap();
+        let first_path = first.current_file().unwrap().to_path_buf();
+        drop(first);
+        let first_len = fs::metadata(&first_path).unwrap().len();
+
+        let mut second = Recorder::new(&dir).unwrap();
+        second.started_at = recording_order(&first_path).unwrap().0;
+        second.write(Linktype::ETHERNET, 2, 1, &[4]).unwrap();
+        let second_path = second.current_file().unwrap().to_path_buf();
+        drop(second);
+
+        // The first recording is left untouched
+        assert_ne!(first_path, second_path);
+        assert_eq!(fs::metadata(&first_path).unwrap().len(), first_len);
+        assert_eq!(list_recordings(&dir).unwrap(), [first_path, second_path]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_list_recordings() {
+        let dir = temp_dir("recordings");
+        fs::create_dir_all(&dir).unwrap();
+        for name in [
+            "capture-20-0.pcapng",
+            "capture-10-10.pcapng",
+            "capture-10-2.pcapng",
+            "notes.txt",
+        ] {
+            File::create(dir.join(name)).unwrap();
+        }
+
+        let names = list_recordings(&dir)
+            .unwrap()
+            .into_iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [
+                "capture-10-2.pcapng",
+                "capture-10-10.pcapng",
+                "capture-20-0.pcapng"
+            ]
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::protocol::protocol::EventId;
+
+/// Counters of the current capture session, they tell apart "no game traffic" from
+/// "the game traffic can't be decoded anymore" (e.g. after a game update)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, specta::Type, tauri_specta::Event)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureStats {
+    /// Frames read from the capture
+    pub frames: u64,
+    /// Size of the frames read from the capture
+    pub bytes: u64,
+    /// Frames without a valid link/IP/TCP header
+    pub header_failures: u64,
+    /// Message headers that can't be right (id 0, oversized content), the stream was desynced
+    /// and its buffer dropped until the next segment
+    pub invalid: u64,
+    /// Times the framer had to wait for the next segment
+    pub incomplete: u64,
+    /// Stream bytes that never reached the framer: skipped gaps, and the segments waiting
+    /// behind a gap when their connection ended
+    pub lost_bytes: u64,
+    pub messages_framed: u64,
+    pub messages_decoded: u64,
+    pub decode_failures: HashMap<EventId, u64>,
+    /// Decoded messages a listener was too slow to take, by listener
+    pub dropped_messages: HashMap<String, u64>,
+    /// Messages dropped by a stage of the pipeline, by stage
+    pub filtered_messages: HashMap<String, u64>,
+}
+
+impl CaptureStats {
+    pub fn record_frame(&mut self, len: usize) {
+        self.frames += 1;
+        self.bytes += len as u64;
+    }
+
+    pub fn record_decode_failure(&mut self, id: EventId) {
+        *self.decode_failures.entry(id).or_default() += 1;
+    }
+
+    pub fn total_decode_failures(&self) -> u64 {
+        self.decode_failures.values().sum()
+    }
+
+    pub fn record_dropped_message(&mut self, listener_id: &str) {
+        match self.dropped_messages.get_mut(listener_id) {
+            Some(count) => *count += 1,
+            None => {
+                self.dropped_messages.insert(listener_id.to_string(), 1);
+            }
+        }
+    }
+
+    pub fn total_dropped_messages(&self) -> u64 {
+        self.dropped_messages.values().sum()
+    }
+
+    pub fn record_filtered_message(&mut self, stage: &str) {
+        match self.filtered_messages.get_mut(stage) {
+            Some(count) => *count += 1,
+            None => {
+                self.filtered_messages.insert(stage.to_string(), 1);
+            }
+        }
+    }
+}
@@ -28,16 +28,71 @@ export const commands = {
   async getLastOpenChatTab(): Promise<string | null> {
     return await TAURI_INVOKE('get_last_open_chat_tab');
   },
+  async getCaptureState(): Promise<CaptureState> {
+    return await TAURI_INVOKE('get_capture_state');
+  },
+  async getCaptureStats(): Promise<CaptureStats> {
+    return await TAURI_INVOKE('get_capture_stats');
+  },
+  async startCapture(): Promise<CaptureState> {
+    return await TAURI_INVOKE('start_capture');
+  },
+  async stopCapture(): Promise<CaptureState> {
+    return await TAURI_INVOKE('stop_capture');
+  },
+  async restartCapture(): Promise<CaptureState> {
+    return await TAURI_INVOKE('restart_capture');
+  },
+  async updateNetworkConfig(network: NetworkConfig): Promise<CaptureState> {
+    return await TAURI_INVOKE('update_network_config', { network });
+  },
+  async startRecording(): Promise<boolean> {
+    return await TAURI_INVOKE('start_recording');
+  },
+  async stopRecording(): Promise<boolean> {
+    return await TAURI_INVOKE('stop_recording');
+  },
+  async listRecordings(): Promise<string[]> {
+    return await TAURI_INVOKE('list_recordings');
+  },
+  async replayRecording(path: string, speed: ReplaySpeed): Promise<CaptureState> {
+    return await TAURI_INVOKE('replay_recording', { path, speed });
+  },
 };
 
 export const events = __makeEvents__<{
   chatEvent: ChatEvent;
+  captureState: CaptureState;
+  captureStats: CaptureStats;
 }>({
   chatEvent: 'plugin:tauri-specta:chat-event',
+  captureState: 'plugin:tauri-specta:capture-state',
+  captureStats: 'plugin:tauri-specta:capture-stats',
 });
 
 /** user-defined types **/
 
+export type CaptureState =
+  | { state: 'idle' }
+  | { state: 'detecting' }
+  | { state: 'capturing'; interface: string | null }
+  | { state: 'replaying'; path: string }
+  | { state: 'errored'; message: string };
+
+export type CaptureStats = {
+  frames: bigint;
+  bytes: bigint;
+  headerFailures: bigint;
+  invalid: bigint;
+  incomplete: bigint;
+  lostBytes: bigint;
+  messagesFramed: bigint;
+  messagesDecoded: bigint;
+  decodeFailures: { [key in number]: bigint };
+  droppedMessages: { [key in string]: bigint };
+  filteredMessages: { [key in string]: bigint };
+};
+
 export type ChatEvent = {
   channel: number;
   sender_name: string;
@@ -61,8 +116,11 @@ export type ChatTabFilterType =
   | { type: 'word'; value: string }
   | { type: 'item'; value: number };
 export type ChatTabOptions = { keepHistory: boolean; notify: boolean };
-export type NetworkConfig = { port: number; interface: string };
-export type NodeConfig = { network: NetworkConfig; gameVersion: Version };
+export type NetworkConfig = { ports: PortRange[]; interface: string | null };
+export type NodeConfig = { network: NetworkConfig; gameVersion: Version; protocol: ProtocolConfig };
+export type PortRange = { start: number; end: number };
+export type ProtocolConfig = { strict: boolean };
+export type ReplaySpeed = 'original' | 'fast';
 export type Version = { version: string; checkForUpdates: boolean };
 
 /** tauri-specta globals **/